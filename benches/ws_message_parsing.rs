@@ -0,0 +1,56 @@
+//! WebSocket notification parsing: the previous serde_json path against the
+//! method pre-scan
+//!
+//! Run with `cargo bench --bench ws_message_parsing`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use badger::ingest::websocket::{JsonRpcResponse, WebSocketNotification};
+use badger::ingest::MessageScanner;
+
+const PROGRAM_NOTIFICATION: &str = r#"{"jsonrpc":"2.0","method":"programNotification","params":{"result":{"context":{"slot":287654321},"value":{"pubkey":"7YttLkHDoNj9wyDur5pM1ejNaAvT9X4eqaYcHQqtj2G5","account":{"data":["F6Lv4mA2AAAAyOHOKPkbAADw1pHSBwAAAAC4ZWY3AAAAAACAxqR+jQMAAQ==","base64"],"executable":false,"lamports":30185184,"owner":"6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P","rentEpoch":18446744073709551615,"space":49}}},"subscription":24040}}"#;
+
+const SLOT_NOTIFICATION: &str = r#"{"jsonrpc":"2.0","method":"slotNotification","params":{"result":{"parent":287654320,"root":287654289,"slot":287654321},"subscription":24041}}"#;
+
+const SUBSCRIPTION_CONFIRMATION: &str = r#"{"jsonrpc":"2.0","result":24040,"id":999}"#;
+
+/// What `handle_message` did before the pre-scan: response first, then notification
+fn parse_serde_json(message: &str) -> Option<u64> {
+    if let Ok(response) = serde_json::from_str::<JsonRpcResponse>(message) {
+        if response.id.is_some() {
+            return response.id;
+        }
+    }
+    serde_json::from_str::<WebSocketNotification>(message)
+        .ok()
+        .map(|notification| notification.params.subscription)
+}
+
+fn parse_scanned(scanner: &MessageScanner, message: &str) -> Option<u64> {
+    match scanner.decode_notification(message) {
+        Some((_, subscription_id, _)) => Some(subscription_id),
+        None => serde_json::from_str::<JsonRpcResponse>(message).ok()?.id,
+    }
+}
+
+fn bench_messages(c: &mut Criterion) {
+    let scanner = MessageScanner::new();
+    for (name, message) in [
+        ("program_notification", PROGRAM_NOTIFICATION),
+        ("slot_notification", SLOT_NOTIFICATION),
+        ("subscription_confirmation", SUBSCRIPTION_CONFIRMATION),
+    ] {
+        let mut group = c.benchmark_group(name);
+        group.bench_function("serde_json", |b| b.iter(|| parse_serde_json(black_box(message))));
+        group.bench_function("method_scan", |b| b.iter(|| parse_scanned(&scanner, black_box(message))));
+        group.finish();
+    }
+}
+
+fn bench_method_scan(c: &mut Criterion) {
+    let scanner = MessageScanner::new();
+    c.bench_function("method_scan_only", |b| b.iter(|| scanner.kind(black_box(PROGRAM_NOTIFICATION))));
+}
+
+criterion_group!(benches, bench_messages, bench_method_scan);
+criterion_main!(benches);
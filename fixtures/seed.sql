@@ -0,0 +1,19 @@
+-- Seed data for --fixtures test mode.
+-- Loaded after all analytics schemas are initialized; statements are idempotent.
+
+INSERT OR REPLACE INTO insider_profiles (
+    wallet_address, first_seen, last_activity, total_trades, successful_trades,
+    success_rate, total_volume, average_trade_size, total_pnl, roi_percentage,
+    average_hold_time, favorite_tokens, trading_frequency, confidence_score,
+    risk_score, copy_worthiness, last_updated
+) VALUES
+    ('9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM', strftime('%s', 'now') - 604800, strftime('%s', 'now') - 3600,
+     42, 31, 0.74, 1250.0, 29.8, 310.5, 24.8, 3.5, '[]', 6.0, 78.0, 25.0, 79.1, strftime('%s', 'now')),
+    ('7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU', strftime('%s', 'now') - 259200, strftime('%s', 'now') - 7200,
+     12, 5, 0.42, 310.0, 25.8, -12.4, -4.0, 1.2, '[]', 4.0, 41.0, 55.0, 42.3, strftime('%s', 'now'));
+
+INSERT OR IGNORE INTO wallet_candidates (
+    wallet_address, discovery_method, source, reported_pnl, reported_win_rate,
+    reported_trades, status, discovered_at
+) VALUES
+    ('9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM', 'IMPORTED', 'fixtures', 310.5, 0.74, 42, 'PROBATION', strftime('%s', 'now'));
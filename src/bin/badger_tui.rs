@@ -0,0 +1,335 @@
+//! Badger operations dashboard
+//!
+//! Attaches to the bot's SQLite database and renders live positions, P&L,
+//! the signal feed, insider activity, ingest rates and alerts. Operator
+//! actions (pause/resume, close position) are queued through the
+//! `ControlChannel` and applied by the running orchestrator.
+
+use anyhow::Result;
+use chrono::{TimeZone, Utc};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Row, Table, TableState};
+use ratatui::{Frame, Terminal};
+use std::io::Stdout;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use badger::core::dex_types::utils::shorten_pubkey;
+use badger::database::analytics::{InsiderActivityRecord, InsiderAnalytics, Position, PositionSummary, PositionTracker};
+use badger::database::{
+    AlertLogEntry, BadgerDatabase, ControlChannel, StoredTradingSignal, WalletLabels, WalletLabelStore,
+    COMMAND_CLOSE_POSITION, COMMAND_PAUSE, COMMAND_RESUME,
+};
+
+const DEFAULT_DATABASE_URL: &str = "sqlite:data/badger.db";
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+const INGEST_WINDOW_SECS: i64 = 60;
+
+/// Everything rendered in a single frame
+#[derive(Default)]
+struct DashboardSnapshot {
+    open_positions: Vec<Position>,
+    summary: Option<PositionSummary>,
+    signals: Vec<StoredTradingSignal>,
+    insider_activity: Vec<InsiderActivityRecord>,
+    ingest_counts: Vec<(String, i64)>,
+    alerts: Vec<AlertLogEntry>,
+    trading_paused: bool,
+}
+
+struct Dashboard {
+    db: Arc<BadgerDatabase>,
+    position_tracker: PositionTracker,
+    insider_analytics: InsiderAnalytics,
+    control: ControlChannel,
+    wallet_labels: WalletLabelStore,
+    snapshot: DashboardSnapshot,
+    selection: TableState,
+    frozen: bool,
+    status: String,
+}
+
+impl Dashboard {
+    async fn new(database_url: &str) -> Result<Self> {
+        let db = Arc::new(BadgerDatabase::attach(database_url).await?);
+        let position_tracker = PositionTracker::new(db.clone());
+        let insider_analytics = InsiderAnalytics::new(db.clone(), Arc::new(PositionTracker::new(db.clone())));
+        let control = ControlChannel::new(db.clone());
+        control.initialize_schema().await?;
+        let wallet_labels = WalletLabelStore::new(db.clone(), Arc::new(WalletLabels::new()));
+
+        Ok(Self {
+            db,
+            position_tracker,
+            insider_analytics,
+            control,
+            wallet_labels,
+            snapshot: DashboardSnapshot::default(),
+            selection: TableState::default(),
+            frozen: false,
+            status: "q quit | ↑/↓ select | c close position | h close half | p pause/resume trading | f freeze view".to_string(),
+        })
+    }
+
+    /// Reload all panes from the database
+    async fn refresh(&mut self) -> Result<()> {
+        let since = Utc::now().timestamp() - INGEST_WINDOW_SECS;
+        // Labels only exist once the bot has initialized the database
+        let _ = self.wallet_labels.load().await;
+
+        self.snapshot = DashboardSnapshot {
+            open_positions: self.position_tracker.get_open_positions().await?,
+            summary: self.position_tracker.get_position_summary().await.ok(),
+            signals: self.db.get_recent_trading_signals(50).await?,
+            // Insider tables only exist once the bot has initialized analytics
+            insider_activity: self.insider_analytics.get_recent_activities(50).await.unwrap_or_default(),
+            ingest_counts: self.db.get_event_counts_since(since).await?,
+            alerts: self.control.get_recent_alerts(50).await?,
+            trading_paused: self.control.is_paused().await?,
+        };
+
+        let len = self.snapshot.open_positions.len();
+        match self.selection.selected() {
+            _ if len == 0 => self.selection.select(None),
+            Some(i) if i >= len => self.selection.select(Some(len - 1)),
+            None => self.selection.select(Some(0)),
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn select_next(&mut self, forward: bool) {
+        let len = self.snapshot.open_positions.len();
+        if len == 0 {
+            return;
+        }
+        let current = self.selection.selected().unwrap_or(0);
+        let next = if forward { (current + 1) % len } else { (current + len - 1) % len };
+        self.selection.select(Some(next));
+    }
+
+    /// Queue a close of the selected position; `amount` is `all`, `50%` or `0.5 SOL`
+    async fn close_selected(&mut self, amount: &str) -> Result<()> {
+        let token_mint = match self.selection.selected().and_then(|i| self.snapshot.open_positions.get(i)) {
+            Some(position) => position.token_mint.clone(),
+            None => {
+                self.status = "No position selected".to_string();
+                return Ok(());
+            }
+        };
+
+        let target = format!("{}:{}", token_mint, amount);
+        let id = self.control.submit_command(COMMAND_CLOSE_POSITION, Some(&target)).await?;
+        self.status = format!("Queued close ({}) #{} for {}", amount, id, shorten_pubkey(&token_mint));
+        Ok(())
+    }
+
+    async fn toggle_pause(&mut self) -> Result<()> {
+        let command = if self.snapshot.trading_paused { COMMAND_RESUME } else { COMMAND_PAUSE };
+        let id = self.control.submit_command(command, None).await?;
+        self.status = format!("Queued {} #{}", command, id);
+        Ok(())
+    }
+}
+
+fn format_time(timestamp: i64) -> String {
+    Utc.timestamp_opt(timestamp, 0)
+        .single()
+        .map(|t| t.format("%H:%M:%S").to_string())
+        .unwrap_or_else(|| "--:--:--".to_string())
+}
+
+fn pane(title: &str) -> Block<'_> {
+    Block::default().borders(Borders::ALL).title(title)
+}
+
+fn draw(frame: &mut Frame, dashboard: &mut Dashboard) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Percentage(40),
+            Constraint::Percentage(30),
+            Constraint::Min(6),
+            Constraint::Length(1),
+        ])
+        .split(frame.size());
+
+    let snapshot = &dashboard.snapshot;
+    let labels = dashboard.wallet_labels.labels().clone();
+
+    // Header: P&L summary and trading state
+    let state = if snapshot.trading_paused {
+        Span::styled(" PAUSED ", Style::default().fg(Color::Black).bg(Color::Yellow))
+    } else {
+        Span::styled(" LIVE ", Style::default().fg(Color::Black).bg(Color::Green))
+    };
+    let summary = match &snapshot.summary {
+        Some(s) => format!(
+            " Open: {} | Closed: {} | P&L: ${:.4} | Fees: ${:.4} | Win Rate: {:.1}%",
+            s.open_positions, s.closed_positions, s.total_pnl, s.total_fees, s.win_rate * 100.0
+        ),
+        None => " P&L unavailable".to_string(),
+    };
+    let frozen = if dashboard.frozen { " [FROZEN]" } else { "" };
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![state, Span::raw(summary), Span::raw(frozen)]))
+            .block(pane("🦡 Badger")),
+        rows[0],
+    );
+
+    // Positions and signal feed
+    let top = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(rows[1]);
+
+    let now = Utc::now().timestamp();
+    let position_rows: Vec<Row> = snapshot.open_positions.iter().map(|p| {
+        Row::new(vec![
+            format!("#{}", p.id),
+            shorten_pubkey(&p.token_mint),
+            format!("{:.8}", p.entry_price),
+            format!("{:.2}", p.quantity),
+            format!("{}m", (now - p.entry_timestamp) / 60),
+            p.insider_wallet.as_deref().map(|wallet| labels.label(wallet)).unwrap_or_else(|| "-".to_string()),
+        ])
+    }).collect();
+    let positions = Table::new(position_rows, [
+        Constraint::Length(6),
+        Constraint::Length(14),
+        Constraint::Length(12),
+        Constraint::Length(14),
+        Constraint::Length(6),
+        Constraint::Min(10),
+    ])
+    .header(Row::new(vec!["ID", "Token", "Entry", "Qty", "Age", "Insider"]).style(Style::default().add_modifier(Modifier::BOLD)))
+    .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+    .block(pane("Open Positions"));
+    frame.render_stateful_widget(positions, top[0], &mut dashboard.selection);
+
+    let signals: Vec<ListItem> = snapshot.signals.iter().map(|s| {
+        ListItem::new(format!(
+            "{} {:<6} {} {:>5.1}% {}",
+            format_time(s.timestamp),
+            s.signal_type,
+            shorten_pubkey(&s.token_mint),
+            s.confidence.unwrap_or(0.0) * 100.0,
+            s.reason.as_deref().unwrap_or(""),
+        ))
+    }).collect();
+    frame.render_widget(List::new(signals).block(pane("Signal Feed")), top[1]);
+
+    // Insider activity and ingest rates
+    let middle = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+        .split(rows[2]);
+
+    let insiders: Vec<ListItem> = snapshot.insider_activity.iter().map(|a| {
+        ListItem::new(format!(
+            "{} {} {:<8} {} {:.2}",
+            format_time(a.timestamp),
+            labels.label(&a.wallet_address),
+            a.activity_type,
+            shorten_pubkey(&a.token_mint),
+            a.amount,
+        ))
+    }).collect();
+    frame.render_widget(List::new(insiders).block(pane("Insider Activity")), middle[0]);
+
+    let ingest: Vec<ListItem> = snapshot.ingest_counts.iter().map(|(event_type, count)| {
+        ListItem::new(format!("{:<20} {:>6}/min", event_type, count * 60 / INGEST_WINDOW_SECS))
+    }).collect();
+    frame.render_widget(List::new(ingest).block(pane("Ingest Rates")), middle[1]);
+
+    // Alerts
+    let alerts: Vec<ListItem> = snapshot.alerts.iter().map(|a| {
+        ListItem::new(format!("{} {:<20} {:<24} {}", format_time(a.created_at), a.alert_type, a.service, labels.annotate(&a.message)))
+    }).collect();
+    frame.render_widget(List::new(alerts).block(pane("Alerts")), rows[3]);
+
+    frame.render_widget(Paragraph::new(dashboard.status.as_str()), rows[4]);
+}
+
+async fn run(terminal: &mut Terminal<CrosstermBackend<Stdout>>, dashboard: &mut Dashboard) -> Result<()> {
+    let mut last_refresh: Option<Instant> = None;
+
+    loop {
+        if !dashboard.frozen && last_refresh.is_none_or(|t| t.elapsed() >= REFRESH_INTERVAL) {
+            if let Err(e) = dashboard.refresh().await {
+                dashboard.status = format!("Refresh failed: {}", e);
+            }
+            last_refresh = Some(Instant::now());
+        }
+
+        terminal.draw(|frame| draw(frame, dashboard))?;
+
+        if !event::poll(Duration::from_millis(200))? {
+            continue;
+        }
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            let result = match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Down | KeyCode::Char('j') => {
+                    dashboard.select_next(true);
+                    Ok(())
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    dashboard.select_next(false);
+                    Ok(())
+                }
+                KeyCode::Char('c') => dashboard.close_selected("all").await,
+                KeyCode::Char('h') => dashboard.close_selected("50%").await,
+                KeyCode::Char('p') => dashboard.toggle_pause().await,
+                KeyCode::Char('f') => {
+                    dashboard.frozen = !dashboard.frozen;
+                    Ok(())
+                }
+                _ => Ok(()),
+            };
+
+            if let Err(e) = result {
+                dashboard.status = format!("Action failed: {}", e);
+            }
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let database_url = std::env::args()
+        .nth(1)
+        .or_else(|| std::env::var("BADGER_DATABASE_URL").ok())
+        .unwrap_or_else(|| DEFAULT_DATABASE_URL.to_string());
+
+    let rt = tokio::runtime::Runtime::new()?;
+    rt.block_on(async {
+        let mut dashboard = Dashboard::new(&database_url).await?;
+
+        enable_raw_mode()?;
+        let mut stdout = std::io::stdout();
+        execute!(stdout, EnterAlternateScreen)?;
+        let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+
+        let result = run(&mut terminal, &mut dashboard).await;
+
+        // Always restore the terminal, even when the dashboard errored
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+
+        result
+    })
+}
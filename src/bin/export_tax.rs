@@ -0,0 +1,61 @@
+//! Exports the tracking-only tax ledger of one calendar year as CSV
+//!
+//! Every fill the bot made is mirrored into `tax_fills` and valued in the
+//! configured fiat currency at the SOL price when it executed. `form8949`
+//! lists disposals matched to their acquisitions with proceeds, cost basis
+//! and holding term; `fills` lists every acquisition and disposal for
+//! jurisdictions that compute gains from those themselves. The CSV goes to
+//! stdout; fills without a fiat value are counted on stderr.
+//!
+//! Usage: `badger-export-tax [--format form8949|fills] [--year YEAR] [database_url]`
+
+use anyhow::{Context, Result};
+use chrono::{Datelike, Utc};
+use std::sync::Arc;
+
+use badger::database::analytics::{TaxExportFormat, TaxLedger, TaxLedgerConfig};
+use badger::database::BadgerDatabase;
+
+const DEFAULT_DATABASE_URL: &str = "sqlite:data/badger.db";
+
+fn main() -> Result<()> {
+    let mut format = TaxExportFormat::Form8949;
+    let mut year = Utc::now().year();
+    let mut database_url = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                format = args.next()
+                    .context("--format requires form8949 or fills")?
+                    .parse()
+                    .map_err(anyhow::Error::msg)?;
+            }
+            "--year" => {
+                year = args.next()
+                    .context("--year requires a year")?
+                    .parse()
+                    .context("--year must be a number")?;
+            }
+            _ => database_url = Some(arg),
+        }
+    }
+    let database_url = database_url
+        .or_else(|| std::env::var("BADGER_DATABASE_URL").ok())
+        .unwrap_or_else(|| DEFAULT_DATABASE_URL.to_string());
+
+    let rt = tokio::runtime::Runtime::new()?;
+    rt.block_on(async {
+        let db = Arc::new(BadgerDatabase::attach(&database_url).await?);
+        let ledger = TaxLedger::new(db, Some(TaxLedgerConfig::from_env()));
+        ledger.initialize_schema().await?;
+
+        let (csv, unvalued) = ledger.export_csv(format, year).await?;
+        print!("{}", csv);
+        if unvalued > 0 {
+            eprintln!("{} fills of {} have no {} value yet and were left out or blank", unvalued, year, ledger.config().currency);
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,55 @@
+//! Recomputes realized P&L, fees and outcomes of all closed positions
+//!
+//! Rewrites closed positions with the current P&L calculation so earlier
+//! pricing or fee bugs stop skewing performance metrics. Previous values are
+//! kept per position in `pnl_recalc_entries`, tagged with the calculation
+//! version. Stop the bot first; the rewrite runs in one transaction.
+//!
+//! Usage: `badger-recompute-pnl [--dry-run] [--fee-rate RATE] [database_url]`
+
+use anyhow::{Context, Result};
+use std::sync::Arc;
+
+use badger::core::StrategyConfig;
+use badger::database::analytics::PnlRecalculator;
+use badger::database::BadgerDatabase;
+
+const DEFAULT_DATABASE_URL: &str = "sqlite:data/badger.db";
+
+fn main() -> Result<()> {
+    let mut dry_run = false;
+    let mut fee_rate = StrategyConfig::default().fee_rate;
+    let mut database_url = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--dry-run" => dry_run = true,
+            "--fee-rate" => {
+                fee_rate = args.next()
+                    .context("--fee-rate requires a rate")?
+                    .parse()
+                    .context("--fee-rate must be a number")?;
+            }
+            _ => database_url = Some(arg),
+        }
+    }
+    let database_url = database_url
+        .or_else(|| std::env::var("BADGER_DATABASE_URL").ok())
+        .unwrap_or_else(|| DEFAULT_DATABASE_URL.to_string());
+
+    let rt = tokio::runtime::Runtime::new()?;
+    rt.block_on(async {
+        let db = Arc::new(BadgerDatabase::attach(&database_url).await?);
+        let recalculator = PnlRecalculator::new(db, fee_rate);
+        recalculator.initialize_schema().await?;
+
+        let run = recalculator.recompute(dry_run).await?;
+        println!("{}Recalculation #{} (v{}, fee rate {}) of {} closed positions in {}",
+            if dry_run { "[dry run] " } else { "" }, run.id, run.version, run.fee_rate, run.positions, database_url);
+        println!("Changed: {} | Outcomes flipped: {}", run.changed, run.flipped_outcomes);
+        println!("Realized P&L: {:.4} SOL -> {:.4} SOL ({:+.4})",
+            run.pnl_before, run.pnl_after, run.pnl_after - run.pnl_before);
+        Ok(())
+    })
+}
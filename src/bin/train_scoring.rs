@@ -0,0 +1,56 @@
+//! Offline trainer for the logistic insider scoring model
+//!
+//! Fits `LogisticScoringModel` on closed copy-trade positions joined with the
+//! copied insider's profile and writes it as JSON. Point
+//! `BADGER_SCORING_MODEL` at the file to score insiders with it.
+//!
+//! Usage: `badger-train-scoring [output.json] [database_url]`
+
+use anyhow::Result;
+use std::path::Path;
+
+use badger::database::analytics::{scoring_training_set, LogisticScoringModel, ScoringModel, WeightedScoringModel};
+use badger::database::BadgerDatabase;
+
+const DEFAULT_DATABASE_URL: &str = "sqlite:data/badger.db";
+const DEFAULT_OUTPUT: &str = "config/scoring_model.json";
+const EPOCHS: usize = 2000;
+const LEARNING_RATE: f64 = 0.5;
+const L2: f64 = 0.01;
+/// Closed copy trades needed before a fit is worth using
+const MIN_SAMPLES: usize = 30;
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let output = args.next().unwrap_or_else(|| DEFAULT_OUTPUT.to_string());
+    let database_url = args.next()
+        .or_else(|| std::env::var("BADGER_DATABASE_URL").ok())
+        .unwrap_or_else(|| DEFAULT_DATABASE_URL.to_string());
+
+    let rt = tokio::runtime::Runtime::new()?;
+    rt.block_on(async {
+        let db = BadgerDatabase::attach(&database_url).await?;
+        let samples = scoring_training_set(&db).await?;
+        if samples.len() < MIN_SAMPLES {
+            anyhow::bail!("Only {} closed copy trades in {}; need {}", samples.len(), database_url, MIN_SAMPLES);
+        }
+
+        let model = LogisticScoringModel::train(&samples, EPOCHS, LEARNING_RATE, L2);
+
+        // Compare against the weighted formula on the same trades
+        let accuracy = |scorer: &dyn ScoringModel| {
+            samples.iter()
+                .filter(|(features, won)| (scorer.score(features).copy_worthiness >= 50.0) == *won)
+                .count() as f64 / samples.len() as f64
+        };
+        println!("Trained on {} copy trades ({:.1}% winners)", samples.len(),
+            samples.iter().filter(|(_, won)| *won).count() as f64 / samples.len() as f64 * 100.0);
+        println!("In-sample accuracy: logistic {:.1}% | weighted {:.1}%",
+            accuracy(&model) * 100.0, accuracy(&WeightedScoringModel) * 100.0);
+        println!("Intercept: {:.4} | Weights: {:?}", model.intercept, model.weights);
+
+        model.save(Path::new(&output))?;
+        println!("Wrote {}", output);
+        Ok(())
+    })
+}
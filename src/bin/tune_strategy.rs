@@ -0,0 +1,185 @@
+//! Offline grid search over strategy entry parameters
+//!
+//! Backtests every combination of pool liquidity threshold, bonding curve
+//! entry band and youngest size-ladder rung against recorded market events
+//! and writes the combinations ranked by P&L. Copy the winning values into a
+//! strategy file to use them.
+//!
+//! Usage: `badger-tune-strategy [report.json] [events.json]`
+//!
+//! Without an events file the events recorded in `market_events` are used.
+
+use anyhow::Result;
+use serde::Serialize;
+
+use badger::core::backtest::{run_backtest, BacktestResult};
+use badger::core::{SizeRung, StrategyConfig};
+use badger::database::{BadgerDatabase, ReplayInput};
+
+const DEFAULT_DATABASE_URL: &str = "sqlite:data/badger.db";
+const DEFAULT_OUTPUT: &str = "data/strategy_grid.json";
+/// Combinations with fewer trades are ranked last; their P&L is mostly noise
+const MIN_TRADES: usize = 10;
+/// Ranked combinations printed to the terminal
+const SHOW_TOP: usize = 10;
+
+const MIN_POOL_LIQUIDITY_SOL: &[f64] = &[1.0, 5.0, 10.0, 25.0];
+const BONDING_CURVE_MIN_PROGRESS: &[f64] = &[0.1, 0.2, 0.3, 0.4, 0.5];
+const BONDING_CURVE_MAX_PROGRESS: &[f64] = &[0.5, 0.7, 0.9];
+const YOUNGEST_RUNG_AGE_SECS: &[i64] = &[60, 300, 900];
+
+/// Parameters varied by the grid
+#[derive(Debug, Clone, Serialize)]
+struct GridPoint {
+    min_pool_liquidity_sol: f64,
+    bonding_curve_min_progress: f64,
+    bonding_curve_max_progress: f64,
+    youngest_rung_age_secs: i64,
+}
+
+impl GridPoint {
+    fn apply(&self, base: &StrategyConfig) -> StrategyConfig {
+        let mut config = base.clone();
+        config.min_pool_liquidity_sol = self.min_pool_liquidity_sol;
+        config.bonding_curve_min_progress = self.bonding_curve_min_progress;
+        config.bonding_curve_max_progress = self.bonding_curve_max_progress;
+        match config.size_ladder.first_mut() {
+            Some(rung) => rung.max_token_age_secs = self.youngest_rung_age_secs,
+            None => config.size_ladder.push(SizeRung {
+                max_token_age_secs: self.youngest_rung_age_secs,
+                max_sol: config.bonding_curve_max_sol,
+            }),
+        }
+        config
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct RankedPoint {
+    rank: usize,
+    parameters: GridPoint,
+    trades: usize,
+    unfilled: usize,
+    total_pnl_sol: f64,
+    return_on_capital: f64,
+    win_rate: f64,
+    max_drawdown_sol: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct GridReport {
+    input_source: String,
+    input_events: usize,
+    input_digest: String,
+    base_config: StrategyConfig,
+    min_trades: usize,
+    combinations: usize,
+    ranked: Vec<RankedPoint>,
+}
+
+fn grid() -> Vec<GridPoint> {
+    let mut points = Vec::new();
+    for &min_pool_liquidity_sol in MIN_POOL_LIQUIDITY_SOL {
+        for &bonding_curve_min_progress in BONDING_CURVE_MIN_PROGRESS {
+            for &bonding_curve_max_progress in BONDING_CURVE_MAX_PROGRESS {
+                if bonding_curve_min_progress >= bonding_curve_max_progress {
+                    continue;
+                }
+                for &youngest_rung_age_secs in YOUNGEST_RUNG_AGE_SECS {
+                    points.push(GridPoint {
+                        min_pool_liquidity_sol,
+                        bonding_curve_min_progress,
+                        bonding_curve_max_progress,
+                        youngest_rung_age_secs,
+                    });
+                }
+            }
+        }
+    }
+    points
+}
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let output = args.next().unwrap_or_else(|| DEFAULT_OUTPUT.to_string());
+    let events_path = args.next();
+    let database_url = std::env::var("BADGER_DATABASE_URL").unwrap_or_else(|_| DEFAULT_DATABASE_URL.to_string());
+
+    let rt = tokio::runtime::Runtime::new()?;
+    let input = rt.block_on(async {
+        match &events_path {
+            Some(path) => Ok::<_, anyhow::Error>(ReplayInput::load(Some(path))?),
+            None => {
+                let db = BadgerDatabase::attach(&database_url).await?;
+                Ok(ReplayInput::from_database(&db).await?)
+            }
+        }
+    })?;
+    if input.events.is_empty() {
+        anyhow::bail!("No market events in {}", input.source);
+    }
+
+    let base = StrategyConfig::default();
+    let points = grid();
+    println!("Backtesting {} combinations over {} events from {}", points.len(), input.events.len(), input.source);
+
+    let mut results: Vec<(GridPoint, BacktestResult)> = points.into_iter()
+        .map(|point| {
+            let result = run_backtest(&point.apply(&base), &input.events);
+            (point, result)
+        })
+        .collect();
+    let combinations = results.len();
+
+    // Enough trades first, then P&L, then the shallower drawdown
+    results.sort_by(|(_, a), (_, b)| {
+        (b.trades.len() >= MIN_TRADES).cmp(&(a.trades.len() >= MIN_TRADES))
+            .then(b.total_pnl_sol.total_cmp(&a.total_pnl_sol))
+            .then(a.max_drawdown_sol.total_cmp(&b.max_drawdown_sol))
+    });
+
+    let ranked: Vec<RankedPoint> = results.into_iter()
+        .enumerate()
+        .map(|(i, (parameters, result))| RankedPoint {
+            rank: i + 1,
+            parameters,
+            trades: result.trades.len(),
+            unfilled: result.unfilled,
+            total_pnl_sol: result.total_pnl_sol,
+            return_on_capital: result.return_on_capital(),
+            win_rate: result.win_rate,
+            max_drawdown_sol: result.max_drawdown_sol,
+        })
+        .collect();
+
+    println!("{:>4} {:>8} {:>6} {:>6} {:>6} {:>7} {:>11} {:>7} {:>9}",
+        "rank", "pool SOL", "min %", "max %", "rung s", "trades", "P&L SOL", "win %", "max DD");
+    for point in ranked.iter().take(SHOW_TOP) {
+        println!("{:>4} {:>8.1} {:>6.0} {:>6.0} {:>6} {:>7} {:>11.4} {:>7.1} {:>9.4}",
+            point.rank,
+            point.parameters.min_pool_liquidity_sol,
+            point.parameters.bonding_curve_min_progress * 100.0,
+            point.parameters.bonding_curve_max_progress * 100.0,
+            point.parameters.youngest_rung_age_secs,
+            point.trades,
+            point.total_pnl_sol,
+            point.win_rate * 100.0,
+            point.max_drawdown_sol);
+    }
+
+    let report = GridReport {
+        input_source: input.source,
+        input_events: input.events.len(),
+        input_digest: input.digest,
+        base_config: base,
+        min_trades: MIN_TRADES,
+        combinations,
+        ranked,
+    };
+    if let Some(parent) = std::path::Path::new(&output).parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&output, serde_json::to_string_pretty(&report)?)?;
+    println!("Wrote {}", output);
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use super::dex_types::{MarketEvent, SwapType, TradingSignal};
+use super::signal_rules::generate_basic_trading_signal;
+use super::types::StrategyConfig;
+
+/// Approximate Solana slot time, used to age tokens in recorded events
+const SLOT_DURATION_MS: u64 = 400;
+
+/// One simulated entry, held to the last recorded price
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestTrade {
+    pub token_mint: String,
+    pub entry_slot: u64,
+    /// SOL per token (6-decimal tokens)
+    pub entry_price: f64,
+    pub exit_price: f64,
+    pub size_sol: f64,
+    /// Net of entry and exit fees
+    pub pnl_sol: f64,
+}
+
+/// Outcome of running a strategy configuration over recorded events
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BacktestResult {
+    /// Buy signals generated (one per token)
+    pub signals: usize,
+    /// Signals for tokens that never had a price afterwards
+    pub unfilled: usize,
+    pub trades: Vec<BacktestTrade>,
+    pub total_pnl_sol: f64,
+    pub capital_sol: f64,
+    pub win_rate: f64,
+    /// Largest peak-to-trough drop of cumulative P&L, trades in entry order (SOL)
+    pub max_drawdown_sol: f64,
+}
+
+impl BacktestResult {
+    /// P&L per SOL deployed
+    pub fn return_on_capital(&self) -> f64 {
+        if self.capital_sol > 0.0 { self.total_pnl_sol / self.capital_sol } else { 0.0 }
+    }
+}
+
+/// Price a market event reveals for a token, in SOL per token
+///
+/// Swaps assume 6-decimal tokens, as bonding curve prices do.
+fn observed_price(event: &MarketEvent) -> Option<(String, f64)> {
+    match event {
+        MarketEvent::BondingCurveUpdated { curve, .. } => {
+            let price = curve.price_sol();
+            curve.token_mint.clone().filter(|_| price > 0.0).map(|mint| (mint, price))
+        }
+        MarketEvent::SwapDetected { swap } => {
+            let (mint, lamports, tokens) = match swap.swap_type {
+                SwapType::Buy => (&swap.token_out, swap.amount_in, swap.amount_out),
+                SwapType::Sell => (&swap.token_in, swap.amount_out, swap.amount_in),
+            };
+            (lamports > 0 && tokens > 0).then(|| {
+                (mint.clone(), (lamports as f64 / 1_000_000_000.0) / (tokens as f64 / 1_000_000.0))
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Replay recorded events through the entry rules of `config`
+///
+/// Each token is entered once, at the first buy signal, sized like live
+/// trading (signal size capped by the token-age ladder). Entries fill at the
+/// price the signal's event reveals, or at the next recorded price for the
+/// token; every position is marked out at the token's last recorded price.
+/// Events must be in chain order.
+pub fn run_backtest(config: &StrategyConfig, events: &[MarketEvent]) -> BacktestResult {
+    let mut first_slot: HashMap<String, u64> = HashMap::new();
+    let mut last_price: HashMap<String, f64> = HashMap::new();
+    let mut migrated: HashSet<String> = HashSet::new();
+    let mut pending: HashMap<String, f64> = HashMap::new();
+    let mut open: Vec<(String, u64, f64, f64)> = Vec::new();
+    let mut entered: HashSet<String> = HashSet::new();
+    let mut result = BacktestResult::default();
+
+    for event in events {
+        let slot = event.get_slot().unwrap_or(0).max(0) as u64;
+        let price = observed_price(event);
+
+        if let MarketEvent::BondingCurveUpdated { curve, .. } = event {
+            if let Some(mint) = &curve.token_mint {
+                first_slot.entry(mint.clone()).or_insert(curve.slot);
+                if curve.complete {
+                    migrated.insert(mint.clone());
+                }
+            }
+        }
+        if let Some((mint, price)) = &price {
+            first_slot.entry(mint.clone()).or_insert(slot);
+            last_price.insert(mint.clone(), *price);
+            if let Some(size_sol) = pending.remove(mint) {
+                open.push((mint.clone(), slot, *price, size_sol));
+            }
+        }
+
+        let Some(TradingSignal::Buy { token_mint, max_amount_sol, .. }) = generate_basic_trading_signal(event, config) else {
+            continue;
+        };
+        if !entered.insert(token_mint.clone()) {
+            continue;
+        }
+        result.signals += 1;
+
+        let first = *first_slot.entry(token_mint.clone()).or_insert(slot);
+        let token_age_secs = (slot.saturating_sub(first) * SLOT_DURATION_MS / 1000) as i64;
+        let size_sol = config.ladder_max_sol(Some(token_age_secs), migrated.contains(&token_mint))
+            .map_or(max_amount_sol, |cap| cap.min(max_amount_sol));
+
+        match last_price.get(&token_mint) {
+            Some(price) => open.push((token_mint, slot, *price, size_sol)),
+            None => {
+                pending.insert(token_mint, size_sol);
+            }
+        }
+    }
+    result.unfilled = pending.len();
+
+    let mut cumulative = 0.0_f64;
+    let mut peak = 0.0_f64;
+    for (token_mint, entry_slot, entry_price, size_sol) in open {
+        let exit_price = last_price.get(&token_mint).copied().unwrap_or(entry_price);
+        let pnl_sol = size_sol * (exit_price / entry_price - 1.0) - 2.0 * config.fee_rate * size_sol;
+
+        cumulative += pnl_sol;
+        peak = peak.max(cumulative);
+        result.max_drawdown_sol = result.max_drawdown_sol.max(peak - cumulative);
+        result.total_pnl_sol += pnl_sol;
+        result.capital_sol += size_sol;
+        result.trades.push(BacktestTrade {
+            token_mint,
+            entry_slot,
+            entry_price,
+            exit_price,
+            size_sol,
+            pnl_sol,
+        });
+    }
+    if !result.trades.is_empty() {
+        result.win_rate = result.trades.iter().filter(|t| t.pnl_sol > 0.0).count() as f64 / result.trades.len() as f64;
+    }
+
+    result
+}
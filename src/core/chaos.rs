@@ -0,0 +1,102 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use super::rng;
+
+/// Whether this build can inject faults (`--features chaos`)
+///
+/// Without the feature every hook is a constant no-op.
+pub const AVAILABLE: bool = cfg!(feature = "chaos");
+
+/// Faults injected into a chaos run
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ChaosConfig {
+    /// Share of WebSocket messages dropped (0.0 - 1.0)
+    pub ws_drop_rate: f64,
+    /// Added before every position write (milliseconds)
+    pub db_write_delay_ms: u64,
+    /// Share of transaction sends that fail (0.0 - 1.0)
+    pub rpc_fail_rate: f64,
+    /// Drop the connection after this many messages; the last message is delivered again after reconnecting
+    pub reconnect_every: Option<u64>,
+}
+
+impl ChaosConfig {
+    /// Named fault profiles run by `scripts/chaos.sh`
+    pub const PROFILES: &'static [&'static str] = &["ws-drop", "db-delay", "rpc-fail", "reconnect", "all"];
+
+    pub fn profile(name: &str) -> Option<Self> {
+        let ws_drop = Self { ws_drop_rate: 0.2, ..Self::default() };
+        let db_delay = Self { db_write_delay_ms: 250, ..Self::default() };
+        let rpc_fail = Self { rpc_fail_rate: 0.3, ..Self::default() };
+        let reconnect = Self { reconnect_every: Some(2), ..Self::default() };
+
+        match name {
+            "ws-drop" => Some(ws_drop),
+            "db-delay" => Some(db_delay),
+            "rpc-fail" => Some(rpc_fail),
+            "reconnect" => Some(reconnect),
+            "all" => Some(Self {
+                ws_drop_rate: ws_drop.ws_drop_rate,
+                db_write_delay_ms: db_delay.db_write_delay_ms,
+                rpc_fail_rate: rpc_fail.rpc_fail_rate,
+                reconnect_every: reconnect.reconnect_every,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Faults in effect; `None` outside chaos runs
+static CHAOS: Mutex<Option<ChaosConfig>> = Mutex::new(None);
+
+/// Messages seen since the last forced reconnect
+static MESSAGES_SINCE_RECONNECT: AtomicU64 = AtomicU64::new(0);
+
+/// Start injecting `config`'s faults; ignored unless built with the `chaos` feature
+pub fn enable(config: ChaosConfig) {
+    *CHAOS.lock().unwrap() = Some(config);
+}
+
+fn config() -> Option<ChaosConfig> {
+    if !AVAILABLE {
+        return None;
+    }
+    *CHAOS.lock().unwrap()
+}
+
+/// Draw against `rate` from the (seedable) process RNG
+fn roll(rate: f64) -> bool {
+    rate > 0.0 && (rng::random_u32() as f64 / u32::MAX as f64) < rate
+}
+
+/// Whether to drop the WebSocket message being handled
+pub fn drop_ws_message() -> bool {
+    config().is_some_and(|chaos| roll(chaos.ws_drop_rate))
+}
+
+/// Whether the transaction send being made fails
+pub fn fail_rpc_send() -> bool {
+    config().is_some_and(|chaos| roll(chaos.rpc_fail_rate))
+}
+
+/// Stall a position write by the configured delay
+pub async fn delay_db_write() {
+    if let Some(chaos) = config().filter(|chaos| chaos.db_write_delay_ms > 0) {
+        tokio::time::sleep(Duration::from_millis(chaos.db_write_delay_ms)).await;
+    }
+}
+
+/// Count a received message; true when the connection should be dropped now
+pub fn reconnect_due() -> bool {
+    let Some(every) = config().and_then(|chaos| chaos.reconnect_every) else {
+        return false;
+    };
+    if MESSAGES_SINCE_RECONNECT.fetch_add(1, Ordering::SeqCst) + 1 >= every {
+        MESSAGES_SINCE_RECONNECT.store(0, Ordering::SeqCst);
+        true
+    } else {
+        false
+    }
+}
@@ -36,6 +36,44 @@ impl DexType {
     }
 }
 
+/// Bonding curve launchpads whose curve accounts are decoded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Launchpad {
+    #[default]
+    PumpFun,
+    /// Raydium LaunchLab, the program behind Bonk.fun / LetsBonk launches
+    LaunchLab,
+    Moonshot,
+}
+
+impl Launchpad {
+    pub fn from_program_id(program_id: &str) -> Option<Self> {
+        match program_id {
+            constants::PUMP_FUN_PROGRAM => Some(Launchpad::PumpFun),
+            constants::LAUNCHLAB_PROGRAM => Some(Launchpad::LaunchLab),
+            constants::MOONSHOT_PROGRAM => Some(Launchpad::Moonshot),
+            _ => None,
+        }
+    }
+
+    pub fn program_id(&self) -> &'static str {
+        match self {
+            Launchpad::PumpFun => constants::PUMP_FUN_PROGRAM,
+            Launchpad::LaunchLab => constants::LAUNCHLAB_PROGRAM,
+            Launchpad::Moonshot => constants::MOONSHOT_PROGRAM,
+        }
+    }
+
+    /// Display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Launchpad::PumpFun => "Pump.fun",
+            Launchpad::LaunchLab => "Bonk.fun",
+            Launchpad::Moonshot => "Moonshot",
+        }
+    }
+}
+
 /// Comprehensive pool information from DEX events
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoolInfo {
@@ -104,6 +142,104 @@ pub struct LargeTransfer {
     pub timestamp: DateTime<Utc>,
 }
 
+/// Launchpad bonding curve account state, in pump.fun terms
+///
+/// Token reserves count tokens still for sale on the curve and virtual
+/// reserves are the current constant-product reserves, whatever the
+/// launchpad's own account layout.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BondingCurveState {
+    pub curve_address: String,
+    /// Token mint, when known (pump.fun curve accounts do not store it)
+    pub token_mint: Option<String>,
+    #[serde(default)]
+    pub launchpad: Launchpad,
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub token_total_supply: u64,
+    /// Tokens for sale on the curve at launch; the curve graduates when they are sold
+    #[serde(default = "default_curve_token_reserves")]
+    pub initial_real_token_reserves: u64,
+    #[serde(default = "default_curve_token_decimals")]
+    pub token_decimals: u8,
+    pub complete: bool,
+    pub slot: u64,
+}
+
+fn default_curve_token_reserves() -> u64 {
+    constants::PUMP_FUN_INITIAL_REAL_TOKEN_RESERVES
+}
+
+fn default_curve_token_decimals() -> u8 {
+    6
+}
+
+impl BondingCurveState {
+    /// Fraction of the curve filled (0.0 - 1.0); graduation happens at 1.0
+    pub fn progress(&self) -> f64 {
+        if self.complete {
+            return 1.0;
+        }
+        if self.initial_real_token_reserves == 0 {
+            return 0.0;
+        }
+        let remaining = self.real_token_reserves as f64 / self.initial_real_token_reserves as f64;
+        (1.0 - remaining).clamp(0.0, 1.0)
+    }
+
+    /// SOL raised so far
+    pub fn sol_raised(&self) -> f64 {
+        self.real_sol_reserves as f64 / 1_000_000_000.0
+    }
+
+    /// Current curve price in SOL per token; 0.0 when the launchpad exposes no reserves
+    pub fn price_sol(&self) -> f64 {
+        if self.virtual_token_reserves == 0 {
+            return 0.0;
+        }
+        (self.virtual_sol_reserves as f64 / 1_000_000_000.0)
+            / (self.virtual_token_reserves as f64 / 10_f64.powi(self.token_decimals as i32))
+    }
+}
+
+/// Balance of one of a token's largest holders
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HolderBalance {
+    pub token_account: String,
+    /// Wallet owning the token account, when it could be resolved
+    pub owner: Option<String>,
+    pub amount: u64,
+    /// Fraction of circulating supply (excluding liquidity pools)
+    pub share: f64,
+    /// Account belongs to a pool or bonding curve rather than a trader
+    pub is_pool: bool,
+}
+
+/// Top-holder distribution of a token at a slot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HolderDistribution {
+    pub token_mint: String,
+    pub slot: u64,
+    pub supply: u64,
+    /// Supply held outside liquidity pools
+    pub circulating: u64,
+    /// Largest holders, biggest first (at most 20, as returned by `getTokenLargestAccounts`)
+    pub holders: Vec<HolderBalance>,
+}
+
+impl HolderDistribution {
+    /// Share of circulating supply held by the largest `n` non-pool holders
+    pub fn top_share(&self, n: usize) -> f64 {
+        self.holders.iter()
+            .filter(|h| !h.is_pool)
+            .take(n)
+            .map(|h| h.share)
+            .sum()
+    }
+}
+
 /// Market events emitted by the ingestion service
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MarketEvent {
@@ -136,6 +272,12 @@ pub enum MarketEvent {
     LargeTransferDetected {
         transfer: LargeTransfer,
     },
+    /// Launchpad bonding curve state changed
+    BondingCurveUpdated {
+        curve: BondingCurveState,
+        /// Progress at the previous observation of this curve, if any
+        previous_progress: Option<f64>,
+    },
 }
 
 impl MarketEvent {
@@ -160,6 +302,9 @@ impl MarketEvent {
             MarketEvent::LargeTransferDetected { transfer } => {
                 format!("transfer_{}", transfer.signature)
             },
+            MarketEvent::BondingCurveUpdated { curve, .. } => {
+                format!("bonding_curve_{}_{}", curve.curve_address, curve.slot)
+            },
         }
     }
 
@@ -172,6 +317,7 @@ impl MarketEvent {
             MarketEvent::LiquidityChanged { .. } => "liquidity_changed".to_string(),
             MarketEvent::SwapDetected { .. } => "swap_detected".to_string(),
             MarketEvent::LargeTransferDetected { .. } => "large_transfer".to_string(),
+            MarketEvent::BondingCurveUpdated { .. } => "bonding_curve_updated".to_string(),
         }
     }
 
@@ -184,6 +330,7 @@ impl MarketEvent {
             MarketEvent::LiquidityChanged { .. } => chrono::Utc::now().timestamp(),
             MarketEvent::SwapDetected { swap } => swap.timestamp.timestamp(),
             MarketEvent::LargeTransferDetected { transfer } => transfer.timestamp.timestamp(),
+            MarketEvent::BondingCurveUpdated { .. } => chrono::Utc::now().timestamp(),
         }
     }
 
@@ -196,6 +343,7 @@ impl MarketEvent {
             MarketEvent::LiquidityChanged { .. } => None,
             MarketEvent::SwapDetected { swap } => Some(swap.slot as i64),
             MarketEvent::LargeTransferDetected { transfer } => Some(transfer.slot as i64),
+            MarketEvent::BondingCurveUpdated { curve, .. } => Some(curve.slot as i64),
         }
     }
 }
@@ -210,6 +358,9 @@ pub enum TradingSignal {
         max_amount_sol: f64,
         reason: String,
         source: SignalSource,
+        /// Insider whose buy is being copied, for `SignalSource::InsiderWallet` signals
+        #[serde(default)]
+        insider_wallet: Option<String>,
     },
     /// Sell signal with targets
     Sell {
@@ -224,6 +375,23 @@ pub enum TradingSignal {
         volume_increase: f64,
         whale_activity: bool,
     },
+    /// Hedge recommendation against aggregate book exposure
+    Hedge {
+        instrument: String,
+        direction: HedgeDirection,
+        size_sol: f64,
+        exposure_sol: f64,
+        reason: String,
+    },
+}
+
+/// Direction of a hedge adjustment
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum HedgeDirection {
+    /// Open or increase a short
+    Short,
+    /// Reduce an existing short
+    Cover,
 }
 
 impl TradingSignal {
@@ -239,6 +407,9 @@ impl TradingSignal {
             TradingSignal::SwapActivity { token_mint, .. } => {
                 format!("swap_{}_{}", token_mint, chrono::Utc::now().timestamp_nanos())
             },
+            TradingSignal::Hedge { instrument, .. } => {
+                format!("hedge_{}_{}", instrument, chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default())
+            },
         }
     }
 
@@ -248,6 +419,7 @@ impl TradingSignal {
             TradingSignal::Buy { .. } => "buy".to_string(),
             TradingSignal::Sell { .. } => "sell".to_string(),
             TradingSignal::SwapActivity { .. } => "swap_activity".to_string(),
+            TradingSignal::Hedge { .. } => "hedge".to_string(),
         }
     }
 
@@ -262,6 +434,7 @@ impl TradingSignal {
             TradingSignal::Buy { confidence, .. } => *confidence,
             TradingSignal::Sell { .. } => 0.8, // Default confidence for sell signals
             TradingSignal::SwapActivity { .. } => 0.6, // Default confidence for activity signals
+            TradingSignal::Hedge { .. } => 1.0, // Hedges are derived from the book, not predicted
         }
     }
 
@@ -271,6 +444,7 @@ impl TradingSignal {
             TradingSignal::Buy { token_mint, .. } => token_mint.clone(),
             TradingSignal::Sell { token_mint, .. } => token_mint.clone(),
             TradingSignal::SwapActivity { token_mint, .. } => token_mint.clone(),
+            TradingSignal::Hedge { instrument, .. } => instrument.clone(),
         }
     }
 
@@ -280,6 +454,15 @@ impl TradingSignal {
             TradingSignal::Buy { source, .. } => *source,
             TradingSignal::Sell { .. } => SignalSource::NewPool, // Default source for sell signals
             TradingSignal::SwapActivity { .. } => SignalSource::VolumeSpike, // Default source for activity signals
+            TradingSignal::Hedge { .. } => SignalSource::RiskManagement,
+        }
+    }
+
+    /// Get the copied insider of a copy-trade buy
+    pub fn get_insider_wallet(&self) -> Option<String> {
+        match self {
+            TradingSignal::Buy { source: SignalSource::InsiderWallet, insider_wallet, .. } => insider_wallet.clone(),
+            _ => None,
         }
     }
 }
@@ -290,6 +473,13 @@ pub enum SignalSource {
     InsiderWallet,
     VolumeSpike,
     LiquidityAdd,
+    RiskManagement,
+    /// Submitted by an authenticated external system
+    External,
+    /// Released from the working-order book
+    WorkingOrder,
+    /// Dedicated launch sniper; the only source allowed to buy in a token's first slots
+    Sniper,
 }
 
 /// Constants for DEX program IDs and common tokens
@@ -300,6 +490,20 @@ pub mod constants {
     pub const SPL_TOKEN_PROGRAM: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
     pub const PUMP_FUN_PROGRAM: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
     
+    // Pump.fun bonding curve
+    pub const PUMP_FUN_BONDING_CURVE_DISCRIMINATOR: [u8; 8] = [0x17, 0xb7, 0xf8, 0x37, 0x60, 0xd8, 0xac, 0x60];
+    pub const PUMP_FUN_INITIAL_REAL_TOKEN_RESERVES: u64 = 793_100_000_000_000; // 793.1M tokens (6 decimals)
+    pub const PUMP_FUN_BONDING_CURVE_SEED: &[u8] = b"bonding-curve";
+
+    // Raydium LaunchLab (Bonk.fun / LetsBonk) pool state
+    pub const LAUNCHLAB_PROGRAM: &str = "LanMV9sAd7wArD4vJFi2qDdfnVhFxYSUg6eKjVTHiPBE";
+    pub const LAUNCHLAB_POOL_STATE_DISCRIMINATOR: [u8; 8] = [0xf7, 0xed, 0xe3, 0xf5, 0xd7, 0xc3, 0xde, 0x46];
+
+    // Moonshot curve account
+    pub const MOONSHOT_PROGRAM: &str = "MoonCVVNZFSYkqNXP6bxHLPL6QQJiMagDL3qcqUQTrG";
+    pub const MOONSHOT_CURVE_ACCOUNT_DISCRIMINATOR: [u8; 8] = [0x08, 0x5b, 0x53, 0x1c, 0x84, 0xd8, 0xf8, 0x16];
+    pub const MOONSHOT_CURVE_SUPPLY_BPS: u64 = 8_000; // 80% of supply is sold on the curve
+    
     // Common tokens
     pub const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
     pub const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
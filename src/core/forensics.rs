@@ -0,0 +1,113 @@
+use chrono::Utc;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+/// Directory trace files are written to, one `<mint>.jsonl` per traced mint
+pub const TRACE_DIR: &str = "data/traces";
+
+/// Open trace files by token mint
+static TRACES: Mutex<BTreeMap<String, BufWriter<File>>> = Mutex::new(BTreeMap::new());
+
+/// Fast path so untraced mints never take the lock
+static ANY_TRACED: AtomicBool = AtomicBool::new(false);
+
+/// Path of the trace file for a mint
+pub fn trace_path(token_mint: &str) -> PathBuf {
+    PathBuf::from(TRACE_DIR).join(format!("{}.jsonl", token_mint))
+}
+
+/// Start recording every decision about `token_mint` to its trace file
+///
+/// The file is appended to, so a mint traced twice keeps its earlier history.
+pub fn enable(token_mint: &str) -> std::io::Result<PathBuf> {
+    let path = trace_path(token_mint);
+    std::fs::create_dir_all(TRACE_DIR)?;
+    let file = OpenOptions::new().create(true).append(true).open(&path)?;
+
+    let mut traces = TRACES.lock().unwrap();
+    traces.insert(token_mint.to_string(), BufWriter::new(file));
+    ANY_TRACED.store(true, Ordering::SeqCst);
+    drop(traces);
+
+    trace(token_mint, "trace_enabled", Value::Null);
+    Ok(path)
+}
+
+/// Stop tracing `token_mint`; returns false if it was not traced
+pub fn disable(token_mint: &str) -> bool {
+    trace(token_mint, "trace_disabled", Value::Null);
+
+    let mut traces = TRACES.lock().unwrap();
+    let removed = match traces.remove(token_mint) {
+        Some(mut writer) => {
+            let _ = writer.flush();
+            true
+        }
+        None => false,
+    };
+    ANY_TRACED.store(!traces.is_empty(), Ordering::SeqCst);
+    removed
+}
+
+/// Whether any mint is traced
+pub fn active() -> bool {
+    ANY_TRACED.load(Ordering::Relaxed)
+}
+
+pub fn is_traced(token_mint: &str) -> bool {
+    active() && TRACES.lock().unwrap().contains_key(token_mint)
+}
+
+/// Mints currently traced
+pub fn traced_mints() -> Vec<String> {
+    if !active() {
+        return Vec::new();
+    }
+    TRACES.lock().unwrap().keys().cloned().collect()
+}
+
+/// Append one trace record for `token_mint`; a no-op unless the mint is traced
+///
+/// # Arguments
+/// * `stage` - Where in the pipeline the record comes from (e.g. `entry_gate`, `risk_check`)
+/// * `detail` - Full state at that point; recorded as-is
+pub fn trace(token_mint: &str, stage: &str, detail: Value) {
+    if !active() {
+        return;
+    }
+
+    let mut traces = TRACES.lock().unwrap();
+    let Some(writer) = traces.get_mut(token_mint) else {
+        return;
+    };
+
+    let record = serde_json::json!({
+        "timestamp_ms": Utc::now().timestamp_millis(),
+        "token_mint": token_mint,
+        "stage": stage,
+        "detail": detail,
+    });
+    // Flushed per record so a crash never loses the trail leading up to it
+    let _ = writeln!(writer, "{}", record).and_then(|_| writer.flush());
+}
+
+/// Record `detail` for every traced mint it mentions
+///
+/// For events that reference several mints (pools, swaps), without having to
+/// know where each variant keeps them.
+pub fn trace_mentions(stage: &str, detail: &Value) {
+    if !active() {
+        return;
+    }
+    let text = detail.to_string();
+    for token_mint in traced_mints() {
+        if text.contains(&token_mint) {
+            trace(&token_mint, stage, detail.clone());
+        }
+    }
+}
@@ -1,7 +1,16 @@
 pub mod types;
 pub mod constants;
 pub mod dex_types;
+pub mod rng;
+pub mod forensics;
+pub mod chaos;
+pub mod signal_rules;
+pub mod backtest;
+pub mod program_registry;
+pub mod warmup;
 
 pub use types::*;
 pub use constants::*;
-pub use dex_types::*;
\ No newline at end of file
+pub use dex_types::*;
+pub use signal_rules::generate_basic_trading_signal;
+pub use warmup::{StrategyWarmup, WarmupConfig, DataPrerequisite, StrategyReadiness};
@@ -0,0 +1,153 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use super::constants::{
+    JUPITER_PROGRAM_ID, METEORA_DLMM_PROGRAM_ID, METEORA_POOLS_PROGRAM_ID, ORCA_PROGRAM_ID,
+    RAYDIUM_CPMM_PROGRAM_ID, RAYDIUM_PROGRAM_ID,
+};
+use super::dex_types::constants::{LAUNCHLAB_PROGRAM, MOONSHOT_PROGRAM, PUMP_FUN_PROGRAM, SPL_TOKEN_PROGRAM};
+
+/// What a program does, for parsers and analytics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ProgramKind {
+    /// Pools holding both sides; deposits and withdrawals are liquidity changes
+    Amm,
+    /// Bonding curve launchpad
+    Launchpad,
+    /// Routes swaps through other venues
+    Aggregator,
+    Lending,
+    /// Token, metadata and other plumbing programs
+    Infrastructure,
+}
+
+impl ProgramKind {
+    pub const ALL: [ProgramKind; 5] = [
+        ProgramKind::Amm,
+        ProgramKind::Launchpad,
+        ProgramKind::Aggregator,
+        ProgramKind::Lending,
+        ProgramKind::Infrastructure,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProgramKind::Amm => "AMM",
+            ProgramKind::Launchpad => "LAUNCHPAD",
+            ProgramKind::Aggregator => "AGGREGATOR",
+            ProgramKind::Lending => "LENDING",
+            ProgramKind::Infrastructure => "INFRASTRUCTURE",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str().eq_ignore_ascii_case(value.trim()))
+    }
+}
+
+/// Venue and kind of a known program
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProgramInfo {
+    pub program_id: String,
+    pub venue: String,
+    pub kind: ProgramKind,
+}
+
+/// Program seen without a registry entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnknownProgram {
+    pub program_id: String,
+    /// Sightings since the counts were last taken
+    pub seen_count: u64,
+    pub first_seen: i64,
+    pub last_seen: i64,
+}
+
+/// Programs classified out of the box
+const BUILTIN_PROGRAMS: &[(&str, &str, ProgramKind)] = &[
+    (RAYDIUM_PROGRAM_ID, "Raydium AMM", ProgramKind::Amm),
+    (RAYDIUM_CPMM_PROGRAM_ID, "Raydium CPMM", ProgramKind::Amm),
+    (ORCA_PROGRAM_ID, "Orca Whirlpool", ProgramKind::Amm),
+    (METEORA_DLMM_PROGRAM_ID, "Meteora DLMM", ProgramKind::Amm),
+    (METEORA_POOLS_PROGRAM_ID, "Meteora Pools", ProgramKind::Amm),
+    (JUPITER_PROGRAM_ID, "Jupiter", ProgramKind::Aggregator),
+    (PUMP_FUN_PROGRAM, "Pump.fun", ProgramKind::Launchpad),
+    (LAUNCHLAB_PROGRAM, "Raydium LaunchLab", ProgramKind::Launchpad),
+    (MOONSHOT_PROGRAM, "Moonshot", ProgramKind::Launchpad),
+    ("So1endDq2YkqhipRh3WViPa8hdiSpxWy6z3Z6tMCpAo", "Solend", ProgramKind::Lending),
+    ("KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD", "Kamino Lend", ProgramKind::Lending),
+    ("MFv2hWf31Z9kbCa1snEPYctwafyhdvnV7FZnsebVacA", "marginfi", ProgramKind::Lending),
+    (SPL_TOKEN_PROGRAM, "SPL Token", ProgramKind::Infrastructure),
+    ("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s", "Metaplex Token Metadata", ProgramKind::Infrastructure),
+];
+
+/// Entries added at runtime, overriding built-ins with the same program id
+static REGISTERED: Mutex<BTreeMap<String, ProgramInfo>> = Mutex::new(BTreeMap::new());
+
+/// Unknown programs seen since the counts were last taken
+static UNKNOWN: Mutex<BTreeMap<String, UnknownProgram>> = Mutex::new(BTreeMap::new());
+
+/// Venue and kind of a program, if registered
+pub fn classify(program_id: &str) -> Option<ProgramInfo> {
+    if let Some(info) = REGISTERED.lock().unwrap().get(program_id) {
+        return Some(info.clone());
+    }
+    BUILTIN_PROGRAMS.iter()
+        .find(|(id, _, _)| *id == program_id)
+        .map(|(id, venue, kind)| ProgramInfo {
+            program_id: id.to_string(),
+            venue: venue.to_string(),
+            kind: *kind,
+        })
+}
+
+/// Classify a program seen in live data, counting it for triage when unknown
+pub fn observe(program_id: &str) -> Option<ProgramInfo> {
+    let info = classify(program_id);
+    if info.is_none() && !program_id.is_empty() {
+        let now = Utc::now().timestamp();
+        let mut unknown = UNKNOWN.lock().unwrap();
+        let entry = unknown.entry(program_id.to_string()).or_insert_with(|| UnknownProgram {
+            program_id: program_id.to_string(),
+            seen_count: 0,
+            first_seen: now,
+            last_seen: now,
+        });
+        entry.seen_count += 1;
+        entry.last_seen = now;
+    }
+    info
+}
+
+/// Whether deposits and withdrawals through the program are liquidity changes
+pub fn is_liquidity_program(program_id: &str) -> bool {
+    classify(program_id).is_some_and(|info| info.kind == ProgramKind::Amm)
+}
+
+/// Add or relabel a program; takes effect for the next classification
+pub fn register(info: ProgramInfo) {
+    UNKNOWN.lock().unwrap().remove(&info.program_id);
+    REGISTERED.lock().unwrap().insert(info.program_id.clone(), info);
+}
+
+/// Drop a runtime entry; built-ins come back into effect. Returns false if there was none
+pub fn unregister(program_id: &str) -> bool {
+    REGISTERED.lock().unwrap().remove(program_id).is_some()
+}
+
+/// Replace every runtime entry
+pub fn replace_registered(programs: Vec<ProgramInfo>) {
+    *REGISTERED.lock().unwrap() = programs.into_iter()
+        .map(|info| (info.program_id.clone(), info))
+        .collect();
+}
+
+/// Unknown programs seen since the last call, most seen first; counts restart from zero
+pub fn take_unknown() -> Vec<UnknownProgram> {
+    let mut unknown: Vec<UnknownProgram> = std::mem::take(&mut *UNKNOWN.lock().unwrap()).into_values().collect();
+    unknown.sort_by_key(|program| std::cmp::Reverse(program.seen_count));
+    unknown
+}
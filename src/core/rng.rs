@@ -0,0 +1,22 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::sync::Mutex;
+
+/// Process-wide RNG; `None` until a seed is set, in which case thread RNG is used
+static SEEDED_RNG: Mutex<Option<StdRng>> = Mutex::new(None);
+
+/// Make all randomness drawn through this module reproducible from `seed`
+///
+/// Set once at startup, before any signals are generated, so replays with the
+/// same seed produce the same sequence.
+pub fn seed_rng(seed: u64) {
+    *SEEDED_RNG.lock().unwrap() = Some(StdRng::seed_from_u64(seed));
+}
+
+/// Random u32 from the seeded RNG, or thread RNG when unseeded
+pub fn random_u32() -> u32 {
+    match SEEDED_RNG.lock().unwrap().as_mut() {
+        Some(rng) => rng.gen(),
+        None => rand::random(),
+    }
+}
@@ -0,0 +1,59 @@
+use super::dex_types::{DexType, MarketEvent, SignalSource, TradingSignal};
+use super::types::StrategyConfig;
+
+/// Generate basic trading signals from market events (Phase 1 implementation)
+///
+/// Shared by live trading, fixture replay and the backtester so all three apply the same entry rules.
+pub fn generate_basic_trading_signal(event: &MarketEvent, config: &StrategyConfig) -> Option<TradingSignal> {
+    match event {
+        MarketEvent::PoolCreated { pool, initial_liquidity_sol, .. } => {
+            // Basic pool creation signal
+            if *initial_liquidity_sol > config.min_pool_liquidity_sol && pool.dex != DexType::Unknown {
+                Some(TradingSignal::Buy {
+                    token_mint: pool.base_mint.clone(),
+                    confidence: config.new_pool_confidence, // Medium confidence for new pools
+                    max_amount_sol: initial_liquidity_sol * config.max_pool_liquidity_fraction, // Cap at a fraction of pool liquidity
+                    reason: format!("New pool on {:?} with {:.1} SOL liquidity", pool.dex, initial_liquidity_sol),
+                    source: SignalSource::NewPool,
+                    insider_wallet: None,
+                })
+            } else {
+                None
+            }
+        }
+        MarketEvent::TokenLaunched { token } => {
+            // Basic new token signal
+            if token.mint_authority.is_none() && token.freeze_authority.is_none() {
+                Some(TradingSignal::Buy {
+                    token_mint: token.mint.clone(),
+                    confidence: config.renounced_token_confidence, // High confidence for renounced tokens
+                    max_amount_sol: config.renounced_token_max_sol, // Conservative fixed cap
+                    reason: "New token with renounced mint and freeze authority".to_string(),
+                    source: SignalSource::NewPool,
+                    insider_wallet: None,
+                })
+            } else {
+                None
+            }
+        }
+        MarketEvent::BondingCurveUpdated { curve, previous_progress } => {
+            // Enter once when a curve moves into its launchpad's progress band
+            let progress = curve.progress();
+            let entered_band = config.in_launchpad_band(curve.launchpad, progress)
+                && !previous_progress.is_some_and(|p| config.in_launchpad_band(curve.launchpad, p));
+            match &curve.token_mint {
+                Some(token_mint) if entered_band && !curve.complete => Some(TradingSignal::Buy {
+                    token_mint: token_mint.clone(),
+                    confidence: config.launchpad_confidence(curve.launchpad),
+                    max_amount_sol: config.launchpad_max_sol(curve.launchpad),
+                    reason: format!("{} bonding curve {:.0}% filled ({:.2} SOL raised)",
+                        curve.launchpad.name(), progress * 100.0, curve.sol_raised()),
+                    source: SignalSource::NewPool,
+                    insider_wallet: None,
+                }),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
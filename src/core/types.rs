@@ -1,4 +1,8 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::dex_types::Launchpad;
+use crate::transport::SignalUrgency;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Token {
@@ -23,6 +27,15 @@ pub struct Signal {
     pub wallet: Option<Wallet>,
     pub amount_sol: f64,
     pub timestamp: u64,
+    /// Picks the execution pathway (Jito template, Jupiter or batched)
+    #[serde(default)]
+    pub urgency: SignalUrgency,
+    /// SOL per token when the signal fired; the swap aborts if the price has moved too far since
+    #[serde(default)]
+    pub reference_price: Option<f64>,
+    /// Strategy that produced the signal, for per-strategy execution stats
+    #[serde(default)]
+    pub strategy: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,4 +54,227 @@ impl ExecutionEngine {
     pub fn new() -> Self {
         Self { is_active: true }
     }
-}
\ No newline at end of file
+}
+/// Active strategy and risk parameters used for signal generation and execution
+///
+/// Missing fields deserialize to their defaults, so strategy files only need overrides.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StrategyConfig {
+    /// Minimum pool liquidity for a new-pool buy signal (SOL)
+    pub min_pool_liquidity_sol: f64,
+    /// Confidence assigned to new-pool signals
+    pub new_pool_confidence: f64,
+    /// Maximum position size as a fraction of pool liquidity
+    pub max_pool_liquidity_fraction: f64,
+    /// Confidence assigned to renounced-token signals
+    pub renounced_token_confidence: f64,
+    /// Maximum position size for renounced-token signals (SOL)
+    pub renounced_token_max_sol: f64,
+    /// Fee rate applied to entries and exits
+    pub fee_rate: f64,
+    /// Seconds a signal may wait for execution before it expires
+    pub signal_ttl_secs: i64,
+    /// Lowest bonding curve progress at which entries are allowed (0.0 - 1.0)
+    pub bonding_curve_min_progress: f64,
+    /// Highest bonding curve progress at which entries are allowed (0.0 - 1.0)
+    pub bonding_curve_max_progress: f64,
+    /// Confidence assigned to bonding curve band entries
+    pub bonding_curve_confidence: f64,
+    /// Maximum position size for bonding curve band entries (SOL)
+    pub bonding_curve_max_sol: f64,
+    /// Bonding curve criteria overridden per launchpad
+    pub launchpad_criteria: BTreeMap<Launchpad, LaunchpadCriteria>,
+    /// Position size caps by token age, youngest rung first; empty disables age sizing
+    pub size_ladder: Vec<SizeRung>,
+    /// Position size cap once a token has migrated off its bonding curve (SOL)
+    pub post_migration_max_sol: Option<f64>,
+    /// Tokens each strategy may enter, by strategy name (e.g. "Sniper"); strategies without one may enter any token
+    pub universes: BTreeMap<String, TokenUniverse>,
+}
+
+/// Bonding curve entry criteria for one launchpad; unset fields use the strategy's `bonding_curve_*` values
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LaunchpadCriteria {
+    pub min_progress: Option<f64>,
+    pub max_progress: Option<f64>,
+    pub confidence: Option<f64>,
+    pub max_sol: Option<f64>,
+}
+
+/// Tokens a strategy may enter; unset bounds and empty lists do not restrict
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TokenUniverse {
+    /// Launchpads the token must have launched on; empty allows any origin
+    pub launchpads: Vec<Launchpad>,
+    /// Token age bounds (seconds)
+    pub min_age_secs: Option<i64>,
+    pub max_age_secs: Option<i64>,
+    /// SOL liquidity bounds of the token's bonding curve or pool
+    pub min_liquidity_sol: Option<f64>,
+    pub max_liquidity_sol: Option<f64>,
+    /// Lowest safety score (0.0 - 1.0) of the token's safety report
+    pub min_safety_score: Option<f64>,
+    /// Mints always in the universe, whatever the other criteria say
+    pub allow: Vec<String>,
+    /// Mints never in the universe; wins over `allow`
+    pub deny: Vec<String>,
+}
+
+/// What is known about a token when its universe membership is checked
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UniverseFacts {
+    pub launchpad: Option<Launchpad>,
+    /// Seconds since creation; a lower bound unless `age_confident`
+    pub age_secs: Option<i64>,
+    pub age_confident: bool,
+    pub liquidity_sol: Option<f64>,
+    pub safety_score: Option<f64>,
+}
+
+impl TokenUniverse {
+    /// Whether checking membership needs the token's safety report
+    pub fn needs_safety_score(&self) -> bool {
+        self.min_safety_score.is_some()
+    }
+
+    /// Reasons a token is outside the universe; empty when it is inside
+    ///
+    /// A fact that is not known fails any bound on it. A low-confidence age
+    /// is a lower bound, so it can show a token is old enough but never
+    /// that it is young enough.
+    pub fn exclusions(&self, token_mint: &str, facts: &UniverseFacts) -> Vec<String> {
+        if self.deny.iter().any(|mint| mint == token_mint) {
+            return vec!["on the deny list".to_string()];
+        }
+        if self.allow.iter().any(|mint| mint == token_mint) {
+            return Vec::new();
+        }
+
+        let mut exclusions = Vec::new();
+        if !self.launchpads.is_empty() {
+            match facts.launchpad {
+                Some(launchpad) if self.launchpads.contains(&launchpad) => {}
+                Some(launchpad) => exclusions.push(format!("launched on {:?}", launchpad)),
+                None => exclusions.push("launchpad origin unknown".to_string()),
+            }
+        }
+        if let Some(min_age) = self.min_age_secs {
+            match facts.age_secs {
+                Some(age) if age >= min_age => {}
+                Some(age) => exclusions.push(format!("{}s old, under {}s", age, min_age)),
+                None => exclusions.push("age unknown".to_string()),
+            }
+        }
+        if let Some(max_age) = self.max_age_secs {
+            match facts.age_secs {
+                Some(age) if age > max_age => exclusions.push(format!("{}s old, over {}s", age, max_age)),
+                Some(_) if facts.age_confident => {}
+                _ => exclusions.push("age not known to be under the maximum".to_string()),
+            }
+        }
+        if self.min_liquidity_sol.is_some() || self.max_liquidity_sol.is_some() {
+            match facts.liquidity_sol {
+                Some(liquidity) if self.min_liquidity_sol.is_some_and(|min| liquidity < min) => {
+                    exclusions.push(format!("{:.2} SOL liquidity, under {:.2}", liquidity, self.min_liquidity_sol.unwrap_or_default()));
+                }
+                Some(liquidity) if self.max_liquidity_sol.is_some_and(|max| liquidity > max) => {
+                    exclusions.push(format!("{:.2} SOL liquidity, over {:.2}", liquidity, self.max_liquidity_sol.unwrap_or_default()));
+                }
+                Some(_) => {}
+                None => exclusions.push("liquidity unknown".to_string()),
+            }
+        }
+        if let Some(min_score) = self.min_safety_score {
+            match facts.safety_score {
+                Some(score) if score >= min_score => {}
+                Some(score) => exclusions.push(format!("safety score {:.2}, under {:.2}", score, min_score)),
+                None => exclusions.push("safety score unknown".to_string()),
+            }
+        }
+        exclusions
+    }
+}
+
+/// One rung of the token-age position size ladder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SizeRung {
+    /// Rung covers tokens younger than this (seconds)
+    pub max_token_age_secs: i64,
+    /// Maximum position size on this rung (SOL)
+    pub max_sol: f64,
+}
+
+impl StrategyConfig {
+    /// Whether a bonding curve progress value falls in the allowed entry band
+    pub fn in_bonding_curve_band(&self, progress: f64) -> bool {
+        progress >= self.bonding_curve_min_progress && progress <= self.bonding_curve_max_progress
+    }
+
+    /// Whether a curve on `launchpad` is in its entry band, with the launchpad's overrides applied
+    pub fn in_launchpad_band(&self, launchpad: Launchpad, progress: f64) -> bool {
+        let criteria = self.launchpad_criteria.get(&launchpad);
+        let min_progress = criteria.and_then(|c| c.min_progress).unwrap_or(self.bonding_curve_min_progress);
+        let max_progress = criteria.and_then(|c| c.max_progress).unwrap_or(self.bonding_curve_max_progress);
+        progress >= min_progress && progress <= max_progress
+    }
+
+    /// Confidence of bonding curve entries on `launchpad`
+    pub fn launchpad_confidence(&self, launchpad: Launchpad) -> f64 {
+        self.launchpad_criteria.get(&launchpad)
+            .and_then(|c| c.confidence)
+            .unwrap_or(self.bonding_curve_confidence)
+    }
+
+    /// Maximum size of bonding curve entries on `launchpad` (SOL)
+    pub fn launchpad_max_sol(&self, launchpad: Launchpad) -> f64 {
+        self.launchpad_criteria.get(&launchpad)
+            .and_then(|c| c.max_sol)
+            .unwrap_or(self.bonding_curve_max_sol)
+    }
+
+    /// Position size cap from the token age ladder
+    ///
+    /// Migrated tokens use the post-migration cap. A token of unknown age is
+    /// sized on the youngest rung; tokens older than the last rung are not capped.
+    pub fn ladder_max_sol(&self, token_age_secs: Option<i64>, migrated: bool) -> Option<f64> {
+        if migrated {
+            if let Some(max_sol) = self.post_migration_max_sol {
+                return Some(max_sol);
+            }
+        }
+        match token_age_secs {
+            Some(age) => self.size_ladder.iter()
+                .find(|rung| age < rung.max_token_age_secs)
+                .map(|rung| rung.max_sol),
+            None => self.size_ladder.first().map(|rung| rung.max_sol),
+        }
+    }
+}
+
+impl Default for StrategyConfig {
+    fn default() -> Self {
+        Self {
+            min_pool_liquidity_sol: 5.0,
+            new_pool_confidence: 0.6,
+            max_pool_liquidity_fraction: 0.1,
+            renounced_token_confidence: 0.8,
+            renounced_token_max_sol: 1.0,
+            fee_rate: 0.005,
+            signal_ttl_secs: 120,
+            bonding_curve_min_progress: 0.3,
+            bonding_curve_max_progress: 0.7,
+            bonding_curve_confidence: 0.65,
+            bonding_curve_max_sol: 0.5,
+            launchpad_criteria: BTreeMap::new(),
+            size_ladder: vec![
+                SizeRung { max_token_age_secs: 300, max_sol: 0.1 },
+                SizeRung { max_token_age_secs: 1800, max_sol: 0.3 },
+            ],
+            post_migration_max_sol: Some(1.0),
+            universes: BTreeMap::new(),
+        }
+    }
+}
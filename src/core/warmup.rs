@@ -0,0 +1,187 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::Mutex;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use super::{MarketEvent, SignalSource};
+
+/// Configuration for strategy warm-up gating
+#[derive(Debug, Clone)]
+pub struct WarmupConfig {
+    /// Price history the market data service must have recorded in this run (seconds); 0 drops the prerequisite
+    pub price_history_secs: i64,
+    /// Swap momentum windows that must have been covered in this run; 0 drops the prerequisite
+    pub momentum_windows: i64,
+}
+
+impl Default for WarmupConfig {
+    fn default() -> Self {
+        Self {
+            price_history_secs: 300,
+            momentum_windows: 3,
+        }
+    }
+}
+
+impl WarmupConfig {
+    /// Defaults with overrides from `BADGER_WARMUP_PRICE_HISTORY_SECS` and `BADGER_WARMUP_MOMENTUM_WINDOWS` when set
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(secs) = std::env::var("BADGER_WARMUP_PRICE_HISTORY_SECS").ok().and_then(|v| v.parse().ok()) {
+            config.price_history_secs = secs;
+        }
+        if let Some(windows) = std::env::var("BADGER_WARMUP_MOMENTUM_WINDOWS").ok().and_then(|v| v.parse().ok()) {
+            config.momentum_windows = windows;
+        }
+        config
+    }
+}
+
+/// Data a strategy needs before its entries are trusted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DataPrerequisite {
+    /// Market events carrying slots are flowing, so token ages in slots are known
+    SlotClock,
+    /// Prices have been recorded into candles for this long (seconds)
+    PriceHistory { secs: i64 },
+    /// Insider profiles were loaded into the wallet intelligence cache
+    InsiderCache,
+    /// Swap volume windows have covered this many window widths
+    MomentumHistory { windows: i64 },
+}
+
+impl SignalSource {
+    /// Every signal source, each of which is a strategy with its own prerequisites
+    pub const ALL: [SignalSource; 8] = [
+        SignalSource::NewPool, SignalSource::InsiderWallet, SignalSource::VolumeSpike, SignalSource::LiquidityAdd,
+        SignalSource::RiskManagement, SignalSource::External, SignalSource::WorkingOrder, SignalSource::Sniper,
+    ];
+
+    /// Data the strategy's entries depend on
+    ///
+    /// Risk management, operator and working-order entries carry their own
+    /// context and are never gated.
+    pub fn prerequisites(&self, config: &WarmupConfig) -> Vec<DataPrerequisite> {
+        let price_history = (config.price_history_secs > 0)
+            .then_some(DataPrerequisite::PriceHistory { secs: config.price_history_secs });
+        let momentum_history = (config.momentum_windows > 0)
+            .then_some(DataPrerequisite::MomentumHistory { windows: config.momentum_windows });
+        match self {
+            SignalSource::NewPool | SignalSource::Sniper => vec![DataPrerequisite::SlotClock],
+            SignalSource::InsiderWallet => vec![DataPrerequisite::SlotClock, DataPrerequisite::InsiderCache],
+            SignalSource::VolumeSpike => momentum_history.into_iter().chain(price_history).collect(),
+            SignalSource::LiquidityAdd => price_history.into_iter().collect(),
+            SignalSource::RiskManagement | SignalSource::External | SignalSource::WorkingOrder => Vec::new(),
+        }
+    }
+}
+
+/// Readiness of one strategy for the status output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyReadiness {
+    pub strategy: String,
+    pub ready: bool,
+    /// Unmet prerequisites with their progress
+    pub waiting_on: Vec<String>,
+}
+
+/// Keeps strategies gated until the data they depend on has warmed up
+///
+/// Components report what they have loaded or observed; a strategy's entries
+/// pass once all of its declared prerequisites are met. Readiness only
+/// counts data gathered by this run, so a restart warms up again instead of
+/// trading on a stale or half-loaded view.
+pub struct StrategyWarmup {
+    config: WarmupConfig,
+    slot_clock: AtomicBool,
+    /// Unix time of the first price recorded in this run; 0 before any
+    price_history_since: AtomicI64,
+    insider_cache_loaded: AtomicBool,
+    momentum_windows: AtomicI64,
+    /// Strategies already reported as warmed up
+    ready: Mutex<HashSet<String>>,
+}
+
+impl StrategyWarmup {
+    pub fn new(config: Option<WarmupConfig>) -> Self {
+        Self {
+            config: config.unwrap_or_default(),
+            slot_clock: AtomicBool::new(false),
+            price_history_since: AtomicI64::new(0),
+            insider_cache_loaded: AtomicBool::new(false),
+            momentum_windows: AtomicI64::new(0),
+            ready: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub fn config(&self) -> &WarmupConfig {
+        &self.config
+    }
+
+    /// Start the slot clock on the first event carrying a slot
+    pub fn observe(&self, event: &MarketEvent) {
+        if event.get_slot().is_some() {
+            self.slot_clock.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// A price was recorded into the candle store at `at`
+    pub fn record_price_sample(&self, at: i64) {
+        let _ = self.price_history_since.compare_exchange(0, at, Ordering::Relaxed, Ordering::Relaxed);
+    }
+
+    /// Insider profiles were loaded into the wallet intelligence cache
+    pub fn mark_insider_cache_loaded(&self) {
+        self.insider_cache_loaded.store(true, Ordering::Relaxed);
+    }
+
+    pub fn insider_cache_loaded(&self) -> bool {
+        self.insider_cache_loaded.load(Ordering::Relaxed)
+    }
+
+    /// Window widths the swap momentum windows cover so far
+    pub fn record_momentum_windows(&self, windows: i64) {
+        self.momentum_windows.fetch_max(windows, Ordering::Relaxed);
+    }
+
+    /// Progress of an unmet prerequisite; None once it is met
+    fn unmet(&self, prerequisite: DataPrerequisite, now: i64) -> Option<String> {
+        match prerequisite {
+            DataPrerequisite::SlotClock => (!self.slot_clock.load(Ordering::Relaxed))
+                .then(|| "slot clock (no slotted events yet)".to_string()),
+            DataPrerequisite::PriceHistory { secs } => {
+                let since = self.price_history_since.load(Ordering::Relaxed);
+                let recorded = if since > 0 { now - since } else { 0 };
+                (recorded < secs).then(|| format!("price history {}/{}s", recorded, secs))
+            }
+            DataPrerequisite::InsiderCache => (!self.insider_cache_loaded.load(Ordering::Relaxed))
+                .then(|| "insider cache not loaded".to_string()),
+            DataPrerequisite::MomentumHistory { windows } => {
+                let covered = self.momentum_windows.load(Ordering::Relaxed);
+                (covered < windows).then(|| format!("momentum windows {}/{}", covered, windows))
+            }
+        }
+    }
+
+    /// Readiness of one strategy
+    pub fn readiness(&self, source: SignalSource) -> StrategyReadiness {
+        let now = Utc::now().timestamp();
+        let strategy = format!("{:?}", source);
+        let prerequisites = source.prerequisites(&self.config);
+        let waiting_on: Vec<String> = prerequisites.iter()
+            .filter_map(|prerequisite| self.unmet(*prerequisite, now))
+            .collect();
+        let ready = waiting_on.is_empty();
+        if ready && !prerequisites.is_empty() && self.ready.lock().unwrap().insert(strategy.clone()) {
+            info!("🌡️ Strategy {} warmed up", strategy);
+        }
+        StrategyReadiness { strategy, ready, waiting_on }
+    }
+
+    /// Readiness of every strategy
+    pub fn all(&self) -> Vec<StrategyReadiness> {
+        SignalSource::ALL.iter().map(|source| self.readiness(*source)).collect()
+    }
+}
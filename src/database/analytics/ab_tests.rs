@@ -0,0 +1,542 @@
+use std::sync::Arc;
+use chrono::Utc;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tokio::sync::RwLock;
+use tracing::{debug, info, instrument, warn};
+
+use crate::core::rng::random_u32;
+use super::strategy_registry::{RegisteredStrategy, StrategyRegistry};
+use super::super::{BadgerDatabase, DatabaseError};
+
+pub const AB_ARM_A: &str = "A";
+pub const AB_ARM_B: &str = "B";
+
+pub const AB_STATUS_RUNNING: &str = "RUNNING";
+pub const AB_STATUS_STOPPED: &str = "STOPPED";
+
+/// Two-sided p-value under which one arm is called better than the other
+pub const AB_SIGNIFICANCE: f64 = 0.05;
+
+/// Signal source of an A/B test arm's signals, e.g. "ab:exit-tuning:B"
+pub fn ab_signal_source(experiment: &str, arm: &str) -> String {
+    format!("ab:{}:{}", experiment, arm)
+}
+
+/// Experiment and arm a signal source belongs to, if it is an A/B test arm's
+pub fn ab_arm_of_source(signal_source: &str) -> Option<(&str, &str)> {
+    signal_source.strip_prefix("ab:")?.rsplit_once(':')
+}
+
+/// A/B test as started by the operator (`START_AB_TEST` target)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbTestSpec {
+    pub name: String,
+    /// Registered strategy run as arm A
+    pub strategy_a: String,
+    /// Registered strategy run as arm B
+    pub strategy_b: String,
+    /// Open exposure each arm may carry (SOL)
+    #[serde(default = "default_budget_sol")]
+    pub budget_sol: f64,
+    /// Closed trades each arm needs before the comparison is reported
+    #[serde(default = "default_min_trades")]
+    pub min_trades: i64,
+}
+
+fn default_budget_sol() -> f64 {
+    1.0
+}
+
+fn default_min_trades() -> i64 {
+    30
+}
+
+/// Stored A/B test
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AbExperiment {
+    pub name: String,
+    pub strategy_a: String,
+    pub strategy_b: String,
+    pub budget_sol: f64,
+    pub min_trades: i64,
+    pub status: String, // "RUNNING", "STOPPED"
+    pub started_at: i64,
+    pub stopped_at: Option<i64>,
+    /// When the comparison was first reported with enough trades on both arms
+    pub reported_at: Option<i64>,
+}
+
+/// One arm of the running A/B test
+#[derive(Debug, Clone)]
+pub struct AbArm {
+    /// "A" or "B"
+    pub label: &'static str,
+    pub strategy: RegisteredStrategy,
+}
+
+/// Running A/B test with its strategies loaded
+#[derive(Debug, Clone)]
+pub struct RunningAbTest {
+    pub experiment: AbExperiment,
+    pub arms: [AbArm; 2],
+}
+
+impl RunningAbTest {
+    pub fn name(&self) -> &str {
+        &self.experiment.name
+    }
+}
+
+/// Track of one arm
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AbArmStats {
+    pub arm: String,
+    pub strategy: String,
+    /// Trades with every lot closed
+    pub closed_trades: i64,
+    pub open_trades: i64,
+    /// SOL spent on entries still (partly) open
+    pub open_exposure_sol: f64,
+    pub winning_trades: i64,
+    pub total_pnl: f64,
+    pub mean_pnl: f64,
+    /// Sample standard deviation of closed trade P&L
+    pub std_pnl: f64,
+    pub win_rate: f64,
+}
+
+/// Statistical comparison of the two arms
+///
+/// Mean P&L per trade is compared with Welch's t-test and win rates with a
+/// two-proportion z-test; both p-values are two-sided and use the normal
+/// approximation, which is fair once each arm has a few dozen trades.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbTestReport {
+    pub experiment: String,
+    pub arms: [AbArmStats; 2],
+    /// Both arms have the experiment's minimum closed trades
+    pub ready: bool,
+    pub pnl_t_stat: f64,
+    pub pnl_p_value: f64,
+    pub win_rate_z_stat: f64,
+    pub win_rate_p_value: f64,
+    /// Strategy with the higher mean P&L, when the difference is significant
+    pub leader: Option<String>,
+}
+
+impl AbTestReport {
+    /// One-line summary for logs and alerts
+    pub fn summary(&self) -> String {
+        let [a, b] = &self.arms;
+        format!(
+            "A/B test {}: {} {} trades mean ${:.4} win {:.0}% vs {} {} trades mean ${:.4} win {:.0}% | P&L p={:.3}, win rate p={:.3} | {}",
+            self.experiment, a.strategy, a.closed_trades, a.mean_pnl, a.win_rate * 100.0,
+            b.strategy, b.closed_trades, b.mean_pnl, b.win_rate * 100.0,
+            self.pnl_p_value, self.win_rate_p_value,
+            match &self.leader {
+                Some(leader) => format!("{} leads", leader),
+                None if self.ready => "no significant difference".to_string(),
+                None => "collecting trades".to_string(),
+            },
+        )
+    }
+}
+
+/// Runs two parameterizations of a strategy side by side on live capital
+///
+/// Each token is assigned to one arm at random the first time either arm
+/// signals on it and stays there, so an arm's exits only ever act on its own
+/// entries. Arms spend from separate budgets, their positions are tracked
+/// per arm (split lots follow their entry through the signal id), and the
+/// arms are compared once both have enough closed trades.
+pub struct StrategyAbTests {
+    db: Arc<BadgerDatabase>,
+    running: RwLock<Option<RunningAbTest>>,
+    /// Token mint -> arm of the running test
+    assignments: DashMap<String, &'static str>,
+}
+
+impl StrategyAbTests {
+    pub fn new(db: Arc<BadgerDatabase>) -> Self {
+        Self {
+            db,
+            running: RwLock::new(None),
+            assignments: DashMap::new(),
+        }
+    }
+
+    /// Initialize A/B test schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing A/B test schema");
+
+        let create_experiments_table = r#"
+            CREATE TABLE IF NOT EXISTS ab_experiments (
+                name TEXT PRIMARY KEY,
+                strategy_a TEXT NOT NULL,
+                strategy_b TEXT NOT NULL,
+                budget_sol REAL NOT NULL,
+                min_trades INTEGER NOT NULL,
+                status TEXT NOT NULL CHECK (status IN ('RUNNING', 'STOPPED')),
+                started_at INTEGER NOT NULL,
+                stopped_at INTEGER,
+                reported_at INTEGER
+            )
+        "#;
+
+        let create_assignments_table = r#"
+            CREATE TABLE IF NOT EXISTS ab_test_assignments (
+                experiment TEXT NOT NULL,
+                token_mint TEXT NOT NULL,
+                arm TEXT NOT NULL CHECK (arm IN ('A', 'B')),
+                assigned_at INTEGER NOT NULL,
+                PRIMARY KEY (experiment, token_mint)
+            )
+        "#;
+
+        let create_trades_table = r#"
+            CREATE TABLE IF NOT EXISTS ab_test_trades (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                experiment TEXT NOT NULL,
+                arm TEXT NOT NULL CHECK (arm IN ('A', 'B')),
+                position_id INTEGER NOT NULL UNIQUE,
+                token_mint TEXT NOT NULL,
+                amount_sol REAL NOT NULL,
+                opened_at INTEGER NOT NULL
+            )
+        "#;
+
+        for table_sql in [create_experiments_table, create_assignments_table, create_trades_table] {
+            sqlx::query(table_sql)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create A/B test table: {}", e)))?;
+        }
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_ab_test_trades_experiment ON ab_test_trades(experiment, arm)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        info!("✅ A/B test schema initialized");
+        Ok(())
+    }
+
+    /// Resume the test left running by a previous run
+    pub async fn load(&self, registry: &StrategyRegistry) -> Result<Option<String>, DatabaseError> {
+        let experiment = sqlx::query_as::<_, AbExperiment>(
+            "SELECT * FROM ab_experiments WHERE status = 'RUNNING' ORDER BY started_at DESC LIMIT 1"
+        )
+        .fetch_optional(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to load A/B test: {}", e)))?;
+
+        let Some(experiment) = experiment else {
+            return Ok(None);
+        };
+        let running = match Self::with_arms(experiment, registry).await {
+            Ok(running) => running,
+            Err(e) => {
+                warn!("Not resuming A/B test: {}", e);
+                return Ok(None);
+            }
+        };
+
+        let assignments = sqlx::query_as::<_, (String, String)>(
+            "SELECT token_mint, arm FROM ab_test_assignments WHERE experiment = ?"
+        )
+        .bind(running.name())
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to load A/B test assignments: {}", e)))?;
+        self.assignments.clear();
+        for (token_mint, arm) in assignments {
+            self.assignments.insert(token_mint, if arm == AB_ARM_A { AB_ARM_A } else { AB_ARM_B });
+        }
+
+        let name = running.name().to_string();
+        *self.running.write().await = Some(running);
+        Ok(Some(name))
+    }
+
+    /// Start an A/B test between two registered strategies
+    pub async fn start(&self, spec: &AbTestSpec, registry: &StrategyRegistry) -> Result<RunningAbTest, DatabaseError> {
+        if let Some(running) = self.running.read().await.as_ref() {
+            return Err(DatabaseError::QueryError(format!("A/B test {} is already running", running.name())));
+        }
+        if spec.strategy_a == spec.strategy_b {
+            return Err(DatabaseError::QueryError("A/B test arms must be different strategies".to_string()));
+        }
+        if spec.budget_sol <= 0.0 || spec.min_trades < 2 {
+            return Err(DatabaseError::QueryError("A/B test needs a positive budget and at least 2 trades per arm".to_string()));
+        }
+
+        let experiment = AbExperiment {
+            name: spec.name.clone(),
+            strategy_a: spec.strategy_a.clone(),
+            strategy_b: spec.strategy_b.clone(),
+            budget_sol: spec.budget_sol,
+            min_trades: spec.min_trades,
+            status: AB_STATUS_RUNNING.to_string(),
+            started_at: Utc::now().timestamp(),
+            stopped_at: None,
+            reported_at: None,
+        };
+        let running = Self::with_arms(experiment, registry).await?;
+
+        sqlx::query(r#"
+            INSERT INTO ab_experiments (name, strategy_a, strategy_b, budget_sol, min_trades, status, started_at)
+            VALUES (?, ?, ?, ?, ?, 'RUNNING', ?)
+        "#)
+        .bind(&running.experiment.name)
+        .bind(&running.experiment.strategy_a)
+        .bind(&running.experiment.strategy_b)
+        .bind(running.experiment.budget_sol)
+        .bind(running.experiment.min_trades)
+        .bind(running.experiment.started_at)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to start A/B test (names cannot be reused): {}", e)))?;
+
+        self.assignments.clear();
+        *self.running.write().await = Some(running.clone());
+        info!("🧪 A/B test {} started: {} vs {}", spec.name, spec.strategy_a, spec.strategy_b);
+        Ok(running)
+    }
+
+    /// Stop the named test; its report covers everything it traded
+    pub async fn stop(&self, name: &str) -> Result<AbTestReport, DatabaseError> {
+        let updated = sqlx::query("UPDATE ab_experiments SET status = 'STOPPED', stopped_at = ? WHERE name = ? AND status = 'RUNNING'")
+            .bind(Utc::now().timestamp())
+            .bind(name)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to stop A/B test: {}", e)))?
+            .rows_affected();
+        if updated == 0 {
+            return Err(DatabaseError::QueryError(format!("No running A/B test named {}", name)));
+        }
+
+        let mut running = self.running.write().await;
+        if running.as_ref().is_some_and(|test| test.name() == name) {
+            *running = None;
+            self.assignments.clear();
+        }
+        drop(running);
+
+        info!("🧪 A/B test {} stopped", name);
+        self.report(name).await
+    }
+
+    /// Test currently splitting signals, if any
+    pub async fn running(&self) -> Option<RunningAbTest> {
+        self.running.read().await.clone()
+    }
+
+    /// Arm a token trades under; drawn at random the first time and kept
+    pub async fn assign(&self, experiment: &str, token_mint: &str) -> Result<&'static str, DatabaseError> {
+        if let Some(arm) = self.assignments.get(token_mint) {
+            return Ok(*arm);
+        }
+        let arm = if random_u32().is_multiple_of(2) { AB_ARM_A } else { AB_ARM_B };
+        sqlx::query("INSERT OR IGNORE INTO ab_test_assignments (experiment, token_mint, arm, assigned_at) VALUES (?, ?, ?, ?)")
+            .bind(experiment)
+            .bind(token_mint)
+            .bind(arm)
+            .bind(Utc::now().timestamp())
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to record A/B test assignment: {}", e)))?;
+
+        debug!("🧪 [{}] {} assigned to arm {}", experiment, token_mint, arm);
+        Ok(*self.assignments.entry(token_mint.to_string()).or_insert(arm))
+    }
+
+    /// Whether an arm can open another `amount_sol` inside its budget
+    pub async fn within_budget(&self, experiment: &AbExperiment, arm: &str, amount_sol: f64) -> Result<bool, DatabaseError> {
+        let open_exposure = self.arm_stats(experiment, arm).await?.open_exposure_sol;
+        Ok(open_exposure + amount_sol <= experiment.budget_sol)
+    }
+
+    /// Attribute a position opened by an arm's signal to the arm
+    pub async fn record_trade(
+        &self,
+        experiment: &str,
+        arm: &str,
+        position_id: i64,
+        token_mint: &str,
+        amount_sol: f64,
+    ) -> Result<(), DatabaseError> {
+        sqlx::query(r#"
+            INSERT OR IGNORE INTO ab_test_trades (experiment, arm, position_id, token_mint, amount_sol, opened_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(experiment)
+        .bind(arm)
+        .bind(position_id)
+        .bind(token_mint)
+        .bind(amount_sol)
+        .bind(Utc::now().timestamp())
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record A/B test trade: {}", e)))?;
+        Ok(())
+    }
+
+    /// Compare the arms of a test
+    pub async fn report(&self, name: &str) -> Result<AbTestReport, DatabaseError> {
+        let experiment = sqlx::query_as::<_, AbExperiment>("SELECT * FROM ab_experiments WHERE name = ?")
+            .bind(name)
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch A/B test: {}", e)))?
+            .ok_or_else(|| DatabaseError::QueryError(format!("No A/B test named {}", name)))?;
+
+        let a = self.arm_stats(&experiment, AB_ARM_A).await?;
+        let b = self.arm_stats(&experiment, AB_ARM_B).await?;
+        let ready = a.closed_trades >= experiment.min_trades && b.closed_trades >= experiment.min_trades;
+
+        let (pnl_t_stat, pnl_p_value) = welch_t_test(&a, &b);
+        let (win_rate_z_stat, win_rate_p_value) = two_proportion_z_test(&a, &b);
+        let leader = (ready && pnl_p_value < AB_SIGNIFICANCE)
+            .then(|| if a.mean_pnl > b.mean_pnl { a.strategy.clone() } else { b.strategy.clone() });
+
+        Ok(AbTestReport {
+            experiment: experiment.name,
+            arms: [a, b],
+            ready,
+            pnl_t_stat,
+            pnl_p_value,
+            win_rate_z_stat,
+            win_rate_p_value,
+            leader,
+        })
+    }
+
+    /// Report of the running test the first time both arms have enough trades
+    pub async fn take_ready_report(&self) -> Result<Option<AbTestReport>, DatabaseError> {
+        let Some(running) = self.running().await else {
+            return Ok(None);
+        };
+        let report = self.report(running.name()).await?;
+        if !report.ready {
+            return Ok(None);
+        }
+
+        let first = sqlx::query("UPDATE ab_experiments SET reported_at = ? WHERE name = ? AND reported_at IS NULL")
+            .bind(Utc::now().timestamp())
+            .bind(running.name())
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to mark A/B test reported: {}", e)))?
+            .rows_affected() > 0;
+        Ok(first.then_some(report))
+    }
+
+    async fn with_arms(experiment: AbExperiment, registry: &StrategyRegistry) -> Result<RunningAbTest, DatabaseError> {
+        let strategy = |name: String| async move {
+            registry.strategy(&name).await
+                .ok_or_else(|| DatabaseError::QueryError(format!("No registered strategy named {}", name)))
+        };
+        let a = strategy(experiment.strategy_a.clone()).await?;
+        let b = strategy(experiment.strategy_b.clone()).await?;
+        Ok(RunningAbTest {
+            experiment,
+            arms: [
+                AbArm { label: AB_ARM_A, strategy: a },
+                AbArm { label: AB_ARM_B, strategy: b },
+            ],
+        })
+    }
+
+    async fn arm_stats(&self, experiment: &AbExperiment, arm: &str) -> Result<AbArmStats, DatabaseError> {
+        // Partial closes split lots into new rows that keep the entry's signal id
+        let trades = sqlx::query_as::<_, (f64, f64, i64)>(r#"
+            SELECT
+                t.amount_sol,
+                COALESCE(SUM(CASE WHEN p.status = 'CLOSED' THEN p.pnl ELSE 0.0 END), 0.0) as pnl,
+                COALESCE(SUM(CASE WHEN p.status != 'CLOSED' THEN 1 ELSE 0 END), 0) as open_lots
+            FROM ab_test_trades t
+            JOIN positions entry ON entry.id = t.position_id
+            JOIN positions p ON p.signal_id = entry.signal_id
+            WHERE t.experiment = ? AND t.arm = ?
+            GROUP BY t.id
+        "#)
+        .bind(&experiment.name)
+        .bind(arm)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch A/B test trades: {}", e)))?;
+
+        let mut stats = AbArmStats {
+            arm: arm.to_string(),
+            strategy: if arm == AB_ARM_A { experiment.strategy_a.clone() } else { experiment.strategy_b.clone() },
+            ..Default::default()
+        };
+        let mut closed = Vec::new();
+        for (amount_sol, pnl, open_lots) in trades {
+            if open_lots > 0 {
+                stats.open_trades += 1;
+                stats.open_exposure_sol += amount_sol;
+            } else {
+                closed.push(pnl);
+            }
+        }
+
+        stats.closed_trades = closed.len() as i64;
+        stats.winning_trades = closed.iter().filter(|pnl| **pnl > 0.0).count() as i64;
+        stats.total_pnl = closed.iter().fold(0.0, |total, pnl| total + pnl);
+        if !closed.is_empty() {
+            stats.mean_pnl = stats.total_pnl / closed.len() as f64;
+            stats.win_rate = stats.winning_trades as f64 / closed.len() as f64;
+        }
+        if closed.len() > 1 {
+            let variance = closed.iter().map(|pnl| (pnl - stats.mean_pnl).powi(2)).sum::<f64>() / (closed.len() - 1) as f64;
+            stats.std_pnl = variance.sqrt();
+        }
+        Ok(stats)
+    }
+}
+
+/// Welch's t statistic for the difference in mean P&L, with a normal-approximation p-value
+fn welch_t_test(a: &AbArmStats, b: &AbArmStats) -> (f64, f64) {
+    if a.closed_trades < 2 || b.closed_trades < 2 {
+        return (0.0, 1.0);
+    }
+    let standard_error = (a.std_pnl.powi(2) / a.closed_trades as f64 + b.std_pnl.powi(2) / b.closed_trades as f64).sqrt();
+    if standard_error <= f64::EPSILON {
+        return (0.0, 1.0);
+    }
+    let t = (a.mean_pnl - b.mean_pnl) / standard_error;
+    (t, two_sided_p_value(t))
+}
+
+/// Pooled two-proportion z statistic for the difference in win rate
+fn two_proportion_z_test(a: &AbArmStats, b: &AbArmStats) -> (f64, f64) {
+    if a.closed_trades == 0 || b.closed_trades == 0 {
+        return (0.0, 1.0);
+    }
+    let (n_a, n_b) = (a.closed_trades as f64, b.closed_trades as f64);
+    let pooled = (a.winning_trades + b.winning_trades) as f64 / (n_a + n_b);
+    let standard_error = (pooled * (1.0 - pooled) * (1.0 / n_a + 1.0 / n_b)).sqrt();
+    if standard_error <= f64::EPSILON {
+        return (0.0, 1.0);
+    }
+    let z = (a.win_rate - b.win_rate) / standard_error;
+    (z, two_sided_p_value(z))
+}
+
+/// P(|Z| >= |z|) for a standard normal Z
+fn two_sided_p_value(z: f64) -> f64 {
+    (1.0 - erf(z.abs() / std::f64::consts::SQRT_2)).clamp(0.0, 1.0)
+}
+
+/// Error function (Abramowitz and Stegun 7.1.26, accurate to about 1.5e-7)
+fn erf(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.3275911 * x.abs());
+    let polynomial = t * (0.254829592 + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let value = 1.0 - polynomial * (-x * x).exp();
+    if x < 0.0 { -value } else { value }
+}
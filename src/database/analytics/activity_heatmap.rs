@@ -0,0 +1,174 @@
+use std::sync::Arc;
+use chrono::{TimeZone, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::instrument;
+
+use super::super::{BadgerDatabase, DatabaseError};
+
+/// Bucket insider activity by UTC hour of day (0-23)
+pub const HEATMAP_HOUR: &str = "HOUR";
+/// Bucket insider activity by UTC day of week (0 = Sunday)
+pub const HEATMAP_WEEKDAY: &str = "WEEKDAY";
+
+/// Configuration for insider activity heatmaps
+#[derive(Debug, Clone)]
+pub struct HeatmapConfig {
+    /// Activity older than this is left out (days)
+    pub lookback_days: i64,
+    /// Resolved trades a bucket needs before its profitability is used for sizing
+    pub min_resolved_trades: i64,
+    /// Scale copy sizes by the insider's profitability in the current hour
+    pub scale_copy_sizes: bool,
+    pub min_size_multiplier: f64,
+    pub max_size_multiplier: f64,
+}
+
+impl Default for HeatmapConfig {
+    fn default() -> Self {
+        Self {
+            lookback_days: 30,
+            min_resolved_trades: 5,
+            scale_copy_sizes: false,
+            min_size_multiplier: 0.5,
+            max_size_multiplier: 1.5,
+        }
+    }
+}
+
+impl HeatmapConfig {
+    /// Defaults with hour-of-day copy sizing enabled by `BADGER_HEATMAP_SIZING=1`
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Ok(value) = std::env::var("BADGER_HEATMAP_SIZING") {
+            config.scale_copy_sizes = matches!(value.as_str(), "1" | "true" | "yes");
+        }
+        config
+    }
+}
+
+/// Activity and outcomes of insider buys in one time bucket
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct HeatmapCell {
+    /// Hour of day (0-23) or day of week (0 = Sunday), UTC
+    pub bucket: i64,
+    pub activities: i64,
+    pub buys: i64,
+    /// Buys with a WIN or LOSS outcome
+    pub resolved_trades: i64,
+    pub wins: i64,
+    /// Mean profit of resolved buys (%)
+    pub avg_profit_pct: f64,
+}
+
+impl HeatmapCell {
+    pub fn win_rate(&self) -> f64 {
+        if self.resolved_trades > 0 { self.wins as f64 / self.resolved_trades as f64 } else { 0.0 }
+    }
+}
+
+/// When insiders are active, and when their buys pay off
+///
+/// Activity comes from `insider_activities`, profitability from the resolved
+/// buys in `insider_trade_outcomes`, both bucketed by the UTC hour of day or
+/// day of week the insider acted in. Per-wallet heatmaps can optionally scale
+/// copy sizes: an insider whose buys at this hour win more often than their
+/// buys overall gets a larger copy, one that does worse a smaller one.
+pub struct ActivityHeatmap {
+    db: Arc<BadgerDatabase>,
+    config: HeatmapConfig,
+}
+
+impl ActivityHeatmap {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<HeatmapConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+        }
+    }
+
+    pub fn config(&self) -> &HeatmapConfig {
+        &self.config
+    }
+
+    /// Heatmap of one insider, or of the whole cohort when `wallet_address` is None
+    ///
+    /// # Arguments
+    /// * `wallet_address` - Insider wallet, or None for every insider together
+    /// * `dimension` - `HEATMAP_HOUR` or `HEATMAP_WEEKDAY`
+    ///
+    /// # Returns
+    /// * `Result<Vec<HeatmapCell>, DatabaseError>` - Buckets with any activity, in bucket order
+    #[instrument(skip(self))]
+    pub async fn heatmap(&self, wallet_address: Option<&str>, dimension: &str) -> Result<Vec<HeatmapCell>, DatabaseError> {
+        let format = match dimension {
+            HEATMAP_HOUR => "%H",
+            HEATMAP_WEEKDAY => "%w",
+            _ => return Err(DatabaseError::QueryError(format!("Unknown heatmap dimension: {}", dimension))),
+        };
+        let since = Utc::now().timestamp() - self.config.lookback_days * 86400;
+
+        let query = format!(r#"
+            WITH activity AS (
+                SELECT CAST(strftime('{format}', timestamp, 'unixepoch') AS INTEGER) AS bucket,
+                       COUNT(*) AS activities,
+                       SUM(CASE WHEN activity_type = 'BUY' THEN 1 ELSE 0 END) AS buys
+                FROM insider_activities
+                WHERE timestamp >= ?1 AND (?2 IS NULL OR wallet_address = ?2)
+                GROUP BY bucket
+            ),
+            outcomes AS (
+                SELECT CAST(strftime('{format}', entry_timestamp, 'unixepoch') AS INTEGER) AS bucket,
+                       COUNT(*) AS resolved_trades,
+                       SUM(CASE WHEN status = 'WIN' THEN 1 ELSE 0 END) AS wins,
+                       AVG(profit_pct) AS avg_profit_pct
+                FROM insider_trade_outcomes
+                WHERE entry_timestamp >= ?1 AND status IN ('WIN', 'LOSS')
+                  AND (?2 IS NULL OR wallet_address = ?2)
+                GROUP BY bucket
+            )
+            SELECT a.bucket,
+                   a.activities,
+                   a.buys,
+                   COALESCE(o.resolved_trades, 0) AS resolved_trades,
+                   COALESCE(o.wins, 0) AS wins,
+                   COALESCE(o.avg_profit_pct, 0.0) AS avg_profit_pct
+            FROM activity a
+            LEFT JOIN outcomes o ON o.bucket = a.bucket
+            ORDER BY a.bucket
+        "#);
+
+        sqlx::query_as::<_, HeatmapCell>(&query)
+            .bind(since)
+            .bind(wallet_address)
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to compute activity heatmap: {}", e)))
+    }
+
+    /// Size multiplier for copying an insider now
+    ///
+    /// The ratio of the insider's win rate in the current UTC hour to their
+    /// win rate overall, clamped to the configured range. None when sizing is
+    /// disabled or either sample is too small to say anything.
+    pub async fn copy_size_multiplier(&self, wallet_address: &str, at: i64) -> Result<Option<f64>, DatabaseError> {
+        if !self.config.scale_copy_sizes {
+            return Ok(None);
+        }
+        let cells = self.heatmap(Some(wallet_address), HEATMAP_HOUR).await?;
+        let hour = Utc.timestamp_opt(at, 0).single().map_or(0, |t| t.hour() as i64);
+
+        let resolved: i64 = cells.iter().map(|c| c.resolved_trades).sum();
+        let wins: i64 = cells.iter().map(|c| c.wins).sum();
+        let Some(cell) = cells.iter().find(|c| c.bucket == hour) else {
+            return Ok(None);
+        };
+        if cell.resolved_trades < self.config.min_resolved_trades || resolved < self.config.min_resolved_trades || wins == 0 {
+            return Ok(None);
+        }
+        let overall_win_rate = wins as f64 / resolved as f64;
+        let multiplier = (cell.win_rate() / overall_win_rate)
+            .clamp(self.config.min_size_multiplier, self.config.max_size_multiplier);
+        Ok(Some(multiplier))
+    }
+}
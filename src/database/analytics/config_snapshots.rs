@@ -0,0 +1,170 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{info, instrument};
+
+use crate::database::{BadgerDatabase, DatabaseError};
+
+/// Stored configuration snapshot
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ConfigSnapshot {
+    pub config_hash: String,
+    pub snapshot: String, // JSON-encoded configuration
+    pub first_seen_at: i64,
+}
+
+/// Trading results grouped by configuration era
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ConfigPerformance {
+    pub config_hash: String,
+    pub first_seen_at: i64,
+    pub total_trades: i64,
+    pub closed_trades: i64,
+    pub winning_trades: i64,
+    pub total_pnl: f64,
+}
+
+/// Persists configuration snapshots and links each executed trade to one
+pub struct ConfigSnapshotStore {
+    db: Arc<BadgerDatabase>,
+}
+
+impl ConfigSnapshotStore {
+    pub fn new(db: Arc<BadgerDatabase>) -> Self {
+        Self { db }
+    }
+
+    /// Initialize configuration snapshot schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing config snapshot schema");
+
+        let create_config_snapshots_table = r#"
+            CREATE TABLE IF NOT EXISTS config_snapshots (
+                config_hash TEXT PRIMARY KEY,
+                snapshot TEXT NOT NULL,
+                first_seen_at INTEGER NOT NULL
+            )
+        "#;
+
+        let create_trade_configs_table = r#"
+            CREATE TABLE IF NOT EXISTS trade_configs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                position_id INTEGER NOT NULL,
+                trade_action TEXT NOT NULL CHECK (trade_action IN ('OPEN', 'CLOSE')),
+                config_hash TEXT NOT NULL,
+                timestamp INTEGER NOT NULL,
+                FOREIGN KEY (position_id) REFERENCES positions (id),
+                FOREIGN KEY (config_hash) REFERENCES config_snapshots (config_hash)
+            )
+        "#;
+
+        let create_indexes = vec![
+            "CREATE INDEX IF NOT EXISTS idx_trade_configs_position ON trade_configs(position_id)",
+            "CREATE INDEX IF NOT EXISTS idx_trade_configs_hash ON trade_configs(config_hash)",
+        ];
+
+        sqlx::query(create_config_snapshots_table)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create config_snapshots table: {}", e)))?;
+
+        sqlx::query(create_trade_configs_table)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create trade_configs table: {}", e)))?;
+
+        for index_sql in create_indexes {
+            sqlx::query(index_sql)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+        }
+
+        info!("✅ Config snapshot schema initialized");
+        Ok(())
+    }
+
+    /// Deterministic hash of a configuration
+    ///
+    /// Struct fields serialize in declaration order, so identical settings
+    /// always produce the same hash.
+    pub fn config_hash<T: Serialize>(config: &T) -> Result<(String, String), DatabaseError> {
+        let snapshot = serde_json::to_string(config)
+            .map_err(|e| DatabaseError::SerializationError(format!("Failed to serialize config: {}", e)))?;
+        let hash = solana_sdk::hash::hash(snapshot.as_bytes()).to_string();
+        Ok((hash, snapshot))
+    }
+
+    /// Persist a configuration snapshot (no-op if already stored)
+    ///
+    /// # Returns
+    /// * `Result<String, DatabaseError>` - Hash identifying the configuration
+    pub async fn record_snapshot<T: Serialize>(&self, config: &T) -> Result<String, DatabaseError> {
+        let (config_hash, snapshot) = Self::config_hash(config)?;
+
+        let inserted = sqlx::query(
+            "INSERT OR IGNORE INTO config_snapshots (config_hash, snapshot, first_seen_at) VALUES (?, ?, ?)"
+        )
+        .bind(&config_hash)
+        .bind(&snapshot)
+        .bind(Utc::now().timestamp())
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record config snapshot: {}", e)))?
+        .rows_affected();
+
+        if inserted > 0 {
+            info!("🧾 New configuration era {}", &config_hash[..8.min(config_hash.len())]);
+        }
+
+        Ok(config_hash)
+    }
+
+    /// Link an executed trade to the configuration active at execution time
+    pub async fn attach_to_trade(&self, position_id: i64, trade_action: &str, config_hash: &str) -> Result<(), DatabaseError> {
+        sqlx::query(
+            "INSERT INTO trade_configs (position_id, trade_action, config_hash, timestamp) VALUES (?, ?, ?, ?)"
+        )
+        .bind(position_id)
+        .bind(trade_action)
+        .bind(config_hash)
+        .bind(Utc::now().timestamp())
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to attach config to trade: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Get a stored configuration snapshot by hash
+    pub async fn get_snapshot(&self, config_hash: &str) -> Result<Option<ConfigSnapshot>, DatabaseError> {
+        sqlx::query_as::<_, ConfigSnapshot>("SELECT * FROM config_snapshots WHERE config_hash = ?")
+            .bind(config_hash)
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch config snapshot: {}", e)))
+    }
+
+    /// Trading results grouped by the configuration positions were opened under
+    pub async fn get_performance_by_config(&self) -> Result<Vec<ConfigPerformance>, DatabaseError> {
+        sqlx::query_as::<_, ConfigPerformance>(r#"
+            SELECT
+                cs.config_hash,
+                cs.first_seen_at,
+                COUNT(p.id) as total_trades,
+                COALESCE(SUM(CASE WHEN p.status = 'CLOSED' THEN 1 ELSE 0 END), 0) as closed_trades,
+                COALESCE(SUM(CASE WHEN p.pnl > 0 THEN 1 ELSE 0 END), 0) as winning_trades,
+                COALESCE(SUM(p.pnl), 0.0) as total_pnl
+            FROM config_snapshots cs
+            JOIN trade_configs tc ON tc.config_hash = cs.config_hash AND tc.trade_action = 'OPEN'
+            JOIN positions p ON p.id = tc.position_id
+            GROUP BY cs.config_hash
+            ORDER BY cs.first_seen_at DESC
+        "#)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch performance by config: {}", e)))
+    }
+}
@@ -0,0 +1,310 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, warn, instrument};
+
+use super::super::{BadgerDatabase, DatabaseError};
+use super::{FILL_BAD, FILL_OK};
+
+/// Achievable exit value reached the take-profit return
+pub const EXIT_TAKE_PROFIT: &str = "TAKE_PROFIT";
+/// Achievable exit value fell to the stop-loss return
+pub const EXIT_STOP_LOSS: &str = "STOP_LOSS";
+
+/// Configuration for take-profit and stop-loss levels; 0 disables a level
+#[derive(Debug, Clone)]
+pub struct ExitLevelConfig {
+    /// Net return after exit costs at which a position is sold (1.0 = +100%)
+    pub take_profit_pct: f64,
+    /// Net loss after exit costs at which a position is sold (0.3 = -30%)
+    pub stop_loss_pct: f64,
+    /// Exit slippage assumed until enough sells have been measured (basis points)
+    pub default_exit_slippage_bps: f64,
+    /// Checked sells needed before measured slippage replaces the default
+    pub min_measured_sells: i64,
+    /// How far back sells are measured (seconds)
+    pub measurement_window_secs: i64,
+}
+
+impl Default for ExitLevelConfig {
+    fn default() -> Self {
+        Self {
+            take_profit_pct: 0.0,
+            stop_loss_pct: 0.0,
+            default_exit_slippage_bps: 100.0,
+            min_measured_sells: 5,
+            measurement_window_secs: 7 * 86400,
+        }
+    }
+}
+
+impl ExitLevelConfig {
+    /// Defaults overridden by `BADGER_TAKE_PROFIT_PCT`, `BADGER_STOP_LOSS_PCT` and `BADGER_EXIT_SLIPPAGE_BPS`
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        let var = |name: &str| std::env::var(name).ok().and_then(|v| v.parse::<f64>().ok());
+        if let Some(pct) = var("BADGER_TAKE_PROFIT_PCT") {
+            config.take_profit_pct = pct;
+        }
+        if let Some(pct) = var("BADGER_STOP_LOSS_PCT") {
+            config.stop_loss_pct = pct;
+        }
+        if let Some(bps) = var("BADGER_EXIT_SLIPPAGE_BPS") {
+            config.default_exit_slippage_bps = bps;
+        }
+        config
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.take_profit_pct > 0.0 || self.stop_loss_pct > 0.0
+    }
+}
+
+/// Concrete exit prices of a position, fixed when it was opened
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ExitLevels {
+    pub position_id: i64,
+    pub token_mint: String,
+    pub entry_price: f64,
+    /// Mid price at which selling nets the take-profit return
+    pub take_profit_price: Option<f64>,
+    /// Mid price at which selling nets the stop-loss return
+    pub stop_loss_price: Option<f64>,
+    /// Expected exit slippage the levels account for (basis points)
+    pub exit_slippage_bps: f64,
+    /// Measured sell tax of the token the levels account for (basis points)
+    pub sell_tax_bps: f64,
+    pub fee_rate: f64,
+    pub created_at: i64,
+    /// `TAKE_PROFIT` or `STOP_LOSS` once a level was hit
+    pub triggered: Option<String>,
+    pub triggered_price: Option<f64>,
+    pub triggered_at: Option<i64>,
+}
+
+impl ExitLevels {
+    /// Level crossed at a mid price, if any
+    pub fn crossed(&self, price: f64) -> Option<&'static str> {
+        if self.stop_loss_price.is_some_and(|stop| price <= stop) {
+            Some(EXIT_STOP_LOSS)
+        } else if self.take_profit_price.is_some_and(|target| price >= target) {
+            Some(EXIT_TAKE_PROFIT)
+        } else {
+            None
+        }
+    }
+
+    /// Share of the mid price a sale actually returns, after slippage, sell tax and fees
+    pub fn exit_value_ratio(&self) -> f64 {
+        (1.0 - (self.exit_slippage_bps + self.sell_tax_bps) / 10_000.0).max(0.0) * (1.0 - self.fee_rate)
+    }
+}
+
+/// Measured cost of selling, from sells cross-checked against the oracle
+#[derive(Debug, Clone, Default, FromRow)]
+struct SellCost {
+    sells: i64,
+    avg_adverse_bps: Option<f64>,
+}
+
+/// Take-profit and stop-loss levels priced for what an exit actually returns
+///
+/// When a position opens, its percentage thresholds are turned into mid
+/// prices at which a sale nets the target return after fees, expected exit
+/// slippage and the token's measured sell tax. Slippage is the mean adverse
+/// deviation of our recent sells against the oracle (see
+/// `FillQualityMonitor`); a token's sell tax is how much worse its own sells
+/// did than that. The position monitor then compares the mid price against
+/// fixed levels instead of raw percentages, so a stop is not hit only after
+/// the achievable value is already past it.
+pub struct ExitLevelBook {
+    db: Arc<BadgerDatabase>,
+    config: ExitLevelConfig,
+}
+
+impl ExitLevelBook {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<ExitLevelConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+        }
+    }
+
+    pub fn config(&self) -> &ExitLevelConfig {
+        &self.config
+    }
+
+    /// Initialize exit level schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing exit level schema");
+
+        let create_exit_levels = r#"
+            CREATE TABLE IF NOT EXISTS exit_levels (
+                position_id INTEGER PRIMARY KEY,
+                token_mint TEXT NOT NULL,
+                entry_price REAL NOT NULL,
+                take_profit_price REAL,
+                stop_loss_price REAL,
+                exit_slippage_bps REAL NOT NULL,
+                sell_tax_bps REAL NOT NULL,
+                fee_rate REAL NOT NULL,
+                created_at INTEGER NOT NULL,
+                triggered TEXT CHECK (triggered IN ('TAKE_PROFIT', 'STOP_LOSS')),
+                triggered_price REAL,
+                triggered_at INTEGER
+            )
+        "#;
+
+        sqlx::query(create_exit_levels)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create exit_levels table: {}", e)))?;
+
+        info!("✅ Exit level schema initialized");
+        Ok(())
+    }
+
+    /// Price and store the exit levels of a position
+    ///
+    /// Called on open with the quoted entry, and again once the confirmed
+    /// entry fill corrects it.
+    ///
+    /// # Returns
+    /// * `Result<Option<ExitLevels>, DatabaseError>` - None when both levels are disabled
+    pub async fn set_for_position(
+        &self,
+        position_id: i64,
+        token_mint: &str,
+        entry_price: f64,
+        fee_rate: f64,
+    ) -> Result<Option<ExitLevels>, DatabaseError> {
+        if !self.config.is_enabled() || entry_price <= 0.0 {
+            return Ok(None);
+        }
+
+        let (exit_slippage_bps, sell_tax_bps) = self.exit_costs(token_mint).await?;
+        let mut levels = ExitLevels {
+            position_id,
+            token_mint: token_mint.to_string(),
+            entry_price,
+            take_profit_price: None,
+            stop_loss_price: None,
+            exit_slippage_bps,
+            sell_tax_bps,
+            fee_rate,
+            created_at: Utc::now().timestamp(),
+            triggered: None,
+            triggered_price: None,
+            triggered_at: None,
+        };
+        let ratio = levels.exit_value_ratio();
+        if ratio <= 0.0 {
+            return Ok(None);
+        }
+        if self.config.take_profit_pct > 0.0 {
+            levels.take_profit_price = Some(entry_price * (1.0 + self.config.take_profit_pct) / ratio);
+        }
+        if self.config.stop_loss_pct > 0.0 {
+            let stop = entry_price * (1.0 - self.config.stop_loss_pct).max(0.0) / ratio;
+            // Exit costs alone exceed the allowed loss: the stop would fire on entry
+            if stop < entry_price {
+                levels.stop_loss_price = Some(stop);
+            } else {
+                warn!("Stop loss of position #{} dropped: exit costs of {:.0} bps exceed the {:.1}% stop",
+                    position_id, exit_slippage_bps + sell_tax_bps, self.config.stop_loss_pct * 100.0);
+            }
+        }
+
+        sqlx::query(r#"
+            INSERT OR REPLACE INTO exit_levels
+                (position_id, token_mint, entry_price, take_profit_price, stop_loss_price,
+                 exit_slippage_bps, sell_tax_bps, fee_rate, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(levels.position_id)
+        .bind(&levels.token_mint)
+        .bind(levels.entry_price)
+        .bind(levels.take_profit_price)
+        .bind(levels.stop_loss_price)
+        .bind(levels.exit_slippage_bps)
+        .bind(levels.sell_tax_bps)
+        .bind(levels.fee_rate)
+        .bind(levels.created_at)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to save exit levels: {}", e)))?;
+
+        debug!("🎯 Exit levels of position #{}: TP {:?} SL {:?} (slippage {:.0} bps, tax {:.0} bps)",
+            position_id, levels.take_profit_price, levels.stop_loss_price, exit_slippage_bps, sell_tax_bps);
+        Ok(Some(levels))
+    }
+
+    /// Untriggered levels of positions that are still open
+    pub async fn open_levels(&self) -> Result<Vec<ExitLevels>, DatabaseError> {
+        sqlx::query_as::<_, ExitLevels>(r#"
+            SELECT l.* FROM exit_levels l
+            JOIN positions p ON p.id = l.position_id
+            WHERE p.status = 'OPEN' AND l.triggered IS NULL
+        "#)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch exit levels: {}", e)))
+    }
+
+    /// Levels of one position
+    pub async fn levels_for(&self, position_id: i64) -> Result<Option<ExitLevels>, DatabaseError> {
+        sqlx::query_as::<_, ExitLevels>("SELECT * FROM exit_levels WHERE position_id = ?")
+            .bind(position_id)
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch exit levels: {}", e)))
+    }
+
+    /// Record that a level was hit so it does not fire again
+    pub async fn mark_triggered(&self, position_id: i64, kind: &str, price: f64) -> Result<(), DatabaseError> {
+        sqlx::query("UPDATE exit_levels SET triggered = ?, triggered_price = ?, triggered_at = ? WHERE position_id = ?")
+            .bind(kind)
+            .bind(price)
+            .bind(Utc::now().timestamp())
+            .bind(position_id)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to mark exit level triggered: {}", e)))?;
+        Ok(())
+    }
+
+    /// Expected exit slippage and the token's sell tax (basis points)
+    async fn exit_costs(&self, token_mint: &str) -> Result<(f64, f64), DatabaseError> {
+        let since = Utc::now().timestamp() - self.config.measurement_window_secs;
+        let query = r#"
+            SELECT COUNT(*) AS sells, AVG(adverse_bps) AS avg_adverse_bps
+            FROM fill_checks
+            WHERE side = 'SELL' AND verdict IN (?, ?) AND executed_at >= ? AND (? IS NULL OR token_mint = ?)
+        "#;
+        let measure = |token_mint: Option<&str>| {
+            sqlx::query_as::<_, SellCost>(query)
+                .bind(FILL_OK)
+                .bind(FILL_BAD)
+                .bind(since)
+                .bind(token_mint.map(str::to_string))
+                .bind(token_mint.map(str::to_string))
+                .fetch_one(self.db.get_pool())
+        };
+
+        let overall = measure(None).await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to measure exit slippage: {}", e)))?;
+        let exit_slippage_bps = match overall.avg_adverse_bps {
+            Some(bps) if overall.sells >= self.config.min_measured_sells => bps.max(0.0),
+            _ => self.config.default_exit_slippage_bps,
+        };
+
+        let token = measure(Some(token_mint)).await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to measure sell tax: {}", e)))?;
+        let sell_tax_bps = token.avg_adverse_bps
+            .map_or(0.0, |bps| (bps - exit_slippage_bps).max(0.0));
+
+        Ok((exit_slippage_bps, sell_tax_bps))
+    }
+}
@@ -0,0 +1,328 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use chrono::Utc;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tokio::sync::RwLock;
+use tracing::{debug, info, instrument};
+
+use crate::core::{MarketEvent, SwapType};
+use crate::database::{BadgerDatabase, DatabaseError};
+use crate::database::audit_log::{
+    audit_value, record_audit, AuditContext, AUDIT_ACTION_CLEAR, AUDIT_ACTION_FLAG, AUDIT_ENTITY_TOXIC_WALLET,
+};
+use super::PositionTracker;
+
+/// Toxic wallet bought a token we don't hold: don't buy it
+pub const FADE_ACTION_AVOID: &str = "AVOID";
+/// Toxic wallet bought a token we hold: flag the position for exit
+pub const FADE_ACTION_SELL_FLAG: &str = "SELL_FLAG";
+
+/// Configuration for fade mode
+#[derive(Debug, Clone)]
+pub struct FadeConfig {
+    /// Emit inverse signals (block buys, exit held positions) instead of only recording them
+    pub emit_signals: bool,
+    /// Trades needed before a wallet can be classified as toxic
+    pub min_trades: i64,
+    /// Success rate at or below which a wallet is toxic (0.0 - 1.0)
+    pub max_success_rate: f64,
+    /// Total P&L below which a wallet is toxic
+    pub max_total_pnl: f64,
+    /// How long an avoid flag blocks buys of the token (seconds)
+    pub avoid_window_secs: i64,
+}
+
+impl Default for FadeConfig {
+    fn default() -> Self {
+        Self {
+            emit_signals: false,
+            min_trades: 10,
+            max_success_rate: 0.3,
+            max_total_pnl: 0.0,
+            avoid_window_secs: 1800,
+        }
+    }
+}
+
+/// Wallet that consistently loses money
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ToxicWallet {
+    pub wallet_address: String,
+    pub total_trades: i64,
+    pub success_rate: f64,
+    pub total_pnl: f64,
+    pub flagged_at: i64,
+    pub updated_at: i64,
+}
+
+/// Inverse signal derived from a toxic wallet's buy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FadeSignal {
+    pub toxic_wallet: String,
+    pub token_mint: String,
+    pub action: String, // "AVOID", "SELL_FLAG"
+    /// Our open position in the token, for sell flags
+    pub position_id: Option<i64>,
+    pub created_at: i64,
+}
+
+/// Fade track summary
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FadePerformance {
+    pub toxic_wallets: i64,
+    pub avoid_flags: i64,
+    pub sell_flags: i64,
+    /// Realized P&L of positions that were later sold after a sell flag
+    pub flagged_position_pnl: f64,
+}
+
+/// Tracks anti-insiders and turns their buys into avoid/exit flags
+///
+/// Wallets are classified from the same insider profiles used to pick
+/// winners, with separate thresholds. Flags are always recorded in
+/// `fade_signals`; they only affect trading when `emit_signals` is set.
+pub struct FadeTracker {
+    db: Arc<BadgerDatabase>,
+    position_tracker: Arc<PositionTracker>,
+    config: FadeConfig,
+    toxic: RwLock<HashSet<String>>,
+    /// Token mint -> time of the latest avoid flag
+    avoided: DashMap<String, i64>,
+}
+
+impl FadeTracker {
+    pub fn new(db: Arc<BadgerDatabase>, position_tracker: Arc<PositionTracker>, config: Option<FadeConfig>) -> Self {
+        Self {
+            db,
+            position_tracker,
+            config: config.unwrap_or_default(),
+            toxic: RwLock::new(HashSet::new()),
+            avoided: DashMap::new(),
+        }
+    }
+
+    pub fn config(&self) -> &FadeConfig {
+        &self.config
+    }
+
+    /// Initialize fade tracking schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing fade tracker schema");
+
+        let create_toxic_wallets = r#"
+            CREATE TABLE IF NOT EXISTS toxic_wallets (
+                wallet_address TEXT PRIMARY KEY,
+                total_trades INTEGER NOT NULL,
+                success_rate REAL NOT NULL,
+                total_pnl REAL NOT NULL,
+                flagged_at INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL
+            )
+        "#;
+
+        let create_fade_signals = r#"
+            CREATE TABLE IF NOT EXISTS fade_signals (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                toxic_wallet TEXT NOT NULL,
+                token_mint TEXT NOT NULL,
+                action TEXT NOT NULL CHECK (action IN ('AVOID', 'SELL_FLAG')),
+                position_id INTEGER,
+                emitted BOOLEAN NOT NULL DEFAULT 0,
+                created_at INTEGER NOT NULL
+            )
+        "#;
+
+        for table_sql in [create_toxic_wallets, create_fade_signals] {
+            sqlx::query(table_sql)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create fade table: {}", e)))?;
+        }
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_fade_signals_created ON fade_signals(created_at)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        info!("✅ Fade tracker schema initialized");
+        Ok(())
+    }
+
+    /// Reclassify wallets from insider profiles
+    ///
+    /// # Returns
+    /// * `Result<usize, DatabaseError>` - Number of toxic wallets
+    pub async fn refresh_toxic_wallets(&self) -> Result<usize, DatabaseError> {
+        let now = Utc::now().timestamp();
+        let mut tx = self.db.begin_transaction().await?;
+
+        let before: HashMap<String, ToxicWallet> = sqlx::query_as::<_, ToxicWallet>("SELECT * FROM toxic_wallets")
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to load toxic wallets: {}", e)))?
+            .into_iter()
+            .map(|wallet| (wallet.wallet_address.clone(), wallet))
+            .collect();
+
+        // Wallets that recovered are dropped; flagged_at survives for those still toxic
+        sqlx::query(r#"
+            DELETE FROM toxic_wallets WHERE wallet_address NOT IN (
+                SELECT wallet_address FROM insider_profiles
+                WHERE total_trades >= ? AND success_rate <= ? AND total_pnl < ?
+            )
+        "#)
+        .bind(self.config.min_trades)
+        .bind(self.config.max_success_rate)
+        .bind(self.config.max_total_pnl)
+        .execute(&mut tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to drop recovered wallets: {}", e)))?;
+
+        sqlx::query(r#"
+            INSERT INTO toxic_wallets (wallet_address, total_trades, success_rate, total_pnl, flagged_at, updated_at)
+            SELECT wallet_address, total_trades, success_rate, total_pnl, ?, ?
+            FROM insider_profiles
+            WHERE total_trades >= ? AND success_rate <= ? AND total_pnl < ?
+            ON CONFLICT(wallet_address) DO UPDATE SET
+                total_trades = excluded.total_trades,
+                success_rate = excluded.success_rate,
+                total_pnl = excluded.total_pnl,
+                updated_at = excluded.updated_at
+        "#)
+        .bind(now)
+        .bind(now)
+        .bind(self.config.min_trades)
+        .bind(self.config.max_success_rate)
+        .bind(self.config.max_total_pnl)
+        .execute(&mut tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to classify toxic wallets: {}", e)))?;
+
+        let after = sqlx::query_as::<_, ToxicWallet>("SELECT * FROM toxic_wallets")
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to load toxic wallets: {}", e)))?;
+
+        // Only blacklist membership changes are audited, not the refreshed stats
+        for wallet in after.iter().filter(|wallet| !before.contains_key(&wallet.wallet_address)) {
+            let context = AuditContext::service("fade_tracker", format!(
+                "{} trades, {:.0}% success, {:.4} total P&L",
+                wallet.total_trades, wallet.success_rate * 100.0, wallet.total_pnl,
+            ));
+            record_audit(&mut *tx, &context, AUDIT_ENTITY_TOXIC_WALLET, &wallet.wallet_address,
+                AUDIT_ACTION_FLAG, None, audit_value(wallet)).await?;
+        }
+        let still_toxic: HashSet<&str> = after.iter().map(|wallet| wallet.wallet_address.as_str()).collect();
+        for wallet in before.values().filter(|wallet| !still_toxic.contains(wallet.wallet_address.as_str())) {
+            let context = AuditContext::service("fade_tracker", "No longer meets the toxic wallet thresholds");
+            record_audit(&mut *tx, &context, AUDIT_ENTITY_TOXIC_WALLET, &wallet.wallet_address,
+                AUDIT_ACTION_CLEAR, audit_value(wallet), None).await?;
+        }
+
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit toxic wallets: {}", e)))?;
+
+        let mut toxic = self.toxic.write().await;
+        *toxic = after.into_iter().map(|wallet| wallet.wallet_address).collect();
+        Ok(toxic.len())
+    }
+
+    pub async fn is_toxic(&self, wallet_address: &str) -> bool {
+        self.toxic.read().await.contains(wallet_address)
+    }
+
+    /// Turn a toxic wallet's buy into an avoid or sell flag
+    pub async fn evaluate(&self, event: &MarketEvent) -> Result<Option<FadeSignal>, DatabaseError> {
+        let MarketEvent::SwapDetected { swap } = event else {
+            return Ok(None);
+        };
+        if swap.swap_type != SwapType::Buy || !self.is_toxic(&swap.wallet).await {
+            return Ok(None);
+        }
+
+        let held = self.position_tracker.get_open_positions().await?
+            .into_iter()
+            .find(|p| p.token_mint == swap.token_out);
+        let now = Utc::now().timestamp();
+
+        let signal = FadeSignal {
+            toxic_wallet: swap.wallet.clone(),
+            token_mint: swap.token_out.clone(),
+            action: if held.is_some() { FADE_ACTION_SELL_FLAG } else { FADE_ACTION_AVOID }.to_string(),
+            position_id: held.map(|p| p.id),
+            created_at: now,
+        };
+
+        sqlx::query(r#"
+            INSERT INTO fade_signals (toxic_wallet, token_mint, action, position_id, emitted, created_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(&signal.toxic_wallet)
+        .bind(&signal.token_mint)
+        .bind(&signal.action)
+        .bind(signal.position_id)
+        .bind(self.config.emit_signals)
+        .bind(now)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record fade signal: {}", e)))?;
+
+        if signal.action == FADE_ACTION_AVOID {
+            self.avoided.insert(signal.token_mint.clone(), now);
+        }
+
+        debug!("🙃 Fade {}: toxic wallet {} bought {}", signal.action, signal.toxic_wallet, signal.token_mint);
+        Ok(Some(signal))
+    }
+
+    /// Whether buys of a token are blocked by a recent avoid flag (only when emitting)
+    pub fn avoids(&self, token_mint: &str) -> bool {
+        if !self.config.emit_signals {
+            return false;
+        }
+        let cutoff = Utc::now().timestamp() - self.config.avoid_window_secs;
+        self.avoided.get(token_mint).is_some_and(|flagged_at| *flagged_at >= cutoff)
+    }
+
+    /// Drop avoid flags older than the window
+    pub fn prune_expired(&self) {
+        let cutoff = Utc::now().timestamp() - self.config.avoid_window_secs;
+        self.avoided.retain(|_, flagged_at| *flagged_at >= cutoff);
+    }
+
+    /// Summary of the fade track
+    pub async fn get_fade_performance(&self) -> Result<FadePerformance, DatabaseError> {
+        let (toxic_wallets, avoid_flags, sell_flags, flagged_position_pnl) = sqlx::query_as::<_, (i64, i64, i64, f64)>(r#"
+            SELECT
+                (SELECT COUNT(*) FROM toxic_wallets),
+                (SELECT COUNT(*) FROM fade_signals WHERE action = 'AVOID'),
+                (SELECT COUNT(*) FROM fade_signals WHERE action = 'SELL_FLAG'),
+                (SELECT COALESCE(SUM(p.pnl), 0.0) FROM positions p
+                 WHERE p.status = 'CLOSED' AND p.id IN (
+                     SELECT position_id FROM fade_signals WHERE action = 'SELL_FLAG' AND position_id IS NOT NULL
+                 ))
+        "#)
+        .fetch_one(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch fade performance: {}", e)))?;
+
+        Ok(FadePerformance {
+            toxic_wallets,
+            avoid_flags,
+            sell_flags,
+            flagged_position_pnl,
+        })
+    }
+
+    /// Toxic wallets, worst P&L first
+    pub async fn get_toxic_wallets(&self, limit: i64) -> Result<Vec<ToxicWallet>, DatabaseError> {
+        sqlx::query_as::<_, ToxicWallet>("SELECT * FROM toxic_wallets ORDER BY total_pnl ASC LIMIT ?")
+            .bind(limit)
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch toxic wallets: {}", e)))
+    }
+}
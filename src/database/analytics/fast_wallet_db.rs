@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use memmap2::Mmap;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use super::insider_analytics::InsiderProfile;
+use super::super::DatabaseError;
+
+/// Bytes of the length prefix in front of every record
+const FRAME_HEADER: usize = 4;
+
+/// One append to the store; a missing profile removes the wallet
+#[derive(Debug, Serialize, Deserialize)]
+struct WalletRecord {
+    wallet_address: String,
+    profile: Option<InsiderProfile>,
+}
+
+struct StoreFile {
+    file: File,
+    /// Records in the file, superseded ones and removals included
+    records: usize,
+}
+
+/// Memory-mapped hot wallet store behind the insider cache
+///
+/// An append-only file of length-prefixed bincode records, one per profile
+/// write. Warming the cache after a restart maps the file once and decodes it
+/// in order (later records win) instead of scanning `insider_profiles`.
+/// SQLite stays the source of truth: the store is only a warm-up copy, and
+/// `InsiderAnalytics::reconcile_wallet_store` corrects it in the background.
+pub struct UltraFastWalletDB {
+    path: PathBuf,
+    inner: Mutex<StoreFile>,
+}
+
+impl UltraFastWalletDB {
+    /// Open (or create) the store file
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, DatabaseError> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| DatabaseError::InitializationError(format!("Failed to create {}: {}", parent.display(), e)))?;
+        }
+        let file = Self::open_append(&path)?;
+        Ok(Self {
+            path,
+            inner: Mutex::new(StoreFile { file, records: 0 }),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Map the file and decode every live profile
+    ///
+    /// A record torn by a crash mid-append ends the load and is cut off so
+    /// later appends start on a frame boundary.
+    pub fn load(&self) -> Result<HashMap<String, InsiderProfile>, DatabaseError> {
+        let mut inner = self.inner.lock().unwrap();
+        let file = File::open(&self.path)
+            .map_err(|e| DatabaseError::InitializationError(format!("Failed to open {}: {}", self.path.display(), e)))?;
+        let len = file.metadata()
+            .map_err(|e| DatabaseError::InitializationError(format!("Failed to stat {}: {}", self.path.display(), e)))?
+            .len() as usize;
+
+        let mut profiles = HashMap::new();
+        let mut records = 0;
+        let mut offset = 0;
+        if len > 0 {
+            // Safety: the file is only appended to through this store, under the lock held here
+            let map = unsafe { Mmap::map(&file) }
+                .map_err(|e| DatabaseError::InitializationError(format!("Failed to map {}: {}", self.path.display(), e)))?;
+
+            while offset + FRAME_HEADER <= len {
+                let size = u32::from_le_bytes(map[offset..offset + FRAME_HEADER].try_into().unwrap()) as usize;
+                let end = offset + FRAME_HEADER + size;
+                if end > len {
+                    break;
+                }
+                let Ok(record) = bincode::deserialize::<WalletRecord>(&map[offset + FRAME_HEADER..end]) else {
+                    break;
+                };
+                match record.profile {
+                    Some(profile) => profiles.insert(record.wallet_address, profile),
+                    None => profiles.remove(&record.wallet_address),
+                };
+                records += 1;
+                offset = end;
+            }
+        }
+
+        if offset < len {
+            warn!("Hot wallet store {} has {} unreadable trailing bytes, truncating", self.path.display(), len - offset);
+            inner.file.set_len(offset as u64)
+                .map_err(|e| DatabaseError::InitializationError(format!("Failed to truncate {}: {}", self.path.display(), e)))?;
+        }
+        inner.records = records;
+
+        debug!("Loaded {} wallet profiles from {} records in {}", profiles.len(), records, self.path.display());
+        Ok(profiles)
+    }
+
+    /// Append a profile, replacing the wallet's previous one
+    pub fn put(&self, profile: &InsiderProfile) -> Result<(), DatabaseError> {
+        self.append(&WalletRecord {
+            wallet_address: profile.wallet_address.clone(),
+            profile: Some(profile.clone()),
+        })
+    }
+
+    /// Append a removal of a wallet
+    pub fn remove(&self, wallet_address: &str) -> Result<(), DatabaseError> {
+        self.append(&WalletRecord {
+            wallet_address: wallet_address.to_string(),
+            profile: None,
+        })
+    }
+
+    /// Whether superseded records outnumber the `live` profiles enough to rewrite the file
+    pub fn needs_compaction(&self, live: usize) -> bool {
+        let inner = self.inner.lock().unwrap();
+        inner.records > 1024 && inner.records > live * 2
+    }
+
+    /// Rewrite the file with only the given profiles
+    ///
+    /// Written to a temporary file and renamed over the store, so a crash
+    /// leaves either the old or the new file.
+    pub fn compact<'a>(&self, profiles: impl IntoIterator<Item = &'a InsiderProfile>) -> Result<usize, DatabaseError> {
+        let mut inner = self.inner.lock().unwrap();
+        let tmp_path = self.path.with_extension("compact");
+        let mut buffer = Vec::new();
+        let mut written = 0;
+        for profile in profiles {
+            encode_frame(&mut buffer, &WalletRecord {
+                wallet_address: profile.wallet_address.clone(),
+                profile: Some(profile.clone()),
+            })?;
+            written += 1;
+        }
+
+        let mut tmp = File::create(&tmp_path)
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create {}: {}", tmp_path.display(), e)))?;
+        tmp.write_all(&buffer)
+            .and_then(|_| tmp.sync_all())
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to write {}: {}", tmp_path.display(), e)))?;
+        std::fs::rename(&tmp_path, &self.path)
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to replace {}: {}", self.path.display(), e)))?;
+
+        inner.file = Self::open_append(&self.path)?;
+        info!("🗜️ Compacted hot wallet store from {} to {} records", inner.records, written);
+        inner.records = written;
+        Ok(written)
+    }
+
+    fn append(&self, record: &WalletRecord) -> Result<(), DatabaseError> {
+        let mut frame = Vec::new();
+        encode_frame(&mut frame, record)?;
+        let mut inner = self.inner.lock().unwrap();
+        // One write per frame so a crash tears at most the last record
+        inner.file.write_all(&frame)
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to append to {}: {}", self.path.display(), e)))?;
+        inner.records += 1;
+        Ok(())
+    }
+
+    fn open_append(path: &Path) -> Result<File, DatabaseError> {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| DatabaseError::InitializationError(format!("Failed to open {}: {}", path.display(), e)))
+    }
+}
+
+fn encode_frame(buffer: &mut Vec<u8>, record: &WalletRecord) -> Result<(), DatabaseError> {
+    let bytes = bincode::serialize(record)
+        .map_err(|e| DatabaseError::SerializationError(format!("Failed to encode wallet record: {}", e)))?;
+    buffer.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    buffer.extend_from_slice(&bytes);
+    Ok(())
+}
@@ -0,0 +1,340 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{info, instrument};
+
+use super::super::{BadgerDatabase, ColdStore, DatabaseError};
+use super::PriceCandleStore;
+
+/// Fill not yet compared against the oracle
+pub const FILL_PENDING: &str = "PENDING";
+/// Executed price within the deviation band of the oracle
+pub const FILL_OK: &str = "OK";
+/// Executed price worse than the oracle by more than the band: suspected bad fill
+pub const FILL_BAD: &str = "BAD_FILL";
+/// Executed price better than the oracle by more than the band: suspected data error
+pub const FILL_DATA_ERROR: &str = "DATA_ERROR";
+/// No oracle price was found near the fill time
+pub const FILL_UNVERIFIED: &str = "UNVERIFIED";
+
+/// Configuration for cross-checking fills against an independent price
+#[derive(Debug, Clone)]
+pub struct FillCheckConfig {
+    /// Deviation from the oracle price beyond which a fill is flagged (basis points)
+    pub max_deviation_bps: f64,
+    /// A live oracle quote counts as "at the fill" when taken within this long of it (seconds)
+    pub max_oracle_skew_secs: i64,
+    /// How often pending fills are checked (seconds)
+    pub check_interval_secs: u64,
+    /// Fills without an oracle price after this long are marked unverified (seconds)
+    pub give_up_after_secs: i64,
+}
+
+impl Default for FillCheckConfig {
+    fn default() -> Self {
+        Self {
+            max_deviation_bps: 500.0,
+            max_oracle_skew_secs: 30,
+            check_interval_secs: 5,
+            give_up_after_secs: 900,
+        }
+    }
+}
+
+impl FillCheckConfig {
+    /// Defaults with the deviation band overridden by `BADGER_FILL_DEVIATION_BPS`
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(bps) = std::env::var("BADGER_FILL_DEVIATION_BPS").ok().and_then(|v| v.parse().ok()) {
+            config.max_deviation_bps = bps;
+        }
+        config
+    }
+}
+
+/// One fill and how its price compared to the oracle
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct FillCheck {
+    pub id: i64,
+    pub position_id: i64,
+    pub token_mint: String,
+    /// "BUY" or "SELL"
+    pub side: String,
+    /// Executed price (SOL per token)
+    pub executed_price: f64,
+    pub executed_at: i64,
+    pub oracle_price: Option<f64>,
+    /// Market data provider, or "candle" for the price candle covering the fill
+    pub oracle_source: Option<String>,
+    pub oracle_at: Option<i64>,
+    /// How much worse than the oracle the fill was; negative when better (basis points)
+    pub adverse_bps: Option<f64>,
+    pub verdict: String,
+}
+
+/// Fill quality over a period
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct FillQualitySummary {
+    pub checked: i64,
+    pub bad_fills: i64,
+    pub data_errors: i64,
+    pub unverified: i64,
+    /// Mean adverse deviation of checked fills (basis points)
+    pub avg_adverse_bps: Option<f64>,
+}
+
+/// Cross-checks executed prices against an independent price source
+///
+/// Every fill is queued with its executed price; the market data service
+/// then prices the token from a provider that played no part in the fill
+/// and compares. A quote taken within the skew window counts as the price
+/// at the fill; later, the price candle covering the fill time stands in.
+/// A fill much worse than the oracle is a suspected bad fill (sandwich,
+/// stale quote, slippage), one much better a suspected data error. Trusting
+/// the executor's own price alone hides systematic execution problems.
+pub struct FillQualityMonitor {
+    db: Arc<BadgerDatabase>,
+    config: FillCheckConfig,
+    /// Resolved checks moved past the hot window, counted into summaries
+    cold_store: Option<Arc<ColdStore>>,
+}
+
+impl FillQualityMonitor {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<FillCheckConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+            cold_store: None,
+        }
+    }
+
+    /// Count checks older than the hot window from the cold store into summaries
+    pub fn with_cold_store(mut self, cold_store: Arc<ColdStore>) -> Self {
+        self.cold_store = Some(cold_store);
+        self
+    }
+
+    pub fn config(&self) -> &FillCheckConfig {
+        &self.config
+    }
+
+    /// Initialize fill check schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing fill check schema");
+
+        let create_fill_checks = r#"
+            CREATE TABLE IF NOT EXISTS fill_checks (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                position_id INTEGER NOT NULL,
+                token_mint TEXT NOT NULL,
+                side TEXT NOT NULL,
+                executed_price REAL NOT NULL,
+                executed_at INTEGER NOT NULL,
+                oracle_price REAL,
+                oracle_source TEXT,
+                oracle_at INTEGER,
+                adverse_bps REAL,
+                verdict TEXT NOT NULL DEFAULT 'PENDING'
+            )
+        "#;
+
+        sqlx::query(create_fill_checks)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create fill_checks table: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_fill_checks_verdict ON fill_checks(verdict, executed_at)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create fill_checks index: {}", e)))?;
+
+        info!("✅ Fill check schema initialized");
+        Ok(())
+    }
+
+    /// Queue a fill for the oracle cross-check
+    pub async fn record_fill(
+        &self,
+        position_id: i64,
+        token_mint: &str,
+        side: &str,
+        executed_price: f64,
+        executed_at: i64,
+    ) -> Result<i64, DatabaseError> {
+        let result = sqlx::query(r#"
+            INSERT INTO fill_checks (position_id, token_mint, side, executed_price, executed_at)
+            VALUES (?, ?, ?, ?, ?)
+        "#)
+        .bind(position_id)
+        .bind(token_mint)
+        .bind(side)
+        .bind(executed_price)
+        .bind(executed_at)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record fill check: {}", e)))?;
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Fills still waiting for an oracle price, oldest first
+    pub async fn pending(&self) -> Result<Vec<FillCheck>, DatabaseError> {
+        sqlx::query_as::<_, FillCheck>("SELECT * FROM fill_checks WHERE verdict = ? ORDER BY executed_at")
+            .bind(FILL_PENDING)
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch pending fill checks: {}", e)))
+    }
+
+    /// Whether a quote taken at `quoted_at` is close enough to the fill to compare against
+    pub fn within_skew(&self, fill: &FillCheck, quoted_at: i64) -> bool {
+        (quoted_at - fill.executed_at).abs() <= self.config.max_oracle_skew_secs
+    }
+
+    /// Compare a fill against an oracle price and store the verdict
+    pub async fn resolve(
+        &self,
+        fill: &FillCheck,
+        oracle_price: f64,
+        oracle_source: &str,
+        oracle_at: i64,
+    ) -> Result<FillCheck, DatabaseError> {
+        let adverse_bps = adverse_bps(&fill.side, fill.executed_price, oracle_price);
+        let verdict = match adverse_bps {
+            Some(bps) if bps > self.config.max_deviation_bps => FILL_BAD,
+            Some(bps) if bps < -self.config.max_deviation_bps => FILL_DATA_ERROR,
+            Some(_) => FILL_OK,
+            None => FILL_UNVERIFIED,
+        };
+
+        sqlx::query(r#"
+            UPDATE fill_checks
+            SET oracle_price = ?, oracle_source = ?, oracle_at = ?, adverse_bps = ?, verdict = ?
+            WHERE id = ?
+        "#)
+        .bind(oracle_price)
+        .bind(oracle_source)
+        .bind(oracle_at)
+        .bind(adverse_bps)
+        .bind(verdict)
+        .bind(fill.id)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to resolve fill check: {}", e)))?;
+
+        Ok(FillCheck {
+            oracle_price: Some(oracle_price),
+            oracle_source: Some(oracle_source.to_string()),
+            oracle_at: Some(oracle_at),
+            adverse_bps,
+            verdict: verdict.to_string(),
+            ..fill.clone()
+        })
+    }
+
+    /// Resolve a fill the live quote came too late for from the candle covering it
+    ///
+    /// # Returns
+    /// * `Result<Option<FillCheck>, DatabaseError>` - None while there is no candle
+    ///   and the fill is not yet past the give-up age
+    pub async fn resolve_from_candles(
+        &self,
+        fill: &FillCheck,
+        candles: &PriceCandleStore,
+    ) -> Result<Option<FillCheck>, DatabaseError> {
+        let candle_secs = candles.config().candle_secs;
+        let bucket_start = fill.executed_at - fill.executed_at.rem_euclid(candle_secs);
+        let candle = candles.candles(&fill.token_mint, bucket_start).await?
+            .into_iter()
+            .find(|c| c.bucket_start == bucket_start);
+        if let Some(candle) = candle {
+            return self.resolve(fill, candle.close, "candle", candle.bucket_start).await.map(Some);
+        }
+
+        if Utc::now().timestamp() - fill.executed_at < self.config.give_up_after_secs {
+            return Ok(None);
+        }
+        sqlx::query("UPDATE fill_checks SET verdict = ? WHERE id = ?")
+            .bind(FILL_UNVERIFIED)
+            .bind(fill.id)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to mark fill check unverified: {}", e)))?;
+        Ok(Some(FillCheck { verdict: FILL_UNVERIFIED.to_string(), ..fill.clone() }))
+    }
+
+    /// Fill quality of fills executed at or after `since`, cold-stored ones included
+    pub async fn summary(&self, since: i64) -> Result<FillQualitySummary, DatabaseError> {
+        let mut summary = sqlx::query_as::<_, FillQualitySummary>(r#"
+            SELECT
+                COALESCE(SUM(CASE WHEN verdict IN ('OK', 'BAD_FILL', 'DATA_ERROR') THEN 1 ELSE 0 END), 0) AS checked,
+                COALESCE(SUM(CASE WHEN verdict = 'BAD_FILL' THEN 1 ELSE 0 END), 0) AS bad_fills,
+                COALESCE(SUM(CASE WHEN verdict = 'DATA_ERROR' THEN 1 ELSE 0 END), 0) AS data_errors,
+                COALESCE(SUM(CASE WHEN verdict = 'UNVERIFIED' THEN 1 ELSE 0 END), 0) AS unverified,
+                AVG(adverse_bps) AS avg_adverse_bps
+            FROM fill_checks
+            WHERE executed_at >= ?
+        "#)
+        .bind(since)
+        .fetch_one(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to summarize fill checks: {}", e)))?;
+
+        let Some(cold_store) = &self.cold_store else { return Ok(summary) };
+        if since >= cold_store.cutoff(Utc::now().timestamp()) {
+            return Ok(summary);
+        }
+        let cold: Vec<FillCheck> = cold_store.read("fill_checks", since, Utc::now().timestamp()).await?;
+        if cold.is_empty() {
+            return Ok(summary);
+        }
+        let hot_adverse: i64 = sqlx::query_scalar("SELECT COUNT(adverse_bps) FROM fill_checks WHERE executed_at >= ?")
+            .bind(since)
+            .fetch_one(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to count checked fills: {}", e)))?;
+
+        let mut adverse_sum = summary.avg_adverse_bps.unwrap_or_default() * hot_adverse as f64;
+        let mut adverse_count = hot_adverse;
+        for fill in &cold {
+            match fill.verdict.as_str() {
+                FILL_OK => summary.checked += 1,
+                FILL_BAD => { summary.checked += 1; summary.bad_fills += 1; }
+                FILL_DATA_ERROR => { summary.checked += 1; summary.data_errors += 1; }
+                FILL_UNVERIFIED => summary.unverified += 1,
+                _ => {}
+            }
+            if let Some(bps) = fill.adverse_bps {
+                adverse_sum += bps;
+                adverse_count += 1;
+            }
+        }
+        summary.avg_adverse_bps = (adverse_count > 0).then(|| adverse_sum / adverse_count as f64);
+        Ok(summary)
+    }
+
+    /// Flagged fills within the hot window, most recent first
+    pub async fn flagged(&self, limit: i64) -> Result<Vec<FillCheck>, DatabaseError> {
+        sqlx::query_as::<_, FillCheck>(
+            "SELECT * FROM fill_checks WHERE verdict IN (?, ?) ORDER BY executed_at DESC LIMIT ?"
+        )
+        .bind(FILL_BAD)
+        .bind(FILL_DATA_ERROR)
+        .bind(limit)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch flagged fills: {}", e)))
+    }
+}
+
+/// How much worse than the oracle a fill was (basis points)
+///
+/// Paying more than the oracle on a buy, or receiving less on a sell, is adverse.
+fn adverse_bps(side: &str, executed_price: f64, oracle_price: f64) -> Option<f64> {
+    if !(oracle_price > 0.0 && executed_price > 0.0) {
+        return None;
+    }
+    let deviation = (executed_price - oracle_price) / oracle_price * 10_000.0;
+    Some(if side == "BUY" { deviation } else { -deviation })
+}
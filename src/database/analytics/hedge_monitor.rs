@@ -0,0 +1,217 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tracing::{debug, info, instrument};
+
+use crate::core::{HedgeDirection, TradingSignal};
+use crate::database::DatabaseError;
+use super::{PositionTracker, PnLCalculator, VolatilityTracker};
+
+/// Configuration for exposure-based hedging
+#[derive(Debug, Clone)]
+pub struct HedgeConfig {
+    /// Aggregate open exposure (SOL) above which a hedge is recommended
+    pub exposure_threshold_sol: f64,
+    /// Fraction of total exposure to hedge once above the threshold
+    pub hedge_ratio: f64,
+    /// Smallest hedge adjustment worth emitting (SOL)
+    pub min_adjustment_sol: f64,
+    /// Perp instrument used for the hedge
+    pub instrument: String,
+    /// How often exposure is re-evaluated (seconds)
+    pub check_interval_secs: u64,
+}
+
+impl Default for HedgeConfig {
+    fn default() -> Self {
+        Self {
+            exposure_threshold_sol: 10.0,
+            hedge_ratio: 0.5,
+            min_adjustment_sol: 1.0,
+            instrument: "SOL-PERP".to_string(),
+            check_interval_secs: 60,
+        }
+    }
+}
+
+/// Hedge adjustment derived from the current book
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HedgeRecommendation {
+    pub instrument: String,
+    pub direction: HedgeDirection,
+    pub size_sol: f64,
+    /// Open exposure, weighted by each token's beta to SOL when betas are known
+    pub exposure_sol: f64,
+    pub target_hedge_sol: f64,
+    pub current_hedge_sol: f64,
+}
+
+impl HedgeRecommendation {
+    /// Convert into a trading signal for the transport bus
+    pub fn to_signal(&self) -> TradingSignal {
+        TradingSignal::Hedge {
+            instrument: self.instrument.clone(),
+            direction: self.direction,
+            size_sol: self.size_sol,
+            exposure_sol: self.exposure_sol,
+            reason: format!(
+                "Open exposure {:.3} SOL; target hedge {:.3} SOL (currently {:.3} SOL)",
+                self.exposure_sol, self.target_hedge_sol, self.current_hedge_sol
+            ),
+        }
+    }
+}
+
+/// Pluggable execution venue for hedge recommendations (e.g. a Drift adapter)
+pub trait HedgingAdapter: Send + Sync {
+    /// Venue name for logging
+    fn name(&self) -> &str;
+
+    /// Place the hedge; returns a venue reference on success
+    fn submit_hedge<'a>(
+        &'a self,
+        recommendation: &'a HedgeRecommendation,
+    ) -> Pin<Box<dyn Future<Output = anyhow::Result<String>> + Send + 'a>>;
+}
+
+/// Adapter that forwards recommendations as JSON to an HTTP endpoint
+pub struct WebhookHedgingAdapter {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl WebhookHedgingAdapter {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+impl HedgingAdapter for WebhookHedgingAdapter {
+    fn name(&self) -> &str {
+        "webhook"
+    }
+
+    fn submit_hedge<'a>(
+        &'a self,
+        recommendation: &'a HedgeRecommendation,
+    ) -> Pin<Box<dyn Future<Output = anyhow::Result<String>> + Send + 'a>> {
+        Box::pin(async move {
+            let response = self.client
+                .post(&self.url)
+                .json(recommendation)
+                .send()
+                .await?
+                .error_for_status()?;
+            Ok(response.status().to_string())
+        })
+    }
+}
+
+/// Watches aggregate open exposure and recommends SOL-perp hedges
+pub struct HedgeMonitor {
+    position_tracker: Arc<PositionTracker>,
+    pnl_calculator: Arc<PnLCalculator>,
+    volatility: Option<Arc<VolatilityTracker>>,
+    config: HedgeConfig,
+    /// Hedge size assumed to be in place after previous recommendations (SOL)
+    current_hedge_sol: RwLock<f64>,
+}
+
+impl HedgeMonitor {
+    pub fn new(
+        position_tracker: Arc<PositionTracker>,
+        pnl_calculator: Arc<PnLCalculator>,
+        config: Option<HedgeConfig>,
+    ) -> Self {
+        Self {
+            position_tracker,
+            pnl_calculator,
+            volatility: None,
+            config: config.unwrap_or_default(),
+            current_hedge_sol: RwLock::new(0.0),
+        }
+    }
+
+    /// Weight each position's exposure by its token's beta to SOL
+    pub fn with_volatility(mut self, volatility: Arc<VolatilityTracker>) -> Self {
+        self.volatility = Some(volatility);
+        self
+    }
+
+    pub fn config(&self) -> &HedgeConfig {
+        &self.config
+    }
+
+    /// Aggregate mark-to-market value of open positions in SOL, beta-weighted when betas are known
+    pub async fn calculate_exposure(&self) -> Result<f64, DatabaseError> {
+        let positions = self.position_tracker.get_open_positions().await?;
+        let mut exposure = 0.0;
+
+        for position in positions {
+            let price = self.pnl_calculator
+                .get_current_price(&position.token_mint)
+                .await
+                .unwrap_or(position.entry_price);
+            let beta = match &self.volatility {
+                Some(volatility) => volatility.beta(&position.token_mint).await,
+                None => 1.0,
+            };
+            exposure += position.quantity * price * beta;
+        }
+
+        Ok(exposure)
+    }
+
+    /// Evaluate exposure and return a hedge adjustment if one is needed
+    ///
+    /// The recommended adjustment is assumed to be applied; call
+    /// `set_current_hedge` if the venue reports a different fill.
+    #[instrument(skip(self))]
+    pub async fn evaluate(&self) -> Result<Option<HedgeRecommendation>, DatabaseError> {
+        let exposure_sol = self.calculate_exposure().await?;
+        let target_hedge_sol = if exposure_sol > self.config.exposure_threshold_sol {
+            exposure_sol * self.config.hedge_ratio
+        } else {
+            0.0
+        };
+
+        let mut current_hedge = self.current_hedge_sol.write().await;
+        let delta = target_hedge_sol - *current_hedge;
+
+        if delta.abs() < self.config.min_adjustment_sol && !(target_hedge_sol == 0.0 && *current_hedge > 0.0) {
+            debug!("Exposure {:.3} SOL, hedge {:.3} SOL - no adjustment", exposure_sol, *current_hedge);
+            return Ok(None);
+        }
+
+        let recommendation = HedgeRecommendation {
+            instrument: self.config.instrument.clone(),
+            direction: if delta > 0.0 { HedgeDirection::Short } else { HedgeDirection::Cover },
+            size_sol: delta.abs(),
+            exposure_sol,
+            target_hedge_sol,
+            current_hedge_sol: *current_hedge,
+        };
+
+        info!(
+            "🛡️ Hedge recommendation: {:?} {:.3} SOL {} (exposure {:.3} SOL)",
+            recommendation.direction, recommendation.size_sol, recommendation.instrument, exposure_sol
+        );
+
+        *current_hedge = target_hedge_sol;
+        Ok(Some(recommendation))
+    }
+
+    /// Override the assumed hedge size (e.g. after a manual or partial fill)
+    pub async fn set_current_hedge(&self, hedge_sol: f64) {
+        *self.current_hedge_sol.write().await = hedge_sol.max(0.0);
+    }
+
+    pub async fn current_hedge(&self) -> f64 {
+        *self.current_hedge_sol.read().await
+    }
+}
@@ -0,0 +1,341 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use chrono::Utc;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Row};
+use tracing::{info, warn, instrument};
+
+use crate::core::HolderDistribution;
+use crate::database::{BadgerDatabase, DatabaseError};
+
+/// Configuration for holder distribution snapshots
+#[derive(Debug, Clone)]
+pub struct HolderSnapshotConfig {
+    /// How often tracked tokens are snapshotted (seconds)
+    pub snapshot_interval_secs: u64,
+    /// Largest single holder share of circulating supply before flagging
+    pub max_top1_share: f64,
+    /// Top-10 holder share of circulating supply before flagging
+    pub max_top10_share: f64,
+    /// Smallest share change between snapshots recorded as accumulation/distribution
+    pub min_share_change: f64,
+    /// How long a buy candidate stays on the watch list without a position (seconds)
+    pub candidate_ttl_secs: i64,
+}
+
+impl Default for HolderSnapshotConfig {
+    fn default() -> Self {
+        Self {
+            snapshot_interval_secs: 300,
+            max_top1_share: 0.2,
+            max_top10_share: 0.5,
+            min_share_change: 0.02,
+            candidate_ttl_secs: 3600,
+        }
+    }
+}
+
+/// Stored holder distribution snapshot
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct HolderSnapshot {
+    pub id: i64,
+    pub token_mint: String,
+    pub slot: i64,
+    pub supply: i64,
+    pub circulating: i64,
+    pub top1_share: f64,
+    pub top10_share: f64,
+    pub concentrated: bool,
+    pub taken_at: i64,
+}
+
+/// Share change of one holder between consecutive snapshots
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct HolderChange {
+    pub token_mint: String,
+    pub owner: String,
+    pub is_deployer: bool,
+    pub previous_share: f64,
+    pub new_share: f64,
+    pub change_type: String, // "ACCUMULATION", "DISTRIBUTION"
+    pub detected_at: i64,
+}
+
+/// Result of recording a snapshot
+#[derive(Debug, Clone)]
+pub struct SnapshotOutcome {
+    pub snapshot: HolderSnapshot,
+    pub changes: Vec<HolderChange>,
+}
+
+/// Top-holder distribution history of held and candidate tokens
+///
+/// Each snapshot is compared with the previous one for the same token; holders
+/// whose share moved by more than the configured threshold are recorded, so
+/// stealth accumulation or distribution (notably by the deployer) shows up
+/// over time.
+pub struct HolderSnapshotStore {
+    db: Arc<BadgerDatabase>,
+    config: HolderSnapshotConfig,
+    /// Buy candidates -> time they were added
+    candidates: DashMap<String, i64>,
+}
+
+impl HolderSnapshotStore {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<HolderSnapshotConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+            candidates: DashMap::new(),
+        }
+    }
+
+    pub fn config(&self) -> &HolderSnapshotConfig {
+        &self.config
+    }
+
+    /// Snapshot a token we are about to buy
+    pub fn watch(&self, token_mint: &str) {
+        self.candidates.insert(token_mint.to_string(), Utc::now().timestamp());
+    }
+
+    /// Buy candidates still on the watch list; expired ones are dropped
+    pub fn candidates(&self) -> Vec<String> {
+        let cutoff = Utc::now().timestamp() - self.config.candidate_ttl_secs;
+        self.candidates.retain(|_, added_at| *added_at >= cutoff);
+        self.candidates.iter().map(|entry| entry.key().clone()).collect()
+    }
+
+    /// Initialize holder snapshot schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing holder snapshot schema");
+
+        let create_holder_snapshots = r#"
+            CREATE TABLE IF NOT EXISTS holder_snapshots (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                token_mint TEXT NOT NULL,
+                slot INTEGER NOT NULL,
+                supply INTEGER NOT NULL,
+                circulating INTEGER NOT NULL,
+                top1_share REAL NOT NULL,
+                top10_share REAL NOT NULL,
+                concentrated BOOLEAN NOT NULL DEFAULT 0,
+                taken_at INTEGER NOT NULL
+            )
+        "#;
+
+        let create_holder_balances = r#"
+            CREATE TABLE IF NOT EXISTS holder_balances (
+                snapshot_id INTEGER NOT NULL,
+                token_account TEXT NOT NULL,
+                owner TEXT,
+                amount INTEGER NOT NULL,
+                share REAL NOT NULL,
+                is_pool BOOLEAN NOT NULL DEFAULT 0,
+                PRIMARY KEY (snapshot_id, token_account),
+                FOREIGN KEY (snapshot_id) REFERENCES holder_snapshots (id)
+            )
+        "#;
+
+        let create_holder_changes = r#"
+            CREATE TABLE IF NOT EXISTS holder_changes (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                token_mint TEXT NOT NULL,
+                owner TEXT NOT NULL,
+                is_deployer BOOLEAN NOT NULL DEFAULT 0,
+                previous_share REAL NOT NULL,
+                new_share REAL NOT NULL,
+                change_type TEXT NOT NULL CHECK (change_type IN ('ACCUMULATION', 'DISTRIBUTION')),
+                detected_at INTEGER NOT NULL
+            )
+        "#;
+
+        let create_indexes = vec![
+            "CREATE INDEX IF NOT EXISTS idx_holder_snapshots_mint ON holder_snapshots(token_mint, taken_at)",
+            "CREATE INDEX IF NOT EXISTS idx_holder_changes_mint ON holder_changes(token_mint, detected_at)",
+        ];
+
+        for table_sql in [create_holder_snapshots, create_holder_balances, create_holder_changes] {
+            sqlx::query(table_sql)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create holder snapshot table: {}", e)))?;
+        }
+
+        for index_sql in create_indexes {
+            sqlx::query(index_sql)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+        }
+
+        info!("✅ Holder snapshot schema initialized");
+        Ok(())
+    }
+
+    /// Owner shares of the latest snapshot of a token
+    async fn latest_shares(&self, token_mint: &str) -> Result<HashMap<String, f64>, DatabaseError> {
+        let rows = sqlx::query(r#"
+            SELECT b.owner, SUM(b.share) AS share
+            FROM holder_balances b
+            WHERE b.snapshot_id = (SELECT MAX(id) FROM holder_snapshots WHERE token_mint = ?)
+              AND b.owner IS NOT NULL AND b.is_pool = 0
+            GROUP BY b.owner
+        "#)
+        .bind(token_mint)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch previous holder shares: {}", e)))?;
+
+        Ok(rows.iter().map(|row| (row.get("owner"), row.get("share"))).collect())
+    }
+
+    /// Store a snapshot, flag concentration and record holder share changes
+    ///
+    /// # Arguments
+    /// * `distribution` - Freshly fetched top-holder distribution
+    /// * `deployer` - Token deployer, when known, so its moves are marked
+    pub async fn record_snapshot(
+        &self,
+        distribution: &HolderDistribution,
+        deployer: Option<&str>,
+    ) -> Result<SnapshotOutcome, DatabaseError> {
+        let previous = self.latest_shares(&distribution.token_mint).await?;
+        let now = Utc::now().timestamp();
+        let top1_share = distribution.top_share(1);
+        let top10_share = distribution.top_share(10);
+        let concentrated = top1_share > self.config.max_top1_share || top10_share > self.config.max_top10_share;
+
+        let mut tx = self.db.begin_transaction().await?;
+
+        let snapshot_id = sqlx::query(r#"
+            INSERT INTO holder_snapshots (token_mint, slot, supply, circulating, top1_share, top10_share, concentrated, taken_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(&distribution.token_mint)
+        .bind(distribution.slot as i64)
+        .bind(distribution.supply as i64)
+        .bind(distribution.circulating as i64)
+        .bind(top1_share)
+        .bind(top10_share)
+        .bind(concentrated)
+        .bind(now)
+        .execute(&mut tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to insert holder snapshot: {}", e)))?
+        .last_insert_rowid();
+
+        let mut current: HashMap<String, f64> = HashMap::new();
+        for holder in &distribution.holders {
+            sqlx::query(r#"
+                INSERT INTO holder_balances (snapshot_id, token_account, owner, amount, share, is_pool)
+                VALUES (?, ?, ?, ?, ?, ?)
+            "#)
+            .bind(snapshot_id)
+            .bind(&holder.token_account)
+            .bind(&holder.owner)
+            .bind(holder.amount as i64)
+            .bind(holder.share)
+            .bind(holder.is_pool)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to insert holder balance: {}", e)))?;
+
+            if let (Some(owner), false) = (&holder.owner, holder.is_pool) {
+                *current.entry(owner.clone()).or_insert(0.0) += holder.share;
+            }
+        }
+
+        // Only the top holders are visible, so a holder dropping out of the list counts as zero
+        let mut changes = Vec::new();
+        if !previous.is_empty() {
+            let mut owners: Vec<&String> = previous.keys().chain(current.keys()).collect();
+            owners.sort();
+            owners.dedup();
+
+            for owner in owners {
+                let previous_share = previous.get(owner).copied().unwrap_or(0.0);
+                let new_share = current.get(owner).copied().unwrap_or(0.0);
+                if (new_share - previous_share).abs() < self.config.min_share_change {
+                    continue;
+                }
+
+                let change = HolderChange {
+                    token_mint: distribution.token_mint.clone(),
+                    owner: owner.clone(),
+                    is_deployer: deployer == Some(owner.as_str()),
+                    previous_share,
+                    new_share,
+                    change_type: if new_share > previous_share { "ACCUMULATION" } else { "DISTRIBUTION" }.to_string(),
+                    detected_at: now,
+                };
+
+                sqlx::query(r#"
+                    INSERT INTO holder_changes (token_mint, owner, is_deployer, previous_share, new_share, change_type, detected_at)
+                    VALUES (?, ?, ?, ?, ?, ?, ?)
+                "#)
+                .bind(&change.token_mint)
+                .bind(&change.owner)
+                .bind(change.is_deployer)
+                .bind(change.previous_share)
+                .bind(change.new_share)
+                .bind(&change.change_type)
+                .bind(change.detected_at)
+                .execute(&mut tx)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to insert holder change: {}", e)))?;
+
+                changes.push(change);
+            }
+        }
+
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit holder snapshot: {}", e)))?;
+
+        if concentrated {
+            warn!("👥 {} is concentrated: top holder {:.1}%, top 10 {:.1}% of circulating supply",
+                distribution.token_mint, top1_share * 100.0, top10_share * 100.0);
+        }
+
+        Ok(SnapshotOutcome {
+            snapshot: HolderSnapshot {
+                id: snapshot_id,
+                token_mint: distribution.token_mint.clone(),
+                slot: distribution.slot as i64,
+                supply: distribution.supply as i64,
+                circulating: distribution.circulating as i64,
+                top1_share,
+                top10_share,
+                concentrated,
+                taken_at: now,
+            },
+            changes,
+        })
+    }
+
+    /// Latest snapshot of a token
+    pub async fn get_latest_snapshot(&self, token_mint: &str) -> Result<Option<HolderSnapshot>, DatabaseError> {
+        sqlx::query_as::<_, HolderSnapshot>(
+            "SELECT * FROM holder_snapshots WHERE token_mint = ? ORDER BY id DESC LIMIT 1"
+        )
+        .bind(token_mint)
+        .fetch_optional(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch holder snapshot: {}", e)))
+    }
+
+    /// Recorded accumulation/distribution moves of a token, newest first
+    pub async fn get_holder_changes(&self, token_mint: &str, limit: i64) -> Result<Vec<HolderChange>, DatabaseError> {
+        sqlx::query_as::<_, HolderChange>(r#"
+            SELECT token_mint, owner, is_deployer, previous_share, new_share, change_type, detected_at
+            FROM holder_changes WHERE token_mint = ? ORDER BY detected_at DESC LIMIT ?
+        "#)
+        .bind(token_mint)
+        .bind(limit)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch holder changes: {}", e)))
+    }
+}
@@ -2,10 +2,12 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use sqlx::Row;
+use sqlx::{Row, FromRow};
 use tracing::{debug, info, warn, error, instrument};
 
 use super::position_tracker::{Position, PositionTracker};
+use super::scoring::{ScoringModel, WeightedScoringModel, InsiderFeatures, InsiderScore};
+use super::wallet_cache::WalletIntelligenceCache;
 use super::super::{BadgerDatabase, DatabaseError};
 use crate::core::{MarketEvent, TradingSignal};
 
@@ -73,11 +75,106 @@ pub struct CopyTradeSignal {
     pub created_at: i64,
 }
 
+/// Single recorded insider activity
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct InsiderActivityRecord {
+    pub wallet_address: String,
+    pub token_mint: String,
+    pub activity_type: String, // "BUY", "SELL", "TRANSFER", "LP_ADD", "LP_REMOVE"
+    pub amount: f64,
+    pub price: Option<f64>,
+    pub timestamp: i64,
+}
+
+/// Configuration for resolving outcomes of insider buys
+#[derive(Debug, Clone)]
+pub struct OutcomeResolverConfig {
+    /// How often pending outcomes are revisited (seconds)
+    pub resolve_interval_secs: u64,
+    /// Buys without a matching sell are marked to the current price after this long (seconds)
+    pub mark_to_market_after_secs: i64,
+    /// Buys still unresolved after this long (no sell, no price) are expired (seconds)
+    pub expire_after_secs: i64,
+    /// Profit at or above which an outcome counts as a win (percent)
+    pub min_win_profit_pct: f64,
+}
+
+impl Default for OutcomeResolverConfig {
+    fn default() -> Self {
+        Self {
+            resolve_interval_secs: 300,
+            mark_to_market_after_secs: 86400,
+            expire_after_secs: 7 * 86400,
+            min_win_profit_pct: 0.0,
+        }
+    }
+}
+
+/// Configuration for treating insider liquidity removal as an exit signal
+#[derive(Debug, Clone)]
+pub struct LpExitConfig {
+    /// Emit exit signals; otherwise removals are only recorded
+    pub emit_signals: bool,
+    /// Smallest SOL withdrawn from the pool that counts as a large removal
+    pub min_removal_sol: f64,
+}
+
+impl Default for LpExitConfig {
+    fn default() -> Self {
+        Self {
+            emit_signals: false,
+            min_removal_sol: 5.0,
+        }
+    }
+}
+
+/// Parameters of a recorded insider trade or liquidity change
+#[derive(Debug, Clone)]
+pub struct NewInsiderActivity {
+    pub wallet_address: String,
+    pub token_mint: String,
+    pub activity_type: String,
+    pub amount: f64,
+    pub price: Option<f64>,
+    pub transaction_hash: Option<String>,
+    pub block_slot: Option<i64>,
+    pub timestamp: i64,
+}
+
+/// Result of one outcome resolution pass
+#[derive(Debug, Clone, Default)]
+pub struct OutcomeResolution {
+    /// New insider buys queued as PENDING
+    pub enqueued: u64,
+    /// Pending buys closed by the insider's later sell
+    pub resolved_by_sell: u64,
+    /// Pending buys marked to the current price
+    pub resolved_by_price: u64,
+    /// Pending buys given up on
+    pub expired: u64,
+}
+
+/// Result of one hot wallet store reconciliation pass
+#[derive(Debug, Clone, Default)]
+pub struct WalletStoreReconciliation {
+    /// Profiles in SQLite
+    pub checked: usize,
+    /// Profiles missing from the store
+    pub added: usize,
+    /// Stored profiles that differed from SQLite
+    pub corrected: usize,
+    /// Stored wallets no longer in SQLite
+    pub removed: usize,
+    /// Whether the store file was rewritten
+    pub compacted: bool,
+}
+
 /// Insider wallet analytics and tracking system
 pub struct InsiderAnalytics {
     db: Arc<BadgerDatabase>,
     position_tracker: Arc<PositionTracker>,
-    tracked_wallets: Arc<tokio::sync::RwLock<HashMap<String, InsiderProfile>>>,
+    tracked_wallets: Arc<WalletIntelligenceCache>,
+    scoring_model: Arc<dyn ScoringModel>,
 }
 
 impl InsiderAnalytics {
@@ -85,10 +182,28 @@ impl InsiderAnalytics {
         Self {
             db,
             position_tracker,
-            tracked_wallets: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+            tracked_wallets: Arc::new(WalletIntelligenceCache::default()),
+            scoring_model: Arc::new(WeightedScoringModel),
         }
     }
 
+    /// Hold profiles in a shared cache instead of a private one
+    pub fn with_cache(mut self, cache: Arc<WalletIntelligenceCache>) -> Self {
+        self.tracked_wallets = cache;
+        self
+    }
+
+    /// In-memory cache of insider profiles
+    pub fn cache(&self) -> Arc<WalletIntelligenceCache> {
+        self.tracked_wallets.clone()
+    }
+
+    /// Score insider profiles with a different model than the weighted formula
+    pub fn with_scoring_model(mut self, scoring_model: Arc<dyn ScoringModel>) -> Self {
+        self.scoring_model = scoring_model;
+        self
+    }
+
     /// Initialize insider analytics schema
     #[instrument(skip(self))]
     pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
@@ -137,7 +252,7 @@ impl InsiderAnalytics {
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
                 wallet_address TEXT NOT NULL,
                 token_mint TEXT NOT NULL,
-                activity_type TEXT NOT NULL CHECK (activity_type IN ('BUY', 'SELL', 'TRANSFER')),
+                activity_type TEXT NOT NULL CHECK (activity_type IN ('BUY', 'SELL', 'TRANSFER', 'LP_ADD', 'LP_REMOVE')),
                 amount REAL NOT NULL,
                 price REAL,
                 transaction_hash TEXT,
@@ -182,6 +297,37 @@ impl InsiderAnalytics {
             )
         "#;
 
+        // Swaps and blocked signals involving our own wallets, kept out of insider stats
+        let create_self_trade_events = r#"
+            CREATE TABLE IF NOT EXISTS self_trade_events (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                event_type TEXT NOT NULL CHECK (event_type IN ('OWN_SWAP', 'BLOCKED_SIGNAL')),
+                wallet_address TEXT NOT NULL,
+                token_mint TEXT NOT NULL,
+                action TEXT NOT NULL CHECK (action IN ('BUY', 'SELL')),
+                transaction_hash TEXT,
+                timestamp INTEGER NOT NULL
+            )
+        "#;
+
+        // Outcome of each priced insider buy, resolved by a later sell or the current price
+        let create_insider_trade_outcomes = r#"
+            CREATE TABLE IF NOT EXISTS insider_trade_outcomes (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                activity_id INTEGER NOT NULL UNIQUE,
+                wallet_address TEXT NOT NULL,
+                token_mint TEXT NOT NULL,
+                entry_price REAL NOT NULL,
+                entry_timestamp INTEGER NOT NULL,
+                status TEXT NOT NULL DEFAULT 'PENDING' CHECK (status IN ('PENDING', 'WIN', 'LOSS', 'EXPIRED')),
+                exit_price REAL,
+                profit_pct REAL,
+                resolution TEXT CHECK (resolution IN ('SELL', 'PRICE')),
+                resolved_at INTEGER,
+                FOREIGN KEY (activity_id) REFERENCES insider_activities (id)
+            )
+        "#;
+
         // Create indexes for better query performance
         let create_indexes = vec![
             "CREATE INDEX IF NOT EXISTS idx_insider_profiles_wallet ON insider_profiles(wallet_address)",
@@ -192,6 +338,9 @@ impl InsiderAnalytics {
             "CREATE INDEX IF NOT EXISTS idx_token_insider_token ON token_insider_summary(token_mint)",
             "CREATE INDEX IF NOT EXISTS idx_copy_signals_status ON copy_trade_signals(status)",
             "CREATE INDEX IF NOT EXISTS idx_copy_signals_created ON copy_trade_signals(created_at)",
+            "CREATE INDEX IF NOT EXISTS idx_self_trade_events_token ON self_trade_events(token_mint)",
+            "CREATE INDEX IF NOT EXISTS idx_trade_outcomes_status ON insider_trade_outcomes(status)",
+            "CREATE INDEX IF NOT EXISTS idx_trade_outcomes_wallet ON insider_trade_outcomes(wallet_address, status)",
         ];
 
         // Execute schema creation
@@ -200,7 +349,9 @@ impl InsiderAnalytics {
             create_insider_patterns, 
             create_insider_activities, 
             create_token_insider_summary, 
-            create_copy_trade_signals
+            create_copy_trade_signals,
+            create_self_trade_events,
+            create_insider_trade_outcomes
         ] {
             sqlx::query(table_sql)
                 .execute(self.db.get_pool())
@@ -231,8 +382,21 @@ impl InsiderAnalytics {
         transaction_hash: Option<&str>,
         block_slot: Option<i64>,
     ) -> Result<(), DatabaseError> {
-        let now = Utc::now().timestamp();
+        self.track_insider_activity_at(&NewInsiderActivity {
+            wallet_address: wallet_address.to_string(),
+            token_mint: token_mint.to_string(),
+            activity_type: activity_type.to_string(),
+            amount,
+            price,
+            transaction_hash: transaction_hash.map(str::to_string),
+            block_slot,
+            timestamp: Utc::now().timestamp(),
+        }).await
+    }
 
+    /// Track insider wallet activity that happened at `timestamp` (e.g. backfilled block time)
+    #[instrument(skip(self))]
+    pub async fn track_insider_activity_at(&self, activity: &NewInsiderActivity) -> Result<(), DatabaseError> {
         // Insert activity record
         sqlx::query(r#"
             INSERT INTO insider_activities (
@@ -240,35 +404,238 @@ impl InsiderAnalytics {
                 transaction_hash, block_slot, timestamp, confidence
             ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, 1.0)
         "#)
-        .bind(wallet_address)
-        .bind(token_mint)
-        .bind(activity_type)
-        .bind(amount)
-        .bind(price)
-        .bind(transaction_hash)
-        .bind(block_slot)
-        .bind(now)
+        .bind(&activity.wallet_address)
+        .bind(&activity.token_mint)
+        .bind(&activity.activity_type)
+        .bind(activity.amount)
+        .bind(activity.price)
+        .bind(&activity.transaction_hash)
+        .bind(activity.block_slot)
+        .bind(activity.timestamp)
         .execute(self.db.get_pool())
         .await
         .map_err(|e| DatabaseError::QueryError(format!("Failed to track insider activity: {}", e)))?;
 
         // Update or create insider profile
-        self.update_insider_profile(wallet_address).await?;
+        self.update_insider_profile(&activity.wallet_address).await?;
 
         // Update token insider summary
-        self.update_token_insider_summary(token_mint).await?;
+        self.update_token_insider_summary(&activity.token_mint).await?;
 
         debug!(
             "📈 Tracked insider activity: {} {} {} tokens for ${:.4}",
-            wallet_address, activity_type, amount, price.unwrap_or(0.0)
+            activity.wallet_address, activity.activity_type, activity.amount, activity.price.unwrap_or(0.0)
+        );
+
+        Ok(())
+    }
+
+    /// Most recent recorded transaction of every tracked insider
+    ///
+    /// # Returns
+    /// * `Result<Vec<(String, Option<String>)>, DatabaseError>` - (wallet, last signature);
+    ///   `None` for insiders with no recorded transaction yet
+    pub async fn last_processed_signatures(&self) -> Result<Vec<(String, Option<String>)>, DatabaseError> {
+        sqlx::query_as::<_, (String, Option<String>)>(r#"
+            SELECT p.wallet_address, (
+                SELECT a.transaction_hash FROM insider_activities a
+                WHERE a.wallet_address = p.wallet_address AND a.transaction_hash IS NOT NULL
+                ORDER BY a.block_slot DESC, a.id DESC
+                LIMIT 1
+            )
+            FROM insider_profiles p
+            ORDER BY p.copy_worthiness DESC
+        "#)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch last insider signatures: {}", e)))
+    }
+
+    /// Whether a liquidity removal by this wallet should trigger an exit from the token
+    ///
+    /// The removal must be large and the wallet one of the token's top insiders
+    /// by volume.
+    ///
+    /// # Arguments
+    /// * `wallet_address` - Wallet that removed liquidity
+    /// * `token_mint` - Non-SOL side of the pool
+    /// * `sol_amount` - SOL withdrawn
+    /// * `config` - LP exit thresholds
+    pub async fn is_lp_exit(
+        &self,
+        wallet_address: &str,
+        token_mint: &str,
+        sol_amount: f64,
+        config: &LpExitConfig,
+    ) -> Result<bool, DatabaseError> {
+        if sol_amount < config.min_removal_sol {
+            return Ok(false);
+        }
+
+        let top_wallets = sqlx::query_scalar::<_, Option<String>>(
+            "SELECT top_insider_wallets FROM token_insider_summary WHERE token_mint = ?"
+        )
+        .bind(token_mint)
+        .fetch_optional(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch token top insiders: {}", e)))?
+        .flatten();
+
+        let top_wallets: Vec<String> = top_wallets
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        Ok(top_wallets.iter().any(|wallet| wallet == wallet_address))
+    }
+
+    /// Resolve outcomes of insider buys stuck in PENDING
+    ///
+    /// Queues priced BUY activities that have no outcome yet, then closes each
+    /// pending buy against the insider's first later priced SELL of the token.
+    /// Buys without a sell are marked to `prices` once old enough, and expired
+    /// when no price is known either. Profiles of affected wallets are rescored.
+    ///
+    /// # Arguments
+    /// * `prices` - Current prices by token mint
+    /// * `config` - Resolution timing and win threshold
+    ///
+    /// # Returns
+    /// * `Result<OutcomeResolution, DatabaseError>` - Counts per resolution path
+    #[instrument(skip(self, prices))]
+    pub async fn resolve_pending_outcomes(
+        &self,
+        prices: &HashMap<String, f64>,
+        config: &OutcomeResolverConfig,
+    ) -> Result<OutcomeResolution, DatabaseError> {
+        let now = Utc::now().timestamp();
+        let enqueued = sqlx::query(r#"
+            INSERT OR IGNORE INTO insider_trade_outcomes (activity_id, wallet_address, token_mint, entry_price, entry_timestamp)
+            SELECT id, wallet_address, token_mint, price, timestamp
+            FROM insider_activities
+            WHERE activity_type = 'BUY' AND price IS NOT NULL AND price > 0
+              AND id NOT IN (SELECT activity_id FROM insider_trade_outcomes)
+        "#)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to queue insider trade outcomes: {}", e)))?
+        .rows_affected();
+        let mut resolution = OutcomeResolution { enqueued, ..OutcomeResolution::default() };
+
+        // Pending buys with the exit price of the insider's first later sell, if any
+        let pending = sqlx::query_as::<_, (i64, String, String, f64, i64, Option<f64>)>(r#"
+            SELECT o.id, o.wallet_address, o.token_mint, o.entry_price, o.entry_timestamp,
+                   (SELECT a.price FROM insider_activities a
+                    WHERE a.wallet_address = o.wallet_address AND a.token_mint = o.token_mint
+                      AND a.activity_type = 'SELL' AND a.price IS NOT NULL AND a.price > 0
+                      AND a.timestamp >= o.entry_timestamp
+                    ORDER BY a.timestamp ASC LIMIT 1)
+            FROM insider_trade_outcomes o
+            WHERE o.status = 'PENDING'
+        "#)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch pending trade outcomes: {}", e)))?;
+
+        let mut affected_wallets = Vec::new();
+        let mut tx = self.db.begin_transaction().await?;
+
+        for (id, wallet_address, token_mint, entry_price, entry_timestamp, sell_price) in pending {
+            let age = now - entry_timestamp;
+            let (exit_price, resolution_type) = match sell_price {
+                Some(price) => (Some(price), Some("SELL")),
+                None if age >= config.mark_to_market_after_secs => {
+                    (prices.get(&token_mint).copied(), prices.get(&token_mint).map(|_| "PRICE"))
+                }
+                None => continue,
+            };
+
+            let (status, profit_pct) = match exit_price {
+                Some(exit_price) => {
+                    let profit_pct = (exit_price - entry_price) / entry_price * 100.0;
+                    let status = if profit_pct >= config.min_win_profit_pct { "WIN" } else { "LOSS" };
+                    (status, Some(profit_pct))
+                }
+                None if age >= config.expire_after_secs => ("EXPIRED", None),
+                None => continue,
+            };
+
+            sqlx::query(r#"
+                UPDATE insider_trade_outcomes
+                SET status = ?, exit_price = ?, profit_pct = ?, resolution = ?, resolved_at = ?
+                WHERE id = ?
+            "#)
+            .bind(status)
+            .bind(exit_price)
+            .bind(profit_pct)
+            .bind(resolution_type)
+            .bind(now)
+            .bind(id)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to resolve trade outcome: {}", e)))?;
+
+            match resolution_type {
+                Some("SELL") => resolution.resolved_by_sell += 1,
+                Some(_) => resolution.resolved_by_price += 1,
+                None => resolution.expired += 1,
+            }
+            if status != "EXPIRED" && !affected_wallets.contains(&wallet_address) {
+                affected_wallets.push(wallet_address);
+            }
+        }
+
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit trade outcomes: {}", e)))?;
+
+        for wallet_address in &affected_wallets {
+            if let Err(e) = self.update_insider_profile(wallet_address).await {
+                warn!("Failed to rescore insider {} after outcome resolution: {}", wallet_address, e);
+            }
+        }
+
+        debug!(
+            "🎯 Outcomes: {} queued, {} resolved by sell, {} by price, {} expired",
+            resolution.enqueued, resolution.resolved_by_sell, resolution.resolved_by_price, resolution.expired
         );
+        Ok(resolution)
+    }
+
+    /// Record a swap by one of our own wallets, or a signal blocked to avoid self-trading
+    ///
+    /// For blocked signals, `wallet_address` and `transaction_hash` identify our
+    /// wallet's swap on the other side. These go to `self_trade_events` rather than
+    /// `insider_activities` so our own flow never inflates insider profiles.
+    #[instrument(skip(self))]
+    pub async fn record_self_trade_event(
+        &self,
+        event_type: &str,
+        wallet_address: &str,
+        token_mint: &str,
+        action: &str,
+        transaction_hash: Option<&str>,
+    ) -> Result<(), DatabaseError> {
+        sqlx::query(r#"
+            INSERT INTO self_trade_events (
+                event_type, wallet_address, token_mint, action,
+                transaction_hash, timestamp
+            ) VALUES (?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(event_type)
+        .bind(wallet_address)
+        .bind(token_mint)
+        .bind(action)
+        .bind(transaction_hash)
+        .bind(Utc::now().timestamp())
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record self-trade event: {}", e)))?;
 
+        debug!("🪞 Recorded {} self-trade event: {} {} {}", event_type, wallet_address, action, token_mint);
         Ok(())
     }
 
-    /// Update insider profile based on recent activity
+    /// Create or update an insider profile from its recorded activity
     #[instrument(skip(self))]
-    async fn update_insider_profile(&self, wallet_address: &str) -> Result<(), DatabaseError> {
+    pub async fn update_insider_profile(&self, wallet_address: &str) -> Result<(), DatabaseError> {
         let now = Utc::now().timestamp();
 
         // Calculate profile statistics
@@ -276,7 +643,7 @@ impl InsiderAnalytics {
             SELECT 
                 MIN(timestamp) as first_seen,
                 MAX(timestamp) as last_activity,
-                COUNT(*) as total_trades,
+                COUNT(CASE WHEN activity_type NOT IN ('LP_ADD', 'LP_REMOVE') THEN 1 END) as total_trades,
                 COUNT(CASE WHEN activity_type = 'BUY' THEN 1 END) as buy_trades,
                 COUNT(CASE WHEN activity_type = 'SELL' THEN 1 END) as sell_trades,
                 COALESCE(SUM(amount * COALESCE(price, 0)), 0) as total_volume,
@@ -322,8 +689,21 @@ impl InsiderAnalytics {
             }
         }
 
-        let success_rate = if positions.len() > 0 {
-            successful_trades as f64 / positions.len() as f64
+        // The insider's own resolved buys count alongside positions we copied
+        let (resolved_outcomes, winning_outcomes) = sqlx::query_as::<_, (i64, i64)>(r#"
+            SELECT COUNT(*), COUNT(CASE WHEN status = 'WIN' THEN 1 END)
+            FROM insider_trade_outcomes
+            WHERE wallet_address = ? AND status IN ('WIN', 'LOSS')
+        "#)
+        .bind(wallet_address)
+        .fetch_one(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to count insider trade outcomes: {}", e)))?;
+        successful_trades += winning_outcomes;
+        let scored_trades = positions.len() as i64 + resolved_outcomes;
+
+        let success_rate = if scored_trades > 0 {
+            successful_trades as f64 / scored_trades as f64
         } else {
             0.0
         };
@@ -340,14 +720,18 @@ impl InsiderAnalytics {
             0.0
         };
 
-        // Calculate confidence score (0-100)
-        let confidence_score = self.calculate_confidence_score(success_rate, total_trades, roi_percentage, trading_frequency);
-
         // Calculate risk score (0-100)
         let risk_score = self.calculate_risk_score(&positions);
 
-        // Calculate copy worthiness (0-100) - overall score
-        let copy_worthiness = (confidence_score * 0.4 + (100.0 - risk_score) * 0.3 + success_rate * 100.0 * 0.3).min(100.0);
+        // Calculate confidence and copy worthiness (0-100) with the configured model
+        let InsiderScore { confidence_score, copy_worthiness } = self.scoring_model.score(&InsiderFeatures {
+            success_rate,
+            total_trades,
+            roi_percentage,
+            trading_frequency,
+            risk_score,
+            average_hold_time: avg_hold_time,
+        });
 
         // Get favorite tokens (top 5)
         let favorite_tokens = self.get_favorite_tokens(wallet_address, 5).await?;
@@ -402,8 +786,7 @@ impl InsiderAnalytics {
 
         // Update in-memory cache
         {
-            let mut tracked_wallets = self.tracked_wallets.write().await;
-            tracked_wallets.insert(wallet_address.to_string(), InsiderProfile {
+            self.tracked_wallets.insert_profile(InsiderProfile {
                 wallet_address: wallet_address.to_string(),
                 first_seen,
                 last_activity,
@@ -435,9 +818,9 @@ impl InsiderAnalytics {
         let stats = sqlx::query(r#"
             SELECT 
                 COUNT(DISTINCT wallet_address) as insider_count,
-                COALESCE(SUM(amount * COALESCE(price, 0)), 0) as total_volume,
-                COALESCE(SUM(CASE WHEN activity_type = 'BUY' THEN amount * COALESCE(price, 0) ELSE 0 END), 0) as buy_volume,
-                COALESCE(SUM(CASE WHEN activity_type = 'SELL' THEN amount * COALESCE(price, 0) ELSE 0 END), 0) as sell_volume,
+                COALESCE(SUM(amount * COALESCE(price, 0.0)), 0.0) as total_volume,
+                COALESCE(SUM(CASE WHEN activity_type = 'BUY' THEN amount * COALESCE(price, 0.0) ELSE 0.0 END), 0.0) as buy_volume,
+                COALESCE(SUM(CASE WHEN activity_type = 'SELL' THEN amount * COALESCE(price, 0.0) ELSE 0.0 END), 0.0) as sell_volume,
                 MIN(timestamp) as first_activity,
                 MAX(timestamp) as last_activity
             FROM insider_activities 
@@ -630,11 +1013,8 @@ impl InsiderAnalytics {
     /// Get insider profile by wallet address
     pub async fn get_insider_profile(&self, wallet_address: &str) -> Result<Option<InsiderProfile>, DatabaseError> {
         // Check memory cache first
-        {
-            let tracked_wallets = self.tracked_wallets.read().await;
-            if let Some(profile) = tracked_wallets.get(wallet_address) {
-                return Ok(Some(profile.clone()));
-            }
+        if let Some(profile) = self.tracked_wallets.profile(wallet_address) {
+            return Ok(Some(profile));
         }
 
         // Query database
@@ -672,10 +1052,7 @@ impl InsiderAnalytics {
             };
 
             // Update cache
-            {
-                let mut tracked_wallets = self.tracked_wallets.write().await;
-                tracked_wallets.insert(wallet_address.to_string(), profile.clone());
-            }
+            self.tracked_wallets.insert_profile(profile.clone());
 
             Ok(Some(profile))
         } else {
@@ -683,6 +1060,18 @@ impl InsiderAnalytics {
         }
     }
 
+    /// Stop treating a wallet as an insider; its recorded activity is kept
+    pub async fn remove_insider_profile(&self, wallet_address: &str) -> Result<(), DatabaseError> {
+        sqlx::query("DELETE FROM insider_profiles WHERE wallet_address = ?")
+            .bind(wallet_address)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to remove insider profile: {}", e)))?;
+        // The hot wallet store catches up on its next reconciliation
+        self.tracked_wallets.remove_profile(wallet_address);
+        Ok(())
+    }
+
     /// Get top performing insiders
     pub async fn get_top_insiders(&self, limit: i64) -> Result<Vec<InsiderProfile>, DatabaseError> {
         let rows = sqlx::query(r#"
@@ -725,6 +1114,61 @@ impl InsiderAnalytics {
         Ok(profiles)
     }
 
+    /// Correct the hot wallet store against `insider_profiles`
+    ///
+    /// SQLite is the source of truth. Profiles missing from the store or
+    /// differing from it are written again (and refreshed in the cache when
+    /// held there), wallets gone from SQLite are removed, and the store is
+    /// compacted once superseded records pile up.
+    pub async fn reconcile_wallet_store(&self) -> Result<Option<WalletStoreReconciliation>, DatabaseError> {
+        let Some(store) = self.tracked_wallets.store() else {
+            return Ok(None);
+        };
+
+        // Profiles written after the scan starts are left to the next pass
+        let scanned_at = Utc::now().timestamp();
+        let rows = sqlx::query("SELECT * FROM insider_profiles")
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch insider profiles: {}", e)))?;
+        let truth: HashMap<String, InsiderProfile> = rows.iter()
+            .map(|row| {
+                let profile = profile_from_row(row);
+                (profile.wallet_address.clone(), profile)
+            })
+            .collect();
+        let stored = store.load()?;
+
+        let mut reconciliation = WalletStoreReconciliation {
+            checked: truth.len(),
+            ..Default::default()
+        };
+        for (wallet_address, profile) in &truth {
+            match stored.get(wallet_address) {
+                None => reconciliation.added += 1,
+                Some(kept) if kept.last_updated >= scanned_at => continue,
+                Some(kept) if kept.last_updated != profile.last_updated || kept.total_trades != profile.total_trades => {
+                    reconciliation.corrected += 1;
+                    self.tracked_wallets.refresh_profile(profile.clone());
+                }
+                Some(_) => continue,
+            }
+            store.put(profile)?;
+        }
+        for (wallet_address, _) in stored.iter().filter(|(wallet, kept)| !truth.contains_key(*wallet) && kept.last_updated < scanned_at) {
+            store.remove(wallet_address)?;
+            self.tracked_wallets.remove_profile(wallet_address);
+            reconciliation.removed += 1;
+        }
+
+        if store.needs_compaction(truth.len()) {
+            store.compact(truth.values())?;
+            reconciliation.compacted = true;
+        }
+
+        Ok(Some(reconciliation))
+    }
+
     /// Get token insider activity summary
     pub async fn get_token_insider_activity(&self, token_mint: &str) -> Result<Option<TokenInsiderActivity>, DatabaseError> {
         let row = sqlx::query(r#"
@@ -757,17 +1201,24 @@ impl InsiderAnalytics {
         }
     }
 
-    // Helper methods for calculations
+    /// Get most recent insider activities across all wallets
+    pub async fn get_recent_activities(&self, limit: i64) -> Result<Vec<InsiderActivityRecord>, DatabaseError> {
+        let activities = sqlx::query_as::<_, InsiderActivityRecord>(r#"
+            SELECT wallet_address, token_mint, activity_type, amount, price, timestamp
+            FROM insider_activities 
+            ORDER BY timestamp DESC, id DESC 
+            LIMIT ?
+        "#)
+        .bind(limit)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch recent insider activities: {}", e)))?;
 
-    fn calculate_confidence_score(&self, success_rate: f64, total_trades: i64, roi: f64, frequency: f64) -> f64 {
-        let base_score = success_rate * 100.0;
-        let volume_bonus = (total_trades.min(100) as f64 / 100.0) * 20.0; // Up to 20 points for volume
-        let roi_bonus = (roi.max(-100.0).min(100.0) / 100.0) * 30.0; // Up to 30 points for ROI
-        let frequency_bonus = (frequency.min(10.0) / 10.0) * 10.0; // Up to 10 points for frequency
-        
-        (base_score + volume_bonus + roi_bonus + frequency_bonus).min(100.0).max(0.0)
+        Ok(activities)
     }
 
+    // Helper methods for calculations
+
     fn calculate_risk_score(&self, positions: &[Position]) -> f64 {
         if positions.is_empty() {
             return 50.0; // Medium risk if no data
@@ -816,4 +1267,27 @@ impl InsiderAnalytics {
 
         Ok(tokens)
     }
-}
\ No newline at end of file
+}
+
+fn profile_from_row(row: &sqlx::sqlite::SqliteRow) -> InsiderProfile {
+    let favorite_tokens_json: String = row.get("favorite_tokens");
+    InsiderProfile {
+        wallet_address: row.get("wallet_address"),
+        first_seen: row.get("first_seen"),
+        last_activity: row.get("last_activity"),
+        total_trades: row.get("total_trades"),
+        successful_trades: row.get("successful_trades"),
+        success_rate: row.get("success_rate"),
+        total_volume: row.get("total_volume"),
+        average_trade_size: row.get("average_trade_size"),
+        total_pnl: row.get("total_pnl"),
+        roi_percentage: row.get("roi_percentage"),
+        average_hold_time: row.get("average_hold_time"),
+        favorite_tokens: serde_json::from_str(&favorite_tokens_json).unwrap_or_default(),
+        trading_frequency: row.get("trading_frequency"),
+        confidence_score: row.get("confidence_score"),
+        risk_score: row.get("risk_score"),
+        copy_worthiness: row.get("copy_worthiness"),
+        last_updated: row.get("last_updated"),
+    }
+}
@@ -2,8 +2,58 @@ pub mod position_tracker;
 pub mod pnl_calculator;
 pub mod performance_tracker;
 pub mod insider_analytics;
+pub mod wallet_discovery;
+pub mod config_snapshots;
+pub mod hedge_monitor;
+pub mod risk_analytics;
+pub mod strategy_registry;
+pub mod skip_rules;
+pub mod holder_snapshots;
+pub mod fade_tracker;
+pub mod summary_views;
+pub mod wallet_portfolio;
+pub mod volume_profile;
+pub mod scoring;
+pub mod treasury;
+pub mod risk_manager;
+pub mod wallet_cache;
+pub mod fast_wallet_db;
+pub mod activity_heatmap;
+pub mod price_candles;
+pub mod token_volatility;
+pub mod fill_quality;
+pub mod position_alerts;
+pub mod pnl_recompute;
+pub mod exit_levels;
+pub mod ab_tests;
+pub mod tax_ledger;
 
 pub use position_tracker::*;
 pub use pnl_calculator::*;
 pub use performance_tracker::*;
-pub use insider_analytics::*;
\ No newline at end of file
+pub use insider_analytics::*;
+pub use wallet_discovery::*;
+pub use config_snapshots::*;
+pub use hedge_monitor::*;
+pub use risk_analytics::*;
+pub use strategy_registry::*;
+pub use skip_rules::*;
+pub use holder_snapshots::*;
+pub use fade_tracker::*;
+pub use summary_views::*;
+pub use wallet_portfolio::*;
+pub use volume_profile::*;
+pub use scoring::*;
+pub use treasury::*;
+pub use risk_manager::*;
+pub use wallet_cache::*;
+pub use fast_wallet_db::*;
+pub use activity_heatmap::*;
+pub use price_candles::*;
+pub use token_volatility::*;
+pub use fill_quality::*;
+pub use position_alerts::*;
+pub use pnl_recompute::*;
+pub use exit_levels::*;
+pub use ab_tests::*;
+pub use tax_ledger::*;
\ No newline at end of file
@@ -7,6 +7,7 @@ use tracing::{debug, info, warn, error, instrument};
 
 use super::position_tracker::{Position, PositionTracker};
 use super::pnl_calculator::{PnLCalculator, PortfolioPnL};
+use super::treasury::TreasuryLedger;
 use super::super::{BadgerDatabase, DatabaseError};
 
 /// Performance metrics for bot trading analysis
@@ -35,6 +36,12 @@ pub struct PerformanceMetrics {
     pub worst_trade: Option<f64>,
     pub consecutive_wins: i64,
     pub consecutive_losses: i64,
+    /// Deposits minus withdrawals during the period (SOL)
+    #[serde(default)]
+    pub net_capital_flow: f64,
+    /// Return chained across the sub-periods between capital flows; None without recorded capital
+    #[serde(default)]
+    pub time_weighted_return: Option<f64>,
     pub calculated_at: i64,
 }
 
@@ -74,6 +81,7 @@ pub struct PerformanceTracker {
     db: Arc<BadgerDatabase>,
     position_tracker: Arc<PositionTracker>,
     pnl_calculator: Arc<PnLCalculator>,
+    treasury: Arc<TreasuryLedger>,
     current_session_id: Arc<tokio::sync::RwLock<Option<String>>>,
 }
 
@@ -84,6 +92,7 @@ impl PerformanceTracker {
         pnl_calculator: Arc<PnLCalculator>
     ) -> Self {
         Self {
+            treasury: Arc::new(TreasuryLedger::new(db.clone())),
             db,
             position_tracker,
             pnl_calculator,
@@ -91,6 +100,11 @@ impl PerformanceTracker {
         }
     }
 
+    /// Capital flow ledger that returns are measured against
+    pub fn treasury(&self) -> Arc<TreasuryLedger> {
+        self.treasury.clone()
+    }
+
     /// Initialize performance tracking schema
     #[instrument(skip(self))]
     pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
@@ -123,6 +137,8 @@ impl PerformanceTracker {
                 worst_trade REAL,
                 consecutive_wins INTEGER NOT NULL DEFAULT 0,
                 consecutive_losses INTEGER NOT NULL DEFAULT 0,
+                net_capital_flow REAL NOT NULL DEFAULT 0.0,
+                time_weighted_return REAL,
                 calculated_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now'))
             )
         "#;
@@ -180,6 +196,27 @@ impl PerformanceTracker {
                 .map_err(|e| DatabaseError::QueryError(format!("Failed to create performance table: {}", e)))?;
         }
 
+        // Snapshot tables created before capital flows were tracked lack these columns
+        for (column, definition) in [
+            ("net_capital_flow", "REAL NOT NULL DEFAULT 0.0"),
+            ("time_weighted_return", "REAL"),
+        ] {
+            let exists = sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM pragma_table_info('performance_snapshots') WHERE name = ?"
+            )
+            .bind(column)
+            .fetch_one(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to inspect performance_snapshots: {}", e)))? > 0;
+
+            if !exists {
+                sqlx::query(&format!("ALTER TABLE performance_snapshots ADD COLUMN {} {}", column, definition))
+                    .execute(self.db.get_pool())
+                    .await
+                    .map_err(|e| DatabaseError::QueryError(format!("Failed to add {} column: {}", column, e)))?;
+            }
+        }
+
         for index_sql in create_indexes {
             sqlx::query(index_sql)
                 .execute(self.db.get_pool())
@@ -187,6 +224,8 @@ impl PerformanceTracker {
                 .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
         }
 
+        self.treasury.initialize_schema().await?;
+
         info!("✅ Performance tracker database schema initialized");
         Ok(())
     }
@@ -209,6 +248,10 @@ impl PerformanceTracker {
         .await
         .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch period positions: {}", e)))?;
 
+        // Returns on capital, net of deposits and withdrawals made during the period
+        let net_capital_flow = self.treasury.net_flow_between(period_start, period_end).await?;
+        let time_weighted_return = self.treasury.time_weighted_return(period_start, period_end).await?;
+
         if positions.is_empty() {
             return Ok(PerformanceMetrics {
                 period_start,
@@ -234,6 +277,8 @@ impl PerformanceTracker {
                 worst_trade: None,
                 consecutive_wins: 0,
                 consecutive_losses: 0,
+                net_capital_flow,
+                time_weighted_return,
                 calculated_at: Utc::now().timestamp(),
             });
         }
@@ -412,6 +457,8 @@ impl PerformanceTracker {
             worst_trade,
             consecutive_wins,
             consecutive_losses,
+            net_capital_flow,
+            time_weighted_return,
             calculated_at: Utc::now().timestamp(),
         })
     }
@@ -561,8 +608,8 @@ impl PerformanceTracker {
                 win_rate, average_win, average_loss, profit_factor, sharpe_ratio, sortino_ratio,
                 max_drawdown, max_drawdown_duration, total_return, annualized_return, volatility,
                 calmar_ratio, trades_per_day, average_hold_time, best_trade, worst_trade,
-                consecutive_wins, consecutive_losses, calculated_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                consecutive_wins, consecutive_losses, net_capital_flow, time_weighted_return, calculated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
         "#)
         .bind(period_type)
         .bind(metrics.period_start)
@@ -588,6 +635,8 @@ impl PerformanceTracker {
         .bind(metrics.worst_trade)
         .bind(metrics.consecutive_wins)
         .bind(metrics.consecutive_losses)
+        .bind(metrics.net_capital_flow)
+        .bind(metrics.time_weighted_return)
         .bind(metrics.calculated_at)
         .execute(self.db.get_pool())
         .await
@@ -635,6 +684,8 @@ impl PerformanceTracker {
                 worst_trade: row.get("worst_trade"),
                 consecutive_wins: row.get("consecutive_wins"),
                 consecutive_losses: row.get("consecutive_losses"),
+                net_capital_flow: row.get("net_capital_flow"),
+                time_weighted_return: row.get("time_weighted_return"),
                 calculated_at: row.get("calculated_at"),
             }))
         } else {
@@ -6,7 +6,7 @@ use sqlx::Row;
 use tracing::{debug, info, warn, error, instrument};
 
 use super::position_tracker::{Position, PositionTracker};
-use super::super::{BadgerDatabase, DatabaseError};
+use super::super::{BadgerDatabase, ColdStore, DatabaseError};
 
 /// P&L calculation result
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -56,7 +56,10 @@ pub struct TokenPnL {
 pub struct PnLCalculator {
     db: Arc<BadgerDatabase>,
     position_tracker: Arc<PositionTracker>,
-    current_prices: Arc<tokio::sync::RwLock<HashMap<String, f64>>>,
+    /// Latest price per token with the time it was set (unix timestamp)
+    current_prices: Arc<tokio::sync::RwLock<HashMap<String, (f64, i64)>>>,
+    /// Snapshots moved past the hot window, merged into history reads
+    cold_store: Option<Arc<ColdStore>>,
 }
 
 impl PnLCalculator {
@@ -65,9 +68,16 @@ impl PnLCalculator {
             db,
             position_tracker,
             current_prices: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+            cold_store: None,
         }
     }
 
+    /// Read snapshots older than the hot window back from the cold store
+    pub fn with_cold_store(mut self, cold_store: Arc<ColdStore>) -> Self {
+        self.cold_store = Some(cold_store);
+        self
+    }
+
     /// Initialize P&L calculation schema
     #[instrument(skip(self))]
     pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
@@ -140,7 +150,7 @@ impl PnLCalculator {
     /// Update current price for a token
     pub async fn update_price(&self, token_mint: &str, price: f64) {
         let mut prices = self.current_prices.write().await;
-        prices.insert(token_mint.to_string(), price);
+        prices.insert(token_mint.to_string(), (price, Utc::now().timestamp()));
         debug!("💰 Updated price for {}: ${:.6}", token_mint, price);
     }
 
@@ -411,9 +421,40 @@ impl PnLCalculator {
     }
 
     /// Get current price from memory
-    async fn get_current_price(&self, token_mint: &str) -> Option<f64> {
+    pub async fn get_current_price(&self, token_mint: &str) -> Option<f64> {
+        let prices = self.current_prices.read().await;
+        prices.get(token_mint).map(|(price, _)| *price)
+    }
+
+    /// Drop cached prices not updated within the window, keeping open positions' prices
+    ///
+    /// # Returns
+    /// * `Result<usize, DatabaseError>` - Number of prices evicted
+    pub async fn prune_prices(&self, max_age_secs: i64) -> Result<usize, DatabaseError> {
+        let open_mints: std::collections::HashSet<String> = self.position_tracker
+            .get_open_positions()
+            .await?
+            .into_iter()
+            .map(|p| p.token_mint)
+            .collect();
+        let cutoff = Utc::now().timestamp() - max_age_secs;
+
+        let mut prices = self.current_prices.write().await;
+        let before = prices.len();
+        prices.retain(|mint, (_, updated_at)| *updated_at >= cutoff || open_mints.contains(mint));
+
+        Ok(before - prices.len())
+    }
+
+    /// Copy of all cached prices
+    pub async fn cached_prices(&self) -> HashMap<String, f64> {
         let prices = self.current_prices.read().await;
-        prices.get(token_mint).copied()
+        prices.iter().map(|(mint, (price, _))| (mint.clone(), *price)).collect()
+    }
+
+    /// Number of tokens with a cached price
+    pub async fn cached_price_count(&self) -> usize {
+        self.current_prices.read().await.len()
     }
 
     /// Calculate maximum drawdown from position history
@@ -452,19 +493,58 @@ impl PnLCalculator {
 
     /// Get P&L history for a time period
     pub async fn get_pnl_history(&self, hours_back: i64) -> Result<Vec<PortfolioPnL>, DatabaseError> {
-        let since_timestamp = Utc::now().timestamp() - (hours_back * 3600);
+        let now = Utc::now().timestamp();
+        self.get_pnl_history_range(now - (hours_back * 3600), now, None).await
+    }
 
+    /// Get P&L snapshots in `[start, end]`, optionally of one snapshot type
+    ///
+    /// Historical series stay in `pnl_snapshots`; metrics that need a window
+    /// query it here instead of accumulating it in memory. Ranges reaching
+    /// past the hot window also get the snapshots moved to the cold store.
+    pub async fn get_pnl_history_range(
+        &self,
+        start: i64,
+        end: i64,
+        snapshot_type: Option<&str>,
+    ) -> Result<Vec<PortfolioPnL>, DatabaseError> {
         let snapshots = sqlx::query(r#"
             SELECT * FROM pnl_snapshots 
-            WHERE timestamp >= ? 
+            WHERE timestamp >= ? AND timestamp <= ? AND (? IS NULL OR snapshot_type = ?)
             ORDER BY timestamp ASC
         "#)
-        .bind(since_timestamp)
+        .bind(start)
+        .bind(end)
+        .bind(snapshot_type)
+        .bind(snapshot_type)
         .fetch_all(self.db.get_pool())
         .await
         .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch P&L history: {}", e)))?;
 
         let mut history = Vec::new();
+        if let Some(cold_store) = &self.cold_store {
+            if start < cold_store.cutoff(Utc::now().timestamp()) {
+                for row in cold_store.read_rows("pnl_snapshots", start, end).await? {
+                    if snapshot_type.is_some_and(|kind| row.get("snapshot_type").and_then(|v| v.as_str()) != Some(kind)) {
+                        continue;
+                    }
+                    let number = |column: &str| row.get(column).and_then(|v| v.as_f64());
+                    history.push(PortfolioPnL {
+                        total_realized_pnl: number("total_realized_pnl").unwrap_or_default(),
+                        total_unrealized_pnl: number("total_unrealized_pnl").unwrap_or_default(),
+                        total_fees: number("total_fees").unwrap_or_default(),
+                        net_pnl: number("net_pnl").unwrap_or_default(),
+                        total_invested: number("total_invested").unwrap_or_default(),
+                        portfolio_roi: number("portfolio_roi").unwrap_or_default(),
+                        win_rate: number("win_rate").unwrap_or_default(),
+                        profit_factor: number("profit_factor").unwrap_or_default(),
+                        sharpe_ratio: number("sharpe_ratio"),
+                        max_drawdown: number("max_drawdown").unwrap_or_default(),
+                        calculated_at: row.get("timestamp").and_then(|v| v.as_i64()).unwrap_or_default(),
+                    });
+                }
+            }
+        }
         for row in snapshots {
             history.push(PortfolioPnL {
                 total_realized_pnl: row.get("total_realized_pnl"),
@@ -0,0 +1,241 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{info, instrument};
+
+use super::super::{BadgerDatabase, DatabaseError};
+use super::Position;
+
+/// Version of the closed-trade P&L calculation
+///
+/// 1. Exit fees charged on the exit price instead of the sale value
+/// 2. Entry and exit fees both charged on trade value
+pub const PNL_CALC_VERSION: i64 = 2;
+
+pub const OUTCOME_WIN: &str = "WIN";
+pub const OUTCOME_LOSS: &str = "LOSS";
+pub const OUTCOME_FLAT: &str = "FLAT";
+
+/// Realized P&L and total fees of a closed lot under the current calculation
+///
+/// # Returns
+/// * `(f64, f64)` - Net P&L and fees (SOL)
+pub fn closed_trade_pnl(entry_price: f64, exit_price: f64, quantity: f64, fee_rate: f64) -> (f64, f64) {
+    let fees = (entry_price + exit_price) * quantity * fee_rate;
+    ((exit_price - entry_price) * quantity - fees, fees)
+}
+
+/// Outcome of a closed trade from its net P&L
+pub fn trade_outcome(pnl: f64) -> &'static str {
+    if pnl > 0.0 {
+        OUTCOME_WIN
+    } else if pnl < 0.0 {
+        OUTCOME_LOSS
+    } else {
+        OUTCOME_FLAT
+    }
+}
+
+/// One recalculation of historical closed positions
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PnlRecalcRun {
+    pub id: i64,
+    /// `PNL_CALC_VERSION` the positions were recomputed with
+    pub version: i64,
+    pub fee_rate: f64,
+    pub positions: i64,
+    /// Positions whose P&L or fees changed
+    pub changed: i64,
+    /// Positions whose outcome flipped (e.g. a win that was really a loss)
+    pub flipped_outcomes: i64,
+    pub pnl_before: f64,
+    pub pnl_after: f64,
+    pub dry_run: bool,
+    pub completed_at: i64,
+}
+
+/// Recomputes realized P&L, fees and outcomes of all closed positions
+///
+/// Closed rows keep whatever P&L the code of the day wrote, so a fee or
+/// pricing bug would otherwise skew win rates, scoring and performance
+/// metrics forever. Each run rewrites the rows with the current calculation
+/// and keeps the previous values per position, tagged with the calculation
+/// version, in `pnl_recalc_entries`.
+pub struct PnlRecalculator {
+    db: Arc<BadgerDatabase>,
+    fee_rate: f64,
+}
+
+impl PnlRecalculator {
+    /// # Arguments
+    /// * `fee_rate` - Fees per side as a fraction of trade value (the strategy's `fee_rate`)
+    pub fn new(db: Arc<BadgerDatabase>, fee_rate: f64) -> Self {
+        Self { db, fee_rate }
+    }
+
+    /// Initialize P&L recalculation schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing P&L recalculation schema");
+
+        let create_runs = r#"
+            CREATE TABLE IF NOT EXISTS pnl_recalc_runs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                version INTEGER NOT NULL,
+                fee_rate REAL NOT NULL,
+                positions INTEGER NOT NULL,
+                changed INTEGER NOT NULL,
+                flipped_outcomes INTEGER NOT NULL,
+                pnl_before REAL NOT NULL,
+                pnl_after REAL NOT NULL,
+                dry_run BOOLEAN NOT NULL,
+                completed_at INTEGER NOT NULL
+            )
+        "#;
+
+        let create_entries = r#"
+            CREATE TABLE IF NOT EXISTS pnl_recalc_entries (
+                run_id INTEGER NOT NULL,
+                position_id INTEGER NOT NULL,
+                version INTEGER NOT NULL,
+                old_pnl REAL,
+                old_fees REAL NOT NULL,
+                old_outcome TEXT,
+                new_pnl REAL NOT NULL,
+                new_fees REAL NOT NULL,
+                new_outcome TEXT NOT NULL,
+                PRIMARY KEY (run_id, position_id)
+            )
+        "#;
+
+        for (statement, table) in [(create_runs, "pnl_recalc_runs"), (create_entries, "pnl_recalc_entries")] {
+            sqlx::query(statement)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create {} table: {}", table, e)))?;
+        }
+
+        info!("✅ P&L recalculation schema initialized");
+        Ok(())
+    }
+
+    /// Recompute every closed position with the current calculation
+    ///
+    /// Changed positions are rewritten and their previous values recorded
+    /// under the new run; a dry run only records the run summary.
+    pub async fn recompute(&self, dry_run: bool) -> Result<PnlRecalcRun, DatabaseError> {
+        let positions = sqlx::query_as::<_, Position>(
+            "SELECT * FROM positions WHERE status = 'CLOSED' AND exit_price IS NOT NULL ORDER BY id"
+        )
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch closed positions: {}", e)))?;
+
+        let mut tx = self.db.begin_transaction().await?;
+        let mut run = PnlRecalcRun {
+            id: 0,
+            version: PNL_CALC_VERSION,
+            fee_rate: self.fee_rate,
+            positions: positions.len() as i64,
+            changed: 0,
+            flipped_outcomes: 0,
+            pnl_before: 0.0,
+            pnl_after: 0.0,
+            dry_run,
+            completed_at: Utc::now().timestamp(),
+        };
+
+        let mut entries = Vec::new();
+        for position in &positions {
+            let exit_price = position.exit_price.unwrap_or_default();
+            let (pnl, fees) = closed_trade_pnl(position.entry_price, exit_price, position.quantity, self.fee_rate);
+            let old_outcome = position.pnl.map(trade_outcome);
+            let new_outcome = trade_outcome(pnl);
+
+            run.pnl_before += position.pnl.unwrap_or_default();
+            run.pnl_after += pnl;
+            let unchanged = position.pnl.is_some_and(|old| (old - pnl).abs() < 1e-12)
+                && (position.fees - fees).abs() < 1e-12;
+            if unchanged {
+                continue;
+            }
+            run.changed += 1;
+            if old_outcome != Some(new_outcome) {
+                run.flipped_outcomes += 1;
+            }
+            entries.push((position, pnl, fees, old_outcome, new_outcome));
+        }
+
+        let result = sqlx::query(r#"
+            INSERT INTO pnl_recalc_runs (
+                version, fee_rate, positions, changed, flipped_outcomes, pnl_before, pnl_after, dry_run, completed_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(run.version)
+        .bind(run.fee_rate)
+        .bind(run.positions)
+        .bind(run.changed)
+        .bind(run.flipped_outcomes)
+        .bind(run.pnl_before)
+        .bind(run.pnl_after)
+        .bind(run.dry_run)
+        .bind(run.completed_at)
+        .execute(&mut tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record P&L recalculation: {}", e)))?;
+        run.id = result.last_insert_rowid();
+
+        if !dry_run {
+            for (position, pnl, fees, old_outcome, new_outcome) in entries {
+                sqlx::query(r#"
+                    INSERT INTO pnl_recalc_entries (
+                        run_id, position_id, version, old_pnl, old_fees, old_outcome, new_pnl, new_fees, new_outcome
+                    ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#)
+                .bind(run.id)
+                .bind(position.id)
+                .bind(run.version)
+                .bind(position.pnl)
+                .bind(position.fees)
+                .bind(old_outcome)
+                .bind(pnl)
+                .bind(fees)
+                .bind(new_outcome)
+                .execute(&mut tx)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to record recalculated position: {}", e)))?;
+
+                sqlx::query("UPDATE positions SET pnl = ?, fees = ?, updated_at = ? WHERE id = ?")
+                    .bind(pnl)
+                    .bind(fees)
+                    .bind(run.completed_at)
+                    .bind(position.id)
+                    .execute(&mut tx)
+                    .await
+                    .map_err(|e| DatabaseError::QueryError(format!("Failed to rewrite position P&L: {}", e)))?;
+
+                sqlx::query("INSERT INTO position_updates (position_id, update_type, old_value, new_value) VALUES (?, 'PNL_RECALC', ?, ?)")
+                    .bind(position.id)
+                    .bind(position.pnl.map(|old| old.to_string()))
+                    .bind(pnl.to_string())
+                    .execute(&mut tx)
+                    .await
+                    .map_err(|e| DatabaseError::QueryError(format!("Failed to log position update: {}", e)))?;
+            }
+        }
+
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit P&L recalculation: {}", e)))?;
+        Ok(run)
+    }
+
+    /// Past recalculations, most recent first
+    pub async fn runs(&self, limit: i64) -> Result<Vec<PnlRecalcRun>, DatabaseError> {
+        sqlx::query_as::<_, PnlRecalcRun>("SELECT * FROM pnl_recalc_runs ORDER BY id DESC LIMIT ?")
+            .bind(limit)
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch P&L recalculations: {}", e)))
+    }
+}
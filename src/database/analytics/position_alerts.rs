@@ -0,0 +1,314 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{info, instrument};
+
+use super::super::{BadgerDatabase, DatabaseError};
+use super::Position;
+
+/// Return on a position crossed the gain threshold
+pub const ALERT_GAIN: &str = "GAIN";
+/// Return on a position crossed the loss threshold
+pub const ALERT_LOSS: &str = "LOSS";
+/// Unrealized P&L moved more than the threshold within the move window
+pub const ALERT_PNL_MOVE: &str = "PNL_MOVE";
+
+/// Alert thresholds of one position; 0 disables a rule
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PositionAlertRules {
+    /// Return at or above which a gain alert fires (1.0 = +100%)
+    pub gain_pct: f64,
+    /// Loss at or beyond which a loss alert fires (0.3 = -30%)
+    pub loss_pct: f64,
+    /// Change in unrealized P&L within the move window that fires a move alert (SOL)
+    pub pnl_move_sol: f64,
+}
+
+/// Per-position override; unset fields keep the default rule
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct PositionAlertOverride {
+    pub gain_pct: Option<f64>,
+    pub loss_pct: Option<f64>,
+    pub pnl_move_sol: Option<f64>,
+}
+
+impl PositionAlertOverride {
+    /// Rules with this override applied over `defaults`
+    pub fn apply(&self, defaults: PositionAlertRules) -> PositionAlertRules {
+        PositionAlertRules {
+            gain_pct: self.gain_pct.unwrap_or(defaults.gain_pct),
+            loss_pct: self.loss_pct.unwrap_or(defaults.loss_pct),
+            pnl_move_sol: self.pnl_move_sol.unwrap_or(defaults.pnl_move_sol),
+        }
+    }
+}
+
+/// Configuration for position alerts
+#[derive(Debug, Clone)]
+pub struct PositionAlertConfig {
+    /// Rules of positions without an override
+    pub defaults: PositionAlertRules,
+    /// Window the P&L move rule looks back over (seconds)
+    pub move_window_secs: i64,
+}
+
+impl Default for PositionAlertConfig {
+    fn default() -> Self {
+        Self {
+            defaults: PositionAlertRules {
+                gain_pct: 1.0,
+                loss_pct: 0.30,
+                pnl_move_sol: 0.5,
+            },
+            move_window_secs: 300,
+        }
+    }
+}
+
+impl PositionAlertConfig {
+    /// Defaults overridden by `BADGER_POSITION_ALERT_GAIN_PCT`, `BADGER_POSITION_ALERT_LOSS_PCT`
+    /// and `BADGER_POSITION_ALERT_MOVE_SOL`
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        let var = |name: &str| std::env::var(name).ok().and_then(|v| v.parse::<f64>().ok());
+        if let Some(pct) = var("BADGER_POSITION_ALERT_GAIN_PCT") {
+            config.defaults.gain_pct = pct;
+        }
+        if let Some(pct) = var("BADGER_POSITION_ALERT_LOSS_PCT") {
+            config.defaults.loss_pct = pct;
+        }
+        if let Some(sol) = var("BADGER_POSITION_ALERT_MOVE_SOL") {
+            config.defaults.pnl_move_sol = sol;
+        }
+        config
+    }
+}
+
+/// Alert fired for a position
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PositionAlert {
+    pub id: i64,
+    pub position_id: i64,
+    pub token_mint: String,
+    /// `GAIN`, `LOSS` or `PNL_MOVE`
+    pub kind: String,
+    pub price: f64,
+    /// Return on the position when the alert fired (1.0 = +100%)
+    pub return_pct: f64,
+    pub unrealized_pnl: f64,
+    /// P&L change over the move window; only set on move alerts (SOL)
+    pub pnl_change: Option<f64>,
+    pub fired_at: i64,
+}
+
+/// Evaluates per-position alert rules against live prices
+///
+/// Gain and loss thresholds fire once per position; the move rule fires at
+/// most once per window. Positions take the configured default rules unless
+/// an override was set for them. Fired alerts are stored so a restart does
+/// not repeat them.
+pub struct PositionAlertMonitor {
+    db: Arc<BadgerDatabase>,
+    config: PositionAlertConfig,
+    /// Position id -> recent (timestamp, unrealized P&L) samples
+    pnl_history: DashMap<i64, VecDeque<(i64, f64)>>,
+}
+
+impl PositionAlertMonitor {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<PositionAlertConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+            pnl_history: DashMap::new(),
+        }
+    }
+
+    pub fn config(&self) -> &PositionAlertConfig {
+        &self.config
+    }
+
+    /// Initialize position alert schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing position alert schema");
+
+        let create_rules = r#"
+            CREATE TABLE IF NOT EXISTS position_alert_rules (
+                position_id INTEGER PRIMARY KEY,
+                gain_pct REAL,
+                loss_pct REAL,
+                pnl_move_sol REAL,
+                updated_at INTEGER NOT NULL
+            )
+        "#;
+
+        let create_alerts = r#"
+            CREATE TABLE IF NOT EXISTS position_alerts (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                position_id INTEGER NOT NULL,
+                token_mint TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                price REAL NOT NULL,
+                return_pct REAL NOT NULL,
+                unrealized_pnl REAL NOT NULL,
+                pnl_change REAL,
+                fired_at INTEGER NOT NULL
+            )
+        "#;
+
+        for (statement, table) in [(create_rules, "position_alert_rules"), (create_alerts, "position_alerts")] {
+            sqlx::query(statement)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create {} table: {}", table, e)))?;
+        }
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_position_alerts_position ON position_alerts(position_id, kind, fired_at)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create position_alerts index: {}", e)))?;
+
+        info!("✅ Position alert schema initialized");
+        Ok(())
+    }
+
+    /// Set a position's override, replacing any earlier one
+    pub async fn set_override(&self, position_id: i64, rules: &PositionAlertOverride, now: i64) -> Result<(), DatabaseError> {
+        sqlx::query(r#"
+            INSERT INTO position_alert_rules (position_id, gain_pct, loss_pct, pnl_move_sol, updated_at)
+            VALUES (?, ?, ?, ?, ?)
+            ON CONFLICT(position_id) DO UPDATE SET
+                gain_pct = excluded.gain_pct,
+                loss_pct = excluded.loss_pct,
+                pnl_move_sol = excluded.pnl_move_sol,
+                updated_at = excluded.updated_at
+        "#)
+        .bind(position_id)
+        .bind(rules.gain_pct)
+        .bind(rules.loss_pct)
+        .bind(rules.pnl_move_sol)
+        .bind(now)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to set position alert rules: {}", e)))?;
+        Ok(())
+    }
+
+    /// Rules in effect for a position
+    pub async fn rules_for(&self, position_id: i64) -> Result<PositionAlertRules, DatabaseError> {
+        let rules_override = sqlx::query_as::<_, PositionAlertOverride>(
+            "SELECT gain_pct, loss_pct, pnl_move_sol FROM position_alert_rules WHERE position_id = ?"
+        )
+        .bind(position_id)
+        .fetch_optional(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch position alert rules: {}", e)))?;
+        Ok(rules_override.unwrap_or_default().apply(self.config.defaults))
+    }
+
+    /// Check an open position against its rules at the current price
+    ///
+    /// # Returns
+    /// * `Result<Vec<PositionAlert>, DatabaseError>` - Alerts fired by this check
+    pub async fn evaluate(&self, position: &Position, price: f64, now: i64) -> Result<Vec<PositionAlert>, DatabaseError> {
+        if position.entry_price <= 0.0 || price <= 0.0 {
+            return Ok(Vec::new());
+        }
+        let rules = self.rules_for(position.id).await?;
+        let return_pct = price / position.entry_price - 1.0;
+        let unrealized_pnl = (price - position.entry_price) * position.quantity;
+
+        // P&L change since the oldest sample still inside the window
+        let pnl_change = {
+            let mut history = self.pnl_history.entry(position.id).or_default();
+            history.push_back((now, unrealized_pnl));
+            while history.front().is_some_and(|(at, _)| now - at > self.config.move_window_secs) {
+                history.pop_front();
+            }
+            history.front().map(|(_, oldest)| unrealized_pnl - oldest)
+        };
+
+        let mut candidates = Vec::new();
+        if rules.gain_pct > 0.0 && return_pct >= rules.gain_pct {
+            candidates.push((ALERT_GAIN, None));
+        }
+        if rules.loss_pct > 0.0 && return_pct <= -rules.loss_pct {
+            candidates.push((ALERT_LOSS, None));
+        }
+        if let Some(change) = pnl_change.filter(|change| rules.pnl_move_sol > 0.0 && change.abs() >= rules.pnl_move_sol) {
+            candidates.push((ALERT_PNL_MOVE, Some(change)));
+        }
+
+        let mut fired = Vec::new();
+        for (kind, change) in candidates {
+            // Thresholds fire once per position, moves once per window
+            let since = if kind == ALERT_PNL_MOVE { now - self.config.move_window_secs } else { 0 };
+            if self.fired_since(position.id, kind, since).await? {
+                continue;
+            }
+            fired.push(self.record(PositionAlert {
+                id: 0,
+                position_id: position.id,
+                token_mint: position.token_mint.clone(),
+                kind: kind.to_string(),
+                price,
+                return_pct,
+                unrealized_pnl,
+                pnl_change: change,
+                fired_at: now,
+            }).await?);
+        }
+        Ok(fired)
+    }
+
+    /// Drop the P&L history of positions that are no longer open
+    pub fn retain_open(&self, open_position_ids: &[i64]) {
+        self.pnl_history.retain(|position_id, _| open_position_ids.contains(position_id));
+    }
+
+    /// Alerts fired for a position, most recent first
+    pub async fn alerts_for(&self, position_id: i64) -> Result<Vec<PositionAlert>, DatabaseError> {
+        sqlx::query_as::<_, PositionAlert>("SELECT * FROM position_alerts WHERE position_id = ? ORDER BY fired_at DESC")
+            .bind(position_id)
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch position alerts: {}", e)))
+    }
+
+    async fn fired_since(&self, position_id: i64, kind: &str, since: i64) -> Result<bool, DatabaseError> {
+        let fired: Option<i64> = sqlx::query_scalar(
+            "SELECT id FROM position_alerts WHERE position_id = ? AND kind = ? AND fired_at >= ? LIMIT 1"
+        )
+        .bind(position_id)
+        .bind(kind)
+        .bind(since)
+        .fetch_optional(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to check position alerts: {}", e)))?;
+        Ok(fired.is_some())
+    }
+
+    /// Store a fired alert; returns it with its row id
+    async fn record(&self, mut alert: PositionAlert) -> Result<PositionAlert, DatabaseError> {
+        let result = sqlx::query(r#"
+            INSERT INTO position_alerts (position_id, token_mint, kind, price, return_pct, unrealized_pnl, pnl_change, fired_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(alert.position_id)
+        .bind(&alert.token_mint)
+        .bind(&alert.kind)
+        .bind(alert.price)
+        .bind(alert.return_pct)
+        .bind(alert.unrealized_pnl)
+        .bind(alert.pnl_change)
+        .bind(alert.fired_at)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record position alert: {}", e)))?;
+
+        alert.id = result.last_insert_rowid();
+        Ok(alert)
+    }
+}
@@ -5,7 +5,8 @@ use serde::{Deserialize, Serialize};
 use sqlx::{Row, FromRow};
 use tracing::{debug, info, warn, error, instrument};
 
-use crate::core::{MarketEvent, TradingSignal};
+use crate::core::{chaos, forensics, TradingSignal};
+use crate::transport::{EnhancedTransportBus, PositionEvent};
 use super::super::{BadgerDatabase, DatabaseError};
 
 /// Position entry representing a trade position
@@ -42,10 +43,89 @@ pub struct PositionSummary {
     pub worst_trade: Option<f64>,
 }
 
+/// Quantities below this are treated as fully closed
+const QUANTITY_EPSILON: f64 = 1e-9;
+
+/// How much of a token's open quantity to close
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CloseAmount {
+    All,
+    /// Fraction of the open quantity in (0, 1]
+    Fraction(f64),
+    /// Tokens worth this much SOL at the exit price
+    Sol(f64),
+}
+
+impl CloseAmount {
+    /// Quantity to close out of `open_quantity`, never more than is open
+    pub fn quantity(&self, open_quantity: f64, exit_price: f64) -> f64 {
+        let quantity = match self {
+            CloseAmount::All => open_quantity,
+            CloseAmount::Fraction(fraction) => open_quantity * fraction,
+            CloseAmount::Sol(sol) if exit_price > 0.0 => sol / exit_price,
+            CloseAmount::Sol(_) => 0.0,
+        };
+        // `clamp` passes NaN through, which would close every lot
+        if quantity.is_nan() {
+            return 0.0;
+        }
+        quantity.clamp(0.0, open_quantity)
+    }
+}
+
+impl std::str::FromStr for CloseAmount {
+    type Err = String;
+
+    /// Parse `all`, a percentage (`50%`) or a SOL amount (`0.5 SOL`)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        if s == "all" {
+            return Ok(CloseAmount::All);
+        }
+        if let Some(percent) = s.strip_suffix('%') {
+            let percent: f64 = percent.trim().parse().map_err(|_| format!("Invalid percentage: {}", s))?;
+            // NaN fails every comparison, so it is rejected explicitly
+            if !percent.is_finite() || percent <= 0.0 || percent > 100.0 {
+                return Err(format!("Percentage must be in (0, 100]: {}", s));
+            }
+            return Ok(if percent >= 100.0 { CloseAmount::All } else { CloseAmount::Fraction(percent / 100.0) });
+        }
+        if let Some(sol) = s.strip_suffix("sol") {
+            let sol: f64 = sol.trim().parse().map_err(|_| format!("Invalid SOL amount: {}", s))?;
+            if !sol.is_finite() || sol <= 0.0 {
+                return Err(format!("SOL amount must be positive and finite: {}", s));
+            }
+            return Ok(CloseAmount::Sol(sol));
+        }
+        Err(format!("Expected 'all', a percentage like '50%' or an amount like '0.5 SOL', got '{}'", s))
+    }
+}
+
+/// Result of closing part or all of a token's open lots
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartialClose {
+    pub token_mint: String,
+    pub closed_quantity: f64,
+    pub remaining_quantity: f64,
+    pub exit_price: f64,
+    /// Net of the closed share of entry fees and the exit fees
+    pub realized_pnl: f64,
+    pub fees: f64,
+    /// Lots closed in full; a lot split by the close is not counted
+    pub lots_closed: usize,
+}
+
+impl PartialClose {
+    pub fn is_full_close(&self) -> bool {
+        self.remaining_quantity <= QUANTITY_EPSILON
+    }
+}
+
 /// Real-time position tracker for trading analytics
 pub struct PositionTracker {
     db: Arc<BadgerDatabase>,
     open_positions: Arc<tokio::sync::RwLock<HashMap<String, Position>>>,
+    transport_bus: Option<Arc<EnhancedTransportBus>>,
 }
 
 impl PositionTracker {
@@ -53,6 +133,23 @@ impl PositionTracker {
         Self {
             db,
             open_positions: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+            transport_bus: None,
+        }
+    }
+
+    /// Publish lifecycle events (opened, increased, stop triggered, closed) on the bus
+    pub fn with_transport_bus(mut self, transport_bus: Arc<EnhancedTransportBus>) -> Self {
+        self.transport_bus = Some(transport_bus);
+        self
+    }
+
+    /// Publish a position event; having no subscribers is not an error
+    async fn emit(&self, event: PositionEvent) {
+        if let Ok(detail) = serde_json::to_value(&event) {
+            forensics::trace(event.token_mint(), "position_event", detail);
+        }
+        if let Some(transport_bus) = &self.transport_bus {
+            let _ = transport_bus.publish_position_event(event).await;
         }
     }
 
@@ -155,6 +252,7 @@ impl PositionTracker {
         };
 
         // Insert position into database
+        chaos::delay_db_write().await;
         let position_id = sqlx::query(r#"
             INSERT INTO positions (
                 token_mint, entry_price, quantity, entry_timestamp, 
@@ -182,10 +280,43 @@ impl PositionTracker {
         opened_position.id = position_id;
 
         // Store in memory for quick access
-        {
+        let previous = {
             let mut open_positions = self.open_positions.write().await;
-            open_positions.insert(opened_position.token_mint.clone(), opened_position.clone());
-        }
+            open_positions.insert(opened_position.token_mint.clone(), opened_position.clone())
+        };
+
+        let event = match previous {
+            Some(_) => {
+                let total_quantity = sqlx::query_scalar::<_, f64>(
+                    "SELECT COALESCE(SUM(quantity), 0) FROM positions WHERE token_mint = ? AND status = 'OPEN'"
+                )
+                .bind(&opened_position.token_mint)
+                .fetch_one(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to sum open quantity: {}", e)))?;
+
+                PositionEvent::PositionIncreased {
+                    position_id,
+                    token_mint: opened_position.token_mint.clone(),
+                    added_quantity: quantity,
+                    total_quantity,
+                    entry_price,
+                    timestamp: now,
+                }
+            }
+            None => PositionEvent::PositionOpened {
+                position_id,
+                token_mint: opened_position.token_mint.clone(),
+                entry_price,
+                quantity,
+                cost_sol: entry_price * quantity,
+                fees,
+                signal_id: opened_position.signal_id.clone(),
+                insider_wallet: opened_position.insider_wallet.clone(),
+                timestamp: now,
+            },
+        };
+        self.emit(event).await;
 
         info!(
             "🔓 Opened position #{} for {} @ ${:.6} (qty: {}, fees: ${:.4})",
@@ -196,14 +327,18 @@ impl PositionTracker {
     }
 
     /// Close a position and calculate P&L
+    ///
+    /// # Arguments
+    /// * `exit_fee_rate` - Exit fees as a fraction of the sale value
     #[instrument(skip(self))]
     pub async fn close_position(
         &self,
         token_mint: &str,
         exit_price: f64,
-        exit_fees: f64,
+        exit_fee_rate: f64,
     ) -> Result<Option<Position>, DatabaseError> {
         let now = Utc::now().timestamp();
+        chaos::delay_db_write().await;
 
         // Find open position
         let position_id = sqlx::query_scalar::<_, i64>(
@@ -232,6 +367,7 @@ impl PositionTracker {
         .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch position: {}", e)))?;
 
         // Calculate P&L: (exit_price - entry_price) * quantity - total_fees
+        let exit_fees = exit_price * position.quantity * exit_fee_rate;
         let total_fees = position.fees + exit_fees;
         let gross_pnl = (exit_price - position.entry_price) * position.quantity;
         let net_pnl = gross_pnl - total_fees;
@@ -288,9 +424,240 @@ impl PositionTracker {
             roi_percentage
         );
 
+        self.emit(PositionEvent::PositionClosed {
+            position_id,
+            token_mint: token_mint.to_string(),
+            entry_price: closed_position.entry_price,
+            exit_price,
+            quantity: closed_position.quantity,
+            realized_pnl: net_pnl,
+            fees: total_fees,
+            hold_secs: now - closed_position.entry_timestamp,
+            timestamp: now,
+        }).await;
+
         Ok(Some(closed_position))
     }
 
+    /// Close part or all of a token's open quantity, oldest lot first
+    ///
+    /// Lots covered by the amount are closed; a lot the amount ends inside is
+    /// split into a CLOSED row carrying the sold quantity (with its share of
+    /// the entry fees) and the original row reduced to the rest, so realized
+    /// P&L always sits on closed rows.
+    ///
+    /// # Arguments
+    /// * `token_mint` - Token to sell
+    /// * `amount` - All, a fraction, or a SOL-denominated amount
+    /// * `exit_price` - Price the tokens are sold at
+    /// * `exit_fee_rate` - Exit fees as a fraction of the sale value
+    ///
+    /// # Returns
+    /// * `Result<Option<PartialClose>, DatabaseError>` - None when nothing is open for the token
+    #[instrument(skip(self))]
+    pub async fn close_position_amount(
+        &self,
+        token_mint: &str,
+        amount: CloseAmount,
+        exit_price: f64,
+        exit_fee_rate: f64,
+    ) -> Result<Option<PartialClose>, DatabaseError> {
+        let now = Utc::now().timestamp();
+        chaos::delay_db_write().await;
+
+        let lots = sqlx::query_as::<_, Position>(
+            "SELECT * FROM positions WHERE token_mint = ? AND status = 'OPEN' ORDER BY entry_timestamp, id"
+        )
+        .bind(token_mint)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch open lots: {}", e)))?;
+
+        if lots.is_empty() {
+            warn!("No open position found for token: {}", token_mint);
+            return Ok(None);
+        }
+
+        let open_quantity: f64 = lots.iter().map(|lot| lot.quantity).sum();
+        let target = amount.quantity(open_quantity, exit_price);
+        if target <= QUANTITY_EPSILON {
+            return Err(DatabaseError::QueryError(format!("Nothing to close for {} with {:?}", token_mint, amount)));
+        }
+
+        let mut tx = self.db.begin_transaction().await?;
+        let mut remaining = target;
+        let mut events = Vec::new();
+        let mut result = PartialClose {
+            token_mint: token_mint.to_string(),
+            closed_quantity: 0.0,
+            remaining_quantity: 0.0,
+            exit_price,
+            realized_pnl: 0.0,
+            fees: 0.0,
+            lots_closed: 0,
+        };
+
+        for lot in &lots {
+            if remaining <= QUANTITY_EPSILON {
+                break;
+            }
+            let closed_quantity = remaining.min(lot.quantity);
+            let full = lot.quantity - closed_quantity <= QUANTITY_EPSILON;
+            let entry_fees = if full { lot.fees } else { lot.fees * closed_quantity / lot.quantity };
+            let exit_fees = closed_quantity * exit_price * exit_fee_rate;
+            let pnl = (exit_price - lot.entry_price) * closed_quantity - entry_fees - exit_fees;
+
+            if full {
+                sqlx::query(r#"
+                    UPDATE positions
+                    SET exit_price = ?, exit_timestamp = ?, status = 'CLOSED',
+                        pnl = ?, fees = ?, updated_at = ?
+                    WHERE id = ?
+                "#)
+                .bind(exit_price)
+                .bind(now)
+                .bind(pnl)
+                .bind(entry_fees + exit_fees)
+                .bind(now)
+                .bind(lot.id)
+                .execute(&mut tx)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to close lot: {}", e)))?;
+
+                sqlx::query("INSERT INTO position_updates (position_id, update_type, old_value, new_value) VALUES (?, 'CLOSE', 'OPEN', 'CLOSED')")
+                    .bind(lot.id)
+                    .execute(&mut tx)
+                    .await
+                    .map_err(|e| DatabaseError::QueryError(format!("Failed to log position update: {}", e)))?;
+
+                result.lots_closed += 1;
+                events.push(PositionEvent::PositionClosed {
+                    position_id: lot.id,
+                    token_mint: token_mint.to_string(),
+                    entry_price: lot.entry_price,
+                    exit_price,
+                    quantity: closed_quantity,
+                    realized_pnl: pnl,
+                    fees: entry_fees + exit_fees,
+                    hold_secs: now - lot.entry_timestamp,
+                    timestamp: now,
+                });
+            } else {
+                // Split the sold quantity off into its own closed row
+                sqlx::query(r#"
+                    INSERT INTO positions (
+                        token_mint, entry_price, exit_price, quantity, entry_timestamp, exit_timestamp,
+                        position_type, status, pnl, fees, signal_id, insider_wallet,
+                        created_at, updated_at
+                    ) VALUES (?, ?, ?, ?, ?, ?, ?, 'CLOSED', ?, ?, ?, ?, ?, ?)
+                "#)
+                .bind(token_mint)
+                .bind(lot.entry_price)
+                .bind(exit_price)
+                .bind(closed_quantity)
+                .bind(lot.entry_timestamp)
+                .bind(now)
+                .bind(&lot.position_type)
+                .bind(pnl)
+                .bind(entry_fees + exit_fees)
+                .bind(&lot.signal_id)
+                .bind(&lot.insider_wallet)
+                .bind(now)
+                .bind(now)
+                .execute(&mut tx)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to insert closed lot: {}", e)))?;
+
+                let left = lot.quantity - closed_quantity;
+                sqlx::query("UPDATE positions SET quantity = ?, fees = ?, updated_at = ? WHERE id = ?")
+                    .bind(left)
+                    .bind(lot.fees - entry_fees)
+                    .bind(now)
+                    .bind(lot.id)
+                    .execute(&mut tx)
+                    .await
+                    .map_err(|e| DatabaseError::QueryError(format!("Failed to reduce lot: {}", e)))?;
+
+                sqlx::query("INSERT INTO position_updates (position_id, update_type, old_value, new_value) VALUES (?, 'PARTIAL_CLOSE', ?, ?)")
+                    .bind(lot.id)
+                    .bind(lot.quantity.to_string())
+                    .bind(left.to_string())
+                    .execute(&mut tx)
+                    .await
+                    .map_err(|e| DatabaseError::QueryError(format!("Failed to log position update: {}", e)))?;
+
+                events.push(PositionEvent::PositionReduced {
+                    position_id: lot.id,
+                    token_mint: token_mint.to_string(),
+                    closed_quantity,
+                    remaining_quantity: left,
+                    exit_price,
+                    realized_pnl: pnl,
+                    fees: entry_fees + exit_fees,
+                    timestamp: now,
+                });
+            }
+
+            result.closed_quantity += closed_quantity;
+            result.realized_pnl += pnl;
+            result.fees += entry_fees + exit_fees;
+            remaining -= closed_quantity;
+        }
+
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit close: {}", e)))?;
+        result.remaining_quantity = (open_quantity - result.closed_quantity).max(0.0);
+
+        // Keep the in-memory view on the newest lot still open
+        let newest_open = sqlx::query_as::<_, Position>(
+            "SELECT * FROM positions WHERE token_mint = ? AND status = 'OPEN' ORDER BY entry_timestamp DESC, id DESC LIMIT 1"
+        )
+        .bind(token_mint)
+        .fetch_optional(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch position: {}", e)))?;
+        {
+            let mut open_positions = self.open_positions.write().await;
+            match newest_open {
+                Some(position) => open_positions.insert(token_mint.to_string(), position),
+                None => open_positions.remove(token_mint),
+            };
+        }
+
+        info!(
+            "🔒 Closed {:.4} of {:.4} {} @ ${:.6} across {} lots | P&L: ${:.4}",
+            result.closed_quantity, open_quantity, token_mint, exit_price, events.len(), result.realized_pnl
+        );
+        for event in events {
+            self.emit(event).await;
+        }
+
+        Ok(Some(result))
+    }
+
+    /// Record that an exit was triggered by the stop loss, ahead of the close itself
+    ///
+    /// # Arguments
+    /// * `token_mint` - Token whose open position is being stopped out
+    /// * `stop_price` - Configured stop level
+    /// * `trigger_price` - Price that crossed the stop
+    pub async fn record_stop_triggered(&self, token_mint: &str, stop_price: f64, trigger_price: f64) {
+        let position_id = match self.open_positions.read().await.get(token_mint) {
+            Some(position) => position.id,
+            None => return,
+        };
+
+        info!("🛑 Stop triggered on position #{} for {} @ ${:.6} (stop ${:.6})",
+              position_id, token_mint, trigger_price, stop_price);
+        self.emit(PositionEvent::StopTriggered {
+            position_id,
+            token_mint: token_mint.to_string(),
+            stop_price,
+            trigger_price,
+            timestamp: Utc::now().timestamp(),
+        }).await;
+    }
+
     /// Get all open positions
     pub async fn get_open_positions(&self) -> Result<Vec<Position>, DatabaseError> {
         let positions = sqlx::query_as::<_, Position>(
@@ -310,12 +677,12 @@ impl PositionTracker {
                 COUNT(*) as total_positions,
                 SUM(CASE WHEN status = 'OPEN' THEN 1 ELSE 0 END) as open_positions,
                 SUM(CASE WHEN status = 'CLOSED' THEN 1 ELSE 0 END) as closed_positions,
-                COALESCE(SUM(CASE WHEN status = 'CLOSED' THEN pnl ELSE 0 END), 0) as total_pnl,
-                COALESCE(SUM(fees), 0) as total_fees,
+                COALESCE(SUM(CASE WHEN status = 'CLOSED' THEN pnl ELSE 0.0 END), 0.0) as total_pnl,
+                COALESCE(SUM(fees), 0.0) as total_fees,
                 COALESCE(AVG(CASE WHEN status = 'CLOSED' AND exit_timestamp IS NOT NULL 
-                    THEN exit_timestamp - entry_timestamp ELSE NULL END), 0) as avg_hold_time,
-                COALESCE(MAX(CASE WHEN status = 'CLOSED' THEN pnl ELSE NULL END), 0) as best_trade,
-                COALESCE(MIN(CASE WHEN status = 'CLOSED' THEN pnl ELSE NULL END), 0) as worst_trade
+                    THEN exit_timestamp - entry_timestamp ELSE NULL END), 0.0) as avg_hold_time,
+                COALESCE(MAX(CASE WHEN status = 'CLOSED' THEN pnl ELSE NULL END), 0.0) as best_trade,
+                COALESCE(MIN(CASE WHEN status = 'CLOSED' THEN pnl ELSE NULL END), 0.0) as worst_trade
             FROM positions
         "#)
         .fetch_one(self.db.get_pool())
@@ -403,6 +770,101 @@ impl PositionTracker {
         Ok(())
     }
 
+    /// Replace an open position's estimated entry with the amounts of its confirmed fill
+    ///
+    /// # Arguments
+    /// * `entry_price` - SOL paid per token received
+    /// * `quantity` - Tokens received
+    /// * `fees` - Fees actually paid on the entry
+    ///
+    /// # Returns
+    /// * `Result<bool, DatabaseError>` - False if the position is no longer open
+    pub async fn apply_entry_fill(&self, position_id: i64, entry_price: f64, quantity: f64, fees: f64) -> Result<bool, DatabaseError> {
+        let Some(position) = sqlx::query_as::<_, Position>("SELECT * FROM positions WHERE id = ? AND status = 'OPEN'")
+            .bind(position_id)
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch position: {}", e)))?
+        else {
+            return Ok(false);
+        };
+        let now = Utc::now().timestamp();
+
+        sqlx::query("UPDATE positions SET entry_price = ?, quantity = ?, fees = ?, updated_at = ? WHERE id = ?")
+            .bind(entry_price)
+            .bind(quantity)
+            .bind(fees)
+            .bind(now)
+            .bind(position_id)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to apply entry fill: {}", e)))?;
+
+        sqlx::query("INSERT INTO position_updates (position_id, update_type, old_value, new_value) VALUES (?, 'ENTRY_FILL', ?, ?)")
+            .bind(position_id)
+            .bind(format!("{}@{} fees {}", position.quantity, position.entry_price, position.fees))
+            .bind(format!("{}@{} fees {}", quantity, entry_price, fees))
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to log position update: {}", e)))?;
+
+        {
+            let mut open_positions = self.open_positions.write().await;
+            if let Some(open) = open_positions.get_mut(&position.token_mint).filter(|open| open.id == position_id) {
+                open.entry_price = entry_price;
+                open.quantity = quantity;
+                open.fees = fees;
+                open.updated_at = now;
+            }
+        }
+
+        debug!("🧾 Position #{} entry filled at {:.9} ({} tokens, was {:.9} x {})",
+            position_id, entry_price, quantity, position.entry_price, position.quantity);
+        Ok(true)
+    }
+
+    /// Replace a closed position's estimated exit with the price of its confirmed fill
+    ///
+    /// # Arguments
+    /// * `exit_price` - SOL received per token sold
+    /// * `fees` - Entry and exit fees actually paid
+    ///
+    /// # Returns
+    /// * `Result<Option<f64>, DatabaseError>` - Corrected P&L, or None if the position is not closed
+    pub async fn apply_exit_fill(&self, position_id: i64, exit_price: f64, fees: f64) -> Result<Option<f64>, DatabaseError> {
+        let Some(position) = sqlx::query_as::<_, Position>("SELECT * FROM positions WHERE id = ? AND status = 'CLOSED'")
+            .bind(position_id)
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch position: {}", e)))?
+        else {
+            return Ok(None);
+        };
+        let pnl = (exit_price - position.entry_price) * position.quantity - fees;
+
+        sqlx::query("UPDATE positions SET exit_price = ?, pnl = ?, fees = ?, updated_at = ? WHERE id = ?")
+            .bind(exit_price)
+            .bind(pnl)
+            .bind(fees)
+            .bind(Utc::now().timestamp())
+            .bind(position_id)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to apply exit fill: {}", e)))?;
+
+        sqlx::query("INSERT INTO position_updates (position_id, update_type, old_value, new_value) VALUES (?, 'EXIT_FILL', ?, ?)")
+            .bind(position_id)
+            .bind(format!("{}@{} pnl {}", position.quantity, position.exit_price.unwrap_or_default(), position.pnl.unwrap_or_default()))
+            .bind(format!("{}@{} pnl {}", position.quantity, exit_price, pnl))
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to log position update: {}", e)))?;
+
+        debug!("🧾 Position #{} exit filled at {:.9}, P&L {:.6} (was {:.6})",
+            position_id, exit_price, pnl, position.pnl.unwrap_or_default());
+        Ok(Some(pnl))
+    }
+
     /// Get recent position history
     pub async fn get_recent_positions(&self, limit: i64) -> Result<Vec<Position>, DatabaseError> {
         let positions = sqlx::query_as::<_, Position>(
@@ -0,0 +1,250 @@
+use std::sync::Arc;
+use chrono::Utc;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{info, instrument};
+
+use super::super::{BadgerDatabase, DatabaseError};
+use crate::transport::{CandleEvent, EnhancedTransportBus};
+
+/// Configuration for the price candle store
+#[derive(Debug, Clone)]
+pub struct PriceCandleConfig {
+    /// Width of one candle (seconds)
+    pub candle_secs: i64,
+    /// Candles older than this are pruned (seconds)
+    pub retention_secs: i64,
+    /// How often candles past retention are pruned (seconds)
+    pub prune_interval_secs: u64,
+    /// Timeframes published as `CandleClosed` events on the transport bus (seconds)
+    pub close_timeframes_secs: Vec<i64>,
+}
+
+impl Default for PriceCandleConfig {
+    fn default() -> Self {
+        Self {
+            candle_secs: 300,
+            retention_secs: 7 * 86400,
+            prune_interval_secs: 3600,
+            close_timeframes_secs: vec![60, 300, 900, 3600],
+        }
+    }
+}
+
+impl PriceCandleConfig {
+    /// Defaults overridden by `BADGER_CANDLE_TIMEFRAMES` (comma-separated seconds)
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Ok(value) = std::env::var("BADGER_CANDLE_TIMEFRAMES") {
+            let timeframes: Vec<i64> = value.split(',')
+                .filter_map(|timeframe| timeframe.trim().parse().ok())
+                .filter(|timeframe| *timeframe > 0)
+                .collect();
+            if !timeframes.is_empty() {
+                config.close_timeframes_secs = timeframes;
+            }
+        }
+        config
+    }
+}
+
+/// Open/high/low/close of the prices sampled within one candle
+///
+/// Token candles are in SOL per token; the SOL candle (under the SOL mint)
+/// is in USD per SOL.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PriceCandle {
+    pub token_mint: String,
+    /// Unix time the candle opens at
+    pub bucket_start: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub samples: i64,
+}
+
+impl PriceCandle {
+    fn closed_event(&self, timeframe_secs: i64) -> CandleEvent {
+        CandleEvent::CandleClosed {
+            token_mint: self.token_mint.clone(),
+            timeframe_secs,
+            bucket_start: self.bucket_start,
+            open: self.open,
+            high: self.high,
+            low: self.low,
+            close: self.close,
+            samples: self.samples,
+        }
+    }
+}
+
+/// Candles built from the prices the market data service fetches
+///
+/// Only `candle_secs` candles are stored. Candles of every close timeframe
+/// are also kept open in memory and published as `CandleClosed` once their
+/// window ends, so strategies and monitors act on bar close without
+/// re-deriving windows from stored candles.
+pub struct PriceCandleStore {
+    db: Arc<BadgerDatabase>,
+    config: PriceCandleConfig,
+    /// Open candle per (token mint, timeframe)
+    open_candles: DashMap<(String, i64), PriceCandle>,
+    transport_bus: Option<Arc<EnhancedTransportBus>>,
+}
+
+impl PriceCandleStore {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<PriceCandleConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+            open_candles: DashMap::new(),
+            transport_bus: None,
+        }
+    }
+
+    /// Publish `CandleClosed` events on the bus
+    pub fn with_transport_bus(mut self, transport_bus: Arc<EnhancedTransportBus>) -> Self {
+        self.transport_bus = Some(transport_bus);
+        self
+    }
+
+    pub fn config(&self) -> &PriceCandleConfig {
+        &self.config
+    }
+
+    /// Initialize price candle schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing price candle schema");
+
+        let create_price_candles = r#"
+            CREATE TABLE IF NOT EXISTS price_candles (
+                token_mint TEXT NOT NULL,
+                bucket_start INTEGER NOT NULL,
+                open REAL NOT NULL,
+                high REAL NOT NULL,
+                low REAL NOT NULL,
+                close REAL NOT NULL,
+                samples INTEGER NOT NULL DEFAULT 1,
+                PRIMARY KEY (token_mint, bucket_start)
+            )
+        "#;
+
+        sqlx::query(create_price_candles)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create price_candles table: {}", e)))?;
+
+        info!("✅ Price candle schema initialized");
+        Ok(())
+    }
+
+    /// Fold a price sample into the candle covering `at`
+    pub async fn record(&self, token_mint: &str, price: f64, at: i64) -> Result<(), DatabaseError> {
+        if price.is_nan() || price <= 0.0 {
+            return Ok(());
+        }
+        let bucket_start = at - at.rem_euclid(self.config.candle_secs);
+
+        sqlx::query(r#"
+            INSERT INTO price_candles (token_mint, bucket_start, open, high, low, close, samples)
+            VALUES (?, ?, ?, ?, ?, ?, 1)
+            ON CONFLICT (token_mint, bucket_start) DO UPDATE SET
+                high = MAX(high, excluded.high),
+                low = MIN(low, excluded.low),
+                close = excluded.close,
+                samples = samples + 1
+        "#)
+        .bind(token_mint)
+        .bind(bucket_start)
+        .bind(price)
+        .bind(price)
+        .bind(price)
+        .bind(price)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record price candle: {}", e)))?;
+
+        for &timeframe_secs in &self.config.close_timeframes_secs {
+            let bucket_start = at - at.rem_euclid(timeframe_secs);
+            let key = (token_mint.to_string(), timeframe_secs);
+            let closed = match self.open_candles.get_mut(&key) {
+                Some(mut candle) if candle.bucket_start == bucket_start => {
+                    candle.high = candle.high.max(price);
+                    candle.low = candle.low.min(price);
+                    candle.close = price;
+                    candle.samples += 1;
+                    continue;
+                }
+                // Late samples for an already closed window are dropped
+                Some(candle) if candle.bucket_start > bucket_start => continue,
+                Some(candle) => Some(candle.clone()),
+                None => None,
+            };
+            self.open_candles.insert(key, PriceCandle {
+                token_mint: token_mint.to_string(),
+                bucket_start,
+                open: price,
+                high: price,
+                low: price,
+                close: price,
+                samples: 1,
+            });
+            if let Some(candle) = closed {
+                self.publish_close(&candle, timeframe_secs).await;
+            }
+        }
+        Ok(())
+    }
+
+    /// Close open candles whose window ended by `now`, including tokens no longer sampled
+    ///
+    /// # Returns
+    /// * `usize` - Number of candles closed
+    pub async fn close_due(&self, now: i64) -> usize {
+        let due: Vec<(String, i64)> = self.open_candles.iter()
+            .filter(|entry| entry.bucket_start + entry.key().1 <= now)
+            .map(|entry| entry.key().clone())
+            .collect();
+
+        let mut closed = 0;
+        for key in due {
+            if let Some(((_, timeframe_secs), candle)) = self.open_candles.remove(&key) {
+                self.publish_close(&candle, timeframe_secs).await;
+                closed += 1;
+            }
+        }
+        closed
+    }
+
+    async fn publish_close(&self, candle: &PriceCandle, timeframe_secs: i64) {
+        if let Some(transport_bus) = &self.transport_bus {
+            let _ = transport_bus.publish_candle_event(candle.closed_event(timeframe_secs)).await;
+        }
+    }
+
+    /// Candles of a token opening at or after `since`, oldest first
+    pub async fn candles(&self, token_mint: &str, since: i64) -> Result<Vec<PriceCandle>, DatabaseError> {
+        sqlx::query_as::<_, PriceCandle>(
+            "SELECT * FROM price_candles WHERE token_mint = ? AND bucket_start >= ? ORDER BY bucket_start"
+        )
+        .bind(token_mint)
+        .bind(since)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch price candles: {}", e)))
+    }
+
+    /// Delete candles past retention; returns how many were removed
+    pub async fn prune(&self) -> Result<u64, DatabaseError> {
+        let cutoff = Utc::now().timestamp() - self.config.retention_secs;
+        let result = sqlx::query("DELETE FROM price_candles WHERE bucket_start < ?")
+            .bind(cutoff)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to prune price candles: {}", e)))?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,301 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument};
+
+use crate::database::{BadgerDatabase, DatabaseError};
+use super::{PositionTracker, PnLCalculator, WalletPortfolio};
+
+/// One-sided z-scores for parametric VaR
+const Z_95: f64 = 1.645;
+const Z_99: f64 = 2.326;
+
+/// Stress scenario applied to the current book
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StressScenario {
+    pub name: String,
+    /// Fractional drop in meme token prices measured in SOL (0.8 = -80%)
+    pub meme_shock: f64,
+    /// Fractional drop in SOL/USD
+    pub sol_shock: f64,
+}
+
+/// Configuration for risk analytics
+#[derive(Debug, Clone)]
+pub struct RiskConfig {
+    /// Per-trade return volatility used until enough closed trades exist
+    pub default_meme_volatility: f64,
+    /// Minimum closed trades before historical volatility replaces the default
+    pub min_return_samples: usize,
+    /// Closed trades considered for historical volatility (days)
+    pub lookback_days: i64,
+    /// Meme sensitivity to SOL moves; SOL shocks are amplified by (beta - 1) in SOL terms
+    pub meme_sol_beta: f64,
+    /// Alert when 99% VaR exceeds this many SOL
+    pub max_var_99_sol: f64,
+    /// Scenarios evaluated on every run
+    pub scenarios: Vec<StressScenario>,
+}
+
+impl Default for RiskConfig {
+    fn default() -> Self {
+        Self {
+            default_meme_volatility: 0.5,
+            min_return_samples: 20,
+            lookback_days: 30,
+            meme_sol_beta: 1.5,
+            max_var_99_sol: 5.0,
+            scenarios: vec![
+                StressScenario { name: "All memes -80%".to_string(), meme_shock: 0.8, sol_shock: 0.0 },
+                StressScenario { name: "SOL -30%".to_string(), meme_shock: 0.0, sol_shock: 0.3 },
+            ],
+        }
+    }
+}
+
+/// Loss under a stress scenario
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioResult {
+    pub name: String,
+    /// Loss in SOL terms
+    pub loss_sol: f64,
+    /// Loss as a fraction of book value in USD terms
+    pub loss_pct_usd: f64,
+}
+
+/// Portfolio risk measures for the current book
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskReport {
+    pub exposure_sol: f64,
+    pub open_positions: usize,
+    pub largest_position_sol: f64,
+    /// Largest position as a fraction of total exposure
+    pub concentration: f64,
+    pub meme_volatility: f64,
+    pub volatility_samples: usize,
+    pub parametric_var_95: f64,
+    pub parametric_var_99: f64,
+    /// Historical-simulation VaR from closed-trade returns (when enough samples)
+    pub historical_var_95: Option<f64>,
+    pub expected_shortfall_95: Option<f64>,
+    pub scenarios: Vec<ScenarioResult>,
+    /// SOL held across all tracked wallets plus open exposure (None without wallet balances)
+    pub capital_sol: Option<f64>,
+    /// 99% VaR as a fraction of capital
+    pub var_99_of_capital: Option<f64>,
+    pub calculated_at: i64,
+}
+
+/// Value-at-Risk and stress testing over open positions
+pub struct RiskAnalytics {
+    db: Arc<BadgerDatabase>,
+    position_tracker: Arc<PositionTracker>,
+    pnl_calculator: Arc<PnLCalculator>,
+    wallet_portfolio: Option<Arc<WalletPortfolio>>,
+    config: RiskConfig,
+}
+
+impl RiskAnalytics {
+    pub fn new(
+        db: Arc<BadgerDatabase>,
+        position_tracker: Arc<PositionTracker>,
+        pnl_calculator: Arc<PnLCalculator>,
+        wallet_portfolio: Option<Arc<WalletPortfolio>>,
+        config: Option<RiskConfig>,
+    ) -> Self {
+        Self {
+            db,
+            position_tracker,
+            pnl_calculator,
+            wallet_portfolio,
+            config: config.unwrap_or_default(),
+        }
+    }
+
+    pub fn config(&self) -> &RiskConfig {
+        &self.config
+    }
+
+    /// Initialize risk analytics schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing risk analytics schema");
+
+        let create_risk_snapshots = r#"
+            CREATE TABLE IF NOT EXISTS risk_snapshots (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                snapshot_type TEXT NOT NULL CHECK (snapshot_type IN ('REALTIME', 'HOURLY', 'DAILY')),
+                exposure_sol REAL NOT NULL,
+                open_positions INTEGER NOT NULL,
+                largest_position_sol REAL NOT NULL,
+                concentration REAL NOT NULL,
+                meme_volatility REAL NOT NULL,
+                parametric_var_95 REAL NOT NULL,
+                parametric_var_99 REAL NOT NULL,
+                historical_var_95 REAL,
+                expected_shortfall_95 REAL,
+                scenarios TEXT NOT NULL,
+                capital_sol REAL,
+                timestamp INTEGER NOT NULL
+            )
+        "#;
+
+        sqlx::query(create_risk_snapshots)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create risk_snapshots table: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_risk_snapshots_timestamp ON risk_snapshots(timestamp)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        info!("✅ Risk analytics schema initialized");
+        Ok(())
+    }
+
+    /// Per-trade returns of recently closed positions
+    async fn closed_trade_returns(&self) -> Result<Vec<f64>, DatabaseError> {
+        let since = Utc::now().timestamp() - self.config.lookback_days * 86400;
+
+        let rows = sqlx::query_as::<_, (f64, f64, f64)>(r#"
+            SELECT entry_price, quantity, pnl FROM positions
+            WHERE status = 'CLOSED' AND pnl IS NOT NULL AND exit_timestamp >= ?
+        "#)
+        .bind(since)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch closed trade returns: {}", e)))?;
+
+        Ok(rows.into_iter()
+            .filter(|(entry_price, quantity, _)| entry_price * quantity > 0.0)
+            .map(|(entry_price, quantity, pnl)| pnl / (entry_price * quantity))
+            .collect())
+    }
+
+    /// Compute VaR, concentration and stress losses for the current book
+    #[instrument(skip(self))]
+    pub async fn calculate_risk(&self) -> Result<RiskReport, DatabaseError> {
+        let positions = self.position_tracker.get_open_positions().await?;
+
+        let mut exposure_sol = 0.0;
+        let mut largest_position_sol: f64 = 0.0;
+        for position in &positions {
+            let price = self.pnl_calculator
+                .get_current_price(&position.token_mint)
+                .await
+                .unwrap_or(position.entry_price);
+            let value = position.quantity * price;
+            exposure_sol += value;
+            largest_position_sol = largest_position_sol.max(value);
+        }
+
+        let returns = self.closed_trade_returns().await?;
+        let enough_samples = returns.len() >= self.config.min_return_samples;
+
+        let meme_volatility = if enough_samples {
+            let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+            let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (returns.len() - 1) as f64;
+            variance.sqrt()
+        } else {
+            self.config.default_meme_volatility
+        };
+
+        // Memes move together, so treat the book as a single position
+        let parametric_var_95 = (exposure_sol * Z_95 * meme_volatility).min(exposure_sol);
+        let parametric_var_99 = (exposure_sol * Z_99 * meme_volatility).min(exposure_sol);
+
+        let (historical_var_95, expected_shortfall_95) = if enough_samples {
+            let mut sorted = returns.clone();
+            sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            let tail_len = ((sorted.len() as f64) * 0.05).ceil().max(1.0) as usize;
+            let tail = &sorted[..tail_len];
+            let var_return = tail[tail_len - 1];
+            let es_return = tail.iter().sum::<f64>() / tail.len() as f64;
+            (
+                Some((-var_return * exposure_sol).clamp(0.0, exposure_sol)),
+                Some((-es_return * exposure_sol).clamp(0.0, exposure_sol)),
+            )
+        } else {
+            (None, None)
+        };
+
+        let scenarios = self.config.scenarios.iter()
+            .map(|scenario| {
+                // SOL moves spill over into memes with (beta - 1) extra sensitivity in SOL terms
+                let meme_drop = (scenario.meme_shock
+                    + scenario.sol_shock * (self.config.meme_sol_beta - 1.0)).clamp(0.0, 1.0);
+                ScenarioResult {
+                    name: scenario.name.clone(),
+                    loss_sol: exposure_sol * meme_drop,
+                    loss_pct_usd: 1.0 - (1.0 - meme_drop) * (1.0 - scenario.sol_shock),
+                }
+            })
+            .collect();
+
+        // Risk relative to everything we hold, not only the trading wallet
+        let capital_sol = match &self.wallet_portfolio {
+            Some(portfolio) => {
+                let summary = portfolio.summary().await?;
+                (!summary.wallets.is_empty()).then_some(summary.total_sol + exposure_sol)
+            }
+            None => None,
+        };
+        let var_99_of_capital = capital_sol
+            .filter(|capital| *capital > 0.0)
+            .map(|capital| parametric_var_99 / capital);
+
+        let report = RiskReport {
+            exposure_sol,
+            open_positions: positions.len(),
+            largest_position_sol,
+            concentration: if exposure_sol > 0.0 { largest_position_sol / exposure_sol } else { 0.0 },
+            meme_volatility,
+            volatility_samples: returns.len(),
+            parametric_var_95,
+            parametric_var_99,
+            historical_var_95,
+            expected_shortfall_95,
+            scenarios,
+            capital_sol,
+            var_99_of_capital,
+            calculated_at: Utc::now().timestamp(),
+        };
+
+        debug!("📉 Risk: exposure {:.3} SOL, VaR99 {:.3} SOL", report.exposure_sol, report.parametric_var_99);
+        Ok(report)
+    }
+
+    /// Save a risk snapshot
+    pub async fn save_risk_snapshot(&self, report: &RiskReport, snapshot_type: &str) -> Result<(), DatabaseError> {
+        let scenarios = serde_json::to_string(&report.scenarios)
+            .map_err(|e| DatabaseError::SerializationError(format!("Failed to serialize scenarios: {}", e)))?;
+
+        sqlx::query(r#"
+            INSERT INTO risk_snapshots (
+                snapshot_type, exposure_sol, open_positions, largest_position_sol, concentration,
+                meme_volatility, parametric_var_95, parametric_var_99, historical_var_95,
+                expected_shortfall_95, scenarios, capital_sol, timestamp
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(snapshot_type)
+        .bind(report.exposure_sol)
+        .bind(report.open_positions as i64)
+        .bind(report.largest_position_sol)
+        .bind(report.concentration)
+        .bind(report.meme_volatility)
+        .bind(report.parametric_var_95)
+        .bind(report.parametric_var_99)
+        .bind(report.historical_var_95)
+        .bind(report.expected_shortfall_95)
+        .bind(scenarios)
+        .bind(report.capital_sol)
+        .bind(report.calculated_at)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to save risk snapshot: {}", e)))?;
+
+        debug!("💾 Saved {} risk snapshot: VaR99 {:.3} SOL", snapshot_type, report.parametric_var_99);
+        Ok(())
+    }
+}
@@ -0,0 +1,291 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::Row;
+use tracing::{info, warn, instrument};
+
+use super::pnl_calculator::PnLCalculator;
+use super::treasury::TreasuryLedger;
+use super::super::{BadgerDatabase, DatabaseError};
+
+/// One step of the de-leveraging ladder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleverageTier {
+    /// Drawdown from peak equity at which the tier applies (0.1 = -10%)
+    pub drawdown: f64,
+    /// Multiplier on requested entry sizes
+    pub size_multiplier: f64,
+    /// Most positions that may be open at once; None leaves the count uncapped
+    pub max_open_positions: Option<usize>,
+}
+
+/// Configuration for drawdown-based de-leveraging
+#[derive(Debug, Clone)]
+pub struct RiskManagerConfig {
+    /// Ladder tiers, shallowest drawdown first
+    pub tiers: Vec<DeleverageTier>,
+    /// Drawdown must recover this far below a tier's threshold before the tier is lifted
+    pub recovery_margin: f64,
+    /// Starting capital not recorded as a treasury deposit (SOL)
+    pub base_equity_sol: f64,
+    /// How often equity is re-evaluated (seconds)
+    pub refresh_interval_secs: u64,
+}
+
+impl Default for RiskManagerConfig {
+    fn default() -> Self {
+        Self {
+            tiers: vec![
+                DeleverageTier { drawdown: 0.10, size_multiplier: 0.5, max_open_positions: Some(10) },
+                DeleverageTier { drawdown: 0.20, size_multiplier: 0.25, max_open_positions: Some(5) },
+                DeleverageTier { drawdown: 0.30, size_multiplier: 0.1, max_open_positions: Some(2) },
+            ],
+            recovery_margin: 0.02,
+            base_equity_sol: 0.0,
+            refresh_interval_secs: 30,
+        }
+    }
+}
+
+impl RiskManagerConfig {
+    /// Defaults with the starting capital from `BADGER_BASE_EQUITY_SOL` when set
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(equity) = std::env::var("BADGER_BASE_EQUITY_SOL").ok().and_then(|v| v.parse::<f64>().ok()) {
+            config.base_equity_sol = equity;
+        }
+        config
+    }
+}
+
+/// Entry limits in force at the current drawdown
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskLimits {
+    /// Ladder tier in force; 0 is full size
+    pub tier: usize,
+    pub equity_sol: f64,
+    pub peak_equity_sol: f64,
+    /// Drawdown from peak equity (0.0 - 1.0)
+    pub drawdown: f64,
+    pub size_multiplier: f64,
+    pub max_open_positions: Option<usize>,
+}
+
+impl Default for RiskLimits {
+    fn default() -> Self {
+        Self {
+            tier: 0,
+            equity_sol: 0.0,
+            peak_equity_sol: 0.0,
+            drawdown: 0.0,
+            size_multiplier: 1.0,
+            max_open_positions: None,
+        }
+    }
+}
+
+/// A new order refused because the tier's position count is reached
+#[derive(Debug, Clone)]
+pub struct DeleverageRefusal {
+    pub tier: usize,
+    pub open_positions: usize,
+    pub max_open_positions: usize,
+}
+
+/// Equity high-water mark carried across restarts
+struct EquityState {
+    peak_equity_sol: f64,
+    /// Net treasury flows already folded into the peak
+    net_flows_sol: f64,
+    limits: RiskLimits,
+}
+
+/// Risk policy consulted on every new order
+///
+/// Instead of a single circuit breaker, entries shrink in steps as equity
+/// falls from its peak: each ladder tier scales entry sizes down and caps how
+/// many positions may be open, and tiers are lifted again as equity recovers.
+/// Equity is recorded capital (treasury flows plus realized P&L) plus
+/// unrealized P&L; deposits and withdrawals move the peak with them so they
+/// never read as gains or drawdowns.
+pub struct RiskManager {
+    db: Arc<BadgerDatabase>,
+    treasury: Arc<TreasuryLedger>,
+    pnl_calculator: Arc<PnLCalculator>,
+    config: RiskManagerConfig,
+    state: tokio::sync::RwLock<EquityState>,
+}
+
+impl RiskManager {
+    pub fn new(
+        db: Arc<BadgerDatabase>,
+        treasury: Arc<TreasuryLedger>,
+        pnl_calculator: Arc<PnLCalculator>,
+        config: Option<RiskManagerConfig>,
+    ) -> Self {
+        let mut config = config.unwrap_or_default();
+        config.tiers.sort_by(|a, b| a.drawdown.total_cmp(&b.drawdown));
+        Self {
+            db,
+            treasury,
+            pnl_calculator,
+            config,
+            state: tokio::sync::RwLock::new(EquityState {
+                peak_equity_sol: 0.0,
+                net_flows_sol: 0.0,
+                limits: RiskLimits::default(),
+            }),
+        }
+    }
+
+    pub fn config(&self) -> &RiskManagerConfig {
+        &self.config
+    }
+
+    /// Initialize risk manager schema and restore the equity peak
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing risk manager schema");
+
+        let create_risk_equity_state = r#"
+            CREATE TABLE IF NOT EXISTS risk_equity_state (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                peak_equity_sol REAL NOT NULL,
+                net_flows_sol REAL NOT NULL DEFAULT 0.0,
+                tier INTEGER NOT NULL DEFAULT 0,
+                updated_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now'))
+            )
+        "#;
+
+        sqlx::query(create_risk_equity_state)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create risk_equity_state table: {}", e)))?;
+
+        let row = sqlx::query("SELECT peak_equity_sol, net_flows_sol, tier FROM risk_equity_state WHERE id = 1")
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to load risk equity state: {}", e)))?;
+        if let Some(row) = row {
+            let mut state = self.state.write().await;
+            state.peak_equity_sol = row.get("peak_equity_sol");
+            state.net_flows_sol = row.get("net_flows_sol");
+            let tier: i64 = row.get("tier");
+            state.limits = self.limits_for_tier(tier as usize);
+        }
+
+        info!("✅ Risk manager schema initialized");
+        Ok(())
+    }
+
+    /// Limits currently in force
+    pub async fn limits(&self) -> RiskLimits {
+        self.state.read().await.limits.clone()
+    }
+
+    /// Size a new order under the current tier
+    ///
+    /// # Arguments
+    /// * `requested_sol` - Entry size before de-leveraging
+    /// * `open_positions` - Positions currently open
+    ///
+    /// # Returns
+    /// * `Result<f64, DeleverageRefusal>` - Allowed entry size, or the refusal when the tier's position cap is reached
+    pub async fn size_new_order(&self, requested_sol: f64, open_positions: usize) -> Result<f64, DeleverageRefusal> {
+        let limits = self.limits().await;
+        if let Some(max_open_positions) = limits.max_open_positions {
+            if open_positions >= max_open_positions {
+                return Err(DeleverageRefusal { tier: limits.tier, open_positions, max_open_positions });
+            }
+        }
+        Ok(requested_sol * limits.size_multiplier)
+    }
+
+    /// Re-evaluate equity and move along the ladder
+    ///
+    /// # Returns
+    /// * `Result<(RiskLimits, bool), DatabaseError>` - Limits now in force and whether the tier changed
+    #[instrument(skip(self))]
+    pub async fn refresh(&self) -> Result<(RiskLimits, bool), DatabaseError> {
+        let now = Utc::now().timestamp();
+        let net_flows = self.treasury.net_flow_between(i64::MIN, now).await?;
+        let recorded_capital = self.config.base_equity_sol + self.treasury.capital_at(now).await?;
+        let unrealized = self.pnl_calculator.calculate_portfolio_pnl().await?.total_unrealized_pnl;
+        let equity = recorded_capital + unrealized;
+
+        let mut state = self.state.write().await;
+        let previous_tier = state.limits.tier;
+
+        // Without recorded capital there is no equity to draw down from
+        if self.config.base_equity_sol + net_flows <= 0.0 {
+            if previous_tier != 0 || state.peak_equity_sol != 0.0 {
+                warn!("No capital recorded (treasury deposits or BADGER_BASE_EQUITY_SOL); de-leveraging ladder disabled");
+            }
+            state.peak_equity_sol = 0.0;
+            state.net_flows_sol = net_flows;
+            state.limits = RiskLimits { equity_sol: equity, ..RiskLimits::default() };
+        } else {
+            state.peak_equity_sol = (state.peak_equity_sol + net_flows - state.net_flows_sol).max(equity);
+            state.net_flows_sol = net_flows;
+            let drawdown = if state.peak_equity_sol > 0.0 {
+                ((state.peak_equity_sol - equity) / state.peak_equity_sol).max(0.0)
+            } else {
+                0.0
+            };
+
+            // Step down as soon as a threshold is crossed, back up only past the recovery margin
+            let breached = self.config.tiers.iter().filter(|tier| drawdown >= tier.drawdown).count();
+            let mut tier = previous_tier.min(self.config.tiers.len());
+            if breached > tier {
+                tier = breached;
+            } else {
+                while tier > breached && drawdown < self.config.tiers[tier - 1].drawdown - self.config.recovery_margin {
+                    tier -= 1;
+                }
+            }
+
+            state.limits = RiskLimits {
+                equity_sol: equity,
+                peak_equity_sol: state.peak_equity_sol,
+                drawdown,
+                ..self.limits_for_tier(tier)
+            };
+        }
+
+        sqlx::query(r#"
+            INSERT INTO risk_equity_state (id, peak_equity_sol, net_flows_sol, tier, updated_at)
+            VALUES (1, ?, ?, ?, ?)
+            ON CONFLICT(id) DO UPDATE SET
+                peak_equity_sol = excluded.peak_equity_sol,
+                net_flows_sol = excluded.net_flows_sol,
+                tier = excluded.tier,
+                updated_at = excluded.updated_at
+        "#)
+        .bind(state.peak_equity_sol)
+        .bind(state.net_flows_sol)
+        .bind(state.limits.tier as i64)
+        .bind(now)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to save risk equity state: {}", e)))?;
+
+        let changed = state.limits.tier != previous_tier;
+        if changed {
+            info!("📉 De-leveraging tier {} -> {} (drawdown {:.1}%, equity {:.4} SOL)",
+                previous_tier, state.limits.tier, state.limits.drawdown * 100.0, equity);
+        }
+        Ok((state.limits.clone(), changed))
+    }
+
+    fn limits_for_tier(&self, tier: usize) -> RiskLimits {
+        match tier.checked_sub(1).and_then(|i| self.config.tiers.get(i)) {
+            Some(step) => RiskLimits {
+                tier,
+                size_multiplier: step.size_multiplier,
+                max_open_positions: step.max_open_positions,
+                ..RiskLimits::default()
+            },
+            None => RiskLimits::default(),
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use std::path::Path;
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::info;
+
+use crate::database::{BadgerDatabase, DatabaseError};
+
+/// Profile statistics an insider is scored on
+#[derive(Debug, Clone, Default, Serialize, Deserialize, FromRow)]
+pub struct InsiderFeatures {
+    /// Winning share of scored trades (0.0 - 1.0)
+    pub success_rate: f64,
+    pub total_trades: i64,
+    pub roi_percentage: f64,
+    /// Trades per day
+    pub trading_frequency: f64,
+    /// Spread of copied position returns (0 - 100)
+    pub risk_score: f64,
+    /// Hours
+    pub average_hold_time: f64,
+}
+
+impl InsiderFeatures {
+    /// Features scaled to roughly [-1, 1] so model weights are comparable
+    pub fn normalized(&self) -> [f64; 6] {
+        [
+            self.success_rate,
+            self.total_trades.min(100) as f64 / 100.0,
+            self.roi_percentage.clamp(-100.0, 100.0) / 100.0,
+            self.trading_frequency.min(10.0) / 10.0,
+            self.risk_score.clamp(0.0, 100.0) / 100.0,
+            self.average_hold_time.min(48.0) / 48.0,
+        ]
+    }
+}
+
+/// Scores produced for an insider profile
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InsiderScore {
+    /// Confidence in the insider's edge (0 - 100)
+    pub confidence_score: f64,
+    /// Overall score used to rank and copy insiders (0 - 100)
+    pub copy_worthiness: f64,
+}
+
+/// Turns insider profile statistics into confidence and copy-worthiness scores
+///
+/// Implementations are swapped via `BADGER_SCORING_MODEL` without touching
+/// `InsiderAnalytics`.
+pub trait ScoringModel: Send + Sync {
+    /// Model name for logging
+    fn name(&self) -> &str;
+
+    fn score(&self, features: &InsiderFeatures) -> InsiderScore;
+}
+
+/// The hand-tuned weighted formula
+pub struct WeightedScoringModel;
+
+impl ScoringModel for WeightedScoringModel {
+    fn name(&self) -> &str {
+        "weighted"
+    }
+
+    fn score(&self, features: &InsiderFeatures) -> InsiderScore {
+        let base_score = features.success_rate * 100.0;
+        let volume_bonus = (features.total_trades.min(100) as f64 / 100.0) * 20.0; // Up to 20 points for volume
+        let roi_bonus = (features.roi_percentage.clamp(-100.0, 100.0) / 100.0) * 30.0; // Up to 30 points for ROI
+        let frequency_bonus = (features.trading_frequency.min(10.0) / 10.0) * 10.0; // Up to 10 points for frequency
+        let confidence_score = (base_score + volume_bonus + roi_bonus + frequency_bonus).clamp(0.0, 100.0);
+
+        let copy_worthiness = (confidence_score * 0.4
+            + (100.0 - features.risk_score) * 0.3
+            + features.success_rate * 100.0 * 0.3).min(100.0);
+
+        InsiderScore { confidence_score, copy_worthiness }
+    }
+}
+
+/// Logistic regression on the probability a copy of the insider closes in profit
+///
+/// Trained offline (`badger-train-scoring`) from closed copy-trade positions
+/// and stored as JSON. Both scores are the predicted probability times 100.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogisticScoringModel {
+    pub intercept: f64,
+    /// One weight per normalized feature, see [`InsiderFeatures::normalized`]
+    pub weights: Vec<f64>,
+    /// Closed copy trades the model was fit on
+    pub trained_on: usize,
+    pub trained_at: i64,
+}
+
+impl LogisticScoringModel {
+    /// Probability that copying an insider with these features wins
+    pub fn probability(&self, features: &InsiderFeatures) -> f64 {
+        let z = self.intercept + features.normalized().iter()
+            .zip(&self.weights)
+            .map(|(x, w)| x * w)
+            .sum::<f64>();
+        1.0 / (1.0 + (-z).exp())
+    }
+
+    /// Fit by batch gradient descent with L2 regularization
+    ///
+    /// # Arguments
+    /// * `samples` - Insider features with whether the copied trade won
+    /// * `epochs` - Gradient steps over the full set
+    /// * `learning_rate` - Step size
+    /// * `l2` - Weight penalty; the intercept is not penalized
+    pub fn train(samples: &[(InsiderFeatures, bool)], epochs: usize, learning_rate: f64, l2: f64) -> Self {
+        let mut model = Self {
+            intercept: 0.0,
+            weights: vec![0.0; 6],
+            trained_on: samples.len(),
+            trained_at: Utc::now().timestamp(),
+        };
+        if samples.is_empty() {
+            return model;
+        }
+
+        let n = samples.len() as f64;
+        for _ in 0..epochs {
+            let mut intercept_gradient = 0.0;
+            let mut gradients = [0.0; 6];
+            for (features, won) in samples {
+                let error = model.probability(features) - if *won { 1.0 } else { 0.0 };
+                intercept_gradient += error;
+                for (gradient, x) in gradients.iter_mut().zip(features.normalized()) {
+                    *gradient += error * x;
+                }
+            }
+
+            model.intercept -= learning_rate * intercept_gradient / n;
+            for (weight, gradient) in model.weights.iter_mut().zip(gradients) {
+                *weight -= learning_rate * (gradient / n + l2 * *weight);
+            }
+        }
+        model
+    }
+
+    pub fn load(path: &Path) -> Result<Self, DatabaseError> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to read scoring model {}: {}", path.display(), e)))?;
+        let model: Self = serde_json::from_str(&json)
+            .map_err(|e| DatabaseError::SerializationError(format!("Invalid scoring model {}: {}", path.display(), e)))?;
+        if model.weights.len() != 6 {
+            return Err(DatabaseError::SerializationError(format!(
+                "Scoring model {} has {} weights, expected 6", path.display(), model.weights.len()
+            )));
+        }
+        Ok(model)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), DatabaseError> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| DatabaseError::SerializationError(e.to_string()))?;
+        std::fs::write(path, json)
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to write scoring model {}: {}", path.display(), e)))
+    }
+}
+
+impl ScoringModel for LogisticScoringModel {
+    fn name(&self) -> &str {
+        "logistic"
+    }
+
+    fn score(&self, features: &InsiderFeatures) -> InsiderScore {
+        let score = self.probability(features) * 100.0;
+        InsiderScore { confidence_score: score, copy_worthiness: score }
+    }
+}
+
+/// Closed copy trades labelled by outcome, with the copied insider's profile
+pub async fn scoring_training_set(db: &BadgerDatabase) -> Result<Vec<(InsiderFeatures, bool)>, DatabaseError> {
+    #[derive(FromRow)]
+    struct TrainingRow {
+        #[sqlx(flatten)]
+        features: InsiderFeatures,
+        won: bool,
+    }
+
+    let rows = sqlx::query_as::<_, TrainingRow>(r#"
+        SELECT ip.success_rate, ip.total_trades, ip.roi_percentage, ip.trading_frequency,
+               ip.risk_score, ip.average_hold_time, p.pnl > 0 AS won
+        FROM positions p
+        JOIN insider_profiles ip ON ip.wallet_address = p.insider_wallet
+        WHERE p.status = 'CLOSED' AND p.pnl IS NOT NULL
+    "#)
+    .fetch_all(db.get_pool())
+    .await
+    .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch scoring training set: {}", e)))?;
+
+    Ok(rows.into_iter().map(|row| (row.features, row.won)).collect())
+}
+
+/// Scoring model selected by `BADGER_SCORING_MODEL`
+///
+/// Unset or `weighted` keeps the hand-tuned formula; anything else is the
+/// path of a trained logistic model.
+pub fn scoring_model_from_env() -> Result<Arc<dyn ScoringModel>, DatabaseError> {
+    match std::env::var("BADGER_SCORING_MODEL") {
+        Ok(value) if !value.is_empty() && value != "weighted" => {
+            let model = LogisticScoringModel::load(Path::new(&value))?;
+            info!("🧮 Insider scoring: logistic model from {} ({} trades)", value, model.trained_on);
+            Ok(Arc::new(model))
+        }
+        _ => Ok(Arc::new(WeightedScoringModel)),
+    }
+}
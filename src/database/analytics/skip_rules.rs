@@ -0,0 +1,470 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use chrono::{DateTime, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Row};
+use tokio::sync::RwLock;
+use tracing::{debug, info, instrument};
+
+use super::wallet_cache::WalletIntelligenceCache;
+use crate::core::constants::SOL_MINT;
+use crate::core::MarketEvent;
+use crate::database::{BadgerDatabase, DatabaseError};
+
+/// Rule suggested by loss analysis, not yet enforced
+pub const SKIP_RULE_PROPOSED: &str = "PROPOSED";
+/// Rule enforced on new copy-trade entries
+pub const SKIP_RULE_ENABLED: &str = "ENABLED";
+/// Rule rejected (or switched off) by the operator
+pub const SKIP_RULE_DISMISSED: &str = "DISMISSED";
+
+/// Configuration for skip rule proposals
+#[derive(Debug, Clone)]
+pub struct SkipRuleConfig {
+    /// Closed copy trades needed in a bucket before a rule is proposed
+    pub min_samples: i64,
+    /// Minimum loss rate of a bucket (0.0 - 1.0)
+    pub min_loss_rate: f64,
+    /// Minimum loss rate above the overall copy-trade loss rate
+    pub min_loss_rate_lift: f64,
+    /// Closed trades older than this are not analyzed (days)
+    pub lookback_days: i64,
+    /// Losing positions listed as evidence per rule
+    pub max_evidence: usize,
+}
+
+impl Default for SkipRuleConfig {
+    fn default() -> Self {
+        Self {
+            min_samples: 5,
+            min_loss_rate: 0.75,
+            min_loss_rate_lift: 0.2,
+            lookback_days: 30,
+            max_evidence: 10,
+        }
+    }
+}
+
+/// Attributes of a copy-trade entry, bucketed so losers can be grouped
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EntryAttributes {
+    pub token_age: Option<String>,
+    pub deployer: Option<String>,
+    pub insider_tier: Option<String>,
+    pub liquidity_band: Option<String>,
+    pub entry_hour: String, // "00" - "23" (UTC)
+}
+
+impl EntryAttributes {
+    /// # Arguments
+    /// * `token_age_secs` - Seconds since the token was first seen launching
+    /// * `copy_worthiness` - Copy-worthiness score (0-100) of the copied insider
+    /// * `liquidity_sol` - SOL liquidity of the pool or bonding curve at entry
+    pub fn new(
+        token_age_secs: Option<i64>,
+        deployer: Option<String>,
+        copy_worthiness: Option<f64>,
+        liquidity_sol: Option<f64>,
+        entry_time: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            token_age: token_age_secs.map(token_age_band),
+            deployer,
+            insider_tier: copy_worthiness.map(insider_tier),
+            liquidity_band: liquidity_sol.map(liquidity_band),
+            entry_hour: format!("{:02}", entry_time.hour()),
+        }
+    }
+
+    /// Known (dimension, value) pairs of this entry
+    pub fn dimensions(&self) -> Vec<(&'static str, String)> {
+        let mut dimensions = vec![("entry_hour", self.entry_hour.clone())];
+        if let Some(age) = &self.token_age {
+            dimensions.push(("token_age", age.clone()));
+        }
+        if let Some(deployer) = &self.deployer {
+            dimensions.push(("deployer", deployer.clone()));
+        }
+        if let Some(tier) = &self.insider_tier {
+            dimensions.push(("insider_tier", tier.clone()));
+        }
+        if let Some(band) = &self.liquidity_band {
+            dimensions.push(("liquidity_band", band.clone()));
+        }
+        dimensions
+    }
+}
+
+fn token_age_band(secs: i64) -> String {
+    match secs {
+        s if s < 300 => "<5m",
+        s if s < 1800 => "5m-30m",
+        s if s < 7200 => "30m-2h",
+        s if s < 86400 => "2h-24h",
+        _ => ">24h",
+    }.to_string()
+}
+
+fn insider_tier(copy_worthiness: f64) -> String {
+    match copy_worthiness {
+        w if w >= 75.0 => "A",
+        w if w >= 50.0 => "B",
+        w if w >= 25.0 => "C",
+        _ => "D",
+    }.to_string()
+}
+
+fn liquidity_band(sol: f64) -> String {
+    match sol {
+        s if s < 5.0 => "<5 SOL",
+        s if s < 20.0 => "5-20 SOL",
+        s if s < 50.0 => "20-50 SOL",
+        s if s < 100.0 => "50-100 SOL",
+        _ => ">100 SOL",
+    }.to_string()
+}
+
+/// Proposed or enabled rule skipping copy trades with a losing attribute
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SkipRule {
+    pub id: i64,
+    pub dimension: String, // "token_age", "deployer", "insider_tier", "liquidity_band", "entry_hour"
+    pub value: String,
+    pub status: String, // "PROPOSED", "ENABLED", "DISMISSED"
+    pub sample_size: i64,
+    pub losses: i64,
+    pub loss_rate: f64,
+    pub baseline_loss_rate: f64,
+    pub total_pnl: f64,
+    pub evidence: String, // JSON array of losing positions
+    pub proposed_at: i64,
+    pub updated_at: i64,
+    pub decided_at: Option<i64>,
+}
+
+/// Losing position cited as evidence for a skip rule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkipEvidence {
+    pub position_id: i64,
+    pub token_mint: String,
+    pub pnl: f64,
+}
+
+#[derive(Default)]
+struct Bucket {
+    trades: i64,
+    losses: i64,
+    total_pnl: f64,
+    losers: Vec<SkipEvidence>,
+}
+
+/// Learns skip rules from losing copy trades
+///
+/// Entry attributes are recorded when a copy trade opens. Closed trades are
+/// grouped per attribute value, and buckets losing far more often than copy
+/// trades overall become proposed rules with the losing positions as
+/// evidence. Proposals are only enforced after an operator enables them.
+pub struct SkipRuleLearner {
+    db: Arc<BadgerDatabase>,
+    config: SkipRuleConfig,
+    /// Token launch times and deployers
+    launches: Arc<WalletIntelligenceCache>,
+    /// Enabled (dimension, value) pairs
+    enabled: RwLock<HashSet<(String, String)>>,
+}
+
+impl SkipRuleLearner {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<SkipRuleConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+            launches: Arc::new(WalletIntelligenceCache::default()),
+            enabled: RwLock::new(HashSet::new()),
+        }
+    }
+
+    /// Keep launch times in a shared cache instead of a private one
+    pub fn with_cache(mut self, cache: Arc<WalletIntelligenceCache>) -> Self {
+        self.launches = cache;
+        self
+    }
+
+    pub fn config(&self) -> &SkipRuleConfig {
+        &self.config
+    }
+
+    /// Initialize skip rule schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing skip rule schema");
+
+        let create_entry_attributes = r#"
+            CREATE TABLE IF NOT EXISTS entry_attributes (
+                position_id INTEGER PRIMARY KEY,
+                token_mint TEXT NOT NULL,
+                token_age TEXT,
+                deployer TEXT,
+                insider_tier TEXT,
+                liquidity_band TEXT,
+                entry_hour TEXT NOT NULL,
+                recorded_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now')),
+                FOREIGN KEY (position_id) REFERENCES positions (id)
+            )
+        "#;
+
+        let create_skip_rules = r#"
+            CREATE TABLE IF NOT EXISTS skip_rules (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                dimension TEXT NOT NULL,
+                value TEXT NOT NULL,
+                status TEXT NOT NULL DEFAULT 'PROPOSED' CHECK (status IN ('PROPOSED', 'ENABLED', 'DISMISSED')),
+                sample_size INTEGER NOT NULL,
+                losses INTEGER NOT NULL,
+                loss_rate REAL NOT NULL,
+                baseline_loss_rate REAL NOT NULL,
+                total_pnl REAL NOT NULL,
+                evidence TEXT NOT NULL, -- JSON array
+                proposed_at INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL,
+                decided_at INTEGER,
+                UNIQUE(dimension, value)
+            )
+        "#;
+
+        for table_sql in [create_entry_attributes, create_skip_rules] {
+            sqlx::query(table_sql)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create skip rule table: {}", e)))?;
+        }
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_skip_rules_status ON skip_rules(status)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        info!("✅ Skip rule schema initialized");
+        Ok(())
+    }
+
+    /// Load enabled rules into memory
+    pub async fn load(&self) -> Result<usize, DatabaseError> {
+        let rules = self.get_rules(Some(SKIP_RULE_ENABLED)).await?;
+        let mut enabled = self.enabled.write().await;
+        enabled.clear();
+        enabled.extend(rules.into_iter().map(|r| (r.dimension, r.value)));
+        Ok(enabled.len())
+    }
+
+    /// Remember launch time and deployer of new tokens for deployer attributes
+    pub fn observe_launch(&self, event: &MarketEvent) {
+        match event {
+            MarketEvent::PoolCreated { pool, creator, .. } => {
+                let mint = if pool.base_mint == SOL_MINT { &pool.quote_mint } else { &pool.base_mint };
+                self.launches.record_launch(mint, pool.created_at.timestamp(), Some(creator.as_str()));
+            }
+            MarketEvent::TokenLaunched { token } => {
+                self.launches.record_launch(&token.mint, token.created_at.timestamp(), None);
+            }
+            _ => {}
+        }
+    }
+
+    /// Deployer of a token, if it was seen launching
+    pub fn deployer(&self, token_mint: &str) -> Option<String> {
+        self.launches.launch(token_mint).and_then(|launch| launch.deployer)
+    }
+
+    /// Enabled rule matching an entry, as (dimension, value)
+    pub async fn matching_rule(&self, attributes: &EntryAttributes) -> Option<(String, String)> {
+        let enabled = self.enabled.read().await;
+        if enabled.is_empty() {
+            return None;
+        }
+        attributes.dimensions().into_iter()
+            .map(|(dimension, value)| (dimension.to_string(), value))
+            .find(|rule| enabled.contains(rule))
+    }
+
+    /// Record the attributes of an opened copy trade
+    pub async fn record_entry(&self, position_id: i64, token_mint: &str, attributes: &EntryAttributes) -> Result<(), DatabaseError> {
+        sqlx::query(r#"
+            INSERT OR REPLACE INTO entry_attributes
+            (position_id, token_mint, token_age, deployer, insider_tier, liquidity_band, entry_hour)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(position_id)
+        .bind(token_mint)
+        .bind(&attributes.token_age)
+        .bind(&attributes.deployer)
+        .bind(&attributes.insider_tier)
+        .bind(&attributes.liquidity_band)
+        .bind(&attributes.entry_hour)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record entry attributes: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Group closed copy trades by attribute and propose rules for losing buckets
+    ///
+    /// Existing rules keep their status; only their statistics and evidence are refreshed.
+    ///
+    /// # Returns
+    /// * `Result<usize, DatabaseError>` - Number of buckets that qualify as skip rules
+    #[instrument(skip(self))]
+    pub async fn analyze_losses(&self) -> Result<usize, DatabaseError> {
+        let since = Utc::now().timestamp() - self.config.lookback_days * 86400;
+
+        let rows = sqlx::query(r#"
+            SELECT p.id, p.token_mint, p.pnl, a.token_age, a.deployer, a.insider_tier, a.liquidity_band, a.entry_hour
+            FROM positions p
+            JOIN entry_attributes a ON a.position_id = p.id
+            WHERE p.status = 'CLOSED' AND p.pnl IS NOT NULL AND p.insider_wallet IS NOT NULL
+              AND p.exit_timestamp >= ?
+            ORDER BY p.pnl ASC
+        "#)
+        .bind(since)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch copy trade outcomes: {}", e)))?;
+
+        if rows.is_empty() {
+            return Ok(0);
+        }
+
+        let mut buckets: HashMap<(&'static str, String), Bucket> = HashMap::new();
+        let mut total_losses = 0;
+
+        for row in &rows {
+            let pnl: f64 = row.get("pnl");
+            let attributes = EntryAttributes {
+                token_age: row.get("token_age"),
+                deployer: row.get("deployer"),
+                insider_tier: row.get("insider_tier"),
+                liquidity_band: row.get("liquidity_band"),
+                entry_hour: row.get("entry_hour"),
+            };
+            if pnl < 0.0 {
+                total_losses += 1;
+            }
+
+            for key in attributes.dimensions() {
+                let bucket = buckets.entry(key).or_default();
+                bucket.trades += 1;
+                bucket.total_pnl += pnl;
+                if pnl < 0.0 {
+                    bucket.losses += 1;
+                    // Rows are ordered worst first
+                    if bucket.losers.len() < self.config.max_evidence {
+                        bucket.losers.push(SkipEvidence {
+                            position_id: row.get("id"),
+                            token_mint: row.get("token_mint"),
+                            pnl,
+                        });
+                    }
+                }
+            }
+        }
+
+        let baseline_loss_rate = total_losses as f64 / rows.len() as f64;
+        let now = Utc::now().timestamp();
+        let mut qualifying = 0;
+
+        for ((dimension, value), bucket) in buckets {
+            let loss_rate = bucket.losses as f64 / bucket.trades as f64;
+            if bucket.trades < self.config.min_samples
+                || bucket.total_pnl >= 0.0
+                || loss_rate < self.config.min_loss_rate
+                || loss_rate - baseline_loss_rate < self.config.min_loss_rate_lift
+            {
+                continue;
+            }
+
+            let evidence = serde_json::to_string(&bucket.losers)
+                .map_err(|e| DatabaseError::SerializationError(format!("Failed to serialize skip evidence: {}", e)))?;
+
+            sqlx::query(r#"
+                INSERT INTO skip_rules
+                (dimension, value, sample_size, losses, loss_rate, baseline_loss_rate, total_pnl, evidence, proposed_at, updated_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                ON CONFLICT(dimension, value) DO UPDATE SET
+                    sample_size = excluded.sample_size,
+                    losses = excluded.losses,
+                    loss_rate = excluded.loss_rate,
+                    baseline_loss_rate = excluded.baseline_loss_rate,
+                    total_pnl = excluded.total_pnl,
+                    evidence = excluded.evidence,
+                    updated_at = excluded.updated_at
+            "#)
+            .bind(dimension)
+            .bind(&value)
+            .bind(bucket.trades)
+            .bind(bucket.losses)
+            .bind(loss_rate)
+            .bind(baseline_loss_rate)
+            .bind(bucket.total_pnl)
+            .bind(&evidence)
+            .bind(now)
+            .bind(now)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to save skip rule: {}", e)))?;
+
+            debug!("🚫 Skip rule candidate {}={}: {}/{} lost ({:.0}% vs {:.0}% baseline)",
+                dimension, value, bucket.losses, bucket.trades, loss_rate * 100.0, baseline_loss_rate * 100.0);
+            qualifying += 1;
+        }
+
+        Ok(qualifying)
+    }
+
+    /// Skip rules, optionally filtered by status, worst P&L first
+    pub async fn get_rules(&self, status: Option<&str>) -> Result<Vec<SkipRule>, DatabaseError> {
+        let query = match status {
+            Some(status) => sqlx::query_as::<_, SkipRule>(
+                "SELECT * FROM skip_rules WHERE status = ? ORDER BY total_pnl ASC"
+            ).bind(status),
+            None => sqlx::query_as::<_, SkipRule>("SELECT * FROM skip_rules ORDER BY total_pnl ASC"),
+        };
+
+        query
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch skip rules: {}", e)))
+    }
+
+    /// Enable a proposed rule, or dismiss a proposed or enabled one
+    pub async fn decide(&self, id: i64, enable: bool) -> Result<SkipRule, DatabaseError> {
+        let status = if enable { SKIP_RULE_ENABLED } else { SKIP_RULE_DISMISSED };
+        let updated = sqlx::query("UPDATE skip_rules SET status = ?, decided_at = ? WHERE id = ?")
+            .bind(status)
+            .bind(Utc::now().timestamp())
+            .bind(id)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to update skip rule: {}", e)))?;
+
+        if updated.rows_affected() == 0 {
+            return Err(DatabaseError::QueryError(format!("No skip rule #{}", id)));
+        }
+
+        let rule = sqlx::query_as::<_, SkipRule>("SELECT * FROM skip_rules WHERE id = ?")
+            .bind(id)
+            .fetch_one(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch skip rule: {}", e)))?;
+
+        let key = (rule.dimension.clone(), rule.value.clone());
+        let mut enabled = self.enabled.write().await;
+        if enable {
+            enabled.insert(key);
+        } else {
+            enabled.remove(&key);
+        }
+
+        info!("🚫 Skip rule #{} {}={} is now {}", rule.id, rule.dimension, rule.value, rule.status);
+        Ok(rule)
+    }
+}
@@ -0,0 +1,418 @@
+use std::path::Path;
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn, instrument};
+
+use crate::core::{StrategyConfig, TradingSignal};
+use crate::database::{BadgerDatabase, DatabaseError};
+use super::ConfigSnapshotStore;
+
+/// Strategy receives events and records hypothetical fills only
+pub const STRATEGY_MODE_SHADOW: &str = "SHADOW";
+/// Strategy drives real execution
+pub const STRATEGY_MODE_LIVE: &str = "LIVE";
+/// Previously live strategy replaced by a promotion
+pub const STRATEGY_MODE_RETIRED: &str = "RETIRED";
+
+/// Stored strategy registration
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct StrategyRecord {
+    pub name: String,
+    pub mode: String, // "SHADOW", "LIVE", "RETIRED"
+    pub config_hash: String,
+    pub config: String, // JSON-encoded StrategyConfig
+    pub registered_at: i64,
+    pub promoted_at: Option<i64>,
+}
+
+/// Strategy loaded in memory for signal generation
+#[derive(Debug, Clone)]
+pub struct RegisteredStrategy {
+    pub name: String,
+    pub config: Arc<StrategyConfig>,
+    pub config_hash: String,
+}
+
+/// Hypothetical P&L track of a shadow strategy
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ShadowPerformance {
+    pub strategy_name: String,
+    pub total_fills: i64,
+    pub open_fills: i64,
+    pub winning_fills: i64,
+    pub realized_pnl: f64,
+}
+
+/// Registry of named strategies running in shadow or live mode
+///
+/// Shadow strategies see every market event and produce signals, but their
+/// fills are recorded in `shadow_fills` at live quotes instead of executed.
+/// A shadow strategy only goes live through `promote`.
+pub struct StrategyRegistry {
+    db: Arc<BadgerDatabase>,
+    shadows: RwLock<Vec<RegisteredStrategy>>,
+    live: RwLock<Option<RegisteredStrategy>>,
+}
+
+impl StrategyRegistry {
+    pub fn new(db: Arc<BadgerDatabase>) -> Self {
+        Self {
+            db,
+            shadows: RwLock::new(Vec::new()),
+            live: RwLock::new(None),
+        }
+    }
+
+    /// Initialize strategy registry schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing strategy registry schema");
+
+        let create_strategies_table = r#"
+            CREATE TABLE IF NOT EXISTS strategies (
+                name TEXT PRIMARY KEY,
+                mode TEXT NOT NULL CHECK (mode IN ('SHADOW', 'LIVE', 'RETIRED')),
+                config_hash TEXT NOT NULL,
+                config TEXT NOT NULL,
+                registered_at INTEGER NOT NULL,
+                promoted_at INTEGER
+            )
+        "#;
+
+        let create_shadow_fills_table = r#"
+            CREATE TABLE IF NOT EXISTS shadow_fills (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                strategy_name TEXT NOT NULL,
+                token_mint TEXT NOT NULL,
+                entry_price REAL NOT NULL,
+                exit_price REAL,
+                quantity REAL NOT NULL,
+                fees REAL NOT NULL DEFAULT 0.0,
+                status TEXT NOT NULL DEFAULT 'OPEN' CHECK (status IN ('OPEN', 'CLOSED')),
+                pnl REAL,
+                reason TEXT,
+                opened_at INTEGER NOT NULL,
+                closed_at INTEGER,
+                FOREIGN KEY (strategy_name) REFERENCES strategies (name)
+            )
+        "#;
+
+        let create_indexes = vec![
+            "CREATE INDEX IF NOT EXISTS idx_strategies_mode ON strategies(mode)",
+            "CREATE INDEX IF NOT EXISTS idx_shadow_fills_strategy ON shadow_fills(strategy_name, token_mint, status)",
+        ];
+
+        for table_sql in [create_strategies_table, create_shadow_fills_table] {
+            sqlx::query(table_sql)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create strategy registry table: {}", e)))?;
+        }
+
+        for index_sql in create_indexes {
+            sqlx::query(index_sql)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+        }
+
+        info!("✅ Strategy registry schema initialized");
+        Ok(())
+    }
+
+    fn to_registered(record: &StrategyRecord) -> Result<RegisteredStrategy, DatabaseError> {
+        let config: StrategyConfig = serde_json::from_str(&record.config)
+            .map_err(|e| DatabaseError::SerializationError(format!("Invalid config for strategy {}: {}", record.name, e)))?;
+        Ok(RegisteredStrategy {
+            name: record.name.clone(),
+            config: Arc::new(config),
+            config_hash: record.config_hash.clone(),
+        })
+    }
+
+    /// Load shadow and live strategies persisted by previous runs
+    pub async fn load(&self) -> Result<usize, DatabaseError> {
+        let records = sqlx::query_as::<_, StrategyRecord>(
+            "SELECT * FROM strategies WHERE mode IN ('SHADOW', 'LIVE') ORDER BY registered_at ASC"
+        )
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to load strategies: {}", e)))?;
+
+        let mut shadows = self.shadows.write().await;
+        let mut live = self.live.write().await;
+        shadows.clear();
+
+        for record in &records {
+            let strategy = match Self::to_registered(record) {
+                Ok(strategy) => strategy,
+                Err(e) => {
+                    warn!("Skipping strategy {}: {}", record.name, e);
+                    continue;
+                }
+            };
+            if record.mode == STRATEGY_MODE_LIVE {
+                *live = Some(strategy);
+            } else {
+                shadows.push(strategy);
+            }
+        }
+
+        Ok(records.len())
+    }
+
+    /// Register (or re-tune) a strategy in shadow mode
+    ///
+    /// # Returns
+    /// * `Result<bool, DatabaseError>` - False when a live strategy already uses this name
+    pub async fn register_shadow(&self, name: &str, config: &StrategyConfig) -> Result<bool, DatabaseError> {
+        let existing_mode = sqlx::query_scalar::<_, String>("SELECT mode FROM strategies WHERE name = ?")
+            .bind(name)
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to look up strategy: {}", e)))?;
+
+        if existing_mode.as_deref() == Some(STRATEGY_MODE_LIVE) {
+            warn!("Strategy {} is live - not re-registering it in shadow mode", name);
+            return Ok(false);
+        }
+
+        let (config_hash, snapshot) = ConfigSnapshotStore::config_hash(config)?;
+
+        sqlx::query(r#"
+            INSERT INTO strategies (name, mode, config_hash, config, registered_at)
+            VALUES (?, 'SHADOW', ?, ?, ?)
+            ON CONFLICT(name) DO UPDATE SET
+                mode = 'SHADOW', config_hash = excluded.config_hash,
+                config = excluded.config, promoted_at = NULL
+        "#)
+        .bind(name)
+        .bind(&config_hash)
+        .bind(&snapshot)
+        .bind(Utc::now().timestamp())
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to register strategy: {}", e)))?;
+
+        let strategy = RegisteredStrategy {
+            name: name.to_string(),
+            config: Arc::new(config.clone()),
+            config_hash,
+        };
+
+        let mut shadows = self.shadows.write().await;
+        shadows.retain(|s| s.name != name);
+        shadows.push(strategy);
+
+        info!("👥 Registered shadow strategy {}", name);
+        Ok(true)
+    }
+
+    /// Register every `*.json` strategy config in a directory as a shadow strategy
+    ///
+    /// The file stem is the strategy name; missing fields take their defaults.
+    pub async fn import_directory(&self, dir: &Path) -> Result<usize, DatabaseError> {
+        let mut entries = match tokio::fs::read_dir(dir).await {
+            Ok(entries) => entries,
+            Err(_) => return Ok(0), // No strategy directory configured
+        };
+
+        let mut total = 0;
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let name = match path.file_stem() {
+                Some(stem) => stem.to_string_lossy().to_string(),
+                None => continue,
+            };
+
+            let config = match tokio::fs::read_to_string(&path).await
+                .map_err(|e| e.to_string())
+                .and_then(|raw| serde_json::from_str::<StrategyConfig>(&raw).map_err(|e| e.to_string()))
+            {
+                Ok(config) => config,
+                Err(e) => {
+                    warn!("Failed to load strategy {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+
+            if self.register_shadow(&name, &config).await? {
+                total += 1;
+            }
+        }
+
+        Ok(total)
+    }
+
+    /// Promote a shadow strategy to live, retiring the current live strategy
+    pub async fn promote(&self, name: &str) -> Result<RegisteredStrategy, DatabaseError> {
+        let mut tx = self.db.begin_transaction().await?;
+
+        let record = sqlx::query_as::<_, StrategyRecord>(
+            "SELECT * FROM strategies WHERE name = ? AND mode = 'SHADOW'"
+        )
+        .bind(name)
+        .fetch_optional(&mut tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to look up strategy: {}", e)))?
+        .ok_or_else(|| DatabaseError::QueryError(format!("No shadow strategy named {}", name)))?;
+
+        let strategy = Self::to_registered(&record)?;
+
+        sqlx::query("UPDATE strategies SET mode = 'RETIRED' WHERE mode = 'LIVE'")
+            .execute(&mut tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to retire live strategy: {}", e)))?;
+
+        sqlx::query("UPDATE strategies SET mode = 'LIVE', promoted_at = ? WHERE name = ?")
+            .bind(Utc::now().timestamp())
+            .bind(name)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to promote strategy: {}", e)))?;
+
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit promotion: {}", e)))?;
+
+        self.shadows.write().await.retain(|s| s.name != name);
+        *self.live.write().await = Some(strategy.clone());
+
+        info!("🚀 Strategy {} promoted to live", name);
+        Ok(strategy)
+    }
+
+    /// Strategies currently running in shadow mode
+    pub async fn shadow_strategies(&self) -> Vec<RegisteredStrategy> {
+        self.shadows.read().await.clone()
+    }
+
+    /// Promoted strategy that replaces the startup configuration, if any
+    pub async fn live_strategy(&self) -> Option<RegisteredStrategy> {
+        self.live.read().await.clone()
+    }
+
+    /// Loaded strategy by name, shadow or live
+    pub async fn strategy(&self, name: &str) -> Option<RegisteredStrategy> {
+        if let Some(live) = self.live.read().await.as_ref().filter(|live| live.name == name) {
+            return Some(live.clone());
+        }
+        self.shadows.read().await.iter().find(|shadow| shadow.name == name).cloned()
+    }
+
+    /// Record a hypothetical fill for a shadow strategy's signal
+    ///
+    /// Buys open a shadow position (one per token); sells close it.
+    ///
+    /// # Returns
+    /// * `Result<Option<f64>, DatabaseError>` - Realized shadow P&L when a position was closed
+    pub async fn record_shadow_fill(
+        &self,
+        strategy: &RegisteredStrategy,
+        signal: &TradingSignal,
+        price: f64,
+    ) -> Result<Option<f64>, DatabaseError> {
+        let now = Utc::now().timestamp();
+
+        match signal {
+            TradingSignal::Buy { token_mint, max_amount_sol, reason, .. } => {
+                if price <= 0.0 {
+                    return Ok(None);
+                }
+
+                let open = sqlx::query_scalar::<_, i64>(
+                    "SELECT COUNT(*) FROM shadow_fills WHERE strategy_name = ? AND token_mint = ? AND status = 'OPEN'"
+                )
+                .bind(&strategy.name)
+                .bind(token_mint)
+                .fetch_one(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to check shadow fills: {}", e)))?;
+
+                if open > 0 {
+                    return Ok(None);
+                }
+
+                sqlx::query(r#"
+                    INSERT INTO shadow_fills (strategy_name, token_mint, entry_price, quantity, fees, reason, opened_at)
+                    VALUES (?, ?, ?, ?, ?, ?, ?)
+                "#)
+                .bind(&strategy.name)
+                .bind(token_mint)
+                .bind(price)
+                .bind(max_amount_sol / price)
+                .bind(max_amount_sol * strategy.config.fee_rate)
+                .bind(reason)
+                .bind(now)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to record shadow fill: {}", e)))?;
+
+                debug!("👥 [{}] shadow buy {} @ {:.9}", strategy.name, token_mint, price);
+                Ok(None)
+            }
+            TradingSignal::Sell { token_mint, .. } => {
+                let open = sqlx::query_as::<_, (i64, f64, f64, f64)>(r#"
+                    SELECT id, entry_price, quantity, fees FROM shadow_fills
+                    WHERE strategy_name = ? AND token_mint = ? AND status = 'OPEN'
+                    ORDER BY opened_at ASC LIMIT 1
+                "#)
+                .bind(&strategy.name)
+                .bind(token_mint)
+                .fetch_optional(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch shadow fill: {}", e)))?;
+
+                let Some((id, entry_price, quantity, entry_fees)) = open else {
+                    return Ok(None);
+                };
+
+                let exit_fees = price * quantity * strategy.config.fee_rate;
+                let fees = entry_fees + exit_fees;
+                let pnl = (price - entry_price) * quantity - fees;
+
+                sqlx::query(r#"
+                    UPDATE shadow_fills SET exit_price = ?, fees = ?, pnl = ?, status = 'CLOSED', closed_at = ?
+                    WHERE id = ?
+                "#)
+                .bind(price)
+                .bind(fees)
+                .bind(pnl)
+                .bind(now)
+                .bind(id)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to close shadow fill: {}", e)))?;
+
+                debug!("👥 [{}] shadow sell {} @ {:.9} P&L {:.4}", strategy.name, token_mint, price, pnl);
+                Ok(Some(pnl))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Hypothetical results per shadow strategy
+    pub async fn get_shadow_performance(&self) -> Result<Vec<ShadowPerformance>, DatabaseError> {
+        sqlx::query_as::<_, ShadowPerformance>(r#"
+            SELECT
+                s.name as strategy_name,
+                COUNT(f.id) as total_fills,
+                COALESCE(SUM(CASE WHEN f.status = 'OPEN' THEN 1 ELSE 0 END), 0) as open_fills,
+                COALESCE(SUM(CASE WHEN f.pnl > 0 THEN 1 ELSE 0 END), 0) as winning_fills,
+                COALESCE(SUM(f.pnl), 0.0) as realized_pnl
+            FROM strategies s
+            LEFT JOIN shadow_fills f ON f.strategy_name = s.name
+            WHERE s.mode = 'SHADOW'
+            GROUP BY s.name
+            ORDER BY realized_pnl DESC
+        "#)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch shadow performance: {}", e)))
+    }
+}
@@ -0,0 +1,359 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, instrument};
+
+use crate::database::{BadgerDatabase, DatabaseError};
+
+/// Configuration for summary table maintenance
+#[derive(Debug, Clone)]
+pub struct SummaryViewConfig {
+    /// How often summaries are refreshed (seconds)
+    pub refresh_interval_secs: u64,
+    /// Rows updated this long before the last refresh are re-read, covering
+    /// writes that landed in the same second as the refresh
+    pub overlap_secs: i64,
+}
+
+impl Default for SummaryViewConfig {
+    fn default() -> Self {
+        Self {
+            refresh_interval_secs: 60,
+            overlap_secs: 5,
+        }
+    }
+}
+
+/// Closed-trade P&L of one strategy on one UTC day
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct DailyStrategyPnl {
+    pub day: String, // YYYY-MM-DD
+    pub strategy_name: String,
+    pub mode: String, // "LIVE", "SHADOW"
+    pub closed_trades: i64,
+    pub winning_trades: i64,
+    pub realized_pnl: f64,
+    pub fees: f64,
+    pub refreshed_at: i64,
+}
+
+/// Activity and copy results of one insider wallet
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct InsiderRollup {
+    pub wallet_address: String,
+    pub buys: i64,
+    pub sells: i64,
+    pub tokens_traded: i64,
+    pub total_volume: f64,
+    pub first_activity: Option<i64>,
+    pub last_activity: Option<i64>,
+    pub copied_positions: i64,
+    pub copied_pnl: f64,
+    pub refreshed_at: i64,
+}
+
+/// Our trading and insider flow for one token
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TokenStats {
+    pub token_mint: String,
+    pub positions: i64,
+    pub open_positions: i64,
+    pub realized_pnl: f64,
+    pub insider_wallets: i64,
+    pub insider_buys: i64,
+    pub insider_sells: i64,
+    pub last_activity: Option<i64>,
+    pub refreshed_at: i64,
+}
+
+/// Rows rewritten by one refresh
+#[derive(Debug, Clone, Default)]
+pub struct SummaryRefresh {
+    pub strategy_days: u64,
+    pub insiders: u64,
+    pub tokens: u64,
+}
+
+/// Pre-aggregated summary tables over `positions`, `shadow_fills` and `insider_activities`
+///
+/// Each refresh only recomputes the days, wallets and tokens touched since
+/// the previous refresh, found through `positions.updated_at`,
+/// `shadow_fills.closed_at` and `insider_activities.detected_at`. Live
+/// positions are attributed to the strategy whose config was active when
+/// they opened (via `trade_configs`), or `default` when none was recorded.
+pub struct SummaryViews {
+    db: Arc<BadgerDatabase>,
+    config: SummaryViewConfig,
+}
+
+impl SummaryViews {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<SummaryViewConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+        }
+    }
+
+    pub fn config(&self) -> &SummaryViewConfig {
+        &self.config
+    }
+
+    /// Initialize summary table schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing summary views schema");
+
+        let create_daily_strategy_pnl = r#"
+            CREATE TABLE IF NOT EXISTS summary_daily_strategy_pnl (
+                day TEXT NOT NULL,
+                strategy_name TEXT NOT NULL,
+                mode TEXT NOT NULL CHECK (mode IN ('LIVE', 'SHADOW')),
+                closed_trades INTEGER NOT NULL,
+                winning_trades INTEGER NOT NULL,
+                realized_pnl REAL NOT NULL,
+                fees REAL NOT NULL,
+                refreshed_at INTEGER NOT NULL,
+                PRIMARY KEY (day, strategy_name, mode)
+            )
+        "#;
+
+        let create_insider_rollups = r#"
+            CREATE TABLE IF NOT EXISTS summary_insider_rollups (
+                wallet_address TEXT PRIMARY KEY,
+                buys INTEGER NOT NULL,
+                sells INTEGER NOT NULL,
+                tokens_traded INTEGER NOT NULL,
+                total_volume REAL NOT NULL,
+                first_activity INTEGER,
+                last_activity INTEGER,
+                copied_positions INTEGER NOT NULL,
+                copied_pnl REAL NOT NULL,
+                refreshed_at INTEGER NOT NULL
+            )
+        "#;
+
+        let create_token_stats = r#"
+            CREATE TABLE IF NOT EXISTS summary_token_stats (
+                token_mint TEXT PRIMARY KEY,
+                positions INTEGER NOT NULL,
+                open_positions INTEGER NOT NULL,
+                realized_pnl REAL NOT NULL,
+                insider_wallets INTEGER NOT NULL,
+                insider_buys INTEGER NOT NULL,
+                insider_sells INTEGER NOT NULL,
+                last_activity INTEGER,
+                refreshed_at INTEGER NOT NULL
+            )
+        "#;
+
+        // Watermark of the last completed refresh
+        let create_refresh_state = r#"
+            CREATE TABLE IF NOT EXISTS summary_refresh_state (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                refreshed_at INTEGER NOT NULL
+            )
+        "#;
+
+        let create_indexes = vec![
+            "CREATE INDEX IF NOT EXISTS idx_positions_updated_at ON positions(updated_at)",
+            "CREATE INDEX IF NOT EXISTS idx_insider_activities_detected ON insider_activities(detected_at)",
+            "CREATE INDEX IF NOT EXISTS idx_summary_insider_pnl ON summary_insider_rollups(copied_pnl DESC)",
+            "CREATE INDEX IF NOT EXISTS idx_summary_token_pnl ON summary_token_stats(realized_pnl DESC)",
+        ];
+
+        for table_sql in [create_daily_strategy_pnl, create_insider_rollups, create_token_stats, create_refresh_state] {
+            sqlx::query(table_sql)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create summary table: {}", e)))?;
+        }
+
+        for index_sql in create_indexes {
+            sqlx::query(index_sql)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+        }
+
+        info!("✅ Summary views schema initialized");
+        Ok(())
+    }
+
+    /// Recompute summaries touched since the previous refresh
+    ///
+    /// The first refresh (no watermark) builds every summary from scratch.
+    ///
+    /// # Returns
+    /// * `Result<SummaryRefresh, DatabaseError>` - Rows rewritten per summary
+    #[instrument(skip(self))]
+    pub async fn refresh(&self) -> Result<SummaryRefresh, DatabaseError> {
+        let now = Utc::now().timestamp();
+        let last_refresh = sqlx::query_scalar::<_, i64>("SELECT refreshed_at FROM summary_refresh_state WHERE id = 1")
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch summary watermark: {}", e)))?;
+        let since = last_refresh.map_or(0, |at| at - self.config.overlap_secs);
+
+        let _permit = self.db.write_permit().await;
+        let mut tx = self.db.begin_transaction().await?;
+
+        // Daily P&L per strategy: rebuild every day with a newly closed trade
+        let affected_days = r#"
+            SELECT date(exit_timestamp, 'unixepoch') FROM positions
+            WHERE status = 'CLOSED' AND exit_timestamp IS NOT NULL AND updated_at >= ?
+            UNION
+            SELECT date(closed_at, 'unixepoch') FROM shadow_fills
+            WHERE status = 'CLOSED' AND closed_at >= ?
+        "#;
+
+        sqlx::query(&format!("DELETE FROM summary_daily_strategy_pnl WHERE day IN ({})", affected_days))
+            .bind(since)
+            .bind(since)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to clear daily strategy P&L: {}", e)))?;
+
+        let strategy_days = sqlx::query(&format!(r#"
+            INSERT INTO summary_daily_strategy_pnl
+                (day, strategy_name, mode, closed_trades, winning_trades, realized_pnl, fees, refreshed_at)
+            SELECT day, strategy_name, mode, COUNT(*),
+                   SUM(CASE WHEN pnl > 0 THEN 1 ELSE 0 END),
+                   COALESCE(SUM(pnl), 0.0), COALESCE(SUM(fees), 0.0), ?
+            FROM (
+                SELECT date(p.exit_timestamp, 'unixepoch') AS day,
+                       COALESCE((
+                           SELECT MIN(s.name) FROM trade_configs tc
+                           JOIN strategies s ON s.config_hash = tc.config_hash
+                           WHERE tc.position_id = p.id AND tc.trade_action = 'OPEN'
+                       ), 'default') AS strategy_name,
+                       'LIVE' AS mode, p.pnl AS pnl, p.fees AS fees
+                FROM positions p
+                WHERE p.status = 'CLOSED' AND p.exit_timestamp IS NOT NULL
+                UNION ALL
+                SELECT date(closed_at, 'unixepoch'), strategy_name, 'SHADOW', pnl, fees
+                FROM shadow_fills
+                WHERE status = 'CLOSED' AND closed_at IS NOT NULL
+            )
+            WHERE day IN ({})
+            GROUP BY day, strategy_name, mode
+        "#, affected_days))
+            .bind(now)
+            .bind(since)
+            .bind(since)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to refresh daily strategy P&L: {}", e)))?
+            .rows_affected();
+
+        // Insider rollups: recompute wallets with new activity or copied positions
+        let insiders = sqlx::query(r#"
+            INSERT OR REPLACE INTO summary_insider_rollups
+                (wallet_address, buys, sells, tokens_traded, total_volume, first_activity, last_activity,
+                 copied_positions, copied_pnl, refreshed_at)
+            SELECT w.wallet_address,
+                   (SELECT COUNT(*) FROM insider_activities a WHERE a.wallet_address = w.wallet_address AND a.activity_type = 'BUY'),
+                   (SELECT COUNT(*) FROM insider_activities a WHERE a.wallet_address = w.wallet_address AND a.activity_type = 'SELL'),
+                   (SELECT COUNT(DISTINCT a.token_mint) FROM insider_activities a WHERE a.wallet_address = w.wallet_address),
+                   (SELECT COALESCE(SUM(a.amount), 0.0) FROM insider_activities a WHERE a.wallet_address = w.wallet_address),
+                   (SELECT MIN(a.timestamp) FROM insider_activities a WHERE a.wallet_address = w.wallet_address),
+                   (SELECT MAX(a.timestamp) FROM insider_activities a WHERE a.wallet_address = w.wallet_address),
+                   (SELECT COUNT(*) FROM positions p WHERE p.insider_wallet = w.wallet_address),
+                   (SELECT COALESCE(SUM(p.pnl), 0.0) FROM positions p WHERE p.insider_wallet = w.wallet_address AND p.status = 'CLOSED'),
+                   ?
+            FROM (
+                SELECT wallet_address FROM insider_activities WHERE detected_at >= ?
+                UNION
+                SELECT insider_wallet FROM positions WHERE insider_wallet IS NOT NULL AND updated_at >= ?
+            ) w
+        "#)
+        .bind(now)
+        .bind(since)
+        .bind(since)
+        .execute(&mut tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to refresh insider rollups: {}", e)))?
+        .rows_affected();
+
+        // Token stats: recompute tokens with new positions or insider activity
+        let tokens = sqlx::query(r#"
+            INSERT OR REPLACE INTO summary_token_stats
+                (token_mint, positions, open_positions, realized_pnl, insider_wallets, insider_buys, insider_sells,
+                 last_activity, refreshed_at)
+            SELECT t.token_mint,
+                   (SELECT COUNT(*) FROM positions p WHERE p.token_mint = t.token_mint),
+                   (SELECT COUNT(*) FROM positions p WHERE p.token_mint = t.token_mint AND p.status = 'OPEN'),
+                   (SELECT COALESCE(SUM(p.pnl), 0.0) FROM positions p WHERE p.token_mint = t.token_mint AND p.status = 'CLOSED'),
+                   (SELECT COUNT(DISTINCT a.wallet_address) FROM insider_activities a WHERE a.token_mint = t.token_mint),
+                   (SELECT COUNT(*) FROM insider_activities a WHERE a.token_mint = t.token_mint AND a.activity_type = 'BUY'),
+                   (SELECT COUNT(*) FROM insider_activities a WHERE a.token_mint = t.token_mint AND a.activity_type = 'SELL'),
+                   MAX(
+                       COALESCE((SELECT MAX(p.updated_at) FROM positions p WHERE p.token_mint = t.token_mint), 0),
+                       COALESCE((SELECT MAX(a.timestamp) FROM insider_activities a WHERE a.token_mint = t.token_mint), 0)
+                   ),
+                   ?
+            FROM (
+                SELECT token_mint FROM positions WHERE updated_at >= ?
+                UNION
+                SELECT token_mint FROM insider_activities WHERE detected_at >= ?
+            ) t
+        "#)
+        .bind(now)
+        .bind(since)
+        .bind(since)
+        .execute(&mut tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to refresh token stats: {}", e)))?
+        .rows_affected();
+
+        sqlx::query(r#"
+            INSERT INTO summary_refresh_state (id, refreshed_at) VALUES (1, ?)
+            ON CONFLICT(id) DO UPDATE SET refreshed_at = excluded.refreshed_at
+        "#)
+        .bind(now)
+        .execute(&mut tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to store summary watermark: {}", e)))?;
+
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit summary refresh: {}", e)))?;
+
+        debug!("📚 Summaries refreshed: {} strategy days, {} insiders, {} tokens", strategy_days, insiders, tokens);
+        Ok(SummaryRefresh {
+            strategy_days,
+            insiders,
+            tokens,
+        })
+    }
+
+    /// Daily strategy P&L for the last `days` days, newest first
+    pub async fn get_daily_strategy_pnl(&self, days: i64) -> Result<Vec<DailyStrategyPnl>, DatabaseError> {
+        sqlx::query_as::<_, DailyStrategyPnl>(r#"
+            SELECT * FROM summary_daily_strategy_pnl
+            WHERE day >= date('now', ?)
+            ORDER BY day DESC, realized_pnl DESC
+        "#)
+        .bind(format!("-{} days", days))
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch daily strategy P&L: {}", e)))
+    }
+
+    /// Insider rollups, best copy P&L first
+    pub async fn get_insider_rollups(&self, limit: i64) -> Result<Vec<InsiderRollup>, DatabaseError> {
+        sqlx::query_as::<_, InsiderRollup>("SELECT * FROM summary_insider_rollups ORDER BY copied_pnl DESC LIMIT ?")
+            .bind(limit)
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch insider rollups: {}", e)))
+    }
+
+    /// Token stats, best realized P&L first
+    pub async fn get_token_stats(&self, limit: i64) -> Result<Vec<TokenStats>, DatabaseError> {
+        sqlx::query_as::<_, TokenStats>("SELECT * FROM summary_token_stats ORDER BY realized_pnl DESC LIMIT ?")
+            .bind(limit)
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch token stats: {}", e)))
+    }
+}
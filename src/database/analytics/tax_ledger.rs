@@ -0,0 +1,537 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::str::FromStr;
+use std::sync::Arc;
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{info, instrument, warn};
+
+use crate::core::constants::SOL_MINT;
+use crate::transport::PositionEvent;
+use super::super::{BadgerDatabase, DatabaseError};
+use super::PriceCandleStore;
+
+/// Fill not yet valued in fiat
+pub const TAX_FILL_PENDING: &str = "PENDING";
+/// Fill valued at the SOL price at execution
+pub const TAX_FILL_PRICED: &str = "PRICED";
+/// No SOL price was found near the fill time
+pub const TAX_FILL_UNPRICED: &str = "UNPRICED";
+
+/// Holding period beyond which a disposal is long-term (seconds)
+const LONG_TERM_SECS: i64 = 365 * 86400;
+
+/// Configuration for the tracking-only tax ledger
+#[derive(Debug, Clone)]
+pub struct TaxLedgerConfig {
+    /// Fiat currency fills are valued in (ISO 4217 code)
+    pub currency: String,
+    /// Units of `currency` per USD; the oracle chain prices SOL in USD only
+    pub usd_rate: f64,
+    /// A live SOL quote counts as "at the fill" when taken within this long of it (seconds)
+    pub max_oracle_skew_secs: i64,
+    /// How often pending fills are valued (seconds)
+    pub check_interval_secs: u64,
+    /// Fills without a SOL price after this long are marked unpriced (seconds)
+    pub give_up_after_secs: i64,
+}
+
+impl Default for TaxLedgerConfig {
+    fn default() -> Self {
+        Self {
+            currency: "USD".to_string(),
+            usd_rate: 1.0,
+            max_oracle_skew_secs: 30,
+            check_interval_secs: 15,
+            give_up_after_secs: 900,
+        }
+    }
+}
+
+impl TaxLedgerConfig {
+    /// Defaults with the currency from `BADGER_TAX_CURRENCY` and its USD rate from `BADGER_TAX_USD_RATE`
+    ///
+    /// A currency other than USD without a valid rate falls back to USD
+    /// rather than valuing fills at a made-up rate.
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        let Some(currency) = std::env::var("BADGER_TAX_CURRENCY").ok().map(|c| c.trim().to_uppercase()) else {
+            return config;
+        };
+        if currency.is_empty() || currency == "USD" {
+            return config;
+        }
+        match std::env::var("BADGER_TAX_USD_RATE").ok().and_then(|v| v.parse::<f64>().ok()).filter(|rate| *rate > 0.0) {
+            Some(rate) => {
+                config.currency = currency;
+                config.usd_rate = rate;
+            }
+            None => warn!("BADGER_TAX_CURRENCY={} needs a positive BADGER_TAX_USD_RATE; valuing the tax ledger in USD", currency),
+        }
+        config
+    }
+}
+
+/// One fill mirrored into the tax ledger
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TaxFill {
+    pub id: i64,
+    pub position_id: i64,
+    pub token_mint: String,
+    /// "BUY" or "SELL"
+    pub side: String,
+    pub quantity: f64,
+    /// Gross SOL paid on a buy, or received on a sell, before fees
+    pub amount_sol: f64,
+    pub fee_sol: f64,
+    pub executed_at: i64,
+    pub currency: String,
+    /// Units of `currency` per USD the fill was valued at
+    pub usd_rate: f64,
+    /// SOL price in `currency` at the fill
+    pub sol_price: Option<f64>,
+    /// `amount_sol` in `currency`
+    pub fiat_value: Option<f64>,
+    /// `fee_sol` in `currency`
+    pub fiat_fee: Option<f64>,
+    /// Market data provider, or "candle" for the SOL price candle covering the fill
+    pub oracle_source: Option<String>,
+    pub oracle_at: Option<i64>,
+    pub status: String,
+}
+
+/// A fill to queue for valuation
+#[derive(Debug, Clone)]
+pub struct NewTaxFill {
+    pub position_id: i64,
+    pub token_mint: String,
+    /// "BUY" or "SELL"
+    pub side: String,
+    pub quantity: f64,
+    pub amount_sol: f64,
+    pub fee_sol: f64,
+    pub executed_at: i64,
+}
+
+/// One disposal in the layout of IRS Form 8949
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Form8949Row {
+    pub description: String,
+    pub date_acquired: i64,
+    pub date_sold: i64,
+    /// Sale value net of the sale's fees
+    pub proceeds: f64,
+    /// Purchase value of the sold quantity including its share of the purchase fees
+    pub cost_basis: f64,
+    pub gain_or_loss: f64,
+    pub long_term: bool,
+}
+
+/// CSV layouts the tax ledger exports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaxExportFormat {
+    /// US disposals with acquisition and sale dates, proceeds and cost basis
+    Form8949,
+    /// Every valued fill, for jurisdictions whose forms are built from raw acquisitions and disposals
+    Fills,
+}
+
+impl FromStr for TaxExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "form8949" | "8949" => Ok(TaxExportFormat::Form8949),
+            "fills" => Ok(TaxExportFormat::Fills),
+            other => Err(format!("unknown tax export format: {} (expected form8949 or fills)", other)),
+        }
+    }
+}
+
+/// Tracking-only ledger of every fill valued in a fiat currency
+///
+/// Position events are mirrored here as buy and sell fills; the market data
+/// service then values each one at the SOL price at execution, from a live
+/// quote when it comes within the skew window and otherwise from the SOL
+/// price candle covering the fill. Nothing here feeds back into positions,
+/// P&L or risk: the ledger only answers what a tax authority asks, in its
+/// currency and with its holding-period rules.
+pub struct TaxLedger {
+    db: Arc<BadgerDatabase>,
+    config: TaxLedgerConfig,
+}
+
+impl TaxLedger {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<TaxLedgerConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+        }
+    }
+
+    pub fn config(&self) -> &TaxLedgerConfig {
+        &self.config
+    }
+
+    /// Initialize tax ledger schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing tax ledger schema");
+
+        let create_tax_fills = r#"
+            CREATE TABLE IF NOT EXISTS tax_fills (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                position_id INTEGER NOT NULL,
+                token_mint TEXT NOT NULL,
+                side TEXT NOT NULL,
+                quantity REAL NOT NULL,
+                amount_sol REAL NOT NULL,
+                fee_sol REAL NOT NULL DEFAULT 0,
+                executed_at INTEGER NOT NULL,
+                currency TEXT NOT NULL,
+                usd_rate REAL NOT NULL,
+                sol_price REAL,
+                fiat_value REAL,
+                fiat_fee REAL,
+                oracle_source TEXT,
+                oracle_at INTEGER,
+                status TEXT NOT NULL DEFAULT 'PENDING'
+            )
+        "#;
+
+        sqlx::query(create_tax_fills)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create tax_fills table: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_tax_fills_status ON tax_fills(status, executed_at)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create tax_fills index: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_tax_fills_position ON tax_fills(position_id, side)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create tax_fills position index: {}", e)))?;
+
+        info!("✅ Tax ledger schema initialized");
+        Ok(())
+    }
+
+    /// Mirror a position event as a fill
+    ///
+    /// Opens and increases are buys; reductions and closes are sells of the
+    /// position's lot, with the lot's share of entry fees taken back out of
+    /// the reported fees so only the sale's own fees count against proceeds.
+    ///
+    /// # Returns
+    /// * `Result<Option<i64>, DatabaseError>` - Id of the recorded fill; None for events that are not fills
+    pub async fn record_event(&self, event: &PositionEvent) -> Result<Option<i64>, DatabaseError> {
+        let fill = match event {
+            PositionEvent::PositionOpened { position_id, token_mint, quantity, cost_sol, fees, timestamp, .. } => {
+                NewTaxFill {
+                    position_id: *position_id,
+                    token_mint: token_mint.clone(),
+                    side: "BUY".to_string(),
+                    quantity: *quantity,
+                    amount_sol: *cost_sol,
+                    fee_sol: *fees,
+                    executed_at: *timestamp,
+                }
+            }
+            PositionEvent::PositionIncreased { position_id, token_mint, added_quantity, entry_price, timestamp, .. } => {
+                let fees = sqlx::query_scalar::<_, f64>("SELECT fees FROM positions WHERE id = ?")
+                    .bind(position_id)
+                    .fetch_optional(self.db.get_pool())
+                    .await
+                    .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch entry fees: {}", e)))?
+                    .unwrap_or(0.0);
+                NewTaxFill {
+                    position_id: *position_id,
+                    token_mint: token_mint.clone(),
+                    side: "BUY".to_string(),
+                    quantity: *added_quantity,
+                    amount_sol: entry_price * added_quantity,
+                    fee_sol: fees,
+                    executed_at: *timestamp,
+                }
+            }
+            PositionEvent::PositionReduced { position_id, token_mint, closed_quantity: quantity, exit_price, fees, timestamp, .. }
+            | PositionEvent::PositionClosed { position_id, token_mint, quantity, exit_price, fees, timestamp, .. } => {
+                let exit_fees = fees - self.entry_fee_share(*position_id, *quantity).await?;
+                NewTaxFill {
+                    position_id: *position_id,
+                    token_mint: token_mint.clone(),
+                    side: "SELL".to_string(),
+                    quantity: *quantity,
+                    amount_sol: exit_price * quantity,
+                    fee_sol: exit_fees.max(0.0),
+                    executed_at: *timestamp,
+                }
+            }
+            PositionEvent::StopTriggered { .. } => return Ok(None),
+        };
+        self.record_fill(&fill).await.map(Some)
+    }
+
+    /// Entry fees carried by `quantity` of a position, per unit of its recorded buy
+    async fn entry_fee_share(&self, position_id: i64, quantity: f64) -> Result<f64, DatabaseError> {
+        let buy = sqlx::query_as::<_, (f64, f64)>(
+            "SELECT quantity, fee_sol FROM tax_fills WHERE position_id = ? AND side = 'BUY' ORDER BY id LIMIT 1"
+        )
+        .bind(position_id)
+        .fetch_optional(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch tax ledger buy: {}", e)))?;
+        Ok(match buy {
+            Some((buy_quantity, fee_sol)) if buy_quantity > 0.0 => fee_sol * quantity / buy_quantity,
+            _ => 0.0,
+        })
+    }
+
+    /// Queue a fill for valuation in the ledger currency
+    pub async fn record_fill(&self, fill: &NewTaxFill) -> Result<i64, DatabaseError> {
+        let result = sqlx::query(r#"
+            INSERT INTO tax_fills (position_id, token_mint, side, quantity, amount_sol, fee_sol, executed_at, currency, usd_rate)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(fill.position_id)
+        .bind(&fill.token_mint)
+        .bind(&fill.side)
+        .bind(fill.quantity)
+        .bind(fill.amount_sol)
+        .bind(fill.fee_sol)
+        .bind(fill.executed_at)
+        .bind(&self.config.currency)
+        .bind(self.config.usd_rate)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record tax fill: {}", e)))?;
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Fills still waiting for a SOL price, oldest first
+    pub async fn pending(&self) -> Result<Vec<TaxFill>, DatabaseError> {
+        sqlx::query_as::<_, TaxFill>("SELECT * FROM tax_fills WHERE status = ? ORDER BY executed_at")
+            .bind(TAX_FILL_PENDING)
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch pending tax fills: {}", e)))
+    }
+
+    /// Whether a quote taken at `quoted_at` is close enough to the fill to value it
+    pub fn within_skew(&self, fill: &TaxFill, quoted_at: i64) -> bool {
+        (quoted_at - fill.executed_at).abs() <= self.config.max_oracle_skew_secs
+    }
+
+    /// Value a fill at a SOL price in USD
+    pub async fn price(
+        &self,
+        fill: &TaxFill,
+        sol_usd: f64,
+        oracle_source: &str,
+        oracle_at: i64,
+    ) -> Result<TaxFill, DatabaseError> {
+        let sol_price = sol_usd * fill.usd_rate;
+        let fiat_value = fill.amount_sol * sol_price;
+        let fiat_fee = fill.fee_sol * sol_price;
+
+        sqlx::query(r#"
+            UPDATE tax_fills
+            SET sol_price = ?, fiat_value = ?, fiat_fee = ?, oracle_source = ?, oracle_at = ?, status = ?
+            WHERE id = ?
+        "#)
+        .bind(sol_price)
+        .bind(fiat_value)
+        .bind(fiat_fee)
+        .bind(oracle_source)
+        .bind(oracle_at)
+        .bind(TAX_FILL_PRICED)
+        .bind(fill.id)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to price tax fill: {}", e)))?;
+
+        Ok(TaxFill {
+            sol_price: Some(sol_price),
+            fiat_value: Some(fiat_value),
+            fiat_fee: Some(fiat_fee),
+            oracle_source: Some(oracle_source.to_string()),
+            oracle_at: Some(oracle_at),
+            status: TAX_FILL_PRICED.to_string(),
+            ..fill.clone()
+        })
+    }
+
+    /// Value a fill the live quote came too late for from the SOL candle covering it
+    ///
+    /// # Returns
+    /// * `Result<Option<TaxFill>, DatabaseError>` - None while there is no candle
+    ///   and the fill is not yet past the give-up age
+    pub async fn price_from_candles(
+        &self,
+        fill: &TaxFill,
+        candles: &PriceCandleStore,
+    ) -> Result<Option<TaxFill>, DatabaseError> {
+        let candle_secs = candles.config().candle_secs;
+        let bucket_start = fill.executed_at - fill.executed_at.rem_euclid(candle_secs);
+        let candle = candles.candles(SOL_MINT, bucket_start).await?
+            .into_iter()
+            .find(|c| c.bucket_start == bucket_start);
+        if let Some(candle) = candle {
+            return self.price(fill, candle.close, "candle", candle.bucket_start).await.map(Some);
+        }
+
+        if Utc::now().timestamp() - fill.executed_at < self.config.give_up_after_secs {
+            return Ok(None);
+        }
+        sqlx::query("UPDATE tax_fills SET status = ? WHERE id = ?")
+            .bind(TAX_FILL_UNPRICED)
+            .bind(fill.id)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to mark tax fill unpriced: {}", e)))?;
+        Ok(Some(TaxFill { status: TAX_FILL_UNPRICED.to_string(), ..fill.clone() }))
+    }
+
+    /// Fills executed in `[since, until)`, oldest first
+    pub async fn fills(&self, since: i64, until: i64) -> Result<Vec<TaxFill>, DatabaseError> {
+        sqlx::query_as::<_, TaxFill>(
+            "SELECT * FROM tax_fills WHERE executed_at >= ? AND executed_at < ? ORDER BY executed_at, id"
+        )
+        .bind(since)
+        .bind(until)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch tax fills: {}", e)))
+    }
+
+    /// Disposals in `[since, until)` matched to the buys of their positions
+    ///
+    /// # Returns
+    /// * `Result<(Vec<Form8949Row>, usize), DatabaseError>` - Rows, and sells left
+    ///   out because they or their buy have no fiat value
+    pub async fn form_8949(&self, since: i64, until: i64) -> Result<(Vec<Form8949Row>, usize), DatabaseError> {
+        let sells: Vec<TaxFill> = self.fills(since, until).await?
+            .into_iter()
+            .filter(|fill| fill.side == "SELL")
+            .collect();
+        let mut buys: HashMap<i64, TaxFill> = HashMap::new();
+        let priced_buys = sqlx::query_as::<_, TaxFill>(
+            "SELECT * FROM tax_fills WHERE side = 'BUY' AND status = ? ORDER BY id"
+        )
+        .bind(TAX_FILL_PRICED)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch tax ledger buys: {}", e)))?;
+        for buy in priced_buys {
+            buys.entry(buy.position_id).or_insert(buy);
+        }
+
+        let mut rows = Vec::new();
+        let mut skipped = 0;
+        for sell in sells {
+            let (Some(sale_value), Some(sale_fee)) = (sell.fiat_value, sell.fiat_fee) else {
+                skipped += 1;
+                continue;
+            };
+            let Some(buy) = buys.get(&sell.position_id).filter(|buy| buy.quantity > 0.0) else {
+                skipped += 1;
+                continue;
+            };
+            let share = sell.quantity / buy.quantity;
+            let cost_basis = (buy.fiat_value.unwrap_or(0.0) + buy.fiat_fee.unwrap_or(0.0)) * share;
+            let proceeds = sale_value - sale_fee;
+            rows.push(Form8949Row {
+                description: format!("{} {}", format_quantity(sell.quantity), sell.token_mint),
+                date_acquired: buy.executed_at,
+                date_sold: sell.executed_at,
+                proceeds,
+                cost_basis,
+                gain_or_loss: proceeds - cost_basis,
+                long_term: sell.executed_at - buy.executed_at > LONG_TERM_SECS,
+            });
+        }
+        Ok((rows, skipped))
+    }
+
+    /// CSV of a calendar year (UTC) in the given layout
+    ///
+    /// # Returns
+    /// * `Result<(String, usize), DatabaseError>` - The CSV, and the number of
+    ///   fills left out for lack of a fiat value
+    pub async fn export_csv(&self, format: TaxExportFormat, year: i32) -> Result<(String, usize), DatabaseError> {
+        let (since, until) = year_bounds(year)
+            .ok_or_else(|| DatabaseError::QueryError(format!("Invalid tax year: {}", year)))?;
+        let currency = &self.config.currency;
+        let mut csv = String::new();
+
+        match format {
+            TaxExportFormat::Form8949 => {
+                let (rows, skipped) = self.form_8949(since, until).await?;
+                let _ = writeln!(csv,
+                    "Description of property,Date acquired,Date sold or disposed of,Proceeds ({c}),Cost or other basis ({c}),Code,Adjustment,Gain or (loss) ({c}),Term",
+                    c = currency);
+                for row in &rows {
+                    let _ = writeln!(csv, "{},{},{},{:.2},{:.2},,,{:.2},{}",
+                        row.description,
+                        format_date(row.date_acquired),
+                        format_date(row.date_sold),
+                        row.proceeds,
+                        row.cost_basis,
+                        row.gain_or_loss,
+                        if row.long_term { "Long-term" } else { "Short-term" });
+                }
+                Ok((csv, skipped))
+            }
+            TaxExportFormat::Fills => {
+                let fills = self.fills(since, until).await?;
+                let _ = writeln!(csv,
+                    "Date,Position,Token,Side,Quantity,Amount (SOL),Fee (SOL),SOL price ({c}),Value ({c}),Fee ({c}),Price source,Status",
+                    c = currency);
+                let mut skipped = 0;
+                for fill in &fills {
+                    if fill.status != TAX_FILL_PRICED {
+                        skipped += 1;
+                    }
+                    let fiat = |value: Option<f64>| value.map(|v| format!("{:.2}", v)).unwrap_or_default();
+                    let _ = writeln!(csv, "{},{},{},{},{},{:.9},{:.9},{},{},{},{},{}",
+                        DateTime::<Utc>::from_timestamp(fill.executed_at, 0).map(|at| at.to_rfc3339()).unwrap_or_default(),
+                        fill.position_id,
+                        fill.token_mint,
+                        fill.side,
+                        format_quantity(fill.quantity),
+                        fill.amount_sol,
+                        fill.fee_sol,
+                        fiat(fill.sol_price),
+                        fiat(fill.fiat_value),
+                        fiat(fill.fiat_fee),
+                        fill.oracle_source.as_deref().unwrap_or(""),
+                        fill.status);
+                }
+                Ok((csv, skipped))
+            }
+        }
+    }
+}
+
+/// Unix bounds `[Jan 1, Jan 1 of the next year)` of a calendar year in UTC
+fn year_bounds(year: i32) -> Option<(i64, i64)> {
+    let start = Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).single()?;
+    let end = Utc.with_ymd_and_hms(year + 1, 1, 1, 0, 0, 0).single()?;
+    Some((start.timestamp(), end.timestamp()))
+}
+
+/// Form 8949 date (MM/DD/YYYY, UTC)
+fn format_date(at: i64) -> String {
+    DateTime::<Utc>::from_timestamp(at, 0)
+        .map(|at| format!("{:02}/{:02}/{}", at.month(), at.day(), at.year()))
+        .unwrap_or_default()
+}
+
+/// Token quantity without float noise or trailing zeros
+fn format_quantity(quantity: f64) -> String {
+    let formatted = format!("{:.6}", quantity);
+    formatted.trim_end_matches('0').trim_end_matches('.').to_string()
+}
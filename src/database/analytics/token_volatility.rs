@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tokio::sync::RwLock;
+use tracing::{debug, info, instrument};
+
+use crate::core::SOL_MINT;
+use super::super::{BadgerDatabase, DatabaseError};
+use super::PriceCandleStore;
+
+/// Configuration for per-token volatility and beta
+#[derive(Debug, Clone)]
+pub struct VolatilityConfig {
+    /// Candles the metrics are computed over
+    pub window_candles: i64,
+    /// Candle returns needed before a token's metrics are trusted
+    pub min_returns: usize,
+    /// Daily volatility a buy is sized for in full (1.0 = 100%)
+    pub target_daily_volatility: f64,
+    /// Smallest fraction of a buy kept for the most volatile tokens
+    pub min_size_factor: f64,
+    /// Beta assumed for tokens without metrics when hedging
+    pub default_beta: f64,
+}
+
+impl Default for VolatilityConfig {
+    fn default() -> Self {
+        Self {
+            window_candles: 72,
+            min_returns: 12,
+            target_daily_volatility: 1.0,
+            min_size_factor: 0.25,
+            default_beta: 1.0,
+        }
+    }
+}
+
+/// Realized volatility and beta to SOL of one token
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TokenVolatility {
+    pub token_mint: String,
+    /// Standard deviation of USD log returns, scaled to one day (0.5 = 50%)
+    pub daily_volatility: f64,
+    /// Sensitivity of the token's USD returns to SOL's; None while SOL did not move
+    pub beta_sol: Option<f64>,
+    pub correlation_sol: Option<f64>,
+    /// Candle returns the metrics were computed from
+    pub returns: i64,
+    pub computed_at: i64,
+}
+
+/// Rolling realized volatility and beta-to-SOL of held tokens
+///
+/// Computed from the price candle store: token candles are in SOL, so a
+/// token's USD return is its SOL return plus SOL's own USD return, and beta
+/// is the slope of that against SOL. Buys of tokens more volatile than the
+/// target are scaled down, and hedges are sized on beta-weighted exposure.
+pub struct VolatilityTracker {
+    db: Arc<BadgerDatabase>,
+    candles: Arc<PriceCandleStore>,
+    config: VolatilityConfig,
+    latest: RwLock<HashMap<String, TokenVolatility>>,
+}
+
+impl VolatilityTracker {
+    pub fn new(db: Arc<BadgerDatabase>, candles: Arc<PriceCandleStore>, config: Option<VolatilityConfig>) -> Self {
+        Self {
+            db,
+            candles,
+            config: config.unwrap_or_default(),
+            latest: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> &VolatilityConfig {
+        &self.config
+    }
+
+    /// Initialize token volatility schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing token volatility schema");
+
+        let create_token_volatility = r#"
+            CREATE TABLE IF NOT EXISTS token_volatility (
+                token_mint TEXT PRIMARY KEY,
+                daily_volatility REAL NOT NULL,
+                beta_sol REAL,
+                correlation_sol REAL,
+                returns INTEGER NOT NULL,
+                computed_at INTEGER NOT NULL
+            )
+        "#;
+
+        sqlx::query(create_token_volatility)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create token_volatility table: {}", e)))?;
+
+        info!("✅ Token volatility schema initialized");
+        Ok(())
+    }
+
+    /// Load persisted metrics into memory; returns how many tokens have them
+    pub async fn load(&self) -> Result<usize, DatabaseError> {
+        let rows = sqlx::query_as::<_, TokenVolatility>("SELECT * FROM token_volatility")
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch token volatility: {}", e)))?;
+        let count = rows.len();
+        *self.latest.write().await = rows.into_iter().map(|v| (v.token_mint.clone(), v)).collect();
+        Ok(count)
+    }
+
+    /// Latest metrics of a token
+    pub async fn get(&self, token_mint: &str) -> Option<TokenVolatility> {
+        self.latest.read().await.get(token_mint).cloned()
+    }
+
+    /// Beta of a token to SOL, falling back to the configured default
+    pub async fn beta(&self, token_mint: &str) -> f64 {
+        self.get(token_mint).await
+            .and_then(|v| v.beta_sol)
+            .unwrap_or(self.config.default_beta)
+    }
+
+    /// Fraction of a buy to keep given the token's volatility
+    ///
+    /// 1.0 at or below the target volatility, shrinking in inverse
+    /// proportion above it down to `min_size_factor`.
+    pub async fn size_factor(&self, token_mint: &str) -> f64 {
+        match self.get(token_mint).await {
+            Some(v) if v.daily_volatility > self.config.target_daily_volatility => {
+                (self.config.target_daily_volatility / v.daily_volatility).max(self.config.min_size_factor)
+            }
+            _ => 1.0,
+        }
+    }
+
+    /// Recompute and persist a token's metrics from its candles
+    ///
+    /// # Returns
+    /// * `Result<Option<TokenVolatility>, DatabaseError>` - None with too few candles
+    pub async fn refresh(&self, token_mint: &str) -> Result<Option<TokenVolatility>, DatabaseError> {
+        let since = Utc::now().timestamp() - self.config.window_candles * self.candles.config().candle_secs;
+        let token_candles = self.candles.candles(token_mint, since).await?;
+        let sol_closes: HashMap<i64, f64> = self.candles.candles(SOL_MINT, since).await?
+            .into_iter()
+            .map(|c| (c.bucket_start, c.close))
+            .collect();
+
+        // Log returns of consecutive candles, with SOL's return over the same span where known
+        let mut token_returns = Vec::new();
+        let mut paired = Vec::new();
+        for window in token_candles.windows(2) {
+            let (prev, next) = (&window[0], &window[1]);
+            if !(prev.close > 0.0 && next.close > 0.0) {
+                continue;
+            }
+            let token_sol_return = (next.close / prev.close).ln();
+            let sol_return = match (sol_closes.get(&prev.bucket_start), sol_closes.get(&next.bucket_start)) {
+                (Some(&from), Some(&to)) if from > 0.0 && to > 0.0 => Some((to / from).ln()),
+                _ => None,
+            };
+            token_returns.push(token_sol_return + sol_return.unwrap_or(0.0));
+            if let Some(sol_return) = sol_return {
+                paired.push((token_sol_return + sol_return, sol_return));
+            }
+        }
+
+        if token_returns.len() < self.config.min_returns {
+            debug!("Volatility of {} skipped: {} candle returns", token_mint, token_returns.len());
+            return Ok(None);
+        }
+
+        let candles_per_day = 86400.0_f64 / self.candles.config().candle_secs as f64;
+        let daily_volatility = std_dev(&token_returns) * candles_per_day.sqrt();
+        let (beta_sol, correlation_sol) = if paired.len() >= self.config.min_returns {
+            beta_and_correlation(&paired)
+        } else {
+            (None, None)
+        };
+
+        let volatility = TokenVolatility {
+            token_mint: token_mint.to_string(),
+            daily_volatility,
+            beta_sol,
+            correlation_sol,
+            returns: token_returns.len() as i64,
+            computed_at: Utc::now().timestamp(),
+        };
+
+        sqlx::query(r#"
+            INSERT OR REPLACE INTO token_volatility
+            (token_mint, daily_volatility, beta_sol, correlation_sol, returns, computed_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(&volatility.token_mint)
+        .bind(volatility.daily_volatility)
+        .bind(volatility.beta_sol)
+        .bind(volatility.correlation_sol)
+        .bind(volatility.returns)
+        .bind(volatility.computed_at)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to save token volatility: {}", e)))?;
+
+        debug!("📈 {} daily volatility {:.0}%, beta to SOL {:?}", token_mint, daily_volatility * 100.0, beta_sol);
+        self.latest.write().await.insert(token_mint.to_string(), volatility.clone());
+        Ok(Some(volatility))
+    }
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// Sample standard deviation
+fn std_dev(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+    let mean = mean(values);
+    (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (values.len() - 1) as f64).sqrt()
+}
+
+/// Beta and correlation of (token, SOL) return pairs; None while SOL did not move
+fn beta_and_correlation(pairs: &[(f64, f64)]) -> (Option<f64>, Option<f64>) {
+    let token: Vec<f64> = pairs.iter().map(|(t, _)| *t).collect();
+    let sol: Vec<f64> = pairs.iter().map(|(_, s)| *s).collect();
+    let (token_mean, sol_mean) = (mean(&token), mean(&sol));
+    let covariance = pairs.iter().map(|(t, s)| (t - token_mean) * (s - sol_mean)).sum::<f64>();
+    let sol_variance = sol.iter().map(|s| (s - sol_mean).powi(2)).sum::<f64>();
+    let token_variance = token.iter().map(|t| (t - token_mean).powi(2)).sum::<f64>();
+    if sol_variance <= f64::EPSILON {
+        return (None, None);
+    }
+    let correlation = (token_variance > f64::EPSILON).then(|| covariance / (sol_variance * token_variance).sqrt());
+    (Some(covariance / sol_variance), correlation)
+}
@@ -0,0 +1,199 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{info, instrument};
+
+use super::super::{BadgerDatabase, DatabaseError};
+
+/// Capital moved onto a trading wallet from outside
+pub const FLOW_DEPOSIT: &str = "DEPOSIT";
+/// Capital moved off a trading wallet
+pub const FLOW_WITHDRAWAL: &str = "WITHDRAWAL";
+
+/// External deposit to or withdrawal from a trading wallet
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TreasuryFlow {
+    #[serde(default)]
+    pub id: i64,
+    pub wallet_address: String,
+    pub direction: String, // "DEPOSIT", "WITHDRAWAL"
+    /// Amount moved (SOL, always positive)
+    pub amount_sol: f64,
+    /// Transfer transaction; flows are recorded once per signature
+    #[serde(default)]
+    pub tx_signature: Option<String>,
+    #[serde(default)]
+    pub note: Option<String>,
+    /// When the transfer landed (defaults to when it is recorded)
+    #[serde(default)]
+    pub occurred_at: i64,
+    #[serde(default)]
+    pub recorded_at: i64,
+}
+
+impl TreasuryFlow {
+    /// Amount with deposits positive and withdrawals negative
+    pub fn signed_amount(&self) -> f64 {
+        if self.direction == FLOW_WITHDRAWAL { -self.amount_sol } else { self.amount_sol }
+    }
+}
+
+/// Ledger of capital moved in and out of the trading wallets
+///
+/// Trading P&L alone says nothing about returns once capital is topped up or
+/// taken out mid-period. Flows split a period into sub-periods of constant
+/// external capital, each sub-period's return is taken on the capital at its
+/// start, and the sub-period returns are chained into a time-weighted return.
+pub struct TreasuryLedger {
+    db: Arc<BadgerDatabase>,
+}
+
+impl TreasuryLedger {
+    pub fn new(db: Arc<BadgerDatabase>) -> Self {
+        Self { db }
+    }
+
+    /// Initialize treasury ledger schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing treasury ledger schema");
+
+        let create_treasury_flows = r#"
+            CREATE TABLE IF NOT EXISTS treasury_flows (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                wallet_address TEXT NOT NULL,
+                direction TEXT NOT NULL CHECK (direction IN ('DEPOSIT', 'WITHDRAWAL')),
+                amount_sol REAL NOT NULL CHECK (amount_sol > 0),
+                tx_signature TEXT UNIQUE,
+                note TEXT,
+                occurred_at INTEGER NOT NULL,
+                recorded_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now'))
+            )
+        "#;
+
+        sqlx::query(create_treasury_flows)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create treasury_flows table: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_treasury_flows_occurred ON treasury_flows(occurred_at)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        info!("✅ Treasury ledger schema initialized");
+        Ok(())
+    }
+
+    /// Record a deposit or withdrawal; returns false when its signature is already recorded
+    pub async fn record_flow(&self, flow: &TreasuryFlow) -> Result<bool, DatabaseError> {
+        if flow.direction != FLOW_DEPOSIT && flow.direction != FLOW_WITHDRAWAL {
+            return Err(DatabaseError::QueryError(format!("Unknown treasury flow direction: {}", flow.direction)));
+        }
+        if flow.amount_sol.is_nan() || flow.amount_sol <= 0.0 {
+            return Err(DatabaseError::QueryError(format!("Treasury flow amount must be positive: {}", flow.amount_sol)));
+        }
+
+        let now = Utc::now().timestamp();
+        let occurred_at = if flow.occurred_at > 0 { flow.occurred_at } else { now };
+        let inserted = sqlx::query(r#"
+            INSERT OR IGNORE INTO treasury_flows
+                (wallet_address, direction, amount_sol, tx_signature, note, occurred_at, recorded_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(&flow.wallet_address)
+        .bind(&flow.direction)
+        .bind(flow.amount_sol)
+        .bind(&flow.tx_signature)
+        .bind(&flow.note)
+        .bind(occurred_at)
+        .bind(now)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record treasury flow: {}", e)))?
+        .rows_affected() > 0;
+
+        if inserted {
+            info!("🏦 Treasury {} of {:.4} SOL on {}", flow.direction.to_lowercase(), flow.amount_sol, flow.wallet_address);
+        }
+        Ok(inserted)
+    }
+
+    /// Flows that landed after `start` and up to `end`, oldest first
+    pub async fn flows_between(&self, start: i64, end: i64) -> Result<Vec<TreasuryFlow>, DatabaseError> {
+        sqlx::query_as::<_, TreasuryFlow>(r#"
+            SELECT * FROM treasury_flows
+            WHERE occurred_at > ? AND occurred_at <= ?
+            ORDER BY occurred_at, id
+        "#)
+        .bind(start)
+        .bind(end)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch treasury flows: {}", e)))
+    }
+
+    /// Deposits minus withdrawals after `start` and up to `end` (SOL)
+    pub async fn net_flow_between(&self, start: i64, end: i64) -> Result<f64, DatabaseError> {
+        sqlx::query_scalar::<_, f64>(r#"
+            SELECT COALESCE(SUM(CASE WHEN direction = 'WITHDRAWAL' THEN -amount_sol ELSE amount_sol END), 0.0)
+            FROM treasury_flows
+            WHERE occurred_at > ? AND occurred_at <= ?
+        "#)
+        .bind(start)
+        .bind(end)
+        .fetch_one(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to sum treasury flows: {}", e)))
+    }
+
+    /// Trading capital at `at`: net external flows plus realized P&L, both up to `at`
+    pub async fn capital_at(&self, at: i64) -> Result<f64, DatabaseError> {
+        let net_flows = self.net_flow_between(i64::MIN, at).await?;
+        let realized_pnl = self.realized_pnl_between(i64::MIN, at).await?;
+        Ok(net_flows + realized_pnl)
+    }
+
+    /// Time-weighted return over a period
+    ///
+    /// Returns None when a sub-period with P&L started without positive
+    /// capital (typically no deposits recorded yet), since no return can be
+    /// taken on it.
+    pub async fn time_weighted_return(&self, start: i64, end: i64) -> Result<Option<f64>, DatabaseError> {
+        let mut boundaries = vec![start];
+        for flow in self.flows_between(start, end).await? {
+            if flow.occurred_at < end && boundaries.last() != Some(&flow.occurred_at) {
+                boundaries.push(flow.occurred_at);
+            }
+        }
+        boundaries.push(end);
+
+        let mut growth = 1.0;
+        for window in boundaries.windows(2) {
+            let pnl = self.realized_pnl_between(window[0], window[1]).await?;
+            if pnl == 0.0 {
+                continue;
+            }
+            let capital = self.capital_at(window[0]).await?;
+            if capital <= 0.0 {
+                return Ok(None);
+            }
+            growth *= 1.0 + pnl / capital;
+        }
+        Ok(Some(growth - 1.0))
+    }
+
+    /// P&L of positions closed after `start` and up to `end`
+    async fn realized_pnl_between(&self, start: i64, end: i64) -> Result<f64, DatabaseError> {
+        sqlx::query_scalar::<_, f64>(r#"
+            SELECT COALESCE(SUM(pnl), 0.0) FROM positions
+            WHERE status = 'CLOSED' AND exit_timestamp > ? AND exit_timestamp <= ?
+        "#)
+        .bind(start)
+        .bind(end)
+        .fetch_one(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to sum realized P&L: {}", e)))
+    }
+}
@@ -0,0 +1,203 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::core::{MarketEvent, SOL_MINT};
+use crate::database::{BadgerDatabase, DatabaseError};
+
+/// Configuration for volume-profile based stop placement
+#[derive(Debug, Clone)]
+pub struct VolumeProfileConfig {
+    /// Trade history the profile is built from (seconds)
+    pub lookback_secs: i64,
+    /// Price buckets between the lowest and highest traded price
+    pub bucket_count: usize,
+    /// Trades needed before a profile is trusted
+    pub min_samples: usize,
+    /// Bucket volume, relative to the mean bucket, that makes a high-volume node
+    pub node_volume_ratio: f64,
+    /// Stop distance below the bottom of the support node (fraction of price)
+    pub stop_buffer_pct: f64,
+    /// Support further than this below the current price is ignored (fraction of price)
+    pub max_stop_distance_pct: f64,
+    /// How often open positions are checked against their stops (seconds)
+    pub check_interval_secs: u64,
+}
+
+impl Default for VolumeProfileConfig {
+    fn default() -> Self {
+        Self {
+            lookback_secs: 6 * 3600,
+            bucket_count: 24,
+            min_samples: 20,
+            node_volume_ratio: 1.5,
+            stop_buffer_pct: 0.02,
+            max_stop_distance_pct: 0.35,
+            check_interval_secs: 15,
+        }
+    }
+}
+
+/// One trade reduced to price and size
+#[derive(Debug, Clone, Copy)]
+pub struct PriceVolumeSample {
+    /// SOL per token
+    pub price: f64,
+    pub volume_sol: f64,
+}
+
+/// SOL volume traded within a price range
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VolumeNode {
+    pub price_low: f64,
+    pub price_high: f64,
+    pub volume_sol: f64,
+}
+
+/// Traded SOL volume by price bucket for one token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VolumeProfile {
+    pub token_mint: String,
+    pub buckets: Vec<VolumeNode>,
+    pub total_volume_sol: f64,
+    pub samples: usize,
+}
+
+impl VolumeProfile {
+    /// Bucket samples evenly between their lowest and highest price
+    pub fn build(token_mint: &str, samples: &[PriceVolumeSample], bucket_count: usize) -> Option<Self> {
+        let bucket_count = bucket_count.max(1);
+        let low = samples.iter().map(|s| s.price).fold(f64::INFINITY, f64::min);
+        let high = samples.iter().map(|s| s.price).fold(f64::NEG_INFINITY, f64::max);
+        if samples.is_empty() || high <= low {
+            return None;
+        }
+
+        let width = (high - low) / bucket_count as f64;
+        let mut buckets: Vec<VolumeNode> = (0..bucket_count)
+            .map(|i| VolumeNode {
+                price_low: low + width * i as f64,
+                price_high: low + width * (i + 1) as f64,
+                volume_sol: 0.0,
+            })
+            .collect();
+        for sample in samples {
+            let index = (((sample.price - low) / width) as usize).min(bucket_count - 1);
+            buckets[index].volume_sol += sample.volume_sol;
+        }
+
+        Some(Self {
+            token_mint: token_mint.to_string(),
+            total_volume_sol: buckets.iter().map(|b| b.volume_sol).sum(),
+            buckets,
+            samples: samples.len(),
+        })
+    }
+
+    /// Buckets holding at least `ratio` times the mean bucket volume
+    pub fn high_volume_nodes(&self, ratio: f64) -> Vec<&VolumeNode> {
+        let mean = self.total_volume_sol / self.buckets.len() as f64;
+        self.buckets.iter().filter(|b| b.volume_sol >= mean * ratio).collect()
+    }
+
+    /// Nearest high-volume node starting below `price`
+    pub fn support_below(&self, price: f64, ratio: f64) -> Option<&VolumeNode> {
+        self.high_volume_nodes(ratio)
+            .into_iter()
+            .filter(|node| node.price_low < price)
+            .max_by(|a, b| a.price_low.total_cmp(&b.price_low))
+    }
+
+    /// Stop just below the nearest liquidity cluster under `price`
+    ///
+    /// # Returns
+    /// * `Option<f64>` - None when there is no support node within the allowed distance
+    pub fn stop_price(&self, price: f64, config: &VolumeProfileConfig) -> Option<f64> {
+        let support = self.support_below(price, config.node_volume_ratio)?;
+        let stop = support.price_low * (1.0 - config.stop_buffer_pct);
+        (stop >= price * (1.0 - config.max_stop_distance_pct)).then_some(stop)
+    }
+}
+
+/// Builds volume profiles of held tokens from stored swap events
+///
+/// High-volume price nodes are where buyers stepped in before; stops placed
+/// just below them ride out noise above the cluster and exit once it breaks,
+/// instead of sitting at a fixed percentage.
+pub struct VolumeProfiler {
+    db: Arc<BadgerDatabase>,
+    config: VolumeProfileConfig,
+}
+
+impl VolumeProfiler {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<VolumeProfileConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+        }
+    }
+
+    pub fn config(&self) -> &VolumeProfileConfig {
+        &self.config
+    }
+
+    /// SOL-paired swaps of a token within the lookback, as price and SOL volume
+    ///
+    /// # Arguments
+    /// * `token_mint` - Token to sample
+    /// * `decimals` - Token decimals, to turn raw amounts into prices
+    pub async fn swap_samples(&self, token_mint: &str, decimals: u8) -> Result<Vec<PriceVolumeSample>, DatabaseError> {
+        let since = Utc::now().timestamp() - self.config.lookback_secs;
+        let rows = sqlx::query_scalar::<_, String>(
+            "SELECT data FROM market_events WHERE event_type = 'swap_detected' AND timestamp >= ? AND data LIKE ?"
+        )
+        .bind(since)
+        .bind(format!("%{}%", token_mint))
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch swap events: {}", e)))?;
+
+        let token_scale = 10f64.powi(decimals as i32);
+        let samples = rows.iter()
+            .filter_map(|data| match serde_json::from_str::<MarketEvent>(data).ok()? {
+                MarketEvent::SwapDetected { swap } => Some(swap),
+                _ => None,
+            })
+            .filter_map(|swap| {
+                let (sol_lamports, token_raw) = if swap.token_in == SOL_MINT && swap.token_out == token_mint {
+                    (swap.amount_in, swap.amount_out)
+                } else if swap.token_out == SOL_MINT && swap.token_in == token_mint {
+                    (swap.amount_out, swap.amount_in)
+                } else {
+                    return None;
+                };
+                if sol_lamports == 0 || token_raw == 0 {
+                    return None;
+                }
+                let volume_sol = sol_lamports as f64 / 1e9;
+                Some(PriceVolumeSample {
+                    price: volume_sol / (token_raw as f64 / token_scale),
+                    volume_sol,
+                })
+            })
+            .collect();
+        Ok(samples)
+    }
+
+    /// Volume profile of a token; None with too few trades to trust
+    pub async fn profile(&self, token_mint: &str, decimals: u8) -> Result<Option<VolumeProfile>, DatabaseError> {
+        let samples = self.swap_samples(token_mint, decimals).await?;
+        if samples.len() < self.config.min_samples {
+            debug!("Volume profile of {} skipped: {} trades", token_mint, samples.len());
+            return Ok(None);
+        }
+        Ok(VolumeProfile::build(token_mint, &samples, self.config.bucket_count))
+    }
+
+    /// Dynamic stop for a held token at the current price
+    pub async fn stop_for(&self, token_mint: &str, decimals: u8, price: f64) -> Result<Option<f64>, DatabaseError> {
+        Ok(self.profile(token_mint, decimals).await?
+            .and_then(|profile| profile.stop_price(price, &self.config)))
+    }
+}
@@ -0,0 +1,343 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use super::fast_wallet_db::UltraFastWalletDB;
+use super::insider_analytics::InsiderProfile;
+
+/// Configuration for the in-memory insider cache
+#[derive(Debug, Clone)]
+pub struct WalletCacheConfig {
+    /// Approximate memory the cache may hold before least recently used entries are evicted (bytes)
+    pub max_memory_bytes: usize,
+    /// Entries not read or written for this long are evicted as stale (seconds)
+    pub max_idle_secs: i64,
+    /// Hot wallet store profiles are written through to, None to keep them in memory only
+    pub store_path: Option<PathBuf>,
+    /// How often the hot wallet store is reconciled against SQLite (seconds)
+    pub reconcile_interval_secs: u64,
+}
+
+impl Default for WalletCacheConfig {
+    fn default() -> Self {
+        Self {
+            max_memory_bytes: 64 * 1024 * 1024,
+            max_idle_secs: 6 * 3600,
+            store_path: None,
+            reconcile_interval_secs: 1800,
+        }
+    }
+}
+
+impl WalletCacheConfig {
+    /// Defaults with the ceiling from `BADGER_INSIDER_CACHE_MB` and the store from `BADGER_INSIDER_CACHE_STORE` when set
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(mb) = std::env::var("BADGER_INSIDER_CACHE_MB").ok().and_then(|v| v.parse::<usize>().ok()) {
+            config.max_memory_bytes = mb * 1024 * 1024;
+        }
+        config.store_path = std::env::var("BADGER_INSIDER_CACHE_STORE").ok()
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from);
+        if let Some(secs) = std::env::var("BADGER_INSIDER_CACHE_RECONCILE_SECS").ok().and_then(|v| v.parse::<u64>().ok()) {
+            config.reconcile_interval_secs = secs.max(1);
+        }
+        config
+    }
+}
+
+/// Cache occupancy and eviction counters since startup
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WalletCacheStats {
+    pub profiles: usize,
+    pub launches: usize,
+    /// Approximate bytes held
+    pub memory_bytes: usize,
+    pub hits: u64,
+    pub misses: u64,
+    /// Entries evicted to stay under the memory ceiling
+    pub evicted_lru: u64,
+    /// Entries evicted after sitting idle
+    pub evicted_stale: u64,
+    /// Profile writes the hot wallet store failed to take
+    pub store_write_failures: u64,
+}
+
+/// When a token launched and who deployed it
+#[derive(Debug, Clone)]
+pub struct TokenLaunch {
+    pub launched_at: i64,
+    pub deployer: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CacheKey {
+    Profile(String),
+    Launch(String),
+}
+
+struct Slot<V> {
+    value: V,
+    /// Position in the recency order
+    tick: u64,
+    last_access: i64,
+    bytes: usize,
+}
+
+#[derive(Default)]
+struct CacheInner {
+    profiles: HashMap<String, Slot<InsiderProfile>>,
+    launches: HashMap<String, Slot<TokenLaunch>>,
+    /// Recency order, least recently used first
+    recency: BTreeMap<u64, CacheKey>,
+    next_tick: u64,
+    memory_bytes: usize,
+    stats: WalletCacheStats,
+}
+
+impl CacheInner {
+    fn touch(&mut self, key: CacheKey, old_tick: Option<u64>) -> u64 {
+        if let Some(old_tick) = old_tick {
+            self.recency.remove(&old_tick);
+        }
+        let tick = self.next_tick;
+        self.next_tick += 1;
+        self.recency.insert(tick, key);
+        tick
+    }
+
+    /// Drop an entry; returns whether it was present
+    fn remove(&mut self, key: &CacheKey) -> bool {
+        let removed = match key {
+            CacheKey::Profile(wallet) => self.profiles.remove(wallet).map(|slot| (slot.tick, slot.bytes)),
+            CacheKey::Launch(mint) => self.launches.remove(mint).map(|slot| (slot.tick, slot.bytes)),
+        };
+        match removed {
+            Some((tick, bytes)) => {
+                self.recency.remove(&tick);
+                self.memory_bytes -= bytes;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn last_access(&self, key: &CacheKey) -> Option<i64> {
+        match key {
+            CacheKey::Profile(wallet) => self.profiles.get(wallet).map(|slot| slot.last_access),
+            CacheKey::Launch(mint) => self.launches.get(mint).map(|slot| slot.last_access),
+        }
+    }
+}
+
+/// In-memory insider wallet profiles and token launch times
+///
+/// A long-running instance sees tens of thousands of tokens and wallets and
+/// only ever needs the recent ones; the database stays the source of truth.
+/// Entries are kept in recency order: past the memory ceiling the least
+/// recently used are evicted, and `evict_stale` drops entries left idle.
+///
+/// With a hot wallet store attached, profile writes go through to it as
+/// well, so a restart warms the cache from one mmap load.
+pub struct WalletIntelligenceCache {
+    config: WalletCacheConfig,
+    inner: Mutex<CacheInner>,
+    store: Option<Arc<UltraFastWalletDB>>,
+}
+
+impl Default for WalletIntelligenceCache {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl WalletIntelligenceCache {
+    pub fn new(config: Option<WalletCacheConfig>) -> Self {
+        Self {
+            config: config.unwrap_or_default(),
+            inner: Mutex::new(CacheInner::default()),
+            store: None,
+        }
+    }
+
+    /// Write profiles through to a hot wallet store
+    pub fn with_store(mut self, store: Arc<UltraFastWalletDB>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    pub fn config(&self) -> &WalletCacheConfig {
+        &self.config
+    }
+
+    /// Hot wallet store profiles are written through to
+    pub fn store(&self) -> Option<Arc<UltraFastWalletDB>> {
+        self.store.clone()
+    }
+
+    /// Fill the cache from the hot wallet store; returns how many profiles were loaded
+    pub fn warm_up(&self) -> Result<usize, super::super::DatabaseError> {
+        let Some(store) = &self.store else {
+            return Ok(0);
+        };
+        let profiles = store.load()?;
+        let loaded = profiles.len();
+        for profile in profiles.into_values() {
+            self.cache_profile(profile);
+        }
+        Ok(loaded)
+    }
+
+    /// Cached profile for a wallet
+    pub fn profile(&self, wallet_address: &str) -> Option<InsiderProfile> {
+        let mut inner = self.inner.lock().unwrap();
+        let Some(old_tick) = inner.profiles.get(wallet_address).map(|slot| slot.tick) else {
+            inner.stats.misses += 1;
+            return None;
+        };
+        let tick = inner.touch(CacheKey::Profile(wallet_address.to_string()), Some(old_tick));
+        inner.stats.hits += 1;
+        let slot = inner.profiles.get_mut(wallet_address)?;
+        slot.tick = tick;
+        slot.last_access = Utc::now().timestamp();
+        Some(slot.value.clone())
+    }
+
+    /// Whether a wallet's profile is cached, without touching its recency or the hit counters
+    pub fn contains_profile(&self, wallet_address: &str) -> bool {
+        self.inner.lock().unwrap().profiles.contains_key(wallet_address)
+    }
+
+    /// Cache a wallet's profile, replacing any previous one, and write it through to the store
+    pub fn insert_profile(&self, profile: InsiderProfile) {
+        if let Some(store) = &self.store {
+            if let Err(e) = store.put(&profile) {
+                warn!("Failed to write {} through to the hot wallet store: {}", profile.wallet_address, e);
+                self.inner.lock().unwrap().stats.store_write_failures += 1;
+            }
+        }
+        self.cache_profile(profile);
+    }
+
+    /// Replace a profile already corrected in the store, if it is cached
+    pub fn refresh_profile(&self, profile: InsiderProfile) {
+        let cached = self.inner.lock().unwrap().profiles.contains_key(&profile.wallet_address);
+        if cached {
+            self.cache_profile(profile);
+        }
+    }
+
+    /// Drop a wallet's profile from memory
+    pub fn remove_profile(&self, wallet_address: &str) {
+        self.inner.lock().unwrap().remove(&CacheKey::Profile(wallet_address.to_string()));
+    }
+
+    fn cache_profile(&self, profile: InsiderProfile) {
+        let bytes = std::mem::size_of::<InsiderProfile>()
+            + profile.wallet_address.len() * 2
+            + profile.favorite_tokens.iter().map(|token| token.len() + std::mem::size_of::<String>()).sum::<usize>();
+        let mut inner = self.inner.lock().unwrap();
+        let key = CacheKey::Profile(profile.wallet_address.clone());
+        inner.remove(&key);
+        let tick = inner.touch(key, None);
+        inner.memory_bytes += bytes;
+        inner.profiles.insert(profile.wallet_address.clone(), Slot {
+            value: profile,
+            tick,
+            last_access: Utc::now().timestamp(),
+            bytes,
+        });
+        self.enforce_ceiling(&mut inner);
+    }
+
+    /// Launch of a token, if seen
+    pub fn launch(&self, token_mint: &str) -> Option<TokenLaunch> {
+        let mut inner = self.inner.lock().unwrap();
+        let Some(old_tick) = inner.launches.get(token_mint).map(|slot| slot.tick) else {
+            inner.stats.misses += 1;
+            return None;
+        };
+        let tick = inner.touch(CacheKey::Launch(token_mint.to_string()), Some(old_tick));
+        inner.stats.hits += 1;
+        let slot = inner.launches.get_mut(token_mint)?;
+        slot.tick = tick;
+        slot.last_access = Utc::now().timestamp();
+        Some(slot.value.clone())
+    }
+
+    /// Record a token launch; the first recorded time is kept and a deployer is filled in once known
+    pub fn record_launch(&self, token_mint: &str, launched_at: i64, deployer: Option<&str>) {
+        let mut inner = self.inner.lock().unwrap();
+        let now = Utc::now().timestamp();
+        if let Some(old_tick) = inner.launches.get(token_mint).map(|slot| slot.tick) {
+            let tick = inner.touch(CacheKey::Launch(token_mint.to_string()), Some(old_tick));
+            let mut added_bytes = 0;
+            if let Some(slot) = inner.launches.get_mut(token_mint) {
+                slot.tick = tick;
+                slot.last_access = now;
+                if slot.value.deployer.is_none() {
+                    if let Some(deployer) = deployer {
+                        slot.value.deployer = Some(deployer.to_string());
+                        slot.bytes += deployer.len();
+                        added_bytes = deployer.len();
+                    }
+                }
+            }
+            inner.memory_bytes += added_bytes;
+        } else {
+            let bytes = std::mem::size_of::<TokenLaunch>() + token_mint.len() * 2 + deployer.map_or(0, str::len);
+            let tick = inner.touch(CacheKey::Launch(token_mint.to_string()), None);
+            inner.memory_bytes += bytes;
+            inner.launches.insert(token_mint.to_string(), Slot {
+                value: TokenLaunch { launched_at, deployer: deployer.map(str::to_string) },
+                tick,
+                last_access: now,
+                bytes,
+            });
+        }
+        self.enforce_ceiling(&mut inner);
+    }
+
+    /// Evict entries idle longer than the configured limit; returns how many
+    pub fn evict_stale(&self) -> usize {
+        let cutoff = Utc::now().timestamp() - self.config.max_idle_secs;
+        let mut inner = self.inner.lock().unwrap();
+        let mut evicted = 0;
+        // Recency order is access order, so stale entries are all at the front
+        while let Some(key) = inner.recency.first_key_value().map(|(_, key)| key.clone()) {
+            if inner.last_access(&key).is_some_and(|at| at >= cutoff) {
+                break;
+            }
+            inner.remove(&key);
+            evicted += 1;
+        }
+        inner.stats.evicted_stale += evicted as u64;
+        if evicted > 0 {
+            debug!("🧹 Evicted {} stale insider cache entries", evicted);
+        }
+        evicted
+    }
+
+    pub fn stats(&self) -> WalletCacheStats {
+        let inner = self.inner.lock().unwrap();
+        WalletCacheStats {
+            profiles: inner.profiles.len(),
+            launches: inner.launches.len(),
+            memory_bytes: inner.memory_bytes,
+            ..inner.stats.clone()
+        }
+    }
+
+    fn enforce_ceiling(&self, inner: &mut CacheInner) {
+        while inner.memory_bytes > self.config.max_memory_bytes {
+            let Some(key) = inner.recency.first_key_value().map(|(_, key)| key.clone()) else {
+                break;
+            };
+            inner.remove(&key);
+            inner.stats.evicted_lru += 1;
+        }
+    }
+}
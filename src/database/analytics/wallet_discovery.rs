@@ -0,0 +1,391 @@
+use std::path::Path;
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, warn, instrument};
+
+use super::insider_analytics::InsiderAnalytics;
+use super::super::{BadgerDatabase, DatabaseError};
+use super::super::audit_log::{
+    audit_value, record_audit, AuditContext, AUDIT_ACTION_PROMOTE, AUDIT_ACTION_REJECT, AUDIT_ENTITY_WALLET_CANDIDATE,
+};
+
+/// How a wallet candidate entered the discovery pipeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiscoveryMethod {
+    /// Detected from our own on-chain observations
+    Observed,
+    /// Loaded from an external ranking (CSV dump, Dune export)
+    Imported,
+}
+
+impl DiscoveryMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiscoveryMethod::Observed => "OBSERVED",
+            DiscoveryMethod::Imported => "IMPORTED",
+        }
+    }
+}
+
+/// Wallet candidate awaiting probation review
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct WalletCandidate {
+    pub wallet_address: String,
+    pub discovery_method: String, // "OBSERVED", "IMPORTED"
+    pub source: Option<String>,
+    pub reported_pnl: Option<f64>,
+    pub reported_win_rate: Option<f64>,
+    pub reported_trades: Option<i64>,
+    pub status: String, // "PROBATION", "PROMOTED", "REJECTED"
+    pub discovered_at: i64,
+    pub reviewed_at: Option<i64>,
+}
+
+/// Probation settings applied to every candidate regardless of discovery method
+#[derive(Debug, Clone)]
+pub struct ProbationConfig {
+    /// Minimum observed trades before a candidate can be promoted
+    pub min_observed_trades: i64,
+    /// Minimum copy worthiness score for promotion
+    pub min_copy_worthiness: f64,
+    /// Candidates not promoted within this window are rejected
+    pub probation_period_secs: i64,
+}
+
+impl Default for ProbationConfig {
+    fn default() -> Self {
+        Self {
+            min_observed_trades: 5,
+            min_copy_worthiness: 60.0,
+            probation_period_secs: 14 * 86400, // 14 days
+        }
+    }
+}
+
+/// Outcome of a probation review pass
+#[derive(Debug, Clone, Default)]
+pub struct ProbationReview {
+    pub reviewed: usize,
+    pub promoted: usize,
+    pub rejected: usize,
+}
+
+/// Wallet discovery pipeline: candidate intake, external imports and probation
+pub struct WalletDiscovery {
+    db: Arc<BadgerDatabase>,
+    insider_analytics: Arc<InsiderAnalytics>,
+    config: ProbationConfig,
+}
+
+impl WalletDiscovery {
+    pub fn new(db: Arc<BadgerDatabase>, insider_analytics: Arc<InsiderAnalytics>, config: Option<ProbationConfig>) -> Self {
+        Self {
+            db,
+            insider_analytics,
+            config: config.unwrap_or_default(),
+        }
+    }
+
+    /// Initialize wallet discovery schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing wallet discovery schema");
+
+        let create_wallet_candidates = r#"
+            CREATE TABLE IF NOT EXISTS wallet_candidates (
+                wallet_address TEXT PRIMARY KEY,
+                discovery_method TEXT NOT NULL CHECK (discovery_method IN ('OBSERVED', 'IMPORTED')),
+                source TEXT,
+                reported_pnl REAL,
+                reported_win_rate REAL,
+                reported_trades INTEGER,
+                status TEXT NOT NULL DEFAULT 'PROBATION' CHECK (status IN ('PROBATION', 'PROMOTED', 'REJECTED')),
+                discovered_at INTEGER NOT NULL,
+                reviewed_at INTEGER
+            )
+        "#;
+
+        sqlx::query(create_wallet_candidates)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create wallet_candidates table: {}", e)))?;
+
+        let create_indexes = vec![
+            "CREATE INDEX IF NOT EXISTS idx_wallet_candidates_status ON wallet_candidates(status)",
+            "CREATE INDEX IF NOT EXISTS idx_wallet_candidates_method ON wallet_candidates(discovery_method)",
+        ];
+
+        for index_sql in create_indexes {
+            sqlx::query(index_sql)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+        }
+
+        info!("✅ Wallet discovery schema initialized");
+        Ok(())
+    }
+
+    /// Add a wallet candidate to probation
+    ///
+    /// Existing candidates are left untouched, and wallets that already have an
+    /// insider profile are tracked insiders rather than candidates.
+    pub async fn add_candidate(
+        &self,
+        wallet_address: &str,
+        method: DiscoveryMethod,
+        source: Option<&str>,
+        reported_pnl: Option<f64>,
+        reported_win_rate: Option<f64>,
+        reported_trades: Option<i64>,
+    ) -> Result<bool, DatabaseError> {
+        if self.insider_analytics.get_insider_profile(wallet_address).await?.is_some() {
+            return Ok(false);
+        }
+        let result = sqlx::query(r#"
+            INSERT OR IGNORE INTO wallet_candidates (
+                wallet_address, discovery_method, source, reported_pnl,
+                reported_win_rate, reported_trades, status, discovered_at
+            ) VALUES (?, ?, ?, ?, ?, ?, 'PROBATION', ?)
+        "#)
+        .bind(wallet_address)
+        .bind(method.as_str())
+        .bind(source)
+        .bind(reported_pnl)
+        .bind(reported_win_rate)
+        .bind(reported_trades)
+        .bind(Utc::now().timestamp())
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to add wallet candidate: {}", e)))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Import a CSV wallet ranking
+    ///
+    /// Requires a header row with a `wallet` or `address` column. Optional
+    /// columns `pnl`, `win_rate` and `trades` are carried as reported metrics.
+    #[instrument(skip(self))]
+    pub async fn import_csv(&self, path: &Path, source: &str) -> Result<usize, DatabaseError> {
+        let content = tokio::fs::read_to_string(path).await
+            .map_err(|e| DatabaseError::SerializationError(format!("Failed to read {}: {}", path.display(), e)))?;
+
+        // Quoted fields may contain commas, e.g. a "1,234.5" PnL column
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .flexible(true)
+            .from_reader(content.as_bytes());
+        let header: Vec<String> = reader.headers()
+            .map_err(|e| DatabaseError::SerializationError(format!("Invalid CSV header in {}: {}", path.display(), e)))?
+            .iter()
+            .map(|h| h.to_lowercase())
+            .collect();
+        if header.iter().all(|h| h.is_empty()) {
+            return Err(DatabaseError::SerializationError(format!("{} is empty", path.display())));
+        }
+
+        let column = |names: &[&str]| header.iter().position(|h| names.contains(&h.as_str()));
+        let wallet_col = column(&["wallet", "address", "wallet_address", "trader"])
+            .ok_or_else(|| DatabaseError::SerializationError(format!("{} has no wallet column", path.display())))?;
+        let pnl_col = column(&["pnl", "pnl_sol", "total_pnl", "profit"]);
+        let win_rate_col = column(&["win_rate", "winrate"]);
+        let trades_col = column(&["trades", "total_trades", "trade_count"]);
+
+        let mut imported = 0;
+        for record in reader.records() {
+            let record = match record {
+                Ok(record) => record,
+                Err(e) => {
+                    debug!("Skipping unreadable CSV row in {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+            let field = |col: Option<usize>| col.and_then(|c| record.get(c)).filter(|f| !f.is_empty());
+            let number = |col: Option<usize>| field(col).and_then(|v| v.replace(',', "").parse::<f64>().ok());
+
+            let wallet = match field(Some(wallet_col)) {
+                Some(wallet) if crate::core::dex_types::utils::is_valid_pubkey(wallet) => wallet,
+                _ => {
+                    debug!("Skipping CSV row without a valid wallet: {:?}", record);
+                    continue;
+                }
+            };
+
+            if self.add_candidate(
+                wallet,
+                DiscoveryMethod::Imported,
+                Some(source),
+                number(pnl_col),
+                number(win_rate_col),
+                number(trades_col).map(|t| t as i64),
+            ).await? {
+                imported += 1;
+            }
+        }
+
+        info!("📥 Imported {} wallet candidates from {}", imported, path.display());
+        Ok(imported)
+    }
+
+    /// Import a Dune query result export (JSON with `result.rows`)
+    #[instrument(skip(self))]
+    pub async fn import_dune_export(&self, path: &Path, source: &str) -> Result<usize, DatabaseError> {
+        let content = tokio::fs::read_to_string(path).await
+            .map_err(|e| DatabaseError::SerializationError(format!("Failed to read {}: {}", path.display(), e)))?;
+        let export: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| DatabaseError::SerializationError(format!("Invalid Dune export {}: {}", path.display(), e)))?;
+
+        let rows = export.get("result").and_then(|r| r.get("rows")).and_then(|r| r.as_array())
+            .or_else(|| export.as_array())
+            .ok_or_else(|| DatabaseError::SerializationError(format!("{} has no result rows", path.display())))?;
+
+        let get_f64 = |row: &serde_json::Value, keys: &[&str]| {
+            keys.iter().find_map(|k| row.get(*k).and_then(|v| v.as_f64()))
+        };
+
+        let mut imported = 0;
+        for row in rows {
+            let wallet = ["wallet", "address", "trader", "wallet_address"].iter()
+                .find_map(|k| row.get(*k).and_then(|v| v.as_str()));
+
+            let wallet = match wallet {
+                Some(wallet) if crate::core::dex_types::utils::is_valid_pubkey(wallet) => wallet,
+                _ => continue,
+            };
+
+            if self.add_candidate(
+                wallet,
+                DiscoveryMethod::Imported,
+                Some(source),
+                get_f64(row, &["pnl", "pnl_sol", "total_pnl", "profit"]),
+                get_f64(row, &["win_rate", "winrate"]),
+                get_f64(row, &["trades", "total_trades", "trade_count"]).map(|t| t as i64),
+            ).await? {
+                imported += 1;
+            }
+        }
+
+        info!("📥 Imported {} wallet candidates from Dune export {}", imported, path.display());
+        Ok(imported)
+    }
+
+    /// Import every `.csv` and `.json` ranking file in a directory
+    pub async fn import_directory(&self, dir: &Path) -> Result<usize, DatabaseError> {
+        let mut entries = match tokio::fs::read_dir(dir).await {
+            Ok(entries) => entries,
+            Err(_) => return Ok(0), // No import directory configured
+        };
+
+        let mut total = 0;
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            let source = path.file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_else(|| "import".to_string());
+
+            let result = match path.extension().and_then(|e| e.to_str()) {
+                Some("csv") => self.import_csv(&path, &source).await,
+                Some("json") => self.import_dune_export(&path, &source).await,
+                _ => continue,
+            };
+
+            match result {
+                Ok(count) => total += count,
+                Err(e) => warn!("Failed to import wallet ranking {}: {}", path.display(), e),
+            }
+        }
+
+        Ok(total)
+    }
+
+    /// Get candidates currently on probation
+    pub async fn get_probation_candidates(&self) -> Result<Vec<WalletCandidate>, DatabaseError> {
+        let candidates = sqlx::query_as::<_, WalletCandidate>(
+            "SELECT * FROM wallet_candidates WHERE status = 'PROBATION' ORDER BY discovered_at ASC"
+        )
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch probation candidates: {}", e)))?;
+
+        Ok(candidates)
+    }
+
+    /// Addresses of candidates on probation, for the polling rotation
+    ///
+    /// Probation wallets are polled but never given a subscription slot; their
+    /// polled trades build the insider profile the review is judged on.
+    pub async fn probation_wallets(&self) -> Result<Vec<String>, DatabaseError> {
+        Ok(self.get_probation_candidates().await?
+            .into_iter()
+            .map(|candidate| candidate.wallet_address)
+            .collect())
+    }
+
+    /// Review probation candidates against our own observed performance
+    ///
+    /// Imported rankings are never trusted directly: a candidate is promoted only
+    /// once its own insider profile meets the thresholds, and rejected when the
+    /// probation window expires first. Promotion refreshes the insider profile so
+    /// the wallet is ranked with the other insiders; rejection drops the profile
+    /// its probation trades built.
+    #[instrument(skip(self))]
+    pub async fn review_probation(&self) -> Result<ProbationReview, DatabaseError> {
+        let now = Utc::now().timestamp();
+        let mut review = ProbationReview::default();
+
+        for candidate in self.get_probation_candidates().await? {
+            review.reviewed += 1;
+
+            let profile = self.insider_analytics.get_insider_profile(&candidate.wallet_address).await?;
+            let qualified = profile.as_ref().is_some_and(|p| {
+                p.total_trades >= self.config.min_observed_trades
+                    && p.copy_worthiness >= self.config.min_copy_worthiness
+            });
+
+            let (new_status, action, reason) = if qualified {
+                review.promoted += 1;
+                let profile = profile.as_ref().expect("qualified candidates have a profile");
+                ("PROMOTED", AUDIT_ACTION_PROMOTE, format!("{} observed trades, copy worthiness {:.1}",
+                    profile.total_trades, profile.copy_worthiness))
+            } else if now - candidate.discovered_at > self.config.probation_period_secs {
+                review.rejected += 1;
+                ("REJECTED", AUDIT_ACTION_REJECT, format!("Not qualified within the {} day probation period",
+                    self.config.probation_period_secs / 86400))
+            } else {
+                continue;
+            };
+
+            let mut reviewed = candidate.clone();
+            reviewed.status = new_status.to_string();
+            reviewed.reviewed_at = Some(now);
+
+            let mut tx = self.db.begin_transaction().await?;
+            sqlx::query("UPDATE wallet_candidates SET status = ?, reviewed_at = ? WHERE wallet_address = ?")
+                .bind(new_status)
+                .bind(now)
+                .bind(&candidate.wallet_address)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to update wallet candidate: {}", e)))?;
+
+            record_audit(&mut *tx, &AuditContext::service("probation_review", reason), AUDIT_ENTITY_WALLET_CANDIDATE,
+                &candidate.wallet_address, action, audit_value(&candidate), audit_value(&reviewed)).await?;
+
+            tx.commit().await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to commit wallet candidate review: {}", e)))?;
+
+            if new_status == "PROMOTED" {
+                self.insider_analytics.update_insider_profile(&candidate.wallet_address).await?;
+            } else {
+                self.insider_analytics.remove_insider_profile(&candidate.wallet_address).await?;
+            }
+
+            info!("🔎 Wallet candidate {} ({}) {}", candidate.wallet_address, candidate.discovery_method, new_status);
+        }
+
+        Ok(review)
+    }
+}
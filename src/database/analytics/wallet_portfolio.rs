@@ -0,0 +1,205 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, instrument};
+
+use crate::database::{BadgerDatabase, DatabaseError};
+
+/// Wallet roles tracked by the portfolio
+pub const WALLET_ROLE_TRADING: &str = "TRADING";
+pub const WALLET_ROLE_COLD: &str = "COLD";
+pub const WALLET_ROLE_RESERVE: &str = "RESERVE";
+
+/// Configuration for multi-wallet portfolio tracking
+#[derive(Debug, Clone)]
+pub struct WalletPortfolioConfig {
+    /// How often wallet balances are refreshed (seconds)
+    pub refresh_interval_secs: u64,
+    /// Balance snapshots kept per wallet (days)
+    pub retention_days: i64,
+}
+
+impl Default for WalletPortfolioConfig {
+    fn default() -> Self {
+        Self {
+            refresh_interval_secs: 60,
+            retention_days: 30,
+        }
+    }
+}
+
+/// Latest balance of one tracked wallet
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct WalletBalance {
+    pub wallet_address: String,
+    pub role: String, // "TRADING", "COLD", "RESERVE"
+    pub sol_balance: f64,
+    pub slot: i64,
+    pub captured_at: i64,
+}
+
+/// Balances aggregated across all tracked wallets
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PortfolioSummary {
+    pub wallets: Vec<WalletBalance>,
+    pub total_sol: f64,
+    pub trading_sol: f64,
+    pub cold_sol: f64,
+    pub reserve_sol: f64,
+}
+
+/// SOL balances of every wallet we own, by role
+///
+/// Trading pool, cold storage and reserve wallets are registered with a role;
+/// each refresh stores a balance snapshot per wallet so status, reports and
+/// risk math can use the whole book rather than the trading wallet alone.
+pub struct WalletPortfolio {
+    db: Arc<BadgerDatabase>,
+    config: WalletPortfolioConfig,
+}
+
+impl WalletPortfolio {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<WalletPortfolioConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+        }
+    }
+
+    pub fn config(&self) -> &WalletPortfolioConfig {
+        &self.config
+    }
+
+    /// Initialize wallet portfolio schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing wallet portfolio schema");
+
+        let create_portfolio_wallets = r#"
+            CREATE TABLE IF NOT EXISTS portfolio_wallets (
+                wallet_address TEXT PRIMARY KEY,
+                role TEXT NOT NULL CHECK (role IN ('TRADING', 'COLD', 'RESERVE')),
+                added_at INTEGER NOT NULL
+            )
+        "#;
+
+        let create_wallet_balance_snapshots = r#"
+            CREATE TABLE IF NOT EXISTS wallet_balance_snapshots (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                wallet_address TEXT NOT NULL,
+                role TEXT NOT NULL,
+                sol_balance REAL NOT NULL,
+                slot INTEGER NOT NULL,
+                captured_at INTEGER NOT NULL,
+                FOREIGN KEY (wallet_address) REFERENCES portfolio_wallets (wallet_address)
+            )
+        "#;
+
+        for table_sql in [create_portfolio_wallets, create_wallet_balance_snapshots] {
+            sqlx::query(table_sql)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create wallet portfolio table: {}", e)))?;
+        }
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_wallet_balance_snapshots_wallet ON wallet_balance_snapshots(wallet_address, captured_at)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        info!("✅ Wallet portfolio schema initialized");
+        Ok(())
+    }
+
+    /// Track a wallet under a role; re-registering changes its role
+    pub async fn register_wallet(&self, wallet_address: &str, role: &str) -> Result<(), DatabaseError> {
+        sqlx::query(r#"
+            INSERT INTO portfolio_wallets (wallet_address, role, added_at) VALUES (?, ?, ?)
+            ON CONFLICT(wallet_address) DO UPDATE SET role = excluded.role
+        "#)
+        .bind(wallet_address)
+        .bind(role)
+        .bind(Utc::now().timestamp())
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to register portfolio wallet: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Tracked wallets with their roles
+    pub async fn wallets(&self) -> Result<Vec<(String, String)>, DatabaseError> {
+        sqlx::query_as::<_, (String, String)>("SELECT wallet_address, role FROM portfolio_wallets ORDER BY role, wallet_address")
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch portfolio wallets: {}", e)))
+    }
+
+    /// Store a balance snapshot for each wallet and prune old snapshots
+    ///
+    /// # Arguments
+    /// * `balances` - (wallet address, lamports) read at `slot`
+    /// * `slot` - Slot the balances were read at
+    #[instrument(skip(self, balances))]
+    pub async fn record_balances(&self, balances: &[(String, u64)], slot: u64) -> Result<(), DatabaseError> {
+        let now = Utc::now().timestamp();
+        let mut tx = self.db.begin_transaction().await?;
+
+        for (wallet_address, lamports) in balances {
+            sqlx::query(r#"
+                INSERT INTO wallet_balance_snapshots (wallet_address, role, sol_balance, slot, captured_at)
+                SELECT wallet_address, role, ?, ?, ? FROM portfolio_wallets WHERE wallet_address = ?
+            "#)
+            .bind(*lamports as f64 / 1_000_000_000.0)
+            .bind(slot as i64)
+            .bind(now)
+            .bind(wallet_address)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to record wallet balance: {}", e)))?;
+        }
+
+        sqlx::query("DELETE FROM wallet_balance_snapshots WHERE captured_at < ?")
+            .bind(now - self.config.retention_days * 86400)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to prune wallet balances: {}", e)))?;
+
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit wallet balances: {}", e)))?;
+
+        debug!("👛 Recorded balances of {} wallets at slot {}", balances.len(), slot);
+        Ok(())
+    }
+
+    /// Latest balance of every tracked wallet, aggregated by role
+    pub async fn summary(&self) -> Result<PortfolioSummary, DatabaseError> {
+        let wallets = sqlx::query_as::<_, WalletBalance>(r#"
+            SELECT s.wallet_address, w.role, s.sol_balance, s.slot, s.captured_at
+            FROM wallet_balance_snapshots s
+            JOIN portfolio_wallets w ON w.wallet_address = s.wallet_address
+            WHERE s.id = (
+                SELECT MAX(id) FROM wallet_balance_snapshots latest
+                WHERE latest.wallet_address = s.wallet_address
+            )
+            ORDER BY w.role, s.sol_balance DESC
+        "#)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch wallet balances: {}", e)))?;
+
+        let mut summary = PortfolioSummary::default();
+        for wallet in &wallets {
+            summary.total_sol += wallet.sol_balance;
+            match wallet.role.as_str() {
+                WALLET_ROLE_TRADING => summary.trading_sol += wallet.sol_balance,
+                WALLET_ROLE_COLD => summary.cold_sol += wallet.sol_balance,
+                _ => summary.reserve_sol += wallet.sol_balance,
+            }
+        }
+        summary.wallets = wallets;
+
+        Ok(summary)
+    }
+}
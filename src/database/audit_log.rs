@@ -0,0 +1,283 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Sqlite};
+use tracing::{debug, info, instrument};
+
+use super::{BadgerDatabase, DatabaseError};
+
+/// Per-insider copy settings (`insider_copy_settings`)
+pub const AUDIT_ENTITY_COPY_SETTINGS: &str = "COPY_SETTINGS";
+/// Wallet labels (`wallet_labels`)
+pub const AUDIT_ENTITY_WALLET_LABEL: &str = "WALLET_LABEL";
+/// Insider wallet candidates under probation (`wallet_candidates`)
+pub const AUDIT_ENTITY_WALLET_CANDIDATE: &str = "WALLET_CANDIDATE";
+/// Blacklisted toxic wallets (`toxic_wallets`)
+pub const AUDIT_ENTITY_TOXIC_WALLET: &str = "TOXIC_WALLET";
+
+pub const AUDIT_ACTION_CREATE: &str = "CREATE";
+pub const AUDIT_ACTION_UPDATE: &str = "UPDATE";
+/// Soft delete: the row is kept with `deleted_at` set
+pub const AUDIT_ACTION_DELETE: &str = "DELETE";
+/// A soft-deleted row was set again
+pub const AUDIT_ACTION_RESTORE: &str = "RESTORE";
+pub const AUDIT_ACTION_PROMOTE: &str = "PROMOTE";
+pub const AUDIT_ACTION_REJECT: &str = "REJECT";
+/// Wallet added to a blacklist
+pub const AUDIT_ACTION_FLAG: &str = "FLAG";
+/// Wallet dropped from a blacklist
+pub const AUDIT_ACTION_CLEAR: &str = "CLEAR";
+
+/// Who changed intelligence data, and why
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditContext {
+    /// "operator (command #<id>)" for control channel commands, the service name for automated changes
+    pub actor: String,
+    pub reason: Option<String>,
+}
+
+impl AuditContext {
+    /// Change made through an operator command
+    pub fn operator(command_id: i64, reason: Option<String>) -> Self {
+        Self {
+            actor: format!("operator (command #{})", command_id),
+            reason,
+        }
+    }
+
+    /// Change made by the bot itself
+    pub fn service(service: &str, reason: impl Into<String>) -> Self {
+        Self {
+            actor: service.to_string(),
+            reason: Some(reason.into()),
+        }
+    }
+}
+
+/// One recorded mutation of intelligence data
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AuditLogEntry {
+    pub id: i64,
+    pub entity_type: String,
+    /// Wallet address the change applies to
+    pub entity_id: String,
+    pub action: String,
+    pub actor: String,
+    pub reason: Option<String>,
+    /// Row before the change as JSON, None when it did not exist
+    pub before_value: Option<String>,
+    /// Row after the change as JSON, None when it was removed
+    pub after_value: Option<String>,
+    pub created_at: i64,
+}
+
+/// One call to the control API, refused ones included
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ApiAuditEntry {
+    pub id: i64,
+    /// Client name of the API key, None when the caller did not authenticate
+    pub client: Option<String>,
+    pub role: Option<String>,
+    pub method: String,
+    pub path: String,
+    pub status: i64,
+    /// Command and target, or the error returned
+    pub detail: Option<String>,
+    pub created_at: i64,
+}
+
+/// JSON form of a row for `before_value` / `after_value`
+pub fn audit_value<T: Serialize>(value: &T) -> Option<String> {
+    serde_json::to_string(value).ok()
+}
+
+/// Record a mutation, normally inside the transaction making it
+///
+/// # Arguments
+/// * `executor` - Pool or open transaction
+/// * `context` - Actor and reason of the change
+/// * `entity_type` - One of the `AUDIT_ENTITY_*` constants
+/// * `entity_id` - Wallet address the change applies to
+/// * `action` - One of the `AUDIT_ACTION_*` constants
+/// * `before` / `after` - Row as JSON before and after the change (see `audit_value`)
+pub async fn record_audit<'e, E>(
+    executor: E,
+    context: &AuditContext,
+    entity_type: &str,
+    entity_id: &str,
+    action: &str,
+    before: Option<String>,
+    after: Option<String>,
+) -> Result<(), DatabaseError>
+where
+    E: sqlx::Executor<'e, Database = Sqlite>,
+{
+    sqlx::query(r#"
+        INSERT INTO intelligence_audit_log
+            (entity_type, entity_id, action, actor, reason, before_value, after_value, created_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+    "#)
+    .bind(entity_type)
+    .bind(entity_id)
+    .bind(action)
+    .bind(&context.actor)
+    .bind(&context.reason)
+    .bind(before)
+    .bind(after)
+    .bind(Utc::now().timestamp())
+    .execute(executor)
+    .await
+    .map_err(|e| DatabaseError::QueryError(format!("Failed to record audit entry: {}", e)))?;
+
+    debug!("📝 {} {} {} by {}", action, entity_type, entity_id, context.actor);
+    Ok(())
+}
+
+/// Audit trail of insider wallet, blacklist and copy settings changes
+///
+/// Answers questions like "why did we stop copying wallet X": every manual
+/// or automated mutation is kept with its actor, reason and the row before
+/// and after.
+pub struct AuditLog {
+    db: Arc<BadgerDatabase>,
+}
+
+impl AuditLog {
+    pub fn new(db: Arc<BadgerDatabase>) -> Self {
+        Self { db }
+    }
+
+    /// Initialize audit log schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing audit log schema");
+
+        let create_audit_log = r#"
+            CREATE TABLE IF NOT EXISTS intelligence_audit_log (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                entity_type TEXT NOT NULL,
+                entity_id TEXT NOT NULL,
+                action TEXT NOT NULL,
+                actor TEXT NOT NULL,
+                reason TEXT,
+                before_value TEXT,
+                after_value TEXT,
+                created_at INTEGER NOT NULL
+            )
+        "#;
+
+        sqlx::query(create_audit_log)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create intelligence_audit_log table: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_audit_log_entity ON intelligence_audit_log(entity_id, created_at)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        let create_api_audit_log = r#"
+            CREATE TABLE IF NOT EXISTS api_audit_log (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                client TEXT,
+                role TEXT,
+                method TEXT NOT NULL,
+                path TEXT NOT NULL,
+                status INTEGER NOT NULL,
+                detail TEXT,
+                created_at INTEGER NOT NULL
+            )
+        "#;
+
+        sqlx::query(create_api_audit_log)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create api_audit_log table: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_api_audit_log_client ON api_audit_log(client, created_at)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        info!("✅ Audit log schema initialized");
+        Ok(())
+    }
+
+    /// Changes to one wallet, newest first
+    pub async fn history(&self, entity_id: &str, limit: i64) -> Result<Vec<AuditLogEntry>, DatabaseError> {
+        sqlx::query_as::<_, AuditLogEntry>(
+            "SELECT * FROM intelligence_audit_log WHERE entity_id = ? ORDER BY id DESC LIMIT ?"
+        )
+        .bind(entity_id)
+        .bind(limit)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch audit history: {}", e)))
+    }
+
+    /// Latest changes across all wallets, newest first
+    pub async fn recent(&self, limit: i64) -> Result<Vec<AuditLogEntry>, DatabaseError> {
+        sqlx::query_as::<_, AuditLogEntry>("SELECT * FROM intelligence_audit_log ORDER BY id DESC LIMIT ?")
+            .bind(limit)
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch audit log: {}", e)))
+    }
+
+    /// Record a control API call
+    pub async fn record_api_call(
+        &self,
+        client: Option<&str>,
+        role: Option<&str>,
+        method: &str,
+        path: &str,
+        status: u16,
+        detail: Option<&str>,
+    ) -> Result<(), DatabaseError> {
+        sqlx::query(r#"
+            INSERT INTO api_audit_log (client, role, method, path, status, detail, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(client)
+        .bind(role)
+        .bind(method)
+        .bind(path)
+        .bind(status as i64)
+        .bind(detail)
+        .bind(Utc::now().timestamp())
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record API call: {}", e)))?;
+
+        debug!("📝 API {} {} by {} -> {}", method, path, client.unwrap_or("anonymous"), status);
+        Ok(())
+    }
+
+    /// Latest control API calls, newest first
+    pub async fn recent_api_calls(&self, limit: i64) -> Result<Vec<ApiAuditEntry>, DatabaseError> {
+        sqlx::query_as::<_, ApiAuditEntry>("SELECT * FROM api_audit_log ORDER BY id DESC LIMIT ?")
+            .bind(limit)
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch API audit log: {}", e)))
+    }
+}
+
+/// Add the `deleted_at` soft-delete column to tables created before it existed
+pub(crate) async fn ensure_deleted_at(db: &BadgerDatabase, table: &str) -> Result<(), DatabaseError> {
+    let exists = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM pragma_table_info(?) WHERE name = 'deleted_at'"
+    )
+    .bind(table)
+    .fetch_one(db.get_pool())
+    .await
+    .map_err(|e| DatabaseError::QueryError(format!("Failed to inspect {}: {}", table, e)))? > 0;
+
+    if !exists {
+        sqlx::query(&format!("ALTER TABLE {} ADD COLUMN deleted_at INTEGER", table))
+            .execute(db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to add deleted_at column to {}: {}", table, e)))?;
+    }
+    Ok(())
+}
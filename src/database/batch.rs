@@ -1,7 +1,8 @@
 use std::collections::VecDeque;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
 use tokio::sync::{broadcast, Mutex, RwLock};
 use tokio::time::interval;
 use tracing::{debug, info, warn, error, instrument};
@@ -10,11 +11,133 @@ use crate::core::{MarketEvent, TradingSignal};
 use super::BadgerDatabase;
 use super::DatabaseError;
 
+/// Bounds and targets for adaptive batching
+///
+/// Batches start small and frequent. They grow and flush less often while
+/// flushes keep filling up (queue depth) or take longer than the target
+/// latency, and shrink back once the queue runs dry.
+#[derive(Debug, Clone)]
+pub struct AdaptiveBatchConfig {
+    pub min_batch_size: usize,
+    pub max_batch_size: usize,
+    pub min_flush_interval: Duration,
+    pub max_flush_interval: Duration,
+    /// Flushes slower than this make batching coarser
+    pub target_flush_latency: Duration,
+}
+
+impl AdaptiveBatchConfig {
+    pub fn market_events() -> Self {
+        Self {
+            min_batch_size: 50,
+            max_batch_size: 5_000,
+            min_flush_interval: Duration::from_millis(250),
+            max_flush_interval: Duration::from_secs(5),
+            target_flush_latency: Duration::from_millis(100),
+        }
+    }
+
+    pub fn trading_signals() -> Self {
+        Self {
+            min_batch_size: 10,
+            max_batch_size: 1_000,
+            min_flush_interval: Duration::from_millis(100),
+            max_flush_interval: Duration::from_secs(3),
+            target_flush_latency: Duration::from_millis(50),
+        }
+    }
+}
+
+/// Upper bounds of the flush time histogram buckets (milliseconds); the last bucket is unbounded
+pub const FLUSH_BUCKETS_MS: [u64; 11] = [1, 2, 5, 10, 25, 50, 100, 250, 500, 1_000, 2_500];
+
+/// Flush timings and the batching currently in force
+#[derive(Debug, Default)]
+pub struct BatchMetrics {
+    batch_size: AtomicUsize,
+    flush_interval_ms: AtomicU64,
+    flushes: AtomicU64,
+    flushed_items: AtomicU64,
+    flush_time_total_us: AtomicU64,
+    /// Flush counts per `FLUSH_BUCKETS_MS` bucket, plus one for slower flushes
+    flush_time_buckets: [AtomicU64; FLUSH_BUCKETS_MS.len() + 1],
+}
+
+/// Snapshot of `BatchMetrics`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchStats {
+    pub batch_size: usize,
+    pub flush_interval_ms: u64,
+    pub pending: usize,
+    pub flushes: u64,
+    pub flushed_items: u64,
+    pub mean_flush_ms: f64,
+    /// (bucket upper bound in ms, None for the unbounded bucket; flushes in the bucket)
+    pub flush_time_histogram: Vec<(Option<u64>, u64)>,
+    pub flush_p50_ms: Option<u64>,
+    pub flush_p99_ms: Option<u64>,
+}
+
+impl BatchMetrics {
+    fn record_flush(&self, items: usize, elapsed: Duration) {
+        self.flushes.fetch_add(1, Ordering::Relaxed);
+        self.flushed_items.fetch_add(items as u64, Ordering::Relaxed);
+        self.flush_time_total_us.fetch_add(elapsed.as_micros() as u64, Ordering::Relaxed);
+        let elapsed_ms = elapsed.as_millis() as u64;
+        let bucket = FLUSH_BUCKETS_MS.iter()
+            .position(|bound| elapsed_ms <= *bound)
+            .unwrap_or(FLUSH_BUCKETS_MS.len());
+        self.flush_time_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Upper bound of the bucket holding the given share of flushes
+    fn percentile_ms(counts: &[u64], share: f64) -> Option<u64> {
+        let total: u64 = counts.iter().sum();
+        if total == 0 {
+            return None;
+        }
+        let rank = ((total as f64 * share).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (bucket, count) in counts.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return Some(FLUSH_BUCKETS_MS.get(bucket).copied().unwrap_or(u64::MAX));
+            }
+        }
+        None
+    }
+
+    pub fn snapshot(&self, pending: usize) -> BatchStats {
+        let counts: Vec<u64> = self.flush_time_buckets.iter().map(|count| count.load(Ordering::Relaxed)).collect();
+        let flushes = self.flushes.load(Ordering::Relaxed);
+        BatchStats {
+            batch_size: self.batch_size.load(Ordering::Relaxed),
+            flush_interval_ms: self.flush_interval_ms.load(Ordering::Relaxed),
+            pending,
+            flushes,
+            flushed_items: self.flushed_items.load(Ordering::Relaxed),
+            mean_flush_ms: if flushes > 0 {
+                self.flush_time_total_us.load(Ordering::Relaxed) as f64 / flushes as f64 / 1000.0
+            } else {
+                0.0
+            },
+            flush_time_histogram: counts.iter().enumerate()
+                .map(|(bucket, count)| (FLUSH_BUCKETS_MS.get(bucket).copied(), *count))
+                .collect(),
+            flush_p50_ms: Self::percentile_ms(&counts, 0.5),
+            flush_p99_ms: Self::percentile_ms(&counts, 0.99),
+        }
+    }
+}
+
 /// High-performance batch processor for database operations
+///
+/// Batch size and flush interval adapt between the configured bounds after
+/// every flush (see `AdaptiveBatchConfig`).
 pub struct BatchProcessor<T> {
     batch: Arc<Mutex<VecDeque<T>>>,
-    batch_size: usize,
-    batch_timeout: Duration,
+    config: AdaptiveBatchConfig,
+    metrics: Arc<BatchMetrics>,
     last_flush: Arc<RwLock<Instant>>,
     pending_count: Arc<AtomicUsize>,
     flush_trigger: broadcast::Sender<()>,
@@ -25,13 +148,16 @@ impl<T> BatchProcessor<T>
 where 
     T: Clone + Send + Sync + 'static,
 {
-    pub fn new(batch_size: usize, batch_timeout: Duration) -> Self {
+    pub fn new(config: AdaptiveBatchConfig) -> Self {
         let (flush_trigger, flush_receiver) = broadcast::channel(100);
+        let metrics = BatchMetrics::default();
+        metrics.batch_size.store(config.min_batch_size, Ordering::Relaxed);
+        metrics.flush_interval_ms.store(config.min_flush_interval.as_millis() as u64, Ordering::Relaxed);
         
         Self {
             batch: Arc::new(Mutex::new(VecDeque::new())),
-            batch_size,
-            batch_timeout,
+            config,
+            metrics: Arc::new(metrics),
             last_flush: Arc::new(RwLock::new(Instant::now())),
             pending_count: Arc::new(AtomicUsize::new(0)),
             flush_trigger,
@@ -39,12 +165,27 @@ where
         }
     }
 
+    /// Batch size currently in force
+    pub fn batch_size(&self) -> usize {
+        self.metrics.batch_size.load(Ordering::Relaxed)
+    }
+
+    /// Flush interval currently in force
+    pub fn flush_interval(&self) -> Duration {
+        Duration::from_millis(self.metrics.flush_interval_ms.load(Ordering::Relaxed))
+    }
+
+    /// Shared flush metrics, readable after the processor has moved into its task
+    pub fn metrics(&self) -> Arc<BatchMetrics> {
+        self.metrics.clone()
+    }
+
     /// Add event to batch with backpressure handling
     pub async fn add(&self, event: T) -> Result<(), DatabaseError> {
         let current_count = self.pending_count.load(Ordering::Relaxed);
         
         // Backpressure: reject if queue is too full
-        if current_count > self.batch_size * 10 {
+        if current_count > self.config.max_batch_size * 10 {
             return Err(DatabaseError::InitializationError(
                 "Batch queue overflow - backpressure activated".to_string()
             ));
@@ -57,7 +198,7 @@ where
         }
 
         // Trigger flush if batch size reached
-        if current_count >= self.batch_size {
+        if current_count >= self.batch_size() {
             let _ = self.flush_trigger.send(());
         }
 
@@ -74,11 +215,12 @@ where
         self.batch.lock().await.is_empty()
     }
 
-    /// Drain all events from batch for processing
+    /// Drain up to one batch of events for processing
     pub async fn drain(&self) -> Vec<T> {
         let mut batch = self.batch.lock().await;
-        let events: Vec<T> = batch.drain(..).collect();
-        self.pending_count.store(0, Ordering::Relaxed);
+        let take = batch.len().min(self.batch_size());
+        let events: Vec<T> = batch.drain(..take).collect();
+        self.pending_count.store(batch.len(), Ordering::Relaxed);
         
         // Update last flush time
         {
@@ -89,11 +231,50 @@ where
         events
     }
 
+    /// Record a flush and adapt batching to it
+    ///
+    /// A full batch, events left behind or a flush slower than the target
+    /// latency mean writes are falling behind: batches double and flushes
+    /// come half as often. A batch under a quarter full means the queue is
+    /// idle: both step back towards small, frequent flushes.
+    pub fn record_flush(&self, items: usize, elapsed: Duration) {
+        self.metrics.record_flush(items, elapsed);
+
+        let batch_size = self.batch_size();
+        let interval = self.flush_interval();
+        let backlog = self.pending_count.load(Ordering::Relaxed);
+        let (batch_size, interval) = if items >= batch_size || backlog > 0 || elapsed > self.config.target_flush_latency {
+            (
+                (batch_size * 2).min(self.config.max_batch_size),
+                (interval * 2).min(self.config.max_flush_interval),
+            )
+        } else if items < batch_size / 4 {
+            (
+                (batch_size / 2).max(self.config.min_batch_size),
+                (interval / 2).max(self.config.min_flush_interval),
+            )
+        } else {
+            (batch_size, interval)
+        };
+        self.metrics.batch_size.store(batch_size, Ordering::Relaxed);
+        self.metrics.flush_interval_ms.store(interval.as_millis() as u64, Ordering::Relaxed);
+
+        // Leftovers go out with the next flush rather than waiting for the timer
+        if backlog >= batch_size {
+            let _ = self.flush_trigger.send(());
+        }
+    }
+
     /// Check if batch should be flushed due to timeout
     pub async fn should_flush_timeout(&self) -> bool {
         let last_flush = *self.last_flush.read().await;
         let elapsed = last_flush.elapsed();
-        elapsed >= self.batch_timeout && !self.is_empty().await
+        elapsed >= self.flush_interval() && !self.is_empty().await
+    }
+
+    /// Current batching and flush timings
+    pub fn stats(&self) -> BatchStats {
+        self.metrics.snapshot(self.pending_count.load(Ordering::Relaxed))
     }
 
     /// Force flush trigger
@@ -115,13 +296,18 @@ impl EnhancedPersistenceService {
     pub fn new(db: Arc<BadgerDatabase>) -> Self {
         Self {
             db,
-            market_event_batcher: BatchProcessor::new(500, Duration::from_secs(5)),
-            trading_signal_batcher: BatchProcessor::new(100, Duration::from_secs(3)),
+            market_event_batcher: BatchProcessor::new(AdaptiveBatchConfig::market_events()),
+            trading_signal_batcher: BatchProcessor::new(AdaptiveBatchConfig::trading_signals()),
             events_processed: Arc::new(AtomicUsize::new(0)),
             signals_processed: Arc::new(AtomicUsize::new(0)),
         }
     }
 
+    /// Flush metrics of the market event and trading signal batches
+    pub fn batch_metrics(&self) -> (Arc<BatchMetrics>, Arc<BatchMetrics>) {
+        (self.market_event_batcher.metrics(), self.trading_signal_batcher.metrics())
+    }
+
     /// Add market event to batch
     pub async fn store_market_event(&self, event: MarketEvent) -> Result<(), DatabaseError> {
         self.market_event_batcher.add(event).await?;
@@ -146,6 +332,8 @@ impl EnhancedPersistenceService {
         let signal_batcher = self.trading_signal_batcher;
         let events_processed = self.events_processed.clone();
         let signals_processed = self.signals_processed.clone();
+        let market_metrics = market_batcher.metrics();
+        let signal_metrics = signal_batcher.metrics();
 
         // Market events batch processor
         let market_processor = {
@@ -155,7 +343,8 @@ impl EnhancedPersistenceService {
             
             tokio::spawn(async move {
                 let mut flush_receiver = batcher.flush_trigger.subscribe();
-                let mut timer = interval(Duration::from_millis(1000)); // Check every second
+                // Fine enough for the shortest adaptive flush interval
+                let mut timer = interval(batcher.config.min_flush_interval / 2);
                 
                 info!("📦 Market events batch processor started");
                 
@@ -189,7 +378,7 @@ impl EnhancedPersistenceService {
             
             tokio::spawn(async move {
                 let mut flush_receiver = batcher.flush_trigger.subscribe();
-                let mut timer = interval(Duration::from_millis(500)); // Check more frequently
+                let mut timer = interval(batcher.config.min_flush_interval / 2);
                 
                 info!("📦 Trading signals batch processor started");
                 
@@ -232,6 +421,11 @@ impl EnhancedPersistenceService {
                     info!("   📦 Market Events Processed: {}", events);
                     info!("   📶 Trading Signals Processed: {}", signals);
                     info!("   ⚡ Total Throughput: {} events", events + signals);
+                    for (name, metrics) in [("Market Events", &market_metrics), ("Trading Signals", &signal_metrics)] {
+                        let stats = metrics.snapshot(0);
+                        info!("   ⏱️ {} batches: size {}, every {}ms, flush mean {:.1}ms p50 {:?}ms p99 {:?}ms",
+                            name, stats.batch_size, stats.flush_interval_ms, stats.mean_flush_ms, stats.flush_p50_ms, stats.flush_p99_ms);
+                    }
                 }
             })
         };
@@ -262,7 +456,9 @@ impl EnhancedPersistenceService {
         if events.is_empty() {
             return Ok(());
         }
+        let started = Instant::now();
 
+        let _permit = db.write_permit().await;
         let batch_size = events.len();
         debug!("🔄 Flushing {} market events", batch_size);
 
@@ -293,6 +489,7 @@ impl EnhancedPersistenceService {
             .map_err(|e| DatabaseError::QueryError(format!("Failed to commit market events: {}", e)))?;
 
         counter.fetch_add(batch_size, Ordering::Relaxed);
+        batcher.record_flush(batch_size, started.elapsed());
         info!("✅ Batch inserted {} market events", batch_size);
         
         Ok(())
@@ -308,7 +505,9 @@ impl EnhancedPersistenceService {
         if signals.is_empty() {
             return Ok(());
         }
+        let started = Instant::now();
 
+        let _permit = db.write_permit().await;
         let batch_size = signals.len();
         debug!("🔄 Flushing {} trading signals", batch_size);
 
@@ -339,6 +538,7 @@ impl EnhancedPersistenceService {
             .map_err(|e| DatabaseError::QueryError(format!("Failed to commit trading signals: {}", e)))?;
 
         counter.fetch_add(batch_size, Ordering::Relaxed);
+        batcher.record_flush(batch_size, started.elapsed());
         info!("✅ Batch inserted {} trading signals", batch_size);
         
         Ok(())
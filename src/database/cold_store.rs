@@ -0,0 +1,430 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use arrow_array::builder::{BooleanBuilder, Float64Builder, Int64Builder, StringBuilder};
+use arrow_array::{Array, ArrayRef, BooleanArray, Float64Array, Int64Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema};
+use chrono::Utc;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{FromRow, Row};
+use tracing::{debug, info, instrument};
+
+use super::{BadgerDatabase, DatabaseError};
+
+/// Row ids deleted per statement when rows move to the cold store
+const DELETE_CHUNK: usize = 500;
+
+/// A table whose aged rows move to the cold store
+#[derive(Debug, Clone, Copy)]
+pub struct ColdTable {
+    pub name: &'static str,
+    /// Unix-time column rows are aged and range-queried by
+    pub time_column: &'static str,
+    /// Further condition a row must meet to move (e.g. no longer pending)
+    pub movable: &'static str,
+}
+
+/// Append-only analytics history that moves out of the operational database
+pub const COLD_TABLES: [ColdTable; 2] = [
+    ColdTable { name: "pnl_snapshots", time_column: "timestamp", movable: "1 = 1" },
+    ColdTable { name: "fill_checks", time_column: "executed_at", movable: "verdict != 'PENDING'" },
+];
+
+/// Configuration for the analytics cold store
+#[derive(Debug, Clone)]
+pub struct ColdStoreConfig {
+    /// Directory holding one subdirectory of Parquet files per table
+    pub path: PathBuf,
+    /// Rows older than this many days move out of SQLite
+    pub hot_days: i64,
+    /// Most rows of one table written to a single Parquet file
+    pub max_rows_per_file: i64,
+}
+
+impl Default for ColdStoreConfig {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from("data/cold"),
+            hot_days: 30,
+            max_rows_per_file: 100_000,
+        }
+    }
+}
+
+impl ColdStoreConfig {
+    /// Defaults with overrides from `BADGER_COLD_STORE_PATH` and `BADGER_COLD_AFTER_DAYS` when set
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Ok(path) = std::env::var("BADGER_COLD_STORE_PATH") {
+            config.path = PathBuf::from(path);
+        }
+        if let Some(days) = std::env::var("BADGER_COLD_AFTER_DAYS").ok().and_then(|v| v.parse().ok()).filter(|days| *days > 0) {
+            config.hot_days = days;
+        }
+        config
+    }
+}
+
+/// One Parquet file of rows moved out of a table
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ColdPartition {
+    pub id: i64,
+    pub table_name: String,
+    pub file_path: String,
+    pub min_time: i64,
+    pub max_time: i64,
+    pub rows: i64,
+    pub created_at: i64,
+}
+
+/// Column of a table as stored in Parquet
+#[derive(Debug, Clone)]
+struct ColdColumn {
+    name: String,
+    data_type: DataType,
+}
+
+/// Parquet files of analytics rows past the hot window
+///
+/// Rows of `COLD_TABLES` older than `hot_days` are written to Parquet under
+/// `path/<table>/` and deleted from SQLite in the same pass, so the
+/// operational database only holds the recent window. Every file is listed
+/// in `cold_partitions` with the time range it covers; readers that ask for
+/// a range reaching past the hot window get the overlapping cold rows too
+/// and merge them with their SQLite results.
+pub struct ColdStore {
+    db: Arc<BadgerDatabase>,
+    config: ColdStoreConfig,
+}
+
+impl ColdStore {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<ColdStoreConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+        }
+    }
+
+    pub fn config(&self) -> &ColdStoreConfig {
+        &self.config
+    }
+
+    /// Initialize cold store schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing cold store schema");
+
+        let create_cold_partitions = r#"
+            CREATE TABLE IF NOT EXISTS cold_partitions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                table_name TEXT NOT NULL,
+                file_path TEXT NOT NULL UNIQUE,
+                min_time INTEGER NOT NULL,
+                max_time INTEGER NOT NULL,
+                rows INTEGER NOT NULL,
+                created_at INTEGER NOT NULL
+            )
+        "#;
+
+        sqlx::query(create_cold_partitions)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create cold_partitions table: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_cold_partitions_range ON cold_partitions(table_name, max_time)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create cold_partitions index: {}", e)))?;
+
+        info!("✅ Cold store schema initialized");
+        Ok(())
+    }
+
+    /// Unix time before which rows belong in the cold store
+    pub fn cutoff(&self, now: i64) -> i64 {
+        now - self.config.hot_days * 86400
+    }
+
+    /// Move every table's rows past the hot window into Parquet
+    ///
+    /// # Returns
+    /// * `Result<Vec<ColdPartition>, DatabaseError>` - Files written in this pass
+    #[instrument(skip(self))]
+    pub async fn move_due(&self) -> Result<Vec<ColdPartition>, DatabaseError> {
+        let cutoff = self.cutoff(Utc::now().timestamp());
+        let mut written = Vec::new();
+        for table in COLD_TABLES {
+            while let Some(partition) = self.move_table(&table, cutoff).await? {
+                let full = partition.rows >= self.config.max_rows_per_file;
+                info!("❄️ Moved {} {} rows ({} - {}) to {}",
+                    partition.rows, table.name, partition.min_time, partition.max_time, partition.file_path);
+                written.push(partition);
+                if !full {
+                    break;
+                }
+            }
+        }
+        Ok(written)
+    }
+
+    /// Write one file of a table's aged rows and delete them from SQLite
+    async fn move_table(&self, table: &ColdTable, cutoff: i64) -> Result<Option<ColdPartition>, DatabaseError> {
+        let columns = self.columns(table.name).await?;
+        let rows = sqlx::query(&format!(
+            "SELECT * FROM {} WHERE {} < ? AND {} ORDER BY {}, id LIMIT ?",
+            table.name, table.time_column, table.movable, table.time_column
+        ))
+        .bind(cutoff)
+        .bind(self.config.max_rows_per_file)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch aged {} rows: {}", table.name, e)))?;
+        if rows.is_empty() {
+            return Ok(None);
+        }
+
+        let ids: Vec<i64> = rows.iter().map(|row| row.get::<i64, _>("id")).collect();
+        let min_time: i64 = rows[0].get(table.time_column);
+        let max_time: i64 = rows[rows.len() - 1].get(table.time_column);
+        let batch = record_batch(&columns, &rows)?;
+
+        let dir = self.config.path.join(table.name);
+        let file_path = dir.join(format!("{}-{}-{}.parquet", min_time, max_time, ids.iter().max().copied().unwrap_or_default()));
+        let target = file_path.clone();
+        tokio::task::spawn_blocking(move || write_parquet(&dir, &target, &batch))
+            .await
+            .map_err(|e| DatabaseError::SerializationError(format!("Parquet writer panicked: {}", e)))??;
+
+        // The file is complete on disk before any row leaves SQLite
+        let created_at = Utc::now().timestamp();
+        let file_path = file_path.to_string_lossy().to_string();
+        let mut tx = self.db.begin_transaction().await?;
+        let id = sqlx::query(r#"
+            INSERT INTO cold_partitions (table_name, file_path, min_time, max_time, rows, created_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(table.name)
+        .bind(&file_path)
+        .bind(min_time)
+        .bind(max_time)
+        .bind(ids.len() as i64)
+        .bind(created_at)
+        .execute(&mut tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record cold partition: {}", e)))?
+        .last_insert_rowid();
+
+        for chunk in ids.chunks(DELETE_CHUNK) {
+            let placeholders = vec!["?"; chunk.len()].join(", ");
+            let statement = format!("DELETE FROM {} WHERE id IN ({})", table.name, placeholders);
+            let mut query = sqlx::query(&statement);
+            for id in chunk {
+                query = query.bind(id);
+            }
+            query.execute(&mut tx)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to delete moved {} rows: {}", table.name, e)))?;
+        }
+        tx.commit()
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit cold move of {}: {}", table.name, e)))?;
+
+        Ok(Some(ColdPartition {
+            id,
+            table_name: table.name.to_string(),
+            file_path,
+            min_time,
+            max_time,
+            rows: ids.len() as i64,
+            created_at,
+        }))
+    }
+
+    /// Columns of a table with the Arrow type each is stored as
+    async fn columns(&self, table_name: &str) -> Result<Vec<ColdColumn>, DatabaseError> {
+        let info = sqlx::query(&format!("PRAGMA table_info({})", table_name))
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to read {} columns: {}", table_name, e)))?;
+        Ok(info.iter()
+            .map(|column| {
+                let declared = column.get::<String, _>("type").to_uppercase();
+                let data_type = if declared.contains("BOOL") {
+                    DataType::Boolean
+                } else if declared.contains("INT") {
+                    DataType::Int64
+                } else if declared.contains("REAL") || declared.contains("FLOA") || declared.contains("DOUB") {
+                    DataType::Float64
+                } else {
+                    DataType::Utf8
+                };
+                ColdColumn { name: column.get("name"), data_type }
+            })
+            .collect())
+    }
+
+    /// Partitions of a table overlapping `[since, until]`
+    pub async fn partitions(&self, table_name: &str, since: i64, until: i64) -> Result<Vec<ColdPartition>, DatabaseError> {
+        sqlx::query_as::<_, ColdPartition>(
+            "SELECT * FROM cold_partitions WHERE table_name = ? AND max_time >= ? AND min_time <= ? ORDER BY min_time, id"
+        )
+        .bind(table_name)
+        .bind(since)
+        .bind(until)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch cold partitions: {}", e)))
+    }
+
+    /// Cold rows of a table with their time in `[since, until]`, as JSON objects keyed by column
+    pub async fn read_rows(&self, table_name: &str, since: i64, until: i64) -> Result<Vec<Map<String, Value>>, DatabaseError> {
+        let table = COLD_TABLES.iter()
+            .find(|table| table.name == table_name)
+            .ok_or_else(|| DatabaseError::QueryError(format!("{} is not a cold store table", table_name)))?;
+        let files: Vec<PathBuf> = self.partitions(table_name, since, until).await?
+            .into_iter()
+            .map(|partition| PathBuf::from(partition.file_path))
+            .collect();
+        if files.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let time_column = table.time_column;
+        let rows = tokio::task::spawn_blocking(move || {
+            let mut rows = Vec::new();
+            for file in files {
+                rows.extend(read_parquet(&file)?.into_iter().filter(|row| {
+                    row.get(time_column).and_then(Value::as_i64).is_some_and(|at| at >= since && at <= until)
+                }));
+            }
+            Ok::<_, DatabaseError>(rows)
+        })
+        .await
+        .map_err(|e| DatabaseError::SerializationError(format!("Parquet reader panicked: {}", e)))??;
+        debug!("❄️ Read {} cold {} rows", rows.len(), table_name);
+        Ok(rows)
+    }
+
+    /// Cold rows of a table in `[since, until]`, deserialized like their SQLite rows
+    pub async fn read<T: DeserializeOwned>(&self, table_name: &str, since: i64, until: i64) -> Result<Vec<T>, DatabaseError> {
+        self.read_rows(table_name, since, until).await?
+            .into_iter()
+            .map(|row| serde_json::from_value(Value::Object(row))
+                .map_err(|e| DatabaseError::SerializationError(format!("Failed to decode cold {} row: {}", table_name, e))))
+            .collect()
+    }
+}
+
+/// Arrow batch of SQLite rows, one nullable column per table column
+fn record_batch(columns: &[ColdColumn], rows: &[SqliteRow]) -> Result<RecordBatch, DatabaseError> {
+    let column_error = |name: &str, e: sqlx::Error| DatabaseError::SerializationError(format!("Failed to read column {}: {}", name, e));
+    let mut arrays: Vec<ArrayRef> = Vec::with_capacity(columns.len());
+    for column in columns {
+        let name = column.name.as_str();
+        let array: ArrayRef = match column.data_type {
+            DataType::Boolean => {
+                let mut builder = BooleanBuilder::with_capacity(rows.len());
+                for row in rows {
+                    builder.append_option(row.try_get::<Option<bool>, _>(name).map_err(|e| column_error(name, e))?);
+                }
+                Arc::new(builder.finish())
+            }
+            DataType::Int64 => {
+                let mut builder = Int64Builder::with_capacity(rows.len());
+                for row in rows {
+                    builder.append_option(row.try_get::<Option<i64>, _>(name).map_err(|e| column_error(name, e))?);
+                }
+                Arc::new(builder.finish())
+            }
+            DataType::Float64 => {
+                let mut builder = Float64Builder::with_capacity(rows.len());
+                for row in rows {
+                    builder.append_option(row.try_get::<Option<f64>, _>(name).map_err(|e| column_error(name, e))?);
+                }
+                Arc::new(builder.finish())
+            }
+            _ => {
+                let mut builder = StringBuilder::new();
+                for row in rows {
+                    builder.append_option(row.try_get::<Option<String>, _>(name).map_err(|e| column_error(name, e))?);
+                }
+                Arc::new(builder.finish())
+            }
+        };
+        arrays.push(array);
+    }
+
+    let schema = Schema::new(columns.iter()
+        .map(|column| Field::new(column.name.as_str(), column.data_type.clone(), true))
+        .collect::<Vec<_>>());
+    RecordBatch::try_new(Arc::new(schema), arrays)
+        .map_err(|e| DatabaseError::SerializationError(format!("Failed to build record batch: {}", e)))
+}
+
+/// Write a batch to `path` through a temporary file renamed into place once complete
+fn write_parquet(dir: &Path, path: &Path, batch: &RecordBatch) -> Result<(), DatabaseError> {
+    let io_error = |e: std::io::Error| DatabaseError::SerializationError(format!("Failed to write {}: {}", path.display(), e));
+    let parquet_error = |e: parquet::errors::ParquetError| DatabaseError::SerializationError(format!("Failed to write {}: {}", path.display(), e));
+
+    std::fs::create_dir_all(dir).map_err(io_error)?;
+    let partial = path.with_extension("parquet.partial");
+    let file = File::create(&partial).map_err(io_error)?;
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(properties)).map_err(parquet_error)?;
+    writer.write(batch).map_err(parquet_error)?;
+    writer.close().map_err(parquet_error)?;
+    File::open(&partial).and_then(|file| file.sync_all()).map_err(io_error)?;
+    std::fs::rename(&partial, path).map_err(io_error)
+}
+
+/// Every row of a Parquet file as a JSON object keyed by column
+fn read_parquet(path: &Path) -> Result<Vec<Map<String, Value>>, DatabaseError> {
+    let parquet_error = |e: parquet::errors::ParquetError| DatabaseError::SerializationError(format!("Failed to read {}: {}", path.display(), e));
+    let file = File::open(path)
+        .map_err(|e| DatabaseError::SerializationError(format!("Failed to open {}: {}", path.display(), e)))?;
+    let reader = ParquetRecordBatchReaderBuilder::try_new(file).map_err(parquet_error)?
+        .build()
+        .map_err(parquet_error)?;
+
+    let mut rows = Vec::new();
+    for batch in reader {
+        let batch = batch.map_err(|e| DatabaseError::SerializationError(format!("Failed to read {}: {}", path.display(), e)))?;
+        let schema = batch.schema();
+        let offset = rows.len();
+        rows.extend((0..batch.num_rows()).map(|_| Map::new()));
+        for (field, array) in schema.fields().iter().zip(batch.columns()) {
+            for (i, row) in rows[offset..].iter_mut().enumerate() {
+                row.insert(field.name().clone(), cell(array.as_ref(), i));
+            }
+        }
+    }
+    Ok(rows)
+}
+
+/// One cell of an Arrow column as JSON
+fn cell(array: &dyn Array, i: usize) -> Value {
+    if array.is_null(i) {
+        return Value::Null;
+    }
+    let any = array.as_any();
+    if let Some(values) = any.downcast_ref::<Int64Array>() {
+        Value::from(values.value(i))
+    } else if let Some(values) = any.downcast_ref::<Float64Array>() {
+        Value::from(values.value(i))
+    } else if let Some(values) = any.downcast_ref::<BooleanArray>() {
+        Value::from(values.value(i))
+    } else if let Some(values) = any.downcast_ref::<StringArray>() {
+        Value::from(values.value(i))
+    } else {
+        Value::Null
+    }
+}
@@ -0,0 +1,331 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, instrument};
+
+use super::{BadgerDatabase, DatabaseError};
+
+/// Pause new position entries
+pub const COMMAND_PAUSE: &str = "PAUSE";
+/// Resume new position entries
+pub const COMMAND_RESUME: &str = "RESUME";
+/// Close the open position for the target token mint
+pub const COMMAND_CLOSE_POSITION: &str = "CLOSE_POSITION";
+/// Promote the target shadow strategy to live
+pub const COMMAND_PROMOTE_STRATEGY: &str = "PROMOTE_STRATEGY";
+/// Approve the target pending cold sweep (by id)
+pub const COMMAND_APPROVE_SWEEP: &str = "APPROVE_SWEEP";
+/// Reject the target pending cold sweep (by id)
+pub const COMMAND_REJECT_SWEEP: &str = "REJECT_SWEEP";
+/// Enforce the target proposed skip rule (by id)
+pub const COMMAND_ENABLE_SKIP_RULE: &str = "ENABLE_SKIP_RULE";
+/// Dismiss or switch off the target skip rule (by id)
+pub const COMMAND_DISMISS_SKIP_RULE: &str = "DISMISS_SKIP_RULE";
+/// Cancel the target working order (by id), or every working order of a token mint
+pub const COMMAND_CANCEL_ORDER: &str = "CANCEL_ORDER";
+/// Record every decision about the target token mint to a forensic trace file
+pub const COMMAND_TRACE_MINT: &str = "TRACE_MINT";
+/// Stop the forensic trace of the target token mint
+pub const COMMAND_UNTRACE_MINT: &str = "UNTRACE_MINT";
+/// Create or replace an insider's copy settings (target is the settings as JSON, with an optional audit `reason`)
+pub const COMMAND_SET_COPY_SETTINGS: &str = "SET_COPY_SETTINGS";
+/// Soft-delete the target insider wallet's copy settings (`<wallet>` or `<wallet>:<reason>`)
+pub const COMMAND_DELETE_COPY_SETTINGS: &str = "DELETE_COPY_SETTINGS";
+/// Record an external deposit or withdrawal (target is the flow as JSON)
+pub const COMMAND_RECORD_TREASURY_FLOW: &str = "RECORD_TREASURY_FLOW";
+/// Create or replace a wallet's label (target is the label as JSON, with an optional audit `reason`)
+pub const COMMAND_SET_WALLET_LABEL: &str = "SET_WALLET_LABEL";
+/// Soft-delete the target wallet's label (`<wallet>` or `<wallet>:<reason>`)
+pub const COMMAND_DELETE_WALLET_LABEL: &str = "DELETE_WALLET_LABEL";
+/// Book an executed profit lock conversion (target is the fill as JSON)
+pub const COMMAND_COMPLETE_PROFIT_LOCK: &str = "COMPLETE_PROFIT_LOCK";
+/// Abandon the target pending profit lock (by id)
+pub const COMMAND_FAIL_PROFIT_LOCK: &str = "FAIL_PROFIT_LOCK";
+/// Create or replace a watch group of insiders (target is the group as JSON)
+pub const COMMAND_SET_WATCH_GROUP: &str = "SET_WATCH_GROUP";
+/// Remove the target watch group (by name)
+pub const COMMAND_DELETE_WATCH_GROUP: &str = "DELETE_WATCH_GROUP";
+/// Create or replace a program's venue and kind (target is the label as JSON)
+pub const COMMAND_SET_PROGRAM_LABEL: &str = "SET_PROGRAM_LABEL";
+/// Remove the target program's runtime label
+pub const COMMAND_DELETE_PROGRAM_LABEL: &str = "DELETE_PROGRAM_LABEL";
+/// Stop trading and hand open positions and working orders to the target instance (by id)
+pub const COMMAND_HANDOFF: &str = "HANDOFF";
+/// Start an A/B test between two registered strategies (target is the test as JSON)
+pub const COMMAND_START_AB_TEST: &str = "START_AB_TEST";
+/// Stop the target A/B test (by name) and report its comparison
+pub const COMMAND_STOP_AB_TEST: &str = "STOP_AB_TEST";
+/// Replay the target dead-lettered execution (by id), or every dead letter with `all`
+pub const COMMAND_REQUEUE_DEAD_LETTER: &str = "REQUEUE_DEAD_LETTER";
+/// Drop the target dead-lettered execution (by id), or every dead letter with `all`
+pub const COMMAND_DISCARD_DEAD_LETTER: &str = "DISCARD_DEAD_LETTER";
+
+/// Every command accepted by `operator_commands`
+const OPERATOR_COMMANDS: &[&str] = &[
+    COMMAND_PAUSE, COMMAND_RESUME, COMMAND_CLOSE_POSITION, COMMAND_PROMOTE_STRATEGY, COMMAND_APPROVE_SWEEP,
+    COMMAND_REJECT_SWEEP, COMMAND_ENABLE_SKIP_RULE, COMMAND_DISMISS_SKIP_RULE, COMMAND_CANCEL_ORDER,
+    COMMAND_TRACE_MINT, COMMAND_UNTRACE_MINT, COMMAND_SET_COPY_SETTINGS, COMMAND_DELETE_COPY_SETTINGS,
+    COMMAND_RECORD_TREASURY_FLOW, COMMAND_SET_WALLET_LABEL, COMMAND_DELETE_WALLET_LABEL, COMMAND_HANDOFF,
+    COMMAND_COMPLETE_PROFIT_LOCK, COMMAND_FAIL_PROFIT_LOCK, COMMAND_SET_WATCH_GROUP, COMMAND_DELETE_WATCH_GROUP,
+    COMMAND_SET_PROGRAM_LABEL, COMMAND_DELETE_PROGRAM_LABEL, COMMAND_START_AB_TEST, COMMAND_STOP_AB_TEST,
+    COMMAND_REQUEUE_DEAD_LETTER, COMMAND_DISCARD_DEAD_LETTER,
+];
+
+/// Commands that move funds, swap the live strategy or hand off trading;
+/// API keys need the admin role to submit them
+pub const ADMIN_COMMANDS: &[&str] = &[
+    COMMAND_PROMOTE_STRATEGY, COMMAND_APPROVE_SWEEP, COMMAND_REJECT_SWEEP, COMMAND_RECORD_TREASURY_FLOW,
+    COMMAND_HANDOFF, COMMAND_COMPLETE_PROFIT_LOCK, COMMAND_FAIL_PROFIT_LOCK, COMMAND_START_AB_TEST,
+    COMMAND_STOP_AB_TEST,
+];
+
+/// Whether `operator_commands` accepts the command
+pub fn is_operator_command(command: &str) -> bool {
+    OPERATOR_COMMANDS.contains(&command)
+}
+
+/// Operator command queued by an external tool (e.g. badger-tui)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct OperatorCommand {
+    pub id: i64,
+    pub command: String, // One of `OPERATOR_COMMANDS`
+    pub target: Option<String>,
+    pub status: String, // "PENDING", "DONE", "FAILED"
+    pub result: Option<String>,
+    pub created_at: i64,
+    pub processed_at: Option<i64>,
+}
+
+/// System alert persisted for operator tooling
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AlertLogEntry {
+    pub id: i64,
+    pub alert_type: String,
+    pub service: String,
+    pub message: String,
+    pub created_at: i64,
+}
+
+/// Database-backed control channel between the orchestrator and operator tools
+pub struct ControlChannel {
+    db: Arc<BadgerDatabase>,
+}
+
+impl ControlChannel {
+    pub fn new(db: Arc<BadgerDatabase>) -> Self {
+        Self { db }
+    }
+
+    /// Initialize control channel schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing operator control schema");
+
+        let create_operator_commands = operator_commands_table("operator_commands");
+
+        let create_alert_log = r#"
+            CREATE TABLE IF NOT EXISTS alert_log (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                alert_type TEXT NOT NULL,
+                service TEXT NOT NULL,
+                message TEXT NOT NULL,
+                created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now'))
+            )
+        "#;
+
+        let create_indexes = vec![
+            "CREATE INDEX IF NOT EXISTS idx_operator_commands_status ON operator_commands(status)",
+            "CREATE INDEX IF NOT EXISTS idx_alert_log_created ON alert_log(created_at)",
+        ];
+
+        for table_sql in [create_operator_commands.as_str(), create_alert_log] {
+            sqlx::query(table_sql)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create control table: {}", e)))?;
+        }
+        self.migrate_operator_commands().await?;
+
+        for index_sql in create_indexes {
+            sqlx::query(index_sql)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+        }
+
+        info!("✅ Operator control schema initialized");
+        Ok(())
+    }
+
+    /// Rebuild `operator_commands` when it was created before its newest commands existed
+    ///
+    /// SQLite cannot alter a CHECK constraint, so the table is copied into one
+    /// created with the current command list.
+    async fn migrate_operator_commands(&self) -> Result<(), DatabaseError> {
+        let table_sql = sqlx::query_scalar::<_, String>(
+            "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = 'operator_commands'"
+        )
+        .fetch_one(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to read operator_commands schema: {}", e)))?;
+
+        if OPERATOR_COMMANDS.iter().all(|command| table_sql.contains(&format!("'{}'", command))) {
+            return Ok(());
+        }
+        info!("🔧 Migrating operator_commands to the current command list");
+
+        let mut tx = self.db.get_pool().begin().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to begin migration: {}", e)))?;
+        for statement in [
+            operator_commands_table("operator_commands_migrated"),
+            "INSERT INTO operator_commands_migrated SELECT * FROM operator_commands".to_string(),
+            "DROP TABLE operator_commands".to_string(),
+            "ALTER TABLE operator_commands_migrated RENAME TO operator_commands".to_string(),
+        ] {
+            sqlx::query(&statement)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to migrate operator_commands: {}", e)))?;
+        }
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit migration: {}", e)))?;
+        Ok(())
+    }
+
+    /// Queue an operator command for the orchestrator
+    pub async fn submit_command(&self, command: &str, target: Option<&str>) -> Result<i64, DatabaseError> {
+        let result = sqlx::query(
+            "INSERT INTO operator_commands (command, target, created_at) VALUES (?, ?, ?)"
+        )
+        .bind(command)
+        .bind(target)
+        .bind(Utc::now().timestamp())
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to submit operator command: {}", e)))?;
+
+        debug!("📨 Queued operator command {} {:?}", command, target);
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Fetch pending commands in submission order
+    pub async fn get_pending_commands(&self) -> Result<Vec<OperatorCommand>, DatabaseError> {
+        let commands = sqlx::query_as::<_, OperatorCommand>(
+            "SELECT * FROM operator_commands WHERE status = 'PENDING' ORDER BY id ASC"
+        )
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch pending commands: {}", e)))?;
+
+        Ok(commands)
+    }
+
+    /// Fetch one command with its status and result
+    pub async fn get_command(&self, id: i64) -> Result<Option<OperatorCommand>, DatabaseError> {
+        sqlx::query_as::<_, OperatorCommand>("SELECT * FROM operator_commands WHERE id = ?")
+            .bind(id)
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch operator command: {}", e)))
+    }
+
+    /// Mark a command as processed with its outcome
+    pub async fn complete_command(&self, id: i64, success: bool, result: &str) -> Result<(), DatabaseError> {
+        sqlx::query(
+            "UPDATE operator_commands SET status = ?, result = ?, processed_at = ? WHERE id = ?"
+        )
+        .bind(if success { "DONE" } else { "FAILED" })
+        .bind(result)
+        .bind(Utc::now().timestamp())
+        .bind(id)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to complete operator command: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Record a system alert for operator tooling
+    pub async fn record_alert(&self, alert_type: &str, service: &str, message: &str) -> Result<(), DatabaseError> {
+        sqlx::query(
+            "INSERT INTO alert_log (alert_type, service, message, created_at) VALUES (?, ?, ?, ?)"
+        )
+        .bind(alert_type)
+        .bind(service)
+        .bind(message)
+        .bind(Utc::now().timestamp())
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record alert: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Get most recent alerts
+    pub async fn get_recent_alerts(&self, limit: i64) -> Result<Vec<AlertLogEntry>, DatabaseError> {
+        let alerts = sqlx::query_as::<_, AlertLogEntry>(
+            "SELECT * FROM alert_log ORDER BY created_at DESC, id DESC LIMIT ?"
+        )
+        .bind(limit)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch recent alerts: {}", e)))?;
+
+        Ok(alerts)
+    }
+
+    /// Alerts recorded after `after_id`, oldest first
+    pub async fn get_alerts_after(&self, after_id: i64) -> Result<Vec<AlertLogEntry>, DatabaseError> {
+        let alerts = sqlx::query_as::<_, AlertLogEntry>(
+            "SELECT * FROM alert_log WHERE id > ? ORDER BY id ASC"
+        )
+        .bind(after_id)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch new alerts: {}", e)))?;
+
+        Ok(alerts)
+    }
+
+    /// Id of the newest alert, 0 when none are recorded
+    pub async fn latest_alert_id(&self) -> Result<i64, DatabaseError> {
+        sqlx::query_scalar::<_, i64>("SELECT COALESCE(MAX(id), 0) FROM alert_log")
+            .fetch_one(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to read latest alert id: {}", e)))
+    }
+
+    /// Whether the most recent processed PAUSE/RESUME command left trading paused
+    pub async fn is_paused(&self) -> Result<bool, DatabaseError> {
+        let last = sqlx::query_scalar::<_, String>(r#"
+            SELECT command FROM operator_commands
+            WHERE status = 'DONE' AND command IN ('PAUSE', 'RESUME')
+            ORDER BY id DESC LIMIT 1
+        "#)
+        .fetch_optional(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to read pause state: {}", e)))?;
+
+        Ok(last.as_deref() == Some(COMMAND_PAUSE))
+    }
+}
+
+/// `CREATE TABLE` statement for operator commands accepting every known command
+fn operator_commands_table(name: &str) -> String {
+    let commands = OPERATOR_COMMANDS.iter()
+        .map(|command| format!("'{}'", command))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(r#"
+        CREATE TABLE IF NOT EXISTS {name} (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            command TEXT NOT NULL CHECK (command IN ({commands})),
+            target TEXT,
+            status TEXT NOT NULL DEFAULT 'PENDING' CHECK (status IN ('PENDING', 'DONE', 'FAILED')),
+            result TEXT,
+            created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now')),
+            processed_at INTEGER
+        )
+    "#)
+}
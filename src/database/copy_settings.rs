@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tokio::sync::RwLock;
+use tracing::{debug, info, instrument};
+
+use super::{BadgerDatabase, DatabaseError};
+use super::audit_log::{
+    audit_value, ensure_deleted_at, record_audit, AuditContext, AUDIT_ACTION_CREATE, AUDIT_ACTION_DELETE,
+    AUDIT_ACTION_RESTORE, AUDIT_ACTION_UPDATE, AUDIT_ENTITY_COPY_SETTINGS,
+};
+
+/// Copy parameters for one insider wallet
+///
+/// Unset fields fall back to the strategy defaults.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct InsiderCopySettings {
+    pub wallet_address: String,
+    /// Disabled insiders are tracked but never copied
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Largest copy buy (SOL)
+    #[serde(default)]
+    pub max_size_sol: Option<f64>,
+    /// Wait before copying an entry (milliseconds)
+    #[serde(default)]
+    pub delay_ms: Option<i64>,
+    /// Insider tier ("A" - "D") used instead of the one derived from the copy-worthiness score
+    #[serde(default)]
+    pub tier_override: Option<String>,
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub updated_at: i64,
+    /// Set when the settings were deleted; deleted settings are kept for the audit trail
+    #[serde(default)]
+    pub deleted_at: Option<i64>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Per-insider copy settings, edited through the control channel
+///
+/// Reads are served from memory; the cache is dropped on every change and
+/// reloaded from the table on the next read. Deletes are soft and every
+/// change is written to the audit log.
+pub struct InsiderCopySettingsStore {
+    db: Arc<BadgerDatabase>,
+    cache: RwLock<Option<HashMap<String, InsiderCopySettings>>>,
+}
+
+impl InsiderCopySettingsStore {
+    pub fn new(db: Arc<BadgerDatabase>) -> Self {
+        Self {
+            db,
+            cache: RwLock::new(None),
+        }
+    }
+
+    /// Initialize insider copy settings schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing insider copy settings schema");
+
+        let create_insider_copy_settings = r#"
+            CREATE TABLE IF NOT EXISTS insider_copy_settings (
+                wallet_address TEXT PRIMARY KEY,
+                enabled BOOLEAN NOT NULL DEFAULT 1,
+                max_size_sol REAL,
+                delay_ms INTEGER,
+                tier_override TEXT CHECK (tier_override IN ('A', 'B', 'C', 'D')),
+                notes TEXT,
+                updated_at INTEGER NOT NULL,
+                deleted_at INTEGER
+            )
+        "#;
+
+        sqlx::query(create_insider_copy_settings)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create insider_copy_settings table: {}", e)))?;
+
+        ensure_deleted_at(&self.db, "insider_copy_settings").await?;
+
+        info!("✅ Insider copy settings schema initialized");
+        Ok(())
+    }
+
+    /// Create or replace the settings of an insider, restoring deleted ones
+    pub async fn upsert(&self, settings: &InsiderCopySettings, context: &AuditContext) -> Result<InsiderCopySettings, DatabaseError> {
+        let mut settings = settings.clone();
+        settings.updated_at = Utc::now().timestamp();
+        settings.deleted_at = None;
+
+        let mut tx = self.db.begin_transaction().await?;
+        let before = sqlx::query_as::<_, InsiderCopySettings>("SELECT * FROM insider_copy_settings WHERE wallet_address = ?")
+            .bind(&settings.wallet_address)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch insider copy settings: {}", e)))?;
+
+        sqlx::query(r#"
+            INSERT OR REPLACE INTO insider_copy_settings
+                (wallet_address, enabled, max_size_sol, delay_ms, tier_override, notes, updated_at, deleted_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, NULL)
+        "#)
+        .bind(&settings.wallet_address)
+        .bind(settings.enabled)
+        .bind(settings.max_size_sol)
+        .bind(settings.delay_ms)
+        .bind(&settings.tier_override)
+        .bind(&settings.notes)
+        .bind(settings.updated_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to save insider copy settings: {}", e)))?;
+
+        let action = match &before {
+            None => AUDIT_ACTION_CREATE,
+            Some(before) if before.deleted_at.is_some() => AUDIT_ACTION_RESTORE,
+            Some(_) => AUDIT_ACTION_UPDATE,
+        };
+        record_audit(&mut *tx, context, AUDIT_ENTITY_COPY_SETTINGS, &settings.wallet_address, action,
+            before.as_ref().and_then(audit_value), audit_value(&settings)).await?;
+
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit insider copy settings: {}", e)))?;
+
+        self.invalidate().await;
+        debug!("⚙️ Copy settings for {} saved", settings.wallet_address);
+        Ok(settings)
+    }
+
+    /// Soft-delete an insider's settings; returns false if there were none
+    pub async fn delete(&self, wallet_address: &str, context: &AuditContext) -> Result<bool, DatabaseError> {
+        let mut tx = self.db.begin_transaction().await?;
+        let Some(before) = sqlx::query_as::<_, InsiderCopySettings>(
+            "SELECT * FROM insider_copy_settings WHERE wallet_address = ? AND deleted_at IS NULL"
+        )
+        .bind(wallet_address)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch insider copy settings: {}", e)))? else {
+            return Ok(false);
+        };
+
+        let mut after = before.clone();
+        after.deleted_at = Some(Utc::now().timestamp());
+        sqlx::query("UPDATE insider_copy_settings SET deleted_at = ? WHERE wallet_address = ?")
+            .bind(after.deleted_at)
+            .bind(wallet_address)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to delete insider copy settings: {}", e)))?;
+
+        record_audit(&mut *tx, context, AUDIT_ENTITY_COPY_SETTINGS, wallet_address, AUDIT_ACTION_DELETE,
+            audit_value(&before), audit_value(&after)).await?;
+
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit insider copy settings: {}", e)))?;
+
+        self.invalidate().await;
+        Ok(true)
+    }
+
+    /// Settings of an insider, if any
+    pub async fn get(&self, wallet_address: &str) -> Result<Option<InsiderCopySettings>, DatabaseError> {
+        if let Some(cache) = self.cache.read().await.as_ref() {
+            return Ok(cache.get(wallet_address).cloned());
+        }
+
+        let settings = self.load().await?;
+        let found = settings.get(wallet_address).cloned();
+        *self.cache.write().await = Some(settings);
+        Ok(found)
+    }
+
+    /// All insiders with settings
+    pub async fn list(&self) -> Result<Vec<InsiderCopySettings>, DatabaseError> {
+        let mut settings: Vec<_> = self.load().await?.into_values().collect();
+        settings.sort_by(|a, b| a.wallet_address.cmp(&b.wallet_address));
+        Ok(settings)
+    }
+
+    /// Drop cached settings so the next read sees the table
+    pub async fn invalidate(&self) {
+        *self.cache.write().await = None;
+    }
+
+    async fn load(&self) -> Result<HashMap<String, InsiderCopySettings>, DatabaseError> {
+        let rows = sqlx::query_as::<_, InsiderCopySettings>("SELECT * FROM insider_copy_settings WHERE deleted_at IS NULL")
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch insider copy settings: {}", e)))?;
+
+        Ok(rows.into_iter().map(|settings| (settings.wallet_address.clone(), settings)).collect())
+    }
+}
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, instrument};
+
+use super::{BadgerDatabase, DatabaseError};
+
+/// SOL the sniper strategy spent inside the guarded launch slots
+pub const COUNTER_SNIPER_SPEND: &str = "sniper_spend_sol";
+
+/// One counter's running total for one UTC day
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct DailyCounter {
+    pub counter: String,
+    pub day: String, // YYYY-MM-DD (UTC)
+    pub value: f64,
+    /// Updates added to the counter
+    pub events: i64,
+    pub updated_at: i64,
+}
+
+/// Day-scoped counters written through on every update
+///
+/// Budgets that were only kept in memory would start from zero after a crash
+/// or restart mid-day; these are restored from the table on startup instead.
+/// Realized P&L and fee spend already have their own ledgers
+/// (`loss_counters`, `fee_spend`).
+pub struct DailyCounters {
+    db: Arc<BadgerDatabase>,
+}
+
+impl DailyCounters {
+    pub fn new(db: Arc<BadgerDatabase>) -> Self {
+        Self { db }
+    }
+
+    /// Initialize daily counter schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing daily counter schema");
+
+        let create_daily_counters = r#"
+            CREATE TABLE IF NOT EXISTS daily_counters (
+                counter TEXT NOT NULL,
+                day TEXT NOT NULL,
+                value REAL NOT NULL DEFAULT 0.0,
+                events INTEGER NOT NULL DEFAULT 0,
+                updated_at INTEGER NOT NULL,
+                PRIMARY KEY (counter, day)
+            )
+        "#;
+
+        sqlx::query(create_daily_counters)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create daily_counters table: {}", e)))?;
+
+        info!("✅ Daily counter schema initialized");
+        Ok(())
+    }
+
+    /// Add to today's value of a counter
+    pub async fn add(&self, counter: &str, amount: f64) -> Result<(), DatabaseError> {
+        let now = Utc::now();
+        sqlx::query(r#"
+            INSERT INTO daily_counters (counter, day, value, events, updated_at)
+            VALUES (?, ?, ?, 1, ?)
+            ON CONFLICT (counter, day) DO UPDATE SET
+                value = value + excluded.value,
+                events = events + 1,
+                updated_at = excluded.updated_at
+        "#)
+        .bind(counter)
+        .bind(now.format("%Y-%m-%d").to_string())
+        .bind(amount)
+        .bind(now.timestamp())
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to update daily counter: {}", e)))?;
+
+        debug!("🧮 Daily counter {} += {}", counter, amount);
+        Ok(())
+    }
+
+    /// Today's value of a counter, if it was updated today
+    pub async fn today(&self, counter: &str) -> Result<Option<DailyCounter>, DatabaseError> {
+        sqlx::query_as::<_, DailyCounter>("SELECT * FROM daily_counters WHERE counter = ? AND day = ?")
+            .bind(counter)
+            .bind(Utc::now().format("%Y-%m-%d").to_string())
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch daily counter: {}", e)))
+    }
+
+    /// Every counter updated on a day
+    pub async fn on_day(&self, day: &str) -> Result<Vec<DailyCounter>, DatabaseError> {
+        sqlx::query_as::<_, DailyCounter>("SELECT * FROM daily_counters WHERE day = ? ORDER BY counter")
+            .bind(day)
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch daily counters: {}", e)))
+    }
+}
@@ -0,0 +1,260 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{info, instrument, warn};
+
+use crate::core::TradingSignal;
+use super::{BadgerDatabase, DatabaseError};
+
+/// Configuration for execution retries and the dead-letter queue
+#[derive(Debug, Clone)]
+pub struct DeadLetterConfig {
+    /// Execution attempts of a signal before it is dead-lettered
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for each one after (milliseconds)
+    pub retry_delay_ms: u64,
+    /// How often requeued letters are picked up for replay (seconds)
+    pub poll_interval_secs: u64,
+}
+
+impl Default for DeadLetterConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            retry_delay_ms: 250,
+            poll_interval_secs: 5,
+        }
+    }
+}
+
+/// Routing context a signal was executed under, kept so it can be replayed as it was
+#[derive(Debug, Clone)]
+pub struct ExecutionContext {
+    /// Outbox id of the failed emission
+    pub signal_id: Option<String>,
+    pub signal_source: String,
+    pub source_service: String,
+    /// Snapshot hash of the strategy configuration it ran under
+    pub config_hash: Option<String>,
+}
+
+/// Signal whose execution failed on every attempt
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct DeadLetter {
+    pub id: i64,
+    pub signal_id: Option<String>,
+    pub signal_type: String,
+    pub token_mint: String,
+    pub payload: String, // JSON-encoded TradingSignal
+    pub signal_source: String,
+    pub source_service: String,
+    pub config_hash: Option<String>,
+    /// Error of the last attempt
+    pub error: String,
+    pub attempts: i64,
+    pub status: String, // "DEAD", "REQUEUED", "REPLAYED", "DISCARDED"
+    pub failed_at: i64,
+    pub requeued_at: Option<i64>,
+    pub requeued_by: Option<String>,
+    pub replayed_at: Option<i64>,
+}
+
+impl DeadLetter {
+    /// The signal as it was routed
+    pub fn signal(&self) -> Result<TradingSignal, DatabaseError> {
+        serde_json::from_str(&self.payload)
+            .map_err(|e| DatabaseError::SerializationError(format!("Failed to decode dead-lettered signal #{}: {}", self.id, e)))
+    }
+}
+
+/// Persistent queue of signals whose execution permanently failed
+///
+/// Many such failures are transient (RPC outages, a locked database), so the
+/// signal is kept with everything needed to route it again instead of being
+/// dropped with a log line. An operator requeues a letter through the control
+/// channel; the dead-letter service then replays it through the live path,
+/// where it passes the same gates as a fresh signal.
+pub struct DeadLetterQueue {
+    db: Arc<BadgerDatabase>,
+    config: DeadLetterConfig,
+}
+
+impl DeadLetterQueue {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<DeadLetterConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+        }
+    }
+
+    pub fn config(&self) -> &DeadLetterConfig {
+        &self.config
+    }
+
+    /// Initialize dead-letter schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing dead-letter schema");
+
+        let create_dead_letters = r#"
+            CREATE TABLE IF NOT EXISTS execution_dead_letters (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                signal_id TEXT,
+                signal_type TEXT NOT NULL,
+                token_mint TEXT NOT NULL,
+                payload TEXT NOT NULL,
+                signal_source TEXT NOT NULL,
+                source_service TEXT NOT NULL,
+                config_hash TEXT,
+                error TEXT NOT NULL,
+                attempts INTEGER NOT NULL,
+                status TEXT NOT NULL DEFAULT 'DEAD' CHECK (status IN ('DEAD', 'REQUEUED', 'REPLAYED', 'DISCARDED')),
+                failed_at INTEGER NOT NULL,
+                requeued_at INTEGER,
+                requeued_by TEXT,
+                replayed_at INTEGER
+            )
+        "#;
+
+        sqlx::query(create_dead_letters)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create execution_dead_letters table: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_execution_dead_letters_status ON execution_dead_letters(status, failed_at)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        info!("✅ Dead-letter schema initialized");
+        Ok(())
+    }
+
+    /// Dead-letter a signal after its last failed attempt
+    pub async fn push(
+        &self,
+        signal: &TradingSignal,
+        context: &ExecutionContext,
+        error: &str,
+        attempts: u32,
+    ) -> Result<DeadLetter, DatabaseError> {
+        let payload = serde_json::to_string(signal)
+            .map_err(|e| DatabaseError::SerializationError(format!("Failed to serialize signal: {}", e)))?;
+        let now = Utc::now().timestamp();
+
+        let id = sqlx::query(r#"
+            INSERT INTO execution_dead_letters
+                (signal_id, signal_type, token_mint, payload, signal_source, source_service,
+                 config_hash, error, attempts, status, failed_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, 'DEAD', ?)
+        "#)
+        .bind(&context.signal_id)
+        .bind(signal.get_signal_type())
+        .bind(signal.get_token_mint())
+        .bind(&payload)
+        .bind(&context.signal_source)
+        .bind(&context.source_service)
+        .bind(&context.config_hash)
+        .bind(error)
+        .bind(attempts as i64)
+        .bind(now)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to dead-letter signal: {}", e)))?
+        .last_insert_rowid();
+
+        warn!("☠️ {} signal for {} dead-lettered as #{} after {} attempts: {}",
+            signal.get_signal_type(), signal.get_token_mint(), id, attempts, error);
+
+        Ok(DeadLetter {
+            id,
+            signal_id: context.signal_id.clone(),
+            signal_type: signal.get_signal_type(),
+            token_mint: signal.get_token_mint(),
+            payload,
+            signal_source: context.signal_source.clone(),
+            source_service: context.source_service.clone(),
+            config_hash: context.config_hash.clone(),
+            error: error.to_string(),
+            attempts: attempts as i64,
+            status: "DEAD".to_string(),
+            failed_at: now,
+            requeued_at: None,
+            requeued_by: None,
+            replayed_at: None,
+        })
+    }
+
+    /// Letters waiting for an operator, newest first
+    pub async fn dead(&self, limit: i64) -> Result<Vec<DeadLetter>, DatabaseError> {
+        sqlx::query_as::<_, DeadLetter>(
+            "SELECT * FROM execution_dead_letters WHERE status = 'DEAD' ORDER BY failed_at DESC, id DESC LIMIT ?"
+        )
+        .bind(limit)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch dead letters: {}", e)))
+    }
+
+    /// Mark letters for replay: one by id, or every dead one with `None`
+    ///
+    /// # Returns
+    /// * Number of letters requeued
+    pub async fn requeue(&self, id: Option<i64>, requeued_by: &str) -> Result<u64, DatabaseError> {
+        self.resolve(id, "REQUEUED", requeued_by).await
+    }
+
+    /// Drop letters without replaying them: one by id, or every dead one with `None`
+    pub async fn discard(&self, id: Option<i64>, discarded_by: &str) -> Result<u64, DatabaseError> {
+        self.resolve(id, "DISCARDED", discarded_by).await
+    }
+
+    async fn resolve(&self, id: Option<i64>, status: &str, resolved_by: &str) -> Result<u64, DatabaseError> {
+        let updated = sqlx::query(r#"
+            UPDATE execution_dead_letters SET status = ?, requeued_at = ?, requeued_by = ?
+            WHERE status = 'DEAD' AND (? IS NULL OR id = ?)
+        "#)
+        .bind(status)
+        .bind(Utc::now().timestamp())
+        .bind(resolved_by)
+        .bind(id)
+        .bind(id)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to update dead letters: {}", e)))?
+        .rows_affected();
+
+        if updated == 0 {
+            if let Some(id) = id {
+                return Err(DatabaseError::QueryError(format!("Dead letter #{} is not waiting", id)));
+            }
+        }
+        Ok(updated)
+    }
+
+    /// Take every requeued letter for replay and mark it replayed
+    pub async fn take_requeued(&self) -> Result<Vec<DeadLetter>, DatabaseError> {
+        let mut tx = self.db.begin_transaction().await?;
+
+        let letters = sqlx::query_as::<_, DeadLetter>(
+            "SELECT * FROM execution_dead_letters WHERE status = 'REQUEUED' ORDER BY id"
+        )
+        .fetch_all(&mut tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch requeued letters: {}", e)))?;
+
+        if !letters.is_empty() {
+            sqlx::query("UPDATE execution_dead_letters SET status = 'REPLAYED', replayed_at = ? WHERE status = 'REQUEUED'")
+                .bind(Utc::now().timestamp())
+                .execute(&mut tx)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to mark letters replayed: {}", e)))?;
+        }
+
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit letter replay: {}", e)))?;
+
+        Ok(letters)
+    }
+}
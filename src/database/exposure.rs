@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, instrument};
+
+use super::{BadgerDatabase, DatabaseError, UNATTRIBUTED_STRATEGY};
+
+/// Configuration for the per-mint exposure cap
+#[derive(Debug, Clone)]
+pub struct ExposureConfig {
+    /// Combined exposure allowed in one token across all strategies (SOL)
+    pub max_mint_exposure_sol: f64,
+    /// Smallest entry worth placing once a signal is cut down to the headroom left (SOL)
+    pub min_entry_sol: f64,
+}
+
+impl Default for ExposureConfig {
+    fn default() -> Self {
+        Self {
+            max_mint_exposure_sol: 1.0,
+            min_entry_sol: 0.01,
+        }
+    }
+}
+
+impl ExposureConfig {
+    /// Defaults with the cap from `BADGER_MAX_MINT_EXPOSURE_SOL` when set
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(cap) = std::env::var("BADGER_MAX_MINT_EXPOSURE_SOL").ok().and_then(|v| v.parse::<f64>().ok()) {
+            config.max_mint_exposure_sol = cap.max(0.0);
+        }
+        config
+    }
+}
+
+/// Exposure of one strategy in a token
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct StrategyExposure {
+    pub strategy: String,
+    pub exposure_sol: f64,
+    pub positions: i64,
+}
+
+/// Combined exposure in one token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MintExposure {
+    pub token_mint: String,
+    /// Open positions across all strategies (SOL)
+    pub exposure_sol: f64,
+    /// Entries routed but not yet executed (SOL)
+    pub pending_sol: f64,
+    pub max_exposure_sol: f64,
+    pub strategies: Vec<StrategyExposure>,
+}
+
+/// Outcome of reserving room for an entry under the cap
+#[derive(Debug)]
+pub enum ExposureDecision {
+    /// The entry fits, possibly cut down to `reservation.amount_sol`
+    Allowed(ExposureReservation),
+    /// Too little room is left for a worthwhile entry
+    Refused {
+        /// Exposure already held or pending in the token (SOL)
+        current_sol: f64,
+        max_exposure_sol: f64,
+    },
+}
+
+/// Room held for an entry between routing and execution
+///
+/// Released when dropped, so an entry that is never executed frees its room;
+/// an executed one is moved to the ledger with `ExposureLedger::record_entry`.
+#[derive(Debug)]
+pub struct ExposureReservation {
+    pub token_mint: String,
+    pub strategy: String,
+    pub amount_sol: f64,
+    pending: Arc<Mutex<HashMap<String, f64>>>,
+}
+
+impl Drop for ExposureReservation {
+    fn drop(&mut self) {
+        let mut pending = self.pending.lock().unwrap();
+        if let Some(amount) = pending.get_mut(&self.token_mint) {
+            *amount -= self.amount_sol;
+            if *amount <= f64::EPSILON {
+                pending.remove(&self.token_mint);
+            }
+        }
+    }
+}
+
+/// Exposure per token across every strategy that trades it
+///
+/// Strategies size their entries independently, so when the insider copier,
+/// momentum and the sniper all fire on the same hot token the combined position
+/// can reach several times what any one of them intends. Every buy reserves
+/// room under one per-mint cap before it executes; whichever signal arrives
+/// second is cut down to the room left, or skipped when too little is left.
+/// Executed entries are kept per position and shrink as the position is sold.
+pub struct ExposureLedger {
+    db: Arc<BadgerDatabase>,
+    config: ExposureConfig,
+    /// Reserved but unexecuted entries, SOL by token
+    pending: Arc<Mutex<HashMap<String, f64>>>,
+    /// Serializes reservations so two signals cannot both take the last of the room
+    reserve_lock: tokio::sync::Mutex<()>,
+}
+
+impl ExposureLedger {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<ExposureConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            reserve_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    pub fn config(&self) -> &ExposureConfig {
+        &self.config
+    }
+
+    /// Initialize exposure schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing exposure schema");
+
+        let create_exposure = r#"
+            CREATE TABLE IF NOT EXISTS mint_exposure (
+                position_id INTEGER PRIMARY KEY,
+                token_mint TEXT NOT NULL,
+                strategy TEXT NOT NULL,
+                exposure_sol REAL NOT NULL,
+                opened_at INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL
+            )
+        "#;
+
+        sqlx::query(create_exposure)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create mint_exposure table: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_mint_exposure_token ON mint_exposure(token_mint)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        info!("✅ Exposure schema initialized");
+        Ok(())
+    }
+
+    /// Bring the ledger in line with the open positions
+    ///
+    /// Drops entries of positions closed while the ledger was not listening
+    /// and adds open positions it never saw, at their entry cost.
+    ///
+    /// # Returns
+    /// * `(removed, added)` entries
+    pub async fn reconcile(&self) -> Result<(u64, u64), DatabaseError> {
+        let now = Utc::now().timestamp();
+        let mut tx = self.db.begin_transaction().await?;
+
+        let removed = sqlx::query(
+            "DELETE FROM mint_exposure WHERE position_id NOT IN (SELECT id FROM positions WHERE status IN ('OPEN', 'PARTIAL'))"
+        )
+        .execute(&mut tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to drop closed exposure: {}", e)))?
+        .rows_affected();
+
+        let added = sqlx::query(r#"
+            INSERT OR IGNORE INTO mint_exposure (position_id, token_mint, strategy, exposure_sol, opened_at, updated_at)
+            SELECT id, token_mint, ?, entry_price * quantity, entry_timestamp, ?
+            FROM positions WHERE status IN ('OPEN', 'PARTIAL')
+        "#)
+        .bind(UNATTRIBUTED_STRATEGY)
+        .bind(now)
+        .execute(&mut tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to add open positions to exposure: {}", e)))?
+        .rows_affected();
+
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit exposure reconcile: {}", e)))?;
+
+        Ok((removed, added))
+    }
+
+    /// Reserve room under the cap for a buy of `requested_sol`
+    pub async fn reserve(&self, token_mint: &str, strategy: &str, requested_sol: f64) -> Result<ExposureDecision, DatabaseError> {
+        let _guard = self.reserve_lock.lock().await;
+
+        // Pending is read before the ledger: an entry recorded in between counts twice, never zero times
+        let pending_sol = self.pending_sol(token_mint);
+        let current_sol = self.held_sol(token_mint).await? + pending_sol;
+        let room = self.config.max_mint_exposure_sol - current_sol;
+        let amount_sol = requested_sol.min(room);
+
+        if amount_sol < self.config.min_entry_sol.min(requested_sol) || amount_sol <= 0.0 {
+            return Ok(ExposureDecision::Refused {
+                current_sol,
+                max_exposure_sol: self.config.max_mint_exposure_sol,
+            });
+        }
+
+        *self.pending.lock().unwrap().entry(token_mint.to_string()).or_insert(0.0) += amount_sol;
+        Ok(ExposureDecision::Allowed(ExposureReservation {
+            token_mint: token_mint.to_string(),
+            strategy: strategy.to_string(),
+            amount_sol,
+            pending: self.pending.clone(),
+        }))
+    }
+
+    /// Move an executed entry's reservation onto the ledger
+    pub async fn record_entry(&self, position_id: i64, reservation: ExposureReservation) -> Result<(), DatabaseError> {
+        let now = Utc::now().timestamp();
+        sqlx::query(r#"
+            INSERT OR REPLACE INTO mint_exposure (position_id, token_mint, strategy, exposure_sol, opened_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(position_id)
+        .bind(&reservation.token_mint)
+        .bind(&reservation.strategy)
+        .bind(reservation.amount_sol)
+        .bind(now)
+        .bind(now)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record exposure: {}", e)))?;
+
+        debug!("📐 Position #{} adds {:.4} SOL of {} exposure to {}",
+            position_id, reservation.amount_sol, reservation.strategy, reservation.token_mint);
+        Ok(())
+    }
+
+    /// Shrink a position's exposure after part of it was sold
+    pub async fn record_reduced(&self, position_id: i64, closed_quantity: f64, remaining_quantity: f64) -> Result<(), DatabaseError> {
+        let total = closed_quantity + remaining_quantity;
+        if total <= 0.0 {
+            return self.record_closed(position_id).await;
+        }
+        sqlx::query("UPDATE mint_exposure SET exposure_sol = exposure_sol * ?, updated_at = ? WHERE position_id = ?")
+            .bind(remaining_quantity / total)
+            .bind(Utc::now().timestamp())
+            .bind(position_id)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to reduce exposure: {}", e)))?;
+        Ok(())
+    }
+
+    /// Drop a closed position's exposure
+    pub async fn record_closed(&self, position_id: i64) -> Result<(), DatabaseError> {
+        sqlx::query("DELETE FROM mint_exposure WHERE position_id = ?")
+            .bind(position_id)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to drop exposure: {}", e)))?;
+        Ok(())
+    }
+
+    /// Combined exposure in one token
+    pub async fn exposure(&self, token_mint: &str) -> Result<MintExposure, DatabaseError> {
+        let strategies = sqlx::query_as::<_, StrategyExposure>(r#"
+            SELECT strategy, SUM(exposure_sol) AS exposure_sol, COUNT(*) AS positions
+            FROM mint_exposure WHERE token_mint = ?
+            GROUP BY strategy ORDER BY exposure_sol DESC
+        "#)
+        .bind(token_mint)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch exposure: {}", e)))?;
+
+        Ok(self.mint_exposure(token_mint.to_string(), strategies))
+    }
+
+    /// Combined exposure of every token held or pending, largest first
+    pub async fn all_exposure(&self) -> Result<Vec<MintExposure>, DatabaseError> {
+        let rows = sqlx::query_as::<_, (String, String, f64, i64)>(r#"
+            SELECT token_mint, strategy, SUM(exposure_sol), COUNT(*)
+            FROM mint_exposure GROUP BY token_mint, strategy
+        "#)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch exposure: {}", e)))?;
+
+        let mut by_mint: HashMap<String, Vec<StrategyExposure>> = HashMap::new();
+        for (token_mint, strategy, exposure_sol, positions) in rows {
+            by_mint.entry(token_mint).or_default().push(StrategyExposure { strategy, exposure_sol, positions });
+        }
+        for token_mint in self.pending.lock().unwrap().keys() {
+            by_mint.entry(token_mint.clone()).or_default();
+        }
+
+        let mut exposures: Vec<MintExposure> = by_mint.into_iter()
+            .map(|(token_mint, mut strategies)| {
+                strategies.sort_by(|a, b| b.exposure_sol.total_cmp(&a.exposure_sol));
+                self.mint_exposure(token_mint, strategies)
+            })
+            .collect();
+        exposures.sort_by(|a, b| (b.exposure_sol + b.pending_sol).total_cmp(&(a.exposure_sol + a.pending_sol)));
+        Ok(exposures)
+    }
+
+    fn mint_exposure(&self, token_mint: String, strategies: Vec<StrategyExposure>) -> MintExposure {
+        MintExposure {
+            pending_sol: self.pending_sol(&token_mint),
+            exposure_sol: strategies.iter().map(|s| s.exposure_sol).sum(),
+            max_exposure_sol: self.config.max_mint_exposure_sol,
+            token_mint,
+            strategies,
+        }
+    }
+
+    fn pending_sol(&self, token_mint: &str) -> f64 {
+        self.pending.lock().unwrap().get(token_mint).copied().unwrap_or(0.0)
+    }
+
+    async fn held_sol(&self, token_mint: &str) -> Result<f64, DatabaseError> {
+        sqlx::query_scalar::<_, f64>("SELECT COALESCE(SUM(exposure_sol), 0.0) FROM mint_exposure WHERE token_mint = ?")
+            .bind(token_mint)
+            .fetch_one(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to sum exposure: {}", e)))
+    }
+}
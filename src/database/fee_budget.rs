@@ -0,0 +1,223 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, instrument};
+
+use super::{BadgerDatabase, DatabaseError};
+
+/// Configuration for the daily fee budget
+#[derive(Debug, Clone)]
+pub struct FeeBudgetConfig {
+    /// Network fees, priority fees and Jito tips allowed per UTC day (SOL)
+    pub daily_budget_sol: f64,
+    /// Share of the budget after which non-urgent transactions are held (0.0 - 1.0)
+    pub throttle_at: f64,
+    /// How often our wallets' transactions are scanned for fees (seconds)
+    pub scan_interval_secs: u64,
+}
+
+impl Default for FeeBudgetConfig {
+    fn default() -> Self {
+        Self {
+            daily_budget_sol: 0.5,
+            throttle_at: 0.8,
+            scan_interval_secs: 60,
+        }
+    }
+}
+
+impl FeeBudgetConfig {
+    /// Defaults with the budget from `BADGER_DAILY_FEE_BUDGET_SOL` when set
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(budget) = std::env::var("BADGER_DAILY_FEE_BUDGET_SOL").ok().and_then(|v| v.parse().ok()) {
+            config.daily_budget_sol = budget;
+        }
+        config
+    }
+}
+
+/// Fees paid by one of our transactions
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct FeeSpendEntry {
+    pub tx_signature: String,
+    pub wallet_address: String,
+    pub spend_date: String, // YYYY-MM-DD (UTC)
+    /// Base signature fees (SOL)
+    pub network_sol: f64,
+    /// Compute-unit price paid above the base fee (SOL)
+    pub priority_sol: f64,
+    pub jito_tip_sol: f64,
+    pub slot: i64,
+    pub recorded_at: i64,
+}
+
+/// Fees of one transaction to record
+#[derive(Debug, Clone)]
+pub struct NewFeeSpend {
+    pub tx_signature: String,
+    pub wallet_address: String,
+    pub network_sol: f64,
+    pub priority_sol: f64,
+    pub jito_tip_sol: f64,
+    pub slot: u64,
+    /// When the transaction landed; spend is booked to that UTC day
+    pub block_time: Option<i64>,
+}
+
+/// Fee spend for one day, by kind
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DailyFeeSpend {
+    pub spend_date: String,
+    pub transactions: i64,
+    pub network_sol: f64,
+    pub priority_sol: f64,
+    pub jito_tip_sol: f64,
+    pub total_sol: f64,
+    pub budget_sol: f64,
+}
+
+impl DailyFeeSpend {
+    /// Share of the daily budget spent
+    pub fn used(&self) -> f64 {
+        if self.budget_sol > 0.0 { self.total_sol / self.budget_sol } else { 0.0 }
+    }
+}
+
+/// Daily spend on network fees, priority fees and Jito tips against a budget
+///
+/// Our own transactions are recorded once each (keyed by signature). When the
+/// day's spend nears the budget, non-urgent transactions (rebalances, cold
+/// sweeps, dust cleanup) are held until the next UTC day; trades are not.
+pub struct FeeBudget {
+    db: Arc<BadgerDatabase>,
+    config: FeeBudgetConfig,
+}
+
+impl FeeBudget {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<FeeBudgetConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+        }
+    }
+
+    pub fn config(&self) -> &FeeBudgetConfig {
+        &self.config
+    }
+
+    /// Initialize fee spend schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing fee budget schema");
+
+        let create_fee_spend = r#"
+            CREATE TABLE IF NOT EXISTS fee_spend (
+                tx_signature TEXT PRIMARY KEY,
+                wallet_address TEXT NOT NULL,
+                spend_date TEXT NOT NULL,
+                network_sol REAL NOT NULL,
+                priority_sol REAL NOT NULL,
+                jito_tip_sol REAL NOT NULL,
+                slot INTEGER NOT NULL,
+                recorded_at INTEGER NOT NULL
+            )
+        "#;
+
+        sqlx::query(create_fee_spend)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create fee_spend table: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_fee_spend_date ON fee_spend(spend_date)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_fee_spend_wallet ON fee_spend(wallet_address, slot)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        info!("✅ Fee budget schema initialized");
+        Ok(())
+    }
+
+    /// Record the fees of one transaction; returns false if it was already recorded
+    pub async fn record(&self, spend: &NewFeeSpend) -> Result<bool, DatabaseError> {
+        let now = Utc::now().timestamp();
+        let spend_date = chrono::DateTime::from_timestamp(spend.block_time.unwrap_or(now), 0)
+            .unwrap_or_else(Utc::now)
+            .format("%Y-%m-%d")
+            .to_string();
+
+        let inserted = sqlx::query(r#"
+            INSERT OR IGNORE INTO fee_spend
+                (tx_signature, wallet_address, spend_date, network_sol, priority_sol, jito_tip_sol, slot, recorded_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(&spend.tx_signature)
+        .bind(&spend.wallet_address)
+        .bind(&spend_date)
+        .bind(spend.network_sol)
+        .bind(spend.priority_sol)
+        .bind(spend.jito_tip_sol)
+        .bind(spend.slot as i64)
+        .bind(now)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record fee spend: {}", e)))?
+        .rows_affected() > 0;
+
+        if inserted {
+            debug!("⛽ {} paid {:.6} SOL fees + {:.6} SOL tip",
+                spend.tx_signature, spend.network_sol + spend.priority_sol, spend.jito_tip_sol);
+        }
+        Ok(inserted)
+    }
+
+    /// Newest recorded transaction of a wallet, where the next scan resumes
+    pub async fn last_signature(&self, wallet_address: &str) -> Result<Option<String>, DatabaseError> {
+        sqlx::query_scalar::<_, String>(
+            "SELECT tx_signature FROM fee_spend WHERE wallet_address = ? ORDER BY slot DESC LIMIT 1"
+        )
+        .bind(wallet_address)
+        .fetch_optional(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch last fee signature: {}", e)))
+    }
+
+    /// Spend for a UTC day (YYYY-MM-DD)
+    pub async fn spend_for(&self, spend_date: &str) -> Result<DailyFeeSpend, DatabaseError> {
+        let (transactions, network_sol, priority_sol, jito_tip_sol) = sqlx::query_as::<_, (i64, f64, f64, f64)>(r#"
+            SELECT COUNT(*), COALESCE(SUM(network_sol), 0.0), COALESCE(SUM(priority_sol), 0.0), COALESCE(SUM(jito_tip_sol), 0.0)
+            FROM fee_spend
+            WHERE spend_date = ?
+        "#)
+        .bind(spend_date)
+        .fetch_one(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to sum fee spend: {}", e)))?;
+
+        Ok(DailyFeeSpend {
+            spend_date: spend_date.to_string(),
+            transactions,
+            network_sol,
+            priority_sol,
+            jito_tip_sol,
+            total_sol: network_sol + priority_sol + jito_tip_sol,
+            budget_sol: self.config.daily_budget_sol,
+        })
+    }
+
+    /// Spend for the current UTC day
+    pub async fn spend_today(&self) -> Result<DailyFeeSpend, DatabaseError> {
+        self.spend_for(&Utc::now().format("%Y-%m-%d").to_string()).await
+    }
+
+    /// Whether non-urgent transactions should be held for the rest of the day
+    pub async fn throttled(&self) -> Result<bool, DatabaseError> {
+        Ok(self.spend_today().await?.used() >= self.config.throttle_at)
+    }
+}
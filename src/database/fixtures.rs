@@ -0,0 +1,362 @@
+use std::collections::HashMap;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use crate::core::MarketEvent;
+use super::{BadgerDatabase, DatabaseError};
+
+/// Database used in `--fixtures` mode, kept apart from live data
+pub const FIXTURE_DATABASE_URL: &str = "sqlite:data/badger-fixtures.db";
+
+/// Embedded seed data (insider profiles, wallet candidates)
+const FIXTURE_SEED_SQL: &str = include_str!("../../fixtures/seed.sql");
+
+/// Embedded market events replayed through the signal pipeline
+const FIXTURE_MARKET_EVENTS: &str = include_str!("../../fixtures/market_events.json");
+
+/// Directory replay manifests are written to
+pub const REPLAY_MANIFEST_DIR: &str = "data/replays";
+
+/// Seed the database with fixture data
+///
+/// Must run after all analytics schemas are initialized.
+pub async fn seed_fixtures(db: &BadgerDatabase) -> Result<usize, DatabaseError> {
+    // Strip comment lines so semicolons in comments don't split statements
+    let sql: String = FIXTURE_SEED_SQL
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("--"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut statements = 0;
+    for statement in sql.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+        sqlx::query(statement)
+            .execute(db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::MigrationError(format!("Failed to apply fixture seed: {}", e)))?;
+        statements += 1;
+    }
+
+    info!("🧪 Applied {} fixture seed statements", statements);
+    Ok(statements)
+}
+
+/// Load the embedded fixture market events
+pub fn fixture_market_events() -> Result<Vec<MarketEvent>, DatabaseError> {
+    serde_json::from_str(FIXTURE_MARKET_EVENTS)
+        .map_err(|e| DatabaseError::SerializationError(format!("Invalid fixture market events: {}", e)))
+}
+
+/// Market events for one replay run and a digest of the raw input
+#[derive(Debug, Clone)]
+pub struct ReplayInput {
+    /// `embedded` or the path the events were read from
+    pub source: String,
+    pub events: Vec<MarketEvent>,
+    pub digest: String,
+}
+
+impl ReplayInput {
+    /// Load replay events from a JSON file, or the embedded fixtures when `path` is `None`
+    pub fn load(path: Option<&str>) -> Result<Self, DatabaseError> {
+        let (source, raw) = match path {
+            Some(path) => {
+                let raw = std::fs::read_to_string(path)
+                    .map_err(|e| DatabaseError::SerializationError(format!("Failed to read replay events {}: {}", path, e)))?;
+                (path.to_string(), raw)
+            }
+            None => ("embedded".to_string(), FIXTURE_MARKET_EVENTS.to_string()),
+        };
+
+        let events = serde_json::from_str(&raw)
+            .map_err(|e| DatabaseError::SerializationError(format!("Invalid replay events in {}: {}", source, e)))?;
+
+        Ok(Self {
+            digest: solana_sdk::hash::hash(raw.as_bytes()).to_string(),
+            source,
+            events,
+        })
+    }
+
+    /// Load the market events recorded in a database, in chain order
+    pub async fn from_database(db: &BadgerDatabase) -> Result<Self, DatabaseError> {
+        let rows: Vec<String> = sqlx::query_scalar("SELECT data FROM market_events ORDER BY slot, id")
+            .fetch_all(db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch recorded market events: {}", e)))?;
+
+        // Event types added after a row was recorded may not parse; skip those rows
+        let events = rows.iter()
+            .filter_map(|data| serde_json::from_str(data).ok())
+            .collect();
+
+        Ok(Self {
+            digest: solana_sdk::hash::hash(rows.join("\n").as_bytes()).to_string(),
+            source: "market_events".to_string(),
+            events,
+        })
+    }
+}
+
+/// Position fields that make up a replay result; wall-clock times and ids are left out
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+struct ReplayOutcome {
+    token_mint: String,
+    position_type: String,
+    status: String,
+    entry_price: f64,
+    exit_price: Option<f64>,
+    quantity: f64,
+    pnl: Option<f64>,
+    fees: f64,
+    insider_wallet: Option<String>,
+}
+
+/// Everything needed to reproduce a replay result bit-for-bit
+///
+/// Written after every fixtures run; `--reproduce <manifest>` replays the same
+/// input with the same seed and frozen config and compares result digests.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayManifest {
+    pub seed: u64,
+    pub input_source: String,
+    pub input_events: usize,
+    /// First and last replayed event ids
+    pub input_range: Option<(String, String)>,
+    pub input_digest: String,
+    pub config_hash: String,
+    /// Frozen strategy config the replay ran with
+    pub config: serde_json::Value,
+    pub code_version: String,
+    pub git_commit: Option<String>,
+    pub positions: usize,
+    pub open_positions: usize,
+    pub total_pnl: f64,
+    pub result_digest: String,
+    pub completed_at: i64,
+    /// Fault profile the replay ran under (`--chaos`)
+    #[serde(default)]
+    pub chaos_profile: Option<String>,
+    /// Safety properties the replay broke; empty when it degraded safely
+    #[serde(default)]
+    pub invariant_violations: Vec<InvariantViolation>,
+}
+
+impl ReplayManifest {
+    /// Build the manifest for a finished replay from the positions it produced
+    ///
+    /// # Arguments
+    /// * `db` - Database the replay wrote its positions to
+    /// * `seed` - RNG seed the replay ran with
+    /// * `input` - Replayed events
+    /// * `config` - Strategy config the replay ran with
+    pub async fn capture<T: Serialize>(
+        db: &BadgerDatabase,
+        seed: u64,
+        input: &ReplayInput,
+        config: &T,
+    ) -> Result<Self, DatabaseError> {
+        let (config_hash, config_json) = super::ConfigSnapshotStore::config_hash(config)?;
+        let config = serde_json::from_str(&config_json)
+            .map_err(|e| DatabaseError::SerializationError(format!("Failed to freeze config: {}", e)))?;
+
+        let outcomes = sqlx::query_as::<_, ReplayOutcome>(r#"
+            SELECT token_mint, position_type, status, entry_price, exit_price, quantity, pnl, fees, insider_wallet
+            FROM positions ORDER BY id
+        "#)
+        .fetch_all(db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch replay positions: {}", e)))?;
+
+        let outcome_json = serde_json::to_string(&outcomes)
+            .map_err(|e| DatabaseError::SerializationError(format!("Failed to serialize replay result: {}", e)))?;
+
+        Ok(Self {
+            seed,
+            input_source: input.source.clone(),
+            input_events: input.events.len(),
+            input_range: input.events.first().zip(input.events.last())
+                .map(|(first, last)| (first.get_event_id(), last.get_event_id())),
+            input_digest: input.digest.clone(),
+            config_hash,
+            config,
+            code_version: env!("CARGO_PKG_VERSION").to_string(),
+            git_commit: option_env!("BADGER_GIT_COMMIT").map(str::to_string),
+            positions: outcomes.len(),
+            open_positions: outcomes.iter().filter(|o| o.status == "OPEN").count(),
+            total_pnl: outcomes.iter().filter_map(|o| o.pnl).sum(),
+            result_digest: solana_sdk::hash::hash(outcome_json.as_bytes()).to_string(),
+            completed_at: Utc::now().timestamp(),
+            chaos_profile: None,
+            invariant_violations: Vec::new(),
+        })
+    }
+
+    /// Read a previously written manifest
+    pub fn load(path: &Path) -> Result<Self, DatabaseError> {
+        let raw = std::fs::read_to_string(path)
+            .map_err(|e| DatabaseError::SerializationError(format!("Failed to read replay manifest {}: {}", path.display(), e)))?;
+        serde_json::from_str(&raw)
+            .map_err(|e| DatabaseError::SerializationError(format!("Invalid replay manifest {}: {}", path.display(), e)))
+    }
+
+    /// Write the manifest to `data/replays/replay-<completed_at>-<result digest>.json`
+    pub fn write(&self) -> Result<PathBuf, DatabaseError> {
+        std::fs::create_dir_all(REPLAY_MANIFEST_DIR)
+            .map_err(|e| DatabaseError::SerializationError(format!("Failed to create {}: {}", REPLAY_MANIFEST_DIR, e)))?;
+
+        let path = Path::new(REPLAY_MANIFEST_DIR).join(format!(
+            "replay-{}-{}.json", self.completed_at, &self.result_digest[..8.min(self.result_digest.len())]
+        ));
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| DatabaseError::SerializationError(format!("Failed to serialize replay manifest: {}", e)))?;
+        std::fs::write(&path, json)
+            .map_err(|e| DatabaseError::SerializationError(format!("Failed to write replay manifest: {}", e)))?;
+
+        Ok(path)
+    }
+}
+
+/// A safety property a replay broke
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvariantViolation {
+    pub invariant: String, // "DOUBLE_BUY", "DUPLICATE_EXECUTION", "EXPOSURE_OVER_CAP", "ORPHANED_POSITION", "ORPHANED_EXECUTION", "UNSETTLED_SIGNAL"
+    pub detail: String,
+}
+
+/// Check that a replay degraded safely under injected faults
+///
+/// * No double buys: no token has more entries than distinct entry events in the input
+/// * No duplicate executions: no trade was acknowledged by more than one signal
+/// * Exposure under the cap: the entry cost held in a token when each entry opened
+///   never exceeded the per-mint cap
+/// * No orphaned positions: every open position was acknowledged by an executed signal,
+///   and every executed entry left a position behind
+/// * Every signal settled: no outbox entry is still waiting on execution or expiry
+///   unless its execution was dead-lettered
+///
+/// # Arguments
+/// * `db` - Database the replay wrote to
+/// * `entry_events` - Distinct input events per token mint that generate a buy
+/// * `max_mint_exposure_sol` - Per-mint exposure cap the replay ran with
+pub async fn check_replay_invariants(
+    db: &BadgerDatabase,
+    entry_events: &HashMap<String, usize>,
+    max_mint_exposure_sol: f64,
+) -> Result<Vec<InvariantViolation>, DatabaseError> {
+    let mut violations = Vec::new();
+
+    // Split-off closed rows of a partially closed lot are not entries
+    let entries = sqlx::query_as::<_, (String, i64)>(r#"
+        SELECT p.token_mint,
+               COUNT(*) - (SELECT COUNT(*) FROM position_updates u
+                           JOIN positions l ON l.id = u.position_id
+                           WHERE u.update_type = 'PARTIAL_CLOSE' AND l.token_mint = p.token_mint)
+        FROM positions p
+        WHERE p.position_type = 'BUY'
+        GROUP BY p.token_mint
+    "#)
+    .fetch_all(db.get_pool())
+    .await
+    .map_err(|e| DatabaseError::QueryError(format!("Failed to count replay entries: {}", e)))?;
+
+    for (token_mint, count) in entries {
+        let expected = entry_events.get(&token_mint).copied().unwrap_or(0);
+        if count as usize > expected {
+            violations.push(InvariantViolation {
+                invariant: "DOUBLE_BUY".to_string(),
+                detail: format!("{} entered {} times for {} entry events", token_mint, count, expected),
+            });
+        }
+    }
+
+    let duplicates = sqlx::query_as::<_, (String, i64)>(r#"
+        SELECT execution_ref, COUNT(*) FROM signal_outbox
+        WHERE status = 'EXECUTED' AND execution_ref IS NOT NULL
+        GROUP BY execution_ref HAVING COUNT(*) > 1
+    "#)
+    .fetch_all(db.get_pool())
+    .await
+    .map_err(|e| DatabaseError::QueryError(format!("Failed to find duplicate executions: {}", e)))?;
+
+    violations.extend(duplicates.into_iter().map(|(execution_ref, count)| InvariantViolation {
+        invariant: "DUPLICATE_EXECUTION".to_string(),
+        detail: format!("{} was acknowledged by {} signals", execution_ref, count),
+    }));
+
+    // Exposure peaks when an entry opens; split-off closed rows still count until their exit
+    let peaks = sqlx::query_as::<_, (String, f64)>(r#"
+        SELECT token_mint, MAX(held_sol) FROM (
+            SELECT p.token_mint, SUM(q.entry_price * q.quantity) AS held_sol
+            FROM positions p
+            JOIN positions q ON q.token_mint = p.token_mint AND q.position_type = 'BUY'
+                AND q.entry_timestamp <= p.entry_timestamp
+                AND (q.exit_timestamp IS NULL OR q.exit_timestamp > p.entry_timestamp)
+            WHERE p.position_type = 'BUY'
+            GROUP BY p.id
+        )
+        GROUP BY token_mint
+    "#)
+    .fetch_all(db.get_pool())
+    .await
+    .map_err(|e| DatabaseError::QueryError(format!("Failed to sum replay exposure: {}", e)))?;
+
+    for (token_mint, held_sol) in peaks {
+        if held_sol > max_mint_exposure_sol + 1e-9 {
+            violations.push(InvariantViolation {
+                invariant: "EXPOSURE_OVER_CAP".to_string(),
+                detail: format!("{} held {:.4} SOL against a {:.4} SOL cap", token_mint, held_sol, max_mint_exposure_sol),
+            });
+        }
+    }
+
+    let unacknowledged = sqlx::query_as::<_, (i64, String)>(r#"
+        SELECT p.id, p.token_mint FROM positions p
+        WHERE p.status = 'OPEN' AND NOT EXISTS (
+            SELECT 1 FROM signal_outbox o
+            WHERE o.token_mint = p.token_mint AND o.status = 'EXECUTED' AND o.execution_ref = 'open:' || p.id
+        )
+    "#)
+    .fetch_all(db.get_pool())
+    .await
+    .map_err(|e| DatabaseError::QueryError(format!("Failed to find orphaned positions: {}", e)))?;
+
+    violations.extend(unacknowledged.into_iter().map(|(id, token_mint)| InvariantViolation {
+        invariant: "ORPHANED_POSITION".to_string(),
+        detail: format!("Open position #{} ({}) has no executed signal", id, token_mint),
+    }));
+
+    let missing = sqlx::query_as::<_, (String, String)>(r#"
+        SELECT o.signal_id, o.execution_ref FROM signal_outbox o
+        WHERE o.status = 'EXECUTED' AND o.execution_ref LIKE 'open:%' AND NOT EXISTS (
+            SELECT 1 FROM positions p WHERE 'open:' || p.id = o.execution_ref
+        )
+    "#)
+    .fetch_all(db.get_pool())
+    .await
+    .map_err(|e| DatabaseError::QueryError(format!("Failed to find orphaned executions: {}", e)))?;
+
+    violations.extend(missing.into_iter().map(|(signal_id, execution_ref)| InvariantViolation {
+        invariant: "ORPHANED_EXECUTION".to_string(),
+        detail: format!("Signal {} executed as {} but the position does not exist", signal_id, execution_ref),
+    }));
+
+    let unsettled = sqlx::query_as::<_, (String, String, String)>(r#"
+        SELECT o.signal_id, o.token_mint, o.status FROM signal_outbox o
+        WHERE o.status IN ('EMITTED', 'PICKED_UP') AND NOT EXISTS (
+            SELECT 1 FROM execution_dead_letters d WHERE d.signal_id = o.signal_id
+        )
+    "#)
+    .fetch_all(db.get_pool())
+    .await
+    .map_err(|e| DatabaseError::QueryError(format!("Failed to find unsettled signals: {}", e)))?;
+
+    violations.extend(unsettled.into_iter().map(|(signal_id, token_mint, status)| InvariantViolation {
+        invariant: "UNSETTLED_SIGNAL".to_string(),
+        detail: format!("Signal {} ({}) was left {}", signal_id, token_mint, status),
+    }));
+
+    Ok(violations)
+}
@@ -0,0 +1,212 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, instrument};
+
+use super::{BadgerDatabase, DatabaseError};
+
+/// Instance that owns trading
+pub const INSTANCE_ACTIVE: &str = "ACTIVE";
+/// Instance started with `--takeover`, warm but not trading until handed over
+pub const INSTANCE_STANDBY: &str = "STANDBY";
+/// Instance that handed over or shut down; it no longer trades
+pub const INSTANCE_RETIRED: &str = "RETIRED";
+
+/// Configuration for process handoff
+#[derive(Debug, Clone)]
+pub struct HandoffConfig {
+    /// How often each instance records a heartbeat (seconds)
+    pub heartbeat_secs: u64,
+    /// An instance without a heartbeat for this long is considered gone (seconds)
+    pub stale_after_secs: i64,
+}
+
+impl Default for HandoffConfig {
+    fn default() -> Self {
+        Self {
+            heartbeat_secs: 5,
+            stale_after_secs: 30,
+        }
+    }
+}
+
+/// A badger process registered against the shared database
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ProcessInstance {
+    pub instance_id: String,
+    pub pid: i64,
+    pub status: String, // "ACTIVE", "STANDBY", "RETIRED"
+    pub started_at: i64,
+    pub heartbeat_at: i64,
+    /// Instance trading was handed to, when retired by a handoff
+    pub handed_to: Option<String>,
+    /// `HandoffState` JSON left for the successor
+    pub handoff_state: Option<String>,
+}
+
+/// In-memory state the retiring instance passes to its successor
+///
+/// Open positions and working orders are already in the shared database;
+/// their counts are passed along so the successor can check it sees them all.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HandoffState {
+    pub trading_paused: bool,
+    pub open_positions: usize,
+    pub working_orders: usize,
+}
+
+/// Registry of badger processes sharing a database, for rolling handoffs
+///
+/// One instance is ACTIVE and trades. A new instance started with
+/// `--takeover` registers as STANDBY, warms up without trading and queues a
+/// HANDOFF command; the active instance stops trading, retires with its
+/// state and exits, and the standby then takes over. An instance whose
+/// heartbeat goes stale is treated as gone.
+pub struct HandoffCoordinator {
+    db: Arc<BadgerDatabase>,
+    config: HandoffConfig,
+    instance_id: String,
+}
+
+impl HandoffCoordinator {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<HandoffConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+            instance_id: format!("badger-{}-{}", std::process::id(), Utc::now().timestamp_millis()),
+        }
+    }
+
+    pub fn config(&self) -> &HandoffConfig {
+        &self.config
+    }
+
+    /// Id of this process
+    pub fn instance_id(&self) -> &str {
+        &self.instance_id
+    }
+
+    /// Initialize process registry schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing process handoff schema");
+
+        let create_process_instances = r#"
+            CREATE TABLE IF NOT EXISTS process_instances (
+                instance_id TEXT PRIMARY KEY,
+                pid INTEGER NOT NULL,
+                status TEXT NOT NULL CHECK (status IN ('ACTIVE', 'STANDBY', 'RETIRED')),
+                started_at INTEGER NOT NULL,
+                heartbeat_at INTEGER NOT NULL,
+                handed_to TEXT,
+                handoff_state TEXT
+            )
+        "#;
+
+        sqlx::query(create_process_instances)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create process_instances table: {}", e)))?;
+
+        info!("✅ Process handoff schema initialized");
+        Ok(())
+    }
+
+    /// Register this process with the given status
+    pub async fn register(&self, status: &str) -> Result<(), DatabaseError> {
+        let now = Utc::now().timestamp();
+        sqlx::query(r#"
+            INSERT OR REPLACE INTO process_instances (instance_id, pid, status, started_at, heartbeat_at)
+            VALUES (?, ?, ?, ?, ?)
+        "#)
+        .bind(&self.instance_id)
+        .bind(std::process::id() as i64)
+        .bind(status)
+        .bind(now)
+        .bind(now)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to register process instance: {}", e)))?;
+
+        debug!("🔁 Registered {} as {}", self.instance_id, status);
+        Ok(())
+    }
+
+    /// Record that this process is alive
+    pub async fn heartbeat(&self) -> Result<(), DatabaseError> {
+        sqlx::query("UPDATE process_instances SET heartbeat_at = ? WHERE instance_id = ?")
+            .bind(Utc::now().timestamp())
+            .bind(&self.instance_id)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to record heartbeat: {}", e)))?;
+        Ok(())
+    }
+
+    /// Another instance that is ACTIVE and still heartbeating, if any
+    pub async fn active_peer(&self) -> Result<Option<ProcessInstance>, DatabaseError> {
+        sqlx::query_as::<_, ProcessInstance>(r#"
+            SELECT * FROM process_instances
+            WHERE status = 'ACTIVE' AND instance_id != ? AND heartbeat_at >= ?
+            ORDER BY started_at DESC LIMIT 1
+        "#)
+        .bind(&self.instance_id)
+        .bind(Utc::now().timestamp() - self.config.stale_after_secs)
+        .fetch_optional(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch active instance: {}", e)))
+    }
+
+    /// A registered instance by id
+    pub async fn get(&self, instance_id: &str) -> Result<Option<ProcessInstance>, DatabaseError> {
+        sqlx::query_as::<_, ProcessInstance>("SELECT * FROM process_instances WHERE instance_id = ?")
+            .bind(instance_id)
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch process instance: {}", e)))
+    }
+
+    /// This process's own registration
+    pub async fn current(&self) -> Result<Option<ProcessInstance>, DatabaseError> {
+        self.get(&self.instance_id).await
+    }
+
+    /// Whether an instance has stopped heartbeating
+    pub fn is_stale(&self, instance: &ProcessInstance) -> bool {
+        Utc::now().timestamp() - instance.heartbeat_at > self.config.stale_after_secs
+    }
+
+    /// Retire this process, leaving its state for the successor when handing off
+    pub async fn retire(&self, handed_to: Option<&str>, state: Option<&HandoffState>) -> Result<(), DatabaseError> {
+        let state = state.map(serde_json::to_string).transpose()
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to serialize handoff state: {}", e)))?;
+
+        sqlx::query(r#"
+            UPDATE process_instances SET status = 'RETIRED', handed_to = ?, handoff_state = ?, heartbeat_at = ?
+            WHERE instance_id = ?
+        "#)
+        .bind(handed_to)
+        .bind(state)
+        .bind(Utc::now().timestamp())
+        .bind(&self.instance_id)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to retire process instance: {}", e)))?;
+
+        info!("🔁 Instance {} retired{}", self.instance_id,
+            handed_to.map(|to| format!(", trading handed to {}", to)).unwrap_or_default());
+        Ok(())
+    }
+
+    /// Mark this process as the one that trades
+    pub async fn activate(&self) -> Result<(), DatabaseError> {
+        sqlx::query("UPDATE process_instances SET status = 'ACTIVE', heartbeat_at = ? WHERE instance_id = ?")
+            .bind(Utc::now().timestamp())
+            .bind(&self.instance_id)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to activate process instance: {}", e)))?;
+        Ok(())
+    }
+}
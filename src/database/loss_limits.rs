@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use chrono::{Datelike, Duration, FixedOffset, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, instrument, warn};
+
+use super::{BadgerDatabase, DatabaseError};
+use crate::core::SignalSource;
+
+pub const PERIOD_DAY: &str = "DAY";
+pub const PERIOD_WEEK: &str = "WEEK";
+
+/// Strategy name of the counters spanning every strategy
+pub const ALL_STRATEGIES: &str = "*";
+
+/// Strategy name of positions opened without a known signal source
+pub const UNATTRIBUTED_STRATEGY: &str = "unattributed";
+
+/// Strategy a signal source's entries are accounted to, e.g. "Sniper" or "InsiderWallet"
+pub fn strategy_name(source: SignalSource) -> String {
+    format!("{:?}", source)
+}
+
+/// Realized losses allowed per period; None leaves a period unlimited
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct LossLimit {
+    pub daily: Option<f64>,
+    pub weekly: Option<f64>,
+}
+
+impl LossLimit {
+    fn for_period(&self, period: &str) -> Option<f64> {
+        if period == PERIOD_DAY { self.daily } else { self.weekly }
+    }
+}
+
+/// Configuration for the loss limit circuit breaker
+#[derive(Debug, Clone)]
+pub struct LossLimitConfig {
+    /// Limits across all strategies
+    pub overall: LossLimit,
+    /// Limits of single strategies, by strategy name
+    pub strategies: HashMap<String, LossLimit>,
+    /// Offset of the timezone days and weeks (starting Monday) are counted in
+    pub utc_offset: FixedOffset,
+}
+
+impl Default for LossLimitConfig {
+    fn default() -> Self {
+        Self {
+            // Off unless configured
+            overall: LossLimit::default(),
+            strategies: HashMap::new(),
+            utc_offset: FixedOffset::east_opt(0).expect("zero offset"),
+        }
+    }
+}
+
+impl LossLimitConfig {
+    /// Defaults with overrides from `BADGER_DAILY_LOSS_LIMIT`, `BADGER_WEEKLY_LOSS_LIMIT`,
+    /// `BADGER_STRATEGY_LOSS_LIMITS` and `BADGER_LOSS_LIMIT_UTC_OFFSET` when set
+    ///
+    /// Strategy limits are `name=daily:weekly` pairs separated by commas, either
+    /// side left empty for no limit (e.g. `Sniper=20:50,InsiderWallet=:100`).
+    /// The offset is `+HH:MM` or `-HH:MM`.
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        let var = |name: &str| std::env::var(name).ok().and_then(|v| v.parse::<f64>().ok());
+        config.overall.daily = var("BADGER_DAILY_LOSS_LIMIT");
+        config.overall.weekly = var("BADGER_WEEKLY_LOSS_LIMIT");
+        if let Ok(value) = std::env::var("BADGER_STRATEGY_LOSS_LIMITS") {
+            for entry in value.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+                let Some((name, limits)) = entry.split_once('=') else {
+                    warn!("Ignoring strategy loss limit {:?}: expected name=daily:weekly", entry);
+                    continue;
+                };
+                let (daily, weekly) = limits.split_once(':').unwrap_or((limits, ""));
+                config.strategies.insert(name.trim().to_string(), LossLimit {
+                    daily: daily.trim().parse().ok(),
+                    weekly: weekly.trim().parse().ok(),
+                });
+            }
+        }
+        if let Ok(value) = std::env::var("BADGER_LOSS_LIMIT_UTC_OFFSET") {
+            match parse_utc_offset(&value) {
+                Some(offset) => config.utc_offset = offset,
+                None => warn!("Ignoring BADGER_LOSS_LIMIT_UTC_OFFSET {:?}: expected +HH:MM", value),
+            }
+        }
+        config
+    }
+
+    /// Whether any limit is configured
+    pub fn is_enabled(&self) -> bool {
+        self.overall != LossLimit::default() || self.strategies.values().any(|limit| *limit != LossLimit::default())
+    }
+
+    fn limit(&self, strategy: &str) -> LossLimit {
+        if strategy == ALL_STRATEGIES {
+            return self.overall;
+        }
+        self.strategies.get(strategy).copied().unwrap_or_default()
+    }
+
+    /// Start dates (YYYY-MM-DD) of the day and week a timestamp falls in
+    pub fn periods_at(&self, at: i64) -> [(&'static str, String); 2] {
+        let date = chrono::DateTime::from_timestamp(at, 0)
+            .unwrap_or_else(Utc::now)
+            .with_timezone(&self.utc_offset)
+            .date_naive();
+        let week_start = date - Duration::days(date.weekday().num_days_from_monday() as i64);
+        [(PERIOD_DAY, format_date(date)), (PERIOD_WEEK, format_date(week_start))]
+    }
+}
+
+fn parse_utc_offset(value: &str) -> Option<FixedOffset> {
+    let value = value.trim();
+    let (sign, rest) = match value.chars().next()? {
+        '+' => (1, &value[1..]),
+        '-' => (-1, &value[1..]),
+        _ => (1, value),
+    };
+    let (hours, minutes) = rest.split_once(':').unwrap_or((rest, "0"));
+    let secs = hours.parse::<i32>().ok()? * 3600 + minutes.parse::<i32>().ok()? * 60;
+    FixedOffset::east_opt(sign * secs)
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+/// Realized P&L of a strategy (or all of them) over one day or week
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct LossCounter {
+    pub period: String,
+    /// First day of the period in the configured timezone (YYYY-MM-DD)
+    pub period_start: String,
+    pub strategy: String,
+    /// Net of wins and losses; negative while losing
+    pub realized_pnl: f64,
+    pub exits: i64,
+    pub updated_at: i64,
+}
+
+/// A limit the period's realized losses have reached
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LossLimitBreach {
+    /// Strategy name, or `*` for the overall limit
+    pub strategy: String,
+    pub period: String,
+    pub period_start: String,
+    pub realized_pnl: f64,
+    pub limit: f64,
+}
+
+impl LossLimitBreach {
+    /// "overall" or "strategy <name>", for logs and alerts
+    pub fn scope(&self) -> String {
+        if self.strategy == ALL_STRATEGIES {
+            "overall".to_string()
+        } else {
+            format!("strategy {}", self.strategy)
+        }
+    }
+}
+
+/// Circuit breaker halting entries once realized losses reach a daily or weekly limit
+///
+/// Realized P&L of every exit is added to day and week counters, overall and
+/// for the strategy that opened the position. Periods are calendar days and
+/// weeks in the configured timezone, and counters live in the database, so
+/// neither a restart nor a process running past midnight resets a limit early.
+/// Only entries are refused; exits always go through.
+pub struct LossLimits {
+    db: Arc<BadgerDatabase>,
+    config: LossLimitConfig,
+}
+
+impl LossLimits {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<LossLimitConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+        }
+    }
+
+    pub fn config(&self) -> &LossLimitConfig {
+        &self.config
+    }
+
+    /// Initialize loss limit schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing loss limit schema");
+
+        let create_loss_counters = r#"
+            CREATE TABLE IF NOT EXISTS loss_counters (
+                period TEXT NOT NULL CHECK (period IN ('DAY', 'WEEK')),
+                period_start TEXT NOT NULL,
+                strategy TEXT NOT NULL,
+                realized_pnl REAL NOT NULL DEFAULT 0.0,
+                exits INTEGER NOT NULL DEFAULT 0,
+                updated_at INTEGER NOT NULL,
+                PRIMARY KEY (period, period_start, strategy)
+            )
+        "#;
+
+        let create_position_strategies = r#"
+            CREATE TABLE IF NOT EXISTS position_strategies (
+                position_id INTEGER PRIMARY KEY,
+                strategy TEXT NOT NULL,
+                opened_at INTEGER NOT NULL
+            )
+        "#;
+
+        for (statement, table) in [
+            (create_loss_counters, "loss_counters"),
+            (create_position_strategies, "position_strategies"),
+        ] {
+            sqlx::query(statement)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create {} table: {}", table, e)))?;
+        }
+
+        info!("✅ Loss limit schema initialized");
+        Ok(())
+    }
+
+    /// Attribute a newly opened position to the strategy whose signal opened it
+    pub async fn record_entry(&self, position_id: i64, strategy: &str) -> Result<(), DatabaseError> {
+        sqlx::query("INSERT OR IGNORE INTO position_strategies (position_id, strategy, opened_at) VALUES (?, ?, ?)")
+            .bind(position_id)
+            .bind(strategy)
+            .bind(Utc::now().timestamp())
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to record position strategy: {}", e)))?;
+        Ok(())
+    }
+
+    /// Strategy a position is accounted to
+    pub async fn strategy_of(&self, position_id: i64) -> Result<String, DatabaseError> {
+        let strategy = sqlx::query_scalar::<_, String>("SELECT strategy FROM position_strategies WHERE position_id = ?")
+            .bind(position_id)
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch position strategy: {}", e)))?;
+        Ok(strategy.unwrap_or_else(|| UNATTRIBUTED_STRATEGY.to_string()))
+    }
+
+    /// Add the realized P&L of an exit to the counters of the period it happened in
+    ///
+    /// # Arguments
+    /// * `position_id` - Position the exit closed or reduced
+    /// * `realized_pnl` - Net P&L of the exit
+    /// * `at` - When the exit happened; decides the day and week it counts to
+    ///
+    /// # Returns
+    /// * `Result<String, DatabaseError>` - Strategy the exit was accounted to
+    pub async fn record_exit(&self, position_id: i64, realized_pnl: f64, at: i64) -> Result<String, DatabaseError> {
+        let strategy = self.strategy_of(position_id).await?;
+        let now = Utc::now().timestamp();
+
+        let mut tx = self.db.begin_transaction().await?;
+        for (period, period_start) in self.config.periods_at(at) {
+            for scope in [ALL_STRATEGIES, strategy.as_str()] {
+                sqlx::query(r#"
+                    INSERT INTO loss_counters (period, period_start, strategy, realized_pnl, exits, updated_at)
+                    VALUES (?, ?, ?, ?, 1, ?)
+                    ON CONFLICT (period, period_start, strategy) DO UPDATE SET
+                        realized_pnl = realized_pnl + excluded.realized_pnl,
+                        exits = exits + 1,
+                        updated_at = excluded.updated_at
+                "#)
+                .bind(period)
+                .bind(&period_start)
+                .bind(scope)
+                .bind(realized_pnl)
+                .bind(now)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to update loss counter: {}", e)))?;
+            }
+        }
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit loss counters: {}", e)))?;
+
+        debug!("🧯 Position #{} realized {:.4} for {}", position_id, realized_pnl, strategy);
+        Ok(strategy)
+    }
+
+    /// Counters of the current day and week
+    pub async fn current_counters(&self) -> Result<Vec<LossCounter>, DatabaseError> {
+        let [(_, day), (_, week)] = self.config.periods_at(Utc::now().timestamp());
+        sqlx::query_as::<_, LossCounter>(r#"
+            SELECT * FROM loss_counters
+            WHERE (period = 'DAY' AND period_start = ?) OR (period = 'WEEK' AND period_start = ?)
+            ORDER BY period, strategy
+        "#)
+        .bind(day)
+        .bind(week)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch loss counters: {}", e)))
+    }
+
+    /// First limit that refuses a new entry for the strategy, overall limits first
+    pub async fn breach(&self, strategy: &str) -> Result<Option<LossLimitBreach>, DatabaseError> {
+        if !self.config.is_enabled() {
+            return Ok(None);
+        }
+        for (period, period_start) in self.config.periods_at(Utc::now().timestamp()) {
+            for scope in [ALL_STRATEGIES, strategy] {
+                let Some(limit) = self.config.limit(scope).for_period(period) else {
+                    continue;
+                };
+                let realized_pnl = sqlx::query_scalar::<_, f64>(
+                    "SELECT realized_pnl FROM loss_counters WHERE period = ? AND period_start = ? AND strategy = ?"
+                )
+                .bind(period)
+                .bind(&period_start)
+                .bind(scope)
+                .fetch_optional(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch loss counter: {}", e)))?
+                .unwrap_or(0.0);
+
+                if realized_pnl < 0.0 && realized_pnl <= -limit {
+                    return Ok(Some(LossLimitBreach {
+                        strategy: scope.to_string(),
+                        period: period.to_string(),
+                        period_start: period_start.clone(),
+                        realized_pnl,
+                        limit,
+                    }));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
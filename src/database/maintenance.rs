@@ -0,0 +1,237 @@
+use std::sync::Arc;
+use std::time::Instant;
+use chrono::{DateTime, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{info, warn, instrument};
+
+use super::{BadgerDatabase, DatabaseError};
+
+/// `PRAGMA auto_vacuum` value for incremental mode
+const AUTO_VACUUM_INCREMENTAL: i64 = 2;
+
+/// Configuration for the database maintenance window
+#[derive(Debug, Clone)]
+pub struct MaintenanceConfig {
+    /// Start of the low-activity window (UTC hour, 0-23)
+    pub window_start_hour: u32,
+    /// Length of the window (hours)
+    pub window_hours: u32,
+    /// Minimum time between two maintenance runs (hours)
+    pub min_interval_hours: i64,
+    /// How often the window is checked (seconds)
+    pub check_interval_secs: u64,
+}
+
+impl Default for MaintenanceConfig {
+    fn default() -> Self {
+        Self {
+            window_start_hour: 4,
+            window_hours: 2,
+            min_interval_hours: 20,
+            check_interval_secs: 300,
+        }
+    }
+}
+
+impl MaintenanceConfig {
+    /// Defaults with the window start from `BADGER_MAINTENANCE_HOUR_UTC` when set
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(hour) = std::env::var("BADGER_MAINTENANCE_HOUR_UTC").ok().and_then(|v| v.parse::<u32>().ok()) {
+            config.window_start_hour = hour % 24;
+        }
+        config
+    }
+
+    /// Whether `at` falls inside the maintenance window
+    pub fn in_window(&self, at: DateTime<Utc>) -> bool {
+        (at.hour() + 24 - self.window_start_hour) % 24 < self.window_hours
+    }
+}
+
+/// Outcome of one maintenance run
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MaintenanceRun {
+    pub id: i64,
+    pub started_at: i64,
+    pub duration_ms: i64,
+    pub integrity_ok: bool,
+    /// `PRAGMA integrity_check` findings, newline separated (None when ok)
+    pub integrity_errors: Option<String>,
+    /// Indexes were rebuilt because the integrity check failed
+    pub reindexed: bool,
+    /// The file was converted to incremental auto-vacuum with a full VACUUM
+    pub full_vacuum: bool,
+    pub pages_freed: i64,
+}
+
+/// Scheduled SQLite upkeep: integrity check, incremental vacuum and statistics refresh
+///
+/// Runs at most once per window. Batch writers are paused for the duration
+/// (in-flight batches commit first, queued events wait in memory); trading
+/// writes are not, and ride out the short locks on the busy timeout.
+pub struct DatabaseMaintenance {
+    db: Arc<BadgerDatabase>,
+    config: MaintenanceConfig,
+}
+
+impl DatabaseMaintenance {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<MaintenanceConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+        }
+    }
+
+    pub fn config(&self) -> &MaintenanceConfig {
+        &self.config
+    }
+
+    /// Initialize maintenance schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing database maintenance schema");
+
+        let create_maintenance_runs = r#"
+            CREATE TABLE IF NOT EXISTS maintenance_runs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                started_at INTEGER NOT NULL,
+                duration_ms INTEGER NOT NULL,
+                integrity_ok BOOLEAN NOT NULL,
+                integrity_errors TEXT,
+                reindexed BOOLEAN NOT NULL DEFAULT 0,
+                full_vacuum BOOLEAN NOT NULL DEFAULT 0,
+                pages_freed INTEGER NOT NULL DEFAULT 0
+            )
+        "#;
+
+        sqlx::query(create_maintenance_runs)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create maintenance_runs table: {}", e)))?;
+
+        info!("✅ Database maintenance schema initialized");
+        Ok(())
+    }
+
+    /// Most recent maintenance run
+    pub async fn last_run(&self) -> Result<Option<MaintenanceRun>, DatabaseError> {
+        sqlx::query_as::<_, MaintenanceRun>("SELECT * FROM maintenance_runs ORDER BY started_at DESC LIMIT 1")
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch last maintenance run: {}", e)))
+    }
+
+    /// Whether maintenance should run now: inside the window and not run recently
+    pub async fn is_due(&self, now: DateTime<Utc>) -> Result<bool, DatabaseError> {
+        if !self.config.in_window(now) {
+            return Ok(false);
+        }
+        let last_run = self.last_run().await?;
+        Ok(last_run.is_none_or(|run| now.timestamp() - run.started_at >= self.config.min_interval_hours * 3600))
+    }
+
+    /// Run integrity check, vacuum and statistics refresh with batch writers paused
+    #[instrument(skip(self))]
+    pub async fn run(&self) -> Result<MaintenanceRun, DatabaseError> {
+        let pool = self.db.get_pool();
+        let started_at = Utc::now().timestamp();
+        let started = Instant::now();
+
+        let _paused = self.db.pause_batch_writes().await;
+        info!("🧰 Database maintenance started (batch writers paused)");
+
+        let findings: Vec<String> = sqlx::query_scalar("PRAGMA integrity_check")
+            .fetch_all(pool)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to run integrity check: {}", e)))?;
+        let integrity_ok = findings.len() == 1 && findings[0] == "ok";
+
+        // Missing or extra index entries are the damage REINDEX can repair
+        let reindexed = !integrity_ok;
+        if reindexed {
+            warn!("🧰 Integrity check found {} problems, rebuilding indexes", findings.len());
+            sqlx::query("REINDEX")
+                .execute(pool)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to reindex: {}", e)))?;
+        }
+
+        let freelist_before = self.freelist_count().await?;
+        let auto_vacuum: i64 = sqlx::query_scalar("PRAGMA auto_vacuum")
+            .fetch_one(pool)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to read auto_vacuum mode: {}", e)))?;
+
+        // Files created before incremental auto-vacuum need one full VACUUM to switch modes
+        let full_vacuum = auto_vacuum != AUTO_VACUUM_INCREMENTAL;
+        if full_vacuum {
+            info!("🧰 Converting database to incremental auto-vacuum (full VACUUM)");
+            let mut conn = pool.acquire()
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to acquire connection: {}", e)))?;
+            sqlx::query("PRAGMA auto_vacuum = INCREMENTAL")
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to set auto_vacuum mode: {}", e)))?;
+            sqlx::query("VACUUM")
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to vacuum: {}", e)))?;
+        } else {
+            sqlx::query("PRAGMA incremental_vacuum")
+                .execute(pool)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to run incremental vacuum: {}", e)))?;
+        }
+        let pages_freed = freelist_before - self.freelist_count().await?;
+
+        for statement in ["ANALYZE", "PRAGMA optimize", "PRAGMA wal_checkpoint(TRUNCATE)"] {
+            sqlx::query(statement)
+                .execute(pool)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to run {}: {}", statement, e)))?;
+        }
+
+        let duration_ms = started.elapsed().as_millis() as i64;
+        let integrity_errors = (!integrity_ok).then(|| findings.join("\n"));
+        let id = sqlx::query(r#"
+            INSERT INTO maintenance_runs
+                (started_at, duration_ms, integrity_ok, integrity_errors, reindexed, full_vacuum, pages_freed)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(started_at)
+        .bind(duration_ms)
+        .bind(integrity_ok)
+        .bind(&integrity_errors)
+        .bind(reindexed)
+        .bind(full_vacuum)
+        .bind(pages_freed)
+        .execute(pool)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record maintenance run: {}", e)))?
+        .last_insert_rowid();
+
+        info!("✅ Database maintenance finished in {}ms: integrity {}, {} pages freed",
+            duration_ms, if integrity_ok { "ok" } else { "FAILED" }, pages_freed);
+
+        Ok(MaintenanceRun {
+            id,
+            started_at,
+            duration_ms,
+            integrity_ok,
+            integrity_errors,
+            reindexed,
+            full_vacuum,
+            pages_freed,
+        })
+    }
+
+    async fn freelist_count(&self) -> Result<i64, DatabaseError> {
+        sqlx::query_scalar("PRAGMA freelist_count")
+            .fetch_one(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to read freelist count: {}", e)))
+    }
+}
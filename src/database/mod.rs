@@ -13,6 +13,27 @@ pub mod batch;
 pub mod validation;
 pub mod cleanup;
 pub mod analytics;
+pub mod control;
+pub mod fixtures;
+pub mod outbox;
+pub mod sweeps;
+pub mod profit_lock;
+pub mod working_orders;
+pub mod fee_budget;
+pub mod copy_settings;
+pub mod maintenance;
+pub mod wallet_labels;
+pub mod handoff;
+pub mod watch_groups;
+pub mod program_registry;
+pub mod loss_limits;
+pub mod own_fills;
+pub mod audit_log;
+pub mod dead_letters;
+pub mod exposure;
+pub mod cold_store;
+pub mod daily_counters;
+pub mod trade_records;
 
 pub use models::*;
 pub use services::*;
@@ -20,6 +41,27 @@ pub use batch::*;
 pub use validation::*;
 pub use cleanup::*;
 pub use analytics::*;
+pub use control::*;
+pub use fixtures::*;
+pub use outbox::*;
+pub use sweeps::*;
+pub use profit_lock::*;
+pub use working_orders::*;
+pub use fee_budget::*;
+pub use copy_settings::*;
+pub use maintenance::*;
+pub use wallet_labels::*;
+pub use handoff::*;
+pub use watch_groups::*;
+pub use program_registry::*;
+pub use loss_limits::*;
+pub use own_fills::*;
+pub use audit_log::*;
+pub use dead_letters::*;
+pub use exposure::*;
+pub use cold_store::*;
+pub use daily_counters::*;
+pub use trade_records::*;
 
 /// Enhanced database manager for Milestone 2 with real-time persistence
 pub struct DatabaseManager {
@@ -30,6 +72,7 @@ pub struct DatabaseManager {
     enhanced_persistence: Option<EnhancedPersistenceService>,
     validation_service: Option<ValidationService>,
     cleanup_service: Option<CleanupService>,
+    database_url: String,
 }
 
 impl DatabaseManager {
@@ -42,9 +85,16 @@ impl DatabaseManager {
             enhanced_persistence: None,
             validation_service: None,
             cleanup_service: None,
+            database_url: "sqlite:data/badger.db".to_string(),
         }
     }
 
+    /// Use a different database (e.g. the fixtures database in test mode)
+    pub fn with_database_url(mut self, database_url: &str) -> Self {
+        self.database_url = database_url.to_string();
+        self
+    }
+
     pub async fn initialize(
         &mut self,
         transport_bus: Arc<EnhancedTransportBus>,
@@ -53,7 +103,7 @@ impl DatabaseManager {
         info!("🗄️ Initializing Database Manager for Phase 3");
 
         // Initialize SQLite database with enhanced configuration
-        let db = Arc::new(BadgerDatabase::new("sqlite:data/badger.db").await?);
+        let db = Arc::new(BadgerDatabase::new(&self.database_url).await?);
 
         // Create enhanced persistence service for high-performance batch processing
         self.enhanced_persistence = Some(EnhancedPersistenceService::new(db.clone()));
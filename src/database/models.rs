@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use sqlx::{Row, FromRow, SqlitePool};
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
@@ -9,6 +10,8 @@ use uuid::Uuid;
 #[derive(Debug, Clone)]
 pub struct BadgerDatabase {
     pool: SqlitePool,
+    /// Held shared by batch writers and exclusively by maintenance
+    write_gate: Arc<tokio::sync::RwLock<()>>,
 }
 
 impl BadgerDatabase {
@@ -24,7 +27,7 @@ impl BadgerDatabase {
         }
 
         // Enhanced SQLite configuration with performance optimizations
-        use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqliteSynchronous, SqlitePoolOptions};
+        use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqliteSynchronous, SqlitePoolOptions, SqliteAutoVacuum};
         use std::str::FromStr;
         
         let connection_options = SqliteConnectOptions::from_str(database_url)
@@ -37,7 +40,8 @@ impl BadgerDatabase {
             .pragma("temp_store", "memory")              // Temp tables in memory
             .pragma("mmap_size", "268435456")            // 256MB memory map
             .pragma("optimize", "1")                     // Enable query optimizer
-            .pragma("wal_autocheckpoint", "1000");       // Checkpoint every 1000 pages
+            .pragma("wal_autocheckpoint", "1000")        // Checkpoint every 1000 pages
+            .auto_vacuum(SqliteAutoVacuum::Incremental); // Free pages reclaimed by maintenance
             
         // Advanced connection pooling configuration
         let pool = SqlitePoolOptions::new()
@@ -50,7 +54,7 @@ impl BadgerDatabase {
             .await
             .map_err(|e| super::DatabaseError::ConnectionError(format!("Failed to connect to database: {}", e)))?;
 
-        let db = Self { pool };
+        let db = Self { pool, write_gate: Arc::default() };
 
         // Run database migrations
         db.run_migrations().await?;
@@ -59,6 +63,30 @@ impl BadgerDatabase {
         Ok(db)
     }
 
+    /// Attach to an existing database without running migrations or resetting the session
+    ///
+    /// Used by external tools (e.g. badger-tui) that share the database with a running bot.
+    pub async fn attach(database_url: &str) -> Result<Self, super::DatabaseError> {
+        use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions};
+        use std::str::FromStr;
+
+        let connection_options = SqliteConnectOptions::from_str(database_url)
+            .map_err(|e| super::DatabaseError::ConnectionError(format!("Invalid database URL: {}", e)))?
+            .create_if_missing(false)
+            .journal_mode(SqliteJournalMode::Wal)
+            .busy_timeout(Duration::from_secs(30));
+
+        let pool = SqlitePoolOptions::new()
+            .max_connections(2)
+            .acquire_timeout(Duration::from_secs(10))
+            .connect_with(connection_options)
+            .await
+            .map_err(|e| super::DatabaseError::ConnectionError(format!("Failed to attach to database: {}", e)))?;
+
+        tracing::info!("✅ BadgerDatabase attached to: {}", database_url);
+        Ok(Self { pool, write_gate: Arc::default() })
+    }
+
     /// Run database migrations to create tables and indexes
     async fn run_migrations(&self) -> Result<(), super::DatabaseError> {
         tracing::info!("🔄 Running database migrations");
@@ -266,6 +294,9 @@ impl BadgerDatabase {
             crate::core::TradingSignal::SwapActivity { token_mint, .. } => {
                 (token_mint.clone(), None, None, Some("Swap activity detected".to_string()))
             }
+            crate::core::TradingSignal::Hedge { instrument, size_sol, reason, .. } => {
+                (instrument.clone(), None, Some(*size_sol), Some(reason.clone()))
+            }
         };
 
         sqlx::query(r#"
@@ -369,6 +400,37 @@ impl BadgerDatabase {
         Ok(events)
     }
 
+    /// Get recent trading signals
+    pub async fn get_recent_trading_signals(&self, limit: i64) -> Result<Vec<StoredTradingSignal>, super::DatabaseError> {
+        let signals = sqlx::query_as::<_, StoredTradingSignal>(r#"
+            SELECT signal_id, signal_type, token_mint, confidence, amount_sol, reason, timestamp
+            FROM trading_signals 
+            ORDER BY timestamp DESC 
+            LIMIT ?
+        "#)
+        .bind(limit)
+        .fetch_all(&self.pool).await
+        .map_err(|e| super::DatabaseError::QueryError(format!("Failed to get recent signals: {}", e)))?;
+
+        Ok(signals)
+    }
+
+    /// Count stored market events per type since the given timestamp
+    pub async fn get_event_counts_since(&self, since: i64) -> Result<Vec<(String, i64)>, super::DatabaseError> {
+        let counts = sqlx::query_as::<_, (String, i64)>(r#"
+            SELECT event_type, COUNT(*) as count
+            FROM market_events 
+            WHERE timestamp >= ?
+            GROUP BY event_type
+            ORDER BY count DESC
+        "#)
+        .bind(since)
+        .fetch_all(&self.pool).await
+        .map_err(|e| super::DatabaseError::QueryError(format!("Failed to count recent events: {}", e)))?;
+
+        Ok(counts)
+    }
+
     /// Update analytics with provided data
     pub async fn update_analytics(&self, analytics: AnalyticsData) -> Result<(), super::DatabaseError> {
         let query = r#"
@@ -490,6 +552,18 @@ impl BadgerDatabase {
         &self.pool
     }
 
+    /// Wait until batch writes are allowed; maintenance waits for the returned permit to drop
+    pub async fn write_permit(&self) -> tokio::sync::RwLockReadGuard<'_, ()> {
+        self.write_gate.read().await
+    }
+
+    /// Hold off batch writers until the returned guard is dropped
+    ///
+    /// Waits for in-flight batches to commit first.
+    pub async fn pause_batch_writes(&self) -> tokio::sync::RwLockWriteGuard<'_, ()> {
+        self.write_gate.write().await
+    }
+
     /// Begin a new database transaction
     pub async fn begin_transaction(&self) -> Result<sqlx::Transaction<sqlx::Sqlite>, super::DatabaseError> {
         self.pool.begin().await
@@ -552,6 +626,17 @@ pub struct StoredMarketEvent {
     pub data: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct StoredTradingSignal {
+    pub signal_id: String,
+    pub signal_type: String,
+    pub token_mint: String,
+    pub confidence: Option<f64>,
+    pub amount_sol: Option<f64>,
+    pub reason: Option<String>,
+    pub timestamp: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct AnalyticsData {
     pub session_start: i64,
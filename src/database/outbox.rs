@@ -0,0 +1,199 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, instrument};
+
+use crate::core::TradingSignal;
+use super::{BadgerDatabase, DatabaseError};
+
+/// Outbox entry tracking a signal from emission to execution
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct OutboxEntry {
+    pub signal_id: String,
+    pub signal_type: String,
+    pub token_mint: String,
+    pub payload: String, // JSON-encoded TradingSignal
+    pub signal_source: String, // "internal" or "external:<client>"
+    pub status: String, // "EMITTED", "PICKED_UP", "EXECUTED", "EXPIRED"
+    pub emitted_at: i64,
+    pub picked_up_at: Option<i64>,
+    pub executed_at: Option<i64>,
+    pub expires_at: i64,
+    pub execution_ref: Option<String>,
+}
+
+/// Outbox status counts for monitoring
+#[derive(Debug, Clone, Default)]
+pub struct OutboxStats {
+    pub emitted: i64,
+    pub picked_up: i64,
+    pub executed: i64,
+    pub expired: i64,
+}
+
+/// Persistent outbox of emitted trading signals with execution acknowledgment
+pub struct SignalOutbox {
+    db: Arc<BadgerDatabase>,
+    default_ttl_secs: i64,
+}
+
+impl SignalOutbox {
+    pub fn new(db: Arc<BadgerDatabase>, default_ttl_secs: i64) -> Self {
+        Self { db, default_ttl_secs }
+    }
+
+    /// Initialize outbox schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing signal outbox schema");
+
+        let create_signal_outbox = r#"
+            CREATE TABLE IF NOT EXISTS signal_outbox (
+                signal_id TEXT PRIMARY KEY,
+                signal_type TEXT NOT NULL,
+                token_mint TEXT NOT NULL,
+                payload TEXT NOT NULL,
+                signal_source TEXT NOT NULL DEFAULT 'internal',
+                status TEXT NOT NULL DEFAULT 'EMITTED' CHECK (status IN ('EMITTED', 'PICKED_UP', 'EXECUTED', 'EXPIRED')),
+                emitted_at INTEGER NOT NULL,
+                picked_up_at INTEGER,
+                executed_at INTEGER,
+                expires_at INTEGER NOT NULL,
+                execution_ref TEXT
+            )
+        "#;
+
+        sqlx::query(create_signal_outbox)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create signal_outbox table: {}", e)))?;
+
+        let create_indexes = vec![
+            "CREATE INDEX IF NOT EXISTS idx_signal_outbox_status ON signal_outbox(status)",
+            "CREATE INDEX IF NOT EXISTS idx_signal_outbox_expires ON signal_outbox(expires_at)",
+            "CREATE INDEX IF NOT EXISTS idx_signal_outbox_source ON signal_outbox(signal_source)",
+        ];
+
+        for index_sql in create_indexes {
+            sqlx::query(index_sql)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+        }
+
+        info!("✅ Signal outbox schema initialized");
+        Ok(())
+    }
+
+    /// Record an emitted signal and where it came from; returns the outbox signal id
+    pub async fn record_emitted(&self, signal: &TradingSignal, signal_source: &str) -> Result<String, DatabaseError> {
+        let signal_id = signal.get_signal_id();
+        let now = Utc::now().timestamp();
+        let payload = serde_json::to_string(signal)
+            .map_err(|e| DatabaseError::SerializationError(format!("Failed to serialize signal: {}", e)))?;
+
+        sqlx::query(r#"
+            INSERT INTO signal_outbox (signal_id, signal_type, token_mint, payload, signal_source, status, emitted_at, expires_at)
+            VALUES (?, ?, ?, ?, ?, 'EMITTED', ?, ?)
+        "#)
+        .bind(&signal_id)
+        .bind(signal.get_signal_type())
+        .bind(signal.get_token_mint())
+        .bind(payload)
+        .bind(signal_source)
+        .bind(now)
+        .bind(now + self.default_ttl_secs)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record emitted signal: {}", e)))?;
+
+        debug!("📮 Signal {} added to outbox", signal_id);
+        Ok(signal_id)
+    }
+
+    /// Acknowledge that the execution engine picked up a signal
+    pub async fn mark_picked_up(&self, signal_id: &str) -> Result<(), DatabaseError> {
+        sqlx::query(
+            "UPDATE signal_outbox SET status = 'PICKED_UP', picked_up_at = ? WHERE signal_id = ? AND status = 'EMITTED'"
+        )
+        .bind(Utc::now().timestamp())
+        .bind(signal_id)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to mark signal picked up: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Acknowledge that a signal was executed
+    pub async fn mark_executed(&self, signal_id: &str, execution_ref: Option<&str>) -> Result<(), DatabaseError> {
+        sqlx::query(r#"
+            UPDATE signal_outbox SET status = 'EXECUTED', executed_at = ?, execution_ref = ?
+            WHERE signal_id = ? AND status IN ('EMITTED', 'PICKED_UP')
+        "#)
+        .bind(Utc::now().timestamp())
+        .bind(execution_ref)
+        .bind(signal_id)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to mark signal executed: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Expire signals past their deadline that were never executed
+    ///
+    /// # Returns
+    /// * Entries that just expired, so callers can alert on them
+    #[instrument(skip(self))]
+    pub async fn expire_stale(&self) -> Result<Vec<OutboxEntry>, DatabaseError> {
+        let now = Utc::now().timestamp();
+
+        let mut tx = self.db.begin_transaction().await?;
+
+        let stale = sqlx::query_as::<_, OutboxEntry>(
+            "SELECT * FROM signal_outbox WHERE status IN ('EMITTED', 'PICKED_UP') AND expires_at <= ?"
+        )
+        .bind(now)
+        .fetch_all(&mut tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch stale signals: {}", e)))?;
+
+        sqlx::query(
+            "UPDATE signal_outbox SET status = 'EXPIRED' WHERE status IN ('EMITTED', 'PICKED_UP') AND expires_at <= ?"
+        )
+        .bind(now)
+        .execute(&mut tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to expire stale signals: {}", e)))?;
+
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit signal expiry: {}", e)))?;
+
+        Ok(stale)
+    }
+
+    /// Outbox status counts
+    pub async fn get_stats(&self) -> Result<OutboxStats, DatabaseError> {
+        let counts = sqlx::query_as::<_, (String, i64)>(
+            "SELECT status, COUNT(*) FROM signal_outbox GROUP BY status"
+        )
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch outbox stats: {}", e)))?;
+
+        let mut stats = OutboxStats::default();
+        for (status, count) in counts {
+            match status.as_str() {
+                "EMITTED" => stats.emitted = count,
+                "PICKED_UP" => stats.picked_up = count,
+                "EXECUTED" => stats.executed = count,
+                "EXPIRED" => stats.expired = count,
+                _ => {}
+            }
+        }
+
+        Ok(stats)
+    }
+}
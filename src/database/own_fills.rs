@@ -0,0 +1,234 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, instrument};
+
+use super::{BadgerDatabase, DatabaseError};
+
+pub const OWN_FILL_PENDING: &str = "PENDING";
+pub const OWN_FILL_PARSED: &str = "PARSED";
+/// The transaction could not be fetched or was not a plain SOL <-> token swap
+pub const OWN_FILL_UNPARSED: &str = "UNPARSED";
+
+/// Configuration for own fill parsing
+#[derive(Debug, Clone)]
+pub struct OwnFillConfig {
+    /// How often pending fills are fetched (seconds)
+    pub poll_interval_secs: u64,
+    /// Transactions fetched per poll
+    pub batch_size: i64,
+    /// Failed fetches after which a fill is given up as unparsed
+    pub max_attempts: i64,
+}
+
+impl Default for OwnFillConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_secs: 5,
+            batch_size: 25,
+            max_attempts: 10,
+        }
+    }
+}
+
+/// One of our swaps and, once parsed, what it actually moved
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct OwnFill {
+    pub signature: String,
+    pub wallet: String,
+    pub token_mint: String,
+    /// "BUY" or "SELL"
+    pub side: String,
+    pub status: String,
+    /// Position the fill was applied to
+    pub position_id: Option<i64>,
+    /// Tokens received or sold (UI units)
+    pub token_amount: Option<f64>,
+    /// SOL paid or received, excluding network fees and token account rent
+    pub sol_amount: Option<f64>,
+    pub fee_sol: Option<f64>,
+    pub price_sol: Option<f64>,
+    pub slot: Option<i64>,
+    pub attempts: i64,
+    pub observed_at: i64,
+    pub parsed_at: Option<i64>,
+}
+
+/// Our own swaps, parsed from their confirmed transactions for exact accounting
+///
+/// Swaps by our wallets are queued as they are seen in the stream. Each is
+/// then fetched once confirmed and decoded from pre/post balances, so
+/// positions carry what was really received and paid instead of the
+/// estimate they were opened or closed at.
+pub struct OwnFillLedger {
+    db: Arc<BadgerDatabase>,
+    config: OwnFillConfig,
+}
+
+impl OwnFillLedger {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<OwnFillConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+        }
+    }
+
+    pub fn config(&self) -> &OwnFillConfig {
+        &self.config
+    }
+
+    /// Initialize own fill schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing own fill schema");
+
+        let create_own_fills = r#"
+            CREATE TABLE IF NOT EXISTS own_fills (
+                signature TEXT PRIMARY KEY,
+                wallet TEXT NOT NULL,
+                token_mint TEXT NOT NULL,
+                side TEXT NOT NULL CHECK (side IN ('BUY', 'SELL')),
+                status TEXT NOT NULL DEFAULT 'PENDING' CHECK (status IN ('PENDING', 'PARSED', 'UNPARSED')),
+                position_id INTEGER,
+                token_amount REAL,
+                sol_amount REAL,
+                fee_sol REAL,
+                price_sol REAL,
+                slot INTEGER,
+                attempts INTEGER NOT NULL DEFAULT 0,
+                observed_at INTEGER NOT NULL,
+                parsed_at INTEGER
+            )
+        "#;
+
+        sqlx::query(create_own_fills)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create own_fills table: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_own_fills_status ON own_fills(status, observed_at)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_own_fills_position ON own_fills(position_id)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        info!("✅ Own fill schema initialized");
+        Ok(())
+    }
+
+    /// Queue a swap by one of our wallets for parsing; returns false if it was already queued
+    pub async fn enqueue(&self, signature: &str, wallet: &str, token_mint: &str, side: &str) -> Result<bool, DatabaseError> {
+        let queued = sqlx::query(
+            "INSERT OR IGNORE INTO own_fills (signature, wallet, token_mint, side, observed_at) VALUES (?, ?, ?, ?, ?)"
+        )
+        .bind(signature)
+        .bind(wallet)
+        .bind(token_mint)
+        .bind(side)
+        .bind(Utc::now().timestamp())
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to queue own fill: {}", e)))?
+        .rows_affected() > 0;
+        Ok(queued)
+    }
+
+    /// Oldest fills still waiting to be parsed
+    pub async fn pending(&self) -> Result<Vec<OwnFill>, DatabaseError> {
+        sqlx::query_as::<_, OwnFill>("SELECT * FROM own_fills WHERE status = 'PENDING' ORDER BY observed_at LIMIT ?")
+            .bind(self.config.batch_size)
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch pending own fills: {}", e)))
+    }
+
+    /// Position a parsed fill belongs to, if one is waiting for it
+    ///
+    /// A buy matches the newest open position in the token without a fill; a
+    /// sell the newest closed one.
+    pub async fn position_for(&self, fill: &OwnFill) -> Result<Option<i64>, DatabaseError> {
+        let status = if fill.side == "BUY" { "OPEN" } else { "CLOSED" };
+        sqlx::query_scalar::<_, i64>(r#"
+            SELECT id FROM positions
+            WHERE token_mint = ? AND status = ?
+              AND id NOT IN (SELECT position_id FROM own_fills WHERE side = ? AND position_id IS NOT NULL)
+            ORDER BY COALESCE(exit_timestamp, entry_timestamp) DESC
+            LIMIT 1
+        "#)
+        .bind(&fill.token_mint)
+        .bind(status)
+        .bind(&fill.side)
+        .fetch_optional(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to match own fill: {}", e)))
+    }
+
+    /// Parsed entry fill of a position, e.g. for its fees when the exit is parsed
+    pub async fn entry_fill(&self, position_id: i64) -> Result<Option<OwnFill>, DatabaseError> {
+        sqlx::query_as::<_, OwnFill>("SELECT * FROM own_fills WHERE position_id = ? AND side = 'BUY' AND status = 'PARSED'")
+            .bind(position_id)
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch entry fill: {}", e)))
+    }
+
+    /// Store what a swap actually moved
+    ///
+    /// # Arguments
+    /// * `token_amount` - Tokens received or sold (UI units)
+    /// * `sol_amount` - SOL paid or received, excluding network fees and token account rent
+    /// * `fee_sol` - Network fee paid by our wallet
+    /// * `position_id` - Position the fill was applied to
+    pub async fn record_parsed(
+        &self,
+        signature: &str,
+        token_amount: f64,
+        sol_amount: f64,
+        fee_sol: f64,
+        slot: u64,
+        position_id: Option<i64>,
+    ) -> Result<(), DatabaseError> {
+        sqlx::query(r#"
+            UPDATE own_fills
+            SET status = 'PARSED', position_id = ?, token_amount = ?, sol_amount = ?, fee_sol = ?,
+                price_sol = ?, slot = ?, attempts = attempts + 1, parsed_at = ?
+            WHERE signature = ?
+        "#)
+        .bind(position_id)
+        .bind(token_amount)
+        .bind(sol_amount)
+        .bind(fee_sol)
+        .bind(if token_amount > 0.0 { sol_amount / token_amount } else { 0.0 })
+        .bind(slot as i64)
+        .bind(Utc::now().timestamp())
+        .bind(signature)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record own fill: {}", e)))?;
+
+        debug!("🧾 Parsed own fill {}: {} tokens for {:.6} SOL", signature, token_amount, sol_amount);
+        Ok(())
+    }
+
+    /// Count a failed fetch; the fill is given up after `max_attempts`, or at once when `give_up` is set
+    pub async fn record_failed(&self, signature: &str, give_up: bool) -> Result<(), DatabaseError> {
+        sqlx::query(r#"
+            UPDATE own_fills
+            SET attempts = attempts + 1,
+                status = CASE WHEN ? OR attempts + 1 >= ? THEN 'UNPARSED' ELSE status END
+            WHERE signature = ?
+        "#)
+        .bind(give_up)
+        .bind(self.config.max_attempts)
+        .bind(signature)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record own fill attempt: {}", e)))?;
+        Ok(())
+    }
+}
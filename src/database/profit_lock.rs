@@ -0,0 +1,305 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, instrument, warn};
+
+use super::{BadgerDatabase, DatabaseError};
+
+/// Conversion proposed and waiting for the operator to execute it
+pub const LOCK_PENDING: &str = "PENDING";
+/// Conversion landed; its USDC is held in the destination wallet
+pub const LOCK_COMPLETED: &str = "COMPLETED";
+/// Conversion abandoned; its profit counts as unlocked again
+pub const LOCK_FAILED: &str = "FAILED";
+
+/// Configuration for locking realized profit into USDC
+#[derive(Debug, Clone)]
+pub struct ProfitLockConfig {
+    /// Share of new realized profit converted to USDC (0.0 disables the policy)
+    pub lock_share: f64,
+    /// Smallest conversion worth proposing (SOL)
+    pub min_lock_sol: f64,
+    /// Largest shortfall of received USDC against the proposal's expectation (basis points)
+    pub max_shortfall_bps: f64,
+    /// Wallet the USDC is held in (trading or cold wallet)
+    pub destination: Option<String>,
+}
+
+impl Default for ProfitLockConfig {
+    fn default() -> Self {
+        Self {
+            lock_share: 0.0,
+            min_lock_sol: 0.25,
+            max_shortfall_bps: 100.0,
+            destination: None,
+        }
+    }
+}
+
+impl ProfitLockConfig {
+    /// Defaults with the locked share from `BADGER_PROFIT_LOCK_SHARE` (0-1)
+    ///
+    /// The USDC goes to the first trading wallet (`BADGER_OWN_WALLETS`), or to
+    /// the cold wallet (`BADGER_COLD_WALLET`) with `BADGER_PROFIT_LOCK_WALLET=cold`.
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(share) = std::env::var("BADGER_PROFIT_LOCK_SHARE").ok().and_then(|v| v.parse::<f64>().ok()) {
+            config.lock_share = share.clamp(0.0, 1.0);
+        }
+        config.destination = match std::env::var("BADGER_PROFIT_LOCK_WALLET").as_deref() {
+            Ok("cold") => std::env::var("BADGER_COLD_WALLET").ok(),
+            _ => std::env::var("BADGER_OWN_WALLETS").ok()
+                .and_then(|wallets| wallets.split(',').map(str::trim).find(|w| !w.is_empty()).map(str::to_string)),
+        };
+        config
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.lock_share > 0.0
+    }
+}
+
+/// Conversion of realized profit from SOL into USDC
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ProfitLock {
+    pub id: i64,
+    /// Total realized P&L when the lock was proposed; the next lock only takes profit above it
+    pub realized_pnl: f64,
+    pub amount_sol: f64,
+    /// SOL price the proposal was sized at (USD)
+    pub sol_usd: f64,
+    pub expected_usdc: f64,
+    pub destination: String,
+    pub status: String, // "PENDING", "COMPLETED", "FAILED"
+    pub usdc_received: Option<f64>,
+    /// How much less USDC arrived than expected (basis points)
+    pub shortfall_bps: Option<f64>,
+    pub tx_signature: Option<String>,
+    pub created_at: i64,
+    pub completed_at: Option<i64>,
+}
+
+/// Completion of a conversion reported by the operator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfitLockFill {
+    pub id: i64,
+    pub tx_signature: String,
+    /// USDC that arrived in the destination wallet
+    pub usdc_received: f64,
+}
+
+/// Profit moved out of SOL so far
+#[derive(Debug, Clone, Default, Serialize, Deserialize, FromRow)]
+pub struct ProfitLockSummary {
+    /// SOL converted by completed locks
+    pub locked_sol: f64,
+    /// USDC received by completed locks
+    pub locked_usdc: f64,
+    /// SOL in proposed conversions not yet executed
+    pub pending_sol: f64,
+}
+
+/// Ledger of realized profit locked into USDC
+///
+/// Each time realized profit grows past the last lock, the configured share
+/// of the new profit is proposed for conversion to USDC, held in the trading
+/// or cold wallet, so gains already banked do not ride SOL drawdowns. This
+/// process holds no signer: the operator executes the swap and reports it,
+/// and the fill is validated against the proposal before it is booked.
+/// Locked SOL no longer counts as sweepable profit.
+pub struct ProfitLockLedger {
+    db: Arc<BadgerDatabase>,
+    config: ProfitLockConfig,
+}
+
+impl ProfitLockLedger {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<ProfitLockConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+        }
+    }
+
+    pub fn config(&self) -> &ProfitLockConfig {
+        &self.config
+    }
+
+    /// Initialize profit lock schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing profit lock schema");
+
+        let create_profit_locks = r#"
+            CREATE TABLE IF NOT EXISTS profit_locks (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                realized_pnl REAL NOT NULL,
+                amount_sol REAL NOT NULL CHECK (amount_sol > 0),
+                sol_usd REAL NOT NULL,
+                expected_usdc REAL NOT NULL,
+                destination TEXT NOT NULL,
+                status TEXT NOT NULL DEFAULT 'PENDING'
+                    CHECK (status IN ('PENDING', 'COMPLETED', 'FAILED')),
+                usdc_received REAL,
+                shortfall_bps REAL,
+                tx_signature TEXT UNIQUE,
+                created_at INTEGER NOT NULL,
+                completed_at INTEGER
+            )
+        "#;
+
+        sqlx::query(create_profit_locks)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create profit_locks table: {}", e)))?;
+
+        info!("✅ Profit lock schema initialized");
+        Ok(())
+    }
+
+    /// SOL converted and pending, and the USDC received
+    pub async fn summary(&self) -> Result<ProfitLockSummary, DatabaseError> {
+        sqlx::query_as::<_, ProfitLockSummary>(r#"
+            SELECT
+                COALESCE(SUM(CASE WHEN status = 'COMPLETED' THEN amount_sol ELSE 0 END), 0.0) AS locked_sol,
+                COALESCE(SUM(CASE WHEN status = 'COMPLETED' THEN usdc_received ELSE 0 END), 0.0) AS locked_usdc,
+                COALESCE(SUM(CASE WHEN status = 'PENDING' THEN amount_sol ELSE 0 END), 0.0) AS pending_sol
+            FROM profit_locks
+        "#)
+        .fetch_one(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to summarize profit locks: {}", e)))
+    }
+
+    /// SOL set aside by completed and pending locks; not available to cold sweeps
+    pub async fn total_locked_sol(&self) -> Result<f64, DatabaseError> {
+        let summary = self.summary().await?;
+        Ok(summary.locked_sol + summary.pending_sol)
+    }
+
+    /// Propose locking the configured share of profit realized since the last lock
+    ///
+    /// # Returns
+    /// * `Result<Option<ProfitLock>, DatabaseError>` - The new proposal, or None when the
+    ///   policy is off, a lock is still pending or there is too little new profit
+    pub async fn propose(&self, total_realized_pnl: f64, sol_usd: f64) -> Result<Option<ProfitLock>, DatabaseError> {
+        if !self.config.is_enabled() {
+            return Ok(None);
+        }
+        let Some(destination) = &self.config.destination else {
+            return Err(DatabaseError::QueryError("Profit lock has no destination wallet".to_string()));
+        };
+        // NaN and infinite prices are as unusable as non-positive ones
+        if !sol_usd.is_finite() || sol_usd <= 0.0 {
+            return Err(DatabaseError::QueryError(format!("Invalid SOL price for profit lock: {}", sol_usd)));
+        }
+        if self.summary().await?.pending_sol > 0.0 {
+            debug!("Profit lock held: a conversion is still pending");
+            return Ok(None);
+        }
+
+        let high_water = sqlx::query_scalar::<_, Option<f64>>(
+            "SELECT MAX(realized_pnl) FROM profit_locks WHERE status = 'COMPLETED'"
+        )
+        .fetch_one(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch profit lock high-water mark: {}", e)))?
+        .unwrap_or(0.0);
+
+        let amount_sol = (total_realized_pnl - high_water) * self.config.lock_share;
+        if amount_sol < self.config.min_lock_sol {
+            debug!("No profit lock: {:.4} SOL of new profit to lock", amount_sol.max(0.0));
+            return Ok(None);
+        }
+
+        let inserted = sqlx::query(r#"
+            INSERT INTO profit_locks (realized_pnl, amount_sol, sol_usd, expected_usdc, destination, created_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(total_realized_pnl)
+        .bind(amount_sol)
+        .bind(sol_usd)
+        .bind(amount_sol * sol_usd)
+        .bind(destination)
+        .bind(Utc::now().timestamp())
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to propose profit lock: {}", e)))?;
+
+        let lock = self.get_lock(inserted.last_insert_rowid()).await?;
+        if let Some(lock) = &lock {
+            info!("🔒 Profit lock #{} proposed: {:.4} SOL -> ~{:.2} USDC to {}",
+                lock.id, lock.amount_sol, lock.expected_usdc, lock.destination);
+        }
+        Ok(lock)
+    }
+
+    pub async fn get_lock(&self, id: i64) -> Result<Option<ProfitLock>, DatabaseError> {
+        sqlx::query_as::<_, ProfitLock>("SELECT * FROM profit_locks WHERE id = ?")
+            .bind(id)
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch profit lock: {}", e)))
+    }
+
+    /// Book an executed conversion after validating it against its proposal
+    ///
+    /// Rejected when the lock is not pending, the signature is missing or
+    /// already booked, or less USDC arrived than the proposal allows for.
+    pub async fn complete(&self, fill: &ProfitLockFill) -> Result<ProfitLock, DatabaseError> {
+        let lock = self.get_lock(fill.id).await?
+            .ok_or_else(|| DatabaseError::QueryError(format!("No profit lock #{}", fill.id)))?;
+        if lock.status != LOCK_PENDING {
+            return Err(DatabaseError::QueryError(format!("Profit lock #{} is {}", lock.id, lock.status)));
+        }
+        if fill.tx_signature.trim().is_empty() {
+            return Err(DatabaseError::QueryError("Profit lock fill needs a transaction signature".to_string()));
+        }
+        if !fill.usdc_received.is_finite() || fill.usdc_received <= 0.0 {
+            return Err(DatabaseError::QueryError(format!("Invalid USDC received: {}", fill.usdc_received)));
+        }
+        let shortfall_bps = (lock.expected_usdc - fill.usdc_received) / lock.expected_usdc * 10_000.0;
+        if shortfall_bps > self.config.max_shortfall_bps {
+            return Err(DatabaseError::QueryError(format!(
+                "Profit lock #{} received {:.2} USDC, {:.0} bps short of {:.2} (limit {:.0} bps); mark it failed or re-check the fill",
+                lock.id, fill.usdc_received, shortfall_bps, lock.expected_usdc, self.config.max_shortfall_bps
+            )));
+        }
+
+        sqlx::query(r#"
+            UPDATE profit_locks
+            SET status = 'COMPLETED', usdc_received = ?, shortfall_bps = ?, tx_signature = ?, completed_at = ?
+            WHERE id = ? AND status = 'PENDING'
+        "#)
+        .bind(fill.usdc_received)
+        .bind(shortfall_bps)
+        .bind(&fill.tx_signature)
+        .bind(Utc::now().timestamp())
+        .bind(lock.id)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to complete profit lock: {}", e)))?;
+
+        info!("🔒 Profit lock #{} completed: {:.4} SOL -> {:.2} USDC in {}",
+            lock.id, lock.amount_sol, fill.usdc_received, lock.destination);
+        self.get_lock(lock.id).await?
+            .ok_or_else(|| DatabaseError::QueryError(format!("Profit lock #{} disappeared", lock.id)))
+    }
+
+    /// Abandon a pending conversion; its profit is proposed again later
+    pub async fn fail(&self, id: i64) -> Result<ProfitLock, DatabaseError> {
+        let updated = sqlx::query("UPDATE profit_locks SET status = 'FAILED', completed_at = ? WHERE id = ? AND status = 'PENDING'")
+            .bind(Utc::now().timestamp())
+            .bind(id)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fail profit lock: {}", e)))?;
+
+        if updated.rows_affected() == 0 {
+            return Err(DatabaseError::QueryError(format!("No pending profit lock #{}", id)));
+        }
+        warn!("🔒 Profit lock #{} marked failed", id);
+        self.get_lock(id).await?
+            .ok_or_else(|| DatabaseError::QueryError(format!("Profit lock #{} disappeared", id)))
+    }
+}
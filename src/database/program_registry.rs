@@ -0,0 +1,192 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, instrument};
+
+use super::{BadgerDatabase, DatabaseError};
+use crate::core::program_registry::{self, ProgramInfo, ProgramKind};
+
+/// Program labelled at runtime, as stored in `program_registry`
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ProgramLabel {
+    pub program_id: String,
+    pub venue: String,
+    /// "AMM", "LAUNCHPAD", "AGGREGATOR", "LENDING" or "INFRASTRUCTURE"
+    pub kind: String,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+impl ProgramLabel {
+    fn info(&self) -> Option<ProgramInfo> {
+        Some(ProgramInfo {
+            program_id: self.program_id.clone(),
+            venue: self.venue.clone(),
+            kind: ProgramKind::parse(&self.kind)?,
+        })
+    }
+}
+
+/// Unknown program with its sightings across restarts
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct UnknownProgramRow {
+    pub program_id: String,
+    pub seen_count: i64,
+    pub first_seen: i64,
+    pub last_seen: i64,
+}
+
+/// Runtime program labels and unknown program counts, edited through the control channel
+///
+/// Every change is written through to the process-wide program registry
+/// parsers classify with.
+pub struct ProgramRegistryStore {
+    db: Arc<BadgerDatabase>,
+}
+
+impl ProgramRegistryStore {
+    pub fn new(db: Arc<BadgerDatabase>) -> Self {
+        Self { db }
+    }
+
+    /// Initialize program registry schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing program registry schema");
+
+        let create_program_registry = r#"
+            CREATE TABLE IF NOT EXISTS program_registry (
+                program_id TEXT PRIMARY KEY,
+                venue TEXT NOT NULL,
+                kind TEXT NOT NULL CHECK (kind IN ('AMM', 'LAUNCHPAD', 'AGGREGATOR', 'LENDING', 'INFRASTRUCTURE')),
+                updated_at INTEGER NOT NULL
+            )
+        "#;
+
+        let create_unknown_programs = r#"
+            CREATE TABLE IF NOT EXISTS unknown_programs (
+                program_id TEXT PRIMARY KEY,
+                seen_count INTEGER NOT NULL,
+                first_seen INTEGER NOT NULL,
+                last_seen INTEGER NOT NULL
+            )
+        "#;
+
+        for (statement, table) in [
+            (create_program_registry, "program_registry"),
+            (create_unknown_programs, "unknown_programs"),
+        ] {
+            sqlx::query(statement)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create {} table: {}", table, e)))?;
+        }
+
+        info!("✅ Program registry schema initialized");
+        Ok(())
+    }
+
+    /// Reload every runtime label into the registry; returns how many there are
+    pub async fn load(&self) -> Result<usize, DatabaseError> {
+        let programs: Vec<ProgramInfo> = self.list().await?.iter().filter_map(ProgramLabel::info).collect();
+        let count = programs.len();
+        program_registry::replace_registered(programs);
+        Ok(count)
+    }
+
+    /// Create or replace the label of a program
+    pub async fn upsert(&self, label: &ProgramLabel) -> Result<ProgramLabel, DatabaseError> {
+        let mut label = label.clone();
+        label.kind = label.kind.trim().to_ascii_uppercase();
+        label.updated_at = Utc::now().timestamp();
+        let info = label.info()
+            .ok_or_else(|| DatabaseError::SerializationError(format!("Unknown program kind {:?}", label.kind)))?;
+
+        sqlx::query("INSERT OR REPLACE INTO program_registry (program_id, venue, kind, updated_at) VALUES (?, ?, ?, ?)")
+            .bind(&label.program_id)
+            .bind(&label.venue)
+            .bind(&label.kind)
+            .bind(label.updated_at)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to save program label: {}", e)))?;
+        sqlx::query("DELETE FROM unknown_programs WHERE program_id = ?")
+            .bind(&label.program_id)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to clear unknown program: {}", e)))?;
+
+        program_registry::register(info);
+        debug!("🏷️ Program {} labelled {} ({})", label.program_id, label.venue, label.kind);
+        Ok(label)
+    }
+
+    /// Remove a program's runtime label; returns false if it had none
+    pub async fn delete(&self, program_id: &str) -> Result<bool, DatabaseError> {
+        let deleted = sqlx::query("DELETE FROM program_registry WHERE program_id = ?")
+            .bind(program_id)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to delete program label: {}", e)))?
+            .rows_affected() > 0;
+
+        program_registry::unregister(program_id);
+        Ok(deleted)
+    }
+
+    /// All runtime labels
+    pub async fn list(&self) -> Result<Vec<ProgramLabel>, DatabaseError> {
+        sqlx::query_as::<_, ProgramLabel>("SELECT * FROM program_registry ORDER BY venue")
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch program labels: {}", e)))
+    }
+
+    /// Add unknown program sightings counted since the last flush
+    ///
+    /// # Arguments
+    /// * `alert_count` - Sightings at which an unknown program is surfaced for triage
+    ///
+    /// # Returns
+    /// * `Result<Vec<UnknownProgramRow>, DatabaseError>` - Programs that reached `alert_count` with this flush
+    pub async fn flush_unknown(&self, alert_count: i64) -> Result<Vec<UnknownProgramRow>, DatabaseError> {
+        let mut crossed = Vec::new();
+        for unknown in program_registry::take_unknown() {
+            sqlx::query(r#"
+                INSERT INTO unknown_programs (program_id, seen_count, first_seen, last_seen)
+                VALUES (?, ?, ?, ?)
+                ON CONFLICT (program_id) DO UPDATE SET
+                    seen_count = seen_count + excluded.seen_count,
+                    last_seen = excluded.last_seen
+            "#)
+            .bind(&unknown.program_id)
+            .bind(unknown.seen_count as i64)
+            .bind(unknown.first_seen)
+            .bind(unknown.last_seen)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to record unknown program: {}", e)))?;
+
+            let row = sqlx::query_as::<_, UnknownProgramRow>("SELECT * FROM unknown_programs WHERE program_id = ?")
+                .bind(&unknown.program_id)
+                .fetch_one(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch unknown program: {}", e)))?;
+            let before = row.seen_count - unknown.seen_count as i64;
+            if before < alert_count && row.seen_count >= alert_count {
+                crossed.push(row);
+            }
+        }
+        Ok(crossed)
+    }
+
+    /// Most seen unknown programs
+    pub async fn unknown(&self, limit: i64) -> Result<Vec<UnknownProgramRow>, DatabaseError> {
+        sqlx::query_as::<_, UnknownProgramRow>("SELECT * FROM unknown_programs ORDER BY seen_count DESC LIMIT ?")
+            .bind(limit)
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch unknown programs: {}", e)))
+    }
+}
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, instrument};
+
+use super::{BadgerDatabase, DatabaseError};
+
+/// Configuration for the daily cold-storage sweep
+#[derive(Debug, Clone)]
+pub struct SweepConfig {
+    /// Realized profit kept in the hot wallet as trading float (SOL)
+    pub float_sol: f64,
+    /// Smallest sweep worth proposing (SOL)
+    pub min_sweep_sol: f64,
+    /// Hour of day (UTC) at which the sweep is proposed
+    pub sweep_hour_utc: u32,
+    /// Cold storage address receiving sweeps
+    pub destination: Option<String>,
+}
+
+impl Default for SweepConfig {
+    fn default() -> Self {
+        Self {
+            float_sol: 5.0,
+            min_sweep_sol: 0.5,
+            sweep_hour_utc: 0,
+            destination: None,
+        }
+    }
+}
+
+/// Proposed or completed transfer of realized profit to cold storage
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ColdSweep {
+    pub id: i64,
+    pub sweep_date: String, // YYYY-MM-DD (UTC)
+    pub realized_pnl: f64,
+    pub float_sol: f64,
+    pub amount_sol: f64,
+    pub destination: Option<String>,
+    pub status: String, // "PENDING_APPROVAL", "APPROVED", "REJECTED", "COMPLETED"
+    pub created_at: i64,
+    pub decided_at: Option<i64>,
+    pub tx_signature: Option<String>,
+}
+
+/// Daily schedule and journal of profit sweeps to cold storage
+///
+/// Sweeps are proposed once per day and only move funds after an operator
+/// approves them through the control channel.
+pub struct ColdSweepLedger {
+    db: Arc<BadgerDatabase>,
+    config: SweepConfig,
+}
+
+impl ColdSweepLedger {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<SweepConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+        }
+    }
+
+    pub fn config(&self) -> &SweepConfig {
+        &self.config
+    }
+
+    /// Initialize cold sweep schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing cold sweep schema");
+
+        let create_cold_sweeps = r#"
+            CREATE TABLE IF NOT EXISTS cold_sweeps (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                sweep_date TEXT NOT NULL UNIQUE,
+                realized_pnl REAL NOT NULL,
+                float_sol REAL NOT NULL,
+                amount_sol REAL NOT NULL,
+                destination TEXT,
+                status TEXT NOT NULL DEFAULT 'PENDING_APPROVAL'
+                    CHECK (status IN ('PENDING_APPROVAL', 'APPROVED', 'REJECTED', 'COMPLETED')),
+                created_at INTEGER NOT NULL,
+                decided_at INTEGER,
+                tx_signature TEXT
+            )
+        "#;
+
+        sqlx::query(create_cold_sweeps)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create cold_sweeps table: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_cold_sweeps_status ON cold_sweeps(status)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        info!("✅ Cold sweep schema initialized");
+        Ok(())
+    }
+
+    /// Total already approved or swept to cold storage
+    pub async fn total_swept(&self) -> Result<f64, DatabaseError> {
+        sqlx::query_scalar::<_, f64>(
+            "SELECT COALESCE(SUM(amount_sol), 0.0) FROM cold_sweeps WHERE status IN ('APPROVED', 'COMPLETED')"
+        )
+        .fetch_one(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to sum cold sweeps: {}", e)))
+    }
+
+    /// Propose today's sweep of realized profit above the float
+    ///
+    /// # Returns
+    /// * `Result<Option<ColdSweep>, DatabaseError>` - The new proposal, or None when
+    ///   there is nothing to sweep or today's sweep already exists
+    pub async fn propose_daily_sweep(&self, total_realized_pnl: f64) -> Result<Option<ColdSweep>, DatabaseError> {
+        let sweep_date = Utc::now().format("%Y-%m-%d").to_string();
+        let amount_sol = total_realized_pnl - self.total_swept().await? - self.config.float_sol;
+
+        if amount_sol < self.config.min_sweep_sol {
+            debug!("No cold sweep for {}: {:.4} SOL above float", sweep_date, amount_sol.max(0.0));
+            return Ok(None);
+        }
+
+        let inserted = sqlx::query(r#"
+            INSERT OR IGNORE INTO cold_sweeps (sweep_date, realized_pnl, float_sol, amount_sol, destination, created_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(&sweep_date)
+        .bind(total_realized_pnl)
+        .bind(self.config.float_sol)
+        .bind(amount_sol)
+        .bind(&self.config.destination)
+        .bind(Utc::now().timestamp())
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to propose cold sweep: {}", e)))?;
+
+        if inserted.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        let sweep = self.get_sweep(inserted.last_insert_rowid()).await?;
+        if let Some(sweep) = &sweep {
+            info!("🧊 Cold sweep #{} proposed: {:.4} SOL (awaiting approval)", sweep.id, sweep.amount_sol);
+        }
+        Ok(sweep)
+    }
+
+    pub async fn get_sweep(&self, id: i64) -> Result<Option<ColdSweep>, DatabaseError> {
+        sqlx::query_as::<_, ColdSweep>("SELECT * FROM cold_sweeps WHERE id = ?")
+            .bind(id)
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch cold sweep: {}", e)))
+    }
+
+    /// Approve or reject a pending sweep
+    pub async fn decide(&self, id: i64, approve: bool) -> Result<ColdSweep, DatabaseError> {
+        let updated = sqlx::query(
+            "UPDATE cold_sweeps SET status = ?, decided_at = ? WHERE id = ? AND status = 'PENDING_APPROVAL'"
+        )
+        .bind(if approve { "APPROVED" } else { "REJECTED" })
+        .bind(Utc::now().timestamp())
+        .bind(id)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to decide cold sweep: {}", e)))?;
+
+        if updated.rows_affected() == 0 {
+            return Err(DatabaseError::QueryError(format!("No pending cold sweep #{}", id)));
+        }
+
+        self.get_sweep(id).await?
+            .ok_or_else(|| DatabaseError::QueryError(format!("Cold sweep #{} disappeared", id)))
+    }
+
+    /// Record the transfer signature of an approved sweep
+    pub async fn mark_completed(&self, id: i64, tx_signature: &str) -> Result<(), DatabaseError> {
+        sqlx::query("UPDATE cold_sweeps SET status = 'COMPLETED', tx_signature = ? WHERE id = ? AND status = 'APPROVED'")
+            .bind(tx_signature)
+            .bind(id)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to complete cold sweep: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Sweep journal, newest first
+    pub async fn get_recent_sweeps(&self, limit: i64) -> Result<Vec<ColdSweep>, DatabaseError> {
+        sqlx::query_as::<_, ColdSweep>("SELECT * FROM cold_sweeps ORDER BY id DESC LIMIT ?")
+            .bind(limit)
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch cold sweeps: {}", e)))
+    }
+}
@@ -0,0 +1,173 @@
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, instrument};
+
+use super::{BadgerDatabase, DatabaseError};
+
+/// One swap sent (or attempted) by the trade executor
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TradeRecord {
+    pub id: Option<i64>,
+    pub token_mint: String,
+    pub token_symbol: Option<String>,
+    pub side: String, // "buy", "sell", "alert"
+    pub amount_sol: f64,
+    pub status: String, // "executed", "failed"
+    pub transaction_signature: Option<String>,
+    /// Network and priority fees paid (SOL)
+    pub gas_fee: Option<f64>,
+    /// Quoted price impact (percent)
+    pub slippage: Option<f64>,
+    pub actual_input_amount: Option<f64>,
+    pub actual_output_amount: Option<f64>,
+    pub profit_loss: Option<f64>,
+    pub error_message: Option<String>,
+    pub created_at: i64,
+}
+
+impl TradeRecord {
+    pub fn new(token_mint: String, token_symbol: Option<String>, side: String, amount_sol: f64, status: String) -> Self {
+        Self {
+            id: None,
+            token_mint,
+            token_symbol,
+            side,
+            amount_sol,
+            status,
+            transaction_signature: None,
+            gas_fee: None,
+            slippage: None,
+            actual_input_amount: None,
+            actual_output_amount: None,
+            profit_loss: None,
+            error_message: None,
+            created_at: Utc::now().timestamp(),
+        }
+    }
+}
+
+/// Totals over every recorded executor trade
+#[derive(Debug, Clone, Default, FromRow)]
+pub struct TradeRecordSummary {
+    pub executed: i64,
+    pub failed: i64,
+    pub total_fees_sol: f64,
+    /// SOL received from sells less SOL spent on buys and fees; open holdings count at zero
+    pub net_sol_flow: f64,
+    pub average_slippage_percent: f64,
+}
+
+/// Swaps sent by the strike trade executor
+///
+/// Kept apart from `positions`: the position tracker books simulated and
+/// replayed fills too, while these rows are on-chain transactions only.
+#[derive(Debug)]
+pub struct TradeRecordStore {
+    db: Arc<BadgerDatabase>,
+}
+
+impl TradeRecordStore {
+    pub fn new(db: Arc<BadgerDatabase>) -> Self {
+        Self { db }
+    }
+
+    /// Initialize trade record schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing trade record schema");
+
+        let create_trade_records = r#"
+            CREATE TABLE IF NOT EXISTS trade_records (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                token_mint TEXT NOT NULL,
+                token_symbol TEXT,
+                side TEXT NOT NULL CHECK (side IN ('buy', 'sell', 'alert')),
+                amount_sol REAL NOT NULL,
+                status TEXT NOT NULL CHECK (status IN ('executed', 'failed')),
+                transaction_signature TEXT,
+                gas_fee REAL,
+                slippage REAL,
+                actual_input_amount REAL,
+                actual_output_amount REAL,
+                profit_loss REAL,
+                error_message TEXT,
+                created_at INTEGER NOT NULL
+            )
+        "#;
+
+        sqlx::query(create_trade_records)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create trade_records table: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_trade_records_mint ON trade_records(token_mint, created_at)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        info!("✅ Trade record schema initialized");
+        Ok(())
+    }
+
+    /// Store one executor trade
+    ///
+    /// # Returns
+    /// * `Result<i64, DatabaseError>` - Id of the new row
+    pub async fn record_trade(&self, record: TradeRecord) -> Result<i64, DatabaseError> {
+        let result = sqlx::query(r#"
+            INSERT INTO trade_records (
+                token_mint, token_symbol, side, amount_sol, status, transaction_signature, gas_fee,
+                slippage, actual_input_amount, actual_output_amount, profit_loss, error_message, created_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(&record.token_mint)
+        .bind(&record.token_symbol)
+        .bind(&record.side)
+        .bind(record.amount_sol)
+        .bind(&record.status)
+        .bind(&record.transaction_signature)
+        .bind(record.gas_fee)
+        .bind(record.slippage)
+        .bind(record.actual_input_amount)
+        .bind(record.actual_output_amount)
+        .bind(record.profit_loss)
+        .bind(&record.error_message)
+        .bind(record.created_at)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record trade: {}", e)))?;
+
+        debug!("🧾 Recorded {} {} of {} ({})", record.status, record.side, record.token_mint, record.amount_sol);
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Most recent trades, newest first
+    pub async fn recent(&self, limit: i64) -> Result<Vec<TradeRecord>, DatabaseError> {
+        sqlx::query_as::<_, TradeRecord>("SELECT * FROM trade_records ORDER BY created_at DESC, id DESC LIMIT ?")
+            .bind(limit)
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch trade records: {}", e)))
+    }
+
+    /// Counts, fees, SOL flow and slippage over every recorded trade
+    pub async fn summary(&self) -> Result<TradeRecordSummary, DatabaseError> {
+        sqlx::query_as::<_, TradeRecordSummary>(r#"
+            SELECT
+                COUNT(CASE WHEN status = 'executed' THEN 1 END) as executed,
+                COUNT(CASE WHEN status = 'failed' THEN 1 END) as failed,
+                COALESCE(SUM(gas_fee), 0.0) as total_fees_sol,
+                COALESCE(SUM(CASE
+                    WHEN status = 'executed' AND side = 'sell' THEN actual_output_amount
+                    WHEN status = 'executed' AND side = 'buy' THEN -actual_input_amount
+                END), 0.0) - COALESCE(SUM(gas_fee), 0.0) as net_sol_flow,
+                COALESCE(AVG(slippage), 0.0) as average_slippage_percent
+            FROM trade_records
+        "#)
+        .fetch_one(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to summarize trade records: {}", e)))
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tracing::{debug, info, instrument};
+
+use super::{BadgerDatabase, DatabaseError};
+use super::audit_log::{
+    audit_value, ensure_deleted_at, record_audit, AuditContext, AUDIT_ACTION_CREATE, AUDIT_ACTION_DELETE,
+    AUDIT_ACTION_RESTORE, AUDIT_ACTION_UPDATE, AUDIT_ENTITY_WALLET_LABEL,
+};
+use crate::core::dex_types::utils::shorten_pubkey;
+
+/// A wallet we copy or watch
+pub const WALLET_LABEL_TRACKED: &str = "TRACKED";
+/// One of our own wallets
+pub const WALLET_LABEL_OWN: &str = "OWN";
+
+/// Human-friendly name for a wallet address
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct WalletLabel {
+    pub wallet_address: String,
+    pub label: String,
+    /// "TRACKED" or "OWN"
+    #[serde(default = "default_kind")]
+    pub kind: String,
+    #[serde(default)]
+    pub updated_at: i64,
+    /// Set when the label was deleted; deleted labels are kept for the audit trail
+    #[serde(default)]
+    pub deleted_at: Option<i64>,
+}
+
+fn default_kind() -> String {
+    WALLET_LABEL_TRACKED.to_string()
+}
+
+/// In-memory wallet label lookup shared by formatting code
+///
+/// Lookups are synchronous hash map reads, so log lines, alerts and reports
+/// can call them freely. Filled and kept current by `WalletLabelStore`.
+#[derive(Default)]
+pub struct WalletLabels {
+    labels: RwLock<HashMap<String, String>>,
+}
+
+impl WalletLabels {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Label of a wallet, if one is assigned
+    pub fn get(&self, wallet_address: &str) -> Option<String> {
+        self.labels.read().unwrap().get(wallet_address).cloned()
+    }
+
+    /// Compact name for reports: the label when known, otherwise the shortened address
+    pub fn label(&self, wallet_address: &str) -> String {
+        self.get(wallet_address).unwrap_or_else(|| shorten_pubkey(wallet_address))
+    }
+
+    /// Name for log lines and alerts: the label with the shortened address, otherwise the full address
+    pub fn display(&self, wallet_address: &str) -> String {
+        match self.get(wallet_address) {
+            Some(label) => format!("{} ({})", label, shorten_pubkey(wallet_address)),
+            None => wallet_address.to_string(),
+        }
+    }
+
+    /// Replace every labelled address in free text with its display name
+    pub fn annotate(&self, text: &str) -> String {
+        let labels = self.labels.read().unwrap();
+        let mut annotated = text.to_string();
+        for (wallet_address, label) in labels.iter() {
+            if annotated.contains(wallet_address.as_str()) {
+                annotated = annotated.replace(wallet_address.as_str(), &format!("{} ({})", label, shorten_pubkey(wallet_address)));
+            }
+        }
+        annotated
+    }
+
+    /// Number of labelled wallets
+    pub fn len(&self) -> usize {
+        self.labels.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn set(&self, wallet_address: &str, label: &str) {
+        self.labels.write().unwrap().insert(wallet_address.to_string(), label.to_string());
+    }
+
+    fn remove(&self, wallet_address: &str) {
+        self.labels.write().unwrap().remove(wallet_address);
+    }
+
+    fn replace_all(&self, labels: HashMap<String, String>) {
+        *self.labels.write().unwrap() = labels;
+    }
+}
+
+/// Wallet labels persisted in `wallet_labels`, edited through the control channel
+///
+/// Every change is written through to the shared `WalletLabels` lookup and
+/// recorded in the audit log. Deletes are soft.
+pub struct WalletLabelStore {
+    db: Arc<BadgerDatabase>,
+    labels: Arc<WalletLabels>,
+}
+
+impl WalletLabelStore {
+    pub fn new(db: Arc<BadgerDatabase>, labels: Arc<WalletLabels>) -> Self {
+        Self { db, labels }
+    }
+
+    /// The shared lookup this store keeps current
+    pub fn labels(&self) -> &Arc<WalletLabels> {
+        &self.labels
+    }
+
+    /// Initialize wallet label schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing wallet label schema");
+
+        let create_wallet_labels = r#"
+            CREATE TABLE IF NOT EXISTS wallet_labels (
+                wallet_address TEXT PRIMARY KEY,
+                label TEXT NOT NULL,
+                kind TEXT NOT NULL DEFAULT 'TRACKED' CHECK (kind IN ('TRACKED', 'OWN')),
+                updated_at INTEGER NOT NULL,
+                deleted_at INTEGER
+            )
+        "#;
+
+        sqlx::query(create_wallet_labels)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create wallet_labels table: {}", e)))?;
+
+        ensure_deleted_at(&self.db, "wallet_labels").await?;
+
+        info!("✅ Wallet label schema initialized");
+        Ok(())
+    }
+
+    /// Reload every label into the shared lookup; returns how many there are
+    pub async fn load(&self) -> Result<usize, DatabaseError> {
+        let labels = self.list().await?;
+        let count = labels.len();
+        self.labels.replace_all(labels.into_iter().map(|l| (l.wallet_address, l.label)).collect());
+        Ok(count)
+    }
+
+    /// Create or replace the label of a wallet, restoring a deleted one
+    pub async fn upsert(&self, label: &WalletLabel, context: &AuditContext) -> Result<WalletLabel, DatabaseError> {
+        let mut label = label.clone();
+        label.updated_at = Utc::now().timestamp();
+        label.deleted_at = None;
+
+        let mut tx = self.db.begin_transaction().await?;
+        let before = sqlx::query_as::<_, WalletLabel>("SELECT * FROM wallet_labels WHERE wallet_address = ?")
+            .bind(&label.wallet_address)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch wallet label: {}", e)))?;
+
+        sqlx::query(
+            "INSERT OR REPLACE INTO wallet_labels (wallet_address, label, kind, updated_at, deleted_at) VALUES (?, ?, ?, ?, NULL)"
+        )
+        .bind(&label.wallet_address)
+        .bind(&label.label)
+        .bind(&label.kind)
+        .bind(label.updated_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to save wallet label: {}", e)))?;
+
+        let action = match &before {
+            None => AUDIT_ACTION_CREATE,
+            Some(before) if before.deleted_at.is_some() => AUDIT_ACTION_RESTORE,
+            Some(_) => AUDIT_ACTION_UPDATE,
+        };
+        record_audit(&mut *tx, context, AUDIT_ENTITY_WALLET_LABEL, &label.wallet_address, action,
+            before.as_ref().and_then(audit_value), audit_value(&label)).await?;
+
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit wallet label: {}", e)))?;
+
+        self.labels.set(&label.wallet_address, &label.label);
+        debug!("🏷️ {} labelled {:?}", label.wallet_address, label.label);
+        Ok(label)
+    }
+
+    /// Soft-delete a wallet's label; returns false if it had none
+    pub async fn delete(&self, wallet_address: &str, context: &AuditContext) -> Result<bool, DatabaseError> {
+        let mut tx = self.db.begin_transaction().await?;
+        let Some(before) = sqlx::query_as::<_, WalletLabel>(
+            "SELECT * FROM wallet_labels WHERE wallet_address = ? AND deleted_at IS NULL"
+        )
+        .bind(wallet_address)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch wallet label: {}", e)))? else {
+            return Ok(false);
+        };
+
+        let mut after = before.clone();
+        after.deleted_at = Some(Utc::now().timestamp());
+        sqlx::query("UPDATE wallet_labels SET deleted_at = ? WHERE wallet_address = ?")
+            .bind(after.deleted_at)
+            .bind(wallet_address)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to delete wallet label: {}", e)))?;
+
+        record_audit(&mut *tx, context, AUDIT_ENTITY_WALLET_LABEL, wallet_address, AUDIT_ACTION_DELETE,
+            audit_value(&before), audit_value(&after)).await?;
+
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit wallet label: {}", e)))?;
+
+        self.labels.remove(wallet_address);
+        Ok(true)
+    }
+
+    /// All labelled wallets
+    pub async fn list(&self) -> Result<Vec<WalletLabel>, DatabaseError> {
+        sqlx::query_as::<_, WalletLabel>("SELECT * FROM wallet_labels WHERE deleted_at IS NULL ORDER BY label")
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch wallet labels: {}", e)))
+    }
+}
@@ -0,0 +1,406 @@
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Arc;
+use chrono::Utc;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tokio::sync::RwLock;
+use tracing::{debug, info, instrument};
+
+use super::{BadgerDatabase, DatabaseError};
+
+/// A group doesn't fire again on the same mint within this long (seconds)
+const SQUAD_REFIRE_SECS: i64 = 3600;
+
+/// Named squad of insiders that tend to buy together
+///
+/// Fires a composite signal when `min_members` distinct members buy the
+/// same mint within `window_secs`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchGroup {
+    pub name: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    pub members: Vec<String>,
+    /// Distinct members that must buy the mint
+    #[serde(default = "default_min_members")]
+    pub min_members: i64,
+    /// Window the buys must fall in (seconds)
+    #[serde(default = "default_window_secs")]
+    pub window_secs: i64,
+    /// Confidence of the composite buy signal
+    #[serde(default = "default_confidence")]
+    pub confidence: f64,
+    /// Size of the composite buy (SOL)
+    #[serde(default = "default_max_amount_sol")]
+    pub max_amount_sol: f64,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_min_members() -> i64 {
+    2
+}
+
+fn default_window_secs() -> i64 {
+    120
+}
+
+fn default_confidence() -> f64 {
+    0.9
+}
+
+fn default_max_amount_sol() -> f64 {
+    0.1
+}
+
+impl WatchGroup {
+    /// Reject groups that could never fire or fire on a single buy
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("watch group needs a name".to_string());
+        }
+        if self.min_members < 2 {
+            return Err(format!("{}: min_members must be at least 2", self.name));
+        }
+        let distinct: HashSet<&String> = self.members.iter().collect();
+        if (distinct.len() as i64) < self.min_members {
+            return Err(format!("{}: {} members cannot reach min_members {}", self.name, distinct.len(), self.min_members));
+        }
+        if self.window_secs <= 0 {
+            return Err(format!("{}: window_secs must be positive", self.name));
+        }
+        if !(0.0..=1.0).contains(&self.confidence) {
+            return Err(format!("{}: confidence must be between 0 and 1", self.name));
+        }
+        if self.max_amount_sol.is_nan() || self.max_amount_sol <= 0.0 {
+            return Err(format!("{}: max_amount_sol must be positive", self.name));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct WatchGroupRow {
+    name: String,
+    enabled: bool,
+    min_members: i64,
+    window_secs: i64,
+    confidence: f64,
+    max_amount_sol: f64,
+    updated_at: i64,
+}
+
+/// `[[group]]` tables of a watch group file
+#[derive(Debug, Deserialize)]
+struct WatchGroupFile {
+    #[serde(default)]
+    group: Vec<WatchGroup>,
+}
+
+/// Composite signal fired by a watch group
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SquadSignal {
+    pub group_name: String,
+    pub token_mint: String,
+    /// Members that bought within the window, comma-separated in buy order
+    pub members: String,
+    pub confidence: f64,
+    pub max_amount_sol: f64,
+    /// Time of the earliest buy counted
+    pub first_buy_at: i64,
+    pub fired_at: i64,
+}
+
+/// Watch groups of co-trading insiders, edited through the control channel
+/// or seeded from a TOML file
+///
+/// Group definitions are served from memory like copy settings; recent
+/// member buys per group and mint are only kept in memory.
+pub struct WatchGroupStore {
+    db: Arc<BadgerDatabase>,
+    cache: RwLock<Option<Vec<WatchGroup>>>,
+    /// Recent (wallet, time) buys per (group, mint)
+    recent_buys: DashMap<(String, String), Vec<(String, i64)>>,
+    /// Last time each (group, mint) fired
+    fired: DashMap<(String, String), i64>,
+}
+
+impl WatchGroupStore {
+    pub fn new(db: Arc<BadgerDatabase>) -> Self {
+        Self {
+            db,
+            cache: RwLock::new(None),
+            recent_buys: DashMap::new(),
+            fired: DashMap::new(),
+        }
+    }
+
+    /// Initialize watch group schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing watch group schema");
+
+        let create_watch_groups = r#"
+            CREATE TABLE IF NOT EXISTS watch_groups (
+                name TEXT PRIMARY KEY,
+                enabled BOOLEAN NOT NULL DEFAULT 1,
+                min_members INTEGER NOT NULL,
+                window_secs INTEGER NOT NULL,
+                confidence REAL NOT NULL,
+                max_amount_sol REAL NOT NULL,
+                updated_at INTEGER NOT NULL
+            )
+        "#;
+
+        let create_watch_group_members = r#"
+            CREATE TABLE IF NOT EXISTS watch_group_members (
+                group_name TEXT NOT NULL,
+                wallet_address TEXT NOT NULL,
+                PRIMARY KEY (group_name, wallet_address)
+            )
+        "#;
+
+        let create_watch_group_signals = r#"
+            CREATE TABLE IF NOT EXISTS watch_group_signals (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                group_name TEXT NOT NULL,
+                token_mint TEXT NOT NULL,
+                members TEXT NOT NULL,
+                confidence REAL NOT NULL,
+                max_amount_sol REAL NOT NULL,
+                first_buy_at INTEGER NOT NULL,
+                fired_at INTEGER NOT NULL
+            )
+        "#;
+
+        for (statement, table) in [
+            (create_watch_groups, "watch_groups"),
+            (create_watch_group_members, "watch_group_members"),
+            (create_watch_group_signals, "watch_group_signals"),
+        ] {
+            sqlx::query(statement)
+                .execute(self.db.get_pool())
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to create {} table: {}", table, e)))?;
+        }
+
+        info!("✅ Watch group schema initialized");
+        Ok(())
+    }
+
+    /// Create or replace the groups defined in a TOML file of `[[group]]` tables
+    ///
+    /// Groups only defined in the database are left alone.
+    ///
+    /// # Returns
+    /// * `Result<usize, DatabaseError>` - Number of groups saved
+    pub async fn load_file(&self, path: &Path) -> Result<usize, DatabaseError> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| DatabaseError::InitializationError(format!("Failed to read {}: {}", path.display(), e)))?;
+        let file: WatchGroupFile = toml::from_str(&content)
+            .map_err(|e| DatabaseError::SerializationError(format!("Invalid watch group file {}: {}", path.display(), e)))?;
+
+        for group in &file.group {
+            self.upsert(group).await?;
+        }
+        Ok(file.group.len())
+    }
+
+    /// Create or replace a group and its members
+    pub async fn upsert(&self, group: &WatchGroup) -> Result<WatchGroup, DatabaseError> {
+        group.validate().map_err(DatabaseError::SerializationError)?;
+        let mut group = group.clone();
+        group.updated_at = Utc::now().timestamp();
+
+        let mut tx = self.db.begin_transaction().await?;
+        sqlx::query(r#"
+            INSERT OR REPLACE INTO watch_groups
+                (name, enabled, min_members, window_secs, confidence, max_amount_sol, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(&group.name)
+        .bind(group.enabled)
+        .bind(group.min_members)
+        .bind(group.window_secs)
+        .bind(group.confidence)
+        .bind(group.max_amount_sol)
+        .bind(group.updated_at)
+        .execute(&mut tx)
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to save watch group: {}", e)))?;
+
+        sqlx::query("DELETE FROM watch_group_members WHERE group_name = ?")
+            .bind(&group.name)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to replace watch group members: {}", e)))?;
+        for wallet in &group.members {
+            sqlx::query("INSERT OR IGNORE INTO watch_group_members (group_name, wallet_address) VALUES (?, ?)")
+                .bind(&group.name)
+                .bind(wallet)
+                .execute(&mut tx)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to save watch group member: {}", e)))?;
+        }
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit watch group: {}", e)))?;
+
+        self.invalidate().await;
+        debug!("👥 Watch group {} saved with {} members", group.name, group.members.len());
+        Ok(group)
+    }
+
+    /// Remove a group; returns false if there was none
+    pub async fn delete(&self, name: &str) -> Result<bool, DatabaseError> {
+        let mut tx = self.db.begin_transaction().await?;
+        sqlx::query("DELETE FROM watch_group_members WHERE group_name = ?")
+            .bind(name)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to delete watch group members: {}", e)))?;
+        let deleted = sqlx::query("DELETE FROM watch_groups WHERE name = ?")
+            .bind(name)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to delete watch group: {}", e)))?
+            .rows_affected() > 0;
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit watch group removal: {}", e)))?;
+
+        self.invalidate().await;
+        Ok(deleted)
+    }
+
+    /// All groups, by name
+    pub async fn list(&self) -> Result<Vec<WatchGroup>, DatabaseError> {
+        if let Some(groups) = self.cache.read().await.as_ref() {
+            return Ok(groups.clone());
+        }
+
+        let groups = self.load().await?;
+        *self.cache.write().await = Some(groups.clone());
+        Ok(groups)
+    }
+
+    /// Drop cached groups so the next read sees the tables
+    pub async fn invalidate(&self) {
+        *self.cache.write().await = None;
+    }
+
+    /// Count an insider's buy towards every enabled group it belongs to
+    ///
+    /// # Returns
+    /// * `Result<Vec<SquadSignal>, DatabaseError>` - Groups whose threshold this buy reached
+    pub async fn record_buy(&self, wallet: &str, token_mint: &str, at: i64) -> Result<Vec<SquadSignal>, DatabaseError> {
+        let mut signals = Vec::new();
+        for group in self.list().await? {
+            if !group.enabled || !group.members.iter().any(|member| member == wallet) {
+                continue;
+            }
+            let key = (group.name.clone(), token_mint.to_string());
+            if self.fired.get(&key).is_some_and(|fired_at| at - *fired_at < SQUAD_REFIRE_SECS) {
+                continue;
+            }
+
+            let buyers = {
+                let mut buys = self.recent_buys.entry(key.clone()).or_default();
+                buys.retain(|(_, bought_at)| at - bought_at <= group.window_secs);
+                if !buys.iter().any(|(buyer, _)| buyer == wallet) {
+                    buys.push((wallet.to_string(), at));
+                }
+                buys.clone()
+            };
+            if (buyers.len() as i64) < group.min_members {
+                continue;
+            }
+
+            self.recent_buys.remove(&key);
+            self.fired.insert(key, at);
+            let signal = SquadSignal {
+                group_name: group.name.clone(),
+                token_mint: token_mint.to_string(),
+                members: buyers.iter().map(|(buyer, _)| buyer.as_str()).collect::<Vec<_>>().join(","),
+                confidence: group.confidence,
+                max_amount_sol: group.max_amount_sol,
+                first_buy_at: buyers.iter().map(|(_, bought_at)| *bought_at).min().unwrap_or(at),
+                fired_at: at,
+            };
+            self.record_signal(&signal).await?;
+            signals.push(signal);
+        }
+        Ok(signals)
+    }
+
+    /// Drop buys outside every group's window and expired refire guards
+    pub async fn prune(&self, now: i64) -> Result<(), DatabaseError> {
+        let longest_window = self.list().await?.iter().map(|group| group.window_secs).max().unwrap_or(0);
+        self.recent_buys.retain(|_, buys| buys.iter().any(|(_, bought_at)| now - bought_at <= longest_window));
+        self.fired.retain(|_, fired_at| now - *fired_at < SQUAD_REFIRE_SECS);
+        Ok(())
+    }
+
+    /// Signals fired by a group, most recent first
+    pub async fn signals(&self, group_name: &str, limit: i64) -> Result<Vec<SquadSignal>, DatabaseError> {
+        sqlx::query_as::<_, SquadSignal>(r#"
+            SELECT group_name, token_mint, members, confidence, max_amount_sol, first_buy_at, fired_at
+            FROM watch_group_signals WHERE group_name = ? ORDER BY fired_at DESC LIMIT ?
+        "#)
+        .bind(group_name)
+        .bind(limit)
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch watch group signals: {}", e)))
+    }
+
+    async fn record_signal(&self, signal: &SquadSignal) -> Result<(), DatabaseError> {
+        sqlx::query(r#"
+            INSERT INTO watch_group_signals
+                (group_name, token_mint, members, confidence, max_amount_sol, first_buy_at, fired_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(&signal.group_name)
+        .bind(&signal.token_mint)
+        .bind(&signal.members)
+        .bind(signal.confidence)
+        .bind(signal.max_amount_sol)
+        .bind(signal.first_buy_at)
+        .bind(signal.fired_at)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to record watch group signal: {}", e)))?;
+        Ok(())
+    }
+
+    async fn load(&self) -> Result<Vec<WatchGroup>, DatabaseError> {
+        let rows = sqlx::query_as::<_, WatchGroupRow>("SELECT * FROM watch_groups ORDER BY name")
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch watch groups: {}", e)))?;
+        let members = sqlx::query_as::<_, (String, String)>(
+            "SELECT group_name, wallet_address FROM watch_group_members ORDER BY wallet_address"
+        )
+        .fetch_all(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch watch group members: {}", e)))?;
+
+        Ok(rows.into_iter().map(|row| WatchGroup {
+            members: members.iter()
+                .filter(|(group_name, _)| *group_name == row.name)
+                .map(|(_, wallet)| wallet.clone())
+                .collect(),
+            name: row.name,
+            enabled: row.enabled,
+            min_members: row.min_members,
+            window_secs: row.window_secs,
+            confidence: row.confidence,
+            max_amount_sol: row.max_amount_sol,
+            updated_at: row.updated_at,
+        }).collect())
+    }
+}
@@ -0,0 +1,293 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use tokio::sync::RwLock;
+use tracing::{debug, info, instrument};
+
+use super::{BadgerDatabase, DatabaseError};
+
+/// Kinds of working orders
+pub const ORDER_KIND_DCA_TRANCHE: &str = "DCA_TRANCHE";
+pub const ORDER_KIND_LADDER_EXIT: &str = "LADDER_EXIT";
+pub const ORDER_KIND_REBALANCE: &str = "REBALANCE";
+pub const ORDER_KIND_COPY_DELAY: &str = "COPY_DELAY";
+
+/// Configuration for the working-order book
+#[derive(Debug, Clone)]
+pub struct WorkingOrderConfig {
+    /// How often working orders are checked against time and price (seconds)
+    pub poll_interval_secs: u64,
+}
+
+impl Default for WorkingOrderConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_secs: 1,
+        }
+    }
+}
+
+/// An intent waiting for its time or price before it is released as a signal
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct WorkingOrder {
+    pub id: i64,
+    pub kind: String, // "DCA_TRANCHE", "LADDER_EXIT", "REBALANCE", "COPY_DELAY"
+    pub token_mint: String,
+    pub side: String, // "BUY" or "SELL"
+    /// Buy size (SOL); unused for sells
+    pub amount_sol: Option<f64>,
+    /// Buys release at or below this price, sells at or above it
+    pub trigger_price: Option<f64>,
+    /// Released once this time has passed (unix seconds)
+    pub due_at: Option<i64>,
+    pub status: String, // "WORKING", "RELEASED", "CANCELLED"
+    /// Service or operator that placed the order
+    pub placed_by: String,
+    pub reason: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl WorkingOrder {
+    /// Whether the order's time or price condition is met
+    pub fn is_due(&self, now: i64, price: Option<f64>) -> bool {
+        let time_due = self.due_at.is_some_and(|due_at| now >= due_at);
+        let price_due = match (self.trigger_price, price) {
+            (Some(trigger), Some(price)) if self.side == "BUY" => price <= trigger,
+            (Some(trigger), Some(price)) => price >= trigger,
+            _ => false,
+        };
+        time_due || price_due
+    }
+}
+
+/// Parameters of a new working order
+#[derive(Debug, Clone)]
+pub struct NewWorkingOrder {
+    pub kind: String,
+    pub token_mint: String,
+    pub side: String,
+    pub amount_sol: Option<f64>,
+    pub trigger_price: Option<f64>,
+    pub due_at: Option<i64>,
+    pub placed_by: String,
+    pub reason: String,
+}
+
+/// Book of our own working orders (pending DCA tranches, ladder exits, rebalances,
+/// delayed copy entries)
+///
+/// Every intent that waits on time or price is placed here instead of in a
+/// sleeping task, so it survives restarts and can be listed and cancelled
+/// through the control channel. Working orders are mirrored in memory for the
+/// release loop; the table keeps the full history.
+pub struct WorkingOrderBook {
+    db: Arc<BadgerDatabase>,
+    config: WorkingOrderConfig,
+    working: RwLock<BTreeMap<i64, WorkingOrder>>,
+}
+
+impl WorkingOrderBook {
+    pub fn new(db: Arc<BadgerDatabase>, config: Option<WorkingOrderConfig>) -> Self {
+        Self {
+            db,
+            config: config.unwrap_or_default(),
+            working: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> &WorkingOrderConfig {
+        &self.config
+    }
+
+    /// Initialize working order schema
+    #[instrument(skip(self))]
+    pub async fn initialize_schema(&self) -> Result<(), DatabaseError> {
+        info!("🔧 Initializing working order schema");
+
+        let create_working_orders = r#"
+            CREATE TABLE IF NOT EXISTS working_orders (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                kind TEXT NOT NULL CHECK (kind IN ('DCA_TRANCHE', 'LADDER_EXIT', 'REBALANCE', 'COPY_DELAY')),
+                token_mint TEXT NOT NULL,
+                side TEXT NOT NULL CHECK (side IN ('BUY', 'SELL')),
+                amount_sol REAL,
+                trigger_price REAL,
+                due_at INTEGER,
+                status TEXT NOT NULL DEFAULT 'WORKING' CHECK (status IN ('WORKING', 'RELEASED', 'CANCELLED')),
+                placed_by TEXT NOT NULL,
+                reason TEXT NOT NULL,
+                created_at INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL
+            )
+        "#;
+
+        sqlx::query(create_working_orders)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create working_orders table: {}", e)))?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_working_orders_status ON working_orders(status, token_mint)")
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create index: {}", e)))?;
+
+        info!("✅ Working order schema initialized");
+        Ok(())
+    }
+
+    /// Load working orders left from the previous run into memory
+    pub async fn load(&self) -> Result<usize, DatabaseError> {
+        let orders = sqlx::query_as::<_, WorkingOrder>("SELECT * FROM working_orders WHERE status = 'WORKING'")
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to load working orders: {}", e)))?;
+
+        let mut working = self.working.write().await;
+        working.clear();
+        for order in orders {
+            working.insert(order.id, order);
+        }
+        Ok(working.len())
+    }
+
+    /// Place a working order
+    pub async fn place(&self, order: NewWorkingOrder) -> Result<WorkingOrder, DatabaseError> {
+        if order.due_at.is_none() && order.trigger_price.is_none() {
+            return Err(DatabaseError::QueryError("Working order needs a due time or trigger price".to_string()));
+        }
+
+        let now = Utc::now().timestamp();
+        let id = sqlx::query(r#"
+            INSERT INTO working_orders
+                (kind, token_mint, side, amount_sol, trigger_price, due_at, placed_by, reason, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#)
+        .bind(&order.kind)
+        .bind(&order.token_mint)
+        .bind(&order.side)
+        .bind(order.amount_sol)
+        .bind(order.trigger_price)
+        .bind(order.due_at)
+        .bind(&order.placed_by)
+        .bind(&order.reason)
+        .bind(now)
+        .bind(now)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to place working order: {}", e)))?
+        .last_insert_rowid();
+
+        let order = WorkingOrder {
+            id,
+            kind: order.kind,
+            token_mint: order.token_mint,
+            side: order.side,
+            amount_sol: order.amount_sol,
+            trigger_price: order.trigger_price,
+            due_at: order.due_at,
+            status: "WORKING".to_string(),
+            placed_by: order.placed_by,
+            reason: order.reason,
+            created_at: now,
+            updated_at: now,
+        };
+        debug!("📋 Working order #{} {} {} {}", order.id, order.kind, order.side, order.token_mint);
+        self.working.write().await.insert(id, order.clone());
+
+        Ok(order)
+    }
+
+    /// All working orders, oldest first
+    pub async fn working_orders(&self) -> Vec<WorkingOrder> {
+        self.working.read().await.values().cloned().collect()
+    }
+
+    /// Working orders straight from the table, for tools without the in-memory book
+    pub async fn get_working_orders(&self) -> Result<Vec<WorkingOrder>, DatabaseError> {
+        sqlx::query_as::<_, WorkingOrder>("SELECT * FROM working_orders WHERE status = 'WORKING' ORDER BY id")
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to fetch working orders: {}", e)))
+    }
+
+    /// Cancel a working order
+    pub async fn cancel(&self, id: i64) -> Result<WorkingOrder, DatabaseError> {
+        let updated = self.set_status(&[id], "CANCELLED").await?;
+        let mut order = self.working.write().await.remove(&id)
+            .filter(|_| updated > 0)
+            .ok_or_else(|| DatabaseError::QueryError(format!("Working order #{} is not working", id)))?;
+        order.status = "CANCELLED".to_string();
+        Ok(order)
+    }
+
+    /// Cancel every working order for a token (e.g. once its position is closed)
+    pub async fn cancel_for_token(&self, token_mint: &str) -> Result<usize, DatabaseError> {
+        let ids: Vec<i64> = self.working.read().await.values()
+            .filter(|order| order.token_mint == token_mint)
+            .map(|order| order.id)
+            .collect();
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        self.set_status(&ids, "CANCELLED").await?;
+        let mut working = self.working.write().await;
+        for id in &ids {
+            working.remove(id);
+        }
+        Ok(ids.len())
+    }
+
+    /// Take every order whose time or price has come and mark it released
+    ///
+    /// # Arguments
+    /// * `prices` - Current prices by token mint
+    /// * `held_kinds` - Kinds kept working even when due (e.g. while the fee budget is spent)
+    pub async fn release_due(&self, prices: &HashMap<String, f64>, held_kinds: &[&str]) -> Result<Vec<WorkingOrder>, DatabaseError> {
+        let now = Utc::now().timestamp();
+        let due: Vec<i64> = self.working.read().await.values()
+            .filter(|order| !held_kinds.contains(&order.kind.as_str()))
+            .filter(|order| order.is_due(now, prices.get(&order.token_mint).copied()))
+            .map(|order| order.id)
+            .collect();
+        if due.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.set_status(&due, "RELEASED").await?;
+        let mut working = self.working.write().await;
+        Ok(due.iter()
+            .filter_map(|id| working.remove(id))
+            .map(|mut order| {
+                order.status = "RELEASED".to_string();
+                order.updated_at = now;
+                order
+            })
+            .collect())
+    }
+
+    /// Move working orders to a final status; returns rows changed
+    async fn set_status(&self, ids: &[i64], status: &str) -> Result<u64, DatabaseError> {
+        let now = Utc::now().timestamp();
+        let mut tx = self.db.begin_transaction().await?;
+        let mut updated = 0;
+
+        for id in ids {
+            updated += sqlx::query("UPDATE working_orders SET status = ?, updated_at = ? WHERE id = ? AND status = 'WORKING'")
+                .bind(status)
+                .bind(now)
+                .bind(id)
+                .execute(&mut tx)
+                .await
+                .map_err(|e| DatabaseError::QueryError(format!("Failed to update working order: {}", e)))?
+                .rows_affected();
+        }
+
+        tx.commit().await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to commit working orders: {}", e)))?;
+        Ok(updated)
+    }
+}
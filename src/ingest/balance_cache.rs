@@ -0,0 +1,178 @@
+use anyhow::Result;
+use dashmap::DashMap;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_program::program_pack::Pack;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use spl_token::state::Account as TokenAccount;
+use std::sync::Arc;
+use tracing::{debug, instrument};
+
+/// `getMultipleAccounts` accepts at most 100 keys per request
+const MAX_ACCOUNTS_PER_REQUEST: usize = 100;
+
+/// Balance observed for an account at a specific slot
+#[derive(Debug, Clone)]
+pub enum CachedBalance {
+    /// Native SOL balance of a system account
+    Lamports(u64),
+    /// SPL token account balance
+    Token { mint: Pubkey, owner: Pubkey, amount: u64 },
+    /// Account does not exist on chain
+    Missing,
+}
+
+/// Cache entry tagged with the slot the balance was read at
+#[derive(Debug, Clone)]
+pub struct BalanceEntry {
+    pub balance: CachedBalance,
+    pub slot: u64,
+}
+
+impl BalanceEntry {
+    pub fn lamports(&self) -> u64 {
+        match self.balance {
+            CachedBalance::Lamports(lamports) => lamports,
+            _ => 0,
+        }
+    }
+
+    pub fn token_amount(&self) -> u64 {
+        match self.balance {
+            CachedBalance::Token { amount, .. } => amount,
+            _ => 0,
+        }
+    }
+}
+
+/// Shared balance cache fed by batched `getMultipleAccounts` lookups
+///
+/// Risk checks, transfer validation and portfolio tracking register the accounts
+/// they need and read from the cache; one `refresh` per cycle replaces the
+/// serial `getBalance` / `getTokenAccountBalance` calls each consumer used to issue.
+pub struct BalanceCache {
+    rpc_client: Arc<RpcClient>,
+    commitment: CommitmentConfig,
+    entries: DashMap<Pubkey, BalanceEntry>,
+    tracked: DashMap<Pubkey, ()>,
+}
+
+impl BalanceCache {
+    pub fn new(rpc_client: Arc<RpcClient>, commitment: CommitmentConfig) -> Self {
+        Self {
+            rpc_client,
+            commitment,
+            entries: DashMap::new(),
+            tracked: DashMap::new(),
+        }
+    }
+
+    /// Register accounts to be included in every refresh
+    pub fn track(&self, accounts: &[Pubkey]) {
+        for account in accounts {
+            self.tracked.insert(*account, ());
+        }
+    }
+
+    /// Stop refreshing an account and drop its cached balance
+    pub fn untrack(&self, account: &Pubkey) {
+        self.tracked.remove(account);
+        self.entries.remove(account);
+    }
+
+    /// Refresh all tracked accounts in batches of up to 100
+    ///
+    /// # Returns
+    /// * `Result<u64>` - Highest slot observed across the batches
+    #[instrument(skip(self))]
+    pub async fn refresh(&self) -> Result<u64> {
+        let accounts: Vec<Pubkey> = self.tracked.iter().map(|entry| *entry.key()).collect();
+        self.fetch(&accounts).await
+    }
+
+    /// Fetch the given accounts in batches and update the cache
+    ///
+    /// # Arguments
+    /// * `accounts` - System accounts and/or SPL token accounts to look up
+    ///
+    /// # Returns
+    /// * `Result<u64>` - Highest slot observed across the batches
+    pub async fn fetch(&self, accounts: &[Pubkey]) -> Result<u64> {
+        let mut latest_slot = 0;
+
+        for chunk in accounts.chunks(MAX_ACCOUNTS_PER_REQUEST) {
+            let response = self.rpc_client
+                .get_multiple_accounts_with_commitment(chunk, self.commitment)
+                .await?;
+            let slot = response.context.slot;
+            latest_slot = latest_slot.max(slot);
+
+            for (pubkey, account) in chunk.iter().zip(response.value) {
+                let balance = match account {
+                    None => CachedBalance::Missing,
+                    Some(account) if account.owner == spl_token::id()
+                        && account.data.len() == TokenAccount::LEN =>
+                    {
+                        match TokenAccount::unpack(&account.data) {
+                            Ok(token_account) => CachedBalance::Token {
+                                mint: token_account.mint,
+                                owner: token_account.owner,
+                                amount: token_account.amount,
+                            },
+                            Err(_) => CachedBalance::Lamports(account.lamports),
+                        }
+                    }
+                    Some(account) => CachedBalance::Lamports(account.lamports),
+                };
+
+                // Never overwrite a fresher reading with an older one
+                let is_newer = self.entries.get(pubkey).is_none_or(|existing| existing.slot <= slot);
+                if is_newer {
+                    self.entries.insert(*pubkey, BalanceEntry { balance, slot });
+                }
+            }
+        }
+
+        debug!("💰 Refreshed {} account balances at slot {}", accounts.len(), latest_slot);
+        Ok(latest_slot)
+    }
+
+    /// Cached balance for an account, if it was read at or after `min_slot`
+    pub fn get_fresh(&self, account: &Pubkey, min_slot: u64) -> Option<BalanceEntry> {
+        self.entries
+            .get(account)
+            .filter(|entry| entry.slot >= min_slot)
+            .map(|entry| entry.clone())
+    }
+
+    /// Cached balance for an account regardless of age
+    pub fn get(&self, account: &Pubkey) -> Option<BalanceEntry> {
+        self.entries.get(account).map(|entry| entry.clone())
+    }
+
+    /// Cached balance, falling back to a single batched lookup when missing or stale
+    pub async fn get_or_fetch(&self, account: &Pubkey, min_slot: u64) -> Result<BalanceEntry> {
+        if let Some(entry) = self.get_fresh(account, min_slot) {
+            return Ok(entry);
+        }
+
+        self.fetch(std::slice::from_ref(account)).await?;
+        self.get(account)
+            .ok_or_else(|| anyhow::anyhow!("No balance returned for account {}", account))
+    }
+
+    /// Total cached balance of `mint` across token accounts owned by `owner`
+    pub fn token_balance_for_owner(&self, owner: &Pubkey, mint: &Pubkey) -> u64 {
+        self.entries
+            .iter()
+            .filter_map(|entry| match entry.balance {
+                CachedBalance::Token { mint: m, owner: o, amount } if &m == mint && &o == owner => Some(amount),
+                _ => None,
+            })
+            .sum()
+    }
+
+    pub fn tracked_count(&self) -> usize {
+        self.tracked.len()
+    }
+}
@@ -0,0 +1,199 @@
+use dashmap::DashMap;
+use serde_json::Value;
+use solana_transaction_status::EncodedTransactionWithStatusMeta;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::debug;
+
+use crate::core::MarketEvent;
+use super::dex_parsers::DexEventParser;
+use super::trade_decoder::{resolved_account_keys, FetchedTransaction};
+
+/// Slots behind the newest seen block after which their dedup entries are pruned
+const SLOT_RETENTION: u64 = 1_000;
+
+/// Configuration for block-level transaction ingestion
+#[derive(Debug, Clone)]
+pub struct BlockIngestConfig {
+    /// Subscribe to every confirmed block and decode all of its transactions
+    pub enabled: bool,
+    /// Blocks decoded at once; blocks beyond this are dropped
+    pub max_in_flight: usize,
+}
+
+impl Default for BlockIngestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_in_flight: 4,
+        }
+    }
+}
+
+impl BlockIngestConfig {
+    /// Defaults with overrides from `BADGER_WS_BLOCKS` (`1` enables) and `BADGER_WS_BLOCKS_MAX_IN_FLIGHT` when set
+    pub fn from_env() -> Self {
+        let mut config = Self {
+            enabled: std::env::var("BADGER_WS_BLOCKS").is_ok_and(|v| v == "1"),
+            ..Self::default()
+        };
+        if let Some(max_in_flight) = std::env::var("BADGER_WS_BLOCKS_MAX_IN_FLIGHT").ok().and_then(|v| v.parse().ok()) {
+            config.max_in_flight = max_in_flight;
+        }
+        config
+    }
+}
+
+/// Counters of the block ingestion path
+#[derive(Debug, Clone, Default)]
+pub struct BlockIngestStats {
+    pub blocks: u64,
+    /// Blocks already decoded, re-sent after a reconnect
+    pub duplicates: u64,
+    /// Dropped because `max_in_flight` blocks were being decoded
+    pub dropped: u64,
+    pub transactions: u64,
+    /// Failed transactions, which moved nothing
+    pub failed: u64,
+    /// Transactions without meta or with a message that did not decode
+    pub undecodable: u64,
+    pub swaps: u64,
+}
+
+/// Turns `blockSubscribe` notifications into decoded swap events
+///
+/// Program subscriptions only sample swaps through account changes; a full
+/// block carries every transaction with its meta, so each successful one is
+/// handed to `DexEventParser` without a fetch. Decoding a block is CPU work,
+/// so it is bounded like the logs path: when decoding falls behind, new
+/// blocks are dropped rather than queued.
+pub struct BlockTransactionIngest {
+    config: BlockIngestConfig,
+    in_flight: Arc<Semaphore>,
+    /// Slots already admitted
+    seen: DashMap<u64, ()>,
+    newest_slot: AtomicU64,
+    blocks: AtomicU64,
+    duplicates: AtomicU64,
+    dropped: AtomicU64,
+    transactions: AtomicU64,
+    failed: AtomicU64,
+    undecodable: AtomicU64,
+    swaps: AtomicU64,
+}
+
+impl BlockTransactionIngest {
+    pub fn new(config: Option<BlockIngestConfig>) -> Self {
+        let config = config.unwrap_or_default();
+        Self {
+            in_flight: Arc::new(Semaphore::new(config.max_in_flight.max(1))),
+            config,
+            seen: DashMap::new(),
+            newest_slot: AtomicU64::new(0),
+            blocks: AtomicU64::new(0),
+            duplicates: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+            transactions: AtomicU64::new(0),
+            failed: AtomicU64::new(0),
+            undecodable: AtomicU64::new(0),
+            swaps: AtomicU64::new(0),
+        }
+    }
+
+    pub fn config(&self) -> &BlockIngestConfig {
+        &self.config
+    }
+
+    /// Claim a block notification for decoding
+    ///
+    /// # Returns
+    /// * `Option<OwnedSemaphorePermit>` - A decode slot to hold until `process_block` returns;
+    ///   None for empty, already seen or over-limit blocks
+    pub fn admit(&self, data: &Value) -> Option<OwnedSemaphorePermit> {
+        let value = data.get("value")?;
+        let slot = value.get("slot")?.as_u64()?;
+        if value.get("block").is_none_or(Value::is_null) {
+            // Skipped slot or a block the node could not serve
+            debug!("Block notification for slot {} without a block: {:?}", slot, value.get("err"));
+            return None;
+        }
+        self.blocks.fetch_add(1, Ordering::Relaxed);
+
+        let newest = self.newest_slot.fetch_max(slot, Ordering::Relaxed).max(slot);
+        if self.seen.insert(slot, ()).is_some() {
+            self.duplicates.fetch_add(1, Ordering::Relaxed);
+            return None;
+        }
+        if self.seen.len() as u64 > SLOT_RETENTION * 2 {
+            self.seen.retain(|seen_slot, _| *seen_slot + SLOT_RETENTION > newest);
+        }
+
+        match self.in_flight.clone().try_acquire_owned() {
+            Ok(permit) => Some(permit),
+            Err(_) => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+                debug!("Block decode limit reached; dropped slot {}", slot);
+                None
+            }
+        }
+    }
+
+    /// Decode every successful transaction of an admitted block into market events
+    ///
+    /// # Arguments
+    /// * `is_tracked` - Wallets besides each signer whose swaps are decoded
+    pub fn process_block(&self, mut data: Value, is_tracked: impl Fn(&str) -> bool) -> Vec<MarketEvent> {
+        let slot = data.pointer("/value/slot").and_then(Value::as_u64).unwrap_or_default();
+        let block_time = data.pointer("/value/block/blockTime").and_then(Value::as_i64);
+        let Some(Value::Array(transactions)) = data.pointer_mut("/value/block/transactions").map(Value::take) else {
+            return Vec::new();
+        };
+
+        let mut events = Vec::new();
+        for raw in transactions {
+            self.transactions.fetch_add(1, Ordering::Relaxed);
+            let Some(tx) = Self::decode_transaction(raw, slot, block_time) else {
+                self.undecodable.fetch_add(1, Ordering::Relaxed);
+                continue;
+            };
+            if tx.meta.err.is_some() {
+                self.failed.fetch_add(1, Ordering::Relaxed);
+                continue;
+            }
+            events.extend(DexEventParser::parse_transaction(&tx, &is_tracked));
+        }
+
+        self.swaps.fetch_add(events.len() as u64, Ordering::Relaxed);
+        debug!("🧱 Decoded block {}: {} swaps", slot, events.len());
+        events
+    }
+
+    fn decode_transaction(raw: Value, slot: u64, block_time: Option<i64>) -> Option<FetchedTransaction> {
+        let encoded: EncodedTransactionWithStatusMeta = serde_json::from_value(raw).ok()?;
+        let meta = encoded.meta?;
+        let transaction = encoded.transaction.decode()?;
+        let signature = transaction.signatures.first()?.to_string();
+        let account_keys = resolved_account_keys(&transaction, &meta);
+
+        Some(FetchedTransaction {
+            signature,
+            slot,
+            block_time,
+            account_keys,
+            meta,
+        })
+    }
+
+    pub fn stats(&self) -> BlockIngestStats {
+        BlockIngestStats {
+            blocks: self.blocks.load(Ordering::Relaxed),
+            duplicates: self.duplicates.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            transactions: self.transactions.load(Ordering::Relaxed),
+            failed: self.failed.load(Ordering::Relaxed),
+            undecodable: self.undecodable.load(Ordering::Relaxed),
+            swaps: self.swaps.load(Ordering::Relaxed),
+        }
+    }
+}
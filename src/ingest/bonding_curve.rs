@@ -0,0 +1,128 @@
+use dashmap::DashMap;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use tracing::debug;
+
+use crate::core::dex_types::constants::{PUMP_FUN_BONDING_CURVE_SEED, PUMP_FUN_PROGRAM};
+use crate::core::{BondingCurveState, Launchpad, MarketEvent};
+
+/// Tracks launchpad bonding curve progress per token
+///
+/// Pump.fun curve accounts don't store their mint, so mints seen in launch/pool
+/// events are mapped to their curve PDA and attached to later curve updates.
+/// Other launchpads carry the mint in the curve account.
+#[derive(Default)]
+pub struct BondingCurveTracker {
+    /// Latest state per curve address
+    curves: DashMap<String, BondingCurveState>,
+    /// Curve address -> token mint
+    mint_by_curve: DashMap<String, String>,
+    /// Token mint -> curve address
+    curve_by_mint: DashMap<String, String>,
+    /// Token mint -> launchpad it launched on, kept after the curve graduates
+    launchpad_by_mint: DashMap<String, Launchpad>,
+}
+
+impl BondingCurveTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Derive the bonding curve address for a pump.fun mint
+    pub fn curve_address_for_mint(mint: &str) -> Option<String> {
+        let mint = Pubkey::from_str(mint).ok()?;
+        let program = Pubkey::from_str(PUMP_FUN_PROGRAM).ok()?;
+        let (curve, _) = Pubkey::find_program_address(&[PUMP_FUN_BONDING_CURVE_SEED, mint.as_ref()], &program);
+        Some(curve.to_string())
+    }
+
+    /// Remember a mint so its curve updates can be attributed to it
+    pub fn register_mint(&self, mint: &str) {
+        if self.curve_by_mint.contains_key(mint) {
+            return;
+        }
+        if let Some(curve) = Self::curve_address_for_mint(mint) {
+            self.mint_by_curve.insert(curve.clone(), mint.to_string());
+            self.curve_by_mint.insert(mint.to_string(), curve);
+        }
+    }
+
+    /// Annotate a market event with tracked curve state
+    ///
+    /// Registers mints from launch/pool events, and fills in the token mint and
+    /// previous progress on bonding curve updates.
+    pub fn observe(&self, event: &mut MarketEvent) {
+        match event {
+            MarketEvent::TokenLaunched { token } => self.register_mint(&token.mint),
+            MarketEvent::PoolCreated { pool, .. } => self.register_mint(&pool.base_mint),
+            MarketEvent::BondingCurveUpdated { curve, previous_progress } => {
+                if curve.token_mint.is_none() {
+                    curve.token_mint = self.mint_by_curve.get(&curve.curve_address).map(|m| m.clone());
+                } else if let Some(mint) = &curve.token_mint {
+                    self.mint_by_curve.insert(curve.curve_address.clone(), mint.clone());
+                    self.curve_by_mint.insert(mint.clone(), curve.curve_address.clone());
+                }
+
+                if let Some(mint) = &curve.token_mint {
+                    self.launchpad_by_mint.insert(mint.clone(), curve.launchpad);
+                }
+
+                *previous_progress = self.curves
+                    .insert(curve.curve_address.clone(), curve.clone())
+                    .map(|previous| previous.progress());
+
+                if curve.complete && previous_progress.is_some_and(|p| p < 1.0) {
+                    debug!("🎓 Bonding curve {} graduated", curve.curve_address);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Latest curve progress for a token mint, if its curve has been observed
+    pub fn progress_for_mint(&self, mint: &str) -> Option<f64> {
+        let curve = self.curve_by_mint.get(mint)?;
+        self.curves.get(curve.value()).map(|state| state.progress())
+    }
+
+    /// Latest curve state for a token mint
+    pub fn state_for_mint(&self, mint: &str) -> Option<BondingCurveState> {
+        let curve = self.curve_by_mint.get(mint)?;
+        self.curves.get(curve.value()).map(|state| state.clone())
+    }
+
+    /// Launchpad a token launched on, if its curve was observed; survives graduation
+    pub fn launchpad_for_mint(&self, mint: &str) -> Option<Launchpad> {
+        self.launchpad_by_mint.get(mint).map(|launchpad| *launchpad)
+    }
+
+    /// Drop state for graduated curves (they trade on an AMM from here on)
+    pub fn prune_completed(&self) -> usize {
+        let completed: Vec<String> = self.curves.iter()
+            .filter(|entry| entry.complete)
+            .map(|entry| entry.key().clone())
+            .collect();
+
+        for curve in &completed {
+            self.curves.remove(curve);
+            if let Some((_, mint)) = self.mint_by_curve.remove(curve) {
+                self.curve_by_mint.remove(&mint);
+            }
+        }
+
+        completed.len()
+    }
+
+    /// Drop all state of a token that is no longer followed
+    pub fn forget_mint(&self, mint: &str) {
+        self.launchpad_by_mint.remove(mint);
+        if let Some((_, curve)) = self.curve_by_mint.remove(mint) {
+            self.curves.remove(&curve);
+            self.mint_by_curve.remove(&curve);
+        }
+    }
+
+    pub fn tracked_count(&self) -> usize {
+        self.curves.len()
+    }
+}
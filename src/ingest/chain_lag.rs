@@ -0,0 +1,169 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU32, AtomicU64, Ordering};
+use tokio::sync::Notify;
+use tracing::debug;
+
+/// Configuration for chain-lag and clock skew monitoring
+#[derive(Debug, Clone)]
+pub struct ChainLagConfig {
+    /// How often the stream is compared against the chain (seconds)
+    pub check_interval_secs: u64,
+    /// Stream lag above which events are considered stale (seconds)
+    pub max_stream_lag_secs: f64,
+    /// Local clock offset from chain block time above which an alert is raised (seconds)
+    pub max_clock_skew_secs: f64,
+    /// Consecutive lagging checks before the ingest endpoint is failed over
+    pub failover_after_checks: u32,
+    /// Expected slot time used to convert slot lag to seconds (milliseconds)
+    pub slot_duration_ms: u64,
+}
+
+impl Default for ChainLagConfig {
+    fn default() -> Self {
+        Self {
+            check_interval_secs: 15,
+            max_stream_lag_secs: 10.0,
+            max_clock_skew_secs: 5.0,
+            failover_after_checks: 2,
+            slot_duration_ms: 400,
+        }
+    }
+}
+
+/// One comparison of the event stream against the chain
+#[derive(Debug, Clone)]
+pub struct ChainLagSample {
+    /// Confirmed slot reported by the reference RPC endpoint
+    pub chain_slot: u64,
+    /// Latest slot seen on our websocket stream
+    pub stream_slot: u64,
+    /// Slots the stream is behind the chain (negative when ahead)
+    pub slot_lag: i64,
+    /// Seconds since the last websocket message
+    pub idle_secs: f64,
+    /// Estimated age of the data we are acting on (seconds)
+    pub stream_lag_secs: f64,
+    /// Local time minus the chain slot's block time (seconds)
+    pub clock_skew_secs: Option<f64>,
+    pub lagging: bool,
+    pub clock_skewed: bool,
+    /// Set when this check triggered an ingest failover
+    pub failover: bool,
+}
+
+/// Tracks how far the websocket event stream trails the chain
+///
+/// The ingest loop reports every message and slot notification; a periodic
+/// check compares them with a reference RPC endpoint. While the stream is
+/// lagging, `is_lagging` is set so new entries can be held back, and after
+/// enough consecutive lagging checks a failover of the ingest endpoint is
+/// requested.
+pub struct ChainLagMonitor {
+    config: ChainLagConfig,
+    stream_slot: AtomicU64,
+    /// Local receive time of the last websocket message (milliseconds)
+    last_message_ms: AtomicI64,
+    lagging_checks: AtomicU32,
+    lagging: AtomicBool,
+    failover: Notify,
+}
+
+impl ChainLagMonitor {
+    pub fn new(config: Option<ChainLagConfig>) -> Self {
+        Self {
+            config: config.unwrap_or_default(),
+            stream_slot: AtomicU64::new(0),
+            last_message_ms: AtomicI64::new(0),
+            lagging_checks: AtomicU32::new(0),
+            lagging: AtomicBool::new(false),
+            failover: Notify::new(),
+        }
+    }
+
+    pub fn config(&self) -> &ChainLagConfig {
+        &self.config
+    }
+
+    /// Record the receive time of a websocket message
+    pub fn observe_message(&self) {
+        self.last_message_ms.store(Utc::now().timestamp_millis(), Ordering::Relaxed);
+    }
+
+    /// Record a slot notification from the stream
+    pub fn observe_slot(&self, slot: u64) {
+        self.stream_slot.fetch_max(slot, Ordering::Relaxed);
+        self.observe_message();
+    }
+
+    /// Whether the latest check found the stream lagging the chain
+    pub fn is_lagging(&self) -> bool {
+        self.lagging.load(Ordering::Relaxed)
+    }
+
+    /// Resolves when a check has requested an ingest endpoint failover
+    pub async fn failover_requested(&self) {
+        self.failover.notified().await
+    }
+
+    /// Compare the stream against the chain as seen by a reference endpoint
+    ///
+    /// No verdict is given before the stream has delivered a slot, so replay
+    /// and fixture runs are never flagged.
+    ///
+    /// # Returns
+    /// * `Result<Option<ChainLagSample>>` - None until the first stream slot arrives
+    pub async fn check(&self, rpc_client: &RpcClient) -> Result<Option<ChainLagSample>> {
+        let stream_slot = self.stream_slot.load(Ordering::Relaxed);
+        if stream_slot == 0 {
+            return Ok(None);
+        }
+
+        let chain_slot = rpc_client.get_slot_with_commitment(CommitmentConfig::confirmed()).await
+            .context("Failed to fetch chain slot")?;
+        // Block time can be missing for a freshly confirmed slot; skew is then unknown
+        let block_time = rpc_client.get_block_time(chain_slot).await.ok();
+
+        let now_ms = Utc::now().timestamp_millis();
+        let idle_secs = (now_ms - self.last_message_ms.load(Ordering::Relaxed)).max(0) as f64 / 1000.0;
+        let slot_lag = chain_slot as i64 - stream_slot as i64;
+        let slot_lag_secs = slot_lag.max(0) as f64 * self.config.slot_duration_ms as f64 / 1000.0;
+        let stream_lag_secs = slot_lag_secs.max(idle_secs);
+        let clock_skew_secs = block_time.map(|t| (now_ms as f64 / 1000.0) - t as f64);
+
+        let lagging = stream_lag_secs > self.config.max_stream_lag_secs;
+        let clock_skewed = clock_skew_secs.is_some_and(|skew| skew.abs() > self.config.max_clock_skew_secs);
+        self.lagging.store(lagging, Ordering::Relaxed);
+
+        let failover = if lagging {
+            let checks = self.lagging_checks.fetch_add(1, Ordering::Relaxed) + 1;
+            if checks >= self.config.failover_after_checks {
+                self.lagging_checks.store(0, Ordering::Relaxed);
+                self.failover.notify_one();
+                true
+            } else {
+                false
+            }
+        } else {
+            self.lagging_checks.store(0, Ordering::Relaxed);
+            false
+        };
+
+        debug!("⏱️ Chain slot {} | stream slot {} | lag {:.1}s | idle {:.1}s | skew {:?}s",
+            chain_slot, stream_slot, stream_lag_secs, idle_secs, clock_skew_secs);
+
+        Ok(Some(ChainLagSample {
+            chain_slot,
+            stream_slot,
+            slot_lag,
+            idle_secs,
+            stream_lag_secs,
+            clock_skew_secs,
+            lagging,
+            clock_skewed,
+            failover,
+        }))
+    }
+}
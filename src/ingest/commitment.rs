@@ -0,0 +1,113 @@
+use solana_sdk::commitment_config::{CommitmentConfig, CommitmentLevel};
+use tracing::warn;
+
+/// Part of the bot reading chain state, each with its own commitment level
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitmentConsumer {
+    /// Program subscriptions feeding signals and pre-trade reads
+    Sniper,
+    /// Account subscriptions for held tokens, insiders and metadata
+    Subscriptions,
+    /// Bulk reads: holders, token ages, backfills, symbols
+    Analytics,
+    /// Own balances and fees
+    Accounting,
+}
+
+impl CommitmentConsumer {
+    pub const ALL: [CommitmentConsumer; 4] = [
+        CommitmentConsumer::Sniper,
+        CommitmentConsumer::Subscriptions,
+        CommitmentConsumer::Analytics,
+        CommitmentConsumer::Accounting,
+    ];
+
+    /// Name used in the `BADGER_COMMITMENT_*` variables
+    pub fn name(&self) -> &'static str {
+        match self {
+            CommitmentConsumer::Sniper => "SNIPER",
+            CommitmentConsumer::Subscriptions => "SUBSCRIPTIONS",
+            CommitmentConsumer::Analytics => "ANALYTICS",
+            CommitmentConsumer::Accounting => "ACCOUNTING",
+        }
+    }
+}
+
+/// Commitment level per consumer
+///
+/// The sniper wants `processed` for speed, accounting `finalized` for
+/// correctness; everything defaults to `confirmed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommitmentLevels {
+    pub sniper: CommitmentConfig,
+    pub subscriptions: CommitmentConfig,
+    pub analytics: CommitmentConfig,
+    pub accounting: CommitmentConfig,
+}
+
+impl Default for CommitmentLevels {
+    fn default() -> Self {
+        Self {
+            sniper: CommitmentConfig::confirmed(),
+            subscriptions: CommitmentConfig::confirmed(),
+            analytics: CommitmentConfig::confirmed(),
+            accounting: CommitmentConfig::confirmed(),
+        }
+    }
+}
+
+impl CommitmentLevels {
+    /// Defaults overridden by `BADGER_COMMITMENT_<CONSUMER>` (processed, confirmed or finalized)
+    pub fn from_env() -> Self {
+        let mut levels = Self::default();
+        for consumer in CommitmentConsumer::ALL {
+            let Ok(value) = std::env::var(format!("BADGER_COMMITMENT_{}", consumer.name())) else {
+                continue;
+            };
+            match parse_commitment(&value) {
+                Some(commitment) => *levels.get_mut(consumer) = commitment,
+                None => warn!("Ignoring BADGER_COMMITMENT_{}={:?}: expected processed, confirmed or finalized",
+                    consumer.name(), value),
+            }
+        }
+        levels
+    }
+
+    pub fn get(&self, consumer: CommitmentConsumer) -> CommitmentConfig {
+        match consumer {
+            CommitmentConsumer::Sniper => self.sniper,
+            CommitmentConsumer::Subscriptions => self.subscriptions,
+            CommitmentConsumer::Analytics => self.analytics,
+            CommitmentConsumer::Accounting => self.accounting,
+        }
+    }
+
+    fn get_mut(&mut self, consumer: CommitmentConsumer) -> &mut CommitmentConfig {
+        match consumer {
+            CommitmentConsumer::Sniper => &mut self.sniper,
+            CommitmentConsumer::Subscriptions => &mut self.subscriptions,
+            CommitmentConsumer::Analytics => &mut self.analytics,
+            CommitmentConsumer::Accounting => &mut self.accounting,
+        }
+    }
+}
+
+/// Parse one of the three current commitment levels
+pub fn parse_commitment(value: &str) -> Option<CommitmentConfig> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "processed" => Some(CommitmentConfig::processed()),
+        "confirmed" => Some(CommitmentConfig::confirmed()),
+        "finalized" => Some(CommitmentConfig::finalized()),
+        _ => None,
+    }
+}
+
+/// Commitment for history reads (`getTransaction`, `getSignaturesForAddress`),
+/// which reject `processed`
+pub fn at_least_confirmed(commitment: CommitmentConfig) -> CommitmentConfig {
+    if commitment.commitment == CommitmentLevel::Processed {
+        CommitmentConfig::confirmed()
+    } else {
+        commitment
+    }
+}
@@ -1,4 +1,5 @@
 use anyhow::{Result, bail};
+use base64::Engine;
 use chrono::Utc;
 use serde_json::Value;
 use tracing::{debug, warn};
@@ -6,6 +7,8 @@ use tracing::{debug, warn};
 use crate::core::dex_types::*;
 use crate::core::dex_types::constants::*;
 use crate::core::dex_types::utils::*;
+use crate::core::program_registry;
+use super::trade_decoder::{decode_wallet_swap, token_balance_owners, FetchedTransaction, WalletSwap};
 
 /// Master parser that routes to specific DEX parsers based on program ID
 pub struct DexEventParser;
@@ -66,16 +69,83 @@ impl DexEventParser {
                         events.extend(pump_events);
                     }
                 }
-                _ => {
-                    // Unknown program, skip
-                    debug!("Unknown program owner: {}", owner);
+                LAUNCHLAB_PROGRAM => {
+                    debug!("🐕 Parsing LaunchLab event for account: {}", shorten_pubkey(pubkey));
+                    if let Ok(launchlab_events) = Self::parse_launchlab_event(account, pubkey, slot) {
+                        events.extend(launchlab_events);
+                    }
+                }
+                MOONSHOT_PROGRAM => {
+                    debug!("🌙 Parsing Moonshot event for account: {}", shorten_pubkey(pubkey));
+                    if let Ok(moonshot_events) = Self::parse_moonshot_event(account, pubkey, slot) {
+                        events.extend(moonshot_events);
+                    }
                 }
+                _ => match program_registry::observe(owner) {
+                    Some(program) => debug!("No parser for {} ({}) account {}",
+                        program.venue, program.kind.as_str(), shorten_pubkey(pubkey)),
+                    None => debug!("Unknown program owner: {}", owner),
+                },
             }
         }
         
         Ok(events)
     }
     
+    /// Parse a fetched transaction into the swaps of its signer and of tracked wallets
+    ///
+    /// Amounts and wallets come from balance changes, so they are exact
+    /// whichever route the swap took; the DEX is the first known program among
+    /// the transaction's accounts. The fee payer's swap is always decoded.
+    /// Other wallets whose token balances changed, such as users of a relayer
+    /// or aggregator, are decoded when `is_tracked` accepts them; pool
+    /// authorities move balances on every swap and would mirror each one.
+    pub fn parse_transaction(tx: &FetchedTransaction, is_tracked: impl Fn(&str) -> bool) -> Vec<MarketEvent> {
+        let Some(fee_payer) = tx.account_keys.first() else {
+            return Vec::new();
+        };
+        let mut wallets = vec![fee_payer.clone()];
+        wallets.extend(token_balance_owners(&tx.meta).into_iter()
+            .filter(|owner| owner != fee_payer && is_tracked(owner)));
+
+        let dex = tx.account_keys.iter()
+            .map(|key| DexType::from_program_id(key))
+            .find(|dex| *dex != DexType::Unknown)
+            .unwrap_or(DexType::Unknown);
+        let timestamp = tx.block_time
+            .and_then(|t| chrono::DateTime::from_timestamp(t, 0))
+            .unwrap_or_else(Utc::now);
+
+        let mut events = Vec::new();
+        for wallet in wallets {
+            let Some(WalletSwap { side, token_mint, token_amount, lamports, .. }) =
+                decode_wallet_swap(&wallet, &tx.account_keys, &tx.meta) else {
+                continue;
+            };
+            let (token_in, token_out, amount_in, amount_out) = match side {
+                SwapType::Buy => (SOL_MINT.to_string(), token_mint, lamports, token_amount),
+                SwapType::Sell => (token_mint, SOL_MINT.to_string(), token_amount, lamports),
+            };
+            debug!("🔁 Decoded {:?} swap {} by {}", side, shorten_pubkey(&tx.signature), shorten_pubkey(&wallet));
+            events.push(MarketEvent::SwapDetected {
+                swap: SwapEvent {
+                    signature: tx.signature.clone(),
+                    slot: tx.slot,
+                    swap_type: side,
+                    token_in,
+                    token_out,
+                    amount_in,
+                    amount_out,
+                    wallet,
+                    dex,
+                    price_impact: None,
+                    timestamp,
+                },
+            });
+        }
+        events
+    }
+    
     /// Parse Raydium AMM program events (pool creation, swaps)
     fn parse_raydium_event(account: &serde_json::Map<String, Value>, pubkey: &str, slot: u64) -> Result<Vec<MarketEvent>> {
         let mut events = Vec::new();
@@ -213,10 +283,19 @@ impl DexEventParser {
         Ok(events)
     }
     
-    /// Parse Pump.fun events (meme coin launches)
+    /// Parse Pump.fun events (bonding curve updates, meme coin launches)
     fn parse_pump_fun_event(account: &serde_json::Map<String, Value>, pubkey: &str, slot: u64) -> Result<Vec<MarketEvent>> {
         let mut events = Vec::new();
         
+        if let Some(raw) = Self::raw_account_data(account) {
+            if let Some(curve) = Self::decode_bonding_curve(&raw, pubkey, slot) {
+                debug!("📈 Bonding curve {} at {:.1}% ({:.2} SOL raised)",
+                    shorten_pubkey(pubkey), curve.progress() * 100.0, curve.sol_raised());
+                events.push(MarketEvent::BondingCurveUpdated { curve, previous_progress: None });
+                return Ok(events);
+            }
+        }
+        
         let lamports = account.get("lamports").and_then(|l| l.as_u64()).unwrap_or(0);
         
         // Pump.fun typically creates tokens with initial liquidity
@@ -244,6 +323,173 @@ impl DexEventParser {
         Ok(events)
     }
     
+    /// Parse Raydium LaunchLab events (Bonk.fun / LetsBonk bonding curve pools)
+    fn parse_launchlab_event(account: &serde_json::Map<String, Value>, pubkey: &str, slot: u64) -> Result<Vec<MarketEvent>> {
+        let mut events = Vec::new();
+        
+        if let Some(raw) = Self::raw_account_data(account) {
+            if let Some(curve) = Self::decode_launchlab_pool(&raw, pubkey, slot) {
+                debug!("📈 LaunchLab pool {} at {:.1}% ({:.2} SOL raised)",
+                    shorten_pubkey(pubkey), curve.progress() * 100.0, curve.sol_raised());
+                events.push(MarketEvent::BondingCurveUpdated { curve, previous_progress: None });
+            }
+        }
+        
+        Ok(events)
+    }
+    
+    /// Parse Moonshot events (bonding curve accounts)
+    fn parse_moonshot_event(account: &serde_json::Map<String, Value>, pubkey: &str, slot: u64) -> Result<Vec<MarketEvent>> {
+        let mut events = Vec::new();
+        
+        let lamports = account.get("lamports").and_then(|l| l.as_u64()).unwrap_or(0);
+        if let Some(raw) = Self::raw_account_data(account) {
+            if let Some(curve) = Self::decode_moonshot_curve(&raw, pubkey, lamports, slot) {
+                debug!("📈 Moonshot curve {} at {:.1}% ({:.2} SOL raised)",
+                    shorten_pubkey(pubkey), curve.progress() * 100.0, curve.sol_raised());
+                events.push(MarketEvent::BondingCurveUpdated { curve, previous_progress: None });
+            }
+        }
+        
+        Ok(events)
+    }
+    
+    /// Raw bytes of an account that arrived base64-encoded: ["<data>", "base64"]
+    ///
+    /// Other encodings and data that fails to decode are skipped, so the rest
+    /// of the notification is still parsed.
+    fn raw_account_data(account: &serde_json::Map<String, Value>) -> Option<Vec<u8>> {
+        let data = account.get("data")?.as_array()?;
+        if data.get(1).and_then(|e| e.as_str()) != Some("base64") {
+            return None;
+        }
+        let encoded = data.first()?.as_str()?;
+        match base64::engine::general_purpose::STANDARD.decode(encoded) {
+            Ok(raw) => Some(raw),
+            Err(e) => {
+                debug!("Skipping undecodable account data: {}", e);
+                None
+            }
+        }
+    }
+    
+    /// Decode a pump.fun bonding curve account
+    ///
+    /// Layout after the 8-byte discriminator: virtual token reserves, virtual SOL
+    /// reserves, real token reserves, real SOL reserves, total supply (u64 LE each),
+    /// then the `complete` flag.
+    pub fn decode_bonding_curve(raw: &[u8], pubkey: &str, slot: u64) -> Option<BondingCurveState> {
+        if raw.len() < 49 || raw[..8] != PUMP_FUN_BONDING_CURVE_DISCRIMINATOR {
+            return None;
+        }
+        
+        let read_u64 = |offset: usize| -> u64 {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&raw[offset..offset + 8]);
+            u64::from_le_bytes(bytes)
+        };
+        
+        Some(BondingCurveState {
+            curve_address: pubkey.to_string(),
+            token_mint: None,
+            launchpad: Launchpad::PumpFun,
+            virtual_token_reserves: read_u64(8),
+            virtual_sol_reserves: read_u64(16),
+            real_token_reserves: read_u64(24),
+            real_sol_reserves: read_u64(32),
+            token_total_supply: read_u64(40),
+            initial_real_token_reserves: PUMP_FUN_INITIAL_REAL_TOKEN_RESERVES,
+            token_decimals: 6,
+            complete: raw[48] != 0,
+            slot,
+        })
+    }
+    
+    /// Decode a Raydium LaunchLab pool state account
+    ///
+    /// Layout after the 8-byte discriminator: epoch (u64), auth bump, status,
+    /// base decimals, quote decimals, migrate type (u8 each), then supply, total
+    /// base for sale, virtual base, virtual quote, real base (sold), real quote
+    /// (raised) as u64 LE; the base mint follows at offset 205 and the quote mint
+    /// at 237. Only SOL-quoted pools are decoded.
+    pub fn decode_launchlab_pool(raw: &[u8], pubkey: &str, slot: u64) -> Option<BondingCurveState> {
+        if raw.len() < 269 || raw[..8] != LAUNCHLAB_POOL_STATE_DISCRIMINATOR {
+            return None;
+        }
+        
+        let read_u64 = |offset: usize| -> u64 {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&raw[offset..offset + 8]);
+            u64::from_le_bytes(bytes)
+        };
+        let read_pubkey = |offset: usize| -> String {
+            bs58::encode(&raw[offset..offset + 32]).into_string()
+        };
+        
+        if read_pubkey(237) != SOL_MINT {
+            return None;
+        }
+        
+        let total_base_sell = read_u64(29);
+        let virtual_base = read_u64(37);
+        let virtual_quote = read_u64(45);
+        let real_base = read_u64(53);
+        let real_quote = read_u64(61);
+        
+        Some(BondingCurveState {
+            curve_address: pubkey.to_string(),
+            token_mint: Some(read_pubkey(205)),
+            launchpad: Launchpad::LaunchLab,
+            virtual_token_reserves: virtual_base.saturating_sub(real_base),
+            virtual_sol_reserves: virtual_quote.saturating_add(real_quote),
+            real_token_reserves: total_base_sell.saturating_sub(real_base),
+            real_sol_reserves: real_quote,
+            token_total_supply: read_u64(21),
+            initial_real_token_reserves: total_base_sell,
+            token_decimals: raw[18],
+            // Status 0 is the funding phase; anything later is migrating or migrated
+            complete: raw[17] != 0,
+            slot,
+        })
+    }
+    
+    /// Decode a Moonshot curve account
+    ///
+    /// Layout after the 8-byte discriminator: total supply, tokens left on the
+    /// curve (u64 LE each), mint (32 bytes), decimals. The account carries no
+    /// reserves, so the SOL raised is its balance above rent and the curve
+    /// price is left to swaps.
+    pub fn decode_moonshot_curve(raw: &[u8], pubkey: &str, lamports: u64, slot: u64) -> Option<BondingCurveState> {
+        if raw.len() < 57 || raw[..8] != MOONSHOT_CURVE_ACCOUNT_DISCRIMINATOR {
+            return None;
+        }
+        
+        let read_u64 = |offset: usize| -> u64 {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&raw[offset..offset + 8]);
+            u64::from_le_bytes(bytes)
+        };
+        
+        let total_supply = read_u64(8);
+        let curve_amount = read_u64(16);
+        let rent = solana_sdk::rent::Rent::default().minimum_balance(raw.len());
+        
+        Some(BondingCurveState {
+            curve_address: pubkey.to_string(),
+            token_mint: Some(bs58::encode(&raw[24..56]).into_string()),
+            launchpad: Launchpad::Moonshot,
+            virtual_token_reserves: 0,
+            virtual_sol_reserves: 0,
+            real_token_reserves: curve_amount,
+            real_sol_reserves: lamports.saturating_sub(rent),
+            token_total_supply: total_supply,
+            initial_real_token_reserves: (total_supply as u128 * MOONSHOT_CURVE_SUPPLY_BPS as u128 / 10_000) as u64,
+            token_decimals: raw[56],
+            complete: curve_amount == 0,
+            slot,
+        })
+    }
+    
     /// Extract detailed pool information from Raydium parsed data
     fn extract_raydium_pool_info(info: &serde_json::Map<String, Value>, pubkey: &str, slot: u64) -> Result<PoolInfo> {
         let base_mint = info.get("baseMint")
@@ -0,0 +1,164 @@
+use anyhow::{Context, Result};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_program::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use spl_token::state::{Account as TokenAccount, Mint};
+use std::collections::HashMap;
+use std::str::FromStr;
+use tracing::debug;
+
+/// Offset of the owner field in an SPL token account
+const TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
+
+/// Rent-exempt balance of an SPL token account, refunded when it is closed
+const TOKEN_ACCOUNT_RENT_LAMPORTS: u64 = 2_039_280;
+
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+/// Configuration for dust consolidation
+#[derive(Debug, Clone)]
+pub struct DustConfig {
+    /// Token balances worth less than this are dust (SOL)
+    pub max_value_sol: f64,
+    /// How often trading wallets are scanned (seconds)
+    pub scan_interval_secs: u64,
+}
+
+impl Default for DustConfig {
+    fn default() -> Self {
+        Self {
+            max_value_sol: 0.01,
+            scan_interval_secs: 3600,
+        }
+    }
+}
+
+/// Token account holding a residual balance
+#[derive(Debug, Clone)]
+pub struct DustAccount {
+    pub wallet: String,
+    pub token_account: String,
+    pub token_mint: String,
+    pub amount: u64,
+    pub ui_amount: f64,
+    /// Estimated value; None when no price (and so no sell route) is known
+    pub value_sol: Option<f64>,
+}
+
+/// What to do with the dust found across trading wallets
+#[derive(Debug, Clone, Default)]
+pub struct DustPlan {
+    /// Priced dust: sell through the known route, then close the account
+    pub sell_and_close: Vec<DustAccount>,
+    /// Empty accounts: close directly to recover rent
+    pub close_only: Vec<DustAccount>,
+    /// Balances without a known price (no sell route); left in place
+    pub unroutable: Vec<DustAccount>,
+}
+
+impl DustPlan {
+    /// Accounts that will be closed
+    pub fn closable_count(&self) -> usize {
+        self.sell_and_close.len() + self.close_only.len()
+    }
+
+    /// SOL recovered from sales plus refunded account rent
+    pub fn recoverable_sol(&self) -> f64 {
+        let sales: f64 = self.sell_and_close.iter().filter_map(|d| d.value_sol).sum();
+        sales + self.closable_count() as f64 * TOKEN_ACCOUNT_RENT_LAMPORTS as f64 / LAMPORTS_PER_SOL
+    }
+}
+
+/// Fetch all SPL token accounts owned by a wallet, with UI amounts
+async fn fetch_token_accounts(rpc_client: &RpcClient, wallet: &Pubkey) -> Result<Vec<(Pubkey, TokenAccount, f64)>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![
+            RpcFilterType::DataSize(TokenAccount::LEN as u64),
+            RpcFilterType::Memcmp(Memcmp::new_base58_encoded(TOKEN_ACCOUNT_OWNER_OFFSET, wallet.as_ref())),
+        ]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+
+    let accounts: Vec<(Pubkey, TokenAccount)> = rpc_client
+        .get_program_accounts_with_config(&spl_token::id(), config)
+        .await
+        .with_context(|| format!("Failed to fetch token accounts of {}", wallet))?
+        .into_iter()
+        .filter_map(|(address, account)| Some((address, TokenAccount::unpack(&account.data).ok()?)))
+        .collect();
+
+    // Decimals per mint for UI amounts
+    let mut mints: Vec<Pubkey> = accounts.iter().map(|(_, account)| account.mint).collect();
+    mints.sort();
+    mints.dedup();
+    let mut decimals: HashMap<Pubkey, u8> = HashMap::new();
+    for chunk in mints.chunks(100) {
+        let mint_accounts = rpc_client.get_multiple_accounts(chunk).await
+            .context("Failed to fetch token mints")?;
+        for (mint, account) in chunk.iter().zip(mint_accounts) {
+            if let Some(mint_state) = account.and_then(|a| Mint::unpack(&a.data).ok()) {
+                decimals.insert(*mint, mint_state.decimals);
+            }
+        }
+    }
+
+    Ok(accounts.into_iter()
+        .filter_map(|(address, account)| {
+            let ui_amount = account.amount as f64 / 10f64.powi(*decimals.get(&account.mint)? as i32);
+            Some((address, account, ui_amount))
+        })
+        .collect())
+}
+
+/// Scan trading wallets for dust and build a consolidation plan
+///
+/// # Arguments
+/// * `wallets` - Trading wallets to scan
+/// * `price_for` - SOL price per token mint; a price means a sell route exists
+/// * `config` - Dust value threshold
+///
+/// # Returns
+/// * `Result<DustPlan>` - Dust grouped by how it can be consolidated
+pub async fn scan_dust(
+    rpc_client: &RpcClient,
+    wallets: &[String],
+    price_for: impl Fn(&str) -> Option<f64>,
+    config: &DustConfig,
+) -> Result<DustPlan> {
+    let mut plan = DustPlan::default();
+
+    for wallet in wallets {
+        let owner = Pubkey::from_str(wallet).context("Invalid wallet address")?;
+
+        for (address, account, ui_amount) in fetch_token_accounts(rpc_client, &owner).await? {
+            let token_mint = account.mint.to_string();
+            let value_sol = price_for(&token_mint).map(|price| ui_amount * price);
+            let dust = DustAccount {
+                wallet: wallet.clone(),
+                token_account: address.to_string(),
+                token_mint,
+                amount: account.amount,
+                ui_amount,
+                value_sol,
+            };
+
+            match (account.amount, value_sol) {
+                (0, _) => plan.close_only.push(dust),
+                (_, Some(value)) if value < config.max_value_sol => plan.sell_and_close.push(dust),
+                (_, Some(_)) => {} // A real position, not dust
+                (_, None) => plan.unroutable.push(dust),
+            }
+        }
+    }
+
+    debug!("🧹 Dust scan: {} to sell, {} to close, {} without route",
+        plan.sell_and_close.len(), plan.close_only.len(), plan.unroutable.len());
+    Ok(plan)
+}
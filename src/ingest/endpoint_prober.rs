@@ -0,0 +1,256 @@
+use futures_util::future::join_all;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+use tokio_tungstenite::connect_async;
+use tracing::{debug, info};
+
+use super::commitment::{CommitmentConsumer, CommitmentLevels};
+use super::endpoint_auth::{EndpointAuth, redact_url};
+use super::insider_subscriptions::http_url_from_ws;
+
+/// Configuration for RPC endpoint probing
+#[derive(Debug, Clone)]
+pub struct ProberConfig {
+    /// How often every endpoint is probed (seconds)
+    pub probe_interval_secs: u64,
+    /// Timeout for a single RPC or WebSocket probe (milliseconds)
+    pub probe_timeout_ms: u64,
+    /// Latency-equivalent penalty per slot behind the best endpoint (milliseconds)
+    pub slot_lag_penalty_ms: f64,
+    /// Minimum score improvement before the execution endpoint is switched (milliseconds)
+    pub switch_margin_ms: f64,
+}
+
+impl Default for ProberConfig {
+    fn default() -> Self {
+        Self {
+            probe_interval_secs: 60,
+            probe_timeout_ms: 5000,
+            slot_lag_penalty_ms: 400.0, // roughly one slot time
+            switch_margin_ms: 50.0,
+        }
+    }
+}
+
+/// RPC endpoint with WebSocket and HTTP URLs
+#[derive(Debug, Clone)]
+pub struct RpcEndpoint {
+    pub ws_url: String,
+    pub http_url: String,
+    /// Paid endpoint reserved for the execution path
+    pub premium: bool,
+    /// WebSocket handshake credentials; HTTP calls rely on keys in the URL
+    pub auth: EndpointAuth,
+}
+
+impl RpcEndpoint {
+    pub fn from_ws(ws_url: &str, premium: bool) -> Self {
+        Self {
+            ws_url: ws_url.to_string(),
+            http_url: http_url_from_ws(ws_url),
+            premium,
+            auth: EndpointAuth::None,
+        }
+    }
+
+    pub fn with_auth(mut self, auth: EndpointAuth) -> Self {
+        self.auth = auth;
+        self
+    }
+}
+
+/// Latest probe result for one endpoint
+#[derive(Debug, Clone)]
+pub struct EndpointHealth {
+    pub endpoint: RpcEndpoint,
+    pub rpc_latency_ms: Option<f64>,
+    pub ws_latency_ms: Option<f64>,
+    pub slot: Option<u64>,
+    /// Slots behind the most advanced endpoint in the same round
+    pub slot_lag: Option<u64>,
+}
+
+impl EndpointHealth {
+    /// Both probes answered
+    pub fn is_healthy(&self) -> bool {
+        self.rpc_latency_ms.is_some() && self.ws_latency_ms.is_some() && self.slot.is_some()
+    }
+
+    /// Lower is better: latency plus a penalty for slot lag
+    pub fn score(&self, config: &ProberConfig) -> Option<f64> {
+        if !self.is_healthy() {
+            return None;
+        }
+        let latency = self.rpc_latency_ms?.max(self.ws_latency_ms?);
+        Some(latency + self.slot_lag.unwrap_or(0) as f64 * config.slot_lag_penalty_ms)
+    }
+}
+
+/// Outcome of a probe round
+#[derive(Debug, Clone, Default)]
+pub struct ProbeRound {
+    pub results: Vec<EndpointHealth>,
+    /// Set when the execution endpoint changed: (previous, new) HTTP URLs, redacted
+    pub execution_switch: Option<(String, String)>,
+}
+
+/// Measures latency and slot lag per endpoint and selects endpoints per path
+///
+/// The execution path follows the best-scoring endpoint (with hysteresis so it
+/// doesn't flap); analytics stay on the best non-premium endpoint.
+pub struct EndpointProber {
+    endpoints: Vec<RpcEndpoint>,
+    config: ProberConfig,
+    health: RwLock<Vec<EndpointHealth>>,
+    execution: RwLock<RpcEndpoint>,
+    analytics: RwLock<RpcEndpoint>,
+    commitments: CommitmentLevels,
+}
+
+impl EndpointProber {
+    /// # Arguments
+    /// * `endpoints` - Candidate endpoints; the first is used until the first probe completes
+    pub fn new(endpoints: Vec<RpcEndpoint>, config: Option<ProberConfig>) -> Self {
+        let first = endpoints.first().cloned().expect("at least one RPC endpoint");
+        let analytics = endpoints.iter().find(|e| !e.premium).cloned().unwrap_or_else(|| first.clone());
+        Self {
+            endpoints,
+            config: config.unwrap_or_default(),
+            health: RwLock::new(Vec::new()),
+            execution: RwLock::new(first),
+            analytics: RwLock::new(analytics),
+            commitments: CommitmentLevels::default(),
+        }
+    }
+
+    /// Commitment levels of the RPC clients handed out per consumer
+    pub fn with_commitments(mut self, commitments: CommitmentLevels) -> Self {
+        self.commitments = commitments;
+        self
+    }
+
+    pub fn config(&self) -> &ProberConfig {
+        &self.config
+    }
+
+    pub fn commitments(&self) -> &CommitmentLevels {
+        &self.commitments
+    }
+
+    /// Endpoint currently selected for transaction submission and execution reads
+    pub fn execution_endpoint(&self) -> RpcEndpoint {
+        self.execution.read().expect("endpoint lock poisoned").clone()
+    }
+
+    /// Endpoint currently selected for analytics and bulk reads
+    pub fn analytics_endpoint(&self) -> RpcEndpoint {
+        self.analytics.read().expect("endpoint lock poisoned").clone()
+    }
+
+    /// Client on the analytics endpoint at the consumer's commitment level
+    pub fn analytics_client(&self, consumer: CommitmentConsumer) -> RpcClient {
+        RpcClient::new_with_commitment(self.analytics_endpoint().http_url, self.commitments.get(consumer))
+    }
+
+    /// Results of the latest probe round
+    pub fn health(&self) -> Vec<EndpointHealth> {
+        self.health.read().expect("endpoint lock poisoned").clone()
+    }
+
+    async fn probe_endpoint(endpoint: RpcEndpoint, timeout: Duration) -> EndpointHealth {
+        let rpc_client = RpcClient::new_with_timeout_and_commitment(
+            endpoint.http_url.clone(),
+            timeout,
+            CommitmentConfig::processed(),
+        );
+
+        let started = Instant::now();
+        let (rpc_latency_ms, slot) = match tokio::time::timeout(timeout, rpc_client.get_slot()).await {
+            Ok(Ok(slot)) => (Some(started.elapsed().as_secs_f64() * 1000.0), Some(slot)),
+            Ok(Err(e)) => {
+                debug!("RPC probe failed for {}: {}", redact_url(&endpoint.http_url), e);
+                (None, None)
+            }
+            Err(_) => (None, None),
+        };
+
+        let started = Instant::now();
+        let ws_connect = async {
+            let request = endpoint.auth.request(&endpoint.ws_url)?;
+            anyhow::Ok(connect_async(request).await?)
+        };
+        let ws_latency_ms = match tokio::time::timeout(timeout, ws_connect).await {
+            Ok(Ok((mut stream, _))) => {
+                let latency = started.elapsed().as_secs_f64() * 1000.0;
+                let _ = stream.close(None).await;
+                Some(latency)
+            }
+            Ok(Err(e)) => {
+                debug!("WebSocket probe failed for {}: {}", redact_url(&endpoint.ws_url), e);
+                None
+            }
+            Err(_) => None,
+        };
+
+        EndpointHealth {
+            endpoint,
+            rpc_latency_ms,
+            ws_latency_ms,
+            slot,
+            slot_lag: None,
+        }
+    }
+
+    /// Probe every endpoint concurrently and update endpoint selection
+    pub async fn probe(&self) -> ProbeRound {
+        let timeout = Duration::from_millis(self.config.probe_timeout_ms);
+        let mut results = join_all(
+            self.endpoints.iter().cloned().map(|endpoint| Self::probe_endpoint(endpoint, timeout))
+        ).await;
+
+        let best_slot = results.iter().filter_map(|r| r.slot).max();
+        for result in &mut results {
+            result.slot_lag = match (best_slot, result.slot) {
+                (Some(best), Some(slot)) => Some(best.saturating_sub(slot)),
+                _ => None,
+            };
+        }
+
+        let best_by = |premium_allowed: bool| {
+            results.iter()
+                .filter(|r| premium_allowed || !r.endpoint.premium)
+                .filter_map(|r| r.score(&self.config).map(|score| (r, score)))
+                .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        };
+
+        let mut round = ProbeRound::default();
+
+        if let Some((best, best_score)) = best_by(true) {
+            let mut execution = self.execution.write().expect("endpoint lock poisoned");
+            let current_score = results.iter()
+                .find(|r| r.endpoint.http_url == execution.http_url)
+                .and_then(|r| r.score(&self.config));
+
+            let should_switch = best.endpoint.http_url != execution.http_url
+                && current_score.is_none_or(|current| current - best_score > self.config.switch_margin_ms);
+
+            if should_switch {
+                info!("⚡ Execution RPC switched to {} (score {:.0}ms, was {})",
+                    redact_url(&best.endpoint.http_url), best_score,
+                    current_score.map(|s| format!("{:.0}ms", s)).unwrap_or_else(|| "unhealthy".to_string()));
+                round.execution_switch = Some((redact_url(&execution.http_url), redact_url(&best.endpoint.http_url)));
+                *execution = best.endpoint.clone();
+            }
+        }
+
+        if let Some((best, _)) = best_by(false) {
+            *self.analytics.write().expect("endpoint lock poisoned") = best.endpoint.clone();
+        }
+
+        *self.health.write().expect("endpoint lock poisoned") = results.clone();
+        round.results = results;
+        round
+    }
+}
@@ -0,0 +1,52 @@
+use dashmap::DashMap;
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+use crate::core::MarketEvent;
+
+/// How long an event id is remembered
+const DEFAULT_DEDUP_WINDOW: Duration = Duration::from_secs(600);
+
+/// Remembered ids after which expired entries are pruned
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Drops market events that were already processed
+///
+/// Reconnects and backfills can deliver the same notification twice; acting
+/// on it again would buy the same launch twice.
+pub struct EventDeduplicator {
+    seen: DashMap<String, Instant>,
+    window: Duration,
+}
+
+impl Default for EventDeduplicator {
+    fn default() -> Self {
+        Self::new(DEFAULT_DEDUP_WINDOW)
+    }
+}
+
+impl EventDeduplicator {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            seen: DashMap::new(),
+            window,
+        }
+    }
+
+    /// Record the event; false if it was already seen within the window
+    pub fn first_sighting(&self, event: &MarketEvent) -> bool {
+        let now = Instant::now();
+        if self.seen.len() > PRUNE_THRESHOLD {
+            self.seen.retain(|_, seen_at| now.duration_since(*seen_at) < self.window);
+        }
+
+        let event_id = event.get_event_id();
+        match self.seen.insert(event_id.clone(), now) {
+            Some(seen_at) if now.duration_since(seen_at) < self.window => {
+                debug!("Duplicate market event {} dropped", event_id);
+                false
+            }
+            _ => true,
+        }
+    }
+}
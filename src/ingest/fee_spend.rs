@@ -0,0 +1,92 @@
+use anyhow::{Context, Result};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::UiTransactionEncoding;
+use std::str::FromStr;
+
+use super::commitment::at_least_confirmed;
+
+/// Jito block engine tip accounts
+pub const JITO_TIP_ACCOUNTS: &[&str] = &[
+    "96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5",
+    "HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe",
+    "Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY",
+    "ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49",
+    "DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh",
+    "ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt",
+    "DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL",
+    "3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT",
+];
+
+/// Lamports charged per transaction signature
+const BASE_FEE_LAMPORTS_PER_SIGNATURE: u64 = 5000;
+
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+/// Fees a wallet paid for one transaction
+#[derive(Debug, Clone)]
+pub struct TxFeeSpend {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    /// Base signature fees (SOL)
+    pub network_sol: f64,
+    /// Compute-unit price paid above the base fee (SOL)
+    pub priority_sol: f64,
+    pub jito_tip_sol: f64,
+}
+
+/// Fees paid by `wallet` in a transaction; None when the wallet was not the fee payer
+///
+/// The transaction fee is split into base signature fees and the priority
+/// fee above them; Jito tips are the balance increase of tip accounts.
+pub async fn fetch_fee_spend(rpc_client: &RpcClient, wallet: &str, signature: &str) -> Result<Option<TxFeeSpend>> {
+    let parsed_signature = Signature::from_str(signature).context("Invalid transaction signature")?;
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        commitment: Some(at_least_confirmed(rpc_client.commitment())),
+        max_supported_transaction_version: Some(0),
+    };
+
+    let confirmed = rpc_client
+        .get_transaction_with_config(&parsed_signature, config)
+        .await
+        .with_context(|| format!("Failed to fetch transaction {}", signature))?;
+
+    let meta = match &confirmed.transaction.meta {
+        Some(meta) => meta,
+        None => return Ok(None),
+    };
+    let transaction = match confirmed.transaction.transaction.decode() {
+        Some(transaction) => transaction,
+        None => return Ok(None),
+    };
+    let account_keys: Vec<String> = transaction.message
+        .static_account_keys()
+        .iter()
+        .map(|k| k.to_string())
+        .collect();
+
+    // The fee payer is always the first account
+    if account_keys.first().map(String::as_str) != Some(wallet) {
+        return Ok(None);
+    }
+
+    let base_fee = BASE_FEE_LAMPORTS_PER_SIGNATURE * transaction.signatures.len() as u64;
+    let priority_fee = meta.fee.saturating_sub(base_fee);
+    let jito_tip: u64 = account_keys.iter()
+        .enumerate()
+        .filter(|(_, key)| JITO_TIP_ACCOUNTS.contains(&key.as_str()))
+        .filter_map(|(i, _)| Some(meta.post_balances.get(i)?.saturating_sub(*meta.pre_balances.get(i)?)))
+        .sum();
+
+    Ok(Some(TxFeeSpend {
+        signature: signature.to_string(),
+        slot: confirmed.slot,
+        block_time: confirmed.block_time,
+        network_sol: base_fee.min(meta.fee) as f64 / LAMPORTS_PER_SOL,
+        priority_sol: priority_fee as f64 / LAMPORTS_PER_SOL,
+        jito_tip_sol: jito_tip as f64 / LAMPORTS_PER_SOL,
+    }))
+}
@@ -0,0 +1,87 @@
+use anyhow::{Context, Result};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_program::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use spl_token::state::Account as TokenAccount;
+use std::str::FromStr;
+use tracing::debug;
+
+use crate::core::{HolderBalance, HolderDistribution};
+
+/// Fetch the top-holder distribution of a token
+///
+/// Uses `getTokenLargestAccounts` and resolves the owner of each token account
+/// with one `getMultipleAccounts` call. Accounts owned by (or equal to) one of
+/// `pool_accounts` are treated as liquidity and excluded from circulating supply.
+///
+/// # Arguments
+/// * `token_mint` - Token to snapshot
+/// * `pool_accounts` - Bonding curve, pool and vault addresses holding liquidity for the token
+///
+/// # Returns
+/// * `Result<HolderDistribution>` - Largest holders with shares of circulating supply
+pub async fn fetch_holder_distribution(
+    rpc_client: &RpcClient,
+    token_mint: &str,
+    pool_accounts: &[String],
+) -> Result<HolderDistribution> {
+    let mint = Pubkey::from_str(token_mint).context("Invalid token mint")?;
+    let commitment = rpc_client.commitment();
+
+    let supply: u64 = rpc_client.get_token_supply_with_commitment(&mint, commitment).await
+        .with_context(|| format!("Failed to fetch supply of {}", token_mint))?
+        .value
+        .amount
+        .parse()
+        .context("Invalid token supply")?;
+
+    let largest = rpc_client.get_token_largest_accounts_with_commitment(&mint, commitment).await
+        .with_context(|| format!("Failed to fetch largest accounts of {}", token_mint))?;
+    let slot = largest.context.slot;
+
+    let addresses: Vec<Pubkey> = largest.value.iter()
+        .filter_map(|account| Pubkey::from_str(&account.address).ok())
+        .collect();
+    let owners: Vec<Option<String>> = rpc_client.get_multiple_accounts(&addresses).await
+        .context("Failed to resolve token account owners")?
+        .into_iter()
+        .map(|account| {
+            account
+                .and_then(|account| TokenAccount::unpack(&account.data).ok())
+                .map(|token_account| token_account.owner.to_string())
+        })
+        .collect();
+
+    let mut holders: Vec<HolderBalance> = largest.value.iter()
+        .zip(owners)
+        .filter_map(|(account, owner)| {
+            let amount = account.amount.amount.parse::<u64>().ok()?;
+            let is_pool = pool_accounts.iter().any(|pool| {
+                *pool == account.address || owner.as_deref() == Some(pool.as_str())
+            });
+            Some(HolderBalance {
+                token_account: account.address.clone(),
+                owner,
+                amount,
+                share: 0.0,
+                is_pool,
+            })
+        })
+        .collect();
+
+    let pooled: u64 = holders.iter().filter(|h| h.is_pool).map(|h| h.amount).sum();
+    let circulating = supply.saturating_sub(pooled);
+    for holder in holders.iter_mut().filter(|h| !h.is_pool) {
+        holder.share = if circulating > 0 { holder.amount as f64 / circulating as f64 } else { 0.0 };
+    }
+
+    debug!("👥 {} largest holders of {} at slot {} ({} pooled)", holders.len(), token_mint, slot, pooled);
+
+    Ok(HolderDistribution {
+        token_mint: token_mint.to_string(),
+        slot,
+        supply,
+        circulating,
+        holders,
+    })
+}
@@ -0,0 +1,111 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::str::FromStr;
+use tracing::debug;
+
+use super::commitment::at_least_confirmed;
+use super::trade_decoder::{fetch_wallet_activity, WalletActivity};
+
+/// Configuration for the startup backfill of missed insider activity
+#[derive(Debug, Clone)]
+pub struct InsiderBackfillConfig {
+    /// How far back to look for insiders with no processed transaction yet (seconds)
+    pub lookback_secs: i64,
+    /// Transactions replayed per insider at most; older ones are left out
+    pub max_signatures_per_wallet: usize,
+    /// Signatures requested per `getSignaturesForAddress` page
+    pub page_size: usize,
+}
+
+impl Default for InsiderBackfillConfig {
+    fn default() -> Self {
+        Self {
+            lookback_secs: 24 * 3600,
+            max_signatures_per_wallet: 200,
+            page_size: 100,
+        }
+    }
+}
+
+/// Successful transaction signatures of a wallet after `until`, oldest first
+///
+/// Pages backwards from the newest signature until `until` is reached, or
+/// (without `until`) until signatures are older than the lookback window.
+///
+/// # Arguments
+/// * `wallet` - Insider wallet address
+/// * `until` - Last signature already processed for the wallet
+/// * `config` - Backfill limits
+pub async fn signatures_since(
+    rpc_client: &RpcClient,
+    wallet: &str,
+    until: Option<&str>,
+    config: &InsiderBackfillConfig,
+) -> Result<Vec<String>> {
+    let pubkey = Pubkey::from_str(wallet).context("Invalid wallet address")?;
+    let until = until.map(Signature::from_str).transpose().context("Invalid last signature")?;
+    let cutoff = Utc::now().timestamp() - config.lookback_secs;
+
+    let mut signatures = Vec::new();
+    let mut before = None;
+    loop {
+        let page = rpc_client.get_signatures_for_address_with_config(&pubkey, GetConfirmedSignaturesForAddress2Config {
+            before,
+            until,
+            limit: Some(config.page_size),
+            commitment: Some(at_least_confirmed(rpc_client.commitment())),
+        }).await
+            .with_context(|| format!("Failed to fetch signatures of {}", wallet))?;
+
+        let page_len = page.len();
+        let mut reached_cutoff = false;
+        for status in page {
+            before = Signature::from_str(&status.signature).ok();
+            if until.is_none() && status.block_time.is_some_and(|block_time| block_time < cutoff) {
+                reached_cutoff = true;
+                break;
+            }
+            if status.err.is_none() {
+                signatures.push(status.signature);
+            }
+        }
+
+        if reached_cutoff || page_len < config.page_size || signatures.len() >= config.max_signatures_per_wallet {
+            break;
+        }
+    }
+
+    signatures.truncate(config.max_signatures_per_wallet);
+    signatures.reverse();
+    Ok(signatures)
+}
+
+/// Decode every trade and liquidity change a wallet made after `until`, oldest first
+///
+/// # Returns
+/// * `Result<Vec<WalletActivity>>` - Activities in chain order; transactions that
+///   were not a swap, LP change or SOL transfer are skipped
+pub async fn fetch_missed_activity(
+    rpc_client: &RpcClient,
+    wallet: &str,
+    until: Option<&str>,
+    config: &InsiderBackfillConfig,
+) -> Result<Vec<WalletActivity>> {
+    let signatures = signatures_since(rpc_client, wallet, until, config).await?;
+
+    let mut activities = Vec::new();
+    for signature in &signatures {
+        match fetch_wallet_activity(rpc_client, wallet, signature).await {
+            Ok(Some(activity)) => activities.push(activity),
+            Ok(None) => {}
+            Err(e) => debug!("Failed to decode backfilled transaction {}: {}", signature, e),
+        }
+    }
+
+    debug!("⏪ {} missed transactions of {} decoded to {} activities", signatures.len(), wallet, activities.len());
+    Ok(activities)
+}
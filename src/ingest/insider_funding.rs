@@ -0,0 +1,126 @@
+use dashmap::DashMap;
+use tracing::debug;
+
+use super::trade_decoder::SolTransferData;
+
+/// Configuration for insider-to-insider funding detection
+#[derive(Debug, Clone)]
+pub struct InsiderFundingConfig {
+    /// Smallest transfer between tracked insiders that counts as funding (SOL)
+    pub min_transfer_sol: f64,
+    /// How long both wallets stay flagged after a funding transfer (seconds)
+    pub flag_secs: i64,
+    /// Size multiplier for copy trades of a flagged wallet; 1.0 flags without boosting
+    pub copy_boost: f64,
+}
+
+impl Default for InsiderFundingConfig {
+    fn default() -> Self {
+        Self {
+            min_transfer_sol: 0.5,
+            flag_secs: 3600,
+            copy_boost: 1.25,
+        }
+    }
+}
+
+impl InsiderFundingConfig {
+    /// Defaults with overrides from `BADGER_INSIDER_FUNDING_MIN_SOL`, `BADGER_INSIDER_FUNDING_FLAG_SECS`
+    /// and `BADGER_INSIDER_FUNDING_BOOST` when set
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(min_sol) = std::env::var("BADGER_INSIDER_FUNDING_MIN_SOL").ok().and_then(|v| v.parse().ok()) {
+            config.min_transfer_sol = min_sol;
+        }
+        if let Some(secs) = std::env::var("BADGER_INSIDER_FUNDING_FLAG_SECS").ok().and_then(|v| v.parse().ok()) {
+            config.flag_secs = secs;
+        }
+        if let Some(boost) = std::env::var("BADGER_INSIDER_FUNDING_BOOST").ok().and_then(|v| v.parse().ok()) {
+            config.copy_boost = boost;
+        }
+        config
+    }
+}
+
+/// A wallet's part in a recent funding transfer with another tracked insider
+#[derive(Debug, Clone)]
+pub struct FundingFlag {
+    /// The other insider in the transfer
+    pub counterparty: String,
+    /// Set when this wallet received the SOL
+    pub funded: bool,
+    pub sol_amount: f64,
+    pub signature: String,
+    pub expires_at: i64,
+}
+
+/// Flags tracked insiders that just moved SOL to or from another tracked insider
+///
+/// Intra-cohort funding often precedes a coordinated push, so both wallets are
+/// flagged for a while and the copy engine may size their buys up. Both sides
+/// can report the same transfer; it is counted once per signature.
+pub struct InsiderFundingMonitor {
+    config: InsiderFundingConfig,
+    /// Wallet -> its latest funding flag
+    flags: DashMap<String, FundingFlag>,
+    /// Transfer signature -> when its flags expire
+    seen: DashMap<String, i64>,
+}
+
+impl InsiderFundingMonitor {
+    pub fn new(config: Option<InsiderFundingConfig>) -> Self {
+        Self {
+            config: config.unwrap_or_default(),
+            flags: DashMap::new(),
+            seen: DashMap::new(),
+        }
+    }
+
+    pub fn config(&self) -> &InsiderFundingConfig {
+        &self.config
+    }
+
+    /// Flag both wallets of a transfer between two tracked insiders
+    ///
+    /// # Returns
+    /// * `bool` - True when the transfer is new and large enough to alert on
+    pub fn record(&self, transfer: &SolTransferData, now: i64) -> bool {
+        self.flags.retain(|_, flag| flag.expires_at > now);
+        self.seen.retain(|_, expires_at| *expires_at > now);
+        if transfer.sol_amount < self.config.min_transfer_sol || transfer.from_wallet == transfer.to_wallet {
+            return false;
+        }
+
+        let expires_at = now + self.config.flag_secs;
+        if self.seen.insert(transfer.signature.clone(), expires_at).is_some() {
+            return false;
+        }
+        for (wallet, counterparty, funded) in [
+            (&transfer.from_wallet, &transfer.to_wallet, false),
+            (&transfer.to_wallet, &transfer.from_wallet, true),
+        ] {
+            self.flags.insert(wallet.clone(), FundingFlag {
+                counterparty: counterparty.clone(),
+                funded,
+                sol_amount: transfer.sol_amount,
+                signature: transfer.signature.clone(),
+                expires_at,
+            });
+        }
+        debug!("🔗 Flagged insiders {} -> {} for {}s ({:.3} SOL)",
+            transfer.from_wallet, transfer.to_wallet, self.config.flag_secs, transfer.sol_amount);
+        true
+    }
+
+    /// A wallet's unexpired funding flag
+    pub fn flag(&self, wallet: &str, now: i64) -> Option<FundingFlag> {
+        self.flags.get(wallet)
+            .filter(|flag| flag.expires_at > now)
+            .map(|flag| flag.clone())
+    }
+
+    /// Wallets flagged right now
+    pub fn flagged_count(&self, now: i64) -> usize {
+        self.flags.iter().filter(|flag| flag.expires_at > now).count()
+    }
+}
@@ -0,0 +1,323 @@
+use anyhow::Result;
+use chrono::Utc;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::{debug, info, warn, instrument};
+
+use crate::database::analytics::InsiderProfile;
+use super::commitment::{at_least_confirmed, parse_commitment};
+use super::trade_decoder::{fetch_wallet_activity, WalletActivity};
+use super::websocket::SolanaWebSocketClient;
+
+/// Configuration for insider subscription slot allocation
+#[derive(Debug, Clone)]
+pub struct SubscriptionSlotConfig {
+    /// Maximum account subscriptions allocated to insider wallets
+    pub max_account_subscriptions: usize,
+    /// How often to re-rank wallets and rebalance slots (seconds)
+    pub rebalance_interval_secs: u64,
+    /// Wallets polled per polling round
+    pub poll_batch_size: usize,
+    /// Interval between polling rounds (seconds)
+    pub poll_interval_secs: u64,
+    /// Half-life for recency decay of wallet activity (hours)
+    pub activity_half_life_hours: f64,
+    /// Commitment used for insider account subscriptions
+    pub commitment: String,
+}
+
+impl Default for SubscriptionSlotConfig {
+    fn default() -> Self {
+        Self {
+            max_account_subscriptions: 100,
+            rebalance_interval_secs: 60,
+            poll_batch_size: 25,
+            poll_interval_secs: 30,
+            activity_half_life_hours: 24.0,
+            commitment: "confirmed".to_string(),
+        }
+    }
+}
+
+/// Signatures fetched per `getSignaturesForAddress` page
+const SIGNATURE_PAGE_SIZE: usize = 1000;
+
+/// Pages read per wallet and poll before older signatures are given up on
+const MAX_SIGNATURE_PAGES: usize = 5;
+
+/// New transactions of a polled wallet
+#[derive(Debug, Clone)]
+pub struct PolledActivity {
+    pub wallet: String,
+    /// Successful transaction signatures since the previous poll, oldest first
+    pub signatures: Vec<String>,
+}
+
+/// Result of a rebalance pass
+#[derive(Debug, Clone, Default)]
+pub struct RebalanceOutcome {
+    pub subscribed: usize,
+    pub unsubscribed: usize,
+    pub polled: usize,
+}
+
+/// Allocates limited WebSocket subscription slots to the highest-priority insiders
+///
+/// Wallets are ranked by confidence and recency of activity. The top N get live
+/// account subscriptions; the rest rotate through periodic RPC polling, and any
+/// activity found while polling raises the wallet's priority for the next rebalance.
+pub struct InsiderSubscriptionManager {
+    client: Arc<SolanaWebSocketClient>,
+    rpc_client: RpcClient,
+    config: SubscriptionSlotConfig,
+    /// Subscribed wallets (address -> subscription request id)
+    subscribed: HashMap<String, u64>,
+    /// Wallets waiting for their polling turn
+    poll_rotation: VecDeque<String>,
+    /// Last signature seen per polled wallet
+    last_signatures: HashMap<String, String>,
+    /// Activity observed through polling (address -> unix timestamp)
+    polled_activity: HashMap<String, i64>,
+}
+
+impl InsiderSubscriptionManager {
+    pub fn new(client: Arc<SolanaWebSocketClient>, rpc_url: &str, config: Option<SubscriptionSlotConfig>) -> Self {
+        let config = config.unwrap_or_default();
+        // Polling reads signature history, which has no processed level
+        let commitment = parse_commitment(&config.commitment)
+            .map(at_least_confirmed)
+            .unwrap_or_else(CommitmentConfig::confirmed);
+        Self {
+            client,
+            rpc_client: RpcClient::new_with_commitment(rpc_url.to_string(), commitment),
+            config,
+            subscribed: HashMap::new(),
+            poll_rotation: VecDeque::new(),
+            last_signatures: HashMap::new(),
+            polled_activity: HashMap::new(),
+        }
+    }
+
+    pub fn config(&self) -> &SubscriptionSlotConfig {
+        &self.config
+    }
+
+    /// Priority score for a wallet: confidence weighted by activity recency
+    pub fn priority_score(&self, profile: &InsiderProfile, now: i64) -> f64 {
+        let last_activity = self.polled_activity
+            .get(&profile.wallet_address)
+            .copied()
+            .unwrap_or(profile.last_activity)
+            .max(profile.last_activity);
+
+        let hours_idle = ((now - last_activity).max(0)) as f64 / 3600.0;
+        let recency = 0.5f64.powf(hours_idle / self.config.activity_half_life_hours);
+        let frequency_bonus = profile.trading_frequency.min(10.0) / 10.0;
+
+        profile.confidence_score * (0.3 + 0.7 * recency) + frequency_bonus * 10.0
+    }
+
+    /// Forget all subscriptions after a reconnect; the next rebalance re-subscribes
+    pub fn reset_subscriptions(&mut self) {
+        if !self.subscribed.is_empty() {
+            info!("🔄 Reset {} insider subscriptions after reconnect", self.subscribed.len());
+        }
+        self.subscribed.clear();
+    }
+
+    /// Re-rank wallets and move subscription slots to the current top N
+    ///
+    /// `probation` wallets (discovery candidates) are only ever polled, after
+    /// every ranked insider.
+    #[instrument(skip(self, profiles, probation))]
+    pub async fn rebalance(&mut self, profiles: &[InsiderProfile], probation: &[String]) -> Result<RebalanceOutcome> {
+        let now = Utc::now().timestamp();
+        let on_probation: HashSet<&str> = probation.iter().map(String::as_str).collect();
+        let mut ranked: Vec<(&InsiderProfile, f64)> = profiles.iter()
+            .filter(|p| Pubkey::from_str(&p.wallet_address).is_ok())
+            .filter(|p| !on_probation.contains(p.wallet_address.as_str()))
+            .map(|p| (p, self.priority_score(p, now)))
+            .collect();
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        let desired: HashSet<&str> = ranked.iter()
+            .take(self.config.max_account_subscriptions)
+            .map(|(p, _)| p.wallet_address.as_str())
+            .collect();
+
+        let mut outcome = RebalanceOutcome::default();
+
+        // Release slots held by wallets that dropped out of the top N
+        let to_release: Vec<String> = self.subscribed.keys()
+            .filter(|wallet| !desired.contains(wallet.as_str()))
+            .cloned()
+            .collect();
+        for wallet in to_release {
+            if let Some(request_id) = self.subscribed.remove(&wallet) {
+                if let Err(e) = self.client.unsubscribe_account(request_id).await {
+                    warn!("Failed to unsubscribe insider {}: {}", wallet, e);
+                }
+                outcome.unsubscribed += 1;
+            }
+        }
+
+        // Fill free slots with newly promoted wallets
+        for wallet in &desired {
+            if self.subscribed.contains_key(*wallet) {
+                continue;
+            }
+            match self.client.subscribe_account(wallet, &self.config.commitment).await {
+                Ok(request_id) => {
+                    self.subscribed.insert(wallet.to_string(), request_id);
+                    outcome.subscribed += 1;
+                }
+                Err(e) => {
+                    // Not connected yet - retry on the next rebalance
+                    debug!("Failed to subscribe insider {}: {}", wallet, e);
+                    break;
+                }
+            }
+        }
+
+        // Everyone else rotates through polling, highest priority first
+        self.poll_rotation = ranked.iter()
+            .map(|(p, _)| p.wallet_address.clone())
+            .filter(|wallet| !self.subscribed.contains_key(wallet))
+            .chain(probation.iter().filter(|wallet| Pubkey::from_str(wallet).is_ok()).cloned())
+            .collect();
+        outcome.polled = self.poll_rotation.len();
+
+        Ok(outcome)
+    }
+
+    /// Poll the next batch of low-priority wallets for new activity
+    ///
+    /// Signature history is paged back to the last signature seen, so every
+    /// transaction since the previous poll is returned, not just the newest.
+    ///
+    /// # Returns
+    /// * `Result<Vec<PolledActivity>>` - Wallets with new on-chain activity since last poll
+    #[instrument(skip(self))]
+    pub async fn poll_next_batch(&mut self) -> Result<Vec<PolledActivity>> {
+        let batch_size = self.config.poll_batch_size.min(self.poll_rotation.len());
+        let mut active_wallets = Vec::new();
+
+        for _ in 0..batch_size {
+            let wallet = match self.poll_rotation.pop_front() {
+                Some(wallet) => wallet,
+                None => break,
+            };
+
+            let pubkey = match Pubkey::from_str(&wallet) {
+                Ok(pubkey) => pubkey,
+                Err(_) => continue,
+            };
+
+            match self.new_signatures(&wallet, &pubkey).await {
+                Ok(Some(signatures)) if !signatures.is_empty() => {
+                    let newest = &signatures[signatures.len() - 1];
+                    self.last_signatures.insert(wallet.clone(), newest.signature.clone());
+                    let seen_at = newest.block_time.unwrap_or_else(|| Utc::now().timestamp());
+                    self.polled_activity.insert(wallet.clone(), seen_at);
+                    active_wallets.push(PolledActivity {
+                        wallet: wallet.clone(),
+                        // Failed transactions moved no funds
+                        signatures: signatures.into_iter()
+                            .filter(|status| status.err.is_none())
+                            .map(|status| status.signature)
+                            .collect(),
+                    });
+                }
+                Ok(_) => {}
+                Err(e) => debug!("Failed to poll insider {}: {}", wallet, e),
+            }
+
+            // Rotate to the back of the queue
+            self.poll_rotation.push_back(wallet);
+        }
+
+        Ok(active_wallets)
+    }
+
+    /// Signatures of a wallet since its last polled signature, oldest first
+    ///
+    /// # Returns
+    /// * `Result<Option<Vec<RpcConfirmedTransactionStatusWithSignature>>>` - None on the first
+    ///   poll, which only establishes a baseline
+    async fn new_signatures(
+        &mut self,
+        wallet: &str,
+        pubkey: &Pubkey,
+    ) -> Result<Option<Vec<RpcConfirmedTransactionStatusWithSignature>>> {
+        let Some(previous) = self.last_signatures.get(wallet) else {
+            let config = GetConfirmedSignaturesForAddress2Config {
+                limit: Some(1),
+                ..Default::default()
+            };
+            let latest = self.rpc_client.get_signatures_for_address_with_config(pubkey, config).await?;
+            if let Some(latest) = latest.first() {
+                self.last_signatures.insert(wallet.to_string(), latest.signature.clone());
+            }
+            return Ok(None);
+        };
+        let until = Signature::from_str(previous)?;
+
+        // Pages come newest first; each continues before the oldest of the last
+        let mut signatures = Vec::new();
+        let mut before = None;
+        for _ in 0..MAX_SIGNATURE_PAGES {
+            let config = GetConfirmedSignaturesForAddress2Config {
+                before,
+                until: Some(until),
+                limit: Some(SIGNATURE_PAGE_SIZE),
+                ..Default::default()
+            };
+            let page = self.rpc_client.get_signatures_for_address_with_config(pubkey, config).await?;
+            let complete = page.len() < SIGNATURE_PAGE_SIZE;
+            before = match page.last() {
+                Some(oldest) => Some(Signature::from_str(&oldest.signature)?),
+                None => None,
+            };
+            signatures.extend(page);
+            if complete || before.is_none() {
+                signatures.reverse();
+                return Ok(Some(signatures));
+            }
+        }
+
+        warn!("Insider {} made more than {} transactions since the last poll; decoding the newest only",
+            wallet, signatures.len());
+        signatures.reverse();
+        Ok(Some(signatures))
+    }
+
+    /// Decode the exact trade, liquidity change or SOL transfer in one of a polled wallet's transactions
+    ///
+    /// # Returns
+    /// * `Result<Option<WalletActivity>>` - None when the transaction was not a SOL <-> token swap, LP change or SOL transfer
+    pub async fn fetch_activity(&self, wallet: &str, signature: &str) -> Result<Option<WalletActivity>> {
+        fetch_wallet_activity(&self.rpc_client, wallet, signature).await
+    }
+
+    pub fn subscribed_count(&self) -> usize {
+        self.subscribed.len()
+    }
+}
+
+/// Derive the HTTP RPC endpoint from a WebSocket endpoint
+pub fn http_url_from_ws(ws_url: &str) -> String {
+    if let Some(rest) = ws_url.strip_prefix("wss://") {
+        format!("https://{}", rest)
+    } else if let Some(rest) = ws_url.strip_prefix("ws://") {
+        format!("http://{}", rest)
+    } else {
+        ws_url.to_string()
+    }
+}
@@ -0,0 +1,189 @@
+use anyhow::Result;
+use dashmap::DashMap;
+use serde_json::Value;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::debug;
+
+use crate::core::MarketEvent;
+use super::dex_parsers::DexEventParser;
+use super::trade_decoder::fetch_transaction;
+
+/// Signatures remembered after which expired entries are pruned
+const PRUNE_THRESHOLD: usize = 20_000;
+
+/// Configuration for logs-based transaction ingestion
+#[derive(Debug, Clone)]
+pub struct LogIngestConfig {
+    /// Subscribe to DEX program logs and fetch each transaction
+    pub enabled: bool,
+    /// Transactions fetched at once; notifications beyond this are dropped
+    pub max_in_flight: usize,
+    /// Fetch attempts per signature while the node has not caught up to it
+    pub fetch_attempts: u32,
+    /// Delay between fetch attempts (milliseconds)
+    pub retry_delay_ms: u64,
+    /// How long a signature is remembered, since several programs log the same transaction (seconds)
+    pub dedup_window_secs: u64,
+}
+
+impl Default for LogIngestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_in_flight: 16,
+            fetch_attempts: 3,
+            retry_delay_ms: 400,
+            dedup_window_secs: 120,
+        }
+    }
+}
+
+impl LogIngestConfig {
+    /// Defaults with overrides from `BADGER_WS_LOGS` (`1` enables) and `BADGER_WS_LOGS_MAX_IN_FLIGHT` when set
+    pub fn from_env() -> Self {
+        let mut config = Self {
+            enabled: std::env::var("BADGER_WS_LOGS").is_ok_and(|v| v == "1"),
+            ..Self::default()
+        };
+        if let Some(max_in_flight) = std::env::var("BADGER_WS_LOGS_MAX_IN_FLIGHT").ok().and_then(|v| v.parse().ok()) {
+            config.max_in_flight = max_in_flight;
+        }
+        config
+    }
+}
+
+/// Counters of the logs ingestion path
+#[derive(Debug, Clone, Default)]
+pub struct LogIngestStats {
+    pub notifications: u64,
+    /// Logs of failed transactions, which moved nothing
+    pub failed: u64,
+    pub duplicates: u64,
+    /// Dropped because `max_in_flight` fetches were running
+    pub dropped: u64,
+    pub fetched: u64,
+    pub swaps: u64,
+}
+
+/// Turns `logsSubscribe` notifications into decoded swap events
+///
+/// A logs notification carries only the signature and log lines. Each new,
+/// successful signature is fetched in full and handed to `DexEventParser`, so
+/// swaps carry the signer's exact amounts. Fetches are bounded; when the RPC
+/// falls behind, new notifications are dropped rather than queued.
+pub struct LogTransactionIngest {
+    config: LogIngestConfig,
+    in_flight: Arc<Semaphore>,
+    /// Signature -> when it was first seen
+    seen: DashMap<String, Instant>,
+    notifications: AtomicU64,
+    failed: AtomicU64,
+    duplicates: AtomicU64,
+    dropped: AtomicU64,
+    fetched: AtomicU64,
+    swaps: AtomicU64,
+}
+
+impl LogTransactionIngest {
+    pub fn new(config: Option<LogIngestConfig>) -> Self {
+        let config = config.unwrap_or_default();
+        Self {
+            in_flight: Arc::new(Semaphore::new(config.max_in_flight.max(1))),
+            config,
+            seen: DashMap::new(),
+            notifications: AtomicU64::new(0),
+            failed: AtomicU64::new(0),
+            duplicates: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+            fetched: AtomicU64::new(0),
+            swaps: AtomicU64::new(0),
+        }
+    }
+
+    pub fn config(&self) -> &LogIngestConfig {
+        &self.config
+    }
+
+    /// Claim a logs notification for fetching
+    ///
+    /// # Returns
+    /// * `Option<(String, OwnedSemaphorePermit)>` - The signature and a fetch slot to hold until
+    ///   the fetch ends; None for failed, already seen or over-limit transactions
+    pub fn admit(&self, data: &Value) -> Option<(String, OwnedSemaphorePermit)> {
+        self.notifications.fetch_add(1, Ordering::Relaxed);
+        let value = data.get("value")?;
+        if !value.get("err").is_none_or(Value::is_null) {
+            self.failed.fetch_add(1, Ordering::Relaxed);
+            return None;
+        }
+        let signature = value.get("signature")?.as_str()?;
+
+        let now = Instant::now();
+        let window = Duration::from_secs(self.config.dedup_window_secs);
+        if self.seen.len() > PRUNE_THRESHOLD {
+            self.seen.retain(|_, seen_at| now.duration_since(*seen_at) < window);
+        }
+        if self.seen.get(signature).is_some_and(|seen_at| now.duration_since(*seen_at) < window) {
+            self.duplicates.fetch_add(1, Ordering::Relaxed);
+            return None;
+        }
+
+        // Only fetched signatures are remembered; a dropped one is retried when another program logs it
+        match self.in_flight.clone().try_acquire_owned() {
+            Ok(permit) => {
+                self.seen.insert(signature.to_string(), now);
+                Some((signature.to_string(), permit))
+            }
+            Err(_) => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+                debug!("Logs fetch limit reached; dropped {}", signature);
+                None
+            }
+        }
+    }
+
+    /// Fetch an admitted signature and decode its market events
+    ///
+    /// # Arguments
+    /// * `is_tracked` - Wallets besides the signer whose swaps in the transaction are decoded
+    pub async fn fetch_events(
+        &self,
+        rpc_client: &RpcClient,
+        signature: &str,
+        is_tracked: impl Fn(&str) -> bool,
+    ) -> Result<Vec<MarketEvent>> {
+        let mut attempt = 1;
+        let tx = loop {
+            match fetch_transaction(rpc_client, signature).await {
+                Ok(Some(tx)) => break tx,
+                Ok(None) => return Ok(Vec::new()),
+                // Logs arrive at the sniper's commitment, which may be ahead of a confirmed read
+                Err(_) if attempt < self.config.fetch_attempts => {
+                    attempt += 1;
+                    tokio::time::sleep(Duration::from_millis(self.config.retry_delay_ms)).await;
+                }
+                Err(e) => return Err(e),
+            }
+        };
+        self.fetched.fetch_add(1, Ordering::Relaxed);
+
+        let events = DexEventParser::parse_transaction(&tx, is_tracked);
+        self.swaps.fetch_add(events.len() as u64, Ordering::Relaxed);
+        Ok(events)
+    }
+
+    pub fn stats(&self) -> LogIngestStats {
+        LogIngestStats {
+            notifications: self.notifications.load(Ordering::Relaxed),
+            failed: self.failed.load(Ordering::Relaxed),
+            duplicates: self.duplicates.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            fetched: self.fetched.load(Ordering::Relaxed),
+            swaps: self.swaps.load(Ordering::Relaxed),
+        }
+    }
+}
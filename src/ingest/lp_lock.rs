@@ -0,0 +1,353 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_program::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::option_serializer::OptionSerializer;
+use solana_transaction_status::UiTransactionEncoding;
+use spl_token::state::Account as TokenAccount;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use tracing::{debug, warn};
+
+use super::commitment::at_least_confirmed;
+
+/// Address LP tokens are sent to when burned by transfer rather than a Burn instruction
+pub const INCINERATOR: &str = "1nc1nerator11111111111111111111111111111111";
+
+/// Where a locker program keeps the unlock time of a lock
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockLayout {
+    /// Streamflow contract metadata: `end_time` (u64) after magic, version, created_at, withdrawn and canceled_at
+    Streamflow,
+    /// Jupiter Lock vesting escrow: cliff time plus `frequency * number_of_period`
+    JupiterLock,
+    /// Tokens never come back out (Raydium Burn & Earn)
+    Permanent,
+    /// Unlock time is not read; the lock counts only when no minimum duration is required
+    Unknown,
+}
+
+/// Program LP tokens are locked with
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockerProgram {
+    pub name: String,
+    pub program_id: String,
+    pub layout: LockLayout,
+}
+
+impl LockerProgram {
+    fn new(name: &str, program_id: &str, layout: LockLayout) -> Self {
+        Self {
+            name: name.to_string(),
+            program_id: program_id.to_string(),
+            layout,
+        }
+    }
+
+    /// Unlock time of a lock from one of this program's state accounts
+    fn unlock_at(&self, data: &[u8]) -> Option<i64> {
+        let read_u64 = |offset: usize| data.get(offset..offset + 8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().expect("8 bytes")));
+        let unlock_at = match self.layout {
+            LockLayout::Streamflow => read_u64(33)?,
+            LockLayout::JupiterLock => {
+                let (cliff_time, frequency, periods) = (read_u64(144)?, read_u64(152)?, read_u64(176)?);
+                cliff_time.checked_add(frequency.checked_mul(periods)?)?
+            }
+            LockLayout::Permanent | LockLayout::Unknown => return None,
+        };
+        // Anything outside 2020 - 2200 is not a timestamp; the layout did not match
+        (1_577_836_800..7_258_118_400).contains(&unlock_at).then_some(unlock_at as i64)
+    }
+}
+
+/// Configuration for LP burn and lock verification
+#[derive(Debug, Clone)]
+pub struct LpLockConfig {
+    /// Most recent LP mint transactions scanned for burns and locks
+    pub signature_limit: usize,
+    /// Locker programs whose escrows count as locked LP
+    pub lockers: Vec<LockerProgram>,
+}
+
+impl Default for LpLockConfig {
+    fn default() -> Self {
+        Self {
+            signature_limit: 200,
+            lockers: vec![
+                LockerProgram::new("Streamflow", "strmRqUCoQUgGUan5YhzUZa6KqdzwX5L6FpUxfmKg5m", LockLayout::Streamflow),
+                LockerProgram::new("Jupiter Lock", "LocpQgucEQHbqNABEYvBvwoxCPsSbG91A1QaQhQQqjn", LockLayout::JupiterLock),
+                LockerProgram::new("Raydium Burn & Earn", "LockrWmn6K5twhz3y9w1dQERbmgSaRkfnTeTKbpofwE", LockLayout::Permanent),
+            ],
+        }
+    }
+}
+
+impl LpLockConfig {
+    /// Defaults plus lockers from `BADGER_LP_LOCKER_PROGRAMS` when set
+    ///
+    /// Extra lockers are `name=program_id` pairs separated by commas; their
+    /// unlock time is not read.
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Ok(value) = std::env::var("BADGER_LP_LOCKER_PROGRAMS") {
+            for entry in value.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+                match entry.split_once('=') {
+                    Some((name, program_id)) if Pubkey::from_str(program_id.trim()).is_ok() => {
+                        config.lockers.push(LockerProgram::new(name.trim(), program_id.trim(), LockLayout::Unknown));
+                    }
+                    _ => warn!("Ignoring LP locker {:?}: expected name=program_id", entry),
+                }
+            }
+        }
+        config
+    }
+}
+
+/// LP tokens held in one locker escrow
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LpLock {
+    pub locker: String,
+    pub escrow_account: String,
+    /// LP tokens still in the escrow
+    pub amount: u64,
+    /// When the tokens can be withdrawn; None when permanent or unknown
+    pub unlock_at: Option<i64>,
+    pub permanent: bool,
+    /// Transaction that locked them
+    pub signature: String,
+}
+
+impl LpLock {
+    /// Whether the lock holds for at least `min_secs` after `at`
+    pub fn holds_for(&self, min_secs: i64, at: i64) -> bool {
+        self.permanent || min_secs <= 0 || self.unlock_at.is_some_and(|unlock_at| unlock_at - at >= min_secs)
+    }
+}
+
+/// How much of a pool's LP supply was burned or locked
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LpLockStatus {
+    pub lp_mint: String,
+    /// LP tokens in existence now
+    pub supply: u64,
+    /// LP tokens destroyed by Burn instructions
+    pub burned: u64,
+    /// LP tokens sent to the incinerator; still in supply but never movable again
+    pub incinerated: u64,
+    pub locks: Vec<LpLock>,
+    /// Transactions scanned, newest first
+    pub scanned_signatures: usize,
+    pub checked_at: i64,
+}
+
+impl LpLockStatus {
+    /// LP tokens ever minted that are accounted for: current supply plus burned
+    fn minted(&self) -> u64 {
+        self.supply.saturating_add(self.burned)
+    }
+
+    fn share(&self, amount: u64) -> f64 {
+        match self.minted() {
+            0 => 0.0,
+            minted => amount as f64 / minted as f64,
+        }
+    }
+
+    /// Share of minted LP that was burned or incinerated
+    pub fn burned_share(&self) -> f64 {
+        self.share(self.burned.saturating_add(self.incinerated))
+    }
+
+    /// Share of minted LP locked for at least `min_lock_secs` from the check
+    pub fn locked_share(&self, min_lock_secs: i64) -> f64 {
+        let locked: u64 = self.locks.iter()
+            .filter(|lock| lock.holds_for(min_lock_secs, self.checked_at))
+            .map(|lock| lock.amount)
+            .sum();
+        self.share(locked)
+    }
+
+    /// Share of minted LP that can no longer be pulled within `min_lock_secs`
+    pub fn secured_share(&self, min_lock_secs: i64) -> f64 {
+        (self.burned_share() + self.locked_share(min_lock_secs)).min(1.0)
+    }
+
+    /// Shortest remaining lock, None without locks or when every lock is permanent or unknown
+    pub fn shortest_lock_secs(&self) -> Option<i64> {
+        self.locks.iter()
+            .filter_map(|lock| lock.unlock_at)
+            .map(|unlock_at| unlock_at - self.checked_at)
+            .min()
+    }
+}
+
+/// Verify how much of a pool's LP supply was burned or locked
+///
+/// Scans the most recent transactions of the LP mint. A transaction in which
+/// LP token balances shrink overall burned the difference; LP landing in
+/// incinerator-owned accounts counts as burned too. LP moved into an account
+/// not owned by a signer in a transaction that calls a known locker program
+/// is a lock, counted at the escrow's current balance so withdrawn locks no
+/// longer count.
+///
+/// # Arguments
+/// * `lp_mint` - LP token mint of the pool
+/// * `config` - Locker programs and scan depth
+///
+/// # Returns
+/// * `Result<LpLockStatus>` - Supply, burned amount and remaining locks
+pub async fn fetch_lp_lock_status(rpc_client: &RpcClient, lp_mint: &str, config: &LpLockConfig) -> Result<LpLockStatus> {
+    let mint = Pubkey::from_str(lp_mint).context("Invalid LP mint")?;
+    let commitment = at_least_confirmed(rpc_client.commitment());
+
+    let supply: u64 = rpc_client.get_token_supply_with_commitment(&mint, commitment).await
+        .with_context(|| format!("Failed to fetch supply of LP mint {}", lp_mint))?
+        .value
+        .amount
+        .parse()
+        .context("Invalid LP supply")?;
+
+    let signatures = rpc_client.get_signatures_for_address_with_config(&mint, GetConfirmedSignaturesForAddress2Config {
+        before: None,
+        until: None,
+        limit: Some(config.signature_limit),
+        commitment: Some(commitment),
+    }).await
+        .with_context(|| format!("Failed to fetch signatures of LP mint {}", lp_mint))?;
+
+    let lockers: HashMap<&str, &LockerProgram> = config.lockers.iter()
+        .map(|locker| (locker.program_id.as_str(), locker))
+        .collect();
+    let tx_config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        commitment: Some(commitment),
+        max_supported_transaction_version: Some(0),
+    };
+
+    let (mut burned, mut incinerated) = (0u64, 0u64);
+    // Escrow account -> (locker, locking signature, locker state accounts of the transaction)
+    let mut candidates: HashMap<String, (&LockerProgram, String, Vec<Pubkey>)> = HashMap::new();
+
+    for entry in signatures.iter().filter(|entry| entry.err.is_none()) {
+        let Ok(signature) = Signature::from_str(&entry.signature) else {
+            continue;
+        };
+        let confirmed = match rpc_client.get_transaction_with_config(&signature, tx_config).await {
+            Ok(confirmed) => confirmed,
+            Err(e) => {
+                debug!("Skipping LP mint transaction {}: {}", entry.signature, e);
+                continue;
+            }
+        };
+        let (Some(meta), Some(transaction)) = (&confirmed.transaction.meta, confirmed.transaction.transaction.decode()) else {
+            continue;
+        };
+
+        // Lookup table addresses follow the static keys, writable first
+        let mut account_keys: Vec<String> = transaction.message.static_account_keys().iter().map(|k| k.to_string()).collect();
+        if let OptionSerializer::Some(loaded) = &meta.loaded_addresses {
+            account_keys.extend(loaded.writable.iter().cloned());
+            account_keys.extend(loaded.readonly.iter().cloned());
+        }
+        let signers: HashSet<&str> = account_keys.iter()
+            .take(transaction.message.header().num_required_signatures as usize)
+            .map(String::as_str)
+            .collect();
+
+        // Per LP token account: (owner, pre, post)
+        let mut balances: HashMap<u8, (Option<String>, u64, u64)> = HashMap::new();
+        for (is_post, token_balances) in [(false, &meta.pre_token_balances), (true, &meta.post_token_balances)] {
+            let OptionSerializer::Some(token_balances) = token_balances else {
+                continue;
+            };
+            for balance in token_balances.iter().filter(|balance| balance.mint == lp_mint) {
+                let amount = balance.ui_token_amount.amount.parse::<u64>().unwrap_or(0);
+                let owner = match &balance.owner {
+                    OptionSerializer::Some(owner) => Some(owner.clone()),
+                    _ => None,
+                };
+                let slot = balances.entry(balance.account_index).or_insert((None, 0, 0));
+                slot.0 = slot.0.take().or(owner);
+                if is_post { slot.2 = amount } else { slot.1 = amount }
+            }
+        }
+
+        let (pre_total, post_total) = balances.values().fold((0u64, 0u64), |(pre, post), (_, before, after)| (pre + before, post + after));
+        burned += pre_total.saturating_sub(post_total);
+        incinerated += balances.values()
+            .filter(|(owner, _, _)| owner.as_deref() == Some(INCINERATOR))
+            .map(|(_, before, after)| after.saturating_sub(*before))
+            .sum::<u64>();
+
+        let Some(locker) = account_keys.iter().find_map(|key| lockers.get(key.as_str()).copied()) else {
+            continue;
+        };
+        let state_accounts: Vec<Pubkey> = account_keys.iter()
+            .filter(|key| !signers.contains(key.as_str()) && key.as_str() != locker.program_id)
+            .filter_map(|key| Pubkey::from_str(key).ok())
+            .collect();
+        for (account_index, (owner, before, after)) in &balances {
+            let is_escrow = after > before
+                && owner.as_deref().is_some_and(|owner| !signers.contains(owner) && owner != INCINERATOR);
+            if let (true, Some(escrow)) = (is_escrow, account_keys.get(*account_index as usize)) {
+                candidates.entry(escrow.clone())
+                    .or_insert_with(|| (locker, entry.signature.clone(), state_accounts.clone()));
+            }
+        }
+    }
+
+    let mut locks = Vec::new();
+    for (escrow, (locker, signature, state_accounts)) in candidates {
+        let Ok(escrow_key) = Pubkey::from_str(&escrow) else {
+            continue;
+        };
+        let amount = rpc_client.get_account_with_commitment(&escrow_key, commitment).await
+            .ok()
+            .and_then(|response| response.value)
+            .and_then(|account| TokenAccount::unpack(&account.data).ok())
+            .map_or(0, |token_account| token_account.amount);
+        if amount == 0 {
+            continue;
+        }
+
+        let unlock_at = match locker.layout {
+            LockLayout::Streamflow | LockLayout::JupiterLock => {
+                let program_id = Pubkey::from_str(&locker.program_id).ok();
+                rpc_client.get_multiple_accounts(&state_accounts).await
+                    .unwrap_or_default()
+                    .into_iter()
+                    .flatten()
+                    .filter(|account| Some(account.owner) == program_id)
+                    .find_map(|account| locker.unlock_at(&account.data))
+            }
+            LockLayout::Permanent | LockLayout::Unknown => None,
+        };
+        locks.push(LpLock {
+            locker: locker.name.clone(),
+            escrow_account: escrow,
+            amount,
+            unlock_at,
+            permanent: locker.layout == LockLayout::Permanent,
+            signature,
+        });
+    }
+
+    debug!("🔒 LP {}: supply {}, burned {}, incinerated {}, {} locks over {} transactions",
+        lp_mint, supply, burned, incinerated, locks.len(), signatures.len());
+    Ok(LpLockStatus {
+        lp_mint: lp_mint.to_string(),
+        supply,
+        burned,
+        incinerated,
+        locks,
+        scanned_signatures: signatures.len(),
+        checked_at: Utc::now().timestamp(),
+    })
+}
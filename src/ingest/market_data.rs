@@ -0,0 +1,582 @@
+use anyhow::{Context, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+use crate::core::SOL_MINT;
+
+/// Configuration for the shared market-data client pool
+#[derive(Debug, Clone)]
+pub struct MarketDataConfig {
+    /// Requests per minute across all providers
+    pub global_requests_per_minute: u32,
+    /// How long a fetched quote is served from cache (seconds)
+    pub cache_ttl_secs: u64,
+    /// Timeout for a single provider request (milliseconds)
+    pub request_timeout_ms: u64,
+    /// Consecutive failures before a provider is benched
+    pub max_consecutive_failures: u32,
+    /// How long a benched provider is skipped (seconds)
+    pub failure_cooldown_secs: u64,
+    /// First back-off after a provider answers HTTP 429 without Retry-After (seconds)
+    pub rate_limit_backoff_secs: u64,
+    /// Longest back-off of a provider that keeps answering HTTP 429 (seconds)
+    pub max_rate_limit_backoff_secs: u64,
+}
+
+impl Default for MarketDataConfig {
+    fn default() -> Self {
+        Self {
+            global_requests_per_minute: 300,
+            cache_ttl_secs: 15,
+            request_timeout_ms: 5000,
+            max_consecutive_failures: 3,
+            failure_cooldown_secs: 60,
+            rate_limit_backoff_secs: 10,
+            max_rate_limit_backoff_secs: 300,
+        }
+    }
+}
+
+/// Market data for one token from whichever provider answered
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenMarketData {
+    pub token_mint: String,
+    /// Price in SOL per token, the unit positions are tracked in
+    pub price_sol: f64,
+    pub price_usd: Option<f64>,
+    pub liquidity_usd: Option<f64>,
+    pub volume_24h_usd: Option<f64>,
+    pub provider: String,
+}
+
+/// Provider answered HTTP 429; `retry_after` is its Retry-After header when sent
+#[derive(Debug, Clone)]
+pub struct RateLimited {
+    pub provider: String,
+    pub retry_after: Option<Duration>,
+}
+
+impl std::fmt::Display for RateLimited {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.retry_after {
+            Some(retry_after) => write!(f, "{} rate limited, retry after {}s", self.provider, retry_after.as_secs()),
+            None => write!(f, "{} rate limited", self.provider),
+        }
+    }
+}
+
+impl std::error::Error for RateLimited {}
+
+/// `RateLimited` error for a 429 response, otherwise the response unchanged
+fn check_rate_limit(provider: &str, response: reqwest::Response) -> Result<reqwest::Response> {
+    if response.status() != reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Ok(response);
+    }
+    let retry_after = response.headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs);
+    Err(RateLimited { provider: provider.to_string(), retry_after }.into())
+}
+
+/// Source of token prices and market stats (DexScreener, Birdeye, ...)
+///
+/// Providers only fetch; throttling, caching and failover live in
+/// [`MarketDataPool`] so every provider gets them the same way.
+pub trait MarketDataProvider: Send + Sync {
+    /// Provider name for logging and stats
+    fn name(&self) -> &str;
+
+    /// Requests per minute the provider allows us
+    fn requests_per_minute(&self) -> u32;
+
+    /// Fetch market data; `Ok(None)` when the provider does not know the token
+    fn fetch<'a>(
+        &'a self,
+        client: &'a Client,
+        token_mint: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<TokenMarketData>>> + Send + 'a>>;
+}
+
+/// DexScreener token endpoint; no API key needed
+pub struct DexScreenerProvider {
+    base_url: String,
+}
+
+impl DexScreenerProvider {
+    pub fn new() -> Self {
+        Self {
+            base_url: "https://api.dexscreener.com/latest/dex/tokens".to_string(),
+        }
+    }
+}
+
+impl Default for DexScreenerProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct DexScreenerResponse {
+    pairs: Option<Vec<DexScreenerPair>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DexScreenerPair {
+    chain_id: String,
+    base_token: DexScreenerToken,
+    quote_token: DexScreenerToken,
+    price_native: Option<String>,
+    price_usd: Option<String>,
+    liquidity: Option<DexScreenerLiquidity>,
+    volume: Option<DexScreenerVolume>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DexScreenerToken {
+    address: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DexScreenerLiquidity {
+    usd: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DexScreenerVolume {
+    h24: Option<f64>,
+}
+
+impl MarketDataProvider for DexScreenerProvider {
+    fn name(&self) -> &str {
+        "dexscreener"
+    }
+
+    /// Documented limit of the token endpoints
+    fn requests_per_minute(&self) -> u32 {
+        300
+    }
+
+    fn fetch<'a>(
+        &'a self,
+        client: &'a Client,
+        token_mint: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<TokenMarketData>>> + Send + 'a>> {
+        Box::pin(async move {
+            let response = client
+                .get(format!("{}/{}", self.base_url, token_mint))
+                .send()
+                .await?;
+            let response: DexScreenerResponse = check_rate_limit(self.name(), response)?
+                .error_for_status()?
+                .json()
+                .await
+                .context("Invalid DexScreener response")?;
+
+            // Deepest SOL-quoted pair on Solana where the token is the base
+            let pair = response.pairs.unwrap_or_default()
+                .into_iter()
+                .filter(|pair| pair.chain_id == "solana"
+                    && pair.base_token.address == token_mint
+                    && pair.quote_token.address == SOL_MINT)
+                .max_by(|a, b| {
+                    let depth = |pair: &DexScreenerPair| pair.liquidity.as_ref().and_then(|l| l.usd).unwrap_or(0.0);
+                    depth(a).total_cmp(&depth(b))
+                });
+
+            let Some(pair) = pair else {
+                return Ok(None);
+            };
+            let Some(price_sol) = pair.price_native.as_deref().and_then(|p| p.parse().ok()) else {
+                return Ok(None);
+            };
+
+            Ok(Some(TokenMarketData {
+                token_mint: token_mint.to_string(),
+                price_sol,
+                price_usd: pair.price_usd.as_deref().and_then(|p| p.parse().ok()),
+                liquidity_usd: pair.liquidity.and_then(|l| l.usd),
+                volume_24h_usd: pair.volume.and_then(|v| v.h24),
+                provider: self.name().to_string(),
+            }))
+        })
+    }
+}
+
+/// Birdeye multi-price endpoint; needs an API key
+pub struct BirdeyeProvider {
+    base_url: String,
+    api_key: String,
+    requests_per_minute: u32,
+}
+
+impl BirdeyeProvider {
+    /// # Arguments
+    /// * `api_key` - Birdeye API key
+    /// * `requests_per_minute` - Request allowance of the key's plan
+    pub fn new(api_key: &str, requests_per_minute: u32) -> Self {
+        Self {
+            base_url: "https://public-api.birdeye.so/defi/multi_price".to_string(),
+            api_key: api_key.to_string(),
+            requests_per_minute,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BirdeyeResponse {
+    data: Option<HashMap<String, Option<BirdeyePrice>>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BirdeyePrice {
+    value: f64,
+    liquidity: Option<f64>,
+}
+
+impl MarketDataProvider for BirdeyeProvider {
+    fn name(&self) -> &str {
+        "birdeye"
+    }
+
+    fn requests_per_minute(&self) -> u32 {
+        self.requests_per_minute
+    }
+
+    fn fetch<'a>(
+        &'a self,
+        client: &'a Client,
+        token_mint: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<TokenMarketData>>> + Send + 'a>> {
+        Box::pin(async move {
+            // Birdeye prices in USD; fetch SOL alongside to convert in one request
+            let response = client
+                .get(&self.base_url)
+                .query(&[("list_address", format!("{},{}", token_mint, SOL_MINT))])
+                .header("X-API-KEY", &self.api_key)
+                .header("x-chain", "solana")
+                .send()
+                .await?;
+            let response: BirdeyeResponse = check_rate_limit(self.name(), response)?
+                .error_for_status()?
+                .json()
+                .await
+                .context("Invalid Birdeye response")?;
+
+            let mut prices = response.data.unwrap_or_default();
+            let token = prices.remove(token_mint).flatten();
+            let sol = prices.remove(SOL_MINT).flatten();
+
+            let (Some(token), Some(sol)) = (token, sol) else {
+                return Ok(None);
+            };
+            if sol.value <= 0.0 {
+                return Ok(None);
+            }
+
+            Ok(Some(TokenMarketData {
+                token_mint: token_mint.to_string(),
+                price_sol: token.value / sol.value,
+                price_usd: Some(token.value),
+                liquidity_usd: token.liquidity,
+                volume_24h_usd: None,
+                provider: self.name().to_string(),
+            }))
+        })
+    }
+}
+
+/// Token bucket refilled continuously up to one minute of allowance
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_sec: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn per_minute(requests_per_minute: u32) -> Self {
+        let capacity = requests_per_minute.max(1) as f64;
+        Self {
+            capacity,
+            tokens: capacity,
+            refill_per_sec: capacity / 60.0,
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.last_refill = now;
+    }
+}
+
+/// Throttling and health of one provider
+#[derive(Debug)]
+struct ProviderState {
+    bucket: TokenBucket,
+    consecutive_failures: u32,
+    /// 429 answers in a row, doubling the back-off each time
+    consecutive_rate_limits: u32,
+    benched_until: Option<Instant>,
+    stats: ProviderStats,
+}
+
+/// A provider with its shared throttle state
+type ProviderSlot = (Arc<dyn MarketDataProvider>, Arc<Mutex<ProviderState>>);
+
+/// Throttle state of every provider in the process, keyed by provider name
+///
+/// Rate limits are per IP or API key, not per pool, so every pool (and every
+/// consumer of one) draws on the same buckets and sees the same back-offs.
+fn provider_state(provider: &dyn MarketDataProvider) -> Arc<Mutex<ProviderState>> {
+    static STATES: OnceLock<Mutex<HashMap<String, Arc<Mutex<ProviderState>>>>> = OnceLock::new();
+    STATES.get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap()
+        .entry(provider.name().to_string())
+        .or_insert_with(|| Arc::new(Mutex::new(ProviderState {
+            bucket: TokenBucket::per_minute(provider.requests_per_minute()),
+            consecutive_failures: 0,
+            consecutive_rate_limits: 0,
+            benched_until: None,
+            stats: ProviderStats {
+                name: provider.name().to_string(),
+                ..Default::default()
+            },
+        })))
+        .clone()
+}
+
+/// Request counters of one provider
+#[derive(Debug, Clone, Default)]
+pub struct ProviderStats {
+    pub name: String,
+    pub requests: u64,
+    pub failures: u64,
+    /// Requests skipped because the provider or pool budget was spent
+    pub throttled: u64,
+    /// HTTP 429 answers
+    pub rate_limited: u64,
+    pub benched: bool,
+}
+
+/// Rate-limited, cached market-data client pool with provider failover
+///
+/// Requests are throttled twice: by each provider's own allowance and by a
+/// pool-wide budget shared by all providers. Providers are tried in priority
+/// order; one that keeps failing is benched for a cooldown and the next takes
+/// over. A provider answering HTTP 429 is backed off (per Retry-After when
+/// sent, doubling otherwise) and its bucket emptied. Provider budgets are
+/// shared by every pool in the process. Answers are cached per token so
+/// concurrent consumers share requests.
+pub struct MarketDataPool {
+    config: MarketDataConfig,
+    client: Client,
+    providers: Vec<ProviderSlot>,
+    global_bucket: Mutex<TokenBucket>,
+    cache: RwLock<HashMap<String, (Instant, TokenMarketData)>>,
+}
+
+impl MarketDataPool {
+    /// # Arguments
+    /// * `providers` - Providers in priority order
+    /// * `config` - Pool configuration
+    pub fn new(providers: Vec<Arc<dyn MarketDataProvider>>, config: MarketDataConfig) -> Result<Self> {
+        let client = Client::builder()
+            .timeout(Duration::from_millis(config.request_timeout_ms))
+            .build()
+            .context("Failed to build market data HTTP client")?;
+
+        let providers = providers.into_iter()
+            .map(|provider| {
+                let state = provider_state(provider.as_ref());
+                (provider, state)
+            })
+            .collect();
+
+        Ok(Self {
+            global_bucket: Mutex::new(TokenBucket::per_minute(config.global_requests_per_minute)),
+            client,
+            providers,
+            cache: RwLock::new(HashMap::new()),
+            config,
+        })
+    }
+
+    /// DexScreener first, then Birdeye when `BADGER_BIRDEYE_API_KEY` is set
+    pub fn from_env(config: MarketDataConfig) -> Result<Self> {
+        let mut providers: Vec<Arc<dyn MarketDataProvider>> = vec![Arc::new(DexScreenerProvider::new())];
+        if let Ok(api_key) = std::env::var("BADGER_BIRDEYE_API_KEY") {
+            providers.push(Arc::new(BirdeyeProvider::new(&api_key, 60)));
+        }
+        Self::new(providers, config)
+    }
+
+    pub fn config(&self) -> &MarketDataConfig {
+        &self.config
+    }
+
+    /// Market data for a token, from cache or the first provider that answers
+    ///
+    /// # Returns
+    /// * `Result<Option<TokenMarketData>>` - `None` when no provider knows the token
+    ///   or every provider is throttled with nothing cached
+    pub async fn get(&self, token_mint: &str) -> Result<Option<TokenMarketData>> {
+        let ttl = Duration::from_secs(self.config.cache_ttl_secs);
+        let cached = self.cache.read().unwrap().get(token_mint).cloned();
+        if let Some((fetched_at, data)) = &cached {
+            if fetched_at.elapsed() < ttl {
+                return Ok(Some(data.clone()));
+            }
+        }
+
+        let mut last_error = None;
+        for (provider, state) in &self.providers {
+            if !self.acquire(state) {
+                continue;
+            }
+
+            match provider.fetch(&self.client, token_mint).await {
+                Ok(data) => {
+                    self.record_success(state);
+                    if let Some(data) = data {
+                        self.cache.write().unwrap()
+                            .insert(token_mint.to_string(), (Instant::now(), data.clone()));
+                        return Ok(Some(data));
+                    }
+                }
+                Err(e) if e.downcast_ref::<RateLimited>().is_some() => {
+                    let retry_after = e.downcast_ref::<RateLimited>().and_then(|limited| limited.retry_after);
+                    self.record_rate_limited(provider.name(), state, retry_after);
+                    last_error = Some(e);
+                }
+                Err(e) => {
+                    self.record_failure(provider.name(), state);
+                    debug!("{} failed for {}: {}", provider.name(), token_mint, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        // Stale data beats none while every provider is throttled or failing
+        if let Some((_, data)) = cached {
+            return Ok(Some(data));
+        }
+        match last_error {
+            Some(e) => Err(e.context(format!("All market data providers failed for {}", token_mint))),
+            None => Ok(None),
+        }
+    }
+
+    /// Take one request from the provider's and the pool's budgets
+    fn acquire(&self, state: &Mutex<ProviderState>) -> bool {
+        let mut state = state.lock().unwrap();
+        if let Some(benched_until) = state.benched_until {
+            if Instant::now() < benched_until {
+                return false;
+            }
+            state.benched_until = None;
+            state.stats.benched = false;
+        }
+
+        let mut global = self.global_bucket.lock().unwrap();
+        state.bucket.refill();
+        global.refill();
+        if state.bucket.tokens < 1.0 || global.tokens < 1.0 {
+            state.stats.throttled += 1;
+            return false;
+        }
+
+        state.bucket.tokens -= 1.0;
+        global.tokens -= 1.0;
+        state.stats.requests += 1;
+        true
+    }
+
+    fn record_success(&self, state: &Mutex<ProviderState>) {
+        let mut state = state.lock().unwrap();
+        state.consecutive_failures = 0;
+        state.consecutive_rate_limits = 0;
+    }
+
+    /// Back the provider off after a 429 and spend its remaining budget
+    fn record_rate_limited(&self, name: &str, state: &Mutex<ProviderState>, retry_after: Option<Duration>) {
+        let mut state = state.lock().unwrap();
+        state.stats.rate_limited += 1;
+        state.consecutive_rate_limits += 1;
+        let backoff = retry_after.unwrap_or_else(|| {
+            let doublings = (state.consecutive_rate_limits - 1).min(10);
+            Duration::from_secs(self.config.rate_limit_backoff_secs.saturating_mul(1 << doublings))
+        }).min(Duration::from_secs(self.config.max_rate_limit_backoff_secs));
+
+        warn!("🐢 Market data provider {} rate limited, backing off {}s", name, backoff.as_secs());
+        // Refill from the end of the back-off, not in a burst right after it
+        state.bucket.tokens = 0.0;
+        state.bucket.last_refill = Instant::now() + backoff;
+        state.benched_until = Some(Instant::now() + backoff);
+        state.stats.benched = true;
+    }
+
+    fn record_failure(&self, name: &str, state: &Mutex<ProviderState>) {
+        let mut state = state.lock().unwrap();
+        state.stats.failures += 1;
+        state.consecutive_failures += 1;
+        if state.consecutive_failures >= self.config.max_consecutive_failures {
+            warn!("📉 Benching market data provider {} for {}s after {} failures",
+                name, self.config.failure_cooldown_secs, state.consecutive_failures);
+            state.benched_until = Some(Instant::now() + Duration::from_secs(self.config.failure_cooldown_secs));
+            state.stats.benched = true;
+            state.consecutive_failures = 0;
+        }
+    }
+
+    /// Requests that can be made now without waiting on a budget
+    ///
+    /// The pool budget capped by the summed budgets of providers that are
+    /// not benched; callers polling many tokens size a batch with it.
+    pub fn available_requests(&self) -> usize {
+        let now = Instant::now();
+        let providers: f64 = self.providers.iter()
+            .map(|(_, state)| {
+                let mut state = state.lock().unwrap();
+                if state.benched_until.is_some_and(|until| now < until) {
+                    return 0.0;
+                }
+                state.bucket.refill();
+                state.bucket.tokens
+            })
+            .sum();
+        let mut global = self.global_bucket.lock().unwrap();
+        global.refill();
+        global.tokens.min(providers).max(0.0) as usize
+    }
+
+    /// Request counters of every provider, in priority order
+    pub fn provider_stats(&self) -> Vec<ProviderStats> {
+        self.providers.iter()
+            .map(|(_, state)| state.lock().unwrap().stats.clone())
+            .collect()
+    }
+
+    /// Drop cached entries older than `max_age_secs`, including stale fallbacks
+    pub fn prune_cache(&self, max_age_secs: u64) -> usize {
+        let max_age = Duration::from_secs(max_age_secs);
+        let mut cache = self.cache.write().unwrap();
+        let before = cache.len();
+        cache.retain(|_, (fetched_at, _)| fetched_at.elapsed() < max_age);
+        before - cache.len()
+    }
+}
@@ -0,0 +1,149 @@
+use memchr::memmem;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Notification methods the client dispatches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationMethod {
+    Account,
+    Signature,
+    Program,
+    Slot,
+    Block,
+    Logs,
+}
+
+impl NotificationMethod {
+    pub fn from_method(method: &str) -> Option<Self> {
+        match method {
+            "accountNotification" => Some(Self::Account),
+            "signatureNotification" => Some(Self::Signature),
+            "programNotification" => Some(Self::Program),
+            "slotNotification" => Some(Self::Slot),
+            "blockNotification" => Some(Self::Block),
+            "logsNotification" => Some(Self::Logs),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Account => "accountNotification",
+            Self::Signature => "signatureNotification",
+            Self::Program => "programNotification",
+            Self::Slot => "slotNotification",
+            Self::Block => "blockNotification",
+            Self::Logs => "logsNotification",
+        }
+    }
+}
+
+/// What a raw message is, read from its envelope without parsing it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageKind<'a> {
+    /// Subscription notification with a method the client dispatches
+    Notification(NotificationMethod),
+    /// Notification with a method the client does not handle
+    UnknownNotification(&'a str),
+    /// No method field: a response to one of our requests
+    Response,
+}
+
+/// Notification envelope borrowing from the message text
+///
+/// Only `params.result` is materialized; `jsonrpc` is skipped and `method`
+/// is a slice of the message, so nothing is allocated for the envelope.
+#[derive(Debug, Deserialize)]
+pub struct RawNotification<'a> {
+    #[serde(borrow)]
+    pub method: &'a str,
+    pub params: RawNotificationParams,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RawNotificationParams {
+    pub subscription: u64,
+    pub result: Value,
+}
+
+/// Classifies WebSocket messages by scanning for the `method` field
+///
+/// Program and slot notifications dominate the stream; serde_json parsing
+/// each one first as a response and then again as a notification doubled
+/// the work. Scanning the bytes for the method up front lets a message be
+/// parsed once into the shape it actually has, and lets notifications for
+/// unhandled methods be dropped before their payload is parsed at all. The
+/// searcher is built once per connection and reused for every message.
+pub struct MessageScanner {
+    method_key: memmem::Finder<'static>,
+}
+
+impl Default for MessageScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageScanner {
+    pub fn new() -> Self {
+        Self {
+            method_key: memmem::Finder::new(b"\"method\""),
+        }
+    }
+
+    /// Value of the first `"method"` field, if the message has one
+    ///
+    /// Solana puts `method` ahead of `params`, so the first occurrence is the
+    /// envelope's. Escaped method names are not expected and yield None.
+    pub fn method<'a>(&self, message: &'a str) -> Option<&'a str> {
+        let bytes = message.as_bytes();
+        let mut at = self.method_key.find(bytes)? + b"\"method\"".len();
+        at = skip_whitespace(bytes, at);
+        if bytes.get(at) != Some(&b':') {
+            return None;
+        }
+        at = skip_whitespace(bytes, at + 1);
+        if bytes.get(at) != Some(&b'"') {
+            return None;
+        }
+        let start = at + 1;
+        let len = memchr::memchr(b'"', &bytes[start..])?;
+        let method = &message[start..start + len];
+        if method.contains('\\') {
+            return None;
+        }
+        Some(method)
+    }
+
+    /// Classify a message without parsing it
+    pub fn kind<'a>(&self, message: &'a str) -> MessageKind<'a> {
+        match self.method(message) {
+            Some(method) => match NotificationMethod::from_method(method) {
+                Some(known) => MessageKind::Notification(known),
+                None => MessageKind::UnknownNotification(method),
+            },
+            None => MessageKind::Response,
+        }
+    }
+
+    /// Parse a notification with a dispatched method in a single pass
+    ///
+    /// # Returns
+    /// * `Option<(NotificationMethod, u64, Value)>` - Method, subscription id and result; None for anything else
+    pub fn decode_notification(&self, message: &str) -> Option<(NotificationMethod, u64, Value)> {
+        if !matches!(self.kind(message), MessageKind::Notification(_)) {
+            return None;
+        }
+        let notification = serde_json::from_str::<RawNotification>(message).ok()?;
+        // The scan only looked at the first "method"; the parsed envelope is authoritative
+        let method = NotificationMethod::from_method(notification.method)?;
+        Some((method, notification.params.subscription, notification.params.result))
+    }
+}
+
+fn skip_whitespace(bytes: &[u8], mut at: usize) -> usize {
+    while bytes.get(at).is_some_and(|b| b.is_ascii_whitespace()) {
+        at += 1;
+    }
+    at
+}
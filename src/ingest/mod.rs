@@ -1,6 +1,78 @@
 pub mod websocket;
 pub mod minimal_test;
 pub mod dex_parsers;
+pub mod insider_subscriptions;
+pub mod balance_cache;
+pub mod bonding_curve;
+pub mod self_trade;
+pub mod trade_decoder;
+pub mod endpoint_prober;
+pub mod holder_distribution;
+pub mod dust_scanner;
+pub mod chain_lag;
+pub mod market_data;
+pub mod insider_backfill;
+pub mod token_registry;
+pub mod fee_spend;
+pub mod event_dedup;
+pub mod token_safety;
+pub mod sniping_guard;
+pub mod message_scan;
+pub mod venue_health;
+pub mod token_age;
+pub mod token_subscriptions;
+pub mod token_metadata;
+pub mod commitment;
+pub mod poll_scheduler;
+pub mod endpoint_auth;
+pub mod lp_lock;
+pub mod momentum;
+pub mod pool_reserves;
+pub mod signature_confirmations;
+pub mod insider_funding;
+pub mod log_ingest;
+pub mod block_ingest;
 
 pub use websocket::SolanaWebSocketClient;
-pub use dex_parsers::DexEventParser;
\ No newline at end of file
+pub use dex_parsers::DexEventParser;
+pub use insider_subscriptions::{InsiderSubscriptionManager, PolledActivity, SubscriptionSlotConfig};
+pub use balance_cache::{BalanceCache, BalanceEntry, CachedBalance};
+pub use bonding_curve::BondingCurveTracker;
+pub use self_trade::{SelfTradeGuard, SelfTradeConflict, OwnSwap};
+pub use trade_decoder::{
+    TradeData, LiquidityData, LiquidityAction, SolTransferData, WalletActivity, WalletSwap, FetchedTransaction,
+    decode_wallet_trade, decode_wallet_swap, decode_wallet_liquidity, decode_wallet_sol_transfer,
+    fetch_transaction, fetch_wallet_trade, fetch_wallet_activity, resolved_account_keys, token_balance_owners,
+};
+pub use endpoint_prober::{EndpointProber, EndpointHealth, ProberConfig, RpcEndpoint};
+pub use holder_distribution::fetch_holder_distribution;
+pub use dust_scanner::{DustConfig, DustPlan, DustAccount, scan_dust};
+pub use chain_lag::{ChainLagMonitor, ChainLagConfig, ChainLagSample};
+pub use market_data::{
+    MarketDataPool, MarketDataProvider, MarketDataConfig, TokenMarketData, ProviderStats,
+    DexScreenerProvider, BirdeyeProvider, RateLimited,
+};
+pub use insider_backfill::{InsiderBackfillConfig, signatures_since, fetch_missed_activity};
+pub use token_registry::{TokenRegistry, TokenRegistryConfig, TokenInfo, TOKEN_METADATA_PROGRAM_ID};
+pub use fee_spend::{TxFeeSpend, fetch_fee_spend, JITO_TIP_ACCOUNTS};
+pub use event_dedup::EventDeduplicator;
+pub use token_safety::{TokenSafetyCache, TokenSafetyConfig, TokenSafetyReport, SafetyRequirement, fetch_safety_report};
+pub use lp_lock::{LpLockConfig, LpLockStatus, LpLock, LockerProgram, LockLayout, fetch_lp_lock_status, INCINERATOR};
+pub use sniping_guard::{SnipingGuard, SnipingGuardConfig, SnipeBlock};
+pub use momentum::{MomentumTracker, MomentumConfig, MomentumWindow, MomentumSnapshot, MomentumObservation};
+pub use pool_reserves::{PoolReserveCache, PoolReserveConfig, PoolReserves, ReservePrice, ReserveCacheStats};
+pub use signature_confirmations::{SignatureConfirmations, ConfirmationConfig, ConfirmationStats};
+pub use insider_funding::{InsiderFundingMonitor, InsiderFundingConfig, FundingFlag};
+pub use log_ingest::{LogTransactionIngest, LogIngestConfig, LogIngestStats};
+pub use block_ingest::{BlockTransactionIngest, BlockIngestConfig, BlockIngestStats};
+pub use message_scan::{MessageScanner, MessageKind, NotificationMethod};
+pub use venue_health::{VenueHealthMonitor, VenueHealthConfig, VenueHealth, VenueStatus, ExecutionVenue};
+pub use token_age::{TokenAgeService, TokenAgeConfig, TokenAge, TokenAgeSource, TokenAgeConfidence};
+pub use token_subscriptions::{TokenSubscriptionRegistry, TokenSubscriptionConfig, DeadTokenReason};
+pub use token_metadata::{
+    MetadataMonitor, MetadataMonitorConfig, TokenMetadata, MetadataField, MetadataChange,
+    parse_token_metadata, metadata_address_for_mint,
+};
+pub use commitment::{CommitmentLevels, CommitmentConsumer, parse_commitment, at_least_confirmed};
+pub use poll_scheduler::{PollScheduler, PollSchedulerConfig, PollPriority};
+pub use endpoint_auth::{EndpointAuth, EndpointSettings, AutoSubscription, redact_url};
\ No newline at end of file
@@ -0,0 +1,278 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use chrono::Utc;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use crate::core::constants::SOL_MINT;
+use crate::core::{MarketEvent, SwapType};
+
+/// Tokens tracked after which idle ones are pruned
+const PRUNE_THRESHOLD: usize = 20_000;
+
+/// Configuration for swap momentum windows
+#[derive(Debug, Clone)]
+pub struct MomentumConfig {
+    /// Width of one window (seconds of block time)
+    pub window_secs: i64,
+    /// How far behind the newest block time a swap may arrive and still count in its window (seconds)
+    pub allowed_lateness_secs: i64,
+    /// Closed windows kept per token
+    pub history_windows: usize,
+    /// Window volume, relative to the mean of the previous windows, logged as a spike
+    pub spike_ratio: f64,
+}
+
+impl Default for MomentumConfig {
+    fn default() -> Self {
+        Self {
+            window_secs: 60,
+            allowed_lateness_secs: 20,
+            history_windows: 30,
+            spike_ratio: 3.0,
+        }
+    }
+}
+
+impl MomentumConfig {
+    /// Defaults with overrides from `BADGER_MOMENTUM_WINDOW_SECS` and `BADGER_MOMENTUM_LATENESS_SECS` when set
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(secs) = std::env::var("BADGER_MOMENTUM_WINDOW_SECS").ok().and_then(|v| v.parse().ok()).filter(|secs| *secs > 0) {
+            config.window_secs = secs;
+        }
+        if let Some(secs) = std::env::var("BADGER_MOMENTUM_LATENESS_SECS").ok().and_then(|v| v.parse().ok()).filter(|secs| *secs >= 0) {
+            config.allowed_lateness_secs = secs;
+        }
+        config
+    }
+}
+
+/// SOL-paired swap volume of one token within one block-time window
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MomentumWindow {
+    /// Unix block time the window opens at
+    pub window_start: i64,
+    pub buy_volume_sol: f64,
+    pub sell_volume_sol: f64,
+    pub buys: u32,
+    pub sells: u32,
+    /// Swaps that arrived after a newer window had already opened
+    pub out_of_order: u32,
+    pub first_slot: u64,
+    pub last_slot: u64,
+}
+
+impl MomentumWindow {
+    pub fn volume_sol(&self) -> f64 {
+        self.buy_volume_sol + self.sell_volume_sol
+    }
+
+    /// Net buy volume as a share of all volume, in [-1, 1]
+    pub fn buy_pressure(&self) -> f64 {
+        let volume = self.volume_sol();
+        if volume > 0.0 { (self.buy_volume_sol - self.sell_volume_sol) / volume } else { 0.0 }
+    }
+}
+
+/// Latest closed window of a token against the ones before it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MomentumSnapshot {
+    pub token_mint: String,
+    pub latest: MomentumWindow,
+    /// Latest window volume over the mean of the earlier closed windows; None without history
+    pub volume_ratio: Option<f64>,
+    pub closed_windows: usize,
+}
+
+/// What happened to an observed swap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MomentumObservation {
+    /// Counted in the window covering its block time
+    Counted,
+    /// Its window had already closed; dropped instead of landing in a later window
+    Late,
+    /// Not a SOL-paired swap
+    Ignored,
+}
+
+/// Windows of one token: still open ones by start, and closed history
+#[derive(Debug, Default)]
+struct TokenWindows {
+    open: BTreeMap<i64, MomentumWindow>,
+    closed: VecDeque<MomentumWindow>,
+    last_event_at: i64,
+}
+
+/// Per-token buy and sell volume in fixed block-time windows
+///
+/// Swaps are bucketed by their block time, not by when they were delivered,
+/// so a burst of delayed WebSocket messages spreads over the windows it
+/// happened in instead of piling into the current one as a phantom spike.
+/// A window closes once the watermark (newest block time seen, capped at the
+/// wall clock, minus the allowed lateness) passes its end; swaps for a
+/// closed window are counted as late and dropped.
+pub struct MomentumTracker {
+    config: MomentumConfig,
+    tokens: DashMap<String, TokenWindows>,
+    /// Newest block time seen (Unix seconds)
+    max_event_time: AtomicI64,
+    /// Block time of the first counted swap; 0 before any
+    first_event_time: AtomicI64,
+    /// Window boundary the last close sweep ran for
+    closed_through: AtomicI64,
+    late_events: AtomicU64,
+}
+
+impl MomentumTracker {
+    pub fn new(config: Option<MomentumConfig>) -> Self {
+        Self {
+            config: config.unwrap_or_default(),
+            tokens: DashMap::new(),
+            max_event_time: AtomicI64::new(0),
+            first_event_time: AtomicI64::new(0),
+            closed_through: AtomicI64::new(0),
+            late_events: AtomicU64::new(0),
+        }
+    }
+
+    pub fn config(&self) -> &MomentumConfig {
+        &self.config
+    }
+
+    /// Block time up to which every window is final
+    ///
+    /// Capped at the wall clock so one swap with a skewed block time cannot
+    /// close every open window early.
+    pub fn watermark(&self) -> i64 {
+        let newest = self.max_event_time.load(Ordering::Relaxed).min(Utc::now().timestamp());
+        newest - self.config.allowed_lateness_secs
+    }
+
+    /// Window widths between the first counted swap and the watermark
+    pub fn covered_windows(&self) -> i64 {
+        match self.first_event_time.load(Ordering::Relaxed) {
+            0 => 0,
+            first => ((self.watermark() - first) / self.config.window_secs).max(0),
+        }
+    }
+
+    /// Swaps dropped because their window had already closed
+    pub fn late_events(&self) -> u64 {
+        self.late_events.load(Ordering::Relaxed)
+    }
+
+    /// Count a swap in the window of its block time
+    pub fn observe(&self, event: &MarketEvent) -> MomentumObservation {
+        let MarketEvent::SwapDetected { swap } = event else {
+            return MomentumObservation::Ignored;
+        };
+        let (token_mint, lamports) = match swap.swap_type {
+            SwapType::Buy if swap.token_in == SOL_MINT => (&swap.token_out, swap.amount_in),
+            SwapType::Sell if swap.token_out == SOL_MINT => (&swap.token_in, swap.amount_out),
+            _ => return MomentumObservation::Ignored,
+        };
+        let volume_sol = lamports as f64 / 1e9;
+        let event_time = swap.timestamp.timestamp();
+        let window_start = event_time - event_time.rem_euclid(self.config.window_secs);
+
+        self.max_event_time.fetch_max(event_time, Ordering::Relaxed);
+        let watermark = self.watermark();
+        if window_start + self.config.window_secs <= watermark {
+            self.late_events.fetch_add(1, Ordering::Relaxed);
+            debug!("📈 Late swap {} for {} ({}s behind the watermark) dropped",
+                swap.signature, token_mint, watermark - event_time);
+            return MomentumObservation::Late;
+        }
+
+        if self.tokens.len() > PRUNE_THRESHOLD {
+            let horizon = watermark - self.config.window_secs * self.config.history_windows as i64;
+            self.tokens.retain(|_, windows| windows.last_event_at >= horizon);
+        }
+        let _ = self.first_event_time.compare_exchange(0, event_time, Ordering::Relaxed, Ordering::Relaxed);
+        let mut windows = self.tokens.entry(token_mint.clone()).or_default();
+        windows.last_event_at = windows.last_event_at.max(event_time);
+        let out_of_order = windows.open.keys().next_back().is_some_and(|newest| *newest > window_start);
+        let window = windows.open.entry(window_start).or_insert_with(|| MomentumWindow {
+            window_start,
+            first_slot: swap.slot,
+            ..Default::default()
+        });
+        match swap.swap_type {
+            SwapType::Buy => {
+                window.buy_volume_sol += volume_sol;
+                window.buys += 1;
+            }
+            SwapType::Sell => {
+                window.sell_volume_sol += volume_sol;
+                window.sells += 1;
+            }
+        }
+        if out_of_order {
+            window.out_of_order += 1;
+        }
+        window.first_slot = window.first_slot.min(swap.slot);
+        window.last_slot = window.last_slot.max(swap.slot);
+        drop(windows);
+
+        // Sweep once per window boundary the watermark crosses, not per swap
+        let boundary = watermark - watermark.rem_euclid(self.config.window_secs);
+        if self.closed_through.fetch_max(boundary, Ordering::Relaxed) < boundary {
+            self.close_due();
+        }
+        MomentumObservation::Counted
+    }
+
+    /// Close every window the watermark has passed
+    ///
+    /// # Returns
+    /// * `usize` - Number of windows closed
+    pub fn close_due(&self) -> usize {
+        let watermark = self.watermark();
+        let mut closed = 0;
+        for mut entry in self.tokens.iter_mut() {
+            let (token_mint, windows) = entry.pair_mut();
+            while let Some(window_start) = windows.open.keys().next().copied() {
+                if window_start + self.config.window_secs > watermark {
+                    break;
+                }
+                let Some(window) = windows.open.remove(&window_start) else { break };
+                let mean = mean_volume(windows.closed.iter());
+                if let Some(mean) = mean.filter(|mean| window.volume_sol() >= mean * self.config.spike_ratio) {
+                    info!("📈 Volume spike on {}: {:.3} SOL in {}s ({:.1}x, buy pressure {:+.2}, {} out of order)",
+                        token_mint, window.volume_sol(), self.config.window_secs, window.volume_sol() / mean,
+                        window.buy_pressure(), window.out_of_order);
+                }
+                windows.closed.push_back(window);
+                while windows.closed.len() > self.config.history_windows {
+                    windows.closed.pop_front();
+                }
+                closed += 1;
+            }
+        }
+        closed
+    }
+
+    /// Latest closed window of a token against its history
+    pub fn snapshot(&self, token_mint: &str) -> Option<MomentumSnapshot> {
+        let windows = self.tokens.get(token_mint)?;
+        let latest = windows.closed.back()?.clone();
+        let earlier = windows.closed.len() - 1;
+        let volume_ratio = mean_volume(windows.closed.iter().take(earlier))
+            .map(|mean| latest.volume_sol() / mean);
+        Some(MomentumSnapshot {
+            token_mint: token_mint.to_string(),
+            latest,
+            volume_ratio,
+            closed_windows: windows.closed.len(),
+        })
+    }
+}
+
+/// Mean volume of windows; None when there are none or they saw no volume
+fn mean_volume<'a>(windows: impl ExactSizeIterator<Item = &'a MomentumWindow>) -> Option<f64> {
+    let count = windows.len();
+    let mean = windows.map(MomentumWindow::volume_sol).sum::<f64>() / count.max(1) as f64;
+    (mean > 0.0).then_some(mean)
+}
@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Why a token is polled; earlier variants are polled first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum PollPriority {
+    /// Held token; its price drives P&L, exits and alerts
+    OpenPosition,
+    /// Token a working order waits on a price trigger for
+    Watched,
+}
+
+/// Configuration for market data polling
+#[derive(Debug, Clone)]
+pub struct PollSchedulerConfig {
+    /// Base interval between polls of a held token (seconds)
+    pub open_position_interval_secs: u64,
+    /// Base interval between polls of a watched token (seconds)
+    pub watched_interval_secs: u64,
+    /// Shortest interval any token is polled at, however volatile (seconds)
+    pub min_interval_secs: u64,
+    /// Daily volatility at which a token's interval is halved (1.0 = 100%)
+    pub reference_volatility: f64,
+    /// Longest a token that keeps failing is backed off (seconds)
+    pub max_backoff_secs: u64,
+}
+
+impl Default for PollSchedulerConfig {
+    fn default() -> Self {
+        Self {
+            open_position_interval_secs: 30,
+            watched_interval_secs: 120,
+            min_interval_secs: 5,
+            reference_volatility: 1.0,
+            max_backoff_secs: 600,
+        }
+    }
+}
+
+impl PollSchedulerConfig {
+    /// Defaults with intervals from `BADGER_PRICE_POLL_SECS` and `BADGER_PRICE_POLL_MIN_SECS` when set
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(secs) = std::env::var("BADGER_PRICE_POLL_SECS").ok().and_then(|v| v.parse::<u64>().ok()) {
+            config.open_position_interval_secs = secs.max(1);
+        }
+        if let Some(secs) = std::env::var("BADGER_PRICE_POLL_MIN_SECS").ok().and_then(|v| v.parse::<u64>().ok()) {
+            config.min_interval_secs = secs.max(1);
+        }
+        config
+    }
+}
+
+#[derive(Debug)]
+struct PollEntry {
+    priority: PollPriority,
+    daily_volatility: f64,
+    next_poll_at: Instant,
+    /// Polls in a row that returned nothing
+    failures: u32,
+}
+
+/// Decides which tokens to price next within the request budget
+///
+/// Replaces polling every held token on a fixed interval: each token has its
+/// own due time, shorter for held tokens and for volatile ones, and a token
+/// whose polls keep failing is backed off exponentially. When more tokens are
+/// due than the market data budget allows, held tokens go first, then the
+/// most volatile, then the longest overdue.
+pub struct PollScheduler {
+    config: PollSchedulerConfig,
+    entries: Mutex<HashMap<String, PollEntry>>,
+}
+
+impl PollScheduler {
+    pub fn new(config: Option<PollSchedulerConfig>) -> Self {
+        Self {
+            config: config.unwrap_or_default(),
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> &PollSchedulerConfig {
+        &self.config
+    }
+
+    /// Tokens scheduled
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Make `token_mints` the tokens polled for `priority`
+    ///
+    /// New tokens are due at once; tokens that had this priority and are not
+    /// listed any more are dropped. A token listed under two priorities keeps
+    /// the more urgent one.
+    pub fn sync(&self, priority: PollPriority, token_mints: &[String]) {
+        let listed: HashSet<&str> = token_mints.iter().map(String::as_str).collect();
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|token_mint, entry| entry.priority != priority || listed.contains(token_mint.as_str()));
+
+        let now = Instant::now();
+        for token_mint in token_mints {
+            let entry = entries.entry(token_mint.clone()).or_insert_with(|| PollEntry {
+                priority,
+                daily_volatility: 0.0,
+                next_poll_at: now,
+                failures: 0,
+            });
+            entry.priority = entry.priority.min(priority);
+        }
+    }
+
+    /// Latest realized daily volatility of a token (0.5 = 50%)
+    pub fn set_volatility(&self, token_mint: &str, daily_volatility: f64) {
+        if let Some(entry) = self.entries.lock().unwrap().get_mut(token_mint) {
+            entry.daily_volatility = daily_volatility.max(0.0);
+        }
+    }
+
+    /// Tokens due for a poll, most urgent first, at most `limit`
+    pub fn due(&self, limit: usize) -> Vec<String> {
+        let now = Instant::now();
+        let entries = self.entries.lock().unwrap();
+        let mut due: Vec<(&String, &PollEntry)> = entries.iter()
+            .filter(|(_, entry)| entry.next_poll_at <= now)
+            .collect();
+        due.sort_by(|(_, a), (_, b)| a.priority.cmp(&b.priority)
+            .then(b.daily_volatility.total_cmp(&a.daily_volatility))
+            .then(a.next_poll_at.cmp(&b.next_poll_at)));
+        due.into_iter().take(limit).map(|(token_mint, _)| token_mint.clone()).collect()
+    }
+
+    /// Schedule a token's next poll after one returned a quote (`success`) or nothing
+    pub fn record(&self, token_mint: &str, success: bool) {
+        let mut entries = self.entries.lock().unwrap();
+        let Some(entry) = entries.get_mut(token_mint) else {
+            return;
+        };
+        let interval = self.interval(entry);
+        let wait = if success {
+            entry.failures = 0;
+            interval
+        } else {
+            entry.failures = entry.failures.saturating_add(1);
+            let backoff = interval.saturating_mul(1u32 << entry.failures.min(10));
+            backoff.min(Duration::from_secs(self.config.max_backoff_secs).max(interval))
+        };
+        entry.next_poll_at = Instant::now() + wait;
+    }
+
+    /// Base interval of the token's priority, shortened by its volatility
+    fn interval(&self, entry: &PollEntry) -> Duration {
+        let base = match entry.priority {
+            PollPriority::OpenPosition => self.config.open_position_interval_secs,
+            PollPriority::Watched => self.config.watched_interval_secs,
+        } as f64;
+        let speedup = 1.0 + entry.daily_volatility / self.config.reference_volatility.max(f64::EPSILON);
+        Duration::from_secs_f64((base / speedup).max(self.config.min_interval_secs as f64))
+    }
+}
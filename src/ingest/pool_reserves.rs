@@ -0,0 +1,260 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use chrono::Utc;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::core::constants::SOL_MINT;
+use crate::core::MarketEvent;
+
+/// Configuration for pricing from cached pool reserves
+#[derive(Debug, Clone)]
+pub struct PoolReserveConfig {
+    /// Slots behind the newest slot seen after which cached reserves are stale
+    pub max_slot_lag: u64,
+    /// Wall-clock age after which cached reserves are stale, so a stalled stream cannot keep them fresh (seconds)
+    pub max_age_secs: i64,
+}
+
+impl Default for PoolReserveConfig {
+    fn default() -> Self {
+        Self {
+            max_slot_lag: 150,
+            max_age_secs: 60,
+        }
+    }
+}
+
+impl PoolReserveConfig {
+    /// Defaults with overrides from `BADGER_RESERVE_MAX_SLOT_LAG` and `BADGER_RESERVE_MAX_AGE_SECS` when set
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(slots) = std::env::var("BADGER_RESERVE_MAX_SLOT_LAG").ok().and_then(|v| v.parse().ok()) {
+            config.max_slot_lag = slots;
+        }
+        if let Some(secs) = std::env::var("BADGER_RESERVE_MAX_AGE_SECS").ok().and_then(|v| v.parse().ok()) {
+            config.max_age_secs = secs;
+        }
+        config
+    }
+}
+
+/// Decoded SOL-paired reserves of one pool or bonding curve at a slot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoolReserves {
+    pub pool_address: String,
+    pub token_mint: String,
+    /// Launchpad or DEX the reserves belong to
+    pub venue: String,
+    /// Token side of the constant product (raw units)
+    pub token_reserves: u64,
+    /// SOL side of the constant product (lamports)
+    pub sol_reserves: u64,
+    pub token_decimals: u8,
+    /// Slot the reserves were observed at
+    pub slot: u64,
+    pub observed_at: i64,
+}
+
+impl PoolReserves {
+    /// Spot price in SOL per token; None when a side is empty
+    pub fn price_sol(&self) -> Option<f64> {
+        if self.token_reserves == 0 || self.sol_reserves == 0 {
+            return None;
+        }
+        let sol = self.sol_reserves as f64 / 1_000_000_000.0;
+        let tokens = self.token_reserves as f64 / 10_f64.powi(self.token_decimals as i32);
+        Some(sol / tokens)
+    }
+}
+
+/// Price derived from cached reserves, with how fresh they were
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReservePrice {
+    pub token_mint: String,
+    pub pool_address: String,
+    pub venue: String,
+    pub price_sol: f64,
+    pub slot: u64,
+    /// Slots between the reserves and the newest slot seen
+    pub slot_lag: u64,
+    pub age_secs: i64,
+}
+
+/// Reserve-priced lookups against those that fell back to a quote
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReserveCacheStats {
+    pub pools: usize,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Latest decoded reserves per pool, tagged with the slot they were seen at
+///
+/// Bonding curve updates carry the constant-product reserves of the curve
+/// and pool creations the opening reserves of an AMM pool. Keeping the
+/// newest state per pool lets the price of a token be computed locally
+/// instead of asking a quote API. A state is only used while it is within
+/// `max_slot_lag` slots of the newest slot the stream delivered and younger
+/// than `max_age_secs`; anything older, and tokens never seen in a decoded
+/// state, fall back to the quote providers.
+pub struct PoolReserveCache {
+    config: PoolReserveConfig,
+    pools: DashMap<String, PoolReserves>,
+    /// Token mint -> pool whose reserves were observed most recently
+    pool_by_mint: DashMap<String, String>,
+    /// Token decimals from launches, needed to price AMM pools
+    decimals: DashMap<String, u8>,
+    latest_slot: AtomicU64,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl PoolReserveCache {
+    pub fn new(config: Option<PoolReserveConfig>) -> Self {
+        Self {
+            config: config.unwrap_or_default(),
+            pools: DashMap::new(),
+            pool_by_mint: DashMap::new(),
+            decimals: DashMap::new(),
+            latest_slot: AtomicU64::new(0),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn config(&self) -> &PoolReserveConfig {
+        &self.config
+    }
+
+    /// Newest slot any market event carried
+    pub fn latest_slot(&self) -> u64 {
+        self.latest_slot.load(Ordering::Relaxed)
+    }
+
+    /// Cache the reserves a market event reveals
+    ///
+    /// Expects bonding curve updates to carry their mint already (see
+    /// `BondingCurveTracker::observe`). A graduated curve is dropped, since
+    /// the token trades on an AMM from then on.
+    pub fn observe(&self, event: &MarketEvent) {
+        if let Some(slot) = event.get_slot() {
+            self.latest_slot.fetch_max(slot as u64, Ordering::Relaxed);
+        }
+        match event {
+            MarketEvent::TokenLaunched { token } => {
+                self.decimals.insert(token.mint.clone(), token.decimals);
+            }
+            MarketEvent::BondingCurveUpdated { curve, .. } => {
+                let Some(token_mint) = &curve.token_mint else { return };
+                if curve.complete {
+                    if self.pools.remove(&curve.curve_address).is_some() {
+                        self.pool_by_mint.remove_if(token_mint, |_, pool| *pool == curve.curve_address);
+                    }
+                    return;
+                }
+                self.decimals.insert(token_mint.clone(), curve.token_decimals);
+                self.store(PoolReserves {
+                    pool_address: curve.curve_address.clone(),
+                    token_mint: token_mint.clone(),
+                    venue: format!("{:?}", curve.launchpad),
+                    token_reserves: curve.virtual_token_reserves,
+                    sol_reserves: curve.virtual_sol_reserves,
+                    token_decimals: curve.token_decimals,
+                    slot: curve.slot,
+                    observed_at: Utc::now().timestamp(),
+                });
+            }
+            MarketEvent::PoolCreated { pool, .. } => {
+                let (token_mint, token_reserves, sol_reserves) = if pool.quote_mint == SOL_MINT {
+                    (&pool.base_mint, pool.initial_base_amount, pool.initial_quote_amount)
+                } else if pool.base_mint == SOL_MINT {
+                    (&pool.quote_mint, pool.initial_quote_amount, pool.initial_base_amount)
+                } else {
+                    return;
+                };
+                let Some(token_decimals) = self.decimals.get(token_mint).map(|d| *d) else {
+                    debug!("No decimals for {}; pool {} not cached for pricing", token_mint, pool.address);
+                    return;
+                };
+                self.store(PoolReserves {
+                    pool_address: pool.address.clone(),
+                    token_mint: token_mint.clone(),
+                    venue: format!("{:?}", pool.dex),
+                    token_reserves,
+                    sol_reserves,
+                    token_decimals,
+                    slot: pool.slot,
+                    observed_at: Utc::now().timestamp(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// Keep a state unless a newer slot of the same pool is already cached
+    fn store(&self, reserves: PoolReserves) {
+        if reserves.price_sol().is_none() {
+            return;
+        }
+        if self.pools.get(&reserves.pool_address).is_some_and(|cached| cached.slot > reserves.slot) {
+            return;
+        }
+        let newest_for_mint = match self.pool_by_mint.get(&reserves.token_mint) {
+            Some(pool) => self.pools.get(pool.value()).is_none_or(|cached| cached.slot <= reserves.slot),
+            None => true,
+        };
+        if newest_for_mint {
+            self.pool_by_mint.insert(reserves.token_mint.clone(), reserves.pool_address.clone());
+        }
+        self.pools.insert(reserves.pool_address.clone(), reserves);
+    }
+
+    /// Latest cached reserves of a token's pool, fresh or not
+    pub fn reserves_for_mint(&self, token_mint: &str) -> Option<PoolReserves> {
+        let pool = self.pool_by_mint.get(token_mint)?;
+        self.pools.get(pool.value()).map(|reserves| reserves.clone())
+    }
+
+    /// Price of a token from reserves still within the freshness bounds
+    ///
+    /// # Returns
+    /// * `Option<ReservePrice>` - None when the token has no cached reserves or
+    ///   they are stale; the caller falls back to a quote
+    pub fn price(&self, token_mint: &str) -> Option<ReservePrice> {
+        let price = self.reserves_for_mint(token_mint).and_then(|reserves| {
+            let slot_lag = self.latest_slot().saturating_sub(reserves.slot);
+            let age_secs = Utc::now().timestamp() - reserves.observed_at;
+            if slot_lag > self.config.max_slot_lag || age_secs > self.config.max_age_secs {
+                return None;
+            }
+            Some(ReservePrice {
+                token_mint: token_mint.to_string(),
+                price_sol: reserves.price_sol()?,
+                pool_address: reserves.pool_address,
+                venue: reserves.venue,
+                slot: reserves.slot,
+                slot_lag,
+                age_secs,
+            })
+        });
+        let counter = if price.is_some() { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        price
+    }
+
+    /// Drop all reserves of a token that is no longer followed
+    pub fn forget_mint(&self, token_mint: &str) {
+        self.pools.retain(|_, reserves| reserves.token_mint != token_mint);
+        self.pool_by_mint.remove(token_mint);
+        self.decimals.remove(token_mint);
+    }
+
+    pub fn stats(&self) -> ReserveCacheStats {
+        ReserveCacheStats {
+            pools: self.pools.len(),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
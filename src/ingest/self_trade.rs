@@ -0,0 +1,145 @@
+use dashmap::{DashMap, DashSet};
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+use crate::core::{MarketEvent, SwapType, TradingSignal};
+
+/// How long an own-wallet swap blocks opposite-side trades on the same token
+const DEFAULT_CONFLICT_WINDOW: Duration = Duration::from_secs(120);
+
+/// Swap made by one of our own wallets
+#[derive(Debug, Clone)]
+pub struct OwnSwap {
+    pub wallet: String,
+    pub token_mint: String,
+    pub swap_type: SwapType,
+    pub signature: String,
+    pub seen_at: Instant,
+}
+
+/// A signal that would trade against one of our own wallets
+#[derive(Debug, Clone)]
+pub struct SelfTradeConflict {
+    pub token_mint: String,
+    /// Side the blocked signal would have taken ("BUY" or "SELL")
+    pub blocked_action: &'static str,
+    /// Our wallet on the other side of the pool
+    pub counterparty_wallet: String,
+    pub counterparty_signature: String,
+}
+
+/// Prevents our rotated wallets from trading against each other
+///
+/// Swaps by our own addresses are remembered per token; a buy is blocked
+/// while one of our wallets is selling the same token (and vice versa), so no
+/// wallet ends up providing exit liquidity to another.
+pub struct SelfTradeGuard {
+    own_wallets: DashSet<String>,
+    /// Token mint -> recent swaps by our wallets
+    recent_swaps: DashMap<String, Vec<OwnSwap>>,
+    conflict_window: Duration,
+}
+
+impl Default for SelfTradeGuard {
+    fn default() -> Self {
+        Self::new(DEFAULT_CONFLICT_WINDOW)
+    }
+}
+
+impl SelfTradeGuard {
+    pub fn new(conflict_window: Duration) -> Self {
+        Self {
+            own_wallets: DashSet::new(),
+            recent_swaps: DashMap::new(),
+            conflict_window,
+        }
+    }
+
+    /// Register one of our trading wallets
+    pub fn register_wallet(&self, address: &str) {
+        self.own_wallets.insert(address.to_string());
+    }
+
+    pub fn is_own_wallet(&self, address: &str) -> bool {
+        self.own_wallets.contains(address)
+    }
+
+    /// Guard is only active with more than one wallet in rotation
+    pub fn is_enabled(&self) -> bool {
+        self.own_wallets.len() > 1
+    }
+
+    /// Registered wallets
+    pub fn wallets(&self) -> Vec<String> {
+        self.own_wallets.iter().map(|wallet| wallet.clone()).collect()
+    }
+
+    pub fn wallet_count(&self) -> usize {
+        self.own_wallets.len()
+    }
+
+    /// Record a swap if it was made by one of our wallets
+    ///
+    /// # Returns
+    /// * `Option<OwnSwap>` - The swap when it belongs to us, so callers can keep
+    ///   it out of insider analytics
+    pub fn observe(&self, event: &MarketEvent) -> Option<OwnSwap> {
+        let MarketEvent::SwapDetected { swap } = event else {
+            return None;
+        };
+        if !self.is_own_wallet(&swap.wallet) {
+            return None;
+        }
+
+        let token_mint = match swap.swap_type {
+            SwapType::Buy => swap.token_out.clone(),
+            SwapType::Sell => swap.token_in.clone(),
+        };
+        let own_swap = OwnSwap {
+            wallet: swap.wallet.clone(),
+            token_mint: token_mint.clone(),
+            swap_type: swap.swap_type,
+            signature: swap.signature.clone(),
+            seen_at: Instant::now(),
+        };
+
+        let mut swaps = self.recent_swaps.entry(token_mint).or_default();
+        swaps.retain(|s| s.seen_at.elapsed() < self.conflict_window);
+        swaps.push(own_swap.clone());
+
+        debug!("🪞 Own wallet {} {:?} {}", own_swap.wallet, own_swap.swap_type, own_swap.token_mint);
+        Some(own_swap)
+    }
+
+    /// Check whether executing a signal would trade against one of our wallets
+    pub fn check(&self, signal: &TradingSignal) -> Option<SelfTradeConflict> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let (token_mint, blocked_action, opposite) = match signal {
+            TradingSignal::Buy { token_mint, .. } => (token_mint, "BUY", SwapType::Sell),
+            TradingSignal::Sell { token_mint, .. } => (token_mint, "SELL", SwapType::Buy),
+            _ => return None,
+        };
+
+        let swaps = self.recent_swaps.get(token_mint)?;
+        swaps.iter()
+            .rev()
+            .find(|s| s.swap_type == opposite && s.seen_at.elapsed() < self.conflict_window)
+            .map(|s| SelfTradeConflict {
+                token_mint: token_mint.clone(),
+                blocked_action,
+                counterparty_wallet: s.wallet.clone(),
+                counterparty_signature: s.signature.clone(),
+            })
+    }
+
+    /// Drop swaps older than the conflict window
+    pub fn prune_expired(&self) {
+        self.recent_swaps.retain(|_, swaps| {
+            swaps.retain(|s| s.seen_at.elapsed() < self.conflict_window);
+            !swaps.is_empty()
+        });
+    }
+}
@@ -0,0 +1,224 @@
+use anyhow::{Result, Context, bail};
+use dashmap::DashMap;
+use dashmap::mapref::entry::Entry;
+use serde_json::Value;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::signature::Signature;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use tokio::sync::oneshot;
+use tokio::time::{Duration, Instant};
+use tracing::{debug, warn};
+use super::websocket::SolanaWebSocketClient;
+
+/// Configuration for waiting on transaction confirmations
+#[derive(Debug, Clone)]
+pub struct ConfirmationConfig {
+    /// Give up on a signature that has not confirmed after this long (seconds)
+    pub timeout_secs: u64,
+    /// Wait this long for the subscription's notification before polling instead (seconds)
+    pub subscription_wait_secs: u64,
+    /// Interval between status requests when polling (milliseconds)
+    pub poll_interval_ms: u64,
+}
+
+impl Default for ConfirmationConfig {
+    fn default() -> Self {
+        Self {
+            timeout_secs: 60,
+            subscription_wait_secs: 20,
+            poll_interval_ms: 2000,
+        }
+    }
+}
+
+/// How confirmations were detected
+#[derive(Debug, Clone, Default)]
+pub struct ConfirmationStats {
+    /// Confirmations delivered by a signature subscription
+    pub via_subscription: u64,
+    /// Confirmations found by a status request
+    pub via_polling: u64,
+    /// Status requests sent to the RPC
+    pub status_requests: u64,
+}
+
+/// A signature subscription's notification, or the waiter expecting it
+enum Slot {
+    Waiting(oneshot::Sender<Result<(), String>>),
+    Arrived(Result<(), String>),
+}
+
+/// Waits for transaction confirmations over `signatureSubscribe`, polling as a fallback
+///
+/// A waiter subscribes to its signature on the shared WebSocket client and
+/// is woken by the notification, which the ingestion loop routes here
+/// through `observe`. One status request right after subscribing covers a
+/// transaction that landed before the subscription existed. Without a
+/// connected client, or when no notification arrives within
+/// `subscription_wait_secs`, the status is polled every `poll_interval_ms`
+/// until `timeout_secs`.
+pub struct SignatureConfirmations {
+    config: ConfirmationConfig,
+    client: RwLock<Option<Arc<SolanaWebSocketClient>>>,
+    /// Subscription request id -> notification or its waiter
+    slots: DashMap<u64, Slot>,
+    via_subscription: AtomicU64,
+    via_polling: AtomicU64,
+    status_requests: AtomicU64,
+}
+
+impl SignatureConfirmations {
+    pub fn new(config: Option<ConfirmationConfig>) -> Self {
+        Self {
+            config: config.unwrap_or_default(),
+            client: RwLock::new(None),
+            slots: DashMap::new(),
+            via_subscription: AtomicU64::new(0),
+            via_polling: AtomicU64::new(0),
+            status_requests: AtomicU64::new(0),
+        }
+    }
+
+    pub fn config(&self) -> &ConfirmationConfig {
+        &self.config
+    }
+
+    /// Subscribe through this client from now on
+    pub fn attach(&self, client: Arc<SolanaWebSocketClient>) {
+        *self.client.write().unwrap() = Some(client);
+    }
+
+    /// Route a signature notification to its waiter
+    ///
+    /// # Returns
+    /// * `bool` - True if the notification belonged to a confirmation subscription
+    pub async fn observe(&self, subscription_id: u64, data: &Value) -> bool {
+        let Some(client) = self.client.read().unwrap().clone() else { return false };
+        let Some(request_id) = client.request_id_for_subscription(subscription_id).await else { return false };
+        client.forget_subscription(request_id).await;
+
+        let outcome = match data.get("value").and_then(|value| value.get("err")) {
+            None | Some(Value::Null) => Ok(()),
+            Some(err) => Err(err.to_string()),
+        };
+        // The notification can beat the waiter registering for it
+        match self.slots.entry(request_id) {
+            Entry::Occupied(entry) => {
+                if let Slot::Waiting(waiter) = entry.remove() {
+                    let _ = waiter.send(outcome);
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(Slot::Arrived(outcome));
+            }
+        }
+        true
+    }
+
+    /// Wait until a sent transaction reaches the RPC client's commitment
+    ///
+    /// # Arguments
+    /// * `rpc_client` - Client the status is polled with; its commitment is the target
+    /// * `signature` - Signature of the sent transaction
+    ///
+    /// # Returns
+    /// * `Result<()>` - Ok once confirmed; an error if it failed on chain or timed out
+    pub async fn wait(&self, rpc_client: &RpcClient, signature: &Signature) -> Result<()> {
+        let started = Instant::now();
+        let deadline = started + Duration::from_secs(self.config.timeout_secs);
+
+        if let Some((client, request_id, notified)) = self.subscribe(rpc_client, signature).await {
+            // A transaction that landed before the subscription never notifies
+            match self.status(rpc_client, signature) {
+                Ok(Some(outcome)) => {
+                    self.slots.remove(&request_id);
+                    if let Err(e) = client.unsubscribe_signature(request_id).await {
+                        debug!(error = %e, "Failed to unsubscribe confirmed signature");
+                    }
+                    self.via_polling.fetch_add(1, Ordering::Relaxed);
+                    return outcome;
+                }
+                Ok(None) => {}
+                Err(e) => debug!(error = %e, %signature, "Status check after subscribing failed"),
+            }
+
+            let wait_until = deadline.min(Instant::now() + Duration::from_secs(self.config.subscription_wait_secs));
+            match tokio::time::timeout_at(wait_until, notified).await {
+                Ok(Ok(outcome)) => {
+                    self.via_subscription.fetch_add(1, Ordering::Relaxed);
+                    debug!(%signature, latency_ms = started.elapsed().as_millis() as u64, "Confirmation notified");
+                    return outcome.map_err(|err| anyhow::anyhow!("Transaction {} failed: {}", signature, err));
+                }
+                _ => {
+                    self.slots.remove(&request_id);
+                    let _ = client.unsubscribe_signature(request_id).await;
+                    warn!(%signature, "No signature notification; polling for confirmation");
+                }
+            }
+        }
+
+        loop {
+            match self.status(rpc_client, signature) {
+                Ok(Some(outcome)) => {
+                    self.via_polling.fetch_add(1, Ordering::Relaxed);
+                    return outcome;
+                }
+                Ok(None) => {}
+                Err(e) => debug!(error = %e, %signature, "Signature status request failed"),
+            }
+            if Instant::now() >= deadline {
+                bail!("Transaction {} not confirmed within {}s", signature, self.config.timeout_secs);
+            }
+            tokio::time::sleep_until(deadline.min(Instant::now() + Duration::from_millis(self.config.poll_interval_ms))).await;
+        }
+    }
+
+    /// Subscribe to a signature and register for its notification
+    ///
+    /// None when no client is attached or the subscribe request could not be sent.
+    async fn subscribe(
+        &self,
+        rpc_client: &RpcClient,
+        signature: &Signature,
+    ) -> Option<(Arc<SolanaWebSocketClient>, u64, oneshot::Receiver<Result<(), String>>)> {
+        let client = self.client.read().unwrap().clone()?;
+        let commitment = rpc_client.commitment().commitment.to_string();
+        let request_id = match client.subscribe_signature(&signature.to_string(), &commitment).await {
+            Ok(request_id) => request_id,
+            Err(e) => {
+                debug!(error = %e, "Signature subscription unavailable; polling for confirmation");
+                return None;
+            }
+        };
+
+        let (waiter, notified) = oneshot::channel();
+        match self.slots.entry(request_id) {
+            Entry::Occupied(entry) => {
+                if let Slot::Arrived(outcome) = entry.remove() {
+                    let _ = waiter.send(outcome);
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(Slot::Waiting(waiter));
+            }
+        }
+        Some((client, request_id, notified))
+    }
+
+    /// Status of a signature at the RPC client's commitment; None while not reached
+    fn status(&self, rpc_client: &RpcClient, signature: &Signature) -> Result<Option<Result<()>>> {
+        self.status_requests.fetch_add(1, Ordering::Relaxed);
+        let status = rpc_client.get_signature_status_with_commitment(signature, rpc_client.commitment())
+            .context("Failed to fetch signature status")?;
+        Ok(status.map(|result| result.map_err(|e| anyhow::anyhow!("Transaction {} failed: {}", signature, e))))
+    }
+
+    pub fn stats(&self) -> ConfirmationStats {
+        ConfirmationStats {
+            via_subscription: self.via_subscription.load(Ordering::Relaxed),
+            via_polling: self.via_polling.load(Ordering::Relaxed),
+            status_requests: self.status_requests.load(Ordering::Relaxed),
+        }
+    }
+}
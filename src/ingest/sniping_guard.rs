@@ -0,0 +1,171 @@
+use chrono::Utc;
+use dashmap::DashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tracing::debug;
+
+use crate::core::constants::SOL_MINT;
+use crate::core::{MarketEvent, SignalSource, TradingSignal};
+
+/// Tokens remembered after which the oldest are pruned
+const PRUNE_THRESHOLD: usize = 50_000;
+
+/// Configuration for the early-block buy guard
+#[derive(Debug, Clone)]
+pub struct SnipingGuardConfig {
+    /// Slots after a token's first appearance in which only the sniper strategy may buy; 0 disables the guard
+    pub guard_slots: u64,
+    /// SOL the sniper strategy may spend per UTC day inside the guarded slots
+    pub sniper_daily_budget_sol: f64,
+}
+
+impl Default for SnipingGuardConfig {
+    fn default() -> Self {
+        Self {
+            // Off unless configured: the built-in new-pool entries fire on the launch event itself
+            guard_slots: 0,
+            sniper_daily_budget_sol: 1.0,
+        }
+    }
+}
+
+impl SnipingGuardConfig {
+    /// Defaults with overrides from `BADGER_SNIPE_GUARD_SLOTS` and `BADGER_SNIPER_BUDGET_SOL` when set
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(slots) = std::env::var("BADGER_SNIPE_GUARD_SLOTS").ok().and_then(|v| v.parse().ok()) {
+            config.guard_slots = slots;
+        }
+        if let Some(budget) = std::env::var("BADGER_SNIPER_BUDGET_SOL").ok().and_then(|v| v.parse().ok()) {
+            config.sniper_daily_budget_sol = budget;
+        }
+        config
+    }
+}
+
+/// A buy refused because the token is only a few slots old
+#[derive(Debug, Clone)]
+pub struct SnipeBlock {
+    pub token_mint: String,
+    /// Slots since the token first appeared
+    pub token_age_slots: u64,
+    pub source: SignalSource,
+    /// Set when the sniper strategy itself was refused for lack of budget
+    pub budget_exhausted: bool,
+}
+
+/// Refuses buys in the first slots of a token's life
+///
+/// Block-0 trading is the sniper strategy's job, with its own daily budget;
+/// copy and momentum signals that happen to fire that early are dropped
+/// instead of aping into the launch. A token's first slot and the current
+/// slot both come from the event stream.
+pub struct SnipingGuard {
+    config: SnipingGuardConfig,
+    /// Token mint -> first slot it was seen in
+    first_slots: DashMap<String, u64>,
+    latest_slot: AtomicU64,
+    /// (UTC date, SOL the sniper spent on it)
+    sniper_spend: Mutex<(String, f64)>,
+}
+
+impl SnipingGuard {
+    pub fn new(config: Option<SnipingGuardConfig>) -> Self {
+        Self {
+            config: config.unwrap_or_default(),
+            first_slots: DashMap::new(),
+            latest_slot: AtomicU64::new(0),
+            sniper_spend: Mutex::new((String::new(), 0.0)),
+        }
+    }
+
+    pub fn config(&self) -> &SnipingGuardConfig {
+        &self.config
+    }
+
+    /// Record launch slots and advance the current slot
+    pub fn observe(&self, event: &MarketEvent) {
+        let launch = match event {
+            MarketEvent::PoolCreated { pool, .. } => {
+                let mint = if pool.base_mint == SOL_MINT { &pool.quote_mint } else { &pool.base_mint };
+                Some((mint, pool.slot))
+            }
+            MarketEvent::TokenLaunched { token } => Some((&token.mint, token.slot)),
+            MarketEvent::BondingCurveUpdated { curve, .. } => curve.token_mint.as_ref().map(|mint| (mint, curve.slot)),
+            _ => None,
+        };
+        if let Some((token_mint, slot)) = launch {
+            if self.first_slots.len() > PRUNE_THRESHOLD {
+                let horizon = self.latest_slot.load(Ordering::Relaxed).saturating_sub(self.config.guard_slots);
+                self.first_slots.retain(|_, first| *first >= horizon);
+            }
+            let mut first = self.first_slots.entry(token_mint.clone()).or_insert(slot);
+            *first = (*first).min(slot);
+        }
+        if let Some(slot) = event.get_slot() {
+            self.latest_slot.fetch_max(slot.max(0) as u64, Ordering::Relaxed);
+        }
+    }
+
+    /// Slots since a token first appeared, if it was seen launching
+    pub fn token_age_slots(&self, token_mint: &str) -> Option<u64> {
+        let first = *self.first_slots.get(token_mint)?;
+        Some(self.latest_slot.load(Ordering::Relaxed).saturating_sub(first))
+    }
+
+    /// Check a signal; returns the block when it is a guarded early buy
+    pub fn check(&self, signal: &TradingSignal) -> Option<SnipeBlock> {
+        let TradingSignal::Buy { token_mint, max_amount_sol, source, .. } = signal else {
+            return None;
+        };
+        let token_age_slots = self.token_age_slots(token_mint)?;
+        if token_age_slots >= self.config.guard_slots {
+            return None;
+        }
+
+        let budget_exhausted = match source {
+            SignalSource::Sniper => {
+                let remaining = self.config.sniper_daily_budget_sol - self.sniper_spent_today();
+                if *max_amount_sol <= remaining {
+                    return None;
+                }
+                true
+            }
+            _ => false,
+        };
+        debug!("🎯 Early buy of {} blocked at {} slots old ({:?})", token_mint, token_age_slots, source);
+        Some(SnipeBlock {
+            token_mint: token_mint.clone(),
+            token_age_slots,
+            source: *source,
+            budget_exhausted,
+        })
+    }
+
+    /// Charge an executed sniper entry against today's budget
+    pub fn record_sniper_entry(&self, amount_sol: f64) {
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+        let mut spend = self.sniper_spend.lock().unwrap();
+        if spend.0 != today {
+            *spend = (today, 0.0);
+        }
+        spend.1 += amount_sol;
+    }
+
+    /// Raise today's sniper spend to a total recorded before a restart
+    pub fn restore_sniper_spend(&self, amount_sol: f64) {
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+        let mut spend = self.sniper_spend.lock().unwrap();
+        if spend.0 != today {
+            *spend = (today, 0.0);
+        }
+        spend.1 = spend.1.max(amount_sol);
+    }
+
+    /// SOL the sniper strategy has spent today
+    pub fn sniper_spent_today(&self) -> f64 {
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+        let spend = self.sniper_spend.lock().unwrap();
+        if spend.0 == today { spend.1 } else { 0.0 }
+    }
+}
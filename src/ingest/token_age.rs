@@ -0,0 +1,284 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use dashmap::{DashMap, DashSet};
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::str::FromStr;
+use tracing::debug;
+
+use super::commitment::at_least_confirmed;
+use super::token_registry::TOKEN_METADATA_PROGRAM_ID;
+use crate::core::constants::SOL_MINT;
+use crate::core::MarketEvent;
+
+/// Tokens remembered after which the oldest are pruned
+const PRUNE_THRESHOLD: usize = 50_000;
+
+/// Configuration for token age resolution
+#[derive(Debug, Clone)]
+pub struct TokenAgeConfig {
+    /// Signatures requested per `getSignaturesForAddress` page
+    pub page_size: usize,
+    /// Pages walked back per address; past this the oldest signature found is only a bound
+    pub max_pages: usize,
+    /// Tokens resolved from chain per service tick
+    pub resolve_batch: usize,
+    /// Sources within this many seconds of the earliest corroborate it
+    pub agreement_secs: i64,
+}
+
+impl Default for TokenAgeConfig {
+    fn default() -> Self {
+        Self {
+            page_size: 1000,
+            max_pages: 5,
+            resolve_batch: 20,
+            agreement_secs: 120,
+        }
+    }
+}
+
+/// Where a token creation time came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TokenAgeSource {
+    /// pump.fun or launchpad creation event seen live
+    LaunchpadCreate,
+    /// Oldest transaction touching the mint account
+    FirstMintActivity,
+    /// Oldest transaction touching the token's Metaplex metadata account
+    MetadataCreated,
+    /// Mint account update or pool creation seen on the stream; the token may be much older
+    FirstObserved,
+}
+
+/// How far a token creation time can be trusted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum TokenAgeConfidence {
+    /// Only an upper bound on the creation time: the token is at least this old
+    Low,
+    Medium,
+    /// Creation seen directly, or corroborated by a second source
+    High,
+}
+
+/// A token's creation time and how it was determined
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenAge {
+    pub token_mint: String,
+    /// Unix time the token was created at (or, at low confidence, existed by)
+    pub created_at: i64,
+    pub source: TokenAgeSource,
+    pub confidence: TokenAgeConfidence,
+}
+
+impl TokenAge {
+    /// Seconds since creation
+    pub fn age_secs(&self) -> i64 {
+        (Utc::now().timestamp() - self.created_at).max(0)
+    }
+
+    /// Age in seconds when it is trustworthy; None at low confidence
+    pub fn confident_age_secs(&self) -> Option<i64> {
+        (self.confidence > TokenAgeConfidence::Low).then(|| self.age_secs())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct AgeEvidence {
+    /// (source, unix time, confidence) of each source that reported
+    sources: Vec<(TokenAgeSource, i64, TokenAgeConfidence)>,
+    /// Chain history has been looked up
+    resolved: bool,
+}
+
+impl AgeEvidence {
+    fn record(&mut self, source: TokenAgeSource, at: i64, confidence: TokenAgeConfidence) {
+        match self.sources.iter_mut().find(|(s, _, _)| *s == source) {
+            // Every source bounds creation from above, so the earliest report wins
+            Some(existing) if at < existing.1 => *existing = (source, at, confidence),
+            Some(existing) if at == existing.1 => existing.2 = existing.2.max(confidence),
+            Some(_) => {}
+            None => self.sources.push((source, at, confidence)),
+        }
+    }
+}
+
+/// Source of truth for how old a token is
+///
+/// Creation times reported by launchpad events, the mint's transaction
+/// history, its Metaplex metadata account and plain stream observations are
+/// merged per token. Every source only bounds creation from above, so the
+/// earliest one is taken; its confidence is raised when a second source
+/// agrees. A token with no evidence has no age - callers must not read
+/// that as brand new.
+pub struct TokenAgeService {
+    config: TokenAgeConfig,
+    evidence: DashMap<String, AgeEvidence>,
+    /// Mints whose chain history should be looked up
+    pending: DashSet<String>,
+}
+
+impl Default for TokenAgeService {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl TokenAgeService {
+    pub fn new(config: Option<TokenAgeConfig>) -> Self {
+        Self {
+            config: config.unwrap_or_default(),
+            evidence: DashMap::new(),
+            pending: DashSet::new(),
+        }
+    }
+
+    pub fn config(&self) -> &TokenAgeConfig {
+        &self.config
+    }
+
+    /// Record creation evidence carried by a market event
+    pub fn observe(&self, event: &MarketEvent) {
+        let (mint, source, at, confidence) = match event {
+            MarketEvent::TokenLaunched { token } if token.mint_authority.as_deref() == Some("pump.fun") => (
+                &token.mint, TokenAgeSource::LaunchpadCreate, token.created_at.timestamp(), TokenAgeConfidence::High,
+            ),
+            // Mint account updates are parsed as launches whether or not the mint is new
+            MarketEvent::TokenLaunched { token } => (
+                &token.mint, TokenAgeSource::FirstObserved, token.created_at.timestamp(), TokenAgeConfidence::Low,
+            ),
+            MarketEvent::PoolCreated { pool, .. } => {
+                let mint = if pool.base_mint == SOL_MINT { &pool.quote_mint } else { &pool.base_mint };
+                (mint, TokenAgeSource::FirstObserved, pool.created_at.timestamp(), TokenAgeConfidence::Low)
+            }
+            // A curve with nothing raised yet was just created
+            MarketEvent::BondingCurveUpdated { curve, previous_progress: None } if curve.real_sol_reserves == 0 && !curve.complete => {
+                let Some(mint) = curve.token_mint.as_ref() else {
+                    return;
+                };
+                (mint, TokenAgeSource::LaunchpadCreate, Utc::now().timestamp(), TokenAgeConfidence::High)
+            }
+            _ => return,
+        };
+        self.record(mint, source, at, confidence);
+    }
+
+    /// Record a creation time reported by any source
+    pub fn record(&self, token_mint: &str, source: TokenAgeSource, at: i64, confidence: TokenAgeConfidence) {
+        if self.evidence.len() > PRUNE_THRESHOLD {
+            self.prune();
+        }
+        self.evidence.entry(token_mint.to_string()).or_default().record(source, at, confidence);
+    }
+
+    /// Merged age of a token, if any source has reported it
+    ///
+    /// A token with only low-confidence evidence is queued for a chain lookup.
+    pub fn age(&self, token_mint: &str) -> Option<TokenAge> {
+        let Some(evidence) = self.evidence.get(token_mint) else {
+            self.pending.insert(token_mint.to_string());
+            return None;
+        };
+        let &(source, created_at, confidence) = evidence.sources.iter().min_by_key(|(_, at, _)| *at)?;
+        let corroborated = evidence.sources.iter()
+            .any(|(other, at, _)| *other != source && (at - created_at).abs() <= self.config.agreement_secs);
+        let confidence = if corroborated { TokenAgeConfidence::High } else { confidence };
+        if confidence == TokenAgeConfidence::Low && !evidence.resolved {
+            self.pending.insert(token_mint.to_string());
+        }
+        Some(TokenAge { token_mint: token_mint.to_string(), created_at, source, confidence })
+    }
+
+    /// Seconds since a token was created, if known
+    pub fn age_secs(&self, token_mint: &str) -> Option<i64> {
+        self.age(token_mint).map(|age| age.age_secs())
+    }
+
+    /// Look up the chain history of queued tokens
+    ///
+    /// # Returns
+    /// * `Result<usize>` - Number of tokens resolved
+    pub async fn resolve_pending(&self, rpc_client: &RpcClient) -> Result<usize> {
+        let batch: Vec<String> = self.pending.iter()
+            .take(self.config.resolve_batch)
+            .map(|mint| mint.clone())
+            .collect();
+
+        let mut resolved = 0;
+        for mint in batch {
+            self.pending.remove(&mint);
+            match self.resolve(rpc_client, &mint).await {
+                Ok(()) => resolved += 1,
+                Err(e) => debug!("Failed to resolve age of {}: {}", mint, e),
+            }
+        }
+        Ok(resolved)
+    }
+
+    /// Merge the mint's and metadata account's oldest transactions into a token's evidence
+    pub async fn resolve(&self, rpc_client: &RpcClient, token_mint: &str) -> Result<()> {
+        let mint = Pubkey::from_str(token_mint).context("Invalid token mint")?;
+        let metadata_program = Pubkey::from_str(TOKEN_METADATA_PROGRAM_ID)?;
+        let metadata = Pubkey::find_program_address(
+            &[b"metadata", metadata_program.as_ref(), mint.as_ref()],
+            &metadata_program,
+        ).0;
+
+        let first_mint_activity = self.oldest_block_time(rpc_client, &mint).await?;
+        let metadata_created = self.oldest_block_time(rpc_client, &metadata).await?;
+
+        let mut evidence = self.evidence.entry(token_mint.to_string()).or_default();
+        if let Some((at, complete)) = first_mint_activity {
+            let confidence = if complete { TokenAgeConfidence::High } else { TokenAgeConfidence::Low };
+            evidence.record(TokenAgeSource::FirstMintActivity, at, confidence);
+        }
+        if let Some((at, complete)) = metadata_created {
+            let confidence = if complete { TokenAgeConfidence::Medium } else { TokenAgeConfidence::Low };
+            evidence.record(TokenAgeSource::MetadataCreated, at, confidence);
+        }
+        evidence.resolved = true;
+        debug!("🕰️ Resolved age of {}: mint {:?}, metadata {:?}", token_mint, first_mint_activity, metadata_created);
+        Ok(())
+    }
+
+    /// Number of tokens waiting for a chain lookup
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Block time of the oldest transaction touching an address, and whether history was walked to its start
+    async fn oldest_block_time(&self, rpc_client: &RpcClient, address: &Pubkey) -> Result<Option<(i64, bool)>> {
+        let mut before = None;
+        let mut oldest = None;
+        for _ in 0..self.config.max_pages {
+            let page = rpc_client.get_signatures_for_address_with_config(address, GetConfirmedSignaturesForAddress2Config {
+                before,
+                until: None,
+                limit: Some(self.config.page_size),
+                commitment: Some(at_least_confirmed(rpc_client.commitment())),
+            }).await
+                .with_context(|| format!("Failed to fetch signatures of {}", address))?;
+
+            let page_len = page.len();
+            if let Some(last) = page.last() {
+                before = Signature::from_str(&last.signature).ok();
+                oldest = last.block_time.or(oldest);
+            }
+            if page_len < self.config.page_size {
+                return Ok(oldest.map(|at| (at, true)));
+            }
+        }
+        Ok(oldest.map(|at| (at, false)))
+    }
+
+    /// Forget tokens older than a week; their age no longer changes any decision
+    fn prune(&self) {
+        let horizon = Utc::now().timestamp() - 7 * 86400;
+        self.evidence.retain(|_, evidence| {
+            evidence.sources.iter().map(|(_, at, _)| *at).min().is_some_and(|at| at >= horizon)
+        });
+    }
+}
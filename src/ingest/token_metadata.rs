@@ -0,0 +1,233 @@
+use anyhow::{Context, Result};
+use base64::Engine;
+use chrono::Utc;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use tracing::debug;
+
+use super::token_registry::TOKEN_METADATA_PROGRAM_ID;
+
+/// Configuration for token metadata change monitoring
+#[derive(Debug, Clone)]
+pub struct MetadataMonitorConfig {
+    /// Tighten the stops of held tokens whose metadata changed
+    pub tighten_stops: bool,
+    /// Stop distance below the current price once tightened (0.1 = 10%)
+    pub tightened_stop_pct: f64,
+    /// How long a change keeps a token's stops tightened (seconds)
+    pub flag_ttl_secs: i64,
+}
+
+impl Default for MetadataMonitorConfig {
+    fn default() -> Self {
+        Self {
+            tighten_stops: false,
+            tightened_stop_pct: 0.10,
+            flag_ttl_secs: 24 * 3600,
+        }
+    }
+}
+
+impl MetadataMonitorConfig {
+    /// Defaults with stop tightening enabled by `BADGER_METADATA_TIGHTEN_STOPS=1`
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Ok(value) = std::env::var("BADGER_METADATA_TIGHTEN_STOPS") {
+            config.tighten_stops = matches!(value.as_str(), "1" | "true" | "yes");
+        }
+        config
+    }
+}
+
+/// Mutable fields of a Metaplex metadata account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenMetadata {
+    pub mint: String,
+    pub update_authority: String,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+/// Metadata field that changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MetadataField {
+    Name,
+    Symbol,
+    Uri,
+    UpdateAuthority,
+}
+
+/// One field of a token's metadata changing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetadataChange {
+    pub token_mint: String,
+    pub field: MetadataField,
+    pub old_value: String,
+    pub new_value: String,
+    pub detected_at: i64,
+}
+
+/// Parse a Metaplex metadata account
+///
+/// Layout: key (1), update authority (32), mint (32), then name, symbol and
+/// URI as borsh strings (u32 length + bytes, padded with NULs).
+pub fn parse_token_metadata(data: &[u8]) -> Option<TokenMetadata> {
+    let update_authority = Pubkey::try_from(data.get(1..33)?).ok()?;
+    let mint = Pubkey::try_from(data.get(33..65)?).ok()?;
+    let mut offset = 65;
+    let mut read_string = || -> Option<String> {
+        let len = u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?) as usize;
+        let bytes = data.get(offset + 4..offset + 4 + len)?;
+        offset += 4 + len;
+        Some(String::from_utf8_lossy(bytes).trim_end_matches('\0').trim().to_string())
+    };
+    let name = read_string()?;
+    let symbol = read_string()?;
+    let uri = read_string()?;
+    Some(TokenMetadata {
+        mint: mint.to_string(),
+        update_authority: update_authority.to_string(),
+        name,
+        symbol,
+        uri,
+    })
+}
+
+/// Metaplex metadata account of a mint
+pub fn metadata_address_for_mint(mint: &str) -> Option<String> {
+    let mint = Pubkey::from_str(mint).ok()?;
+    let program = Pubkey::from_str(TOKEN_METADATA_PROGRAM_ID).ok()?;
+    let (metadata, _) = Pubkey::find_program_address(&[b"metadata", program.as_ref(), mint.as_ref()], &program);
+    Some(metadata.to_string())
+}
+
+/// Watches held tokens' Metaplex metadata for name, URI and authority changes
+///
+/// Metadata swaps (a new name or URI, the update authority handed to a fresh
+/// wallet) are a common precursor to scams. The metadata account of every
+/// held token is subscribed; each notification is compared with the last
+/// known state, changes are reported and the token is flagged so its stops
+/// can be tightened for a while.
+pub struct MetadataMonitor {
+    config: MetadataMonitorConfig,
+    known: DashMap<String, TokenMetadata>,
+    /// Token mint -> when its metadata last changed
+    flagged: DashMap<String, i64>,
+}
+
+impl MetadataMonitor {
+    pub fn new(config: Option<MetadataMonitorConfig>) -> Self {
+        Self {
+            config: config.unwrap_or_default(),
+            known: DashMap::new(),
+            flagged: DashMap::new(),
+        }
+    }
+
+    pub fn config(&self) -> &MetadataMonitorConfig {
+        &self.config
+    }
+
+    /// Whether a token's metadata is known, so later updates can be compared
+    pub fn is_known(&self, token_mint: &str) -> bool {
+        self.known.contains_key(token_mint)
+    }
+
+    /// Fetch the current metadata of tokens not known yet
+    ///
+    /// # Returns
+    /// * `Result<usize>` - Number of tokens whose metadata was loaded
+    pub async fn load_missing(&self, rpc_client: &RpcClient, mints: &[String]) -> Result<usize> {
+        let missing: Vec<(String, Pubkey)> = mints.iter()
+            .filter(|mint| !self.known.contains_key(mint.as_str()))
+            .filter_map(|mint| Some((mint.clone(), Pubkey::from_str(&metadata_address_for_mint(mint)?).ok()?)))
+            .collect();
+        let mut loaded = 0;
+        for chunk in missing.chunks(100) {
+            let addresses: Vec<Pubkey> = chunk.iter().map(|(_, address)| *address).collect();
+            let accounts = rpc_client.get_multiple_accounts(&addresses).await
+                .context("Failed to fetch token metadata accounts")?;
+            for ((mint, _), account) in chunk.iter().zip(accounts) {
+                if let Some(metadata) = account.and_then(|a| parse_token_metadata(&a.data)) {
+                    self.known.insert(mint.clone(), metadata);
+                    loaded += 1;
+                }
+            }
+        }
+        Ok(loaded)
+    }
+
+    /// Compare a metadata account notification with the token's last known metadata
+    ///
+    /// The first state seen for a token is only recorded.
+    ///
+    /// # Returns
+    /// * `Vec<MetadataChange>` - Changed fields; empty when nothing changed or the data is not metadata
+    pub fn observe_notification(&self, token_mint: &str, data: &Value) -> Vec<MetadataChange> {
+        let metadata = data.get("value")
+            .and_then(|value| value.get("data"))
+            .and_then(|data| data.as_array())
+            .and_then(|data| data.first())
+            .and_then(|encoded| encoded.as_str())
+            .and_then(|encoded| base64::engine::general_purpose::STANDARD.decode(encoded).ok())
+            .and_then(|bytes| parse_token_metadata(&bytes));
+        match metadata {
+            Some(metadata) if metadata.mint == token_mint => self.observe(metadata),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Compare metadata with the last known state and remember it
+    pub fn observe(&self, metadata: TokenMetadata) -> Vec<MetadataChange> {
+        let Some(previous) = self.known.insert(metadata.mint.clone(), metadata.clone()) else {
+            debug!("🪪 Recorded metadata of {} ({:?})", metadata.mint, metadata.symbol);
+            return Vec::new();
+        };
+
+        let now = Utc::now().timestamp();
+        let changes: Vec<MetadataChange> = [
+            (MetadataField::Name, &previous.name, &metadata.name),
+            (MetadataField::Symbol, &previous.symbol, &metadata.symbol),
+            (MetadataField::Uri, &previous.uri, &metadata.uri),
+            (MetadataField::UpdateAuthority, &previous.update_authority, &metadata.update_authority),
+        ]
+        .into_iter()
+        .filter(|(_, old, new)| old != new)
+        .map(|(field, old, new)| MetadataChange {
+            token_mint: metadata.mint.clone(),
+            field,
+            old_value: old.clone(),
+            new_value: new.clone(),
+            detected_at: now,
+        })
+        .collect();
+
+        if !changes.is_empty() {
+            self.flagged.insert(metadata.mint.clone(), now);
+        }
+        changes
+    }
+
+    /// Whether a token's metadata changed within the flag period
+    pub fn is_flagged(&self, token_mint: &str) -> bool {
+        let cutoff = Utc::now().timestamp() - self.config.flag_ttl_secs;
+        self.flagged.get(token_mint).is_some_and(|changed_at| *changed_at >= cutoff)
+    }
+
+    /// Stop for a held token given its price: tightened below the price while flagged
+    pub fn tightened_stop(&self, token_mint: &str, price: f64) -> Option<f64> {
+        (self.config.tighten_stops && self.is_flagged(token_mint))
+            .then_some(price * (1.0 - self.config.tightened_stop_pct))
+    }
+
+    /// Drop a token that is no longer held or tracked
+    pub fn forget_mint(&self, token_mint: &str) {
+        self.known.remove(token_mint);
+        self.flagged.remove(token_mint);
+    }
+}
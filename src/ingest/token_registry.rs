@@ -0,0 +1,192 @@
+use anyhow::{Context, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_program::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use spl_token::state::Mint;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::RwLock;
+use std::time::Duration;
+use tracing::{debug, info};
+
+use super::token_metadata::parse_token_metadata;
+
+/// Metaplex token metadata program
+pub const TOKEN_METADATA_PROGRAM_ID: &str = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
+
+/// Configuration for token symbol and decimals resolution
+#[derive(Debug, Clone)]
+pub struct TokenRegistryConfig {
+    /// Jupiter token list; every entry has address, symbol, name and decimals
+    pub token_list_url: String,
+    /// How often the token list is reloaded (seconds)
+    pub refresh_interval_secs: u64,
+    /// Timeout for the token list download (milliseconds)
+    pub request_timeout_ms: u64,
+}
+
+impl Default for TokenRegistryConfig {
+    fn default() -> Self {
+        Self {
+            token_list_url: "https://token.jup.ag/all".to_string(),
+            refresh_interval_secs: 6 * 3600,
+            request_timeout_ms: 30_000,
+        }
+    }
+}
+
+/// Symbol, name and decimals of a token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenInfo {
+    #[serde(rename = "address")]
+    pub mint: String,
+    pub symbol: String,
+    pub name: String,
+    pub decimals: u8,
+}
+
+/// In-memory map of token mints to symbol and decimals
+///
+/// Loaded from the Jupiter token list and refreshed periodically. Mints the
+/// list does not know yet (fresh launches, mostly) are resolved on demand from
+/// the mint account and its Metaplex metadata, and kept across list reloads.
+/// Lookups are synchronous hash map reads, so display and report code can call
+/// them freely.
+pub struct TokenRegistry {
+    client: Client,
+    config: TokenRegistryConfig,
+    tokens: RwLock<HashMap<String, TokenInfo>>,
+}
+
+impl TokenRegistry {
+    pub fn new(config: Option<TokenRegistryConfig>) -> Self {
+        let config = config.unwrap_or_default();
+        let client = Client::builder()
+            .timeout(Duration::from_millis(config.request_timeout_ms))
+            .build()
+            .unwrap_or_default();
+        Self {
+            client,
+            config,
+            tokens: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> &TokenRegistryConfig {
+        &self.config
+    }
+
+    /// Reload the token list; returns the number of tokens known afterwards
+    pub async fn refresh(&self) -> Result<usize> {
+        let list: Vec<TokenInfo> = self.client
+            .get(&self.config.token_list_url)
+            .send()
+            .await
+            .context("Failed to download token list")?
+            .error_for_status()?
+            .json()
+            .await
+            .context("Failed to parse token list")?;
+
+        let mut tokens = self.tokens.write().unwrap();
+        let listed = list.len();
+        for token in list {
+            tokens.insert(token.mint.clone(), token);
+        }
+        info!("🏷️ Token list loaded: {} listed, {} known", listed, tokens.len());
+        Ok(tokens.len())
+    }
+
+    /// Resolve mints the registry does not know from chain
+    ///
+    /// Decimals come from the mint account, symbol and name from the Metaplex
+    /// metadata account when there is one.
+    ///
+    /// # Returns
+    /// * `Result<usize>` - Number of mints newly resolved
+    pub async fn resolve_missing(&self, rpc_client: &RpcClient, mints: &[String]) -> Result<usize> {
+        let missing: Vec<Pubkey> = {
+            let tokens = self.tokens.read().unwrap();
+            mints.iter()
+                .filter(|mint| !tokens.contains_key(mint.as_str()))
+                .filter_map(|mint| Pubkey::from_str(mint).ok())
+                .collect()
+        };
+        if missing.is_empty() {
+            return Ok(0);
+        }
+
+        let metadata_program = Pubkey::from_str(TOKEN_METADATA_PROGRAM_ID)?;
+        let mut resolved = Vec::new();
+        for chunk in missing.chunks(50) {
+            let metadata_addresses: Vec<Pubkey> = chunk.iter()
+                .map(|mint| Pubkey::find_program_address(
+                    &[b"metadata", metadata_program.as_ref(), mint.as_ref()],
+                    &metadata_program,
+                ).0)
+                .collect();
+            let addresses: Vec<Pubkey> = chunk.iter().copied().chain(metadata_addresses).collect();
+            let accounts = rpc_client.get_multiple_accounts(&addresses).await
+                .context("Failed to fetch token mints and metadata")?;
+            let (mint_accounts, metadata_accounts) = accounts.split_at(chunk.len());
+
+            for ((mint, mint_account), metadata_account) in chunk.iter().zip(mint_accounts).zip(metadata_accounts) {
+                let decimals = match mint_account.as_ref().and_then(|a| Mint::unpack(&a.data).ok()) {
+                    Some(mint_state) => mint_state.decimals,
+                    None => continue,
+                };
+                let (name, symbol) = metadata_account.as_ref()
+                    .and_then(|a| parse_token_metadata(&a.data))
+                    .map(|metadata| (metadata.name, metadata.symbol))
+                    .unwrap_or_default();
+                resolved.push(TokenInfo {
+                    mint: mint.to_string(),
+                    symbol,
+                    name,
+                    decimals,
+                });
+            }
+        }
+
+        let count = resolved.len();
+        let mut tokens = self.tokens.write().unwrap();
+        for token in resolved {
+            debug!("🏷️ Resolved {} as {:?} from chain", token.mint, token.symbol);
+            tokens.insert(token.mint.clone(), token);
+        }
+        Ok(count)
+    }
+
+    /// Token info for a mint, if known
+    pub fn get(&self, mint: &str) -> Option<TokenInfo> {
+        self.tokens.read().unwrap().get(mint).cloned()
+    }
+
+    /// Symbol for a mint; None when unknown or the token has no symbol
+    pub fn symbol(&self, mint: &str) -> Option<String> {
+        self.tokens.read().unwrap().get(mint)
+            .map(|token| token.symbol.clone())
+            .filter(|symbol| !symbol.is_empty())
+    }
+
+    /// Decimals for a mint, if known
+    pub fn decimals(&self, mint: &str) -> Option<u8> {
+        self.tokens.read().unwrap().get(mint).map(|token| token.decimals)
+    }
+
+    /// Display label: the symbol when known, otherwise the shortened mint
+    pub fn label(&self, mint: &str) -> String {
+        self.symbol(mint).unwrap_or_else(|| mint[..8.min(mint.len())].to_string())
+    }
+
+    /// Number of tokens known
+    pub fn len(&self) -> usize {
+        self.tokens.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
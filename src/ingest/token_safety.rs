@@ -0,0 +1,339 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_program::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use spl_token::state::Mint;
+use std::collections::HashMap;
+use std::str::FromStr;
+use tracing::{debug, warn};
+
+use crate::core::{MarketEvent, SOL_MINT};
+use super::holder_distribution::fetch_holder_distribution;
+use super::lp_lock::{fetch_lp_lock_status, LpLockConfig, LpLockStatus};
+
+/// Cached reports after which expired ones are pruned
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// What a token must satisfy before an insider in a tier is copied into it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SafetyRequirement {
+    /// Reject tokens that can still be minted
+    pub require_mint_renounced: bool,
+    /// Reject tokens whose holders can still be frozen (honeypots)
+    pub require_freeze_renounced: bool,
+    /// Largest share of circulating supply a single holder may have (0.0 - 1.0)
+    pub max_top1_share: f64,
+    /// Largest share of circulating supply the top 10 holders may have (0.0 - 1.0)
+    pub max_top10_share: f64,
+    /// Skip the copy when no report could be produced in time
+    pub reject_unchecked: bool,
+    /// Smallest share of the pool's LP that must be burned or locked (0.0 - 1.0); 0 skips the LP check
+    #[serde(default)]
+    pub min_lp_secured_share: f64,
+    /// Shortest remaining lock for locked LP to count towards `min_lp_secured_share` (seconds)
+    #[serde(default)]
+    pub min_lp_lock_secs: i64,
+}
+
+/// Configuration for copy-trade safety checks
+#[derive(Debug, Clone)]
+pub struct TokenSafetyConfig {
+    /// How long a report is reused before the mint is checked again (seconds)
+    pub report_ttl_secs: i64,
+    /// Longest a copy trade waits for a missing report (milliseconds)
+    pub check_timeout_ms: u64,
+    /// Requirements per insider tier ("A" - "D")
+    pub tier_requirements: HashMap<String, SafetyRequirement>,
+    /// Requirements for insiders without a tier
+    pub default_requirement: SafetyRequirement,
+    /// Requirements for launch entries (sniper and new-pool signals); None leaves them unchecked
+    pub sniper_requirement: Option<SafetyRequirement>,
+    /// LP burn and lock verification
+    pub lp_lock: LpLockConfig,
+}
+
+impl Default for TokenSafetyConfig {
+    fn default() -> Self {
+        let strict = SafetyRequirement {
+            require_mint_renounced: true,
+            require_freeze_renounced: true,
+            max_top1_share: 0.2,
+            max_top10_share: 0.5,
+            reject_unchecked: true,
+            min_lp_secured_share: 0.0,
+            min_lp_lock_secs: 0,
+        };
+        let tier_requirements = HashMap::from([
+            // Proven insiders are trusted on distribution, never on honeypots
+            ("A".to_string(), SafetyRequirement {
+                require_mint_renounced: false,
+                max_top1_share: 0.5,
+                max_top10_share: 0.9,
+                reject_unchecked: false,
+                ..strict.clone()
+            }),
+            ("B".to_string(), SafetyRequirement {
+                max_top1_share: 0.3,
+                max_top10_share: 0.7,
+                reject_unchecked: false,
+                ..strict.clone()
+            }),
+            ("C".to_string(), strict.clone()),
+            ("D".to_string(), strict.clone()),
+        ]);
+
+        Self {
+            report_ttl_secs: 300,
+            check_timeout_ms: 2_000,
+            tier_requirements,
+            default_requirement: strict,
+            sniper_requirement: None,
+            lp_lock: LpLockConfig::default(),
+        }
+    }
+}
+
+impl TokenSafetyConfig {
+    /// Defaults with a launch entry LP requirement from `BADGER_SNIPER_MIN_LP_SECURED`
+    /// and `BADGER_SNIPER_MIN_LP_LOCK_DAYS` when set, and extra lockers from `BADGER_LP_LOCKER_PROGRAMS`
+    ///
+    /// Launch entries are only checked for LP; authorities and holder
+    /// concentration stay the copy-trade requirements' concern.
+    pub fn from_env() -> Self {
+        let mut config = Self {
+            lp_lock: LpLockConfig::from_env(),
+            ..Self::default()
+        };
+        let min_share = std::env::var("BADGER_SNIPER_MIN_LP_SECURED").ok().and_then(|v| v.parse::<f64>().ok());
+        let min_days = std::env::var("BADGER_SNIPER_MIN_LP_LOCK_DAYS").ok().and_then(|v| v.parse::<f64>().ok());
+        if min_share.is_some() || min_days.is_some() {
+            config.sniper_requirement = Some(SafetyRequirement {
+                require_mint_renounced: false,
+                require_freeze_renounced: false,
+                max_top1_share: 1.0,
+                max_top10_share: 1.0,
+                reject_unchecked: true,
+                min_lp_secured_share: min_share.unwrap_or(0.9).clamp(0.0, 1.0),
+                min_lp_lock_secs: (min_days.unwrap_or(0.0).max(0.0) * 86400.0) as i64,
+            });
+        }
+        config
+    }
+}
+
+/// Safety facts about a token mint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenSafetyReport {
+    pub token_mint: String,
+    pub mint_authority_renounced: bool,
+    pub freeze_authority_renounced: bool,
+    /// Share of circulating supply held by the largest non-pool holder
+    pub top1_share: f64,
+    /// Share of circulating supply held by the 10 largest non-pool holders
+    pub top10_share: f64,
+    /// LP burned and locked, when the token's pool was seen and its LP mint is known
+    #[serde(default)]
+    pub lp_lock: Option<LpLockStatus>,
+    pub checked_at: i64,
+}
+
+impl TokenSafetyReport {
+    /// Overall safety from 0.0 to 1.0
+    ///
+    /// Mean of renounced mint authority, renounced freeze authority, the
+    /// supply share outside the 10 largest holders and, when the LP was
+    /// checked, the share of LP burned or locked.
+    pub fn safety_score(&self) -> f64 {
+        let mut parts = vec![
+            if self.mint_authority_renounced { 1.0 } else { 0.0 },
+            if self.freeze_authority_renounced { 1.0 } else { 0.0 },
+            (1.0 - self.top10_share).clamp(0.0, 1.0),
+        ];
+        if let Some(lp_lock) = &self.lp_lock {
+            parts.push(lp_lock.secured_share(0));
+        }
+        parts.iter().sum::<f64>() / parts.len() as f64
+    }
+
+    /// Reasons the token fails `requirement`; empty when it passes
+    pub fn violations(&self, requirement: &SafetyRequirement) -> Vec<String> {
+        let mut violations = Vec::new();
+        if requirement.require_mint_renounced && !self.mint_authority_renounced {
+            violations.push("mint authority not renounced".to_string());
+        }
+        if requirement.require_freeze_renounced && !self.freeze_authority_renounced {
+            violations.push("freeze authority not renounced".to_string());
+        }
+        if self.top1_share > requirement.max_top1_share {
+            violations.push(format!("top holder has {:.1}% of supply", self.top1_share * 100.0));
+        }
+        if self.top10_share > requirement.max_top10_share {
+            violations.push(format!("top 10 holders have {:.1}% of supply", self.top10_share * 100.0));
+        }
+        if requirement.min_lp_secured_share > 0.0 {
+            match &self.lp_lock {
+                None => violations.push("LP burn or lock not verified".to_string()),
+                Some(lp_lock) => {
+                    let secured = lp_lock.secured_share(requirement.min_lp_lock_secs);
+                    if secured < requirement.min_lp_secured_share {
+                        violations.push(format!("{:.1}% of LP burned or locked for {} days",
+                            secured * 100.0, requirement.min_lp_lock_secs / 86400));
+                    }
+                }
+            }
+        }
+        violations
+    }
+}
+
+/// Check a token's mint authorities, holder concentration and, given its pool's LP mint, LP burns and locks
+///
+/// # Arguments
+/// * `token_mint` - Token to check
+/// * `pool_accounts` - Bonding curve, pool and vault addresses excluded from holder shares
+/// * `lp_mint` - LP token mint of the token's pool, with the verification settings
+///
+/// # Returns
+/// * `Result<TokenSafetyReport>` - Authorities and concentration at the current slot
+pub async fn fetch_safety_report(
+    rpc_client: &RpcClient,
+    token_mint: &str,
+    pool_accounts: &[String],
+    lp_mint: Option<(&str, &LpLockConfig)>,
+) -> Result<TokenSafetyReport> {
+    let mint = Pubkey::from_str(token_mint).context("Invalid token mint")?;
+    let mint_account = rpc_client.get_account(&mint).await
+        .with_context(|| format!("Failed to fetch mint account of {}", token_mint))?;
+    let mint_state = Mint::unpack(&mint_account.data)
+        .with_context(|| format!("{} is not an SPL token mint", token_mint))?;
+
+    let distribution = fetch_holder_distribution(rpc_client, token_mint, pool_accounts).await?;
+
+    // A failed LP check leaves the LP unverified rather than failing the whole report
+    let lp_lock = match lp_mint {
+        Some((lp_mint, config)) => match fetch_lp_lock_status(rpc_client, lp_mint, config).await {
+            Ok(status) => Some(status),
+            Err(e) => {
+                warn!("🔒 LP check of {} failed: {}", token_mint, e);
+                None
+            }
+        },
+        None => None,
+    };
+
+    Ok(TokenSafetyReport {
+        token_mint: token_mint.to_string(),
+        mint_authority_renounced: mint_state.mint_authority.is_none(),
+        freeze_authority_renounced: mint_state.freeze_authority.is_none(),
+        top1_share: distribution.top_share(1),
+        top10_share: distribution.top_share(10),
+        lp_lock,
+        checked_at: Utc::now().timestamp(),
+    })
+}
+
+/// Pool a token launched in, as seen on the event stream
+#[derive(Debug, Clone)]
+struct LaunchPool {
+    lp_mint: String,
+    /// Pool and vault addresses, excluded from holder shares
+    accounts: Vec<String>,
+    seen_at: i64,
+}
+
+/// Recent safety reports by token mint
+///
+/// Copy trades and checked launch entries consult the cache before a buy is
+/// emitted and fetch the report themselves on a miss, so a token is checked
+/// at most once per TTL no matter how many insiders pile into it.
+pub struct TokenSafetyCache {
+    config: TokenSafetyConfig,
+    reports: DashMap<String, TokenSafetyReport>,
+    /// Token mint -> pool it launched in, for the LP check
+    pools: DashMap<String, LaunchPool>,
+}
+
+impl TokenSafetyCache {
+    pub fn new(config: Option<TokenSafetyConfig>) -> Self {
+        Self {
+            config: config.unwrap_or_default(),
+            reports: DashMap::new(),
+            pools: DashMap::new(),
+        }
+    }
+
+    pub fn config(&self) -> &TokenSafetyConfig {
+        &self.config
+    }
+
+    /// Requirements for an insider tier
+    pub fn requirement_for(&self, tier: Option<&str>) -> &SafetyRequirement {
+        tier.and_then(|tier| self.config.tier_requirements.get(tier))
+            .unwrap_or(&self.config.default_requirement)
+    }
+
+    /// Requirements for launch entries, when configured
+    pub fn sniper_requirement(&self) -> Option<&SafetyRequirement> {
+        self.config.sniper_requirement.as_ref()
+    }
+
+    /// Remember the pool and LP mint of tokens launching in a new pool
+    pub fn observe(&self, event: &MarketEvent) {
+        let MarketEvent::PoolCreated { pool, .. } = event else {
+            return;
+        };
+        if Pubkey::from_str(&pool.lp_mint).is_err() {
+            return;
+        }
+        let now = Utc::now().timestamp();
+        if self.pools.len() > PRUNE_THRESHOLD {
+            self.pools.retain(|_, launch| now - launch.seen_at < 86400);
+        }
+        let token_mint = if pool.base_mint == SOL_MINT { &pool.quote_mint } else { &pool.base_mint };
+        self.pools.insert(token_mint.clone(), LaunchPool {
+            lp_mint: pool.lp_mint.clone(),
+            accounts: vec![pool.address.clone(), pool.base_vault.clone(), pool.quote_vault.clone()],
+            seen_at: now,
+        });
+    }
+
+    /// Pool and vault addresses of the pool a token launched in
+    pub fn pool_accounts(&self, token_mint: &str) -> Vec<String> {
+        self.pools.get(token_mint).map(|launch| launch.accounts.clone()).unwrap_or_default()
+    }
+
+    /// Cached report for a mint, if still fresh
+    pub fn cached(&self, token_mint: &str) -> Option<TokenSafetyReport> {
+        let report = self.reports.get(token_mint)?;
+        (Utc::now().timestamp() - report.checked_at < self.config.report_ttl_secs).then(|| report.clone())
+    }
+
+    /// Report for a mint, fetched from chain if none is cached
+    pub async fn report(
+        &self,
+        rpc_client: &RpcClient,
+        token_mint: &str,
+        pool_accounts: &[String],
+    ) -> Result<TokenSafetyReport> {
+        if let Some(report) = self.cached(token_mint) {
+            return Ok(report);
+        }
+
+        let lp_mint = self.pools.get(token_mint).map(|launch| launch.lp_mint.clone());
+        let report = fetch_safety_report(
+            rpc_client, token_mint, pool_accounts, lp_mint.as_deref().map(|lp_mint| (lp_mint, &self.config.lp_lock)),
+        ).await?;
+        debug!("🛡️ Safety report for {}: mint renounced {}, freeze renounced {}, top holder {:.1}%, LP secured {}",
+            token_mint, report.mint_authority_renounced, report.freeze_authority_renounced, report.top1_share * 100.0,
+            report.lp_lock.as_ref().map_or("unknown".to_string(), |lp_lock| format!("{:.1}%", lp_lock.secured_share(0) * 100.0)));
+        if self.reports.len() > PRUNE_THRESHOLD {
+            let ttl = self.config.report_ttl_secs;
+            self.reports.retain(|_, cached| report.checked_at - cached.checked_at < ttl);
+        }
+        self.reports.insert(token_mint.to_string(), report.clone());
+        Ok(report)
+    }
+}
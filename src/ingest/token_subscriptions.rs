@@ -0,0 +1,247 @@
+use chrono::Utc;
+use dashmap::{DashMap, DashSet};
+use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use crate::core::{MarketEvent, SOL_MINT};
+use super::websocket::SolanaWebSocketClient;
+
+/// Configuration for token account subscriptions
+#[derive(Debug, Clone)]
+pub struct TokenSubscriptionConfig {
+    /// A token without swaps, liquidity or curve changes for this long is dead (seconds)
+    pub max_idle_secs: i64,
+    /// A pool whose liquidity falls below this is drained (SOL)
+    pub drained_liquidity_sol: f64,
+    /// How often subscriptions are added for held tokens and dead ones pruned (seconds)
+    pub prune_interval_secs: u64,
+    /// Commitment used for token account subscriptions
+    pub commitment: String,
+}
+
+impl Default for TokenSubscriptionConfig {
+    fn default() -> Self {
+        Self {
+            max_idle_secs: 1800,
+            drained_liquidity_sol: 0.5,
+            prune_interval_secs: 60,
+            commitment: "confirmed".to_string(),
+        }
+    }
+}
+
+impl TokenSubscriptionConfig {
+    /// Defaults with the idle period overridden by `BADGER_DEAD_TOKEN_IDLE_SECS`
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(secs) = std::env::var("BADGER_DEAD_TOKEN_IDLE_SECS").ok().and_then(|v| v.parse().ok()) {
+            config.max_idle_secs = secs;
+        }
+        config
+    }
+}
+
+/// Why a token was declared dead
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeadTokenReason {
+    /// Its pool was burned or its liquidity fell below the threshold
+    Drained,
+    /// Nothing happened on it for the idle period
+    Idle,
+}
+
+#[derive(Debug, Clone)]
+struct TokenActivity {
+    last_activity: i64,
+    drained: bool,
+}
+
+/// Per-token account subscriptions and the activity that keeps them alive
+///
+/// Held tokens get their bonding curve, pool or metadata accounts subscribed. Every
+/// market event touching a token refreshes it; a token whose pool is burned
+/// or drained, or that sees no activity for the idle period, is dead: its
+/// subscriptions are removed and the caller drops its parser state, so
+/// subscription slots and tracking no longer only ever grow.
+pub struct TokenSubscriptionRegistry {
+    config: TokenSubscriptionConfig,
+    activity: DashMap<String, TokenActivity>,
+    /// Pool, curve or metadata account -> token mint
+    mint_by_account: DashMap<String, String>,
+    /// Token mint -> subscribed (account, request id)
+    subscriptions: DashMap<String, Vec<(String, u64)>>,
+    /// Tokens pruned as drained; not subscribed again unless a new pool appears
+    drained: DashSet<String>,
+}
+
+impl TokenSubscriptionRegistry {
+    pub fn new(config: Option<TokenSubscriptionConfig>) -> Self {
+        Self {
+            config: config.unwrap_or_default(),
+            activity: DashMap::new(),
+            mint_by_account: DashMap::new(),
+            subscriptions: DashMap::new(),
+            drained: DashSet::new(),
+        }
+    }
+
+    pub fn config(&self) -> &TokenSubscriptionConfig {
+        &self.config
+    }
+
+    /// Record activity, pool drains and pool ownership carried by a market event
+    pub fn observe(&self, event: &MarketEvent) {
+        let now = Utc::now().timestamp();
+        match event {
+            MarketEvent::PoolCreated { pool, .. } => {
+                let mint = if pool.base_mint == SOL_MINT { &pool.quote_mint } else { &pool.base_mint };
+                self.mint_by_account.insert(pool.address.clone(), mint.clone());
+                self.drained.remove(mint);
+                self.touch(mint, now, false);
+            }
+            MarketEvent::TokenLaunched { token } => self.touch(&token.mint, now, false),
+            MarketEvent::SwapDetected { swap } => {
+                for mint in [&swap.token_in, &swap.token_out] {
+                    if mint != SOL_MINT {
+                        self.touch(mint, now, false);
+                    }
+                }
+            }
+            MarketEvent::LiquidityChanged { pool_address, new_total_sol, .. } => {
+                if let Some(mint) = self.mint_by_account.get(pool_address).map(|m| m.clone()) {
+                    self.touch(&mint, now, *new_total_sol < self.config.drained_liquidity_sol);
+                }
+            }
+            MarketEvent::PoolBurned { pool_address, .. } => {
+                if let Some(mint) = self.mint_by_account.get(pool_address).map(|m| m.clone()) {
+                    self.touch(&mint, now, true);
+                }
+            }
+            MarketEvent::BondingCurveUpdated { curve, .. } => {
+                let mint = curve.token_mint.clone()
+                    .or_else(|| self.mint_by_account.get(&curve.curve_address).map(|m| m.clone()));
+                if let Some(mint) = mint {
+                    self.touch(&mint, now, false);
+                }
+            }
+            MarketEvent::LargeTransferDetected { .. } => {}
+        }
+    }
+
+    fn touch(&self, token_mint: &str, at: i64, drained: bool) {
+        let mut activity = self.activity.entry(token_mint.to_string())
+            .or_insert(TokenActivity { last_activity: at, drained });
+        activity.last_activity = activity.last_activity.max(at);
+        activity.drained = drained;
+    }
+
+    /// Whether a token's accounts are subscribed
+    pub fn is_subscribed(&self, token_mint: &str) -> bool {
+        self.subscriptions.contains_key(token_mint)
+    }
+
+    /// Subscribed account and its token mint for a subscribe request
+    pub fn account_for_request(&self, request_id: u64) -> Option<(String, String)> {
+        self.subscriptions.iter().find_map(|entry| {
+            entry.value().iter()
+                .find(|(_, id)| *id == request_id)
+                .map(|(account, _)| (account.clone(), entry.key().clone()))
+        })
+    }
+
+    /// Subscribe a token's pool, curve or metadata accounts
+    ///
+    /// Its idle clock starts now when nothing was seen on it yet. Tokens
+    /// already pruned as drained are skipped.
+    pub async fn subscribe(&self, client: &SolanaWebSocketClient, token_mint: &str, accounts: &[String]) -> anyhow::Result<()> {
+        if self.is_subscribed(token_mint) || self.drained.contains(token_mint) {
+            return Ok(());
+        }
+        let mut subscribed = Vec::new();
+        for account in accounts {
+            let request_id = client.subscribe_account(account, &self.config.commitment).await?;
+            self.mint_by_account.insert(account.clone(), token_mint.to_string());
+            subscribed.push((account.clone(), request_id));
+        }
+        self.activity.entry(token_mint.to_string())
+            .or_insert(TokenActivity { last_activity: Utc::now().timestamp(), drained: false });
+        self.subscriptions.insert(token_mint.to_string(), subscribed);
+        debug!("📌 Subscribed {} accounts of {}", accounts.len(), token_mint);
+        Ok(())
+    }
+
+    /// Tokens that are drained, or idle past the configured period and not in `held`
+    pub fn dead_tokens(&self, held: &HashSet<String>) -> Vec<(String, DeadTokenReason)> {
+        let cutoff = Utc::now().timestamp() - self.config.max_idle_secs;
+        self.activity.iter()
+            .filter_map(|entry| {
+                if entry.drained {
+                    Some((entry.key().clone(), DeadTokenReason::Drained))
+                } else if entry.last_activity < cutoff && !held.contains(entry.key()) {
+                    Some((entry.key().clone(), DeadTokenReason::Idle))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Unsubscribe and forget every dead token
+    ///
+    /// Held tokens are only pruned once drained; a quiet position is still watched.
+    ///
+    /// # Returns
+    /// * `(Vec<(String, DeadTokenReason)>, usize)` - Dead tokens, and how many subscriptions were removed
+    pub async fn prune(&self, client: &SolanaWebSocketClient, held: &HashSet<String>) -> (Vec<(String, DeadTokenReason)>, usize) {
+        let dead = self.dead_tokens(held);
+        let mut unsubscribed = 0;
+        for (token_mint, reason) in &dead {
+            self.activity.remove(token_mint);
+            if *reason == DeadTokenReason::Drained {
+                self.drained.insert(token_mint.clone());
+            }
+            self.mint_by_account.retain(|_, mint| mint != token_mint);
+            let Some((_, subscriptions)) = self.subscriptions.remove(token_mint) else {
+                continue;
+            };
+            for (account, request_id) in subscriptions {
+                match client.unsubscribe_account(request_id).await {
+                    Ok(_) => unsubscribed += 1,
+                    Err(e) => warn!("Failed to unsubscribe {} of {}: {}", account, token_mint, e),
+                }
+            }
+            debug!("🧹 Unsubscribed {} ({:?})", token_mint, reason);
+        }
+        (dead, unsubscribed)
+    }
+
+    /// Re-send every token subscription after a reconnect
+    pub async fn resubscribe(&self, client: &SolanaWebSocketClient) {
+        let tokens: Vec<(String, Vec<String>)> = self.subscriptions.iter()
+            .map(|entry| (entry.key().clone(), entry.value().iter().map(|(account, _)| account.clone()).collect()))
+            .collect();
+        if tokens.is_empty() {
+            return;
+        }
+        self.subscriptions.clear();
+        for (token_mint, accounts) in &tokens {
+            if let Err(e) = self.subscribe(client, token_mint, accounts).await {
+                // Not connected yet - the next pass subscribes held tokens again
+                debug!("Failed to resubscribe {}: {}", token_mint, e);
+                break;
+            }
+        }
+        info!("🔄 Resubscribed {} tokens after reconnect", self.subscriptions.len());
+    }
+
+    /// Number of tokens with account subscriptions
+    pub fn subscribed_count(&self) -> usize {
+        self.subscriptions.len()
+    }
+
+    /// Number of tokens whose activity is tracked
+    pub fn tracked_count(&self) -> usize {
+        self.activity.len()
+    }
+}
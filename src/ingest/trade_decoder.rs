@@ -0,0 +1,433 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::VersionedTransaction;
+use solana_transaction_status::option_serializer::OptionSerializer;
+use solana_transaction_status::{UiTransactionEncoding, UiTransactionStatusMeta, UiTransactionTokenBalance};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use super::commitment::at_least_confirmed;
+use crate::core::constants::SOL_MINT;
+use crate::core::program_registry;
+use crate::core::SwapType;
+
+/// Rent-exempt balance of an SPL token account, paid on creation and refunded on close
+const TOKEN_ACCOUNT_RENT_LAMPORTS: i64 = 2_039_280;
+
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+/// Exact trade by one wallet, decoded from transaction balance deltas
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeData {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub wallet: String,
+    pub token_mint: String,
+    pub side: SwapType,
+    /// Tokens bought or sold (UI units)
+    pub token_amount: f64,
+    /// SOL paid or received, excluding network fees and token account rent
+    pub sol_amount: f64,
+    /// SOL per token
+    pub price_sol: f64,
+    /// Network fee paid by the wallet, if it was the fee payer (SOL)
+    #[serde(default)]
+    pub fee_sol: f64,
+}
+
+/// Direction of a liquidity provision change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LiquidityAction {
+    Add,
+    Remove,
+}
+
+/// Liquidity added to or removed from a pool by one wallet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiquidityData {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub wallet: String,
+    /// Liquidity program invoked (Raydium AMM/CPMM, Meteora DLMM/pools)
+    pub program_id: String,
+    /// Non-SOL side of the pool
+    pub token_mint: String,
+    pub action: LiquidityAction,
+    /// Tokens deposited or withdrawn (UI units)
+    pub token_amount: f64,
+    /// SOL deposited or withdrawn, excluding network fees and token account rent
+    pub sol_amount: f64,
+}
+
+/// Plain SOL moved between one wallet and a single counterparty
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolTransferData {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub from_wallet: String,
+    pub to_wallet: String,
+    /// SOL received by the counterparty, excluding network fees
+    pub sol_amount: f64,
+}
+
+/// What a wallet did in one transaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum WalletActivity {
+    Trade(TradeData),
+    Liquidity(LiquidityData),
+    Transfer(SolTransferData),
+}
+
+/// Sum a wallet's token balances per mint, keyed by account index
+fn owned_token_balances(balances: &[UiTransactionTokenBalance], wallet: &str) -> HashMap<u8, (String, i128, u8)> {
+    balances.iter()
+        .filter(|b| Option::<&String>::from(b.owner.as_ref()).map(|o| o.as_str()) == Some(wallet))
+        .filter_map(|b| {
+            let amount = b.ui_token_amount.amount.parse::<i128>().ok()?;
+            Some((b.account_index, (b.mint.clone(), amount, b.ui_token_amount.decimals)))
+        })
+        .collect()
+}
+
+/// Raw legs of a wallet's SOL <-> token swap
+#[derive(Debug, Clone)]
+pub struct WalletSwap {
+    pub side: SwapType,
+    pub token_mint: String,
+    /// Tokens bought or sold (base units)
+    pub token_amount: u64,
+    pub decimals: u8,
+    /// SOL paid or received, excluding network fees and token account rent
+    pub lamports: u64,
+}
+
+/// Find a wallet's SOL <-> token swap in transaction meta, in base units
+///
+/// # Returns
+/// * `Option<WalletSwap>` - The swap when exactly one token moved against SOL
+pub fn decode_wallet_swap(wallet: &str, account_keys: &[String], meta: &UiTransactionStatusMeta) -> Option<WalletSwap> {
+    let (sol_delta, mut token_deltas) = wallet_balance_deltas(wallet, account_keys, meta)?;
+
+    if token_deltas.len() != 1 || sol_delta == 0 {
+        return None;
+    }
+    let (token_mint, (token_delta, decimals)) = token_deltas.drain().next()?;
+
+    let side = match (token_delta > 0, sol_delta < 0) {
+        (true, true) => SwapType::Buy,
+        (false, false) => SwapType::Sell,
+        _ => return None, // Token and SOL moved the same way - not a swap
+    };
+
+    Some(WalletSwap {
+        side,
+        token_mint,
+        token_amount: u64::try_from(token_delta.unsigned_abs()).ok()?,
+        decimals,
+        lamports: sol_delta.unsigned_abs(),
+    })
+}
+
+/// Decode a wallet's SOL <-> token trade from pre/post balances in transaction meta
+///
+/// Wrapped SOL counts as SOL, and token account rent is added back so the SOL
+/// leg reflects only what was paid to (or received from) the pool.
+///
+/// # Arguments
+/// * `wallet` - Wallet whose trade is decoded
+/// * `account_keys` - Static account keys of the transaction message
+/// * `meta` - Transaction status meta with pre/post balances
+///
+/// # Returns
+/// * `Option<TradeData>` - The trade when exactly one token moved against SOL
+pub fn decode_wallet_trade(
+    wallet: &str,
+    signature: &str,
+    slot: u64,
+    block_time: Option<i64>,
+    account_keys: &[String],
+    meta: &UiTransactionStatusMeta,
+) -> Option<TradeData> {
+    let WalletSwap { side, token_mint, token_amount, decimals, lamports } = decode_wallet_swap(wallet, account_keys, meta)?;
+
+    let token_amount = token_amount as f64 / 10f64.powi(decimals as i32);
+    let sol_amount = lamports as f64 / LAMPORTS_PER_SOL;
+    let fee_sol = match account_keys.first() {
+        Some(fee_payer) if fee_payer == wallet => meta.fee as f64 / LAMPORTS_PER_SOL,
+        _ => 0.0,
+    };
+
+    Some(TradeData {
+        signature: signature.to_string(),
+        slot,
+        block_time,
+        wallet: wallet.to_string(),
+        token_mint,
+        side,
+        token_amount,
+        sol_amount,
+        price_sol: sol_amount / token_amount,
+        fee_sol,
+    })
+}
+
+/// Decode a wallet's liquidity deposit or withdrawal on a known LP program
+///
+/// Token and SOL move the same way (both into the pool on add, both out on
+/// remove); an LP token moving the other way, if the pool mints one, is ignored.
+///
+/// # Returns
+/// * `Option<LiquidityData>` - The LP change when a liquidity program was invoked
+pub fn decode_wallet_liquidity(
+    wallet: &str,
+    signature: &str,
+    slot: u64,
+    block_time: Option<i64>,
+    account_keys: &[String],
+    meta: &UiTransactionStatusMeta,
+) -> Option<LiquidityData> {
+    // Invoked programs are always static keys
+    let program_id = account_keys.iter().find(|k| program_registry::is_liquidity_program(k))?;
+    let (sol_delta, token_deltas) = wallet_balance_deltas(wallet, account_keys, meta)?;
+    if sol_delta == 0 {
+        return None;
+    }
+
+    let action = if sol_delta < 0 { LiquidityAction::Add } else { LiquidityAction::Remove };
+    let mut same_direction = token_deltas.into_iter().filter(|(_, (delta, _))| (*delta < 0) == (sol_delta < 0));
+    let (token_mint, (token_delta, decimals)) = same_direction.next()?;
+    if same_direction.next().is_some() {
+        return None; // Several tokens moved with SOL - not a single SOL pool
+    }
+
+    Some(LiquidityData {
+        signature: signature.to_string(),
+        slot,
+        block_time,
+        wallet: wallet.to_string(),
+        program_id: program_id.clone(),
+        token_mint,
+        action,
+        token_amount: token_delta.unsigned_abs() as f64 / 10f64.powi(decimals as i32),
+        sol_amount: sol_delta.unsigned_abs() as f64 / LAMPORTS_PER_SOL,
+    })
+}
+
+/// Decode a plain SOL transfer between a wallet and exactly one other account
+///
+/// No token balance of the wallet may change, and the counterparty must be the
+/// only account whose balance moved the other way, so swaps, LP changes and
+/// multi-recipient payouts are not mistaken for funding.
+///
+/// # Returns
+/// * `Option<SolTransferData>` - The transfer, oriented from sender to receiver
+pub fn decode_wallet_sol_transfer(
+    wallet: &str,
+    signature: &str,
+    slot: u64,
+    block_time: Option<i64>,
+    account_keys: &[String],
+    meta: &UiTransactionStatusMeta,
+) -> Option<SolTransferData> {
+    let (sol_delta, token_deltas) = wallet_balance_deltas(wallet, account_keys, meta)?;
+    if sol_delta == 0 || !token_deltas.is_empty() {
+        return None;
+    }
+
+    let mut counterparties = account_keys.iter().enumerate()
+        .filter(|(_, key)| key.as_str() != wallet)
+        .filter_map(|(i, key)| {
+            let delta = *meta.post_balances.get(i)? as i64 - *meta.pre_balances.get(i)? as i64;
+            ((delta < 0) != (sol_delta < 0) && delta != 0).then_some((key, delta))
+        });
+    let (counterparty, counterparty_delta) = counterparties.next()?;
+    if counterparties.next().is_some() {
+        return None;
+    }
+
+    let (from_wallet, to_wallet, lamports) = if sol_delta < 0 {
+        (wallet, counterparty.as_str(), counterparty_delta)
+    } else {
+        (counterparty.as_str(), wallet, sol_delta)
+    };
+    Some(SolTransferData {
+        signature: signature.to_string(),
+        slot,
+        block_time,
+        from_wallet: from_wallet.to_string(),
+        to_wallet: to_wallet.to_string(),
+        sol_amount: lamports.unsigned_abs() as f64 / LAMPORTS_PER_SOL,
+    })
+}
+
+/// Net raw token amount and decimals by mint
+type TokenDeltas = HashMap<String, (i128, u8)>;
+
+/// Net SOL (lamports, wrapped SOL included) and per-mint token changes of a wallet
+///
+/// Network fees and token account rent are added back, so only value moved to
+/// or from other programs remains. Mints with no net change are dropped.
+fn wallet_balance_deltas(
+    wallet: &str,
+    account_keys: &[String],
+    meta: &UiTransactionStatusMeta,
+) -> Option<(i64, TokenDeltas)> {
+    if meta.err.is_some() {
+        return None;
+    }
+
+    let wallet_index = account_keys.iter().position(|k| k == wallet)?;
+    let mut sol_delta = *meta.post_balances.get(wallet_index)? as i64 - *meta.pre_balances.get(wallet_index)? as i64;
+
+    // The fee payer's balance also pays the network fee
+    if wallet_index == 0 {
+        sol_delta += meta.fee as i64;
+    }
+
+    let pre: Vec<UiTransactionTokenBalance> = Option::from(meta.pre_token_balances.clone()).unwrap_or_default();
+    let post: Vec<UiTransactionTokenBalance> = Option::from(meta.post_token_balances.clone()).unwrap_or_default();
+    let pre = owned_token_balances(&pre, wallet);
+    let post = owned_token_balances(&post, wallet);
+
+    // Token accounts opened or closed in this transaction move rent, not trade value
+    let opened = post.keys().filter(|i| !pre.contains_key(i)).count() as i64;
+    let closed = pre.keys().filter(|i| !post.contains_key(i)).count() as i64;
+    sol_delta += (opened - closed) * TOKEN_ACCOUNT_RENT_LAMPORTS;
+
+    let mut token_deltas: TokenDeltas = HashMap::new();
+    for (mint, amount, decimals) in post.values() {
+        let entry = token_deltas.entry(mint.clone()).or_insert((0, *decimals));
+        entry.0 += amount;
+    }
+    for (mint, amount, decimals) in pre.values() {
+        let entry = token_deltas.entry(mint.clone()).or_insert((0, *decimals));
+        entry.0 -= amount;
+    }
+
+    // Wrapped SOL is part of the SOL leg
+    if let Some((wsol_delta, _)) = token_deltas.remove(SOL_MINT) {
+        sol_delta += wsol_delta as i64;
+    }
+    token_deltas.retain(|_, (delta, _)| *delta != 0);
+
+    Some((sol_delta, token_deltas))
+}
+
+/// Fetch a transaction and decode the given wallet's trade in it
+pub async fn fetch_wallet_trade(rpc_client: &RpcClient, wallet: &str, signature: &str) -> Result<Option<TradeData>> {
+    Ok(match fetch_wallet_activity(rpc_client, wallet, signature).await? {
+        Some(WalletActivity::Trade(trade)) => Some(trade),
+        _ => None,
+    })
+}
+
+/// A confirmed transaction's static account keys and status meta
+#[derive(Debug, Clone)]
+pub struct FetchedTransaction {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    /// Account keys as balance indexes refer to them; the fee payer comes first
+    pub account_keys: Vec<String>,
+    pub meta: UiTransactionStatusMeta,
+}
+
+/// Account keys of a transaction in the order balance indexes refer to
+///
+/// Lookup table addresses of v0 messages follow the static keys, writable first.
+pub fn resolved_account_keys(transaction: &VersionedTransaction, meta: &UiTransactionStatusMeta) -> Vec<String> {
+    let mut account_keys: Vec<String> = transaction.message
+        .static_account_keys()
+        .iter()
+        .map(|k| k.to_string())
+        .collect();
+    if let OptionSerializer::Some(loaded) = &meta.loaded_addresses {
+        account_keys.extend(loaded.writable.iter().cloned());
+        account_keys.extend(loaded.readonly.iter().cloned());
+    }
+    account_keys
+}
+
+/// Owners of the token accounts whose balance changed in a transaction
+pub fn token_balance_owners(meta: &UiTransactionStatusMeta) -> Vec<String> {
+    // Per token account: (owner, pre, post)
+    let mut balances: HashMap<u8, (Option<String>, i128, i128)> = HashMap::new();
+    for (is_post, token_balances) in [(false, &meta.pre_token_balances), (true, &meta.post_token_balances)] {
+        let OptionSerializer::Some(token_balances) = token_balances else {
+            continue;
+        };
+        for balance in token_balances {
+            let amount = balance.ui_token_amount.amount.parse::<i128>().unwrap_or(0);
+            let slot = balances.entry(balance.account_index).or_insert((None, 0, 0));
+            if let OptionSerializer::Some(owner) = &balance.owner {
+                slot.0.get_or_insert_with(|| owner.clone());
+            }
+            if is_post { slot.2 = amount } else { slot.1 = amount }
+        }
+    }
+
+    let mut owners: Vec<String> = balances.into_values()
+        .filter(|(_, pre, post)| pre != post)
+        .filter_map(|(owner, _, _)| owner)
+        .collect();
+    owners.sort();
+    owners.dedup();
+    owners
+}
+
+/// Fetch a transaction at confirmed commitment or higher
+///
+/// # Returns
+/// * `Result<Option<FetchedTransaction>>` - None when the node returned no meta or an undecodable message
+pub async fn fetch_transaction(rpc_client: &RpcClient, signature: &str) -> Result<Option<FetchedTransaction>> {
+    let parsed_signature = Signature::from_str(signature).context("Invalid transaction signature")?;
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        commitment: Some(at_least_confirmed(rpc_client.commitment())),
+        max_supported_transaction_version: Some(0),
+    };
+
+    let confirmed = rpc_client
+        .get_transaction_with_config(&parsed_signature, config)
+        .await
+        .with_context(|| format!("Failed to fetch transaction {}", signature))?;
+
+    let Some(transaction) = confirmed.transaction.transaction.decode() else {
+        return Ok(None);
+    };
+    let Some(meta) = confirmed.transaction.meta else {
+        return Ok(None);
+    };
+    let account_keys = resolved_account_keys(&transaction, &meta);
+
+    Ok(Some(FetchedTransaction {
+        signature: signature.to_string(),
+        slot: confirmed.slot,
+        block_time: confirmed.block_time,
+        account_keys,
+        meta,
+    }))
+}
+
+/// Fetch a transaction and decode the given wallet's swap, liquidity change or SOL transfer in it
+pub async fn fetch_wallet_activity(rpc_client: &RpcClient, wallet: &str, signature: &str) -> Result<Option<WalletActivity>> {
+    let Some(tx) = fetch_transaction(rpc_client, signature).await? else {
+        return Ok(None);
+    };
+    let (slot, block_time, account_keys, meta) = (tx.slot, tx.block_time, &tx.account_keys, &tx.meta);
+
+    if let Some(trade) = decode_wallet_trade(wallet, signature, slot, block_time, account_keys, meta) {
+        return Ok(Some(WalletActivity::Trade(trade)));
+    }
+    if let Some(lp) = decode_wallet_liquidity(wallet, signature, slot, block_time, account_keys, meta) {
+        return Ok(Some(WalletActivity::Liquidity(lp)));
+    }
+    Ok(decode_wallet_sol_transfer(wallet, signature, slot, block_time, account_keys, meta)
+        .map(WalletActivity::Transfer))
+}
@@ -0,0 +1,387 @@
+use futures_util::future::join_all;
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcSimulateTransactionConfig;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::Transaction;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+use crate::core::constants::{SOL_MINT, USDC_MINT};
+
+/// Quote API an entry can be executed through
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ExecutionVenue {
+    Jupiter,
+    Raydium,
+}
+
+impl ExecutionVenue {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "jupiter" => Some(Self::Jupiter),
+            "raydium" => Some(Self::Raydium),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Jupiter => "jupiter",
+            Self::Raydium => "raydium",
+        }
+    }
+}
+
+/// Health of a venue or the submission path after the latest probe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VenueStatus {
+    /// Not probed yet
+    Unknown,
+    Healthy,
+    /// Answering, but slowly or after a failed probe
+    Degraded,
+    /// Failing repeatedly or rejecting our credentials
+    Unavailable,
+}
+
+impl VenueStatus {
+    /// Whether orders may be routed to it
+    pub fn is_usable(&self) -> bool {
+        !matches!(self, VenueStatus::Unavailable)
+    }
+}
+
+/// Configuration for pre-trade venue health checks
+#[derive(Debug, Clone)]
+pub struct VenueHealthConfig {
+    /// Venues probed, in order of preference
+    pub venues: Vec<ExecutionVenue>,
+    pub jupiter_quote_url: String,
+    /// Sent as `x-api-key` to the Jupiter quote API
+    pub jupiter_api_key: Option<String>,
+    pub raydium_quote_url: String,
+    /// SOL -> USDC amount quoted on every venue (lamports)
+    pub probe_amount_lamports: u64,
+    /// Payer of the simulated transfer; a random key still exercises the RPC path
+    pub probe_wallet: Option<Pubkey>,
+    /// How often venues are re-probed (seconds)
+    pub probe_interval_secs: u64,
+    /// Timeout for a single probe (milliseconds)
+    pub probe_timeout_ms: u64,
+    /// Probes slower than this leave the venue degraded (milliseconds)
+    pub degraded_latency_ms: f64,
+    /// Consecutive failed probes after which a venue is unavailable
+    pub unavailable_after_failures: u32,
+}
+
+impl Default for VenueHealthConfig {
+    fn default() -> Self {
+        Self {
+            venues: vec![ExecutionVenue::Jupiter, ExecutionVenue::Raydium],
+            jupiter_quote_url: "https://quote-api.jup.ag/v6/quote".to_string(),
+            jupiter_api_key: None,
+            raydium_quote_url: "https://transaction-v1.raydium.io/compute/swap-base-in".to_string(),
+            probe_amount_lamports: 10_000_000, // 0.01 SOL
+            probe_wallet: None,
+            probe_interval_secs: 120,
+            probe_timeout_ms: 5000,
+            degraded_latency_ms: 1500.0,
+            unavailable_after_failures: 2,
+        }
+    }
+}
+
+impl VenueHealthConfig {
+    /// Defaults with overrides from `BADGER_EXECUTION_VENUES`, `BADGER_JUPITER_QUOTE_URL`,
+    /// `BADGER_JUPITER_API_KEY` and the first of `BADGER_OWN_WALLETS` when set
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Ok(venues) = std::env::var("BADGER_EXECUTION_VENUES") {
+            config.venues = venues.split(',')
+                .filter_map(|name| {
+                    let venue = ExecutionVenue::from_name(name);
+                    if venue.is_none() && !name.trim().is_empty() {
+                        warn!("Unknown execution venue {} ignored", name.trim());
+                    }
+                    venue
+                })
+                .collect();
+        }
+        if let Ok(url) = std::env::var("BADGER_JUPITER_QUOTE_URL") {
+            config.jupiter_quote_url = url;
+        }
+        config.jupiter_api_key = std::env::var("BADGER_JUPITER_API_KEY").ok().filter(|key| !key.is_empty());
+        config.probe_wallet = std::env::var("BADGER_OWN_WALLETS").ok()
+            .and_then(|wallets| wallets.split(',').find_map(|w| Pubkey::from_str(w.trim()).ok()));
+        config
+    }
+}
+
+/// Latest probe result for one venue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueHealth {
+    /// None for the transaction submission path
+    pub venue: Option<ExecutionVenue>,
+    pub status: VenueStatus,
+    pub latency_ms: Option<f64>,
+    pub consecutive_failures: u32,
+    /// Why the last probe failed
+    pub last_error: Option<String>,
+    pub checked_at: i64,
+}
+
+/// Outcome of a probe round
+#[derive(Debug, Clone, Default)]
+pub struct VenueProbeRound {
+    pub venues: Vec<VenueHealth>,
+    pub submission: Option<VenueHealth>,
+    /// Venues (or "rpc" for the submission path) whose status changed: (name, previous, new)
+    pub changes: Vec<(String, VenueStatus, VenueStatus)>,
+}
+
+/// Outcome of a single probe before it is folded into a status
+enum ProbeOutcome {
+    Answered(f64),
+    Failed(String),
+    /// Credentials rejected; retrying will not help
+    Rejected(String),
+}
+
+/// Checks execution venues before and during a trading session
+///
+/// A broken quote API key otherwise only shows up when a real trade fails.
+/// Every round quotes a liquid pair (SOL -> USDC) on each configured venue
+/// and simulates a transfer through the execution RPC endpoint, and marks
+/// each healthy, degraded or unavailable. The router only sends entries to
+/// usable venues and holds them while the submission path is down.
+pub struct VenueHealthMonitor {
+    config: VenueHealthConfig,
+    client: Client,
+    venues: RwLock<HashMap<ExecutionVenue, VenueHealth>>,
+    submission: RwLock<Option<VenueHealth>>,
+}
+
+impl VenueHealthMonitor {
+    pub fn new(config: Option<VenueHealthConfig>) -> Self {
+        let config = config.unwrap_or_default();
+        let client = Client::builder()
+            .timeout(Duration::from_millis(config.probe_timeout_ms))
+            .build()
+            .unwrap_or_default();
+        Self {
+            config,
+            client,
+            venues: RwLock::new(HashMap::new()),
+            submission: RwLock::new(None),
+        }
+    }
+
+    pub fn config(&self) -> &VenueHealthConfig {
+        &self.config
+    }
+
+    /// Status of a venue; Unknown until it is probed
+    pub fn status(&self, venue: ExecutionVenue) -> VenueStatus {
+        self.venues.read().expect("venue lock poisoned")
+            .get(&venue)
+            .map_or(VenueStatus::Unknown, |health| health.status)
+    }
+
+    /// Status of transaction submission through the execution RPC endpoint
+    pub fn submission_status(&self) -> VenueStatus {
+        self.submission.read().expect("venue lock poisoned")
+            .as_ref()
+            .map_or(VenueStatus::Unknown, |health| health.status)
+    }
+
+    /// Venue entries should be routed to: the first healthy one in preference
+    /// order, else the first degraded one
+    pub fn preferred_venue(&self) -> Option<ExecutionVenue> {
+        let venues = self.venues.read().expect("venue lock poisoned");
+        let status = |venue: &ExecutionVenue| venues.get(venue).map_or(VenueStatus::Unknown, |h| h.status);
+        self.config.venues.iter()
+            .find(|venue| matches!(status(venue), VenueStatus::Healthy | VenueStatus::Unknown))
+            .or_else(|| self.config.venues.iter().find(|venue| status(venue).is_usable()))
+            .copied()
+    }
+
+    /// Why new entries cannot be executed right now, if they cannot
+    pub fn trading_blocker(&self) -> Option<String> {
+        if !self.submission_status().is_usable() {
+            return Some("transaction submission unavailable".to_string());
+        }
+        if !self.config.venues.is_empty() && self.preferred_venue().is_none() {
+            return Some("no execution venue available".to_string());
+        }
+        None
+    }
+
+    /// Latest results for every venue, in preference order
+    pub fn health(&self) -> Vec<VenueHealth> {
+        let venues = self.venues.read().expect("venue lock poisoned");
+        self.config.venues.iter().filter_map(|venue| venues.get(venue).cloned()).collect()
+    }
+
+    /// Probe every venue and the submission path, and update their statuses
+    ///
+    /// # Arguments
+    /// * `rpc_http_url` - Execution RPC endpoint transactions are submitted through
+    pub async fn probe(&self, rpc_http_url: &str) -> VenueProbeRound {
+        let (venue_outcomes, submission_outcome) = tokio::join!(
+            join_all(self.config.venues.iter().map(|venue| self.probe_venue(*venue))),
+            self.probe_submission(rpc_http_url),
+        );
+
+        let mut round = VenueProbeRound::default();
+        {
+            let mut venues = self.venues.write().expect("venue lock poisoned");
+            for (venue, outcome) in self.config.venues.iter().zip(venue_outcomes) {
+                let previous = venues.get(venue).cloned();
+                let health = self.fold(Some(*venue), previous.as_ref(), outcome);
+                let before = previous.map_or(VenueStatus::Unknown, |h| h.status);
+                if before != health.status {
+                    round.changes.push((venue.name().to_string(), before, health.status));
+                }
+                venues.insert(*venue, health.clone());
+                round.venues.push(health);
+            }
+        }
+        {
+            let mut submission = self.submission.write().expect("venue lock poisoned");
+            let health = self.fold(None, submission.as_ref(), submission_outcome);
+            let before = submission.as_ref().map_or(VenueStatus::Unknown, |h| h.status);
+            if before != health.status {
+                round.changes.push(("rpc".to_string(), before, health.status));
+            }
+            *submission = Some(health.clone());
+            round.submission = Some(health);
+        }
+
+        for (name, before, after) in &round.changes {
+            info!("🩺 Execution venue {}: {:?} -> {:?}", name, before, after);
+        }
+        round
+    }
+
+    fn fold(&self, venue: Option<ExecutionVenue>, previous: Option<&VenueHealth>, outcome: ProbeOutcome) -> VenueHealth {
+        let failures = previous.map_or(0, |h| h.consecutive_failures);
+        let checked_at = chrono::Utc::now().timestamp();
+        match outcome {
+            ProbeOutcome::Answered(latency_ms) => VenueHealth {
+                venue,
+                status: if latency_ms > self.config.degraded_latency_ms { VenueStatus::Degraded } else { VenueStatus::Healthy },
+                latency_ms: Some(latency_ms),
+                consecutive_failures: 0,
+                last_error: None,
+                checked_at,
+            },
+            ProbeOutcome::Failed(error) => VenueHealth {
+                venue,
+                status: if failures + 1 >= self.config.unavailable_after_failures {
+                    VenueStatus::Unavailable
+                } else {
+                    VenueStatus::Degraded
+                },
+                latency_ms: None,
+                consecutive_failures: failures + 1,
+                last_error: Some(error),
+                checked_at,
+            },
+            ProbeOutcome::Rejected(error) => VenueHealth {
+                venue,
+                status: VenueStatus::Unavailable,
+                latency_ms: None,
+                consecutive_failures: failures + 1,
+                last_error: Some(error),
+                checked_at,
+            },
+        }
+    }
+
+    /// Quote the probe amount of SOL into USDC
+    async fn probe_venue(&self, venue: ExecutionVenue) -> ProbeOutcome {
+        let amount = self.config.probe_amount_lamports.to_string();
+        let request = match venue {
+            ExecutionVenue::Jupiter => {
+                let request = self.client.get(&self.config.jupiter_quote_url)
+                    .query(&[("inputMint", SOL_MINT), ("outputMint", USDC_MINT), ("amount", &amount), ("slippageBps", "50")]);
+                match &self.config.jupiter_api_key {
+                    Some(key) => request.header("x-api-key", key),
+                    None => request,
+                }
+            }
+            ExecutionVenue::Raydium => self.client.get(&self.config.raydium_quote_url)
+                .query(&[("inputMint", SOL_MINT), ("outputMint", USDC_MINT), ("amount", &amount), ("slippageBps", "50"), ("txVersion", "V0")]),
+        };
+
+        let started = Instant::now();
+        let response = match request.send().await {
+            Ok(response) => response,
+            Err(e) => return ProbeOutcome::Failed(format!("request failed: {}", e)),
+        };
+        let latency_ms = started.elapsed().as_secs_f64() * 1000.0;
+        match response.status() {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                return ProbeOutcome::Rejected(format!("credentials rejected ({})", response.status()));
+            }
+            status if !status.is_success() => return ProbeOutcome::Failed(format!("HTTP {}", status)),
+            _ => {}
+        }
+
+        let body: serde_json::Value = match response.json().await {
+            Ok(body) => body,
+            Err(e) => return ProbeOutcome::Failed(format!("unreadable quote: {}", e)),
+        };
+        // Jupiter answers {"outAmount": "..."}, Raydium {"success": true, "data": {"outputAmount": "..."}}
+        let out_amount = match venue {
+            ExecutionVenue::Jupiter => body.get("outAmount"),
+            ExecutionVenue::Raydium => body.get("data").and_then(|data| data.get("outputAmount")),
+        };
+        match out_amount.and_then(|v| v.as_str()).and_then(|v| v.parse::<u64>().ok()) {
+            Some(amount) if amount > 0 => {
+                debug!("🩺 {} quoted {} SOL lamports -> {} USDC units in {:.0}ms",
+                    venue.name(), self.config.probe_amount_lamports, amount, latency_ms);
+                ProbeOutcome::Answered(latency_ms)
+            }
+            _ => ProbeOutcome::Failed(format!("no quote in response: {}", body)),
+        }
+    }
+
+    /// Simulate a zero-lamport self-transfer through the execution endpoint
+    ///
+    /// Signatures are not verified and the blockhash is replaced, so nothing
+    /// needs signing; an unfunded payer fails inside the simulation, which
+    /// still proves the endpoint accepts and executes transactions.
+    async fn probe_submission(&self, rpc_http_url: &str) -> ProbeOutcome {
+        let rpc_client = RpcClient::new_with_timeout_and_commitment(
+            rpc_http_url.to_string(),
+            Duration::from_millis(self.config.probe_timeout_ms),
+            CommitmentConfig::processed(),
+        );
+        let payer = self.config.probe_wallet.unwrap_or_else(Pubkey::new_unique);
+        let transaction = Transaction::new_with_payer(&[system_instruction::transfer(&payer, &payer, 0)], Some(&payer));
+        let simulation_config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            ..RpcSimulateTransactionConfig::default()
+        };
+
+        let started = Instant::now();
+        match rpc_client.simulate_transaction_with_config(&transaction, simulation_config).await {
+            Ok(response) => {
+                if let (Some(err), Some(_)) = (&response.value.err, self.config.probe_wallet) {
+                    debug!("🩺 Probe transfer from {} failed in simulation: {}", payer, err);
+                }
+                ProbeOutcome::Answered(started.elapsed().as_secs_f64() * 1000.0)
+            }
+            Err(e) => ProbeOutcome::Failed(format!("simulateTransaction failed: {}", e)),
+        }
+    }
+}
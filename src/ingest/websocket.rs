@@ -6,13 +6,19 @@ use tokio::sync::mpsc;
 use tokio::time::{sleep, Duration, timeout, Instant};
 use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
 use tracing::{info, warn, error, debug, instrument};
-use url::Url;
+use crate::core::chaos;
+use crate::core::Launchpad;
+use crate::core::dex_types::constants::{
+    JUPITER_V6_PROGRAM, LAUNCHLAB_PROGRAM, MOONSHOT_PROGRAM, ORCA_WHIRLPOOL_PROGRAM, PUMP_FUN_PROGRAM, RAYDIUM_AMM_PROGRAM,
+};
+use crate::ingest::message_scan::{MessageKind, MessageScanner, NotificationMethod};
+use crate::ingest::endpoint_auth::{AutoSubscription, EndpointSettings, redact_url};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 /// Configuration for Solana WebSocket connection
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct WebSocketConfig {
     /// Primary RPC WebSocket URL (e.g., "wss://api.mainnet-beta.solana.com/")
     pub primary_url: String,
@@ -26,6 +32,32 @@ pub struct WebSocketConfig {
     pub reconnect_delay_ms: u64,
     /// Heartbeat interval to keep connection alive
     pub heartbeat_interval_ms: u64,
+    /// Commitment of the built-in program and account subscriptions
+    pub commitment: String,
+    /// Auth and auto-subscriptions of endpoints that need them, matched on URL
+    pub endpoints: Vec<EndpointSettings>,
+    /// Also subscribe to the transaction logs of each DEX program
+    pub logs_subscribe: bool,
+    /// Also subscribe to every confirmed block with full transaction details
+    pub block_subscribe: bool,
+}
+
+// URLs may carry API keys; never print them whole
+impl std::fmt::Debug for WebSocketConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebSocketConfig")
+            .field("primary_url", &redact_url(&self.primary_url))
+            .field("backup_urls", &self.backup_urls.iter().map(|url| redact_url(url)).collect::<Vec<_>>())
+            .field("connect_timeout_ms", &self.connect_timeout_ms)
+            .field("reconnect_delay_ms", &self.reconnect_delay_ms)
+            .field("commitment", &self.commitment)
+            .field("logs_subscribe", &self.logs_subscribe)
+            .field("block_subscribe", &self.block_subscribe)
+            .field("endpoints", &self.endpoints.iter()
+                .map(|endpoint| (redact_url(&endpoint.url), &endpoint.auth, &endpoint.subscriptions))
+                .collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for WebSocketConfig {
@@ -40,10 +72,47 @@ impl Default for WebSocketConfig {
             max_reconnect_attempts: 10,
             reconnect_delay_ms: 1000,
             heartbeat_interval_ms: 30000,
+            commitment: "confirmed".to_string(),
+            endpoints: Vec::new(),
+            logs_subscribe: false,
+            block_subscribe: false,
         }
     }
 }
 
+impl WebSocketConfig {
+    /// Configuration for the public Solana devnet endpoint (test mode)
+    pub fn devnet() -> Self {
+        Self {
+            primary_url: "wss://api.devnet.solana.com/".to_string(),
+            backup_urls: vec![],
+            ..Self::default()
+        }
+    }
+
+    /// Settings of the endpoint at `url`; anonymous with every subscription when not configured
+    pub fn endpoint(&self, url: &str) -> EndpointSettings {
+        self.endpoints.iter()
+            .find(|endpoint| endpoint.url == url)
+            .cloned()
+            .unwrap_or_else(|| EndpointSettings { url: url.to_string(), ..Default::default() })
+    }
+
+    /// Make the configured endpoints the primary and first backups, ahead of the current URLs
+    pub fn prefer_configured_endpoints(&mut self) {
+        let Some((first, rest)) = self.endpoints.split_first() else {
+            return;
+        };
+        let mut backups: Vec<String> = rest.iter().map(|endpoint| endpoint.url.clone()).collect();
+        backups.extend(std::iter::once(self.primary_url.clone()).chain(self.backup_urls.drain(..)));
+        backups.retain(|url| url != &first.url);
+        let mut seen = std::collections::HashSet::new();
+        backups.retain(|url| seen.insert(url.clone()));
+        self.primary_url = first.url.clone();
+        self.backup_urls = backups;
+    }
+}
+
 /// JSON-RPC request for Solana WebSocket subscriptions
 #[derive(Debug, Serialize)]
 pub struct JsonRpcRequest {
@@ -119,6 +188,8 @@ pub enum WebSocketEvent {
     SlotUpdate { subscription_id: u64, data: Value },
     /// Block update received
     BlockUpdate { subscription_id: u64, data: Value },
+    /// Transaction logs mentioning a subscribed program received
+    LogsNotification { subscription_id: u64, data: Value },
     /// Error occurred
     Error { error: String },
 }
@@ -163,6 +234,8 @@ pub struct SolanaWebSocketClient {
     active_subscriptions: Arc<tokio::sync::RwLock<HashMap<u64, u64>>>,
     /// Channel for sending messages to WebSocket (populated when connected)
     message_sender: Arc<tokio::sync::RwLock<Option<mpsc::UnboundedSender<Message>>>>,
+    /// Signalled to drop the current connection and move to the next URL
+    failover: Arc<tokio::sync::Notify>,
 }
 
 impl std::fmt::Debug for SolanaWebSocketClient {
@@ -184,13 +257,13 @@ impl SolanaWebSocketClient {
     /// * `Result<(Self, mpsc::UnboundedReceiver<WebSocketEvent>)>` - Client instance and event receiver
     #[instrument]
     pub fn new(config: WebSocketConfig) -> Result<(Self, mpsc::UnboundedReceiver<WebSocketEvent>)> {
-        info!("Initializing Solana WebSocket client with primary URL: {}", config.primary_url);
+        info!("Initializing Solana WebSocket client with primary URL: {}", redact_url(&config.primary_url));
         
         let (event_sender, event_receiver) = mpsc::unbounded_channel();
         
         let stats = ConnectionStats {
             state: ConnectionState::Disconnected,
-            current_url: config.primary_url.clone(),
+            current_url: redact_url(&config.primary_url),
             connection_attempts: 0,
             successful_connections: 0,
             messages_sent: 0,
@@ -203,11 +276,12 @@ impl SolanaWebSocketClient {
         let client = Self {
             config,
             event_sender,
-            request_id: Arc::new(AtomicU64::new(1)),
+            request_id: Arc::new(AtomicU64::new(1000)), // Below 1000 reserved for auto-subscriptions
             connection_state: Arc::new(tokio::sync::RwLock::new(ConnectionState::Disconnected)),
             stats: Arc::new(tokio::sync::RwLock::new(stats)),
             active_subscriptions: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
             message_sender: Arc::new(tokio::sync::RwLock::new(None)),
+            failover: Arc::new(tokio::sync::Notify::new()),
         };
         
         Ok((client, event_receiver))
@@ -233,19 +307,19 @@ impl SolanaWebSocketClient {
                 *state = ConnectionState::Connecting;
                 let mut stats = self.stats.write().await;
                 stats.state = ConnectionState::Connecting;
-                stats.current_url = url.clone();
+                stats.current_url = redact_url(url);
                 stats.connection_attempts += 1;
             }
             
             // Emit connecting event
-            let _ = self.event_sender.send(WebSocketEvent::Connected { url: url.clone() });
+            let _ = self.event_sender.send(WebSocketEvent::Connected { url: redact_url(url) });
             
             match self.connect_and_handle(url).await {
                 Ok(()) => {
                     debug!("WebSocket connection closed normally");
                 }
                 Err(e) => {
-                    error!(error = %e, url = %url, "WebSocket connection failed");
+                    error!(error = %e, url = %redact_url(url), "WebSocket connection failed");
                     
                     // Update connection state to failed
                     {
@@ -257,7 +331,7 @@ impl SolanaWebSocketClient {
                     
                     // Emit error event
                     let _ = self.event_sender.send(WebSocketEvent::Error {
-                        error: format!("Connection to {} failed: {}", url, e),
+                        error: format!("Connection to {} failed: {}", redact_url(url), e),
                     });
                     
                     // Try next URL
@@ -289,22 +363,24 @@ impl SolanaWebSocketClient {
     /// 
     /// # Returns
     /// * `Result<()>` - Ok if connection was successful and closed normally
-    #[instrument(skip(self))]
+    #[instrument(skip(self, url), fields(url = %redact_url(url)))]
     async fn connect_and_handle(&self, url: &str) -> Result<()> {
-        info!("Attempting to connect to Solana RPC WebSocket: {}", url);
+        let redacted_url = redact_url(url);
+        info!("Attempting to connect to Solana RPC WebSocket: {}", redacted_url);
         
-        // Parse and validate URL
-        let parsed_url = Url::parse(url).context("Failed to parse WebSocket URL")?;
+        // Build the handshake with the endpoint's credentials
+        let endpoint = self.config.endpoint(url);
+        let request = endpoint.auth.request(url)?;
         
         // Establish WebSocket connection with timeout
         let (ws_stream, response) = timeout(
             Duration::from_millis(self.config.connect_timeout_ms),
-            connect_async(parsed_url)
+            connect_async(request)
         ).await
             .context("Connection timeout")?
             .context("Failed to connect to WebSocket")?;
         
-        info!("Successfully connected to {} (HTTP {})", url, response.status());
+        info!("Successfully connected to {} (HTTP {})", redacted_url, response.status());
         
         // Update connection state to connected
         {
@@ -330,6 +406,7 @@ impl SolanaWebSocketClient {
         
         // Auto-subscribe to key data streams after connection and channel setup
         info!("🔧 Auto-subscribing to Solana data streams...");
+        let commitment = self.config.commitment.as_str();
         
         // Subscribe to slot updates (most reliable)
         let slot_request = JsonRpcRequest {
@@ -339,7 +416,9 @@ impl SolanaWebSocketClient {
             params: serde_json::json!([]),
         };
         
-        if let Ok(slot_msg) = serde_json::to_string(&slot_request) {
+        if !endpoint.subscribes(AutoSubscription::Slot) {
+            debug!("Skipping slot subscription on {}", redacted_url);
+        } else if let Ok(slot_msg) = serde_json::to_string(&slot_request) {
             match tx.send(Message::Text(slot_msg)) {
                 Ok(_) => info!("📡 Sent slot subscription request"),
                 Err(e) => error!("❌ Failed to send slot subscription: {}", e),
@@ -355,11 +434,13 @@ impl SolanaWebSocketClient {
             method: "accountSubscribe".to_string(),
             params: serde_json::json!([
                 "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
-                {"commitment": "processed", "encoding": "jsonParsed"}
+                {"commitment": commitment, "encoding": "jsonParsed"}
             ]),
         };
         
-        if let Ok(account_msg) = serde_json::to_string(&account_request) {
+        if !endpoint.subscribes(AutoSubscription::UsdcAccount) {
+            debug!("Skipping USDC account subscription on {}", redacted_url);
+        } else if let Ok(account_msg) = serde_json::to_string(&account_request) {
             match tx.send(Message::Text(account_msg)) {
                 Ok(_) => info!("📡 Sent USDC account subscription request"),
                 Err(e) => error!("❌ Failed to send account subscription: {}", e),
@@ -375,11 +456,13 @@ impl SolanaWebSocketClient {
             method: "programSubscribe".to_string(),
             params: serde_json::json!([
                 "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8",
-                {"commitment": "confirmed", "encoding": "jsonParsed", "filters": []}
+                {"commitment": commitment, "encoding": "jsonParsed", "filters": []}
             ]),
         };
         
-        if let Ok(program_msg) = serde_json::to_string(&raydium_request) {
+        if !endpoint.subscribes(AutoSubscription::Raydium) {
+            debug!("Skipping Raydium program subscription on {}", redacted_url);
+        } else if let Ok(program_msg) = serde_json::to_string(&raydium_request) {
             match tx.send(Message::Text(program_msg)) {
                 Ok(_) => info!("📡 Sent Raydium program subscription request"),
                 Err(e) => error!("❌ Failed to send Raydium program subscription: {}", e),
@@ -395,11 +478,13 @@ impl SolanaWebSocketClient {
             method: "programSubscribe".to_string(),
             params: serde_json::json!([
                 "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4",
-                {"commitment": "confirmed", "encoding": "jsonParsed", "filters": []}
+                {"commitment": commitment, "encoding": "jsonParsed", "filters": []}
             ]),
         };
         
-        if let Ok(jupiter_msg) = serde_json::to_string(&jupiter_request) {
+        if !endpoint.subscribes(AutoSubscription::Jupiter) {
+            debug!("Skipping Jupiter V6 program subscription on {}", redacted_url);
+        } else if let Ok(jupiter_msg) = serde_json::to_string(&jupiter_request) {
             match tx.send(Message::Text(jupiter_msg)) {
                 Ok(_) => info!("📡 Sent Jupiter V6 program subscription request"),
                 Err(e) => error!("❌ Failed to send Jupiter program subscription: {}", e),
@@ -415,11 +500,13 @@ impl SolanaWebSocketClient {
             method: "programSubscribe".to_string(),
             params: serde_json::json!([
                 "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc",
-                {"commitment": "confirmed", "encoding": "jsonParsed", "filters": []}
+                {"commitment": commitment, "encoding": "jsonParsed", "filters": []}
             ]),
         };
         
-        if let Ok(orca_msg) = serde_json::to_string(&orca_request) {
+        if !endpoint.subscribes(AutoSubscription::Orca) {
+            debug!("Skipping Orca Whirlpool program subscription on {}", redacted_url);
+        } else if let Ok(orca_msg) = serde_json::to_string(&orca_request) {
             match tx.send(Message::Text(orca_msg)) {
                 Ok(_) => info!("📡 Sent Orca Whirlpool program subscription request"),
                 Err(e) => error!("❌ Failed to send Orca program subscription: {}", e),
@@ -435,13 +522,15 @@ impl SolanaWebSocketClient {
             method: "programSubscribe".to_string(),
             params: serde_json::json!([
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
-                {"commitment": "confirmed", "encoding": "jsonParsed", "filters": [
+                {"commitment": commitment, "encoding": "jsonParsed", "filters": [
                     {"dataSize": 82} // Filter for mint accounts only
                 ]}
             ]),
         };
         
-        if let Ok(spl_msg) = serde_json::to_string(&spl_request) {
+        if !endpoint.subscribes(AutoSubscription::SplToken) {
+            debug!("Skipping SPL Token program subscription on {}", redacted_url);
+        } else if let Ok(spl_msg) = serde_json::to_string(&spl_request) {
             match tx.send(Message::Text(spl_msg)) {
                 Ok(_) => info!("📡 Sent SPL Token program subscription request"),
                 Err(e) => error!("❌ Failed to send SPL Token program subscription: {}", e),
@@ -457,11 +546,13 @@ impl SolanaWebSocketClient {
             method: "programSubscribe".to_string(),
             params: serde_json::json!([
                 "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P",
-                {"commitment": "confirmed", "encoding": "jsonParsed", "filters": []}
+                {"commitment": commitment, "encoding": "jsonParsed", "filters": []}
             ]),
         };
         
-        if let Ok(pump_msg) = serde_json::to_string(&pump_request) {
+        if !endpoint.subscribes(AutoSubscription::PumpFun) {
+            debug!("Skipping Pump.fun program subscription on {}", redacted_url);
+        } else if let Ok(pump_msg) = serde_json::to_string(&pump_request) {
             match tx.send(Message::Text(pump_msg)) {
                 Ok(_) => info!("📡 Sent Pump.fun program subscription request"),
                 Err(e) => error!("❌ Failed to send Pump.fun program subscription: {}", e),
@@ -470,8 +561,101 @@ impl SolanaWebSocketClient {
             error!("❌ Failed to serialize Pump.fun program subscription request");
         }
         
+        // Subscribe to the other bonding curve launchpads (Bonk.fun / LetsBonk, Moonshot)
+        for (id, launchpad, subscription) in [
+            (992, Launchpad::LaunchLab, AutoSubscription::LaunchLab),
+            (991, Launchpad::Moonshot, AutoSubscription::Moonshot),
+        ] {
+            if !endpoint.subscribes(subscription) {
+                debug!("Skipping {} program subscription on {}", launchpad.name(), redacted_url);
+                continue;
+            }
+            let launchpad_request = JsonRpcRequest {
+                jsonrpc: "2.0".to_string(),
+                id,
+                method: "programSubscribe".to_string(),
+                params: serde_json::json!([
+                    launchpad.program_id(),
+                    {"commitment": commitment, "encoding": "jsonParsed", "filters": []}
+                ]),
+            };
+            
+            if let Ok(launchpad_msg) = serde_json::to_string(&launchpad_request) {
+                match tx.send(Message::Text(launchpad_msg)) {
+                    Ok(_) => info!("📡 Sent {} program subscription request", launchpad.name()),
+                    Err(e) => error!("❌ Failed to send {} program subscription: {}", launchpad.name(), e),
+                }
+            } else {
+                error!("❌ Failed to serialize {} program subscription request", launchpad.name());
+            }
+        }
+        
+        // Transaction logs of the DEX programs; consumers fetch each signature for swap detail
+        if self.config.logs_subscribe {
+            for (id, name, program_id, subscription) in [
+                (990, "Raydium", RAYDIUM_AMM_PROGRAM, AutoSubscription::Raydium),
+                (989, "Jupiter V6", JUPITER_V6_PROGRAM, AutoSubscription::Jupiter),
+                (988, "Orca Whirlpool", ORCA_WHIRLPOOL_PROGRAM, AutoSubscription::Orca),
+                (987, "Pump.fun", PUMP_FUN_PROGRAM, AutoSubscription::PumpFun),
+                (986, Launchpad::LaunchLab.name(), LAUNCHLAB_PROGRAM, AutoSubscription::LaunchLab),
+                (985, Launchpad::Moonshot.name(), MOONSHOT_PROGRAM, AutoSubscription::Moonshot),
+            ] {
+                if !endpoint.subscribes(subscription) {
+                    debug!("Skipping {} logs subscription on {}", name, redacted_url);
+                    continue;
+                }
+                let logs_request = JsonRpcRequest {
+                    jsonrpc: "2.0".to_string(),
+                    id,
+                    method: "logsSubscribe".to_string(),
+                    params: serde_json::json!([
+                        {"mentions": [program_id]},
+                        {"commitment": commitment}
+                    ]),
+                };
+                
+                if let Ok(logs_msg) = serde_json::to_string(&logs_request) {
+                    match tx.send(Message::Text(logs_msg)) {
+                        Ok(_) => info!("📡 Sent {} logs subscription request", name),
+                        Err(e) => error!("❌ Failed to send {} logs subscription: {}", name, e),
+                    }
+                } else {
+                    error!("❌ Failed to serialize {} logs subscription request", name);
+                }
+            }
+        }
+        
+        // Every confirmed block in full; the node must run with block subscriptions enabled
+        if self.config.block_subscribe {
+            let block_commitment = if commitment == "processed" { "confirmed" } else { commitment };
+            let block_request = JsonRpcRequest {
+                jsonrpc: "2.0".to_string(),
+                id: 984,
+                method: "blockSubscribe".to_string(),
+                params: serde_json::json!([
+                    "all",
+                    {
+                        "commitment": block_commitment,
+                        "encoding": "base64",
+                        "transactionDetails": "full",
+                        "showRewards": false,
+                        "maxSupportedTransactionVersion": 0
+                    }
+                ]),
+            };
+            
+            if let Ok(block_msg) = serde_json::to_string(&block_request) {
+                match tx.send(Message::Text(block_msg)) {
+                    Ok(_) => info!("📡 Sent block subscription request ({})", block_commitment),
+                    Err(e) => error!("❌ Failed to send block subscription: {}", e),
+                }
+            } else {
+                error!("❌ Failed to serialize block subscription request");
+            }
+        }
+        
         // Spawn task to handle outgoing messages
-        let tx_task = {
+        let mut tx_task = {
             let event_sender = self.event_sender.clone();
             let stats = self.stats.clone();
             let mut ws_sender = ws_sender;
@@ -496,12 +680,13 @@ impl SolanaWebSocketClient {
         };
         
         // Spawn task to handle incoming messages
-        let rx_task = {
+        let mut rx_task = {
             let event_sender = self.event_sender.clone();
             let stats = self.stats.clone();
             let active_subscriptions = self.active_subscriptions.clone();
             
             tokio::spawn(async move {
+                let scanner = MessageScanner::new();
                 while let Some(msg) = ws_receiver.next().await {
                     match msg {
                         Ok(Message::Text(text)) => {
@@ -512,6 +697,15 @@ impl SolanaWebSocketClient {
                                 stats.last_message_time = Some(Instant::now());
                             }
                             
+                            // Injected faults (chaos builds only)
+                            if chaos::drop_ws_message() {
+                                continue;
+                            }
+                            if chaos::reconnect_due() {
+                                warn!("Chaos: forcing WebSocket reconnect");
+                                break;
+                            }
+                            
                            // debug!("Received WebSocket message: {}", text);
                             
                             // Print all non-ping/pong messages to see what's actually coming through
@@ -520,7 +714,7 @@ impl SolanaWebSocketClient {
                             }
                             
                             // Parse and handle JSON-RPC message
-                            if let Err(e) = Self::handle_message(&text, &event_sender, &active_subscriptions).await {
+                            if let Err(e) = Self::handle_message(&text, &scanner, &event_sender, &active_subscriptions).await {
                                 warn!(error = %e, message = %text, "Failed to handle WebSocket message");
                                 println!("❌ FAILED TO PARSE MESSAGE: {} - {}", e, text);
                             }
@@ -557,7 +751,7 @@ impl SolanaWebSocketClient {
         };
         
         // Spawn heartbeat task to keep connection alive
-        let heartbeat_task = {
+        let mut heartbeat_task = {
             let tx = tx.clone();
             let heartbeat_interval = Duration::from_millis(self.config.heartbeat_interval_ms);
             
@@ -576,10 +770,20 @@ impl SolanaWebSocketClient {
         };
         
         // Wait for tasks to complete (indicates connection closed)
-        tokio::select! {
-            _ = tx_task => debug!("WebSocket sender task completed"),
-            _ = rx_task => debug!("WebSocket receiver task completed"),
-            _ = heartbeat_task => debug!("Heartbeat task completed"),
+        let failover = self.failover.clone();
+        let failed_over = tokio::select! {
+            _ = &mut tx_task => { debug!("WebSocket sender task completed"); false }
+            _ = &mut rx_task => { debug!("WebSocket receiver task completed"); false }
+            _ = &mut heartbeat_task => { debug!("Heartbeat task completed"); false }
+            _ = failover.notified() => true,
+        };
+        
+        if failed_over {
+            // Drop this connection; returning an error makes the run loop move to the next URL
+            tx_task.abort();
+            rx_task.abort();
+            heartbeat_task.abort();
+            bail!("Failover requested away from {}", redacted_url);
         }
         
         Ok(())
@@ -589,15 +793,42 @@ impl SolanaWebSocketClient {
     /// 
     /// # Arguments
     /// * `message` - Raw JSON message text
+    /// * `scanner` - Connection's method scanner
     /// * `event_sender` - Channel to send events to consumers
     /// * `active_subscriptions` - Map of active subscriptions
-    #[instrument(skip(event_sender, active_subscriptions))]
+    #[instrument(skip(scanner, event_sender, active_subscriptions))]
     async fn handle_message(
         message: &str,
+        scanner: &MessageScanner,
         event_sender: &mpsc::UnboundedSender<WebSocketEvent>,
         active_subscriptions: &Arc<tokio::sync::RwLock<HashMap<u64, u64>>>,
     ) -> Result<()> {
-        // Try to parse as JSON-RPC response first
+        // Notifications are the bulk of the traffic; the method decides how to parse
+        match scanner.kind(message) {
+            MessageKind::Notification(_) => {
+                let Some((method, subscription_id, data)) = scanner.decode_notification(message) else {
+                    warn!("Failed to parse WebSocket notification");
+                    return Ok(());
+                };
+                let event = match method {
+                    NotificationMethod::Account => WebSocketEvent::AccountUpdate { subscription_id, data },
+                    NotificationMethod::Signature => WebSocketEvent::TransactionNotification { subscription_id, data },
+                    NotificationMethod::Program => WebSocketEvent::ProgramAccountUpdate { subscription_id, data },
+                    NotificationMethod::Slot => WebSocketEvent::SlotUpdate { subscription_id, data },
+                    NotificationMethod::Block => WebSocketEvent::BlockUpdate { subscription_id, data },
+                    NotificationMethod::Logs => WebSocketEvent::LogsNotification { subscription_id, data },
+                };
+                debug!("Received {} for subscription {}", method.as_str(), subscription_id);
+                let _ = event_sender.send(event);
+                return Ok(());
+            }
+            MessageKind::UnknownNotification(method) => {
+                warn!("Unknown notification method: {}", method);
+                return Ok(());
+            }
+            MessageKind::Response => {}
+        }
+        
         if let Ok(response) = serde_json::from_str::<JsonRpcResponse>(message) {
             if let Some(id) = response.id {
                 if let Some(result) = response.result {
@@ -629,33 +860,16 @@ impl SolanaWebSocketClient {
             return Ok(());
         }
         
-        // Try to parse as WebSocket notification
-        if let Ok(notification) = serde_json::from_str::<WebSocketNotification>(message) {
-            let subscription_id = notification.params.subscription;
-            let data = notification.params.result;
-            
-            // Determine event type based on the method
-            let event = match notification.method.as_str() {
-                "accountNotification" => WebSocketEvent::AccountUpdate { subscription_id, data },
-                "signatureNotification" => WebSocketEvent::TransactionNotification { subscription_id, data },
-                "programNotification" => WebSocketEvent::ProgramAccountUpdate { subscription_id, data },
-                "slotNotification" => WebSocketEvent::SlotUpdate { subscription_id, data },
-                "blockNotification" => WebSocketEvent::BlockUpdate { subscription_id, data },
-                method => {
-                    warn!("Unknown notification method: {}", method);
-                    return Ok(());
-                }
-            };
-            
-            debug!("Received {} for subscription {}", notification.method, subscription_id);
-            let _ = event_sender.send(event);
-            return Ok(());
-        }
-        
-        warn!("Failed to parse WebSocket message as JSON-RPC response or notification");
+        warn!("Failed to parse WebSocket message as JSON-RPC response");
         Ok(())
     }
     
+    /// Drops the current connection and reconnects to the next configured URL
+    pub fn request_failover(&self) {
+        warn!("Failover of WebSocket connection requested");
+        self.failover.notify_one();
+    }
+    
     /// Subscribes to account changes for a specific public key
     /// 
     /// # Arguments
@@ -687,6 +901,124 @@ impl SolanaWebSocketClient {
         Ok(request_id)
     }
     
+    /// Removes an account subscription
+    /// 
+    /// # Arguments
+    /// * `request_id` - Request ID returned by `subscribe_account`
+    /// 
+    /// # Returns
+    /// * `Result<bool>` - True if an active subscription was found and removed
+    #[instrument(skip(self))]
+    pub async fn unsubscribe_account(&self, request_id: u64) -> Result<bool> {
+        let subscription_id = {
+            let mut subs = self.active_subscriptions.write().await;
+            subs.remove(&request_id)
+        };
+        
+        let subscription_id = match subscription_id {
+            Some(id) => id,
+            None => return Ok(false), // Never confirmed or lost on reconnect
+        };
+        
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: self.request_id.fetch_add(1, Ordering::Relaxed),
+            method: "accountUnsubscribe".to_string(),
+            params: serde_json::json!([subscription_id]),
+        };
+        
+        self.send_request(request).await?;
+        debug!("Unsubscribed account subscription {}", subscription_id);
+        
+        Ok(true)
+    }
+    
+    /// Subscribes to the status of one transaction signature
+    ///
+    /// The node sends a single notification once the signature reaches the
+    /// commitment and then drops the subscription; call `forget_subscription`
+    /// when it arrives.
+    ///
+    /// # Arguments
+    /// * `signature` - Base58 transaction signature
+    /// * `commitment` - Commitment level ("finalized", "confirmed", "processed")
+    ///
+    /// # Returns
+    /// * `Result<u64>` - Request ID for tracking the subscription
+    #[instrument(skip(self))]
+    pub async fn subscribe_signature(&self, signature: &str, commitment: &str) -> Result<u64> {
+        let request_id = self.request_id.fetch_add(1, Ordering::Relaxed);
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: request_id,
+            method: "signatureSubscribe".to_string(),
+            params: serde_json::json!([
+                signature,
+                {
+                    "commitment": commitment
+                }
+            ]),
+        };
+
+        self.send_request(request).await?;
+        debug!("Subscribed to signature {} with commitment {}", signature, commitment);
+
+        Ok(request_id)
+    }
+
+    /// Removes a signature subscription that has not notified yet
+    ///
+    /// # Arguments
+    /// * `request_id` - Request ID returned by `subscribe_signature`
+    ///
+    /// # Returns
+    /// * `Result<bool>` - True if an active subscription was found and removed
+    #[instrument(skip(self))]
+    pub async fn unsubscribe_signature(&self, request_id: u64) -> Result<bool> {
+        let Some(subscription_id) = self.forget_subscription(request_id).await else {
+            return Ok(false);
+        };
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: self.request_id.fetch_add(1, Ordering::Relaxed),
+            method: "signatureUnsubscribe".to_string(),
+            params: serde_json::json!([subscription_id]),
+        };
+
+        self.send_request(request).await?;
+        debug!("Unsubscribed signature subscription {}", subscription_id);
+
+        Ok(true)
+    }
+
+    /// Drops the mapping of a subscription the node already ended
+    ///
+    /// # Arguments
+    /// * `request_id` - Request ID returned by the subscribe call
+    ///
+    /// # Returns
+    /// * `Option<u64>` - Subscription ID that was mapped, if it was confirmed
+    pub async fn forget_subscription(&self, request_id: u64) -> Option<u64> {
+        let mut subs = self.active_subscriptions.write().await;
+        subs.remove(&request_id)
+    }
+
+    /// Request ID of the confirmed subscription a notification arrived on
+    /// 
+    /// # Arguments
+    /// * `subscription_id` - Subscription ID carried by the notification
+    /// 
+    /// # Returns
+    /// * `Option<u64>` - Request ID returned by the subscribe call, if still active
+    pub async fn request_id_for_subscription(&self, subscription_id: u64) -> Option<u64> {
+        let subs = self.active_subscriptions.read().await;
+        subs.iter()
+            .find(|(_, &sub_id)| sub_id == subscription_id)
+            .map(|(&request_id, _)| request_id)
+    }
+    
     /// Subscribes to program account changes for a specific program ID
     /// 
     /// # Arguments
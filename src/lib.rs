@@ -7,6 +7,9 @@ pub mod ingest;
 // Transport and communication modules
 pub mod transport;
 
+// Trade execution modules
+pub mod strike;
+
 // Database and persistence modules (Phase 3)
 pub mod database;
 
@@ -6,20 +6,78 @@ use tracing::{info, warn, error, debug};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 use std::time::Duration;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use badger::ingest::websocket::{SolanaWebSocketClient, WebSocketConfig, WebSocketEvent};
-use badger::ingest::DexEventParser;
-use badger::core::{MarketEvent, TradingSignal, DexType};
+use badger::ingest::{
+    DexEventParser, BondingCurveTracker, SelfTradeGuard, EndpointProber, RpcEndpoint, fetch_holder_distribution,
+    DustConfig, scan_dust, ChainLagMonitor, BalanceCache, WalletActivity, LiquidityData, LiquidityAction,
+    MarketDataPool, MarketDataConfig, PoolReserveCache, PoolReserveConfig, PollScheduler, PollSchedulerConfig, PollPriority, InsiderBackfillConfig, fetch_missed_activity, TokenRegistry,
+    signatures_since, fetch_fee_spend, EventDeduplicator, TokenSafetyCache, TokenSafetyConfig, TokenSafetyReport,
+    SnipingGuard, SnipingGuardConfig, MomentumTracker, MomentumConfig, MomentumObservation, VenueHealthMonitor, VenueHealthConfig, VenueStatus, TokenAgeService,
+    TokenSubscriptionRegistry, TokenSubscriptionConfig, DeadTokenReason, SignatureConfirmations,
+    InsiderFundingMonitor, InsiderFundingConfig, SolTransferData, LogTransactionIngest, LogIngestConfig,
+    BlockTransactionIngest, BlockIngestConfig,
+    MetadataMonitor, MetadataMonitorConfig, MetadataChange, metadata_address_for_mint,
+    CommitmentLevels, CommitmentConsumer, fetch_wallet_trade, EndpointSettings, redact_url,
+};
+use badger::ingest::insider_subscriptions::{InsiderSubscriptionManager, SubscriptionSlotConfig, http_url_from_ws};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use badger::core::{MarketEvent, TradingSignal, DexType, StrategyConfig, UniverseFacts, generate_basic_trading_signal, SOL_MINT};
+use badger::core::{StrategyWarmup, WarmupConfig};
+use badger::core::{chaos, chaos::ChaosConfig, forensics, program_registry};
 use badger::transport::{
     EnhancedTransportBus, ServiceRegistry, ServiceInfo, ServiceType, ServiceCapability, 
-    ServiceStatus, SubscriptionInfo, EventType, WalletEvent, SystemAlert
+    ServiceStatus, SubscriptionInfo, EventType, WalletEvent, SystemAlert, CoordinatedActivityType,
+    IpcEventSender, IpcEventReceiver, DEFAULT_IPC_SOCKET,
+    PositionEvent, PositionWebhookSink, PositionWebhookConfig, CandleEvent,
+    Notification, NotificationDigest, NotificationDigestConfig, NotificationRoute, SignalUrgency,
 };
+#[cfg(feature = "api")]
+use badger::transport::{SignalApiServer, SignalApiConfig, ExternalSignal, ExternalSide, ApiRequest, PositionAlertUpdate, ApiQuery, ApiQueryKind, ApiRole};
+#[cfg(feature = "api")]
+use badger::database::analytics::PositionAlertOverride;
 use badger::database::analytics::{
-    PositionTracker, PnLCalculator, PerformanceTracker, InsiderAnalytics
+    PositionTracker, CloseAmount, PartialClose, VolumeProfiler, scoring_model_from_env, PnLCalculator, PerformanceTracker, InsiderAnalytics, NewInsiderActivity, WalletDiscovery, DiscoveryMethod,
+    ConfigSnapshotStore, HedgeMonitor, HedgingAdapter, WebhookHedgingAdapter,
+    RiskAnalytics, RiskReport, StrategyRegistry, SkipRuleLearner, EntryAttributes, SKIP_RULE_PROPOSED, SkipEvidence,
+    HolderSnapshotStore, FadeTracker, FadeConfig, FADE_ACTION_SELL_FLAG, SummaryViews,
+    OutcomeResolverConfig, LpExitConfig, WalletPortfolio, PortfolioSummary, WALLET_ROLE_TRADING, WALLET_ROLE_COLD, WALLET_ROLE_RESERVE,
+    TreasuryLedger, TreasuryFlow, RiskManager, RiskManagerConfig, WalletIntelligenceCache, WalletCacheConfig, UltraFastWalletDB,
+    ActivityHeatmap, HeatmapConfig, HeatmapCell, HEATMAP_HOUR, HEATMAP_WEEKDAY,
+    PriceCandleStore, PriceCandleConfig, VolatilityTracker, FillQualityMonitor, FillCheckConfig, FillCheck, FILL_BAD, FILL_DATA_ERROR,
+    PositionAlertMonitor, PositionAlertConfig, PositionAlert, ALERT_PNL_MOVE,
+    ExitLevelBook, ExitLevelConfig, EXIT_TAKE_PROFIT, TaxLedger, TaxLedgerConfig, TaxFill,
+};
+use badger::database::{
+    ControlChannel, OperatorCommand, COMMAND_PAUSE, COMMAND_RESUME, COMMAND_CLOSE_POSITION, COMMAND_PROMOTE_STRATEGY,
+    COMMAND_APPROVE_SWEEP, COMMAND_REJECT_SWEEP, COMMAND_ENABLE_SKIP_RULE, COMMAND_DISMISS_SKIP_RULE,
+    COMMAND_CANCEL_ORDER, COMMAND_TRACE_MINT, COMMAND_UNTRACE_MINT, COMMAND_SET_COPY_SETTINGS, COMMAND_DELETE_COPY_SETTINGS,
+    COMMAND_RECORD_TREASURY_FLOW, COMMAND_SET_WALLET_LABEL, COMMAND_DELETE_WALLET_LABEL, COMMAND_HANDOFF,
+    COMMAND_COMPLETE_PROFIT_LOCK, COMMAND_FAIL_PROFIT_LOCK, COMMAND_SET_WATCH_GROUP, COMMAND_DELETE_WATCH_GROUP,
+    COMMAND_SET_PROGRAM_LABEL, COMMAND_DELETE_PROGRAM_LABEL, COMMAND_START_AB_TEST, COMMAND_STOP_AB_TEST,
+    COMMAND_REQUEUE_DEAD_LETTER, COMMAND_DISCARD_DEAD_LETTER, ADMIN_COMMANDS, is_operator_command,
 };
+use badger::database::{FIXTURE_DATABASE_URL, seed_fixtures, ReplayInput, ReplayManifest, check_replay_invariants};
+use badger::database::{SignalOutbox, ColdSweepLedger, SweepConfig, ProfitLockLedger, ProfitLockConfig, ProfitLockFill, WorkingOrderBook, WorkingOrder, NewWorkingOrder, ORDER_KIND_REBALANCE, ORDER_KIND_COPY_DELAY};
+use badger::database::{DeadLetterQueue, DeadLetter, ExecutionContext};
+use badger::database::{FeeBudget, FeeBudgetConfig, NewFeeSpend, InsiderCopySettings, InsiderCopySettingsStore};
+use badger::database::{LossLimits, LossLimitConfig, strategy_name};
+use badger::database::{ExposureLedger, ExposureConfig, ExposureDecision, ExposureReservation};
+use badger::database::{OwnFillLedger, OwnFillConfig};
+use badger::database::{DailyCounters, COUNTER_SNIPER_SPEND};
+use badger::database::{WatchGroup, WatchGroupStore, SquadSignal};
+use badger::database::{ProgramLabel, ProgramRegistryStore};
+use badger::database::{DatabaseMaintenance, MaintenanceConfig, ColdStore, ColdStoreConfig};
+use badger::database::{WalletLabel, WalletLabels, WalletLabelStore};
+use badger::database::{AuditContext, AuditLog};
+use badger::database::{StrategyAbTests, AbTestSpec, RunningAbTest, ab_arm_of_source, ab_signal_source};
+use badger::database::{HandoffCoordinator, HandoffState, ProcessInstance, INSTANCE_ACTIVE, INSTANCE_STANDBY, INSTANCE_RETIRED};
 
-use chrono::Utc;
-use std::collections::HashMap;
+use chrono::{Timelike, Utc};
+use std::collections::{HashMap, HashSet};
 
 /// Parse and display slot update data in a human-readable format
 fn parse_and_display_slot_update(subscription_id: u64, data: &serde_json::Value) {
@@ -82,7 +140,7 @@ fn parse_and_display_program_update(subscription_id: u64, data: &serde_json::Val
                 display_market_event(&event);
                 
                 // Generate trading signals based on events (Phase 1 basic implementation)
-                if let Some(signal) = generate_basic_trading_signal(&event) {
+                if let Some(signal) = generate_basic_trading_signal(&event, &StrategyConfig::default()) {
                     display_trading_signal(&signal);
                 }
             }
@@ -146,68 +204,57 @@ fn display_market_event(event: &MarketEvent) {
                 &transfer.from_wallet[..8], &transfer.to_wallet[..8]);
             println!("   USD Value: ${:.2}", transfer.amount_usd.unwrap_or(0.0));
         }
+        MarketEvent::BondingCurveUpdated { curve, previous_progress } => {
+            println!("📈 BONDING CURVE UPDATE");
+            println!("   Curve: {} | Mint: {}", 
+                &curve.curve_address[..8], curve.token_mint.as_deref().map(|m| &m[..8]).unwrap_or("unknown"));
+            println!("   Progress: {:.1}% (was {}) | Raised: {:.2} SOL | Complete: {}", 
+                curve.progress() * 100.0,
+                previous_progress.map(|p| format!("{:.1}%", p * 100.0)).unwrap_or_else(|| "-".to_string()),
+                curve.sol_raised(), curve.complete);
+        }
         _ => {
             println!("📊 MARKET EVENT: {:?}", event);
         }
     }
 }
 
-/// Generate basic trading signals from market events (Phase 1 implementation)
-fn generate_basic_trading_signal(event: &MarketEvent) -> Option<TradingSignal> {
-    match event {
-        MarketEvent::PoolCreated { pool, initial_liquidity_sol, .. } => {
-            // Basic pool creation signal
-            if *initial_liquidity_sol > 5.0 && pool.dex != DexType::Unknown {
-                Some(TradingSignal::Buy {
-                    token_mint: pool.base_mint.clone(),
-                    confidence: 0.6, // Medium confidence for new pools
-                    max_amount_sol: initial_liquidity_sol * 0.1, // Max 10% of pool liquidity
-                    reason: format!("New pool on {:?} with {:.1} SOL liquidity", pool.dex, initial_liquidity_sol),
-                    source: badger::core::SignalSource::NewPool,
-                })
-            } else {
-                None
-            }
-        }
-        MarketEvent::TokenLaunched { token } => {
-            // Basic new token signal
-            if token.mint_authority.is_none() && token.freeze_authority.is_none() {
-                Some(TradingSignal::Buy {
-                    token_mint: token.mint.clone(),
-                    confidence: 0.8, // High confidence for renounced tokens
-                    max_amount_sol: 1.0, // Conservative 1 SOL max
-                    reason: "New token with renounced mint and freeze authority".to_string(),
-                    source: badger::core::SignalSource::NewPool,
-                })
-            } else {
-                None
-            }
-        }
-        _ => None,
-    }
-}
-
 /// Display trading signals in production format
 fn display_trading_signal(signal: &TradingSignal) {
     match signal {
-        TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source } => {
+        TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source, insider_wallet } => {
             println!("🎯 BUY SIGNAL GENERATED");
             println!("   Token: {} | Confidence: {:.1}%", 
                 &token_mint[..8], confidence * 100.0);
             println!("   Max Amount: {:.3} SOL | Source: {:?}", max_amount_sol, source);
             println!("   Reason: {}", reason);
+            if let Some(insider_wallet) = insider_wallet {
+                println!("   Insider: {}", insider_wallet);
+            }
+            if let Some(urgency) = SignalUrgency::for_signal(signal) {
+                println!("   Urgency: {:?} | Pathway: {:?}", urgency, urgency.pathway());
+            }
         }
         TradingSignal::Sell { token_mint, price_target, stop_loss, reason } => {
             println!("💰 SELL SIGNAL GENERATED");
             println!("   Token: {} | Target: {:.6} | Stop: {:.6}", 
                 &token_mint[..8], price_target, stop_loss);
             println!("   Reason: {}", reason);
+            if let Some(urgency) = SignalUrgency::for_signal(signal) {
+                println!("   Urgency: {:?} | Pathway: {:?}", urgency, urgency.pathway());
+            }
         }
         TradingSignal::SwapActivity { token_mint, volume_increase, whale_activity } => {
             println!("📈 SWAP ACTIVITY DETECTED");
             println!("   Token: {} | Volume +{:.1}% | Whale: {}", 
                 &token_mint[..8], volume_increase * 100.0, whale_activity);
         }
+        TradingSignal::Hedge { instrument, direction, size_sol, exposure_sol, reason } => {
+            println!("🛡️ HEDGE RECOMMENDATION");
+            println!("   {:?} {:.3} SOL {} | Exposure: {:.3} SOL", 
+                direction, size_sol, instrument, exposure_sol);
+            println!("   Reason: {}", reason);
+        }
     }
 }
 
@@ -260,12 +307,153 @@ async fn process_market_event_for_insider_analytics(
     }
 }
 
+/// Replay insider activity missed while badger was down through the insider analysis path
+///
+/// Activities are recorded at their block time, oldest first, so win rates and
+/// outcome resolution see them as if they had been observed live. Historical
+/// liquidity removals are recorded but never trigger exits.
+///
+/// # Returns
+/// * `(usize, usize)` - Insiders backfilled and activities recorded
+async fn backfill_insider_activity(
+    rpc_client: &RpcClient,
+    insider_analytics: &Arc<InsiderAnalytics>,
+    config: &InsiderBackfillConfig,
+) -> (usize, usize) {
+    let insiders = match insider_analytics.last_processed_signatures().await {
+        Ok(insiders) => insiders,
+        Err(e) => {
+            warn!("Failed to load insiders for backfill: {}", e);
+            return (0, 0);
+        }
+    };
+
+    let (mut wallets, mut recorded) = (0, 0);
+    for (wallet, last_signature) in insiders {
+        let activities = match fetch_missed_activity(rpc_client, &wallet, last_signature.as_deref(), config).await {
+            Ok(activities) => activities,
+            Err(e) => {
+                debug!("Failed to backfill insider {}: {}", wallet, e);
+                continue;
+            }
+        };
+        if activities.is_empty() {
+            continue;
+        }
+        wallets += 1;
+
+        for activity in activities {
+            let (token_mint, activity_type, amount, price, signature, slot, block_time) = match &activity {
+                WalletActivity::Trade(trade) => {
+                    let activity_type = match trade.side {
+                        badger::core::SwapType::Buy => "BUY",
+                        badger::core::SwapType::Sell => "SELL",
+                    };
+                    (&trade.token_mint, activity_type, trade.token_amount, Some(trade.price_sol),
+                        &trade.signature, trade.slot, trade.block_time)
+                }
+                WalletActivity::Liquidity(lp) => {
+                    let activity_type = match lp.action {
+                        LiquidityAction::Add => "LP_ADD",
+                        LiquidityAction::Remove => "LP_REMOVE",
+                    };
+                    let price = (lp.token_amount > 0.0).then(|| lp.sol_amount / lp.token_amount);
+                    (&lp.token_mint, activity_type, lp.token_amount, price, &lp.signature, lp.slot, lp.block_time)
+                }
+                // Funding between insiders only matters while it can still precede a push
+                WalletActivity::Transfer(_) => continue,
+            };
+
+            let new_activity = NewInsiderActivity {
+                wallet_address: wallet.clone(),
+                token_mint: token_mint.clone(),
+                activity_type: activity_type.to_string(),
+                amount,
+                price,
+                transaction_hash: Some(signature.clone()),
+                block_slot: Some(slot as i64),
+                timestamp: block_time.unwrap_or_else(|| Utc::now().timestamp()),
+            };
+            match insider_analytics.track_insider_activity_at(&new_activity).await {
+                Ok(()) => recorded += 1,
+                Err(e) => warn!("Failed to record backfilled insider activity: {}", e),
+            }
+        }
+    }
+
+    (wallets, recorded)
+}
+
+/// Record an insider's liquidity change and exit on large removals by a token's top insiders
+async fn track_insider_liquidity(
+    lp: &LiquidityData,
+    insider_analytics: &Arc<InsiderAnalytics>,
+    pipeline: &MarketEventPipeline,
+    lp_exit: &LpExitConfig,
+) {
+    let activity_type = match lp.action {
+        LiquidityAction::Add => "LP_ADD",
+        LiquidityAction::Remove => "LP_REMOVE",
+    };
+    let price = (lp.token_amount > 0.0).then(|| lp.sol_amount / lp.token_amount);
+
+    if let Err(e) = insider_analytics.track_insider_activity(
+        &lp.wallet,
+        &lp.token_mint,
+        activity_type,
+        lp.token_amount,
+        price,
+        Some(&lp.signature),
+        Some(lp.slot as i64),
+    ).await {
+        warn!("Failed to track insider liquidity change: {}", e);
+        return;
+    }
+    debug!("💧 Tracked insider {} {} on {} ({:.3} SOL)", lp.wallet, activity_type, lp.token_mint, lp.sol_amount);
+
+    if lp.action != LiquidityAction::Remove {
+        return;
+    }
+    match insider_analytics.is_lp_exit(&lp.wallet, &lp.token_mint, lp.sol_amount, lp_exit).await {
+        Ok(true) => {
+            let insider = pipeline.wallet_labels.display(&lp.wallet);
+            info!("💧 Top insider {} pulled {:.3} SOL of liquidity from {}", insider, lp.sol_amount, lp.token_mint);
+            if lp_exit.emit_signals {
+                let reason = format!("LP exit: top insider {} removed {:.3} SOL", insider, lp.sol_amount);
+                pipeline.exit_position(&lp.token_mint, reason, "insider-lp").await;
+            }
+        }
+        Ok(false) => {}
+        Err(e) => warn!("Failed to evaluate LP exit: {}", e),
+    }
+}
+
+/// Position change produced by executing a trading signal
+struct TradeExecution {
+    position_id: i64,
+    trade_action: &'static str, // "OPEN" or "CLOSE"
+    /// Executed price (SOL per token)
+    price: f64,
+}
+
+impl TradeExecution {
+    /// Reference stored with the signal outbox acknowledgment
+    fn execution_ref(&self) -> String {
+        format!("{}:{}", self.trade_action.to_lowercase(), self.position_id)
+    }
+}
+
 /// Process trading signal for position tracking and P&L calculation (Phase 3: Task 3.1)
+///
+/// # Returns
+/// * `Result<Option<TradeExecution>>` - The position change when the signal opened or closed
+///   a position; an error when the execution failed and may succeed on a retry
 async fn process_trading_signal_for_analytics(
     signal: &TradingSignal,
+    config: &StrategyConfig,
     position_tracker: &Arc<PositionTracker>,
     pnl_calculator: &Arc<PnLCalculator>,
-) {
+) -> Result<Option<TradeExecution>> {
     match signal {
         TradingSignal::Buy { token_mint, confidence, max_amount_sol, .. } => {
             // For demonstration, we're simulating opening a position
@@ -273,10 +461,15 @@ async fn process_trading_signal_for_analytics(
             
             let entry_price = 0.000001; // Simulated entry price - would come from actual trade
             let quantity = max_amount_sol / entry_price;
-            let fees = max_amount_sol * 0.005; // 0.5% fee simulation
+            let fees = max_amount_sol * config.fee_rate; // Fee simulation
             
             // Check if this might be an insider signal by looking for wallet patterns
-            let insider_wallet = extract_potential_insider_wallet(signal);
+            let insider_wallet = signal.get_insider_wallet();
+            
+            if chaos::fail_rpc_send() {
+                warn!("Chaos: buy transaction send for {} failed", token_mint);
+                anyhow::bail!("Buy transaction send for {} failed", token_mint);
+            }
             
             match position_tracker.open_position(
                 signal,
@@ -290,54 +483,61 @@ async fn process_trading_signal_for_analytics(
                     
                     // Update P&L calculator with current price
                     pnl_calculator.update_price(token_mint, entry_price).await;
+                    Ok(Some(TradeExecution { position_id: position.id, trade_action: "OPEN", price: entry_price }))
                 }
                 Err(e) => {
                     warn!("Failed to open position for analytics: {}", e);
+                    Err(anyhow::anyhow!("Failed to open position: {}", e))
                 }
             }
         }
-        TradingSignal::Sell { token_mint, price_target, .. } => {
+        TradingSignal::Sell { token_mint, price_target, stop_loss, .. } => {
             // Simulate closing a position
             let exit_price = *price_target;
-            let exit_fees = exit_price * 0.005; // 0.5% fee simulation
+
+            if chaos::fail_rpc_send() {
+                warn!("Chaos: sell transaction send for {} failed", token_mint);
+                anyhow::bail!("Sell transaction send for {} failed", token_mint);
+            }
+
+            if *stop_loss > 0.0 && exit_price <= *stop_loss {
+                position_tracker.record_stop_triggered(token_mint, *stop_loss, exit_price).await;
+            }
             
-            match position_tracker.close_position(token_mint, exit_price, exit_fees).await {
+            match position_tracker.close_position(token_mint, exit_price, config.fee_rate).await {
                 Ok(Some(closed_position)) => {
                     info!("📊 Position closed for analytics: #{} P&L: ${:.4}", 
                           closed_position.id, closed_position.pnl.unwrap_or(0.0));
+                    Ok(Some(TradeExecution { position_id: closed_position.id, trade_action: "CLOSE", price: exit_price }))
                 }
                 Ok(None) => {
                     debug!("No open position found to close for token: {}", token_mint);
+                    Ok(None)
                 }
                 Err(e) => {
                     warn!("Failed to close position for analytics: {}", e);
+                    Err(anyhow::anyhow!("Failed to close position: {}", e))
                 }
             }
         }
         _ => {
             // Other signal types don't directly map to position changes
+            Ok(None)
         }
     }
 }
 
-/// Extract potential insider wallet from trading signal context
-fn extract_potential_insider_wallet(signal: &TradingSignal) -> Option<String> {
-    // This is a placeholder - in a real implementation, you would extract
-    // the wallet address from the signal context or source data
-    match signal.get_source() {
-        badger::core::SignalSource::InsiderWallet => {
-            // Extract wallet from signal metadata or context
-            Some("insider_wallet_placeholder".to_string())
-        }
-        _ => None,
-    }
-}
-
 /// Generate real-time trading report (Phase 3: Task 3.1)
 async fn generate_real_time_report(
     position_tracker: &Arc<PositionTracker>,
     pnl_calculator: &Arc<PnLCalculator>,
     insider_analytics: &Arc<InsiderAnalytics>,
+    risk_analytics: &Arc<RiskAnalytics>,
+    wallet_portfolio: &Arc<WalletPortfolio>,
+    token_registry: &TokenRegistry,
+    wallet_labels: &WalletLabels,
+    fill_checks: &Arc<FillQualityMonitor>,
+    warmup: &StrategyWarmup,
 ) -> Result<()> {
     println!("\n═══════════════════════════════════════════════════════");
     println!("📊 BADGER BOT REAL-TIME ANALYTICS REPORT");
@@ -359,6 +559,20 @@ async fn generate_real_time_report(
         }
         Err(e) => warn!("Failed to get position summary: {}", e),
     }
+
+    // List open positions by symbol
+    match position_tracker.get_open_positions().await {
+        Ok(positions) if !positions.is_empty() => {
+            println!("📂 OPEN POSITIONS:");
+            for position in positions.iter().take(10) {
+                println!("   #{} {} | Qty: {:.2} @ {:.8} | P&L: ${:.4}",
+                    position.id, token_registry.label(&position.token_mint),
+                    position.quantity, position.entry_price, position.pnl.unwrap_or(0.0));
+            }
+        }
+        Ok(_) => {}
+        Err(e) => warn!("Failed to get open positions: {}", e),
+    }
     
     // Get portfolio P&L
     match pnl_calculator.calculate_portfolio_pnl().await {
@@ -376,7 +590,32 @@ async fn generate_real_time_report(
         }
         Err(e) => warn!("Failed to calculate portfolio P&L: {}", e),
     }
+
+    // Get balances across all our wallets
+    match wallet_portfolio.summary().await {
+        Ok(summary) if !summary.wallets.is_empty() => print_wallet_summary(&summary, wallet_labels),
+        Ok(_) => {}
+        Err(e) => warn!("Failed to get wallet balances: {}", e),
+    }
+
+    // Get portfolio risk
+    match risk_analytics.calculate_risk().await {
+        Ok(risk) if risk.open_positions > 0 => print_risk_summary(&risk),
+        Ok(_) => {}
+        Err(e) => warn!("Failed to calculate portfolio risk: {}", e),
+    }
     
+    // Get fill quality against independent prices over the last day
+    match fill_checks.summary(Utc::now().timestamp() - 86400).await {
+        Ok(fills) if fills.checked + fills.unverified > 0 => {
+            println!("🔍 FILL QUALITY (24h):");
+            println!("   Checked: {} | Bad fills: {} | Data errors: {} | Unverified: {} | Avg adverse: {:+.0} bps",
+                fills.checked, fills.bad_fills, fills.data_errors, fills.unverified, fills.avg_adverse_bps.unwrap_or_default());
+        }
+        Ok(_) => {}
+        Err(e) => warn!("Failed to summarize fill quality: {}", e),
+    }
+
     // Get top insiders
     match insider_analytics.get_top_insiders(5).await {
         Ok(top_insiders) => {
@@ -385,7 +624,7 @@ async fn generate_real_time_report(
                 for (i, insider) in top_insiders.iter().take(3).enumerate() {
                     println!("   {}. {} | Score: {:.1} | Success: {:.1}% | ROI: {:.1}%",
                         i + 1,
-                        &insider.wallet_address[..8],
+                        wallet_labels.label(&insider.wallet_address),
                         insider.copy_worthiness,
                         insider.success_rate * 100.0,
                         insider.roi_percentage
@@ -398,7 +637,204 @@ async fn generate_real_time_report(
         Err(e) => warn!("Failed to get top insiders: {}", e),
     }
 
+    // Strategies whose entries are still gated on data warm-up
+    println!("🌡️ STRATEGY READINESS:");
+    for readiness in warmup.all() {
+        if readiness.ready {
+            println!("   {} | ready", readiness.strategy);
+        } else {
+            println!("   {} | warming up: {}", readiness.strategy, readiness.waiting_on.join(", "));
+        }
+    }
+
+    println!("═══════════════════════════════════════════════════════\n");
+    Ok(())
+}
+
+/// Alert operators to a held token's metadata changing
+async fn report_metadata_change(change: &MetadataChange, control_channel: Option<&ControlChannel>) {
+    let message = format!("Metadata of {} changed: {:?} {:?} -> {:?}",
+        change.token_mint, change.field, change.old_value, change.new_value);
+    warn!("🪪 {}", message);
+    forensics::trace(&change.token_mint, "metadata_change", serde_json::json!({ "change": change }));
+    if let Some(control_channel) = control_channel {
+        if let Err(e) = control_channel.record_alert("METADATA_CHANGE", "metadata-monitor-001", &message).await {
+            warn!("Failed to record metadata change alert: {}", e);
+        }
+    }
+}
+
+/// Log a fired position alert and send it to the notification channel
+async fn report_position_alert(alert: &PositionAlert, label: &str, control_channel: Option<&ControlChannel>) {
+    let message = if alert.kind == ALERT_PNL_MOVE {
+        format!("Position #{} {} unrealized P&L moved {:+.4} SOL to {:+.4} SOL ({:+.1}%)",
+            alert.position_id, label, alert.pnl_change.unwrap_or_default(), alert.unrealized_pnl, alert.return_pct * 100.0)
+    } else {
+        format!("Position #{} {} is {:+.1}% ({:+.4} SOL unrealized) at {:.10}",
+            alert.position_id, label, alert.return_pct * 100.0, alert.unrealized_pnl, alert.price)
+    };
+    info!("🔔 {}", message);
+    forensics::trace(&alert.token_mint, "position_alert", serde_json::json!({ "alert": alert }));
+    if let Some(control_channel) = control_channel {
+        if let Err(e) = control_channel.record_alert("POSITION_ALERT", "position-monitor-001", &message).await {
+            warn!("Failed to record position alert: {}", e);
+        }
+    }
+}
+
+/// Log a checked fill, and trace it when it was flagged
+fn report_fill_check(fill: &FillCheck) {
+    if fill.verdict != FILL_BAD && fill.verdict != FILL_DATA_ERROR {
+        debug!("🔍 Fill #{} of {} checked: {} ({:+.0} bps)",
+            fill.id, fill.token_mint, fill.verdict, fill.adverse_bps.unwrap_or_default());
+        return;
+    }
+    warn!("🔍 Suspected {} on {} of {}: executed at {:.10}, {} had {:.10} ({:+.0} bps adverse)",
+        if fill.verdict == FILL_BAD { "bad fill" } else { "price data error" },
+        fill.side, fill.token_mint, fill.executed_price,
+        fill.oracle_source.as_deref().unwrap_or("oracle"), fill.oracle_price.unwrap_or_default(),
+        fill.adverse_bps.unwrap_or_default());
+    forensics::trace(&fill.token_mint, "fill_check", serde_json::json!({ "fill": fill }));
+}
+
+/// Log a tax ledger fill once its fiat value is known or given up on
+fn report_tax_fill(fill: &TaxFill) {
+    match (fill.fiat_value, fill.sol_price) {
+        (Some(value), Some(sol_price)) => debug!("🧮 Tax fill #{} ({} {} of position #{}) valued at {:.2} {} (SOL at {:.2} from {})",
+            fill.id, fill.side, fill.token_mint, fill.position_id, value, fill.currency, sol_price,
+            fill.oracle_source.as_deref().unwrap_or("oracle")),
+        _ => warn!("🧮 No SOL price near tax fill #{} ({} {} of position #{}); left unpriced",
+            fill.id, fill.side, fill.token_mint, fill.position_id),
+    }
+}
+
+/// Print balances per wallet and aggregated by role
+fn print_wallet_summary(summary: &PortfolioSummary, wallet_labels: &WalletLabels) {
+    println!("👛 WALLETS:");
+    println!("   Total: {:.4} SOL | Trading: {:.4} SOL | Cold: {:.4} SOL | Reserve: {:.4} SOL",
+        summary.total_sol, summary.trading_sol, summary.cold_sol, summary.reserve_sol);
+    for wallet in &summary.wallets {
+        println!("   [{}] {} | {:.4} SOL", wallet.role, wallet_labels.label(&wallet.wallet_address), wallet.sol_balance);
+    }
+}
+
+/// Print VaR, concentration and stress scenario losses
+fn print_risk_summary(risk: &RiskReport) {
+    println!("📉 PORTFOLIO RISK:");
+    println!("   Exposure: {:.4} SOL | Positions: {} | Largest: {:.4} SOL ({:.1}%)",
+        risk.exposure_sol, risk.open_positions, risk.largest_position_sol, risk.concentration * 100.0);
+    println!("   VaR 95%: {:.4} SOL | VaR 99%: {:.4} SOL | Volatility: {:.1}% ({} trades)",
+        risk.parametric_var_95, risk.parametric_var_99, risk.meme_volatility * 100.0, risk.volatility_samples);
+    if let (Some(capital), Some(var_share)) = (risk.capital_sol, risk.var_99_of_capital) {
+        println!("   Capital (all wallets): {:.4} SOL | VaR 99% of capital: {:.1}%", capital, var_share * 100.0);
+    }
+    if let (Some(var), Some(es)) = (risk.historical_var_95, risk.expected_shortfall_95) {
+        println!("   Historical VaR 95%: {:.4} SOL | Expected Shortfall: {:.4} SOL", var, es);
+    }
+    for scenario in &risk.scenarios {
+        println!("   Stress [{}]: -{:.4} SOL ({:.1}% in USD)",
+            scenario.name, scenario.loss_sol, scenario.loss_pct_usd * 100.0);
+    }
+}
+
+/// Generate daily risk report and persist the daily snapshot
+async fn generate_daily_risk_report(risk_analytics: &Arc<RiskAnalytics>) -> Result<()> {
+    let risk = risk_analytics.calculate_risk().await
+        .map_err(|e| anyhow::anyhow!("Failed to calculate portfolio risk: {}", e))?;
+
+    println!("\n═══════════════════════════════════════════════════════");
+    println!("📅 BADGER BOT DAILY RISK REPORT");
+    println!("═══════════════════════════════════════════════════════");
+    print_risk_summary(&risk);
     println!("═══════════════════════════════════════════════════════\n");
+
+    risk_analytics.save_risk_snapshot(&risk, "DAILY").await
+        .map_err(|e| anyhow::anyhow!("Failed to save daily risk snapshot: {}", e))?;
+
+    Ok(())
+}
+
+/// Analyze losing copy trades and list skip rules awaiting operator approval
+async fn generate_skip_rule_report(skip_rules: &Arc<SkipRuleLearner>) -> Result<()> {
+    skip_rules.analyze_losses().await
+        .map_err(|e| anyhow::anyhow!("Failed to analyze copy trade losses: {}", e))?;
+    let proposed = skip_rules.get_rules(Some(SKIP_RULE_PROPOSED)).await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch proposed skip rules: {}", e))?;
+
+    if proposed.is_empty() {
+        return Ok(());
+    }
+
+    println!("\n🚫 PROPOSED SKIP RULES (enable with ENABLE_SKIP_RULE <id>):");
+    for rule in proposed {
+        println!("   #{} {} = {} | Lost {}/{} ({:.0}% vs {:.0}% overall) | P&L: ${:.4}",
+            rule.id, rule.dimension, rule.value, rule.losses, rule.sample_size,
+            rule.loss_rate * 100.0, rule.baseline_loss_rate * 100.0, rule.total_pnl);
+
+        let evidence: Vec<SkipEvidence> = serde_json::from_str(&rule.evidence).unwrap_or_default();
+        for loser in evidence.iter().take(3) {
+            println!("      position #{} {} P&L: ${:.4}", loser.position_id, loser.token_mint, loser.pnl);
+        }
+    }
+
+    Ok(())
+}
+
+/// Print when the insider cohort is most active and most profitable
+async fn generate_activity_heatmap_report(activity_heatmap: &Arc<ActivityHeatmap>) -> Result<()> {
+    let hours = activity_heatmap.heatmap(None, HEATMAP_HOUR).await
+        .map_err(|e| anyhow::anyhow!("Failed to compute hourly insider heatmap: {}", e))?;
+    let weekdays = activity_heatmap.heatmap(None, HEATMAP_WEEKDAY).await
+        .map_err(|e| anyhow::anyhow!("Failed to compute weekday insider heatmap: {}", e))?;
+
+    if hours.is_empty() {
+        return Ok(());
+    }
+
+    const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    let min_trades = activity_heatmap.config().min_resolved_trades;
+    let busiest = |cells: &[HeatmapCell]| cells.iter().max_by_key(|cell| cell.activities).cloned();
+    let most_profitable = |cells: &[HeatmapCell]| cells.iter()
+        .filter(|cell| cell.resolved_trades >= min_trades)
+        .max_by(|a, b| a.avg_profit_pct.total_cmp(&b.avg_profit_pct))
+        .cloned();
+
+    println!("\n🕒 INSIDER ACTIVITY HEATMAP (UTC, last {} days):", activity_heatmap.config().lookback_days);
+    if let Some(cell) = busiest(&hours) {
+        println!("   Busiest hour: {:02}:00 | Activities: {} | Buys: {}", cell.bucket, cell.activities, cell.buys);
+    }
+    if let Some(cell) = most_profitable(&hours) {
+        println!("   Most profitable hour: {:02}:00 | Win rate: {:.1}% of {} | Avg profit: {:.1}%",
+            cell.bucket, cell.win_rate() * 100.0, cell.resolved_trades, cell.avg_profit_pct);
+    }
+    let weekday_name = |bucket: i64| WEEKDAYS.get(bucket as usize).copied().unwrap_or("?");
+    if let Some(cell) = busiest(&weekdays) {
+        println!("   Busiest day: {} | Activities: {} | Buys: {}", weekday_name(cell.bucket), cell.activities, cell.buys);
+    }
+    if let Some(cell) = most_profitable(&weekdays) {
+        println!("   Most profitable day: {} | Win rate: {:.1}% of {} | Avg profit: {:.1}%",
+            weekday_name(cell.bucket), cell.win_rate() * 100.0, cell.resolved_trades, cell.avg_profit_pct);
+    }
+
+    Ok(())
+}
+
+/// Print yesterday's and today's P&L per strategy from the summary tables
+async fn generate_strategy_pnl_report(summary_views: &Arc<SummaryViews>) -> Result<()> {
+    let days = summary_views.get_daily_strategy_pnl(1).await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch daily strategy P&L: {}", e))?;
+
+    if days.is_empty() {
+        return Ok(());
+    }
+
+    println!("\n📚 DAILY STRATEGY P&L:");
+    for day in days {
+        println!("   {} {} ({}) | Trades: {} | Won: {} | P&L: ${:.4} | Fees: ${:.4}",
+            day.day, day.strategy_name, day.mode, day.closed_trades, day.winning_trades,
+            day.realized_pnl, day.fees);
+    }
+
     Ok(())
 }
 
@@ -422,6 +858,10 @@ async fn generate_performance_report(
                 if let Some(sharpe) = metrics.sharpe_ratio {
                     println!("   Sharpe Ratio: {:.2} | Max Drawdown: {:.2}%", sharpe, metrics.max_drawdown);
                 }
+                if let Some(twr) = metrics.time_weighted_return {
+                    println!("   Time-Weighted Return: {:.2}% | Net Capital Flow: {:.4} SOL",
+                        twr * 100.0, metrics.net_capital_flow);
+                }
                 
                 // Save performance snapshot
                 if let Err(e) = performance_tracker.save_performance_snapshot(&metrics, "HOURLY").await {
@@ -445,215 +885,5792 @@ async fn generate_performance_report(
     Ok(())
 }
 
-/// Production-ready Badger trading bot orchestrator
-/// 
-/// This orchestrator manages the core WebSocket ingestion system for real-time
-/// Solana blockchain data processing with the enhanced transport layer for
-/// service communication. It handles graceful startup, shutdown,
-/// and error recovery for all services.
-struct BadgerOrchestrator {
-    shutdown_tx: broadcast::Sender<()>,
-    tasks: Vec<JoinHandle<Result<()>>>,
-    websocket_config: WebSocketConfig,
-    transport_bus: Arc<EnhancedTransportBus>,
-    service_registry: Arc<ServiceRegistry>,
-    database_manager: Option<badger::DatabaseManager>,
-    // Analytics components
-    position_tracker: Option<Arc<PositionTracker>>,
-    pnl_calculator: Option<Arc<PnLCalculator>>,
-    performance_tracker: Option<Arc<PerformanceTracker>>,
-    insider_analytics: Option<Arc<InsiderAnalytics>>,
+/// Summarize a system alert as (alert type, service, message) for the alert log
+fn describe_system_alert(alert: &SystemAlert) -> (&'static str, String, String) {
+    match alert {
+        SystemAlert::ConnectionIssue { service, endpoint, error, retry_count } => (
+            "CONNECTION_ISSUE", service.clone(),
+            format!("{} failed: {} (retry {})", endpoint, error, retry_count),
+        ),
+        SystemAlert::HighTrafficDetected { events_per_minute, threshold, service } => (
+            "HIGH_TRAFFIC", service.clone(),
+            format!("{}/min (threshold: {})", events_per_minute, threshold),
+        ),
+        SystemAlert::ExecutionError { order_id, token_mint, error, amount_sol } => (
+            "EXECUTION_ERROR", "execution".to_string(),
+            format!("Order {} for {} ({:.3} SOL): {}", order_id, token_mint, amount_sol, error),
+        ),
+        SystemAlert::ConfigurationChange { setting, old_value, new_value, service } => (
+            "CONFIGURATION_CHANGE", service.clone(),
+            format!("{}: {} -> {}", setting, old_value, new_value),
+        ),
+        SystemAlert::PerformanceWarning { metric, current_value, threshold, service } => (
+            "PERFORMANCE_WARNING", service.clone(),
+            format!("{} = {:.2} (threshold: {:.2})", metric, current_value, threshold),
+        ),
+        SystemAlert::ServiceStartup { service, version } => (
+            "SERVICE_STARTUP", service.clone(),
+            format!("Started v{}", version),
+        ),
+        SystemAlert::ServiceShutdown { service, reason, uptime_seconds } => (
+            "SERVICE_SHUTDOWN", service.clone(),
+            format!("Stopped: {} (uptime {}s)", reason, uptime_seconds),
+        ),
+        SystemAlert::CoordinatedActivity { activity_type, wallets, total_value_sol, evidence } => (
+            "COORDINATED_ACTIVITY", "insider-analytics".to_string(),
+            format!("{:?} between {} ({:.3} SOL): {}", activity_type, wallets.join(", "), total_value_sol, evidence.join(", ")),
+        ),
+    }
 }
 
-impl BadgerOrchestrator {
-    fn new() -> Self {
-        let (shutdown_tx, _) = broadcast::channel(16);
-        
-        let websocket_config = WebSocketConfig {
-            primary_url: "wss://api.mainnet-beta.solana.com".to_string(),
-            backup_urls: vec![
-                "wss://rpc.ankr.com/solana_ws".to_string(),
-                "wss://solana-api.projectserum.com".to_string(),
-            ],
-            connect_timeout_ms: 10000,
-            max_reconnect_attempts: 10,
-            reconnect_delay_ms: 5000,
-            heartbeat_interval_ms: 10000,
-        };
-        
-        // Initialize the enhanced transport bus
-        let transport_bus = Arc::new(EnhancedTransportBus::new());
-        
-        // Initialize the service registry
-        let service_registry = Arc::new(ServiceRegistry::new(transport_bus.clone()));
-        
-        Self {
-            shutdown_tx,
-            tasks: Vec::new(),
-            websocket_config,
-            transport_bus,
-            service_registry,
-            database_manager: None,
-            // Initialize analytics components as None - will be set up later
-            position_tracker: None,
-            pnl_calculator: None,
-            performance_tracker: None,
-            insider_analytics: None,
-        }
+/// Parse a command's JSON target, splitting off the optional `reason` recorded in the audit log
+fn parse_with_reason<T: serde::de::DeserializeOwned>(target: &str) -> serde_json::Result<(T, Option<String>)> {
+    let mut value: serde_json::Value = serde_json::from_str(target)?;
+    let reason = value.as_object_mut()
+        .and_then(|fields| fields.remove("reason"))
+        .and_then(|reason| reason.as_str().map(str::to_string));
+    Ok((serde_json::from_value(value)?, reason))
+}
+
+/// Split a `<wallet>` or `<wallet>:<reason>` command target
+fn split_reason(target: &str) -> (&str, Option<String>) {
+    match target.split_once(':') {
+        Some((wallet, reason)) => (wallet, Some(reason.trim().to_string())),
+        None => (target, None),
     }
+}
 
-    /// Initialize the database services (Phase 3)
-    async fn initialize_database_services(&mut self) -> Result<()> {
-        info!("🗄️ Initializing Phase 3 Database Services");
-        
-        // Initialize database manager (directory creation handled in database layer)
-        let mut database_manager = badger::DatabaseManager::new();
-        
-        // Initialize with transport bus and service registry
-        if let Err(e) = database_manager.initialize(
-            self.transport_bus.clone(),
-            self.service_registry.clone(),
-        ).await {
-            error!("Failed to initialize database manager: {}", e);
-            return Err(anyhow::anyhow!("Database initialization failed: {}", e));
+/// Flags and stores operator commands act on
+struct OperatorServices {
+    /// Manual closes sell through the same path as auto-exits
+    pipeline: MarketEventPipeline,
+    trading_paused: Arc<AtomicBool>,
+    owns_trading: Arc<AtomicBool>,
+    handoff: Arc<HandoffCoordinator>,
+    position_tracker: Arc<PositionTracker>,
+    strategy_registry: Arc<StrategyRegistry>,
+    config_snapshots: Arc<ConfigSnapshotStore>,
+    cold_sweeps: Arc<ColdSweepLedger>,
+    profit_locks: Arc<ProfitLockLedger>,
+    skip_rules: Arc<SkipRuleLearner>,
+    working_orders: Arc<WorkingOrderBook>,
+    copy_settings: Arc<InsiderCopySettingsStore>,
+    watch_groups: Arc<WatchGroupStore>,
+    program_registry: Arc<ProgramRegistryStore>,
+    treasury: Arc<TreasuryLedger>,
+    wallet_labels: Arc<WalletLabelStore>,
+    ab_tests: Arc<StrategyAbTests>,
+    dead_letters: Arc<DeadLetterQueue>,
+}
+
+/// Apply a single operator command queued through the control channel
+async fn apply_operator_command(command: &OperatorCommand, services: &OperatorServices) -> Result<String> {
+    let OperatorServices {
+        pipeline,
+        trading_paused,
+        owns_trading,
+        handoff,
+        position_tracker,
+        strategy_registry,
+        config_snapshots,
+        cold_sweeps,
+        profit_locks,
+        skip_rules,
+        working_orders,
+        copy_settings,
+        watch_groups,
+        program_registry,
+        treasury,
+        wallet_labels,
+        ab_tests,
+        dead_letters,
+    } = services;
+    match command.command.as_str() {
+        COMMAND_PAUSE => {
+            trading_paused.store(true, Ordering::SeqCst);
+            Ok("Trading paused; exits still run".to_string())
         }
-        
-        // Start all database services
-        let db_handles = database_manager.start_all_services().await
-            .map_err(|e| anyhow::anyhow!("Failed to start database services: {}", e))?;
-        
-        // Convert database service handles to our handle type
-        for handle in db_handles {
-            let converted_handle = tokio::spawn(async move {
-                match handle.await {
-                    Ok(Ok(())) => Ok(()),
-                    Ok(Err(e)) => Err(anyhow::anyhow!("Database service error: {}", e)),
-                    Err(e) => Err(anyhow::anyhow!("Database service join error: {}", e)),
+        COMMAND_RESUME => {
+            trading_paused.store(false, Ordering::SeqCst);
+            Ok("Trading resumed".to_string())
+        }
+        COMMAND_CLOSE_POSITION => {
+            // Target is `<mint>` or `<mint>:<amount>` with amount `all`, `50%` or `0.5 SOL`
+            let target = command.target.as_deref()
+                .ok_or_else(|| anyhow::anyhow!("CLOSE_POSITION requires a token mint"))?;
+            let (token_mint, amount) = match target.split_once(':') {
+                Some((token_mint, amount)) => (token_mint, amount.parse::<CloseAmount>().map_err(|e| anyhow::anyhow!(e))?),
+                None => (target, CloseAmount::All),
+            };
+            if !owns_trading.load(Ordering::SeqCst) {
+                return Err(anyhow::anyhow!("Another instance is trading; close {} there", token_mint));
+            }
+            if !position_tracker.get_open_positions().await?.iter().any(|p| p.token_mint == token_mint) {
+                return Err(anyhow::anyhow!("No open position for {}", token_mint));
+            }
+
+            // Routed as a sell at the current mark; working orders go with a full close
+            match pipeline.close_position(token_mint, amount, "Operator close".to_string(), "operator-control").await? {
+                Some(closed) if closed.is_full_close() => {
+                    Ok(format!("Closed {} ({:.4} tokens) P&L: ${:.4}", token_mint, closed.closed_quantity, closed.realized_pnl))
                 }
-            });
-            self.tasks.push(converted_handle);
+                Some(closed) => Ok(format!("Closed {:.4} of {} ({:.4} left) P&L: ${:.4}",
+                    closed.closed_quantity, token_mint, closed.remaining_quantity, closed.realized_pnl)),
+                None => Err(anyhow::anyhow!("No current price for {}; close not sent", token_mint)),
+            }
         }
-        
-        self.database_manager = Some(database_manager);
-        
-        info!("✅ Phase 3 Database Services initialized successfully");
-        
-        // Initialize analytics components after database is ready
-        self.initialize_analytics().await?;
-        
+        COMMAND_PROMOTE_STRATEGY => {
+            let name = command.target.as_deref()
+                .ok_or_else(|| anyhow::anyhow!("PROMOTE_STRATEGY requires a strategy name"))?;
+            let strategy = strategy_registry.promote(name).await?;
+
+            // Trades under the promoted config are attributed to its snapshot
+            config_snapshots.record_snapshot(strategy.config.as_ref()).await?;
+            Ok(format!("Strategy {} is live (config {})", strategy.name,
+                &strategy.config_hash[..8.min(strategy.config_hash.len())]))
+        }
+        COMMAND_APPROVE_SWEEP | COMMAND_REJECT_SWEEP => {
+            let id: i64 = command.target.as_deref()
+                .and_then(|target| target.parse().ok())
+                .ok_or_else(|| anyhow::anyhow!("{} requires a sweep id", command.command))?;
+            let approve = command.command == COMMAND_APPROVE_SWEEP;
+            let sweep = cold_sweeps.decide(id, approve).await?;
+
+            if approve {
+                // No signer in this process - the transfer is executed by the treasury operator
+                warn!("🧊 Cold sweep #{} approved: transfer {:.4} SOL to {}",
+                    sweep.id, sweep.amount_sol, sweep.destination.as_deref().unwrap_or("<unset cold wallet>"));
+                Ok(format!("Cold sweep #{} approved ({:.4} SOL)", sweep.id, sweep.amount_sol))
+            } else {
+                Ok(format!("Cold sweep #{} rejected", sweep.id))
+            }
+        }
+        COMMAND_COMPLETE_PROFIT_LOCK => {
+            let target = command.target.as_deref()
+                .ok_or_else(|| anyhow::anyhow!("COMPLETE_PROFIT_LOCK requires fill JSON"))?;
+            let fill: ProfitLockFill = serde_json::from_str(target)
+                .map_err(|e| anyhow::anyhow!("Invalid profit lock fill: {}", e))?;
+            let lock = profit_locks.complete(&fill).await?;
+            Ok(format!("Profit lock #{} booked: {:.4} SOL -> {:.2} USDC in {}",
+                lock.id, lock.amount_sol, lock.usdc_received.unwrap_or_default(), lock.destination))
+        }
+        COMMAND_FAIL_PROFIT_LOCK => {
+            let id: i64 = command.target.as_deref()
+                .and_then(|target| target.parse().ok())
+                .ok_or_else(|| anyhow::anyhow!("FAIL_PROFIT_LOCK requires a profit lock id"))?;
+            let lock = profit_locks.fail(id).await?;
+            Ok(format!("Profit lock #{} failed ({:.4} SOL unlocked)", lock.id, lock.amount_sol))
+        }
+        COMMAND_ENABLE_SKIP_RULE | COMMAND_DISMISS_SKIP_RULE => {
+            let id: i64 = command.target.as_deref()
+                .and_then(|target| target.parse().ok())
+                .ok_or_else(|| anyhow::anyhow!("{} requires a skip rule id", command.command))?;
+            let rule = skip_rules.decide(id, command.command == COMMAND_ENABLE_SKIP_RULE).await?;
+            Ok(format!("Skip rule #{} {}={} {}", rule.id, rule.dimension, rule.value, rule.status.to_lowercase()))
+        }
+        COMMAND_CANCEL_ORDER => {
+            let target = command.target.as_deref()
+                .ok_or_else(|| anyhow::anyhow!("CANCEL_ORDER requires an order id or token mint"))?;
+            match target.parse::<i64>() {
+                Ok(id) => {
+                    let order = working_orders.cancel(id).await?;
+                    Ok(format!("Working order #{} {} {} cancelled", order.id, order.kind, order.token_mint))
+                }
+                Err(_) => {
+                    let cancelled = working_orders.cancel_for_token(target).await?;
+                    Ok(format!("Cancelled {} working orders for {}", cancelled, target))
+                }
+            }
+        }
+        COMMAND_TRACE_MINT => {
+            let token_mint = command.target.as_deref()
+                .ok_or_else(|| anyhow::anyhow!("TRACE_MINT requires a token mint"))?;
+            let path = forensics::enable(token_mint)?;
+            Ok(format!("Tracing {} to {}", token_mint, path.display()))
+        }
+        COMMAND_UNTRACE_MINT => {
+            let token_mint = command.target.as_deref()
+                .ok_or_else(|| anyhow::anyhow!("UNTRACE_MINT requires a token mint"))?;
+            if forensics::disable(token_mint) {
+                Ok(format!("Stopped tracing {}", token_mint))
+            } else {
+                Err(anyhow::anyhow!("{} is not traced", token_mint))
+            }
+        }
+        COMMAND_SET_COPY_SETTINGS => {
+            let target = command.target.as_deref()
+                .ok_or_else(|| anyhow::anyhow!("SET_COPY_SETTINGS requires settings JSON"))?;
+            let (settings, reason): (InsiderCopySettings, _) = parse_with_reason(target)
+                .map_err(|e| anyhow::anyhow!("Invalid copy settings: {}", e))?;
+            let settings = copy_settings.upsert(&settings, &AuditContext::operator(command.id, reason)).await?;
+            Ok(format!("Copy settings for {} saved ({})", settings.wallet_address,
+                if settings.enabled { "enabled" } else { "disabled" }))
+        }
+        COMMAND_DELETE_COPY_SETTINGS => {
+            let (wallet, reason) = command.target.as_deref().map(split_reason)
+                .ok_or_else(|| anyhow::anyhow!("DELETE_COPY_SETTINGS requires an insider wallet"))?;
+            if copy_settings.delete(wallet, &AuditContext::operator(command.id, reason)).await? {
+                Ok(format!("Copy settings for {} removed", wallet))
+            } else {
+                Err(anyhow::anyhow!("No copy settings for {}", wallet))
+            }
+        }
+        COMMAND_SET_WATCH_GROUP => {
+            let target = command.target.as_deref()
+                .ok_or_else(|| anyhow::anyhow!("SET_WATCH_GROUP requires group JSON"))?;
+            let group: WatchGroup = serde_json::from_str(target)
+                .map_err(|e| anyhow::anyhow!("Invalid watch group: {}", e))?;
+            let group = watch_groups.upsert(&group).await?;
+            Ok(format!("Watch group {} saved: {} of {} members within {}s ({})", group.name,
+                group.min_members, group.members.len(), group.window_secs,
+                if group.enabled { "enabled" } else { "disabled" }))
+        }
+        COMMAND_DELETE_WATCH_GROUP => {
+            let name = command.target.as_deref()
+                .ok_or_else(|| anyhow::anyhow!("DELETE_WATCH_GROUP requires a group name"))?;
+            if watch_groups.delete(name).await? {
+                Ok(format!("Watch group {} removed", name))
+            } else {
+                Err(anyhow::anyhow!("No watch group named {}", name))
+            }
+        }
+        COMMAND_SET_PROGRAM_LABEL => {
+            let target = command.target.as_deref()
+                .ok_or_else(|| anyhow::anyhow!("SET_PROGRAM_LABEL requires label JSON"))?;
+            let label: ProgramLabel = serde_json::from_str(target)
+                .map_err(|e| anyhow::anyhow!("Invalid program label: {}", e))?;
+            let label = program_registry.upsert(&label).await?;
+            Ok(format!("Program {} labelled {} ({})", label.program_id, label.venue, label.kind))
+        }
+        COMMAND_DELETE_PROGRAM_LABEL => {
+            let program_id = command.target.as_deref()
+                .ok_or_else(|| anyhow::anyhow!("DELETE_PROGRAM_LABEL requires a program id"))?;
+            if program_registry.delete(program_id).await? {
+                Ok(format!("Program label for {} removed", program_id))
+            } else {
+                Err(anyhow::anyhow!("No program label for {}", program_id))
+            }
+        }
+        COMMAND_SET_WALLET_LABEL => {
+            let target = command.target.as_deref()
+                .ok_or_else(|| anyhow::anyhow!("SET_WALLET_LABEL requires label JSON"))?;
+            let (label, reason): (WalletLabel, _) = parse_with_reason(target)
+                .map_err(|e| anyhow::anyhow!("Invalid wallet label: {}", e))?;
+            let label = wallet_labels.upsert(&label, &AuditContext::operator(command.id, reason)).await?;
+            Ok(format!("{} labelled {:?}", label.wallet_address, label.label))
+        }
+        COMMAND_DELETE_WALLET_LABEL => {
+            let (wallet, reason) = command.target.as_deref().map(split_reason)
+                .ok_or_else(|| anyhow::anyhow!("DELETE_WALLET_LABEL requires a wallet"))?;
+            if wallet_labels.delete(wallet, &AuditContext::operator(command.id, reason)).await? {
+                Ok(format!("Label of {} removed", wallet))
+            } else {
+                Err(anyhow::anyhow!("{} has no label", wallet))
+            }
+        }
+        COMMAND_RECORD_TREASURY_FLOW => {
+            let target = command.target.as_deref()
+                .ok_or_else(|| anyhow::anyhow!("RECORD_TREASURY_FLOW requires flow JSON"))?;
+            let flow: TreasuryFlow = serde_json::from_str(target)
+                .map_err(|e| anyhow::anyhow!("Invalid treasury flow: {}", e))?;
+            if treasury.record_flow(&flow).await? {
+                Ok(format!("Recorded {} of {:.4} SOL on {}", flow.direction.to_lowercase(), flow.amount_sol, flow.wallet_address))
+            } else {
+                Ok(format!("Treasury flow {} already recorded", flow.tx_signature.as_deref().unwrap_or("")))
+            }
+        }
+        COMMAND_HANDOFF => {
+            let successor = command.target.as_deref()
+                .ok_or_else(|| anyhow::anyhow!("HANDOFF requires the instance id taking over"))?;
+            if successor == handoff.instance_id() {
+                // Queued by this instance while standing by; its predecessor went away without answering
+                return Ok("Handoff target is this instance - nothing to hand over".to_string());
+            }
+            // Stop opening and closing positions before the state is captured
+            owns_trading.store(false, Ordering::SeqCst);
+            let state = HandoffState {
+                trading_paused: trading_paused.load(Ordering::SeqCst),
+                open_positions: position_tracker.get_open_positions().await?.len(),
+                working_orders: working_orders.working_orders().await.len(),
+            };
+            handoff.retire(Some(successor), Some(&state)).await?;
+            Ok(format!("Trading handed to {} ({} open positions, {} working orders)",
+                successor, state.open_positions, state.working_orders))
+        }
+        COMMAND_START_AB_TEST => {
+            let spec: AbTestSpec = serde_json::from_str(command.target.as_deref().unwrap_or(""))
+                .map_err(|e| anyhow::anyhow!("Invalid A/B test: {}", e))?;
+            let test = ab_tests.start(&spec, strategy_registry).await?;
+            Ok(format!("A/B test {} started: {} vs {}, {:.4} SOL per arm, report after {} trades each",
+                test.name(), test.arms[0].strategy.name, test.arms[1].strategy.name,
+                test.experiment.budget_sol, test.experiment.min_trades))
+        }
+        COMMAND_STOP_AB_TEST => {
+            let name = command.target.as_deref()
+                .ok_or_else(|| anyhow::anyhow!("STOP_AB_TEST requires a test name"))?;
+            let report = ab_tests.stop(name).await?;
+            Ok(report.summary())
+        }
+        COMMAND_REQUEUE_DEAD_LETTER | COMMAND_DISCARD_DEAD_LETTER => {
+            let id = match command.target.as_deref() {
+                Some("all") => None,
+                Some(target) => Some(target.parse::<i64>()
+                    .map_err(|_| anyhow::anyhow!("{} requires a dead letter id or `all`", command.command))?),
+                None => anyhow::bail!("{} requires a dead letter id or `all`", command.command),
+            };
+            let target = id.map_or("every dead letter".to_string(), |id| format!("dead letter #{}", id));
+            if command.command == COMMAND_REQUEUE_DEAD_LETTER {
+                let requeued = dead_letters.requeue(id, "operator").await?;
+                Ok(format!("Requeued {} ({} signals) for replay", target, requeued))
+            } else {
+                let discarded = dead_letters.discard(id, "operator").await?;
+                Ok(format!("Discarded {} ({} signals)", target, discarded))
+            }
+        }
+        other => Err(anyhow::anyhow!("Unknown operator command: {}", other)),
+    }
+}
+
+/// Which part of the system this process runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum ProcessRole {
+    /// Ingestion and analysis in one process
+    #[default]
+    Combined,
+    /// WebSocket ingestion only; events are shipped to an analyzer over IPC
+    Ingest,
+    /// Analysis, persistence and execution fed by an ingest process over IPC
+    Analyzer,
+}
+
+/// Command-line launch options
+#[derive(Debug, Clone, Default)]
+struct LaunchOptions {
+    /// Connect to devnet RPC instead of mainnet
+    devnet: bool,
+    /// Seed a dedicated fixtures database and replay fixture market events (implies devnet)
+    fixtures: bool,
+    /// Shut down automatically after this many seconds (for CI runs)
+    run_for: Option<u64>,
+    /// Split-process role (`--role ingest|analyzer`)
+    role: ProcessRole,
+    /// Unix socket between ingest and analyzer processes
+    ipc_socket: Option<String>,
+    /// RNG seed for fixture replays (defaults to 0 in fixtures mode)
+    seed: Option<u64>,
+    /// Market events JSON file replayed instead of the embedded fixtures
+    events: Option<String>,
+    /// Replay manifest whose seed, input and config are reused and whose result is checked
+    reproduce: Option<ReplayManifest>,
+    /// Strategy config frozen in the reproduced manifest
+    frozen_config: Option<StrategyConfig>,
+    /// Fault profile injected into this run (`--chaos`, chaos builds only)
+    chaos: Option<String>,
+    /// Take over trading from the instance already running on this database
+    takeover: bool,
+}
+
+impl LaunchOptions {
+    fn from_args() -> Result<Self> {
+        let mut options = Self::default();
+        let mut args = std::env::args().skip(1);
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--devnet" => options.devnet = true,
+                "--fixtures" => {
+                    options.fixtures = true;
+                    options.devnet = true;
+                }
+                "--run-for" => {
+                    let secs = args.next()
+                        .ok_or_else(|| anyhow::anyhow!("--run-for requires a number of seconds"))?;
+                    options.run_for = Some(secs.parse()
+                        .map_err(|_| anyhow::anyhow!("Invalid --run-for value: {}", secs))?);
+                }
+                "--role" => {
+                    let role = args.next()
+                        .ok_or_else(|| anyhow::anyhow!("--role requires ingest or analyzer"))?;
+                    options.role = match role.as_str() {
+                        "ingest" => ProcessRole::Ingest,
+                        "analyzer" => ProcessRole::Analyzer,
+                        "combined" => ProcessRole::Combined,
+                        other => return Err(anyhow::anyhow!("Invalid --role value: {}", other)),
+                    };
+                }
+                "--ipc-socket" => {
+                    options.ipc_socket = Some(args.next()
+                        .ok_or_else(|| anyhow::anyhow!("--ipc-socket requires a path"))?);
+                }
+                "--seed" => {
+                    let seed = args.next()
+                        .ok_or_else(|| anyhow::anyhow!("--seed requires a number"))?;
+                    options.seed = Some(seed.parse()
+                        .map_err(|_| anyhow::anyhow!("Invalid --seed value: {}", seed))?);
+                }
+                "--events" => {
+                    options.events = Some(args.next()
+                        .ok_or_else(|| anyhow::anyhow!("--events requires a path"))?);
+                    options.fixtures = true;
+                    options.devnet = true;
+                }
+                "--reproduce" => {
+                    let path = args.next()
+                        .ok_or_else(|| anyhow::anyhow!("--reproduce requires a manifest path"))?;
+                    let manifest = ReplayManifest::load(std::path::Path::new(&path))
+                        .map_err(|e| anyhow::anyhow!("Failed to load replay manifest: {}", e))?;
+                    options.frozen_config = Some(serde_json::from_value(manifest.config.clone())
+                        .map_err(|e| anyhow::anyhow!("Invalid strategy config in {}: {}", path, e))?);
+                    options.reproduce = Some(manifest);
+                    options.fixtures = true;
+                    options.devnet = true;
+                }
+                "--chaos" => {
+                    let profile = args.next()
+                        .ok_or_else(|| anyhow::anyhow!("--chaos requires a profile ({})", ChaosConfig::PROFILES.join(", ")))?;
+                    if !chaos::AVAILABLE {
+                        return Err(anyhow::anyhow!("--chaos requires a build with --features chaos"));
+                    }
+                    if ChaosConfig::profile(&profile).is_none() {
+                        return Err(anyhow::anyhow!("Unknown chaos profile {} ({})", profile, ChaosConfig::PROFILES.join(", ")));
+                    }
+                    options.chaos = Some(profile);
+                }
+                "--takeover" => options.takeover = true,
+                other => return Err(anyhow::anyhow!("Unknown argument: {}", other)),
+            }
+        }
+
+        Ok(options)
+    }
+
+    fn is_test_mode(&self) -> bool {
+        self.devnet || self.fixtures
+    }
+
+    /// Seed for fixture replays; a reproduced manifest's seed wins over `--seed`
+    fn replay_seed(&self) -> u64 {
+        self.reproduce.as_ref().map(|manifest| manifest.seed)
+            .or(self.seed)
+            .unwrap_or(0)
+    }
+
+    /// Events file for fixture replays; `None` replays the embedded fixtures
+    fn replay_events(&self) -> Option<&str> {
+        match &self.reproduce {
+            Some(manifest) if manifest.input_source != "embedded" => Some(manifest.input_source.as_str()),
+            Some(_) => None,
+            None => self.events.as_deref(),
+        }
+    }
+
+    fn ipc_socket_path(&self) -> std::path::PathBuf {
+        std::path::PathBuf::from(self.ipc_socket.as_deref().unwrap_or(DEFAULT_IPC_SOCKET))
+    }
+}
+
+/// Where the ingestion loop delivers parsed market events
+#[derive(Clone)]
+enum MarketEventSink {
+    /// Process in this process
+    Pipeline(Box<MarketEventPipeline>),
+    /// Ship to a separate analyzer process (`--role ingest`)
+    Ipc(IpcEventSender),
+}
+
+impl MarketEventSink {
+    async fn deliver(&self, market_event: MarketEvent, source_service: &str) {
+        match self {
+            MarketEventSink::Pipeline(pipeline) => pipeline.process(market_event, source_service).await,
+            MarketEventSink::Ipc(sender) => {
+                if let Err(e) = sender.send(&market_event) {
+                    warn!("Failed to ship market event to analyzer: {}", e);
+                }
+            }
+        }
+    }
+}
+
+/// Market event → trading signal → position pipeline
+///
+/// Shared by live ingestion and fixture replay so both exercise the same path.
+#[derive(Clone)]
+struct MarketEventPipeline {
+    service_registry: Arc<ServiceRegistry>,
+    insider_analytics: Option<Arc<InsiderAnalytics>>,
+    position_tracker: Option<Arc<PositionTracker>>,
+    pnl_calculator: Option<Arc<PnLCalculator>>,
+    signal_outbox: Option<Arc<SignalOutbox>>,
+    config_snapshots: Option<Arc<ConfigSnapshotStore>>,
+    strategy_config: Arc<StrategyConfig>,
+    config_hash: Option<String>,
+    bonding_curves: Arc<BondingCurveTracker>,
+    pool_reserves: Arc<PoolReserveCache>,
+    self_trade_guard: Arc<SelfTradeGuard>,
+    sniping_guard: Arc<SnipingGuard>,
+    insider_funding: Arc<InsiderFundingMonitor>,
+    momentum: Arc<MomentumTracker>,
+    warmup: Arc<StrategyWarmup>,
+    token_ages: Arc<TokenAgeService>,
+    event_dedup: Arc<EventDeduplicator>,
+    strategy_registry: Option<Arc<StrategyRegistry>>,
+    skip_rules: Option<Arc<SkipRuleLearner>>,
+    holder_snapshots: Option<Arc<HolderSnapshotStore>>,
+    fade_tracker: Option<Arc<FadeTracker>>,
+    working_orders: Option<Arc<WorkingOrderBook>>,
+    copy_settings: Option<Arc<InsiderCopySettingsStore>>,
+    watch_groups: Option<Arc<WatchGroupStore>>,
+    activity_heatmap: Option<Arc<ActivityHeatmap>>,
+    volatility: Option<Arc<VolatilityTracker>>,
+    fill_checks: Option<Arc<FillQualityMonitor>>,
+    position_alerts: Option<Arc<PositionAlertMonitor>>,
+    risk_manager: Option<Arc<RiskManager>>,
+    loss_limits: Option<Arc<LossLimits>>,
+    own_fills: Option<Arc<OwnFillLedger>>,
+    daily_counters: Option<Arc<DailyCounters>>,
+    exit_levels: Option<Arc<ExitLevelBook>>,
+    ab_tests: Option<Arc<StrategyAbTests>>,
+    wallet_discovery: Option<Arc<WalletDiscovery>>,
+    dead_letters: Option<Arc<DeadLetterQueue>>,
+    exposure: Option<Arc<ExposureLedger>>,
+    token_safety: Arc<TokenSafetyCache>,
+    endpoint_prober: Arc<EndpointProber>,
+    venue_health: Arc<VenueHealthMonitor>,
+    chain_lag: Arc<ChainLagMonitor>,
+    wallet_labels: Arc<WalletLabels>,
+    trading_paused: Arc<AtomicBool>,
+    /// Cleared while another instance trades on this database (standby or handed off)
+    owns_trading: Arc<AtomicBool>,
+    /// Set once missed insider activity has been backfilled; copy trades wait for it
+    insider_backfill_done: Arc<AtomicBool>,
+}
+
+impl MarketEventPipeline {
+    /// Exit a held position because a toxic wallet just bought the token
+    async fn exit_on_fade(&self, token_mint: &str, toxic_wallet: &str, source_service: &str) {
+        self.exit_position(token_mint, format!("Fade: toxic wallet {} bought", toxic_wallet), source_service).await;
+    }
+
+    /// Route a market sell of a token through transport and position tracking
+    async fn exit_position(&self, token_mint: &str, reason: String, source_service: &str) {
+        self.exit_position_amount(token_mint, CloseAmount::All, reason, source_service).await;
+    }
+
+    /// Route a market sell of all or part of a token's open lots
+    async fn exit_position_amount(&self, token_mint: &str, amount: CloseAmount, reason: String, source_service: &str) {
+        // Pausing stops new entries only; exits keep running
+        if !self.owns_trading.load(Ordering::SeqCst) {
+            return;
+        }
+        match self.close_position(token_mint, amount, reason, source_service).await {
+            Ok(_) => {}
+            Err(e) => warn!("Failed to exit {}: {}", token_mint, e),
+        }
+    }
+
+    /// Sell all or part of a token's open lots at the current price
+    ///
+    /// The execution entry point for manual closes and auto-exit rules; lots
+    /// are closed oldest first (see `PositionTracker::close_position_amount`).
+    ///
+    /// # Arguments
+    /// * `amount` - `CloseAmount::All`, a fraction, or a SOL-denominated amount
+    ///
+    /// # Returns
+    /// * `Result<Option<PartialClose>>` - None when there was no price or nothing open
+    async fn close_position(
+        &self,
+        token_mint: &str,
+        amount: CloseAmount,
+        reason: String,
+        source_service: &str,
+    ) -> Result<Option<PartialClose>> {
+        let (Some(position_tracker), Some(pnl_calc)) = (&self.position_tracker, &self.pnl_calculator) else {
+            return Ok(None);
+        };
+        let Some(price) = pnl_calc.get_current_price(token_mint).await else {
+            debug!("No price for {} - exit skipped ({})", token_mint, reason);
+            return Ok(None);
+        };
+
+        let signal = TradingSignal::Sell {
+            token_mint: token_mint.to_string(),
+            price_target: price,
+            stop_loss: 0.0,
+            reason: match amount {
+                CloseAmount::All => reason,
+                CloseAmount::Fraction(fraction) => format!("{} ({:.0}%)", reason, fraction * 100.0),
+                CloseAmount::Sol(sol) => format!("{} ({:.4} SOL)", reason, sol),
+            },
+        };
+        display_trading_signal(&signal);
+
+        if let Err(e) = self.service_registry.route_trading_signal(signal, Some(source_service)).await {
+            warn!("Failed to route exit signal: {}", e);
+        }
+
+        if chaos::fail_rpc_send() {
+            return Err(anyhow::anyhow!("Chaos: sell transaction send for {} failed", token_mint));
+        }
+
+        let closed = position_tracker.close_position_amount(token_mint, amount, price, self.strategy_config.fee_rate).await
+            .map_err(|e| anyhow::anyhow!("Failed to close {}: {}", token_mint, e))?;
+        if closed.as_ref().is_some_and(|closed| closed.is_full_close()) {
+            self.cancel_working_orders(token_mint).await;
+        }
+        Ok(closed)
+    }
+
+    /// Drop working orders of a token whose position was just closed
+    async fn cancel_working_orders(&self, token_mint: &str) {
+        if let Some(working_orders) = &self.working_orders {
+            match working_orders.cancel_for_token(token_mint).await {
+                Ok(0) => {}
+                Ok(cancelled) => info!("📋 Cancelled {} working orders for closed {}", cancelled, token_mint),
+                Err(e) => warn!("Failed to cancel working orders for {}: {}", token_mint, e),
+            }
+        }
+    }
+
+    /// Feed a released working order into the live path
+    async fn process_working_order(&self, order: WorkingOrder, price: Option<f64>) {
+        let (strategy_config, config_hash) = self.live_strategy().await;
+        let reason = format!("{} #{}: {}", order.kind, order.id, order.reason);
+
+        let signal = match order.side.as_str() {
+            "BUY" => TradingSignal::Buy {
+                token_mint: order.token_mint,
+                confidence: 1.0,
+                max_amount_sol: order.amount_sol.unwrap_or_default(),
+                reason,
+                source: badger::core::SignalSource::WorkingOrder,
+                insider_wallet: None,
+            },
+            _ => TradingSignal::Sell {
+                token_mint: order.token_mint,
+                price_target: price.or(order.trigger_price).unwrap_or_default(),
+                stop_loss: 0.0,
+                reason,
+            },
+        };
+
+        let signal_source = format!("working-order:{}", order.placed_by);
+        self.route_signal(signal, &strategy_config, &config_hash, &signal_source, "working-orders").await;
+    }
+
+    /// Skip rule attributes of a copy-trade buy (None for other signals)
+    async fn entry_attributes(&self, signal: &TradingSignal) -> Option<EntryAttributes> {
+        let skip_rules = self.skip_rules.as_ref()?;
+        let TradingSignal::Buy { token_mint, .. } = signal else {
+            return None;
+        };
+        let insider_wallet = signal.get_insider_wallet()?;
+
+        let deployer = skip_rules.deployer(token_mint);
+        let token_age = self.token_ages.age(token_mint);
+        // Skip rule bands need a real age; a low-confidence bound would misfile the entry
+        let token_age_secs = token_age.as_ref().and_then(|age| age.confident_age_secs());
+        let profile = match &self.insider_analytics {
+            Some(insider_analytics) => insider_analytics.get_insider_profile(&insider_wallet).await
+                .ok()
+                .flatten(),
+            None => None,
+        };
+        let copy_worthiness = profile.as_ref().map(|profile| profile.copy_worthiness);
+        let liquidity_sol = self.bonding_curves.state_for_mint(token_mint)
+            .map(|curve| curve.real_sol_reserves as f64 / 1_000_000_000.0);
+
+        let mut attributes = EntryAttributes::new(token_age_secs, deployer, copy_worthiness, liquidity_sol, Utc::now());
+        if let Some(copy_settings) = &self.copy_settings {
+            if let Ok(Some(InsiderCopySettings { tier_override: Some(tier), .. })) = copy_settings.get(&insider_wallet).await {
+                attributes.insider_tier = Some(tier);
+            }
+        }
+        forensics::trace(token_mint, "insider_score", serde_json::json!({
+            "insider_wallet": insider_wallet,
+            "profile": profile,
+            "token_age": token_age,
+            "liquidity_sol": liquidity_sol,
+            "entry_attributes": attributes,
+        }));
+        Some(attributes)
+    }
+
+    /// Entry checks applied to a strategy's signals before they are routed
+    fn passes_entry_gates(&self, signal: &TradingSignal, config: &StrategyConfig) -> bool {
+        // Strategies stay gated until the data they depend on has warmed up
+        if let TradingSignal::Buy { token_mint, source, .. } = signal {
+            let readiness = self.warmup.readiness(*source);
+            if !readiness.ready {
+                forensics::trace(token_mint, "entry_gate", serde_json::json!({
+                    "gate": "strategy_warmup",
+                    "readiness": readiness,
+                    "passed": false,
+                }));
+                debug!("Skipping buy for {}: {} warming up ({})",
+                    token_mint, readiness.strategy, readiness.waiting_on.join(", "));
+                return false;
+            }
+        }
+        // Gate launchpad entries on bonding curve progress when the curve is known
+        if let TradingSignal::Buy { token_mint, .. } = signal {
+            if let Some(curve) = self.bonding_curves.state_for_mint(token_mint) {
+                let progress = curve.progress();
+                let in_band = config.in_launchpad_band(curve.launchpad, progress);
+                forensics::trace(token_mint, "entry_gate", serde_json::json!({
+                    "gate": "bonding_curve_band",
+                    "progress": progress,
+                    "curve": curve,
+                    "passed": in_band,
+                }));
+                if !in_band {
+                    debug!("Skipping buy for {}: bonding curve {:.1}% outside entry band",
+                        token_mint, progress * 100.0);
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Cap a buy at the strategy's token-age ladder size
+    fn apply_size_ladder(&self, signal: TradingSignal, config: &StrategyConfig) -> TradingSignal {
+        let TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source, insider_wallet } = signal else {
+            return signal;
+        };
+
+        let requested_sol = max_amount_sol;
+        // A low-confidence age is a lower bound, which can only put the token on a smaller rung
+        let token_age = self.token_ages.age(&token_mint);
+        let token_age_secs = token_age.as_ref().map(|age| age.age_secs());
+        let migrated = self.bonding_curves.state_for_mint(&token_mint).is_some_and(|curve| curve.complete);
+        let max_amount_sol = match config.ladder_max_sol(token_age_secs, migrated) {
+            Some(ladder_sol) if ladder_sol < max_amount_sol => {
+                debug!("🪜 Sizing {} at {:.3} SOL (age {:?}s, migrated {})",
+                    token_mint, ladder_sol, token_age_secs, migrated);
+                ladder_sol
+            }
+            _ => max_amount_sol,
+        };
+        forensics::trace(&token_mint, "size_ladder", serde_json::json!({
+            "token_age": token_age,
+            "migrated": migrated,
+            "requested_sol": requested_sol,
+            "sized_sol": max_amount_sol,
+        }));
+
+        TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source, insider_wallet }
+    }
+
+    /// Scale a buy to the de-leveraging tier in force
+    ///
+    /// # Returns
+    /// * `Option<TradingSignal>` - None when the tier's open position cap is reached
+    async fn apply_risk_limits(&self, signal: TradingSignal) -> Option<TradingSignal> {
+        let (Some(risk_manager), Some(position_tracker)) = (&self.risk_manager, &self.position_tracker) else {
+            return Some(signal);
+        };
+        let TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source, insider_wallet } = signal else {
+            return Some(signal);
+        };
+
+        // Lots of one token count as one position
+        let open_positions = match position_tracker.get_open_positions().await {
+            Ok(positions) => positions.iter()
+                .map(|p| p.token_mint.as_str())
+                .collect::<std::collections::HashSet<_>>()
+                .len(),
+            Err(e) => {
+                warn!("Failed to count open positions for risk limits: {}", e);
+                0
+            }
+        };
+        let limits = risk_manager.limits().await;
+        match risk_manager.size_new_order(max_amount_sol, open_positions).await {
+            Ok(sized_sol) => {
+                if limits.tier > 0 {
+                    debug!("📉 Sizing {} at {:.3} SOL under de-leveraging tier {}", token_mint, sized_sol, limits.tier);
+                    forensics::trace(&token_mint, "risk_check", serde_json::json!({
+                        "check": "deleverage",
+                        "passed": true,
+                        "limits": limits,
+                        "requested_sol": max_amount_sol,
+                        "sized_sol": sized_sol,
+                    }));
+                }
+                Some(TradingSignal::Buy { token_mint, confidence, max_amount_sol: sized_sol, reason, source, insider_wallet })
+            }
+            Err(refusal) => {
+                info!("📉 Skipping buy of {}: {} positions open, tier {} allows {}",
+                    token_mint, refusal.open_positions, refusal.tier, refusal.max_open_positions);
+                forensics::trace(&token_mint, "risk_check", serde_json::json!({
+                    "check": "deleverage",
+                    "passed": false,
+                    "limits": limits,
+                    "open_positions": refusal.open_positions,
+                }));
+                None
+            }
+        }
+    }
+
+    /// Cut a buy down to the room left under the combined per-token exposure cap
+    ///
+    /// Every strategy's entries count against the same cap, so whichever signal
+    /// arrives second on a token is reduced or skipped.
+    ///
+    /// # Returns
+    /// * `Option<(TradingSignal, Option<ExposureReservation>)>` - None when too little room is left
+    async fn apply_exposure_cap(&self, signal: TradingSignal) -> Option<(TradingSignal, Option<ExposureReservation>)> {
+        let Some(exposure) = &self.exposure else {
+            return Some((signal, None));
+        };
+        let TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source, insider_wallet } = signal else {
+            return Some((signal, None));
+        };
+
+        let strategy = strategy_name(source);
+        let max_exposure_sol = exposure.config().max_mint_exposure_sol;
+        match exposure.reserve(&token_mint, &strategy, max_amount_sol).await {
+            Ok(ExposureDecision::Allowed(reservation)) => {
+                let sized_sol = reservation.amount_sol;
+                if sized_sol < max_amount_sol {
+                    info!("📐 Cutting {} buy of {} from {:.3} to {:.3} SOL under the {:.2} SOL exposure cap",
+                        strategy, token_mint, max_amount_sol, sized_sol, max_exposure_sol);
+                    forensics::trace(&token_mint, "risk_check", serde_json::json!({
+                        "check": "exposure_cap",
+                        "passed": true,
+                        "strategy": strategy,
+                        "requested_sol": max_amount_sol,
+                        "sized_sol": sized_sol,
+                        "max_exposure_sol": max_exposure_sol,
+                    }));
+                }
+                Some((TradingSignal::Buy { token_mint, confidence, max_amount_sol: sized_sol, reason, source, insider_wallet }, Some(reservation)))
+            }
+            Ok(ExposureDecision::Refused { current_sol, max_exposure_sol }) => {
+                info!("📐 Skipping {} buy of {}: {:.3} SOL already held or pending across strategies, cap {:.2}",
+                    strategy, token_mint, current_sol, max_exposure_sol);
+                forensics::trace(&token_mint, "risk_check", serde_json::json!({
+                    "check": "exposure_cap",
+                    "passed": false,
+                    "strategy": strategy,
+                    "requested_sol": max_amount_sol,
+                    "current_sol": current_sol,
+                    "max_exposure_sol": max_exposure_sol,
+                }));
+                None
+            }
+            Err(e) => {
+                warn!("Failed to check exposure cap: {}", e);
+                Some((TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source, insider_wallet }, None))
+            }
+        }
+    }
+
+    /// Apply the copied insider's settings (enabled, max size, delay) to a copy-trade buy
+    ///
+    /// # Returns
+    /// * `Option<TradingSignal>` - None when the insider is disabled or the entry was deferred
+    async fn apply_copy_settings(&self, signal: TradingSignal) -> Option<TradingSignal> {
+        let (Some(copy_settings), Some(insider_wallet)) = (&self.copy_settings, signal.get_insider_wallet()) else {
+            return Some(signal);
+        };
+        let settings = match copy_settings.get(&insider_wallet).await {
+            Ok(Some(settings)) => settings,
+            Ok(None) => return Some(signal),
+            Err(e) => {
+                warn!("Failed to read copy settings for {}: {}", insider_wallet, e);
+                return Some(signal);
+            }
+        };
+        let TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source, insider_wallet: Some(insider_wallet) } = signal else {
+            return Some(signal);
+        };
+
+        forensics::trace(&token_mint, "copy_settings", serde_json::json!({
+            "insider_wallet": insider_wallet,
+            "settings": settings,
+            "requested_sol": max_amount_sol,
+        }));
+        if !settings.enabled {
+            info!("🔕 Skipping copy trade on {}: insider {} is disabled", token_mint, self.wallet_labels.display(&insider_wallet));
+            return None;
+        }
+        let max_amount_sol = settings.max_size_sol.map_or(max_amount_sol, |max_size| max_amount_sol.min(max_size));
+
+        // Delayed entries wait in the working-order book and come back as working-order buys
+        if let (Some(delay_ms), Some(working_orders)) = (settings.delay_ms.filter(|ms| *ms > 0), &self.working_orders) {
+            let order = NewWorkingOrder {
+                kind: ORDER_KIND_COPY_DELAY.to_string(),
+                token_mint: token_mint.clone(),
+                side: "BUY".to_string(),
+                amount_sol: Some(max_amount_sol),
+                trigger_price: None,
+                due_at: Some(Utc::now().timestamp() + (delay_ms + 999) / 1000),
+                placed_by: "copy-settings".to_string(),
+                reason: format!("Copy of {} delayed {}ms: {}", insider_wallet, delay_ms, reason),
+            };
+            match working_orders.place(order).await {
+                Ok(order) => {
+                    info!("⏳ Copy trade on {} delayed {}ms (working order #{})", token_mint, delay_ms, order.id);
+                    return None;
+                }
+                Err(e) => warn!("Failed to delay copy trade on {}, entering now: {}", token_mint, e),
+            }
+        }
+
+        Some(TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source, insider_wallet: Some(insider_wallet) })
+    }
+
+    /// Scale a copy-trade buy of an insider recently funded by, or funding, another tracked insider
+    fn apply_funding_boost(&self, signal: TradingSignal) -> TradingSignal {
+        let Some(insider_wallet) = signal.get_insider_wallet() else {
+            return signal;
+        };
+        let Some(flag) = self.insider_funding.flag(&insider_wallet, Utc::now().timestamp()) else {
+            return signal;
+        };
+        let TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source, insider_wallet: Some(insider_wallet) } = signal else {
+            return signal;
+        };
+
+        let boost = self.insider_funding.config().copy_boost;
+        let sized_sol = max_amount_sol * boost;
+        forensics::trace(&token_mint, "insider_funding", serde_json::json!({
+            "insider_wallet": insider_wallet,
+            "counterparty": flag.counterparty,
+            "funded": flag.funded,
+            "transfer_sol": flag.sol_amount,
+            "signature": flag.signature,
+            "boost": boost,
+            "requested_sol": max_amount_sol,
+            "sized_sol": sized_sol,
+        }));
+        debug!("🔗 Copy of {} on {} scaled {:.2}x after insider funding", self.wallet_labels.display(&insider_wallet), token_mint, boost);
+        TradingSignal::Buy { token_mint, confidence, max_amount_sol: sized_sol, reason, source, insider_wallet: Some(insider_wallet) }
+    }
+
+    /// Scale a copy-trade buy by the insider's profitability in the current hour
+    ///
+    /// Only when heatmap sizing is enabled and the insider has enough resolved
+    /// trades in this hour bucket to judge.
+    async fn apply_hour_sizing(&self, signal: TradingSignal) -> TradingSignal {
+        let (Some(activity_heatmap), Some(insider_wallet)) = (&self.activity_heatmap, signal.get_insider_wallet()) else {
+            return signal;
+        };
+        let multiplier = match activity_heatmap.copy_size_multiplier(&insider_wallet, Utc::now().timestamp()).await {
+            Ok(Some(multiplier)) => multiplier,
+            Ok(None) => return signal,
+            Err(e) => {
+                warn!("Failed to read activity heatmap for {}: {}", insider_wallet, e);
+                return signal;
+            }
+        };
+        let TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source, insider_wallet: Some(insider_wallet) } = signal else {
+            return signal;
+        };
+
+        let sized_sol = max_amount_sol * multiplier;
+        forensics::trace(&token_mint, "hour_sizing", serde_json::json!({
+            "insider_wallet": insider_wallet,
+            "multiplier": multiplier,
+            "requested_sol": max_amount_sol,
+            "sized_sol": sized_sol,
+        }));
+        debug!("🕒 Copy of {} on {} scaled {:.2}x for the current hour", self.wallet_labels.display(&insider_wallet), token_mint, multiplier);
+        TradingSignal::Buy { token_mint, confidence, max_amount_sol: sized_sol, reason, source, insider_wallet: Some(insider_wallet) }
+    }
+
+    /// Scale a buy down when the token's realized volatility is above target
+    async fn apply_volatility_sizing(&self, signal: TradingSignal) -> TradingSignal {
+        let Some(volatility) = &self.volatility else {
+            return signal;
+        };
+        let TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source, insider_wallet } = signal else {
+            return signal;
+        };
+        let Some(metrics) = volatility.get(&token_mint).await else {
+            return TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source, insider_wallet };
+        };
+
+        let factor = volatility.size_factor(&token_mint).await;
+        let sized_sol = max_amount_sol * factor;
+        forensics::trace(&token_mint, "volatility_sizing", serde_json::json!({
+            "volatility": metrics,
+            "factor": factor,
+            "requested_sol": max_amount_sol,
+            "sized_sol": sized_sol,
+        }));
+        if factor < 1.0 {
+            debug!("📈 Sizing {} at {:.3} SOL for {:.0}% daily volatility",
+                token_mint, sized_sol, metrics.daily_volatility * 100.0);
+        }
+        TradingSignal::Buy { token_mint, confidence, max_amount_sol: sized_sol, reason, source, insider_wallet }
+    }
+
+    /// Check a copy-trade or launch buy against the token's safety report
+    ///
+    /// The report is fetched when none is cached; how strict the check is
+    /// depends on the copied insider's tier. Sniper and new-pool entries are
+    /// only checked when LP minimums are configured.
+    async fn passes_safety_check(&self, signal: &TradingSignal, insider_tier: Option<&str>) -> bool {
+        let TradingSignal::Buy { token_mint, source, .. } = signal else {
+            return true;
+        };
+        let (entry, requirement) = if signal.get_insider_wallet().is_some() {
+            ("copy trade", self.token_safety.requirement_for(insider_tier))
+        } else if matches!(source, badger::core::SignalSource::Sniper | badger::core::SignalSource::NewPool) {
+            // Launch entries are checked for LP burns and locks once minimums are configured
+            match self.token_safety.sniper_requirement() {
+                Some(requirement) => ("launch entry", requirement),
+                None => return true,
+            }
+        } else {
+            return true;
+        };
+
+        let report = self.safety_report(token_mint).await;
+        let (passed, violations) = match &report {
+            Ok(report) => {
+                let violations = report.violations(requirement);
+                (violations.is_empty(), violations)
+            }
+            Err(e) => {
+                warn!("🛡️ Safety check of {} failed: {}", token_mint, e);
+                (!requirement.reject_unchecked, vec![format!("unchecked: {}", e)])
+            }
+        };
+        forensics::trace(token_mint, "risk_check", serde_json::json!({
+            "check": "token_safety",
+            "passed": passed,
+            "entry": entry,
+            "insider_tier": insider_tier,
+            "requirement": requirement,
+            "report": report.as_ref().ok(),
+            "violations": violations,
+        }));
+        if !passed {
+            info!("🛡️ Skipping {} on {} (tier {}): {}",
+                entry, token_mint, insider_tier.unwrap_or("none"), violations.join(", "));
+        }
+        passed
+    }
+
+    /// Safety report of a token, cached or fetched within the check timeout
+    async fn safety_report(&self, token_mint: &str) -> Result<TokenSafetyReport> {
+        let rpc_client = self.endpoint_prober.analytics_client(CommitmentConsumer::Sniper);
+        let pool_accounts: Vec<String> = BondingCurveTracker::curve_address_for_mint(token_mint)
+            .into_iter()
+            .chain(self.token_safety.pool_accounts(token_mint))
+            .collect();
+        let timeout = Duration::from_millis(self.token_safety.config().check_timeout_ms);
+        match tokio::time::timeout(timeout, self.token_safety.report(&rpc_client, token_mint, &pool_accounts)).await {
+            Ok(report) => report,
+            Err(_) => Err(anyhow::anyhow!("no report within {}ms", timeout.as_millis())),
+        }
+    }
+
+    /// Whether a buy's token is in the universe its strategy declared
+    ///
+    /// Checked once for every strategy before sizing, so strategies declare
+    /// launchpads, age, liquidity and safety bounds instead of filtering
+    /// tokens themselves.
+    async fn passes_token_universe(&self, signal: &TradingSignal, config: &StrategyConfig) -> bool {
+        let TradingSignal::Buy { token_mint, source, .. } = signal else {
+            return true;
+        };
+        let strategy = strategy_name(*source);
+        let Some(universe) = config.universes.get(&strategy) else {
+            return true;
+        };
+
+        let token_age = self.token_ages.age(token_mint);
+        // A live curve's real reserves, otherwise the SOL side of the token's cached pool
+        let liquidity_sol = match self.bonding_curves.state_for_mint(token_mint).filter(|curve| !curve.complete) {
+            Some(curve) => Some(curve.real_sol_reserves as f64 / 1_000_000_000.0),
+            None => self.pool_reserves.reserves_for_mint(token_mint)
+                .map(|reserves| reserves.sol_reserves as f64 / 1_000_000_000.0),
+        };
+        let safety_score = if universe.needs_safety_score() {
+            match self.safety_report(token_mint).await {
+                Ok(report) => Some(report.safety_score()),
+                Err(e) => {
+                    debug!("No safety score for {}: {}", token_mint, e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        let facts = UniverseFacts {
+            launchpad: self.bonding_curves.launchpad_for_mint(token_mint),
+            age_secs: token_age.as_ref().map(|age| age.age_secs()),
+            age_confident: token_age.as_ref().is_some_and(|age| age.confident_age_secs().is_some()),
+            liquidity_sol,
+            safety_score,
+        };
+
+        let exclusions = universe.exclusions(token_mint, &facts);
+        forensics::trace(token_mint, "entry_gate", serde_json::json!({
+            "gate": "token_universe",
+            "strategy": strategy,
+            "facts": facts,
+            "exclusions": exclusions,
+            "passed": exclusions.is_empty(),
+        }));
+        if !exclusions.is_empty() {
+            info!("🌐 Skipping {} buy of {}: outside its token universe ({})", strategy, token_mint, exclusions.join(", "));
+            return false;
+        }
+        true
+    }
+
+    /// Generate signals for each shadow strategy and record hypothetical fills at live quotes
+    async fn run_shadow_strategies(&self, market_event: &MarketEvent, registry: &Arc<StrategyRegistry>) {
+        for strategy in registry.shadow_strategies().await {
+            let signal = match generate_basic_trading_signal(market_event, &strategy.config) {
+                Some(signal) if self.passes_entry_gates(&signal, &strategy.config) => signal,
+                _ => continue,
+            };
+            if !self.passes_token_universe(&signal, &strategy.config).await {
+                continue;
+            }
+            let signal = self.apply_size_ladder(signal, &strategy.config);
+
+            let token_mint = match &signal {
+                TradingSignal::Buy { token_mint, .. } | TradingSignal::Sell { token_mint, .. } => token_mint,
+                _ => continue,
+            };
+
+            // Shadow fills need a real quote; unpriced tokens are skipped rather than guessed
+            let price = match &self.pnl_calculator {
+                Some(pnl_calc) => pnl_calc.get_current_price(token_mint).await,
+                None => None,
+            };
+            let Some(price) = price else {
+                debug!("👥 [{}] no live quote for {} - shadow signal not filled", strategy.name, token_mint);
+                continue;
+            };
+
+            forensics::trace(token_mint, "shadow_signal", serde_json::json!({
+                "strategy": strategy.name,
+                "signal": signal,
+                "price": price,
+            }));
+            match registry.record_shadow_fill(&strategy, &signal, price).await {
+                Ok(Some(pnl)) => info!("👥 [{}] shadow close {} P&L: ${:.4}", strategy.name, token_mint, pnl),
+                Ok(None) => {}
+                Err(e) => warn!("Failed to record shadow fill for {}: {}", strategy.name, e),
+            }
+        }
+    }
+
+    /// Generate each A/B test arm's signals and route those on tokens assigned to the arm
+    async fn run_ab_test(&self, market_event: &MarketEvent, test: &RunningAbTest, source_service: &str) {
+        let Some(ab_tests) = &self.ab_tests else {
+            return;
+        };
+        for arm in &test.arms {
+            let Some(signal) = generate_basic_trading_signal(market_event, &arm.strategy.config) else {
+                continue;
+            };
+            let token_mint = signal.get_token_mint();
+            match ab_tests.assign(test.name(), &token_mint).await {
+                Ok(assigned) if assigned == arm.label => {}
+                Ok(_) => continue,
+                Err(e) => {
+                    warn!("Failed to assign {} to an A/B test arm: {}", token_mint, e);
+                    continue;
+                }
+            }
+
+            if let TradingSignal::Buy { max_amount_sol, .. } = &signal {
+                match ab_tests.within_budget(&test.experiment, arm.label, *max_amount_sol).await {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        debug!("🧪 [{}] arm {} budget spent - skipping buy of {}", test.name(), arm.label, token_mint);
+                        forensics::trace(&token_mint, "decision", serde_json::json!({
+                            "outcome": "ab_test_budget_spent",
+                            "experiment": test.name(),
+                            "arm": arm.label,
+                        }));
+                        continue;
+                    }
+                    Err(e) => {
+                        warn!("Failed to check A/B test budget: {}", e);
+                        continue;
+                    }
+                }
+            }
+
+            forensics::trace(&token_mint, "signal_generated", serde_json::json!({
+                "signal": signal,
+                "config_hash": arm.strategy.config_hash,
+                "experiment": test.name(),
+                "arm": arm.label,
+            }));
+            let signal_source = ab_signal_source(test.name(), arm.label);
+            self.route_signal(signal, &arm.strategy.config, &Some(arm.strategy.config_hash.clone()),
+                &signal_source, source_service).await;
+        }
+    }
+
+    /// Route a parsed market event through transport, analytics and signal generation
+    async fn process(&self, mut market_event: MarketEvent, source_service: &str) {
+        // Redelivered events (reconnects, backfill overlap) are only acted on once
+        if !self.event_dedup.first_sighting(&market_event) {
+            return;
+        }
+        
+        // Attach bonding curve context (mint, previous progress) before anything sees the event
+        self.bonding_curves.observe(&mut market_event);
+        self.pool_reserves.observe(&market_event);
+        if forensics::active() {
+            forensics::trace_mentions("market_event", &serde_json::json!({
+                "source_service": source_service,
+                "event": market_event,
+            }));
+        }
+        let own_swap = self.self_trade_guard.observe(&market_event);
+        self.sniping_guard.observe(&market_event);
+        if self.momentum.observe(&market_event) == MomentumObservation::Late {
+            let late_events = self.momentum.late_events();
+            if late_events.is_power_of_two() {
+                warn!("📈 {} swaps arrived more than {}s late and were left out of momentum windows",
+                    late_events, self.momentum.config().allowed_lateness_secs);
+            }
+        }
+        self.warmup.observe(&market_event);
+        self.warmup.record_momentum_windows(self.momentum.covered_windows());
+        self.token_safety.observe(&market_event);
+        self.token_ages.observe(&market_event);
+        if let Some(skip_rules) = &self.skip_rules {
+            skip_rules.observe_launch(&market_event);
+        }
+        
+        // Display the event (for Phase 1 compatibility)
+        display_market_event(&market_event);
+        
+        // Route through transport layer (Phase 2 enhancement)
+        match self.service_registry.route_market_event(
+            market_event.clone(), 
+            Some(source_service)
+        ).await {
+            Ok(_) => println!("   📤 MarketEvent routed to transport bus successfully"),
+            Err(e) => warn!("Failed to route market event: {}", e),
+        }
+        
+        // Our own swaps are parsed once confirmed for exact position accounting
+        if let (Some(own_fills), Some(swap)) = (&self.own_fills, &own_swap) {
+            let side = match swap.swap_type {
+                badger::core::SwapType::Buy => "BUY",
+                badger::core::SwapType::Sell => "SELL",
+            };
+            if let Err(e) = own_fills.enqueue(&swap.signature, &swap.wallet, &swap.token_mint, side).await {
+                warn!("Failed to queue own fill {}: {}", swap.signature, e);
+            }
+        }
+        
+        // Process with insider analytics (Phase 3: Task 3.1)
+        if let Some(insider_analytics) = &self.insider_analytics {
+            match &own_swap {
+                // Our own wallets are recorded separately so they never look like insiders
+                Some(swap) => {
+                    let action = match swap.swap_type {
+                        badger::core::SwapType::Buy => "BUY",
+                        badger::core::SwapType::Sell => "SELL",
+                    };
+                    if let Err(e) = insider_analytics.record_self_trade_event(
+                        "OWN_SWAP", &swap.wallet, &swap.token_mint, action, Some(&swap.signature),
+                    ).await {
+                        warn!("Failed to record own-wallet swap: {}", e);
+                    }
+                }
+                None => process_market_event_for_insider_analytics(&market_event, insider_analytics).await,
+            }
+        }
+        
+        // Buys by watch group members count towards squad signals
+        if let (None, MarketEvent::SwapDetected { swap }) = (&own_swap, &market_event) {
+            if matches!(swap.swap_type, badger::core::SwapType::Buy) {
+                self.observe_insider_buy(&swap.wallet, &swap.token_out, source_service).await;
+            }
+        }
+        
+        // Buys by consistently losing wallets become avoid/exit flags
+        if let (Some(fade_tracker), None) = (&self.fade_tracker, &own_swap) {
+            match fade_tracker.evaluate(&market_event).await {
+                Ok(Some(fade)) if fade.action == FADE_ACTION_SELL_FLAG && fade_tracker.config().emit_signals => {
+                    self.exit_on_fade(&fade.token_mint, &fade.toxic_wallet, source_service).await;
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Failed to evaluate fade signal: {}", e),
+            }
+        }
+        
+        // Shadow strategies see every event but only record hypothetical fills
+        if let Some(registry) = &self.strategy_registry {
+            self.run_shadow_strategies(&market_event, registry).await;
+        }
+        
+        // A running A/B test splits tokens between its arms instead
+        if let Some(test) = match &self.ab_tests {
+            Some(ab_tests) => ab_tests.running().await,
+            None => None,
+        } {
+            self.run_ab_test(&market_event, &test, source_service).await;
+            return;
+        }
+        
+        // A promoted strategy replaces the startup configuration
+        let (strategy_config, config_hash) = self.live_strategy().await;
+        
+        // Generate and route trading signals
+        if let Some(signal) = generate_basic_trading_signal(&market_event, &strategy_config) {
+            forensics::trace(&signal.get_token_mint(), "signal_generated", serde_json::json!({
+                "signal": signal,
+                "config_hash": config_hash,
+            }));
+            self.route_signal(signal, &strategy_config, &config_hash, "internal", source_service).await;
+        }
+    }
+
+    /// Count an insider's buy towards its watch groups and route the squad signals it completes
+    async fn observe_insider_buy(&self, wallet: &str, token_mint: &str, source_service: &str) {
+        let Some(watch_groups) = &self.watch_groups else {
+            return;
+        };
+        let squads = match watch_groups.record_buy(wallet, token_mint, Utc::now().timestamp()).await {
+            Ok(squads) => squads,
+            Err(e) => {
+                warn!("Failed to evaluate watch groups: {}", e);
+                return;
+            }
+        };
+        for squad in squads {
+            self.route_squad_signal(squad, wallet, source_service).await;
+        }
+    }
+
+    /// Flag and alert on SOL moved between two tracked insiders
+    async fn observe_insider_transfer(&self, transfer: &SolTransferData) {
+        let Some(insider_analytics) = &self.insider_analytics else {
+            return;
+        };
+        // The polled wallet is tracked; the transfer only counts if the other side is too
+        let mut tracked = [false; 2];
+        for (side, wallet) in [&transfer.from_wallet, &transfer.to_wallet].into_iter().enumerate() {
+            match insider_analytics.get_insider_profile(wallet).await {
+                Ok(profile) => tracked[side] = profile.is_some(),
+                Err(e) => {
+                    warn!("Failed to look up insider {}: {}", wallet, e);
+                    return;
+                }
+            }
+        }
+        match tracked {
+            [true, true] => {}
+            [true, false] => {
+                self.observe_funded_wallet(transfer).await;
+                return;
+            }
+            _ => return,
+        }
+        if !self.insider_funding.record(transfer, Utc::now().timestamp()) {
+            return;
+        }
+
+        let (from, to) = (self.wallet_labels.display(&transfer.from_wallet), self.wallet_labels.display(&transfer.to_wallet));
+        info!("🔗 Insider {} funded insider {} with {:.3} SOL", from, to, transfer.sol_amount);
+        let alert = SystemAlert::CoordinatedActivity {
+            activity_type: CoordinatedActivityType::InsiderFunding,
+            wallets: vec![transfer.from_wallet.clone(), transfer.to_wallet.clone()],
+            total_value_sol: transfer.sol_amount,
+            evidence: vec![transfer.signature.clone()],
+        };
+        if let Err(e) = self.service_registry.route_system_alert(alert, Some("insider-funding-001")).await {
+            warn!("Failed to route insider funding alert: {}", e);
+        }
+    }
+
+    /// Put a wallet funded by a tracked insider on discovery probation
+    async fn observe_funded_wallet(&self, transfer: &SolTransferData) {
+        let Some(wallet_discovery) = &self.wallet_discovery else {
+            return;
+        };
+        if transfer.sol_amount < self.insider_funding.config().min_transfer_sol {
+            return;
+        }
+        let source = format!("funded_by:{}", transfer.from_wallet);
+        match wallet_discovery.add_candidate(&transfer.to_wallet, DiscoveryMethod::Observed, Some(&source), None, None, None).await {
+            Ok(true) => info!("🔎 Wallet {} funded by insider {} with {:.3} SOL is on probation",
+                transfer.to_wallet, self.wallet_labels.display(&transfer.from_wallet), transfer.sol_amount),
+            Ok(false) => {}
+            Err(e) => warn!("Failed to add wallet candidate {}: {}", transfer.to_wallet, e),
+        }
+    }
+
+    /// Route a watch group's composite buy through the live path
+    ///
+    /// `insider_wallet` is the member whose buy completed the group; per-insider
+    /// copy settings and sizing are looked up by it.
+    async fn route_squad_signal(&self, squad: SquadSignal, insider_wallet: &str, source_service: &str) {
+        let members = squad.members.split(',')
+            .map(|member| self.wallet_labels.display(member))
+            .collect::<Vec<_>>()
+            .join(", ");
+        info!("👥 Watch group {} fired on {}: {}", squad.group_name, squad.token_mint, members);
+        if let Ok(detail) = serde_json::to_value(&squad) {
+            forensics::trace(&squad.token_mint, "watch_group_signal", detail);
+        }
+
+        let (strategy_config, config_hash) = self.live_strategy().await;
+        let signal = TradingSignal::Buy {
+            token_mint: squad.token_mint,
+            confidence: squad.confidence,
+            max_amount_sol: squad.max_amount_sol,
+            reason: format!("Watch group {}: {} bought within {}s", squad.group_name, members,
+                squad.fired_at - squad.first_buy_at),
+            source: badger::core::SignalSource::InsiderWallet,
+            insider_wallet: Some(insider_wallet.to_string()),
+        };
+        let signal_source = format!("watch-group:{}", squad.group_name);
+        self.route_signal(signal, &strategy_config, &config_hash, &signal_source, source_service).await;
+    }
+
+    /// Active strategy configuration and its snapshot hash
+    async fn live_strategy(&self) -> (Arc<StrategyConfig>, Option<String>) {
+        match &self.strategy_registry {
+            Some(registry) => match registry.live_strategy().await {
+                Some(live) => (live.config, Some(live.config_hash)),
+                None => (self.strategy_config.clone(), self.config_hash.clone()),
+            },
+            None => (self.strategy_config.clone(), self.config_hash.clone()),
+        }
+    }
+
+    /// Feed a signal submitted through the external signal API into the live path
+    #[cfg(feature = "api")]
+    async fn process_external(&self, external: ExternalSignal) {
+        let (strategy_config, config_hash) = self.live_strategy().await;
+        let request = external.request;
+        let reason = format!("{}: {}", external.signal_source, request.reason.unwrap_or_default());
+
+        let signal = match request.side {
+            ExternalSide::Buy => TradingSignal::Buy {
+                token_mint: request.token_mint,
+                confidence: request.confidence.unwrap_or(0.5),
+                max_amount_sol: request.amount_sol.unwrap_or_default(),
+                reason,
+                source: badger::core::SignalSource::External,
+                insider_wallet: None,
+            },
+            ExternalSide::Sell => {
+                // Without a target the exit is priced at the current mark, never at zero
+                let mark = match &self.pnl_calculator {
+                    Some(pnl_calculator) => pnl_calculator.cached_prices().await.get(&request.token_mint).copied(),
+                    None => None,
+                };
+                let Some(price_target) = request.price_target.or(mark).filter(|price| *price > 0.0) else {
+                    warn!("📡 Dropping {} sell of {}: no price_target and no current mark",
+                        external.signal_source, request.token_mint);
+                    return;
+                };
+                TradingSignal::Sell {
+                    token_mint: request.token_mint,
+                    price_target,
+                    stop_loss: request.stop_loss.unwrap_or_default(),
+                    reason,
+                }
+            }
+        };
+
+        self.route_signal(signal, &strategy_config, &config_hash, &external.signal_source, "signal-api").await;
+    }
+
+    /// Store alert rules set for a position through the external signal API
+    #[cfg(feature = "api")]
+    async fn set_position_alerts(&self, update: PositionAlertUpdate) {
+        let Some(position_alerts) = &self.position_alerts else {
+            return;
+        };
+        let rules = PositionAlertOverride {
+            gain_pct: update.rules.gain_pct,
+            loss_pct: update.rules.loss_pct,
+            pnl_move_sol: update.rules.pnl_move_sol,
+        };
+        match position_alerts.set_override(update.position_id, &rules, Utc::now().timestamp()).await {
+            Ok(()) => info!("🔔 {} set alert rules of position #{}: {:?}", update.signal_source, update.position_id, rules),
+            Err(e) => warn!("Failed to set alert rules of position #{}: {}", update.position_id, e),
+        }
+    }
+
+    /// Answer a read of exposure or open positions through the external signal API
+    #[cfg(feature = "api")]
+    async fn answer_query(&self, query: ApiQuery) {
+        let answer = match query.kind {
+            ApiQueryKind::Exposure { token_mint } => match (&self.exposure, token_mint) {
+                (None, _) => (503, serde_json::json!({"error": "exposure ledger not initialized"})),
+                (Some(exposure), Some(token_mint)) => match exposure.exposure(&token_mint).await {
+                    Ok(exposure) => (200, serde_json::json!(exposure)),
+                    Err(e) => (500, serde_json::json!({"error": e.to_string()})),
+                },
+                (Some(exposure), None) => match exposure.all_exposure().await {
+                    Ok(exposures) => (200, serde_json::json!({"exposure": exposures})),
+                    Err(e) => (500, serde_json::json!({"error": e.to_string()})),
+                },
+            },
+            ApiQueryKind::OpenPositions => match &self.position_tracker {
+                None => (503, serde_json::json!({"error": "position tracker not initialized"})),
+                Some(position_tracker) => match position_tracker.get_open_positions().await {
+                    Ok(positions) => (200, serde_json::json!({"positions": positions})),
+                    Err(e) => (500, serde_json::json!({"error": e.to_string()})),
+                },
+            },
+            ApiQueryKind::CommandStatus { .. } => (404, serde_json::json!({"error": "not found"})),
+        };
+        let _ = query.reply.send(answer);
+    }
+
+    /// Execute a signal, retrying failures with a doubling delay
+    ///
+    /// # Returns
+    /// * The last error and the number of attempts once every attempt failed
+    async fn execute_with_retries(
+        &self,
+        signal: &TradingSignal,
+        strategy_config: &StrategyConfig,
+        position_tracker: &Arc<PositionTracker>,
+        pnl_calculator: &Arc<PnLCalculator>,
+    ) -> std::result::Result<Option<TradeExecution>, (anyhow::Error, u32)> {
+        let (max_attempts, mut delay) = match &self.dead_letters {
+            Some(dead_letters) => (
+                dead_letters.config().max_attempts.max(1),
+                Duration::from_millis(dead_letters.config().retry_delay_ms),
+            ),
+            None => (1, Duration::ZERO),
+        };
+
+        let mut attempt = 1;
+        loop {
+            match process_trading_signal_for_analytics(signal, strategy_config, position_tracker, pnl_calculator).await {
+                Ok(execution) => return Ok(execution),
+                Err(e) if attempt >= max_attempts => return Err((e, attempt)),
+                Err(e) => {
+                    debug!("Execution attempt {}/{} for {} failed: {}", attempt, max_attempts, signal.get_token_mint(), e);
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    /// Keep a signal whose execution failed on every attempt for an operator to requeue
+    async fn dead_letter(&self, signal: &TradingSignal, context: &ExecutionContext, error: &anyhow::Error, attempts: u32) {
+        let token_mint = signal.get_token_mint();
+        forensics::trace(&token_mint, "decision", serde_json::json!({
+            "outcome": "dead_lettered",
+            "error": error.to_string(),
+            "attempts": attempts,
+        }));
+
+        let Some(dead_letters) = &self.dead_letters else {
+            error!("❌ Execution of {} signal for {} failed after {} attempts: {}",
+                signal.get_signal_type(), token_mint, attempts, error);
+            return;
+        };
+        let letter = match dead_letters.push(signal, context, &error.to_string(), attempts).await {
+            Ok(letter) => letter,
+            Err(e) => {
+                error!("❌ Execution of {} signal for {} failed ({}) and could not be dead-lettered: {}",
+                    signal.get_signal_type(), token_mint, error, e);
+                return;
+            }
+        };
+
+        let amount_sol = match signal {
+            TradingSignal::Buy { max_amount_sol, .. } => *max_amount_sol,
+            _ => 0.0,
+        };
+        let alert = SystemAlert::ExecutionError {
+            order_id: format!("dead-letter:{}", letter.id),
+            token_mint,
+            error: format!("{} (dead-lettered after {} attempts; requeue with {} {})",
+                error, attempts, COMMAND_REQUEUE_DEAD_LETTER, letter.id),
+            amount_sol,
+        };
+        if let Err(e) = self.service_registry.route_system_alert(alert, Some("dead-letters-001")).await {
+            warn!("Failed to route dead-letter alert: {}", e);
+        }
+    }
+
+    /// Replay a requeued dead letter through the live path
+    ///
+    /// The signal runs under the configuration it failed with when that
+    /// snapshot is still stored, and passes every gate a fresh signal would.
+    async fn process_dead_letter(&self, letter: DeadLetter) {
+        let signal = match letter.signal() {
+            Ok(signal) => signal,
+            Err(e) => {
+                warn!("Failed to replay dead letter #{}: {}", letter.id, e);
+                return;
+            }
+        };
+
+        let snapshot = match (&self.config_snapshots, &letter.config_hash) {
+            (Some(store), Some(config_hash)) => store.get_snapshot(config_hash).await.unwrap_or_else(|e| {
+                warn!("Failed to load config snapshot {}: {}", config_hash, e);
+                None
+            }),
+            _ => None,
+        };
+        let (strategy_config, config_hash) = match snapshot
+            .and_then(|snapshot| serde_json::from_str::<StrategyConfig>(&snapshot.snapshot).ok())
+        {
+            Some(config) => (Arc::new(config), letter.config_hash.clone()),
+            None => self.live_strategy().await,
+        };
+
+        info!("♻️ Replaying dead letter #{}: {} {}", letter.id, letter.signal_type, letter.token_mint);
+        self.route_signal(signal, &strategy_config, &config_hash, &letter.signal_source, "dead-letters").await;
+    }
+
+    /// Apply entry gates and risk checks to a signal, then route and execute it
+    ///
+    /// Internal and external signals share this path; `signal_source` is
+    /// recorded in the outbox so their outcomes can be told apart.
+    async fn route_signal(
+        &self,
+        signal: TradingSignal,
+        strategy_config: &Arc<StrategyConfig>,
+        config_hash: &Option<String>,
+        signal_source: &str,
+        source_service: &str,
+    ) {
+        let token_mint = signal.get_token_mint();
+        if !self.owns_trading.load(Ordering::SeqCst) {
+            // Another instance trades on this database; this one only keeps its state warm
+            forensics::trace(&token_mint, "decision", serde_json::json!({ "outcome": "standby" }));
+            return;
+        }
+        if !self.passes_entry_gates(&signal, strategy_config) {
+            forensics::trace(&token_mint, "decision", serde_json::json!({ "outcome": "rejected_by_entry_gates" }));
+            return;
+        }
+        if !self.passes_token_universe(&signal, strategy_config).await {
+            forensics::trace(&token_mint, "decision", serde_json::json!({ "outcome": "rejected_by_token_universe" }));
+            return;
+        }
+        // Multipliers run ahead of the ladder and per-insider caps, which may only cut them back
+        let signal = self.apply_hour_sizing(signal).await;
+        let signal = self.apply_funding_boost(signal);
+        let signal = self.apply_size_ladder(signal, strategy_config);
+        let Some(signal) = self.apply_copy_settings(signal).await else {
+            return;
+        };
+        let signal = self.apply_volatility_sizing(signal).await;
+        let Some(signal) = self.apply_risk_limits(signal).await else {
+            forensics::trace(&token_mint, "decision", serde_json::json!({ "outcome": "rejected_by_deleveraging" }));
+            return;
+        };
+        // Held until the entry executes; dropping it on any other path frees the room
+        let Some((signal, mut exposure_reservation)) = self.apply_exposure_cap(signal).await else {
+            forensics::trace(&token_mint, "decision", serde_json::json!({ "outcome": "rejected_by_exposure_cap" }));
+            return;
+        };
+        let entry_attributes = self.entry_attributes(&signal).await;
+        let insider_tier = entry_attributes.as_ref().and_then(|attributes| attributes.insider_tier.as_deref());
+        if !self.passes_safety_check(&signal, insider_tier).await {
+            forensics::trace(&token_mint, "decision", serde_json::json!({ "outcome": "rejected_by_token_safety" }));
+            return;
+        }
+        forensics::trace(&token_mint, "route", serde_json::json!({
+            "signal": signal,
+            "signal_source": signal_source,
+            "source_service": source_service,
+            "config_hash": config_hash,
+            "trading_paused": self.trading_paused.load(Ordering::SeqCst),
+            "chain_lagging": self.chain_lag.is_lagging(),
+            "venue": self.venue_health.preferred_venue(),
+            "execution_pathway": SignalUrgency::for_signal(&signal).map(SignalUrgency::pathway),
+        }));
+        
+        // Buy candidates get holder distribution snapshots before and while we hold them
+        if let (Some(holder_snapshots), TradingSignal::Buy { token_mint, .. }) = (&self.holder_snapshots, &signal) {
+            holder_snapshots.watch(token_mint);
+        }
+        
+        display_trading_signal(&signal);
+        
+        // Copy-trade entries are checked against operator-enabled skip rules
+        let skip_match = match (&self.skip_rules, &entry_attributes) {
+            (Some(skip_rules), Some(attributes)) => skip_rules.matching_rule(attributes).await,
+            _ => None,
+        };
+        let blocked = |check: &str| forensics::trace(&token_mint, "risk_check", serde_json::json!({
+            "check": check,
+            "passed": false,
+        }));
+        // Entries stop once realized losses reach a daily or weekly limit
+        let loss_breach = match (&self.loss_limits, &signal) {
+            (Some(loss_limits), TradingSignal::Buy { source, .. }) => match loss_limits.breach(&strategy_name(*source)).await {
+                Ok(breach) => breach,
+                Err(e) => {
+                    warn!("Failed to check loss limits: {}", e);
+                    None
+                }
+            },
+            _ => None,
+        };
+        
+        // Process signal with analytics (Phase 3: Task 3.1)
+        if self.trading_paused.load(Ordering::SeqCst) && matches!(signal, TradingSignal::Buy { .. }) {
+            // Exits still go through; entries wait for RESUME
+            debug!("⏸️ Trading paused by operator - skipping buy of {}", signal.get_token_mint());
+            blocked("trading_paused");
+        } else if let Some(breach) = &loss_breach {
+            // Exits still go through; entries wait for the period to roll over
+            warn!("🧯 Skipping buy of {}: {} {} loss limit reached ({:.4} realized since {}, limit {:.4})",
+                signal.get_token_mint(), breach.scope(), breach.period.to_lowercase(), breach.realized_pnl, breach.period_start, breach.limit);
+            forensics::trace(&token_mint, "risk_check", serde_json::json!({
+                "check": "loss_limit",
+                "passed": false,
+                "breach": breach,
+            }));
+        } else if self.chain_lag.is_lagging() && matches!(signal, TradingSignal::Buy { .. }) {
+            // Exits still go through; entries on stale data do not
+            warn!("⏱️ Skipping buy of {}: event stream is lagging the chain", signal.get_token_mint());
+            blocked("chain_lag");
+        } else if let (TradingSignal::Buy { .. }, Some(reason)) = (&signal, self.venue_health.trading_blocker()) {
+            // Exits still go through; entries wait for a working venue
+            warn!("🩺 Skipping buy of {}: {}", signal.get_token_mint(), reason);
+            forensics::trace(&token_mint, "risk_check", serde_json::json!({
+                "check": "venue_health",
+                "passed": false,
+                "reason": reason,
+            }));
+        } else if let Some(block) = self.sniping_guard.check(&signal) {
+            // Launch-block entries belong to the sniper strategy and its budget
+            if block.budget_exhausted {
+                warn!("🎯 Skipping snipe of {}: sniper budget of {:.2} SOL spent for today",
+                    block.token_mint, self.sniping_guard.config().sniper_daily_budget_sol);
+            } else {
+                info!("🎯 Skipping {:?} buy of {}: token is {} slots old", block.source, block.token_mint, block.token_age_slots);
+            }
+            forensics::trace(&token_mint, "risk_check", serde_json::json!({
+                "check": "sniping_guard",
+                "passed": false,
+                "token_age_slots": block.token_age_slots,
+                "source": block.source,
+                "budget_exhausted": block.budget_exhausted,
+            }));
+        } else if let Some(conflict) = self.self_trade_guard.check(&signal) {
+            // Never trade against one of our own rotated wallets
+            warn!("🪞 Blocked {} on {}: own wallet {} is on the other side ({})",
+                conflict.blocked_action, conflict.token_mint,
+                self.wallet_labels.display(&conflict.counterparty_wallet), conflict.counterparty_signature);
+            forensics::trace(&token_mint, "risk_check", serde_json::json!({
+                "check": "self_trade",
+                "passed": false,
+                "blocked_action": conflict.blocked_action,
+                "counterparty_wallet": conflict.counterparty_wallet,
+                "counterparty_signature": conflict.counterparty_signature,
+            }));
+            if let Some(insider_analytics) = &self.insider_analytics {
+                if let Err(e) = insider_analytics.record_self_trade_event(
+                    "BLOCKED_SIGNAL",
+                    &conflict.counterparty_wallet,
+                    &conflict.token_mint,
+                    conflict.blocked_action,
+                    Some(&conflict.counterparty_signature),
+                ).await {
+                    warn!("Failed to record blocked self-trade: {}", e);
+                }
+            }
+        } else if self.fade_tracker.as_ref().is_some_and(|fade| {
+            matches!(&signal, TradingSignal::Buy { token_mint, .. } if fade.avoids(token_mint))
+        }) {
+            info!("🙃 Skipping buy of {}: recently bought by a toxic wallet", signal.get_token_mint());
+            blocked("fade");
+        } else if entry_attributes.is_some() && !self.insider_backfill_done.load(Ordering::SeqCst) {
+            // Insider statistics are incomplete until missed activity is replayed
+            info!("⏪ Skipping copy trade on {}: insider backfill in progress", signal.get_token_mint());
+            blocked("insider_backfill");
+        } else if let Some((dimension, value)) = skip_match {
+            info!("🚫 Skipping copy trade on {}: skip rule {}={}", signal.get_token_mint(), dimension, value);
+            forensics::trace(&token_mint, "risk_check", serde_json::json!({
+                "check": "skip_rule",
+                "passed": false,
+                "dimension": dimension,
+                "value": value,
+            }));
+        } else if let (Some(position_tracker), Some(pnl_calc)) = (&self.position_tracker, &self.pnl_calculator) {
+            // Published only once every gate passed, so subscribers never act on a blocked signal
+            match self.service_registry.route_trading_signal(
+                signal.clone(),
+                Some(source_service)
+            ).await {
+                Ok(_) => println!("   📤 TradingSignal routed to transport bus successfully"),
+                Err(e) => warn!("Failed to route trading signal: {}", e),
+            }
+            
+            // Recorded only once every gate passed, so blocked signals never expire as unexecuted
+            let outbox_id = match &self.signal_outbox {
+                Some(outbox) => match outbox.record_emitted(&signal, signal_source).await {
+                    Ok(signal_id) => Some(signal_id),
+                    Err(e) => {
+                        warn!("Failed to record signal in outbox: {}", e);
+                        None
+                    }
+                },
+                None => None,
+            };
+            if let (Some(outbox), Some(signal_id)) = (&self.signal_outbox, &outbox_id) {
+                if let Err(e) = outbox.mark_picked_up(signal_id).await {
+                    warn!("Failed to acknowledge signal pickup: {}", e);
+                }
+            }
+            
+            let execution = match self.execute_with_retries(&signal, strategy_config, position_tracker, pnl_calc).await {
+                Ok(execution) => execution,
+                Err((e, attempts)) => {
+                    let context = ExecutionContext {
+                        signal_id: outbox_id.clone(),
+                        signal_source: signal_source.to_string(),
+                        source_service: source_service.to_string(),
+                        config_hash: config_hash.clone(),
+                    };
+                    self.dead_letter(&signal, &context, &e, attempts).await;
+                    return;
+                }
+            };
+            forensics::trace(&token_mint, "decision", match &execution {
+                Some(execution) => serde_json::json!({
+                    "outcome": "executed",
+                    "trade_action": execution.trade_action,
+                    "position_id": execution.position_id,
+                    "execution_ref": execution.execution_ref(),
+                }),
+                None => serde_json::json!({ "outcome": "not_executed" }),
+            });
+            
+            if let Some(execution) = execution {
+                if let (Some(outbox), Some(signal_id)) = (&self.signal_outbox, &outbox_id) {
+                    if let Err(e) = outbox.mark_executed(signal_id, Some(&execution.execution_ref())).await {
+                        warn!("Failed to acknowledge signal execution: {}", e);
+                    }
+                }
+                
+                if execution.trade_action == "CLOSE" {
+                    self.cancel_working_orders(&signal.get_token_mint()).await;
+                }
+
+                // Queue the fill for a cross-check against an independent price source
+                if let Some(fill_checks) = &self.fill_checks {
+                    let side = if execution.trade_action == "OPEN" { "BUY" } else { "SELL" };
+                    if let Err(e) = fill_checks.record_fill(
+                        execution.position_id, &token_mint, side, execution.price, Utc::now().timestamp(),
+                    ).await {
+                        warn!("Failed to queue fill check: {}", e);
+                    }
+                }
+                if let TradingSignal::Buy { source: badger::core::SignalSource::Sniper, max_amount_sol, .. } = &signal {
+                    self.sniping_guard.record_sniper_entry(*max_amount_sol);
+                    // Written through so a restart mid-day resumes today's sniper budget
+                    if let Some(daily_counters) = &self.daily_counters {
+                        if let Err(e) = daily_counters.add(COUNTER_SNIPER_SPEND, *max_amount_sol).await {
+                            warn!("Failed to update daily counter {}: {}", COUNTER_SNIPER_SPEND, e);
+                        }
+                    }
+                }
+                
+                // Attribute the trade to the configuration it ran under
+                if let (Some(store), Some(config_hash)) = (&self.config_snapshots, config_hash) {
+                    if let Err(e) = store.attach_to_trade(execution.position_id, execution.trade_action, config_hash).await {
+                        warn!("Failed to attach config snapshot to trade: {}", e);
+                    }
+                }
+                
+                // Losses of the position count against the limits of the strategy that opened it
+                if let (Some(loss_limits), TradingSignal::Buy { source, .. }) = (&self.loss_limits, &signal) {
+                    if execution.trade_action == "OPEN" {
+                        if let Err(e) = loss_limits.record_entry(execution.position_id, &strategy_name(*source)).await {
+                            warn!("Failed to record position strategy: {}", e);
+                        }
+                    }
+                }
+                
+                // The entry's room moves from its reservation onto the exposure ledger
+                if let (Some(exposure), Some(reservation)) = (&self.exposure, exposure_reservation.take()) {
+                    if execution.trade_action == "OPEN" {
+                        if let Err(e) = exposure.record_entry(execution.position_id, reservation).await {
+                            warn!("Failed to record exposure: {}", e);
+                        }
+                    }
+                }
+                
+                // A/B test entries count towards their arm's budget and P&L track
+                if let (Some(ab_tests), Some((experiment, arm)), TradingSignal::Buy { max_amount_sol, .. })
+                    = (&self.ab_tests, ab_arm_of_source(signal_source), &signal)
+                {
+                    if execution.trade_action == "OPEN" {
+                        if let Err(e) = ab_tests.record_trade(experiment, arm, execution.position_id, &token_mint, *max_amount_sol).await {
+                            warn!("Failed to record A/B test trade: {}", e);
+                        }
+                    }
+                }
+                
+                // Exit levels are fixed at entry, priced for what a sale will actually return
+                if let Some(exit_levels) = &self.exit_levels {
+                    if execution.trade_action == "OPEN" {
+                        if let Err(e) = exit_levels.set_for_position(
+                            execution.position_id, &token_mint, execution.price, strategy_config.fee_rate,
+                        ).await {
+                            warn!("Failed to set exit levels: {}", e);
+                        }
+                    }
+                }
+                
+                // Keep entry attributes so losing copy trades can be analyzed later
+                if let (Some(skip_rules), Some(attributes)) = (&self.skip_rules, &entry_attributes) {
+                    if execution.trade_action == "OPEN" {
+                        if let Err(e) = skip_rules.record_entry(execution.position_id, &signal.get_token_mint(), attributes).await {
+                            warn!("Failed to record entry attributes: {}", e);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Production-ready Badger trading bot orchestrator
+/// 
+/// This orchestrator manages the core WebSocket ingestion system for real-time
+/// Solana blockchain data processing with the enhanced transport layer for
+/// service communication. It handles graceful startup, shutdown,
+/// and error recovery for all services.
+struct BadgerOrchestrator {
+    shutdown_tx: broadcast::Sender<()>,
+    tasks: Vec<JoinHandle<Result<()>>>,
+    websocket_config: WebSocketConfig,
+    transport_bus: Arc<EnhancedTransportBus>,
+    service_registry: Arc<ServiceRegistry>,
+    database_manager: Option<badger::DatabaseManager>,
+    // Analytics components
+    position_tracker: Option<Arc<PositionTracker>>,
+    pnl_calculator: Option<Arc<PnLCalculator>>,
+    performance_tracker: Option<Arc<PerformanceTracker>>,
+    insider_analytics: Option<Arc<InsiderAnalytics>>,
+    wallet_discovery: Option<Arc<WalletDiscovery>>,
+    // Operator control (badger-tui)
+    control_channel: Option<Arc<ControlChannel>>,
+    trading_paused: Arc<AtomicBool>,
+    // Rolling deployment handoff between instances sharing the database
+    handoff: Option<Arc<HandoffCoordinator>>,
+    owns_trading: Arc<AtomicBool>,
+    retired: Arc<tokio::sync::Notify>,
+    insider_backfill_done: Arc<AtomicBool>,
+    signal_outbox: Option<Arc<SignalOutbox>>,
+    // Active strategy configuration and its snapshot hash
+    strategy_config: Arc<StrategyConfig>,
+    config_snapshots: Option<Arc<ConfigSnapshotStore>>,
+    config_hash: Option<String>,
+    hedge_monitor: Option<Arc<HedgeMonitor>>,
+    risk_analytics: Option<Arc<RiskAnalytics>>,
+    bonding_curves: Arc<BondingCurveTracker>,
+    pool_reserves: Arc<PoolReserveCache>,
+    self_trade_guard: Arc<SelfTradeGuard>,
+    sniping_guard: Arc<SnipingGuard>,
+    insider_funding: Arc<InsiderFundingMonitor>,
+    momentum: Arc<MomentumTracker>,
+    warmup: Arc<StrategyWarmup>,
+    /// Confirmation waits woken by signature notifications on the ingestion WebSocket
+    signature_confirmations: Arc<SignatureConfirmations>,
+    /// Fetches and decodes transactions announced by logs notifications
+    log_ingest: Arc<LogTransactionIngest>,
+    /// Decodes every transaction of blocks from the block subscription
+    block_ingest: Arc<BlockTransactionIngest>,
+    token_ages: Arc<TokenAgeService>,
+    event_dedup: Arc<EventDeduplicator>,
+    token_registry: Arc<TokenRegistry>,
+    strategy_registry: Option<Arc<StrategyRegistry>>,
+    cold_sweeps: Option<Arc<ColdSweepLedger>>,
+    profit_locks: Option<Arc<ProfitLockLedger>>,
+    skip_rules: Option<Arc<SkipRuleLearner>>,
+    holder_snapshots: Option<Arc<HolderSnapshotStore>>,
+    fade_tracker: Option<Arc<FadeTracker>>,
+    summary_views: Option<Arc<SummaryViews>>,
+    wallet_portfolio: Option<Arc<WalletPortfolio>>,
+    working_orders: Option<Arc<WorkingOrderBook>>,
+    copy_settings: Option<Arc<InsiderCopySettingsStore>>,
+    watch_groups: Option<Arc<WatchGroupStore>>,
+    activity_heatmap: Option<Arc<ActivityHeatmap>>,
+    price_candles: Option<Arc<PriceCandleStore>>,
+    volatility: Option<Arc<VolatilityTracker>>,
+    fill_checks: Option<Arc<FillQualityMonitor>>,
+    position_alerts: Option<Arc<PositionAlertMonitor>>,
+    treasury: Option<Arc<TreasuryLedger>>,
+    wallet_labels: Arc<WalletLabels>,
+    wallet_label_store: Option<Arc<WalletLabelStore>>,
+    program_registry: Option<Arc<ProgramRegistryStore>>,
+    risk_manager: Option<Arc<RiskManager>>,
+    token_safety: Arc<TokenSafetyCache>,
+    metadata_monitor: Arc<MetadataMonitor>,
+    market_data: Option<Arc<MarketDataPool>>,
+    volume_profiler: Option<Arc<VolumeProfiler>>,
+    fee_budget: Option<Arc<FeeBudget>>,
+    loss_limits: Option<Arc<LossLimits>>,
+    own_fills: Option<Arc<OwnFillLedger>>,
+    daily_counters: Option<Arc<DailyCounters>>,
+    exit_levels: Option<Arc<ExitLevelBook>>,
+    ab_tests: Option<Arc<StrategyAbTests>>,
+    dead_letters: Option<Arc<DeadLetterQueue>>,
+    exposure: Option<Arc<ExposureLedger>>,
+    tax_ledger: Option<Arc<TaxLedger>>,
+    cold_store: Option<Arc<ColdStore>>,
+    maintenance: Option<Arc<DatabaseMaintenance>>,
+    endpoint_prober: Arc<EndpointProber>,
+    venue_health: Arc<VenueHealthMonitor>,
+    chain_lag: Arc<ChainLagMonitor>,
+    options: LaunchOptions,
+}
+
+impl BadgerOrchestrator {
+    fn new(options: LaunchOptions) -> Self {
+        let (shutdown_tx, _) = broadcast::channel(16);
+        
+        let mainnet_config = WebSocketConfig {
+            primary_url: "wss://api.mainnet-beta.solana.com".to_string(),
+            backup_urls: vec![
+                "wss://rpc.ankr.com/solana_ws".to_string(),
+                "wss://solana-api.projectserum.com".to_string(),
+            ],
+            connect_timeout_ms: 10000,
+            max_reconnect_attempts: 10,
+            reconnect_delay_ms: 5000,
+            heartbeat_interval_ms: 10000,
+            commitment: "confirmed".to_string(),
+            endpoints: Vec::new(),
+            logs_subscribe: false,
+            block_subscribe: false,
+        };
+        
+        // Test modes never touch mainnet
+        let mut websocket_config = if options.is_test_mode() {
+            WebSocketConfig::devnet()
+        } else {
+            mainnet_config
+        };
+        
+        // Commitment per consumer: program feeds follow the sniper's level
+        let commitments = CommitmentLevels::from_env();
+        websocket_config.commitment = commitments.sniper.commitment.to_string();
+        
+        // Logs mode adds per-program logs subscriptions whose transactions are fetched in full
+        let log_ingest = Arc::new(LogTransactionIngest::new(Some(LogIngestConfig::from_env())));
+        websocket_config.logs_subscribe = log_ingest.config().enabled;
+        
+        // Block mode sees every transaction, so logs would only repeat its swaps
+        let block_ingest = Arc::new(BlockTransactionIngest::new(Some(BlockIngestConfig::from_env())));
+        websocket_config.block_subscribe = block_ingest.config().enabled;
+        if websocket_config.block_subscribe && websocket_config.logs_subscribe {
+            info!("📦 Block ingestion enabled; logs subscriptions are not needed and stay off");
+            websocket_config.logs_subscribe = false;
+        }
+        
+        // Authenticated provider endpoints go ahead of the public ones
+        websocket_config.endpoints = EndpointSettings::from_env().unwrap_or_else(|e| {
+            warn!("Ignoring WebSocket endpoint settings: {}", e);
+            Vec::new()
+        });
+        if !options.is_test_mode() {
+            websocket_config.prefer_configured_endpoints();
+        }
+        
+        // Candidate RPC endpoints: configured WebSocket URLs plus optional paid endpoints
+        let mut endpoints: Vec<RpcEndpoint> = std::iter::once(&websocket_config.primary_url)
+            .chain(websocket_config.backup_urls.iter())
+            .map(|url| RpcEndpoint::from_ws(url, false))
+            .collect();
+        if let Ok(premium) = std::env::var("BADGER_PREMIUM_RPC_URLS") {
+            endpoints.extend(premium.split(',').map(str::trim).filter(|u| !u.is_empty())
+                .map(|url| RpcEndpoint::from_ws(url, true)));
+        }
+        let endpoints: Vec<RpcEndpoint> = endpoints.into_iter()
+            .map(|endpoint| {
+                let auth = websocket_config.endpoint(&endpoint.ws_url).auth;
+                endpoint.with_auth(auth)
+            })
+            .collect();
+        let endpoint_prober = Arc::new(EndpointProber::new(endpoints, None).with_commitments(commitments));
+        
+        // Initialize the enhanced transport bus
+        let transport_bus = Arc::new(EnhancedTransportBus::new());
+        
+        // Initialize the service registry
+        let service_registry = Arc::new(ServiceRegistry::new(transport_bus.clone()));
+        
+        // Our rotated wallets; self-trade prevention activates with two or more
+        let self_trade_guard = Arc::new(SelfTradeGuard::default());
+        if let Ok(wallets) = std::env::var("BADGER_OWN_WALLETS") {
+            for wallet in wallets.split(',').map(str::trim).filter(|w| !w.is_empty()) {
+                self_trade_guard.register_wallet(wallet);
+            }
+        }
+        if self_trade_guard.is_enabled() {
+            info!("🪞 Self-trade prevention enabled for {} wallets", self_trade_guard.wallet_count());
+        }
+        
+        Self {
+            shutdown_tx,
+            tasks: Vec::new(),
+            websocket_config,
+            transport_bus,
+            service_registry,
+            database_manager: None,
+            // Initialize analytics components as None - will be set up later
+            position_tracker: None,
+            pnl_calculator: None,
+            performance_tracker: None,
+            insider_analytics: None,
+            wallet_discovery: None,
+            control_channel: None,
+            trading_paused: Arc::new(AtomicBool::new(false)),
+            handoff: None,
+            owns_trading: Arc::new(AtomicBool::new(true)),
+            retired: Arc::new(tokio::sync::Notify::new()),
+            insider_backfill_done: Arc::new(AtomicBool::new(false)),
+            signal_outbox: None,
+            strategy_config: Arc::new(options.frozen_config.clone().unwrap_or_default()),
+            config_snapshots: None,
+            config_hash: None,
+            hedge_monitor: None,
+            risk_analytics: None,
+            bonding_curves: Arc::new(BondingCurveTracker::new()),
+            pool_reserves: Arc::new(PoolReserveCache::new(Some(PoolReserveConfig::from_env()))),
+            self_trade_guard,
+            sniping_guard: Arc::new(SnipingGuard::new(Some(SnipingGuardConfig::from_env()))),
+            insider_funding: Arc::new(InsiderFundingMonitor::new(Some(InsiderFundingConfig::from_env()))),
+            momentum: Arc::new(MomentumTracker::new(Some(MomentumConfig::from_env()))),
+            warmup: Arc::new(StrategyWarmup::new(Some(WarmupConfig::from_env()))),
+            signature_confirmations: Arc::new(SignatureConfirmations::new(None)),
+            log_ingest,
+            block_ingest,
+            token_ages: Arc::new(TokenAgeService::new(None)),
+            event_dedup: Arc::new(EventDeduplicator::default()),
+            token_registry: Arc::new(TokenRegistry::new(None)),
+            strategy_registry: None,
+            cold_sweeps: None,
+            profit_locks: None,
+            skip_rules: None,
+            holder_snapshots: None,
+            fade_tracker: None,
+            summary_views: None,
+            wallet_portfolio: None,
+            working_orders: None,
+            copy_settings: None,
+            watch_groups: None,
+            activity_heatmap: None,
+            price_candles: None,
+            volatility: None,
+            fill_checks: None,
+            position_alerts: None,
+            treasury: None,
+            wallet_labels: Arc::new(WalletLabels::new()),
+            wallet_label_store: None,
+            program_registry: None,
+            risk_manager: None,
+            token_safety: Arc::new(TokenSafetyCache::new(Some(TokenSafetyConfig::from_env()))),
+            metadata_monitor: Arc::new(MetadataMonitor::new(Some(MetadataMonitorConfig::from_env()))),
+            market_data: None,
+            volume_profiler: None,
+            fee_budget: None,
+            loss_limits: None,
+            own_fills: None,
+            daily_counters: None,
+            exit_levels: None,
+            ab_tests: None,
+            dead_letters: None,
+            exposure: None,
+            tax_ledger: None,
+            cold_store: None,
+            maintenance: None,
+            endpoint_prober,
+            venue_health: Arc::new(VenueHealthMonitor::new(Some(VenueHealthConfig::from_env()))),
+            chain_lag: Arc::new(ChainLagMonitor::new(None)),
+            options,
+        }
+    }
+
+    /// Build the shared market event pipeline from the current analytics components
+    fn market_event_pipeline(&self) -> MarketEventPipeline {
+        MarketEventPipeline {
+            service_registry: self.service_registry.clone(),
+            insider_analytics: self.insider_analytics.clone(),
+            position_tracker: self.position_tracker.clone(),
+            pnl_calculator: self.pnl_calculator.clone(),
+            signal_outbox: self.signal_outbox.clone(),
+            config_snapshots: self.config_snapshots.clone(),
+            strategy_config: self.strategy_config.clone(),
+            config_hash: self.config_hash.clone(),
+            bonding_curves: self.bonding_curves.clone(),
+            pool_reserves: self.pool_reserves.clone(),
+            self_trade_guard: self.self_trade_guard.clone(),
+            sniping_guard: self.sniping_guard.clone(),
+            insider_funding: self.insider_funding.clone(),
+            momentum: self.momentum.clone(),
+            warmup: self.warmup.clone(),
+            token_ages: self.token_ages.clone(),
+            event_dedup: self.event_dedup.clone(),
+            strategy_registry: self.strategy_registry.clone(),
+            skip_rules: self.skip_rules.clone(),
+            holder_snapshots: self.holder_snapshots.clone(),
+            fade_tracker: self.fade_tracker.clone(),
+            working_orders: self.working_orders.clone(),
+            copy_settings: self.copy_settings.clone(),
+            watch_groups: self.watch_groups.clone(),
+            activity_heatmap: self.activity_heatmap.clone(),
+            volatility: self.volatility.clone(),
+            fill_checks: self.fill_checks.clone(),
+            position_alerts: self.position_alerts.clone(),
+            risk_manager: self.risk_manager.clone(),
+            loss_limits: self.loss_limits.clone(),
+            own_fills: self.own_fills.clone(),
+            daily_counters: self.daily_counters.clone(),
+            exit_levels: self.exit_levels.clone(),
+            ab_tests: self.ab_tests.clone(),
+            wallet_discovery: self.wallet_discovery.clone(),
+            dead_letters: self.dead_letters.clone(),
+            exposure: self.exposure.clone(),
+            token_safety: self.token_safety.clone(),
+            endpoint_prober: self.endpoint_prober.clone(),
+            venue_health: self.venue_health.clone(),
+            chain_lag: self.chain_lag.clone(),
+            wallet_labels: self.wallet_labels.clone(),
+            trading_paused: self.trading_paused.clone(),
+            owns_trading: self.owns_trading.clone(),
+            insider_backfill_done: self.insider_backfill_done.clone(),
+        }
+    }
+
+    /// Initialize the database services (Phase 3)
+    async fn initialize_database_services(&mut self) -> Result<()> {
+        info!("🗄️ Initializing Phase 3 Database Services");
+        
+        // Initialize database manager (directory creation handled in database layer)
+        let mut database_manager = if self.options.fixtures {
+            // Start every fixtures run from a clean database
+            let fixture_path = FIXTURE_DATABASE_URL.trim_start_matches("sqlite:");
+            for suffix in ["", "-wal", "-shm"] {
+                let _ = std::fs::remove_file(format!("{}{}", fixture_path, suffix));
+            }
+            info!("🧪 Fixtures mode: using {}", FIXTURE_DATABASE_URL);
+            badger::DatabaseManager::new().with_database_url(FIXTURE_DATABASE_URL)
+        } else if self.options.devnet {
+            badger::DatabaseManager::new().with_database_url("sqlite:data/badger-devnet.db")
+        } else {
+            badger::DatabaseManager::new()
+        };
+        
+        // Initialize with transport bus and service registry
+        if let Err(e) = database_manager.initialize(
+            self.transport_bus.clone(),
+            self.service_registry.clone(),
+        ).await {
+            error!("Failed to initialize database manager: {}", e);
+            return Err(anyhow::anyhow!("Database initialization failed: {}", e));
+        }
+        
+        // Start all database services
+        let db_handles = database_manager.start_all_services().await
+            .map_err(|e| anyhow::anyhow!("Failed to start database services: {}", e))?;
+        
+        // Convert database service handles to our handle type
+        for handle in db_handles {
+            let converted_handle = tokio::spawn(async move {
+                match handle.await {
+                    Ok(Ok(())) => Ok(()),
+                    Ok(Err(e)) => Err(anyhow::anyhow!("Database service error: {}", e)),
+                    Err(e) => Err(anyhow::anyhow!("Database service join error: {}", e)),
+                }
+            });
+            self.tasks.push(converted_handle);
+        }
+        
+        self.database_manager = Some(database_manager);
+        
+        info!("✅ Phase 3 Database Services initialized successfully");
+        
+        // Initialize analytics components after database is ready
+        self.initialize_analytics().await?;
+        
+        if self.options.fixtures {
+            let db = self.database_manager.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("Database manager not initialized"))?
+                .get_database();
+            seed_fixtures(&db).await
+                .map_err(|e| anyhow::anyhow!("Failed to seed fixtures: {}", e))?;
+        }
+        
+        Ok(())
+    }
+
+    /// Initialize analytics components (Task 3.1: Real-time Metrics Calculation)
+    async fn initialize_analytics(&mut self) -> Result<()> {
+        info!("🔧 Initializing analytics components for real-time metrics");
+        
+        let db_manager = self.database_manager.as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Database manager not initialized"))?;
+        let db = db_manager.get_database();
+
+        // Initialize position tracker
+        let position_tracker = Arc::new(PositionTracker::new(db.clone())
+            .with_transport_bus(self.transport_bus.clone()));
+        position_tracker.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize position tracker schema: {}", e))?;
+
+        // Initialize the cold store that analytics history past the hot window moves to
+        let cold_store = Arc::new(ColdStore::new(db.clone(), Some(ColdStoreConfig::from_env())));
+        cold_store.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize cold store schema: {}", e))?;
+
+        // Initialize P&L calculator
+        let pnl_calculator = Arc::new(PnLCalculator::new(db.clone(), position_tracker.clone())
+            .with_cold_store(cold_store.clone()));
+        pnl_calculator.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize P&L calculator schema: {}", e))?;
+
+        // Initialize performance tracker
+        let performance_tracker = Arc::new(PerformanceTracker::new(
+            db.clone(), 
+            position_tracker.clone(),
+            pnl_calculator.clone()
+        ));
+        performance_tracker.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize performance tracker schema: {}", e))?;
+
+        // Initialize insider analytics
+        let scoring_model = scoring_model_from_env()
+            .map_err(|e| anyhow::anyhow!("Failed to load insider scoring model: {}", e))?;
+        let wallet_cache_config = WalletCacheConfig::from_env();
+        let mut wallet_cache = WalletIntelligenceCache::new(Some(wallet_cache_config.clone()));
+        if let Some(store_path) = &wallet_cache_config.store_path {
+            let store = UltraFastWalletDB::open(store_path)
+                .map_err(|e| anyhow::anyhow!("Failed to open hot wallet store: {}", e))?;
+            wallet_cache = wallet_cache.with_store(Arc::new(store));
+            match wallet_cache.warm_up() {
+                Ok(loaded) => {
+                    info!("🔥 Warmed insider cache with {} profiles from {}", loaded, store_path.display());
+                    self.warmup.mark_insider_cache_loaded();
+                }
+                Err(e) => warn!("Failed to warm insider cache from {}: {}", store_path.display(), e),
+            }
+        } else {
+            // Without a hot wallet store profiles are read through from SQLite
+            self.warmup.mark_insider_cache_loaded();
+        }
+        let wallet_cache = Arc::new(wallet_cache);
+        let insider_analytics = Arc::new(InsiderAnalytics::new(db.clone(), position_tracker.clone())
+            .with_scoring_model(scoring_model)
+            .with_cache(wallet_cache.clone()));
+        insider_analytics.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize insider analytics schema: {}", e))?;
+
+        // Initialize wallet discovery and load external rankings into probation
+        let wallet_discovery = Arc::new(WalletDiscovery::new(db.clone(), insider_analytics.clone(), None));
+        wallet_discovery.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize wallet discovery schema: {}", e))?;
+        match wallet_discovery.import_directory(std::path::Path::new("data/wallet_imports")).await {
+            Ok(count) if count > 0 => info!("📥 Imported {} wallet candidates into probation", count),
+            Ok(_) => {}
+            Err(e) => warn!("Failed to import wallet rankings: {}", e),
+        }
+
+        // Initialize operator control channel
+        let control_channel = Arc::new(ControlChannel::new(db.clone()));
+        control_channel.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize control channel schema: {}", e))?;
+
+        // Restore pause state from the last processed operator command
+        if control_channel.is_paused().await.unwrap_or(false) {
+            warn!("⏸️ Trading is paused by operator - new positions will not be opened");
+            self.trading_paused.store(true, Ordering::SeqCst);
+        }
+
+        // Register with other instances on this database; only one trades at a time
+        let handoff = Arc::new(HandoffCoordinator::new(db.clone(), None));
+        handoff.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize process handoff schema: {}", e))?;
+        let active_peer = handoff.active_peer().await
+            .map_err(|e| anyhow::anyhow!("Failed to check for a running instance: {}", e))?;
+        match active_peer {
+            Some(peer) if self.options.takeover => {
+                info!("🔁 Standing by to take over trading from {} (pid {})", peer.instance_id, peer.pid);
+                self.owns_trading.store(false, Ordering::SeqCst);
+                handoff.register(INSTANCE_STANDBY).await
+                    .map_err(|e| anyhow::anyhow!("Failed to register instance: {}", e))?;
+            }
+            Some(peer) => {
+                return Err(anyhow::anyhow!(
+                    "Instance {} (pid {}) is already trading on this database - start with --takeover to replace it",
+                    peer.instance_id, peer.pid,
+                ));
+            }
+            None => {
+                if self.options.takeover {
+                    info!("🔁 No running instance to take over from - trading immediately");
+                }
+                handoff.register(INSTANCE_ACTIVE).await
+                    .map_err(|e| anyhow::anyhow!("Failed to register instance: {}", e))?;
+            }
+        }
+
+        // Initialize signal outbox
+        let signal_outbox = Arc::new(SignalOutbox::new(db.clone(), self.strategy_config.signal_ttl_secs));
+        signal_outbox.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize signal outbox schema: {}", e))?;
+
+        // Snapshot the active strategy configuration so trades can be grouped by era
+        let config_snapshots = Arc::new(ConfigSnapshotStore::new(db.clone()));
+        config_snapshots.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize config snapshot schema: {}", e))?;
+        let config_hash = config_snapshots.record_snapshot(self.strategy_config.as_ref()).await
+            .map_err(|e| anyhow::anyhow!("Failed to record config snapshot: {}", e))?;
+        info!("🧾 Active strategy config: {}", config_hash);
+
+        // Load named strategies; new ones from data/strategies start in shadow mode
+        let strategy_registry = Arc::new(StrategyRegistry::new(db.clone()));
+        strategy_registry.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize strategy registry schema: {}", e))?;
+        strategy_registry.load().await
+            .map_err(|e| anyhow::anyhow!("Failed to load strategies: {}", e))?;
+        match strategy_registry.import_directory(std::path::Path::new("data/strategies")).await {
+            Ok(count) if count > 0 => info!("👥 Registered {} shadow strategies", count),
+            Ok(_) => {}
+            Err(e) => warn!("Failed to import strategies: {}", e),
+        }
+        if let Some(live) = strategy_registry.live_strategy().await {
+            info!("🚀 Live strategy: {} (config {})", live.name, live.config_hash);
+            config_snapshots.record_snapshot(live.config.as_ref()).await
+                .map_err(|e| anyhow::anyhow!("Failed to record live strategy snapshot: {}", e))?;
+        }
+
+        // Resume an A/B test between registered strategies left running
+        let ab_tests = Arc::new(StrategyAbTests::new(db.clone()));
+        ab_tests.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize A/B test schema: {}", e))?;
+        match ab_tests.load(&strategy_registry).await {
+            Ok(Some(name)) => info!("🧪 Resumed A/B test {}", name),
+            Ok(None) => {}
+            Err(e) => warn!("Failed to load A/B test: {}", e),
+        }
+
+        // Signals whose execution failed on every retry, kept for requeueing
+        let dead_letters = Arc::new(DeadLetterQueue::new(db.clone(), None));
+        dead_letters.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize dead-letter schema: {}", e))?;
+        match dead_letters.dead(1000).await {
+            Ok(letters) if !letters.is_empty() => warn!("☠️ {} dead-lettered executions waiting for an operator", letters.len()),
+            Ok(_) => {}
+            Err(e) => warn!("Failed to load dead letters: {}", e),
+        }
+
+        // Combined exposure per token across strategies, capped for every entry
+        let exposure = Arc::new(ExposureLedger::new(db.clone(), Some(ExposureConfig::from_env())));
+        exposure.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize exposure schema: {}", e))?;
+        match exposure.reconcile().await {
+            Ok((removed, added)) if removed + added > 0 => info!("📐 Exposure ledger reconciled: {} closed positions dropped, {} open positions added", removed, added),
+            Ok(_) => {}
+            Err(e) => warn!("Failed to reconcile exposure ledger: {}", e),
+        }
+
+        // Initialize daily cold sweep schedule
+        let cold_sweeps = Arc::new(ColdSweepLedger::new(db.clone(), Some(SweepConfig {
+            destination: std::env::var("BADGER_COLD_WALLET").ok(),
+            ..SweepConfig::default()
+        })));
+        cold_sweeps.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize cold sweep schema: {}", e))?;
+
+        // Initialize the optional locking of realized profit into USDC
+        let profit_locks = Arc::new(ProfitLockLedger::new(db.clone(), Some(ProfitLockConfig::from_env())));
+        profit_locks.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize profit lock schema: {}", e))?;
+
+        // Initialize the book of working orders; orders survive restarts
+        let working_orders = Arc::new(WorkingOrderBook::new(db.clone(), None));
+        working_orders.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize working order schema: {}", e))?;
+        match working_orders.load().await {
+            Ok(count) if count > 0 => info!("📋 Restored {} working orders", count),
+            Ok(_) => {}
+            Err(e) => warn!("Failed to load working orders: {}", e),
+        }
+
+        // Initialize the audit log of insider wallet, blacklist and copy settings changes
+        let audit_log = AuditLog::new(db.clone());
+        audit_log.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize audit log schema: {}", e))?;
+
+        // Initialize per-insider copy settings
+        let copy_settings = Arc::new(InsiderCopySettingsStore::new(db.clone()));
+        copy_settings.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize insider copy settings schema: {}", e))?;
+
+        // Initialize insider watch groups, seeded from the watch group file when present
+        let watch_groups = Arc::new(WatchGroupStore::new(db.clone()));
+        watch_groups.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize watch group schema: {}", e))?;
+        let watch_group_file = std::env::var("BADGER_WATCH_GROUPS_FILE")
+            .unwrap_or_else(|_| "config/watch_groups.toml".to_string());
+        if std::path::Path::new(&watch_group_file).exists() {
+            match watch_groups.load_file(std::path::Path::new(&watch_group_file)).await {
+                Ok(loaded) => info!("👥 Loaded {} watch groups from {}", loaded, watch_group_file),
+                Err(e) => warn!("Failed to load watch groups: {}", e),
+            }
+        }
+
+        // Initialize the drawdown de-leveraging ladder
+        let risk_manager = Arc::new(RiskManager::new(
+            db.clone(),
+            performance_tracker.treasury(),
+            pnl_calculator.clone(),
+            Some(RiskManagerConfig::from_env()),
+        ));
+        risk_manager.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize risk manager schema: {}", e))?;
+
+        // Initialize daily fee budget
+        let fee_budget = Arc::new(FeeBudget::new(db.clone(), Some(FeeBudgetConfig::from_env())));
+        fee_budget.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize fee budget schema: {}", e))?;
+
+        // Initialize daily and weekly loss limits
+        let loss_limits = Arc::new(LossLimits::new(db.clone(), Some(LossLimitConfig::from_env())));
+        loss_limits.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize loss limit schema: {}", e))?;
+
+        // Initialize day-scoped counters and pick up today's from before a restart
+        let daily_counters = Arc::new(DailyCounters::new(db.clone()));
+        daily_counters.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize daily counter schema: {}", e))?;
+        match daily_counters.today(COUNTER_SNIPER_SPEND).await {
+            Ok(Some(spend)) => {
+                self.sniping_guard.restore_sniper_spend(spend.value);
+                info!("🧮 Restored today's sniper spend: {:.3} SOL over {} entries", spend.value, spend.events);
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Failed to restore sniper spend: {}", e),
+        }
+
+        // Initialize parsing of our own fills
+        let own_fills = Arc::new(OwnFillLedger::new(db.clone(), Some(OwnFillConfig::default())));
+        own_fills.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize own fill schema: {}", e))?;
+
+        // Initialize take-profit and stop-loss levels priced for achievable exits
+        let exit_levels = Arc::new(ExitLevelBook::new(db.clone(), Some(ExitLevelConfig::from_env())));
+        exit_levels.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize exit level schema: {}", e))?;
+
+        // Initialize the database maintenance window
+        let maintenance = Arc::new(DatabaseMaintenance::new(db.clone(), Some(MaintenanceConfig::from_env())));
+        maintenance.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize maintenance schema: {}", e))?;
+
+        // Load wallet labels shown in logs, alerts and reports
+        let wallet_label_store = Arc::new(WalletLabelStore::new(db.clone(), self.wallet_labels.clone()));
+        wallet_label_store.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize wallet label schema: {}", e))?;
+        match wallet_label_store.load().await {
+            Ok(count) if count > 0 => info!("🏷️ {} wallet labels loaded", count),
+            Ok(_) => {}
+            Err(e) => warn!("Failed to load wallet labels: {}", e),
+        }
+
+        // Initialize program labels added at runtime on top of the built-in program registry
+        let program_registry = Arc::new(ProgramRegistryStore::new(db.clone()));
+        program_registry.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize program registry schema: {}", e))?;
+        match program_registry.load().await {
+            Ok(count) if count > 0 => info!("🏷️ {} program labels loaded", count),
+            Ok(_) => {}
+            Err(e) => warn!("Failed to load program labels: {}", e),
+        }
+
+        // Initialize insider activity heatmaps (no schema; reads insider activity and outcomes)
+        let activity_heatmap = Arc::new(ActivityHeatmap::new(db.clone(), Some(HeatmapConfig::from_env())));
+        if activity_heatmap.config().scale_copy_sizes {
+            info!("🕒 Copy sizes scale with each insider's profitability by hour of day");
+        }
+
+        // Initialize copy-trade skip rules learned from losses
+        let skip_rules = Arc::new(SkipRuleLearner::new(db.clone(), None).with_cache(wallet_cache.clone()));
+        skip_rules.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize skip rule schema: {}", e))?;
+        match skip_rules.load().await {
+            Ok(count) if count > 0 => info!("🚫 {} skip rules enabled", count),
+            Ok(_) => {}
+            Err(e) => warn!("Failed to load skip rules: {}", e),
+        }
+
+        // Initialize holder distribution snapshots
+        let holder_snapshots = Arc::new(HolderSnapshotStore::new(db.clone(), None));
+        holder_snapshots.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize holder snapshot schema: {}", e))?;
+
+        // Initialize fade tracking of consistently losing wallets
+        let fade_tracker = Arc::new(FadeTracker::new(db.clone(), position_tracker.clone(), Some(FadeConfig {
+            emit_signals: std::env::var("BADGER_FADE_MODE").map(|v| v == "1" || v == "true").unwrap_or(false),
+            ..FadeConfig::default()
+        })));
+        fade_tracker.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize fade tracker schema: {}", e))?;
+        match fade_tracker.refresh_toxic_wallets().await {
+            Ok(count) if count > 0 => info!("🙃 {} toxic wallets tracked (fade signals {})", count,
+                if fade_tracker.config().emit_signals { "emitted" } else { "recorded only" }),
+            Ok(_) => {}
+            Err(e) => warn!("Failed to classify toxic wallets: {}", e),
+        }
+
+        // Initialize pre-aggregated summary tables
+        let summary_views = Arc::new(SummaryViews::new(db.clone(), None));
+        summary_views.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize summary views schema: {}", e))?;
+
+        // Initialize balance tracking of all our wallets (trading pool, cold, reserve)
+        let wallet_portfolio = Arc::new(WalletPortfolio::new(db.clone(), None));
+        wallet_portfolio.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize wallet portfolio schema: {}", e))?;
+        for (env_var, role) in [
+            ("BADGER_OWN_WALLETS", WALLET_ROLE_TRADING),
+            ("BADGER_COLD_WALLET", WALLET_ROLE_COLD),
+            ("BADGER_RESERVE_WALLETS", WALLET_ROLE_RESERVE),
+        ] {
+            let Ok(wallets) = std::env::var(env_var) else { continue };
+            for wallet in wallets.split(',').map(str::trim).filter(|w| !w.is_empty()) {
+                wallet_portfolio.register_wallet(wallet, role).await
+                    .map_err(|e| anyhow::anyhow!("Failed to register {} wallet: {}", role, e))?;
+            }
+        }
+
+        // Initialize price candles and per-token volatility/beta built from them
+        let price_candles = Arc::new(PriceCandleStore::new(db.clone(), Some(PriceCandleConfig::from_env()))
+            .with_transport_bus(self.transport_bus.clone()));
+        price_candles.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize price candle schema: {}", e))?;
+        let volatility = Arc::new(VolatilityTracker::new(db.clone(), price_candles.clone(), None));
+        volatility.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize token volatility schema: {}", e))?;
+        if let Err(e) = volatility.load().await {
+            warn!("Failed to load token volatility: {}", e);
+        }
+
+        // Initialize the cross-check of fills against independent prices
+        let fill_checks = Arc::new(FillQualityMonitor::new(db.clone(), Some(FillCheckConfig::from_env()))
+            .with_cold_store(cold_store.clone()));
+        fill_checks.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize fill check schema: {}", e))?;
+
+        // Initialize the tracking-only tax ledger, valued apart from trading P&L
+        let tax_ledger = Arc::new(TaxLedger::new(db.clone(), Some(TaxLedgerConfig::from_env())));
+        tax_ledger.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize tax ledger schema: {}", e))?;
+
+        // Initialize per-position alert rules
+        let position_alerts = Arc::new(PositionAlertMonitor::new(db.clone(), Some(PositionAlertConfig::from_env())));
+        position_alerts.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize position alert schema: {}", e))?;
+
+        // Initialize exposure hedge monitor
+        let hedge_monitor = Arc::new(
+            HedgeMonitor::new(position_tracker.clone(), pnl_calculator.clone(), None)
+                .with_volatility(volatility.clone())
+        );
+
+        // Initialize portfolio risk analytics
+        let risk_analytics = Arc::new(RiskAnalytics::new(
+            db.clone(), position_tracker.clone(), pnl_calculator.clone(), Some(wallet_portfolio.clone()), None,
+        ));
+        risk_analytics.initialize_schema().await
+            .map_err(|e| anyhow::anyhow!("Failed to initialize risk analytics schema: {}", e))?;
+
+        // Initialize volume-profile stop placement
+        let volume_profiler = Arc::new(VolumeProfiler::new(db.clone(), None));
+
+        // Store references
+        self.volume_profiler = Some(volume_profiler);
+        self.fee_budget = Some(fee_budget);
+        self.loss_limits = Some(loss_limits);
+        self.daily_counters = Some(daily_counters);
+        self.own_fills = Some(own_fills);
+        self.exit_levels = Some(exit_levels);
+        self.ab_tests = Some(ab_tests);
+        self.dead_letters = Some(dead_letters);
+        self.exposure = Some(exposure);
+        self.tax_ledger = Some(tax_ledger);
+        self.cold_store = Some(cold_store);
+        self.maintenance = Some(maintenance);
+        self.hedge_monitor = Some(hedge_monitor);
+        self.risk_analytics = Some(risk_analytics);
+        self.strategy_registry = Some(strategy_registry);
+        self.cold_sweeps = Some(cold_sweeps);
+        self.profit_locks = Some(profit_locks);
+        self.working_orders = Some(working_orders);
+        self.copy_settings = Some(copy_settings);
+        self.watch_groups = Some(watch_groups);
+        self.activity_heatmap = Some(activity_heatmap);
+        self.price_candles = Some(price_candles);
+        self.volatility = Some(volatility);
+        self.fill_checks = Some(fill_checks);
+        self.position_alerts = Some(position_alerts);
+        self.wallet_label_store = Some(wallet_label_store);
+        self.program_registry = Some(program_registry);
+        self.treasury = Some(performance_tracker.treasury());
+        self.risk_manager = Some(risk_manager);
+        self.skip_rules = Some(skip_rules);
+        self.holder_snapshots = Some(holder_snapshots);
+        self.fade_tracker = Some(fade_tracker);
+        self.summary_views = Some(summary_views);
+        self.wallet_portfolio = Some(wallet_portfolio);
+        self.config_snapshots = Some(config_snapshots);
+        self.config_hash = Some(config_hash);
+        self.control_channel = Some(control_channel);
+        self.handoff = Some(handoff);
+        self.signal_outbox = Some(signal_outbox);
+        self.position_tracker = Some(position_tracker);
+        self.pnl_calculator = Some(pnl_calculator);
+        self.performance_tracker = Some(performance_tracker);
+        self.insider_analytics = Some(insider_analytics);
+        self.wallet_discovery = Some(wallet_discovery);
+
+        info!("✅ Analytics components initialized successfully");
+        info!("   📊 Position Tracker: Ready for real-time position tracking");
+        info!("   💰 P&L Calculator: Ready for real-time profit/loss calculation");
+        info!("   📈 Performance Tracker: Ready for bot performance metrics");
+        info!("   🕵️ Insider Analytics: Ready for wallet intelligence tracking");
+        
+        Ok(())
+    }
+
+    /// Start real-time analytics reporting service (Phase 3: Task 3.1)
+    async fn start_analytics_reporting_service(&mut self) -> Result<()> {
+        info!("📊 Starting real-time analytics reporting service");
+
+        let position_tracker = self.position_tracker.clone()
+            .ok_or_else(|| anyhow::anyhow!("Position tracker not initialized"))?;
+        let pnl_calculator = self.pnl_calculator.clone()
+            .ok_or_else(|| anyhow::anyhow!("P&L calculator not initialized"))?;
+        let performance_tracker = self.performance_tracker.clone()
+            .ok_or_else(|| anyhow::anyhow!("Performance tracker not initialized"))?;
+        let insider_analytics = self.insider_analytics.clone()
+            .ok_or_else(|| anyhow::anyhow!("Insider analytics not initialized"))?;
+        let wallet_discovery = self.wallet_discovery.clone()
+            .ok_or_else(|| anyhow::anyhow!("Wallet discovery not initialized"))?;
+        let config_snapshots = self.config_snapshots.clone()
+            .ok_or_else(|| anyhow::anyhow!("Config snapshot store not initialized"))?;
+        let risk_analytics = self.risk_analytics.clone()
+            .ok_or_else(|| anyhow::anyhow!("Risk analytics not initialized"))?;
+        let strategy_registry = self.strategy_registry.clone()
+            .ok_or_else(|| anyhow::anyhow!("Strategy registry not initialized"))?;
+        let skip_rules = self.skip_rules.clone()
+            .ok_or_else(|| anyhow::anyhow!("Skip rule learner not initialized"))?;
+        let fade_tracker = self.fade_tracker.clone()
+            .ok_or_else(|| anyhow::anyhow!("Fade tracker not initialized"))?;
+        let wallet_portfolio = self.wallet_portfolio.clone()
+            .ok_or_else(|| anyhow::anyhow!("Wallet portfolio not initialized"))?;
+        let activity_heatmap = self.activity_heatmap.clone()
+            .ok_or_else(|| anyhow::anyhow!("Activity heatmap not initialized"))?;
+        let fill_checks = self.fill_checks.clone()
+            .ok_or_else(|| anyhow::anyhow!("Fill quality monitor not initialized"))?;
+        let ab_tests = self.ab_tests.clone()
+            .ok_or_else(|| anyhow::anyhow!("A/B tests not initialized"))?;
+        let control_channel = self.control_channel.clone();
+        let token_registry = self.token_registry.clone();
+        let wallet_labels = self.wallet_labels.clone();
+        let service_registry = self.service_registry.clone();
+        let warmup = self.warmup.clone();
+
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let reporting_task = tokio::spawn(async move {
+            let mut reporting_interval = tokio::time::interval(Duration::from_secs(60)); // Report every minute
+            let mut performance_interval = tokio::time::interval(Duration::from_secs(300)); // Performance every 5 minutes
+            let mut daily_interval = tokio::time::interval(Duration::from_secs(86400)); // Risk report daily
+            daily_interval.tick().await; // First daily report after a full day
+            
+            // Start a trading session
+            let session_id = match performance_tracker.start_trading_session().await {
+                Ok(id) => {
+                    info!("🚀 Started trading session: {}", id);
+                    id
+                }
+                Err(e) => {
+                    error!("Failed to start trading session: {}", e);
+                    return Err(anyhow::anyhow!("Failed to start trading session: {}", e));
+                }
+            };
+
+            loop {
+                tokio::select! {
+                    // Real-time reporting every minute
+                    _ = reporting_interval.tick() => {
+                        if let Err(e) = generate_real_time_report(
+                            &position_tracker,
+                            &pnl_calculator, 
+                            &insider_analytics,
+                            &risk_analytics,
+                            &wallet_portfolio,
+                            &token_registry,
+                            &wallet_labels,
+                            &fill_checks,
+                            &warmup,
+                        ).await {
+                            warn!("Failed to generate real-time report: {}", e);
+                        }
+                    }
+
+                    // Performance metrics every 5 minutes
+                    _ = performance_interval.tick() => {
+                        if let Err(e) = generate_performance_report(
+                            &performance_tracker,
+                            &pnl_calculator
+                        ).await {
+                            warn!("Failed to generate performance report: {}", e);
+                        }
+
+                        // Keep the price cache bounded on long-running instances
+                        match pnl_calculator.prune_prices(3600).await {
+                            Ok(evicted) if evicted > 0 => debug!("🧹 Evicted {} stale prices", evicted),
+                            Ok(_) => {}
+                            Err(e) => warn!("Failed to prune price cache: {}", e),
+                        }
+
+                        // Review wallet candidates on probation
+                        match wallet_discovery.review_probation().await {
+                            Ok(review) if review.promoted > 0 || review.rejected > 0 => {
+                                info!("🔎 Probation review: {} reviewed, {} promoted, {} rejected",
+                                    review.reviewed, review.promoted, review.rejected);
+                            }
+                            Ok(_) => {}
+                            Err(e) => warn!("Failed to review wallet candidates: {}", e),
+                        }
+
+                        // Results per configuration era
+                        match config_snapshots.get_performance_by_config().await {
+                            Ok(eras) if eras.len() > 1 => {
+                                println!("🧾 RESULTS BY CONFIGURATION:");
+                                for era in eras {
+                                    println!("   {} | Trades: {} | Closed: {} | Wins: {} | P&L: ${:.4}",
+                                        &era.config_hash[..8.min(era.config_hash.len())],
+                                        era.total_trades, era.closed_trades, era.winning_trades, era.total_pnl);
+                                }
+                            }
+                            Ok(_) => {}
+                            Err(e) => warn!("Failed to get performance by config: {}", e),
+                        }
+
+                        // Hypothetical P&L of strategies awaiting promotion
+                        match strategy_registry.get_shadow_performance().await {
+                            Ok(shadows) if !shadows.is_empty() => {
+                                println!("👥 SHADOW STRATEGIES:");
+                                for shadow in shadows {
+                                    println!("   {} | Fills: {} | Open: {} | Wins: {} | P&L: ${:.4}",
+                                        shadow.strategy_name, shadow.total_fills, shadow.open_fills,
+                                        shadow.winning_fills, shadow.realized_pnl);
+                                }
+                            }
+                            Ok(_) => {}
+                            Err(e) => warn!("Failed to get shadow strategy performance: {}", e),
+                        }
+
+                        // Arms of the running A/B test; the comparison is alerted once both have enough trades
+                        if let Some(test) = ab_tests.running().await {
+                            match ab_tests.report(test.name()).await {
+                                Ok(report) => {
+                                    println!("🧪 A/B TEST {}:", report.experiment);
+                                    for arm in &report.arms {
+                                        println!("   {} {} | Closed: {} | Open: {} ({:.4} SOL) | Wins: {} | P&L: ${:.4} (mean ${:.4}, sd ${:.4})",
+                                            arm.arm, arm.strategy, arm.closed_trades, arm.open_trades, arm.open_exposure_sol,
+                                            arm.winning_trades, arm.total_pnl, arm.mean_pnl, arm.std_pnl);
+                                    }
+                                    println!("   P&L t={:.2} p={:.3} | Win rate z={:.2} p={:.3}",
+                                        report.pnl_t_stat, report.pnl_p_value, report.win_rate_z_stat, report.win_rate_p_value);
+                                }
+                                Err(e) => warn!("Failed to report A/B test: {}", e),
+                            }
+                            match ab_tests.take_ready_report().await {
+                                Ok(Some(report)) => {
+                                    let message = report.summary();
+                                    info!("🧪 {}", message);
+                                    if let Some(control_channel) = &control_channel {
+                                        if let Err(e) = control_channel.record_alert("AB_TEST_REPORT", "ab-tests-001", &message).await {
+                                            warn!("Failed to record A/B test alert: {}", e);
+                                        }
+                                    }
+                                }
+                                Ok(None) => {}
+                                Err(e) => warn!("Failed to check A/B test report: {}", e),
+                            }
+                        }
+
+                        // Reclassify anti-insiders and report the fade track
+                        fade_tracker.prune_expired();
+                        if let Err(e) = fade_tracker.refresh_toxic_wallets().await {
+                            warn!("Failed to classify toxic wallets: {}", e);
+                        }
+                        match fade_tracker.get_fade_performance().await {
+                            Ok(fade) if fade.toxic_wallets > 0 => {
+                                println!("🙃 FADE TRACK: {} toxic wallets | Avoid flags: {} | Sell flags: {} | Flagged P&L: ${:.4}",
+                                    fade.toxic_wallets, fade.avoid_flags, fade.sell_flags, fade.flagged_position_pnl);
+                            }
+                            Ok(_) => {}
+                            Err(e) => warn!("Failed to get fade performance: {}", e),
+                        }
+
+                        // Record intraday risk and alert when VaR breaches the limit
+                        match risk_analytics.calculate_risk().await {
+                            Ok(risk) => {
+                                if let Err(e) = risk_analytics.save_risk_snapshot(&risk, "REALTIME").await {
+                                    warn!("Failed to save risk snapshot: {}", e);
+                                }
+
+                                let max_var = risk_analytics.config().max_var_99_sol;
+                                if risk.parametric_var_99 > max_var {
+                                    warn!("📉 VaR 99% {:.4} SOL exceeds limit {:.4} SOL", risk.parametric_var_99, max_var);
+                                    let alert = SystemAlert::PerformanceWarning {
+                                        metric: "var_99_sol".to_string(),
+                                        current_value: risk.parametric_var_99,
+                                        threshold: max_var,
+                                        service: "risk-analytics-001".to_string(),
+                                    };
+                                    if let Err(e) = service_registry.route_system_alert(alert, Some("risk-analytics-001")).await {
+                                        warn!("Failed to route VaR alert: {}", e);
+                                    }
+                                }
+                            }
+                            Err(e) => warn!("Failed to calculate portfolio risk: {}", e),
+                        }
+                    }
+
+                    // Daily risk, skip rule and activity heatmap reports
+                    _ = daily_interval.tick() => {
+                        if let Err(e) = generate_daily_risk_report(&risk_analytics).await {
+                            warn!("Failed to generate daily risk report: {}", e);
+                        }
+                        if let Err(e) = generate_skip_rule_report(&skip_rules).await {
+                            warn!("Failed to generate skip rule report: {}", e);
+                        }
+                        if let Err(e) = generate_activity_heatmap_report(&activity_heatmap).await {
+                            warn!("Failed to generate activity heatmap report: {}", e);
+                        }
+                    }
+
+                    // Handle shutdown
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Analytics reporting service received shutdown signal");
+                        
+                        // End the trading session
+                        if let Err(e) = performance_tracker.end_trading_session().await {
+                            warn!("Failed to end trading session cleanly: {}", e);
+                        }
+                        
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(reporting_task);
+        info!("✅ Analytics reporting service started successfully");
+        Ok(())
+    }
+
+    /// Replay fixture market events through the live pipeline (fixtures mode)
+    ///
+    /// Writes a manifest (seed, input, frozen config, code version, result digest)
+    /// to `data/replays` so the result can be reproduced with `--reproduce`.
+    async fn start_fixture_replay_service(&mut self) -> Result<()> {
+        let input = ReplayInput::load(self.options.replay_events())
+            .map_err(|e| anyhow::anyhow!("Failed to load replay events: {}", e))?;
+        let pipeline = self.market_event_pipeline();
+        let position_tracker = self.position_tracker.clone()
+            .ok_or_else(|| anyhow::anyhow!("Position tracker not initialized"))?;
+        let config_snapshots = self.config_snapshots.clone()
+            .ok_or_else(|| anyhow::anyhow!("Config snapshot store not initialized"))?;
+        let db = self.database_manager.as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Database manager not initialized"))?
+            .get_database();
+        let max_mint_exposure_sol = self.exposure.as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Exposure ledger not initialized"))?
+            .config().max_mint_exposure_sol;
+        let seed = self.options.replay_seed();
+        let expected = self.options.reproduce.clone();
+        let chaos_profile = self.options.chaos.clone();
+
+        // Freeze the config the whole replay runs with
+        let (strategy_config, _) = pipeline.live_strategy().await;
+
+        // Distinct buy-generating input events per token; more entries than this is a double buy
+        let mut entry_event_ids = std::collections::HashSet::new();
+        let mut entry_events: HashMap<String, usize> = HashMap::new();
+        for event in &input.events {
+            if let Some(TradingSignal::Buy { token_mint, .. }) = generate_basic_trading_signal(event, &strategy_config) {
+                if entry_event_ids.insert(event.get_event_id()) {
+                    *entry_events.entry(token_mint).or_default() += 1;
+                }
+            }
+        }
+        let config_hash = config_snapshots.record_snapshot(strategy_config.as_ref()).await
+            .map_err(|e| anyhow::anyhow!("Failed to record replay config snapshot: {}", e))?;
+
+        info!("🧪 Replaying {} market events from {} (seed {}, config {})",
+            input.events.len(), input.source, seed, &config_hash[..8.min(config_hash.len())]);
+
+        let replay_task = tokio::spawn(async move {
+            // Fixture events stand in for WebSocket notifications when faults are injected
+            let mut previous: Option<MarketEvent> = None;
+            for event in input.events.iter().cloned() {
+                if chaos::drop_ws_message() {
+                    continue;
+                }
+                if chaos::reconnect_due() {
+                    // A resubscribe can deliver the last notification again
+                    if let Some(previous) = previous.take() {
+                        pipeline.process(previous, "fixture-replay").await;
+                    }
+                }
+                previous = Some(event.clone());
+                pipeline.process(event, "fixture-replay").await;
+            }
+
+            let summary = position_tracker.get_position_summary().await?;
+            info!("🧪 Fixture replay complete: {} positions ({} open)",
+                summary.total_positions, summary.open_positions);
+
+            let mut manifest = ReplayManifest::capture(&db, seed, &input, strategy_config.as_ref()).await?;
+            manifest.invariant_violations = check_replay_invariants(&db, &entry_events, max_mint_exposure_sol).await?;
+            manifest.chaos_profile = chaos_profile;
+            for violation in &manifest.invariant_violations {
+                warn!("❌ Replay invariant {} broken: {}", violation.invariant, violation.detail);
+            }
+            if let (Some(profile), true) = (&manifest.chaos_profile, manifest.invariant_violations.is_empty()) {
+                info!("✅ Replay degraded safely under chaos profile {}", profile);
+            }
+            let path = manifest.write()?;
+            info!("🧾 Replay manifest written to {} (result {})", path.display(), manifest.result_digest);
+
+            if let Some(expected) = expected {
+                if expected.result_digest == manifest.result_digest && expected.config_hash == manifest.config_hash {
+                    info!("✅ Replay reproduced result {}", expected.result_digest);
+                } else {
+                    warn!("❌ Replay diverged: expected result {} (config {}), got {} (config {})",
+                        expected.result_digest, expected.config_hash, manifest.result_digest, manifest.config_hash);
+                }
+            }
+            Ok(())
+        });
+
+        self.tasks.push(replay_task);
+        Ok(())
+    }
+
+    /// Start release of working orders whose time or price has come
+    ///
+    /// Released orders go through the same gates and risk checks as any other
+    /// signal; cancelled orders never reach this loop.
+    async fn start_working_order_service(&mut self) -> Result<()> {
+        info!("📋 Starting working order service");
+
+        let working_orders = self.working_orders.clone()
+            .ok_or_else(|| anyhow::anyhow!("Working order book not initialized"))?;
+        let pnl_calculator = self.pnl_calculator.clone()
+            .ok_or_else(|| anyhow::anyhow!("P&L calculator not initialized"))?;
+        let fee_budget = self.fee_budget.clone()
+            .ok_or_else(|| anyhow::anyhow!("Fee budget not initialized"))?;
+        let owns_trading = self.owns_trading.clone();
+        let pipeline = self.market_event_pipeline();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let order_task = tokio::spawn(async move {
+            let mut poll_interval = tokio::time::interval(
+                Duration::from_secs(working_orders.config().poll_interval_secs)
+            );
+
+            loop {
+                tokio::select! {
+                    _ = poll_interval.tick() => {
+                        // Orders are released by the instance that trades
+                        if !owns_trading.load(Ordering::SeqCst) {
+                            continue;
+                        }
+                        let prices = pnl_calculator.cached_prices().await;
+                        // Rebalances wait for tomorrow's fee budget once today's is nearly spent
+                        let held_kinds: &[&str] = match fee_budget.throttled().await {
+                            Ok(true) => &[ORDER_KIND_REBALANCE],
+                            Ok(false) => &[],
+                            Err(e) => {
+                                warn!("Failed to check fee budget: {}", e);
+                                &[]
+                            }
+                        };
+                        let released = match working_orders.release_due(&prices, held_kinds).await {
+                            Ok(released) => released,
+                            Err(e) => {
+                                warn!("Failed to release working orders: {}", e);
+                                continue;
+                            }
+                        };
+
+                        for order in released {
+                            info!("📋 Releasing working order #{} {} {} {}", order.id, order.kind, order.side, order.token_mint);
+                            let price = prices.get(&order.token_mint).copied();
+                            pipeline.process_working_order(order, price).await;
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Working order service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(order_task);
+        info!("✅ Working order service started successfully");
+        Ok(())
+    }
+
+    /// Start replay of dead-lettered executions an operator requeued
+    async fn start_dead_letter_service(&mut self) -> Result<()> {
+        info!("☠️ Starting dead-letter replay service");
+
+        let dead_letters = self.dead_letters.clone()
+            .ok_or_else(|| anyhow::anyhow!("Dead-letter queue not initialized"))?;
+        let owns_trading = self.owns_trading.clone();
+        let pipeline = self.market_event_pipeline();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let replay_task = tokio::spawn(async move {
+            let mut poll_interval = tokio::time::interval(
+                Duration::from_secs(dead_letters.config().poll_interval_secs)
+            );
+
+            loop {
+                tokio::select! {
+                    _ = poll_interval.tick() => {
+                        // Letters are replayed by the instance that trades
+                        if !owns_trading.load(Ordering::SeqCst) {
+                            continue;
+                        }
+                        let letters = match dead_letters.take_requeued().await {
+                            Ok(letters) => letters,
+                            Err(e) => {
+                                warn!("Failed to take requeued dead letters: {}", e);
+                                continue;
+                            }
+                        };
+                        for letter in letters {
+                            pipeline.process_dead_letter(letter).await;
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Dead-letter replay service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(replay_task);
+        info!("✅ Dead-letter replay service started successfully");
+        Ok(())
+    }
+
+    /// Start the startup backfill of insider activity missed while badger was down
+    ///
+    /// Copy trading stays gated until the backfill finishes, so entries are not
+    /// taken on win rates with a blind spot. Nothing to backfill in fixtures mode.
+    async fn start_insider_backfill_service(&mut self) -> Result<()> {
+        let insider_analytics = match (&self.insider_analytics, self.options.fixtures) {
+            (Some(insider_analytics), false) => insider_analytics.clone(),
+            _ => {
+                self.insider_backfill_done.store(true, Ordering::SeqCst);
+                return Ok(());
+            }
+        };
+        info!("⏪ Starting insider activity backfill - copy trading held until complete");
+
+        let endpoint_prober = self.endpoint_prober.clone();
+        let insider_backfill_done = self.insider_backfill_done.clone();
+
+        let backfill_task = tokio::spawn(async move {
+            let started = std::time::Instant::now();
+            let rpc_client = endpoint_prober.analytics_client(CommitmentConsumer::Analytics);
+            let (wallets, recorded) = backfill_insider_activity(
+                &rpc_client,
+                &insider_analytics,
+                &InsiderBackfillConfig::default(),
+            ).await;
+
+            insider_backfill_done.store(true, Ordering::SeqCst);
+            info!("✅ Insider backfill complete: {} activities from {} insiders in {:.1}s - copy trading enabled",
+                recorded, wallets, started.elapsed().as_secs_f64());
+            Ok(())
+        });
+
+        self.tasks.push(backfill_task);
+        Ok(())
+    }
+
+    /// Start the operator control service used by badger-tui
+    ///
+    /// Applies queued PAUSE/RESUME/CLOSE_POSITION commands and records system
+    /// alerts so external tools can display them.
+    async fn start_operator_control_service(&mut self) -> Result<()> {
+        info!("🎛️ Starting operator control service");
+
+        let control_channel = self.control_channel.clone()
+            .ok_or_else(|| anyhow::anyhow!("Control channel not initialized"))?;
+        let services = OperatorServices {
+            pipeline: self.market_event_pipeline(),
+            trading_paused: self.trading_paused.clone(),
+            owns_trading: self.owns_trading.clone(),
+            handoff: self.handoff.clone()
+                .ok_or_else(|| anyhow::anyhow!("Handoff coordinator not initialized"))?,
+            position_tracker: self.position_tracker.clone()
+                .ok_or_else(|| anyhow::anyhow!("Position tracker not initialized"))?,
+            strategy_registry: self.strategy_registry.clone()
+                .ok_or_else(|| anyhow::anyhow!("Strategy registry not initialized"))?,
+            config_snapshots: self.config_snapshots.clone()
+                .ok_or_else(|| anyhow::anyhow!("Config snapshot store not initialized"))?,
+            cold_sweeps: self.cold_sweeps.clone()
+                .ok_or_else(|| anyhow::anyhow!("Cold sweep ledger not initialized"))?,
+            profit_locks: self.profit_locks.clone()
+                .ok_or_else(|| anyhow::anyhow!("Profit lock ledger not initialized"))?,
+            skip_rules: self.skip_rules.clone()
+                .ok_or_else(|| anyhow::anyhow!("Skip rule learner not initialized"))?,
+            working_orders: self.working_orders.clone()
+                .ok_or_else(|| anyhow::anyhow!("Working order book not initialized"))?,
+            copy_settings: self.copy_settings.clone()
+                .ok_or_else(|| anyhow::anyhow!("Insider copy settings not initialized"))?,
+            watch_groups: self.watch_groups.clone()
+                .ok_or_else(|| anyhow::anyhow!("Watch groups not initialized"))?,
+            program_registry: self.program_registry.clone()
+                .ok_or_else(|| anyhow::anyhow!("Program registry not initialized"))?,
+            treasury: self.treasury.clone()
+                .ok_or_else(|| anyhow::anyhow!("Treasury ledger not initialized"))?,
+            wallet_labels: self.wallet_label_store.clone()
+                .ok_or_else(|| anyhow::anyhow!("Wallet label store not initialized"))?,
+            ab_tests: self.ab_tests.clone()
+                .ok_or_else(|| anyhow::anyhow!("A/B tests not initialized"))?,
+            dead_letters: self.dead_letters.clone()
+                .ok_or_else(|| anyhow::anyhow!("Dead-letter queue not initialized"))?,
+        };
+        let mut system_alerts = self.transport_bus.subscribe_system_alerts().await;
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let control_task = tokio::spawn(async move {
+            let mut poll_interval = tokio::time::interval(Duration::from_secs(1));
+
+            loop {
+                tokio::select! {
+                    _ = poll_interval.tick() => {
+                        // Commands are left for the instance that trades
+                        if !services.owns_trading.load(Ordering::SeqCst) {
+                            continue;
+                        }
+                        let commands = match control_channel.get_pending_commands().await {
+                            Ok(commands) => commands,
+                            Err(e) => {
+                                warn!("Failed to fetch operator commands: {}", e);
+                                continue;
+                            }
+                        };
+
+                        for command in commands {
+                            let outcome = apply_operator_command(&command, &services).await;
+
+                            let (success, result) = match outcome {
+                                Ok(message) => {
+                                    info!("🎛️ Operator command #{} {}: {}", command.id, command.command, message);
+                                    (true, message)
+                                }
+                                Err(e) => {
+                                    warn!("Operator command #{} {} failed: {}", command.id, command.command, e);
+                                    (false, e.to_string())
+                                }
+                            };
+
+                            if let Err(e) = control_channel.complete_command(command.id, success, &result).await {
+                                warn!("Failed to complete operator command #{}: {}", command.id, e);
+                            }
+                        }
+                    }
+
+                    Ok(alert) = system_alerts.recv() => {
+                        let (alert_type, service, message) = describe_system_alert(&alert);
+                        if let Err(e) = control_channel.record_alert(alert_type, &service, &message).await {
+                            warn!("Failed to record system alert: {}", e);
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Operator control service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(control_task);
+        info!("✅ Operator control service started successfully");
+        Ok(())
+    }
+
+    /// Start the rolling deployment handoff service
+    ///
+    /// Heartbeats this instance. A standby instance (`--takeover`) queues a
+    /// HANDOFF command for the running one, waits until it has retired (or
+    /// stopped heartbeating), then reloads the shared state and starts
+    /// trading. An active instance that has handed over signals the process
+    /// to shut down.
+    async fn start_handoff_service(&mut self) -> Result<()> {
+        info!("🔁 Starting handoff service");
+
+        let handoff = self.handoff.clone()
+            .ok_or_else(|| anyhow::anyhow!("Handoff coordinator not initialized"))?;
+        let control_channel = self.control_channel.clone()
+            .ok_or_else(|| anyhow::anyhow!("Control channel not initialized"))?;
+        let position_tracker = self.position_tracker.clone()
+            .ok_or_else(|| anyhow::anyhow!("Position tracker not initialized"))?;
+        let working_orders = self.working_orders.clone()
+            .ok_or_else(|| anyhow::anyhow!("Working order book not initialized"))?;
+        let copy_settings = self.copy_settings.clone()
+            .ok_or_else(|| anyhow::anyhow!("Insider copy settings not initialized"))?;
+        let watch_groups = self.watch_groups.clone()
+            .ok_or_else(|| anyhow::anyhow!("Watch groups not initialized"))?;
+        let skip_rules = self.skip_rules.clone()
+            .ok_or_else(|| anyhow::anyhow!("Skip rule learner not initialized"))?;
+        let wallet_label_store = self.wallet_label_store.clone()
+            .ok_or_else(|| anyhow::anyhow!("Wallet label store not initialized"))?;
+        let trading_paused = self.trading_paused.clone();
+        let owns_trading = self.owns_trading.clone();
+        let retired = self.retired.clone();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        // The instance being replaced, while this one stands by
+        let mut predecessor = None;
+        if !owns_trading.load(Ordering::SeqCst) {
+            predecessor = handoff.active_peer().await
+                .map_err(|e| anyhow::anyhow!("Failed to fetch running instance: {}", e))?
+                .map(|peer| peer.instance_id);
+            match &predecessor {
+                Some(predecessor_id) => {
+                    control_channel.submit_command(COMMAND_HANDOFF, Some(handoff.instance_id())).await
+                        .map_err(|e| anyhow::anyhow!("Failed to request handoff: {}", e))?;
+                    info!("🔁 Handoff requested from {}", predecessor_id);
+                }
+                None => {
+                    // The running instance stopped since startup; nothing to wait for
+                    handoff.activate().await
+                        .map_err(|e| anyhow::anyhow!("Failed to activate instance: {}", e))?;
+                    owns_trading.store(true, Ordering::SeqCst);
+                    info!("🔁 Running instance is gone - trading immediately");
+                }
+            }
+        }
+
+        let handoff_task = tokio::spawn(async move {
+            let mut heartbeat_interval = tokio::time::interval(
+                Duration::from_secs(handoff.config().heartbeat_secs)
+            );
+
+            loop {
+                tokio::select! {
+                    _ = heartbeat_interval.tick() => {
+                        if let Err(e) = handoff.heartbeat().await {
+                            warn!("Failed to record instance heartbeat: {}", e);
+                            continue;
+                        }
+
+                        if owns_trading.load(Ordering::SeqCst) {
+                            continue;
+                        }
+                        if predecessor.is_none() {
+                            // Handed over by this instance - nothing more to do here
+                            match handoff.current().await {
+                                Ok(Some(ProcessInstance { status, handed_to: Some(successor), .. })) if status == INSTANCE_RETIRED => {
+                                    info!("🔁 Trading handed to {}", successor);
+                                    retired.notify_one();
+                                    break;
+                                }
+                                Ok(_) => {}
+                                Err(e) => warn!("Failed to read instance status: {}", e),
+                            }
+                        }
+
+                        // Standing by: wait for the predecessor to retire or go away
+                        let Some(predecessor_id) = predecessor.clone() else {
+                            continue;
+                        };
+                        let instance = match handoff.get(&predecessor_id).await {
+                            Ok(instance) => instance,
+                            Err(e) => {
+                                warn!("Failed to read status of {}: {}", predecessor_id, e);
+                                continue;
+                            }
+                        };
+                        let state = match &instance {
+                            Some(instance) if instance.status == INSTANCE_RETIRED
+                                && instance.handed_to.as_deref() == Some(handoff.instance_id()) => {
+                                instance.handoff_state.as_deref()
+                                    .and_then(|state| serde_json::from_str::<HandoffState>(state).ok())
+                            }
+                            Some(instance) if instance.status == INSTANCE_ACTIVE && !handoff.is_stale(instance) => continue,
+                            _ => {
+                                warn!("🔁 {} went away without handing over - taking over", predecessor_id);
+                                None
+                            }
+                        };
+
+                        // Pick up whatever the predecessor changed while this instance stood by
+                        let paused = match &state {
+                            Some(state) => state.trading_paused,
+                            None => control_channel.is_paused().await.unwrap_or(false),
+                        };
+                        trading_paused.store(paused, Ordering::SeqCst);
+                        let restored_orders = working_orders.load().await.unwrap_or_else(|e| {
+                            warn!("Failed to reload working orders: {}", e);
+                            0
+                        });
+                        copy_settings.invalidate().await;
+                        watch_groups.invalidate().await;
+                        if let Err(e) = skip_rules.load().await {
+                            warn!("Failed to reload skip rules: {}", e);
+                        }
+                        if let Err(e) = wallet_label_store.load().await {
+                            warn!("Failed to reload wallet labels: {}", e);
+                        }
+                        let open_positions = position_tracker.get_open_positions().await
+                            .map(|positions| positions.len())
+                            .unwrap_or_default();
+                        if let Some(state) = &state {
+                            if state.open_positions != open_positions || state.working_orders != restored_orders {
+                                warn!("🔁 Handoff state mismatch: {} open positions and {} working orders handed over, {} and {} found",
+                                    state.open_positions, state.working_orders, open_positions, restored_orders);
+                            }
+                        }
+
+                        if let Err(e) = handoff.activate().await {
+                            warn!("Failed to activate instance: {}", e);
+                            continue;
+                        }
+                        owns_trading.store(true, Ordering::SeqCst);
+                        predecessor = None;
+
+                        let message = format!("{} took over trading from {}: {} open positions, {} working orders{}",
+                            handoff.instance_id(), predecessor_id, open_positions, restored_orders,
+                            if paused { ", paused" } else { "" });
+                        info!("🔁 {}", message);
+                        if let Err(e) = control_channel.record_alert("HANDOFF", "handoff-001", &message).await {
+                            warn!("Failed to record handoff alert: {}", e);
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Handoff service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(handoff_task);
+        info!("✅ Handoff service started successfully");
+        Ok(())
+    }
+
+    /// Start the exposure hedge service
+    ///
+    /// Emits hedge recommendations on the transport bus when aggregate exposure
+    /// crosses the threshold, and forwards them to a hedging adapter when
+    /// `BADGER_HEDGE_WEBHOOK_URL` is set.
+    async fn start_hedge_service(&mut self) -> Result<()> {
+        info!("🛡️ Starting exposure hedge service");
+
+        let hedge_monitor = self.hedge_monitor.clone()
+            .ok_or_else(|| anyhow::anyhow!("Hedge monitor not initialized"))?;
+        let adapter: Option<Arc<dyn HedgingAdapter>> = std::env::var("BADGER_HEDGE_WEBHOOK_URL")
+            .ok()
+            .map(|url| Arc::new(WebhookHedgingAdapter::new(&url)) as Arc<dyn HedgingAdapter>);
+        let service_registry = self.service_registry.clone();
+        let owns_trading = self.owns_trading.clone();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        if let Some(adapter) = &adapter {
+            info!("🛡️ Hedge recommendations will be forwarded to {} adapter", adapter.name());
+        }
+
+        let hedge_task = tokio::spawn(async move {
+            let mut check_interval = tokio::time::interval(
+                Duration::from_secs(hedge_monitor.config().check_interval_secs)
+            );
+
+            loop {
+                tokio::select! {
+                    _ = check_interval.tick() => {
+                        if !owns_trading.load(Ordering::SeqCst) {
+                            continue;
+                        }
+                        let recommendation = match hedge_monitor.evaluate().await {
+                            Ok(Some(recommendation)) => recommendation,
+                            Ok(None) => continue,
+                            Err(e) => {
+                                warn!("Failed to evaluate hedge exposure: {}", e);
+                                continue;
+                            }
+                        };
+
+                        let signal = recommendation.to_signal();
+                        display_trading_signal(&signal);
+                        if let Err(e) = service_registry.route_trading_signal(signal, Some("hedge-monitor-001")).await {
+                            warn!("Failed to route hedge recommendation: {}", e);
+                        }
+
+                        if let Some(adapter) = &adapter {
+                            match adapter.submit_hedge(&recommendation).await {
+                                Ok(reference) => info!("🛡️ Hedge submitted via {}: {}", adapter.name(), reference),
+                                Err(e) => {
+                                    warn!("Hedging adapter {} failed: {}", adapter.name(), e);
+                                    // Hedge was not placed - keep the previous size so it is retried
+                                    hedge_monitor.set_current_hedge(recommendation.current_hedge_sol).await;
+                                }
+                            }
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Hedge service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(hedge_task);
+        info!("✅ Exposure hedge service started successfully");
+        Ok(())
+    }
+
+    /// Start the RPC endpoint prober
+    ///
+    /// Measures RPC/WebSocket latency and slot lag per endpoint every round and
+    /// moves the execution path to the lowest-lag endpoint.
+    async fn start_endpoint_probe_service(&mut self) -> Result<()> {
+        info!("⚡ Starting RPC endpoint probe service");
+
+        let prober = self.endpoint_prober.clone();
+        let service_registry = self.service_registry.clone();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let probe_task = tokio::spawn(async move {
+            let mut probe_interval = tokio::time::interval(
+                Duration::from_secs(prober.config().probe_interval_secs)
+            );
+
+            loop {
+                tokio::select! {
+                    _ = probe_interval.tick() => {
+                        let round = prober.probe().await;
+                        for result in &round.results {
+                            debug!("⚡ {} | RPC {:?}ms | WS {:?}ms | lag {:?} slots",
+                                redact_url(&result.endpoint.http_url),
+                                result.rpc_latency_ms.map(|l| l.round()),
+                                result.ws_latency_ms.map(|l| l.round()),
+                                result.slot_lag);
+                        }
+
+                        if let Some((old_value, new_value)) = round.execution_switch {
+                            let alert = SystemAlert::ConfigurationChange {
+                                setting: "execution_rpc".to_string(),
+                                old_value,
+                                new_value,
+                                service: "endpoint-prober-001".to_string(),
+                            };
+                            if let Err(e) = service_registry.route_system_alert(alert, Some("endpoint-prober-001")).await {
+                                warn!("Failed to route endpoint switch alert: {}", e);
+                            }
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Endpoint probe service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(probe_task);
+        info!("✅ RPC endpoint probe service started successfully");
+        Ok(())
+    }
+
+    /// Start execution venue health checks
+    ///
+    /// One round runs before trading starts so a broken quote API key or RPC
+    /// submission path is caught before the first entry; rounds then repeat on
+    /// an interval and every status change is raised as an operator alert.
+    /// Skipped in fixtures mode so replays stay offline.
+    async fn start_venue_health_service(&mut self) -> Result<()> {
+        if self.options.fixtures {
+            return Ok(());
+        }
+        info!("🩺 Starting venue health service");
+
+        let venue_health = self.venue_health.clone();
+        let prober = self.endpoint_prober.clone();
+        let control_channel = self.control_channel.clone()
+            .ok_or_else(|| anyhow::anyhow!("Control channel not initialized"))?;
+
+        // Pre-trade check
+        let round = venue_health.probe(&prober.execution_endpoint().http_url).await;
+        for health in round.venues.iter().chain(round.submission.iter()) {
+            let name = health.venue.map_or("rpc", |venue| venue.name());
+            match health.status {
+                VenueStatus::Healthy => info!("🩺 {} healthy ({:.0}ms)", name, health.latency_ms.unwrap_or_default()),
+                status => {
+                    let message = format!("{} {:?} before trading: {}", name, status,
+                        health.last_error.as_deref().unwrap_or("slow response"));
+                    warn!("🩺 {}", message);
+                    if let Err(e) = control_channel.record_alert("VENUE_HEALTH", "venue-health-001", &message).await {
+                        warn!("Failed to record venue health alert: {}", e);
+                    }
+                }
+            }
+        }
+        if let Some(reason) = venue_health.trading_blocker() {
+            error!("🩺 New entries are held: {}", reason);
+        }
+
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+        let venue_task = tokio::spawn(async move {
+            let mut probe_interval = tokio::time::interval(
+                Duration::from_secs(venue_health.config().probe_interval_secs)
+            );
+            // The pre-trade round just ran
+            probe_interval.tick().await;
+
+            loop {
+                tokio::select! {
+                    _ = probe_interval.tick() => {
+                        let round = venue_health.probe(&prober.execution_endpoint().http_url).await;
+                        for (name, before, after) in round.changes {
+                            let error = round.venues.iter().chain(round.submission.iter())
+                                .find(|health| health.venue.map_or("rpc", |venue| venue.name()) == name)
+                                .and_then(|health| health.last_error.clone());
+                            let message = match error {
+                                Some(error) => format!("{} {:?} -> {:?}: {}", name, before, after, error),
+                                None => format!("{} {:?} -> {:?}", name, before, after),
+                            };
+                            if let Err(e) = control_channel.record_alert("VENUE_HEALTH", "venue-health-001", &message).await {
+                                warn!("Failed to record venue health alert: {}", e);
+                            }
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Venue health service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(venue_task);
+        info!("✅ Venue health service started successfully");
+        Ok(())
+    }
+
+    /// Start accounting of realized P&L against the daily and weekly loss limits
+    ///
+    /// Every PositionReduced and PositionClosed event adds its realized P&L to
+    /// the counters of the day and week it happened in. An alert is raised the
+    /// first time a limit is reached in a period; entries are refused in
+    /// `route_signal` until the period rolls over.
+    async fn start_loss_limit_service(&mut self) -> Result<()> {
+        let loss_limits = self.loss_limits.clone()
+            .ok_or_else(|| anyhow::anyhow!("Loss limits not initialized"))?;
+        let control_channel = self.control_channel.clone()
+            .ok_or_else(|| anyhow::anyhow!("Control channel not initialized"))?;
+        info!("🧯 Starting loss limit service ({} per-strategy limits, UTC{})",
+              loss_limits.config().strategies.len(), loss_limits.config().utc_offset);
+
+        let mut position_events = self.transport_bus.subscribe_position_events().await;
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let loss_task = tokio::spawn(async move {
+            let mut alerted: HashSet<(String, String, String)> = HashSet::new();
+
+            loop {
+                tokio::select! {
+                    event = position_events.recv() => {
+                        let (position_id, realized_pnl, timestamp) = match event {
+                            Ok(PositionEvent::PositionReduced { position_id, realized_pnl, timestamp, .. })
+                            | Ok(PositionEvent::PositionClosed { position_id, realized_pnl, timestamp, .. }) => (position_id, realized_pnl, timestamp),
+                            Ok(_) => continue,
+                            Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                                warn!("Loss limit service lagged, {} position events not counted", skipped);
+                                continue;
+                            }
+                            Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                        };
+
+                        let strategy = match loss_limits.record_exit(position_id, realized_pnl, timestamp).await {
+                            Ok(strategy) => strategy,
+                            Err(e) => {
+                                warn!("Failed to count realized P&L of position #{}: {}", position_id, e);
+                                continue;
+                            }
+                        };
+                        if realized_pnl >= 0.0 {
+                            continue;
+                        }
+
+                        let breach = match loss_limits.breach(&strategy).await {
+                            Ok(Some(breach)) => breach,
+                            Ok(None) => continue,
+                            Err(e) => {
+                                warn!("Failed to check loss limits: {}", e);
+                                continue;
+                            }
+                        };
+                        if !alerted.insert((breach.strategy.clone(), breach.period.clone(), breach.period_start.clone())) {
+                            continue;
+                        }
+                        let message = format!(
+                            "{} {} loss limit reached: {:.4} realized since {} (limit {:.4}); entries halted until the period rolls over",
+                            breach.scope(), breach.period.to_lowercase(), breach.realized_pnl, breach.period_start, breach.limit
+                        );
+                        warn!("🧯 {}", message);
+                        if let Err(e) = control_channel.record_alert("LOSS_LIMIT", "loss-limit-001", &message).await {
+                            warn!("Failed to record loss limit alert: {}", e);
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Loss limit service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(loss_task);
+        info!("✅ Loss limit service started successfully");
+        Ok(())
+    }
+
+    /// Start upkeep of the per-token exposure ledger
+    ///
+    /// Sales shrink a position's exposure by the share of it sold and a close
+    /// drops it, freeing room under the cap for new entries.
+    async fn start_exposure_service(&mut self) -> Result<()> {
+        let exposure = self.exposure.clone()
+            .ok_or_else(|| anyhow::anyhow!("Exposure ledger not initialized"))?;
+        info!("📐 Starting exposure service ({:.2} SOL per token across strategies)", exposure.config().max_mint_exposure_sol);
+
+        let mut position_events = self.transport_bus.subscribe_position_events().await;
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let exposure_task = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    event = position_events.recv() => {
+                        let result = match event {
+                            Ok(PositionEvent::PositionReduced { position_id, closed_quantity, remaining_quantity, .. }) => {
+                                exposure.record_reduced(position_id, closed_quantity, remaining_quantity).await
+                            }
+                            Ok(PositionEvent::PositionClosed { position_id, .. }) => exposure.record_closed(position_id).await,
+                            Ok(_) => continue,
+                            Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                                // Exits missed here are caught up by the reconcile on the next start
+                                warn!("Exposure service lagged, {} position events missed", skipped);
+                                continue;
+                            }
+                            Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                        };
+                        if let Err(e) = result {
+                            warn!("Failed to update exposure: {}", e);
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Exposure service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(exposure_task);
+        info!("✅ Exposure service started successfully");
+        Ok(())
+    }
+
+    /// Start mirroring of position events into the tracking-only tax ledger
+    ///
+    /// Fills are recorded here and valued in the ledger currency by the
+    /// market data service, at the SOL price when they executed.
+    async fn start_tax_ledger_service(&mut self) -> Result<()> {
+        let tax_ledger = self.tax_ledger.clone()
+            .ok_or_else(|| anyhow::anyhow!("Tax ledger not initialized"))?;
+        info!("🧮 Starting tax ledger service (fills valued in {})", tax_ledger.config().currency);
+
+        let mut position_events = self.transport_bus.subscribe_position_events().await;
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let tax_task = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    event = position_events.recv() => {
+                        match event {
+                            Ok(event) => {
+                                if let Err(e) = tax_ledger.record_event(&event).await {
+                                    warn!("Failed to record tax fill of position #{}: {}", event.position_id(), e);
+                                }
+                            }
+                            Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                                warn!("Tax ledger service lagged, {} position events missed", skipped);
+                            }
+                            Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Tax ledger service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(tax_task);
+        info!("✅ Tax ledger service started successfully");
+        Ok(())
+    }
+
+    /// Start parsing of our own fills into exact position accounting
+    ///
+    /// Swaps by our wallets queued from the stream are fetched once confirmed
+    /// and decoded from pre/post balances and the fee. A buy replaces the
+    /// estimated entry price, quantity and fees of its open position; a sell
+    /// replaces the exit price and P&L of its closed position once the entry
+    /// fill is known too.
+    async fn start_own_fill_service(&mut self) -> Result<()> {
+        if self.self_trade_guard.wallet_count() == 0 || self.options.fixtures {
+            debug!("No trading wallets configured - own fill parsing disabled");
+            return Ok(());
+        }
+        info!("🧾 Starting own fill parsing service");
+
+        let own_fills = self.own_fills.clone()
+            .ok_or_else(|| anyhow::anyhow!("Own fill ledger not initialized"))?;
+        let position_tracker = self.position_tracker.clone()
+            .ok_or_else(|| anyhow::anyhow!("Position tracker not initialized"))?;
+        let pnl_calculator = self.pnl_calculator.clone()
+            .ok_or_else(|| anyhow::anyhow!("P&L calculator not initialized"))?;
+        let exit_levels = self.exit_levels.clone()
+            .ok_or_else(|| anyhow::anyhow!("Exit level book not initialized"))?;
+        let endpoint_prober = self.endpoint_prober.clone();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let fill_task = tokio::spawn(async move {
+            let mut poll_interval = tokio::time::interval(Duration::from_secs(own_fills.config().poll_interval_secs));
+
+            loop {
+                tokio::select! {
+                    _ = poll_interval.tick() => {
+                        let pending = match own_fills.pending().await {
+                            Ok(pending) => pending,
+                            Err(e) => {
+                                warn!("Failed to load pending own fills: {}", e);
+                                continue;
+                            }
+                        };
+                        if pending.is_empty() {
+                            continue;
+                        }
+
+                        let rpc_client = endpoint_prober.analytics_client(CommitmentConsumer::Accounting);
+                        for fill in pending {
+                            let trade = match fetch_wallet_trade(&rpc_client, &fill.wallet, &fill.signature).await {
+                                Ok(Some(trade)) => trade,
+                                // Not a plain SOL <-> token swap; keep the estimate
+                                Ok(None) => {
+                                    debug!("🧾 Own transaction {} is not a swap we can parse", fill.signature);
+                                    if let Err(e) = own_fills.record_failed(&fill.signature, true).await {
+                                        warn!("Failed to record own fill attempt: {}", e);
+                                    }
+                                    continue;
+                                }
+                                // Usually not confirmed yet
+                                Err(e) => {
+                                    debug!("🧾 Own fill {} not fetched yet: {}", fill.signature, e);
+                                    if let Err(e) = own_fills.record_failed(&fill.signature, false).await {
+                                        warn!("Failed to record own fill attempt: {}", e);
+                                    }
+                                    continue;
+                                }
+                            };
+
+                            let position_id = match own_fills.position_for(&fill).await {
+                                Ok(position_id) => position_id,
+                                Err(e) => {
+                                    warn!("Failed to match own fill {}: {}", fill.signature, e);
+                                    None
+                                }
+                            };
+                            let applied = match (position_id, fill.side.as_str()) {
+                                (Some(position_id), "BUY") => {
+                                    match position_tracker.apply_entry_fill(position_id, trade.price_sol, trade.token_amount, trade.fee_sol).await {
+                                        Ok(applied) => {
+                                            if applied {
+                                                pnl_calculator.update_price(&trade.token_mint, trade.price_sol).await;
+                                                // Re-price exit levels from the real entry
+                                                if let Ok(Some(levels)) = exit_levels.levels_for(position_id).await {
+                                                    if let Err(e) = exit_levels.set_for_position(
+                                                        position_id, &trade.token_mint, trade.price_sol, levels.fee_rate,
+                                                    ).await {
+                                                        warn!("Failed to re-price exit levels of position #{}: {}", position_id, e);
+                                                    }
+                                                }
+                                            }
+                                            applied
+                                        }
+                                        Err(e) => {
+                                            warn!("Failed to apply entry fill to position #{}: {}", position_id, e);
+                                            false
+                                        }
+                                    }
+                                }
+                                (Some(position_id), _) => match own_fills.entry_fill(position_id).await {
+                                    // Exit P&L needs the real entry fees too
+                                    Ok(Some(entry)) => {
+                                        let fees = entry.fee_sol.unwrap_or_default() + trade.fee_sol;
+                                        match position_tracker.apply_exit_fill(position_id, trade.price_sol, fees).await {
+                                            Ok(pnl) => pnl.is_some(),
+                                            Err(e) => {
+                                                warn!("Failed to apply exit fill to position #{}: {}", position_id, e);
+                                                false
+                                            }
+                                        }
+                                    }
+                                    Ok(None) => false,
+                                    Err(e) => {
+                                        warn!("Failed to load entry fill of position #{}: {}", position_id, e);
+                                        false
+                                    }
+                                },
+                                (None, _) => false,
+                            };
+
+                            forensics::trace(&trade.token_mint, "own_fill", serde_json::json!({
+                                "fill": trade,
+                                "position_id": position_id,
+                                "applied": applied,
+                            }));
+                            if let Err(e) = own_fills.record_parsed(
+                                &fill.signature, trade.token_amount, trade.sol_amount, trade.fee_sol, trade.slot,
+                                position_id.filter(|_| applied),
+                            ).await {
+                                warn!("Failed to record own fill {}: {}", fill.signature, e);
+                            }
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Own fill parsing service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(fill_task);
+        info!("✅ Own fill parsing service started successfully");
+        Ok(())
+    }
+
+    /// Start fee spend tracking of our trading wallets
+    ///
+    /// New transactions paid by our wallets are scanned for network fees,
+    /// priority fees and Jito tips and booked to the day they landed. An alert
+    /// is raised once per day when spend crosses the throttle share of the
+    /// budget; from then on rebalances, cold sweeps and dust cleanup are held.
+    async fn start_fee_budget_service(&mut self) -> Result<()> {
+        if self.self_trade_guard.wallet_count() == 0 || self.options.fixtures {
+            debug!("No trading wallets configured - fee budget tracking disabled");
+            return Ok(());
+        }
+        info!("⛽ Starting fee budget service");
+
+        let fee_budget = self.fee_budget.clone()
+            .ok_or_else(|| anyhow::anyhow!("Fee budget not initialized"))?;
+        let control_channel = self.control_channel.clone()
+            .ok_or_else(|| anyhow::anyhow!("Control channel not initialized"))?;
+        let self_trade_guard = self.self_trade_guard.clone();
+        let endpoint_prober = self.endpoint_prober.clone();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let fee_task = tokio::spawn(async move {
+            let mut scan_interval = tokio::time::interval(
+                Duration::from_secs(fee_budget.config().scan_interval_secs)
+            );
+            let scan_config = InsiderBackfillConfig::default();
+            let mut alerted_date: Option<String> = None;
+
+            loop {
+                tokio::select! {
+                    _ = scan_interval.tick() => {
+                        let rpc_client = endpoint_prober.analytics_client(CommitmentConsumer::Accounting);
+                        for wallet in self_trade_guard.wallets() {
+                            let last = match fee_budget.last_signature(&wallet).await {
+                                Ok(last) => last,
+                                Err(e) => {
+                                    warn!("Failed to load last fee signature of {}: {}", wallet, e);
+                                    continue;
+                                }
+                            };
+                            let signatures = match signatures_since(&rpc_client, &wallet, last.as_deref(), &scan_config).await {
+                                Ok(signatures) => signatures,
+                                Err(e) => {
+                                    warn!("Failed to fetch signatures of {}: {}", wallet, e);
+                                    continue;
+                                }
+                            };
+
+                            for signature in signatures {
+                                match fetch_fee_spend(&rpc_client, &wallet, &signature).await {
+                                    Ok(Some(spend)) => {
+                                        let spend = NewFeeSpend {
+                                            tx_signature: spend.signature,
+                                            wallet_address: wallet.clone(),
+                                            network_sol: spend.network_sol,
+                                            priority_sol: spend.priority_sol,
+                                            jito_tip_sol: spend.jito_tip_sol,
+                                            slot: spend.slot,
+                                            block_time: spend.block_time,
+                                        };
+                                        if let Err(e) = fee_budget.record(&spend).await {
+                                            warn!("Failed to record fee spend: {}", e);
+                                        }
+                                    }
+                                    Ok(None) => {}
+                                    Err(e) => debug!("Failed to decode fees of {}: {}", signature, e),
+                                }
+                            }
+                        }
+
+                        let spend = match fee_budget.spend_today().await {
+                            Ok(spend) => spend,
+                            Err(e) => {
+                                warn!("Failed to sum fee spend: {}", e);
+                                continue;
+                            }
+                        };
+                        debug!("⛽ Fees today: {:.6} / {:.4} SOL ({} txs)", spend.total_sol, spend.budget_sol, spend.transactions);
+
+                        if spend.used() >= fee_budget.config().throttle_at && alerted_date.as_deref() != Some(spend.spend_date.as_str()) {
+                            let message = format!(
+                                "Fee spend {:.4} of {:.4} SOL today (network {:.4}, priority {:.4}, Jito {:.4}); holding rebalances, sweeps and dust cleanup",
+                                spend.total_sol, spend.budget_sol, spend.network_sol, spend.priority_sol, spend.jito_tip_sol
+                            );
+                            warn!("⛽ {}", message);
+                            if let Err(e) = control_channel.record_alert("FEE_BUDGET", "fee-budget-001", &message).await {
+                                warn!("Failed to record fee budget alert: {}", e);
+                            }
+                            alerted_date = Some(spend.spend_date);
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Fee budget service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(fee_task);
+        info!("✅ Fee budget service started successfully");
+        Ok(())
+    }
+
+    /// Start eviction of stale insider cache entries
+    ///
+    /// Profiles and launch times left idle are dropped from memory on an
+    /// interval (the memory ceiling is enforced on every insert), and cache
+    /// occupancy and eviction counts are logged. With a hot wallet store the
+    /// store is also reconciled against SQLite, starting right away so a
+    /// store left over from another database is corrected early.
+    async fn start_insider_cache_service(&mut self) -> Result<()> {
+        info!("🧹 Starting insider cache service");
+
+        let insider_analytics = self.insider_analytics.clone()
+            .ok_or_else(|| anyhow::anyhow!("Insider analytics not initialized"))?;
+        let cache = insider_analytics.cache();
+        let warmup = self.warmup.clone();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let cache_task = tokio::spawn(async move {
+            let mut evict_interval = tokio::time::interval(Duration::from_secs(600));
+            let mut reconcile_interval = tokio::time::interval(Duration::from_secs(cache.config().reconcile_interval_secs));
+
+            loop {
+                tokio::select! {
+                    _ = reconcile_interval.tick(), if cache.store().is_some() => {
+                        match insider_analytics.reconcile_wallet_store().await {
+                            Ok(Some(pass)) if pass.added + pass.corrected + pass.removed > 0 || pass.compacted => {
+                                info!("🔥 Hot wallet store reconciled: {} profiles, {} added, {} corrected, {} removed{}",
+                                    pass.checked, pass.added, pass.corrected, pass.removed,
+                                    if pass.compacted { ", compacted" } else { "" });
+                            }
+                            Ok(_) => debug!("Hot wallet store matches SQLite"),
+                            Err(e) => warn!("Failed to reconcile hot wallet store: {}", e),
+                        }
+                        // Retry a warm-up that failed at startup once the store is readable
+                        if !warmup.insider_cache_loaded() {
+                            match cache.warm_up() {
+                                Ok(loaded) => {
+                                    info!("🔥 Warmed insider cache with {} profiles", loaded);
+                                    warmup.mark_insider_cache_loaded();
+                                }
+                                Err(e) => warn!("Failed to warm insider cache: {}", e),
+                            }
+                        }
+                    }
+
+                    _ = evict_interval.tick() => {
+                        let evicted = cache.evict_stale();
+                        let stats = cache.stats();
+                        info!("🧹 Insider cache: {} profiles, {} launches, {:.1} MB ({} stale evicted now; {} LRU / {} stale evicted total, hit rate {:.0}%)",
+                            stats.profiles, stats.launches, stats.memory_bytes as f64 / (1024.0 * 1024.0),
+                            evicted, stats.evicted_lru, stats.evicted_stale,
+                            stats.hits as f64 * 100.0 / (stats.hits + stats.misses).max(1) as f64);
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Insider cache service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(cache_task);
+        info!("✅ Insider cache service started successfully");
+        Ok(())
+    }
+
+    /// Start the drawdown de-leveraging ladder
+    ///
+    /// Equity is re-evaluated on an interval; every tier change is raised as
+    /// an operator alert so a shrinking (or restored) book is never a surprise.
+    async fn start_risk_manager_service(&mut self) -> Result<()> {
+        info!("📉 Starting risk manager service");
+
+        let risk_manager = self.risk_manager.clone()
+            .ok_or_else(|| anyhow::anyhow!("Risk manager not initialized"))?;
+        let control_channel = self.control_channel.clone()
+            .ok_or_else(|| anyhow::anyhow!("Control channel not initialized"))?;
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let risk_task = tokio::spawn(async move {
+            let mut refresh_interval = tokio::time::interval(
+                Duration::from_secs(risk_manager.config().refresh_interval_secs)
+            );
+
+            loop {
+                tokio::select! {
+                    _ = refresh_interval.tick() => {
+                        let limits = match risk_manager.refresh().await {
+                            Ok((limits, true)) => limits,
+                            Ok(_) => continue,
+                            Err(e) => {
+                                warn!("Failed to refresh risk limits: {}", e);
+                                continue;
+                            }
+                        };
+                        let message = if limits.tier == 0 {
+                            format!("Drawdown {:.1}% from peak {:.4} SOL: full position sizes restored",
+                                limits.drawdown * 100.0, limits.peak_equity_sol)
+                        } else {
+                            format!("Drawdown {:.1}% from peak {:.4} SOL: tier {}, sizes x{:.2}{}",
+                                limits.drawdown * 100.0, limits.peak_equity_sol, limits.tier, limits.size_multiplier,
+                                limits.max_open_positions.map(|n| format!(", at most {} open positions", n)).unwrap_or_default())
+                        };
+                        warn!("📉 {}", message);
+                        if let Err(e) = control_channel.record_alert("DELEVERAGE", "risk-manager-001", &message).await {
+                            warn!("Failed to record de-leveraging alert: {}", e);
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Risk manager service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(risk_task);
+        info!("✅ Risk manager service started successfully");
+        Ok(())
+    }
+
+    /// Start the database maintenance window
+    ///
+    /// Once per low-activity window the database gets an integrity check,
+    /// incremental vacuum and statistics refresh with batch writers paused.
+    /// Analytics rows past the hot window move to the cold store first, so
+    /// the vacuum reclaims their pages. A failed integrity check raises an
+    /// operator alert.
+    async fn start_database_maintenance_service(&mut self) -> Result<()> {
+        info!("🧰 Starting database maintenance service");
+
+        let maintenance = self.maintenance.clone()
+            .ok_or_else(|| anyhow::anyhow!("Database maintenance not initialized"))?;
+        let cold_store = self.cold_store.clone()
+            .ok_or_else(|| anyhow::anyhow!("Cold store not initialized"))?;
+        let control_channel = self.control_channel.clone()
+            .ok_or_else(|| anyhow::anyhow!("Control channel not initialized"))?;
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let maintenance_task = tokio::spawn(async move {
+            let mut check_interval = tokio::time::interval(
+                Duration::from_secs(maintenance.config().check_interval_secs)
+            );
+
+            loop {
+                tokio::select! {
+                    _ = check_interval.tick() => {
+                        match maintenance.is_due(Utc::now()).await {
+                            Ok(true) => {}
+                            Ok(false) => continue,
+                            Err(e) => {
+                                warn!("Failed to check maintenance schedule: {}", e);
+                                continue;
+                            }
+                        }
+
+                        if let Err(e) = cold_store.move_due().await {
+                            error!("❌ Failed to move analytics history to the cold store: {}", e);
+                        }
+
+                        let run = match maintenance.run().await {
+                            Ok(run) => run,
+                            Err(e) => {
+                                error!("❌ Database maintenance failed: {}", e);
+                                continue;
+                            }
+                        };
+                        if !run.integrity_ok {
+                            let message = format!(
+                                "Integrity check failed{}: {}",
+                                if run.reindexed { " (indexes rebuilt)" } else { "" },
+                                run.integrity_errors.as_deref().unwrap_or_default().lines().take(5).collect::<Vec<_>>().join("; ")
+                            );
+                            error!("🧰 {}", message);
+                            if let Err(e) = control_channel.record_alert("DATABASE_INTEGRITY", "maintenance-001", &message).await {
+                                warn!("Failed to record integrity alert: {}", e);
+                            }
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Database maintenance service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(maintenance_task);
+        info!("✅ Database maintenance service started successfully");
+        Ok(())
+    }
+
+    /// Start the daily cold sweep schedule
+    ///
+    /// Once per day at the configured UTC hour, realized profit above the float is
+    /// proposed for transfer to cold storage and waits for operator approval.
+    async fn start_cold_sweep_service(&mut self) -> Result<()> {
+        info!("🧊 Starting daily cold sweep service");
+
+        let cold_sweeps = self.cold_sweeps.clone()
+            .ok_or_else(|| anyhow::anyhow!("Cold sweep ledger not initialized"))?;
+        let profit_locks = self.profit_locks.clone()
+            .ok_or_else(|| anyhow::anyhow!("Profit lock ledger not initialized"))?;
+        let pnl_calculator = self.pnl_calculator.clone()
+            .ok_or_else(|| anyhow::anyhow!("P&L calculator not initialized"))?;
+        let control_channel = self.control_channel.clone()
+            .ok_or_else(|| anyhow::anyhow!("Control channel not initialized"))?;
+        let fee_budget = self.fee_budget.clone()
+            .ok_or_else(|| anyhow::anyhow!("Fee budget not initialized"))?;
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let sweep_task = tokio::spawn(async move {
+            let mut check_interval = tokio::time::interval(Duration::from_secs(300));
+
+            loop {
+                tokio::select! {
+                    _ = check_interval.tick() => {
+                        // Today's sweep is unique per date, so later checks are no-ops
+                        if Utc::now().hour() < cold_sweeps.config().sweep_hour_utc {
+                            continue;
+                        }
+                        if fee_budget.throttled().await.unwrap_or(false) {
+                            debug!("⛽ Fee budget nearly spent - cold sweep held");
+                            continue;
+                        }
+
+                        let realized = match pnl_calculator.calculate_portfolio_pnl().await {
+                            Ok(portfolio) => portfolio.total_realized_pnl,
+                            Err(e) => {
+                                warn!("Failed to calculate realized P&L for cold sweep: {}", e);
+                                continue;
+                            }
+                        };
+
+                        // Profit already converted to USDC is not swept again as SOL
+                        let locked = match profit_locks.total_locked_sol().await {
+                            Ok(locked) => locked,
+                            Err(e) => {
+                                warn!("Failed to load locked profit for cold sweep: {}", e);
+                                continue;
+                            }
+                        };
+
+                        match cold_sweeps.propose_daily_sweep(realized - locked).await {
+                            Ok(Some(sweep)) => {
+                                let message = format!(
+                                    "Sweep #{} of {:.4} SOL awaiting approval (APPROVE_SWEEP {})",
+                                    sweep.id, sweep.amount_sol, sweep.id
+                                );
+                                if let Err(e) = control_channel.record_alert("COLD_SWEEP", "cold-sweep-001", &message).await {
+                                    warn!("Failed to record cold sweep alert: {}", e);
+                                }
+                            }
+                            Ok(None) => {}
+                            Err(e) => warn!("Failed to propose cold sweep: {}", e),
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Cold sweep service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(sweep_task);
+        info!("✅ Daily cold sweep service started successfully");
+        Ok(())
+    }
+
+    /// Start the profit lock policy
+    ///
+    /// When realized profit grows past the last lock, the configured share of
+    /// the new profit is proposed for conversion to USDC at the latest SOL price
+    /// and the operator is alerted to execute it. Off unless
+    /// `BADGER_PROFIT_LOCK_SHARE` is set.
+    async fn start_profit_lock_service(&mut self) -> Result<()> {
+        let profit_locks = self.profit_locks.clone()
+            .ok_or_else(|| anyhow::anyhow!("Profit lock ledger not initialized"))?;
+        if !profit_locks.config().is_enabled() {
+            return Ok(());
+        }
+        if profit_locks.config().destination.is_none() {
+            warn!("🔒 Profit lock disabled: no destination wallet (BADGER_OWN_WALLETS or BADGER_COLD_WALLET)");
+            return Ok(());
+        }
+        info!("🔒 Starting profit lock service ({:.0}% of new profit to USDC)", profit_locks.config().lock_share * 100.0);
+
+        let pnl_calculator = self.pnl_calculator.clone()
+            .ok_or_else(|| anyhow::anyhow!("P&L calculator not initialized"))?;
+        let price_candles = self.price_candles.clone()
+            .ok_or_else(|| anyhow::anyhow!("Price candle store not initialized"))?;
+        let control_channel = self.control_channel.clone()
+            .ok_or_else(|| anyhow::anyhow!("Control channel not initialized"))?;
+        let fee_budget = self.fee_budget.clone()
+            .ok_or_else(|| anyhow::anyhow!("Fee budget not initialized"))?;
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let lock_task = tokio::spawn(async move {
+            let mut check_interval = tokio::time::interval(Duration::from_secs(300));
+
+            loop {
+                tokio::select! {
+                    _ = check_interval.tick() => {
+                        if fee_budget.throttled().await.unwrap_or(false) {
+                            debug!("⛽ Fee budget nearly spent - profit lock held");
+                            continue;
+                        }
+
+                        // USD value of the conversion, from the last hour's SOL candles
+                        let since = Utc::now().timestamp() - 3600;
+                        let sol_usd = match price_candles.candles(SOL_MINT, since).await {
+                            Ok(candles) => candles.last().map(|candle| candle.close),
+                            Err(e) => {
+                                warn!("Failed to load SOL price for profit lock: {}", e);
+                                continue;
+                            }
+                        };
+                        let Some(sol_usd) = sol_usd else {
+                            debug!("No recent SOL price - profit lock held");
+                            continue;
+                        };
+
+                        let realized = match pnl_calculator.calculate_portfolio_pnl().await {
+                            Ok(portfolio) => portfolio.total_realized_pnl,
+                            Err(e) => {
+                                warn!("Failed to calculate realized P&L for profit lock: {}", e);
+                                continue;
+                            }
+                        };
+
+                        match profit_locks.propose(realized, sol_usd).await {
+                            Ok(Some(lock)) => {
+                                let message = format!(
+                                    "Profit lock #{}: convert {:.4} SOL to ~{:.2} USDC into {} (COMPLETE_PROFIT_LOCK or FAIL_PROFIT_LOCK {})",
+                                    lock.id, lock.amount_sol, lock.expected_usdc, lock.destination, lock.id
+                                );
+                                if let Err(e) = control_channel.record_alert("PROFIT_LOCK", "profit-lock-001", &message).await {
+                                    warn!("Failed to record profit lock alert: {}", e);
+                                }
+                            }
+                            Ok(None) => {}
+                            Err(e) => warn!("Failed to propose profit lock: {}", e),
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Profit lock service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(lock_task);
+        info!("✅ Profit lock service started successfully");
+        Ok(())
+    }
+
+    /// Start chain-lag and clock skew monitoring of the event stream
+    ///
+    /// Compares the websocket stream with the analytics RPC endpoint, alerts
+    /// operators when it lags or the local clock drifts, and has the ingest
+    /// loop fail over to the next websocket URL when the lag persists.
+    async fn start_chain_lag_service(&mut self) -> Result<()> {
+        info!("⏱️ Starting chain-lag monitoring service");
+
+        let chain_lag = self.chain_lag.clone();
+        let endpoint_prober = self.endpoint_prober.clone();
+        let control_channel = self.control_channel.clone()
+            .ok_or_else(|| anyhow::anyhow!("Control channel not initialized"))?;
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let lag_task = tokio::spawn(async move {
+            let mut check_interval = tokio::time::interval(
+                Duration::from_secs(chain_lag.config().check_interval_secs)
+            );
+            let mut was_lagging = false;
+
+            loop {
+                tokio::select! {
+                    _ = check_interval.tick() => {
+                        let rpc_client = endpoint_prober.analytics_client(CommitmentConsumer::Analytics);
+                        let sample = match chain_lag.check(&rpc_client).await {
+                            Ok(Some(sample)) => sample,
+                            Ok(None) => continue,
+                            Err(e) => {
+                                warn!("Failed to check chain lag: {}", e);
+                                continue;
+                            }
+                        };
+
+                        let mut messages = Vec::new();
+                        if sample.lagging && !was_lagging {
+                            messages.push(format!(
+                                "Event stream {:.1}s behind chain (slot {} vs {}, idle {:.1}s); buys held",
+                                sample.stream_lag_secs, sample.stream_slot, sample.chain_slot, sample.idle_secs
+                            ));
+                        } else if !sample.lagging && was_lagging {
+                            messages.push(format!("Event stream caught up with chain (lag {:.1}s)", sample.stream_lag_secs));
+                        }
+                        if sample.failover {
+                            messages.push("Lag persisted; failing over ingest endpoint".to_string());
+                        }
+                        if sample.clock_skewed {
+                            messages.push(format!("Local clock skewed {:.1}s from chain block time",
+                                sample.clock_skew_secs.unwrap_or_default()));
+                        }
+                        was_lagging = sample.lagging;
+
+                        for message in messages {
+                            warn!("⏱️ {}", message);
+                            if let Err(e) = control_channel.record_alert("CHAIN_LAG", "chain-lag-001", &message).await {
+                                warn!("Failed to record chain lag alert: {}", e);
+                            }
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Chain-lag monitoring service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(lag_task);
+        info!("✅ Chain-lag monitoring service started successfully");
+        Ok(())
+    }
+
+    /// Start balance refresh of all tracked wallets
+    ///
+    /// Reads every registered wallet in one batched lookup per cycle and
+    /// stores a snapshot, feeding the aggregated wallet views and risk math.
+    async fn start_wallet_portfolio_service(&mut self) -> Result<()> {
+        info!("👛 Starting wallet portfolio tracking service");
+
+        let wallet_portfolio = self.wallet_portfolio.clone()
+            .ok_or_else(|| anyhow::anyhow!("Wallet portfolio not initialized"))?;
+        let endpoint_prober = self.endpoint_prober.clone();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let portfolio_task = tokio::spawn(async move {
+            let mut refresh_interval = tokio::time::interval(
+                Duration::from_secs(wallet_portfolio.config().refresh_interval_secs)
+            );
+
+            loop {
+                tokio::select! {
+                    _ = refresh_interval.tick() => {
+                        let wallets = match wallet_portfolio.wallets().await {
+                            Ok(wallets) if !wallets.is_empty() => wallets,
+                            Ok(_) => continue,
+                            Err(e) => {
+                                warn!("Failed to load portfolio wallets: {}", e);
+                                continue;
+                            }
+                        };
+                        let pubkeys: Vec<(String, Pubkey)> = wallets.into_iter()
+                            .filter_map(|(address, _)| Pubkey::from_str(&address).ok().map(|pubkey| (address, pubkey)))
+                            .collect();
+
+                        let rpc_client = Arc::new(endpoint_prober.analytics_client(CommitmentConsumer::Accounting));
+                        let balance_cache = BalanceCache::new(rpc_client, endpoint_prober.commitments().accounting);
+                        let accounts: Vec<Pubkey> = pubkeys.iter().map(|(_, pubkey)| *pubkey).collect();
+                        let slot = match balance_cache.fetch(&accounts).await {
+                            Ok(slot) => slot,
+                            Err(e) => {
+                                warn!("Failed to fetch wallet balances: {}", e);
+                                continue;
+                            }
+                        };
+
+                        let balances: Vec<(String, u64)> = pubkeys.into_iter()
+                            .map(|(address, pubkey)| {
+                                let lamports = balance_cache.get(&pubkey).map_or(0, |entry| entry.lamports());
+                                (address, lamports)
+                            })
+                            .collect();
+                        if let Err(e) = wallet_portfolio.record_balances(&balances, slot).await {
+                            warn!("Failed to record wallet balances: {}", e);
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Wallet portfolio service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(portfolio_task);
+        info!("✅ Wallet portfolio tracking service started successfully");
+        Ok(())
+    }
+
+    /// Start price refresh of open positions from the market-data pool
+    ///
+    /// DexScreener (and Birdeye when `BADGER_BIRDEYE_API_KEY` is set) quotes
+    /// feed the P&L price cache that mark-to-market, the outcome resolver and
+    /// risk reports read. Held tokens, and tokens working orders wait on a
+    /// price trigger for, are polled by `PollScheduler` within the pool's
+    /// request budget: held and volatile tokens more often, tokens whose
+    /// polls fail backed off. Skipped in fixtures mode so replays stay offline.
+    async fn start_market_data_service(&mut self) -> Result<()> {
+        if self.options.fixtures {
+            return Ok(());
+        }
+        info!("💹 Starting market data price service");
+
+        let market_data = Arc::new(MarketDataPool::from_env(MarketDataConfig::default())?);
+        let position_tracker = self.position_tracker.clone()
+            .ok_or_else(|| anyhow::anyhow!("Position tracker not initialized"))?;
+        let pnl_calculator = self.pnl_calculator.clone()
+            .ok_or_else(|| anyhow::anyhow!("P&L calculator not initialized"))?;
+        let price_candles = self.price_candles.clone()
+            .ok_or_else(|| anyhow::anyhow!("Price candle store not initialized"))?;
+        let volatility = self.volatility.clone()
+            .ok_or_else(|| anyhow::anyhow!("Volatility tracker not initialized"))?;
+        let fill_checks = self.fill_checks.clone()
+            .ok_or_else(|| anyhow::anyhow!("Fill quality monitor not initialized"))?;
+        let working_orders = self.working_orders.clone()
+            .ok_or_else(|| anyhow::anyhow!("Working order book not initialized"))?;
+        let tax_ledger = self.tax_ledger.clone()
+            .ok_or_else(|| anyhow::anyhow!("Tax ledger not initialized"))?;
+        let scheduler = PollScheduler::new(Some(PollSchedulerConfig::from_env()));
+        let warmup = self.warmup.clone();
+        let pool_reserves = self.pool_reserves.clone();
+        self.market_data = Some(market_data.clone());
+        let mut candle_events = self.transport_bus.subscribe_candle_events().await;
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let price_task = tokio::spawn(async move {
+            // Which tokens to poll changes slowly; when to poll each is checked every second
+            let mut schedule_interval = tokio::time::interval(Duration::from_secs(10));
+            let mut poll_interval = tokio::time::interval(Duration::from_secs(1));
+            let mut prune_interval = tokio::time::interval(
+                Duration::from_secs(price_candles.config().prune_interval_secs)
+            );
+            prune_interval.tick().await;
+            let mut fill_check_interval = tokio::time::interval(
+                Duration::from_secs(fill_checks.config().check_interval_secs)
+            );
+            let mut tax_interval = tokio::time::interval(
+                Duration::from_secs(tax_ledger.config().check_interval_secs)
+            );
+            // Latest implied SOL/USD price with its provider and time, for valuing fills
+            let mut last_sol_usd: Option<(f64, String, i64)> = None;
+
+            loop {
+                tokio::select! {
+                    _ = schedule_interval.tick() => {
+                        let positions = match position_tracker.get_open_positions().await {
+                            Ok(positions) => positions,
+                            Err(e) => {
+                                warn!("Failed to load open positions for pricing: {}", e);
+                                continue;
+                            }
+                        };
+                        let mut held: Vec<String> = positions.into_iter().map(|p| p.token_mint).collect();
+                        held.sort();
+                        held.dedup();
+                        let mut watched: Vec<String> = working_orders.working_orders().await.into_iter()
+                            .filter(|order| order.trigger_price.is_some())
+                            .map(|order| order.token_mint)
+                            .collect();
+                        watched.sort();
+                        watched.dedup();
+
+                        scheduler.sync(PollPriority::OpenPosition, &held);
+                        scheduler.sync(PollPriority::Watched, &watched);
+                        for token_mint in held.iter().chain(&watched) {
+                            if let Some(token_volatility) = volatility.get(token_mint).await {
+                                scheduler.set_volatility(token_mint, token_volatility.daily_volatility);
+                            }
+                        }
+                        market_data.prune_cache(3600);
+                        let reserve_stats = pool_reserves.stats();
+                        debug!("💧 Reserve pricing: {} pools cached, {} prices from reserves, {} fell back to quotes",
+                            reserve_stats.pools, reserve_stats.hits, reserve_stats.misses);
+                    }
+
+                    _ = poll_interval.tick() => {
+                        let due = scheduler.due(market_data.available_requests());
+                        if due.is_empty() {
+                            continue;
+                        }
+
+                        let now = Utc::now().timestamp();
+                        price_candles.close_due(now).await;
+                        let mut sol_usd = None;
+                        for token_mint in due {
+                            // Fresh reserves from the stream price a token without spending a quote
+                            let price_sol = match pool_reserves.price(&token_mint) {
+                                Some(reserve_price) => Some(reserve_price.price_sol),
+                                None => match market_data.get(&token_mint).await {
+                                    Ok(Some(data)) => {
+                                        if let Some(price_usd) = data.price_usd.filter(|_| data.price_sol > 0.0) {
+                                            sol_usd.get_or_insert((price_usd / data.price_sol, data.provider.clone()));
+                                        }
+                                        Some(data.price_sol)
+                                    }
+                                    Ok(None) => {
+                                        debug!("No market data for {}", token_mint);
+                                        None
+                                    }
+                                    Err(e) => {
+                                        warn!("Failed to price {}: {}", token_mint, e);
+                                        None
+                                    }
+                                },
+                            };
+                            scheduler.record(&token_mint, price_sol.is_some());
+                            let Some(price_sol) = price_sol else { continue };
+                            pnl_calculator.update_price(&token_mint, price_sol).await;
+                            if let Err(e) = position_tracker.update_position_price(&token_mint, price_sol).await {
+                                warn!("Failed to update position price for {}: {}", token_mint, e);
+                            }
+                            match price_candles.record(&token_mint, price_sol, now).await {
+                                Ok(()) => warmup.record_price_sample(now),
+                                Err(e) => warn!("Failed to record price candle for {}: {}", token_mint, e),
+                            }
+                        }
+                        // SOL's USD price, implied by any token quoted in both, is the beta reference
+                        if let Some((sol_usd, provider)) = sol_usd {
+                            if let Err(e) = price_candles.record(SOL_MINT, sol_usd, now).await {
+                                warn!("Failed to record SOL price candle: {}", e);
+                            }
+                            last_sol_usd = Some((sol_usd, provider, now));
+                        }
+                    }
+
+                    // Volatility and beta move only when a stored candle closes
+                    Ok(event) = candle_events.recv() => {
+                        let CandleEvent::CandleClosed { token_mint, timeframe_secs, .. } = event;
+                        if timeframe_secs != price_candles.config().candle_secs || token_mint == SOL_MINT {
+                            continue;
+                        }
+                        if let Err(e) = volatility.refresh(&token_mint).await {
+                            warn!("Failed to compute volatility of {}: {}", token_mint, e);
+                        }
+                    }
+
+                    _ = prune_interval.tick() => {
+                        if let Err(e) = price_candles.prune().await {
+                            warn!("Failed to prune price candles: {}", e);
+                        }
+                    }
+
+                    _ = fill_check_interval.tick() => {
+                        let pending = match fill_checks.pending().await {
+                            Ok(pending) => pending,
+                            Err(e) => {
+                                warn!("Failed to load pending fill checks: {}", e);
+                                continue;
+                            }
+                        };
+                        for fill in pending {
+                            let now = Utc::now().timestamp();
+                            let resolved = if fill_checks.within_skew(&fill, now) {
+                                match market_data.get(&fill.token_mint).await {
+                                    Ok(Some(data)) => fill_checks.resolve(&fill, data.price_sol, &data.provider, now).await.map(Some),
+                                    Ok(None) => Ok(None),
+                                    Err(e) => {
+                                        debug!("No oracle price for fill #{} of {}: {}", fill.id, fill.token_mint, e);
+                                        Ok(None)
+                                    }
+                                }
+                            } else {
+                                // Too late for a live quote to stand for the fill; use the candle covering it
+                                fill_checks.resolve_from_candles(&fill, &price_candles).await
+                            };
+                            match resolved {
+                                Ok(Some(checked)) => report_fill_check(&checked),
+                                Ok(None) => {}
+                                Err(e) => warn!("Failed to check fill #{}: {}", fill.id, e),
+                            }
+                        }
+                    }
+
+                    _ = tax_interval.tick() => {
+                        let pending = match tax_ledger.pending().await {
+                            Ok(pending) => pending,
+                            Err(e) => {
+                                warn!("Failed to load pending tax fills: {}", e);
+                                continue;
+                            }
+                        };
+                        for fill in pending {
+                            let priced = match &last_sol_usd {
+                                Some((sol_usd, provider, quoted_at)) if tax_ledger.within_skew(&fill, *quoted_at) => {
+                                    tax_ledger.price(&fill, *sol_usd, provider, *quoted_at).await.map(Some)
+                                }
+                                // No quote near the fill; use the SOL candle covering it
+                                _ => tax_ledger.price_from_candles(&fill, &price_candles).await,
+                            };
+                            match priced {
+                                Ok(Some(priced)) => report_tax_fill(&priced),
+                                Ok(None) => {}
+                                Err(e) => warn!("Failed to value tax fill #{}: {}", fill.id, e),
+                            }
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Market data price service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(price_task);
+        info!("✅ Market data price service started successfully");
+        Ok(())
+    }
+
+    /// Start stop monitoring of open positions at volume-profile support
+    ///
+    /// Each held token's stop sits just below the nearest high-volume price
+    /// node under the current price, built from stored swaps. Stops only
+    /// ratchet up as price climbs into new nodes; a price at or below the stop
+    /// exits the position. Tokens without enough trades keep no stop here.
+    /// Positions with take-profit or stop-loss levels (see `ExitLevelBook`)
+    /// are sold when the price crosses one.
+    async fn start_position_monitor_service(&mut self) -> Result<()> {
+        info!("🛑 Starting position monitor service");
+
+        let volume_profiler = self.volume_profiler.clone()
+            .ok_or_else(|| anyhow::anyhow!("Volume profiler not initialized"))?;
+        let position_tracker = self.position_tracker.clone()
+            .ok_or_else(|| anyhow::anyhow!("Position tracker not initialized"))?;
+        let pnl_calculator = self.pnl_calculator.clone()
+            .ok_or_else(|| anyhow::anyhow!("P&L calculator not initialized"))?;
+        let token_registry = self.token_registry.clone();
+        let metadata_monitor = self.metadata_monitor.clone();
+        let control_channel = self.control_channel.clone();
+        let pipeline = self.market_event_pipeline();
+        let position_alerts = pipeline.position_alerts.clone()
+            .ok_or_else(|| anyhow::anyhow!("Position alerts not initialized"))?;
+        let exit_levels = pipeline.exit_levels.clone()
+            .ok_or_else(|| anyhow::anyhow!("Exit level book not initialized"))?;
+        let stop_timeframe_secs = self.price_candles.as_ref()
+            .map(|price_candles| price_candles.config().candle_secs);
+        let mut candle_events = self.transport_bus.subscribe_candle_events().await;
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let monitor_task = tokio::spawn(async move {
+            let mut check_interval = tokio::time::interval(
+                Duration::from_secs(volume_profiler.config().check_interval_secs)
+            );
+            let mut stops: HashMap<String, f64> = HashMap::new();
+
+            loop {
+                tokio::select! {
+                    _ = check_interval.tick() => {
+                        let positions = match position_tracker.get_open_positions().await {
+                            Ok(positions) => positions,
+                            Err(e) => {
+                                warn!("Failed to load open positions for stop monitoring: {}", e);
+                                continue;
+                            }
+                        };
+
+                        let mut token_mints: Vec<String> = positions.iter().map(|p| p.token_mint.clone()).collect();
+                        token_mints.sort();
+                        token_mints.dedup();
+                        stops.retain(|token_mint, _| token_mints.contains(token_mint));
+                        position_alerts.retain_open(&positions.iter().map(|p| p.id).collect::<Vec<_>>());
+                        let open_levels = if exit_levels.config().is_enabled() {
+                            match exit_levels.open_levels().await {
+                                Ok(levels) => levels,
+                                Err(e) => {
+                                    warn!("Failed to load exit levels: {}", e);
+                                    Vec::new()
+                                }
+                            }
+                        } else {
+                            Vec::new()
+                        };
+
+                        for token_mint in token_mints {
+                            let Some(price) = pnl_calculator.get_current_price(&token_mint).await else {
+                                continue;
+                            };
+
+                            let now = Utc::now().timestamp();
+                            for position in positions.iter().filter(|p| p.token_mint == token_mint) {
+                                match position_alerts.evaluate(position, price, now).await {
+                                    Ok(alerts) => for alert in alerts {
+                                        report_position_alert(&alert, &token_registry.label(&token_mint), control_channel.as_deref()).await;
+                                    },
+                                    Err(e) => warn!("Failed to evaluate alerts of position #{}: {}", position.id, e),
+                                }
+                            }
+
+                            // Take-profit and stop-loss levels already include exit slippage, sell tax and fees
+                            let open_quantity: f64 = positions.iter()
+                                .filter(|p| p.token_mint == token_mint)
+                                .map(|p| p.quantity)
+                                .sum();
+                            let mut level_hit = false;
+                            for levels in open_levels.iter().filter(|l| l.token_mint == token_mint) {
+                                let Some(kind) = levels.crossed(price) else {
+                                    continue;
+                                };
+                                let Some(position) = positions.iter().find(|p| p.id == levels.position_id) else {
+                                    continue;
+                                };
+                                forensics::trace(&token_mint, "exit_level", serde_json::json!({
+                                    "position_id": levels.position_id,
+                                    "kind": kind,
+                                    "price": price,
+                                    "take_profit_price": levels.take_profit_price,
+                                    "stop_loss_price": levels.stop_loss_price,
+                                    "exit_value_ratio": levels.exit_value_ratio(),
+                                }));
+                                if let Err(e) = exit_levels.mark_triggered(levels.position_id, kind, price).await {
+                                    warn!("Failed to mark exit level of position #{}: {}", levels.position_id, e);
+                                    continue;
+                                }
+                                let reason = if kind == EXIT_TAKE_PROFIT {
+                                    format!("Take profit: {:.10} >= {:.10}", price, levels.take_profit_price.unwrap_or_default())
+                                } else {
+                                    let stop = levels.stop_loss_price.unwrap_or_default();
+                                    position_tracker.record_stop_triggered(&token_mint, stop, price).await;
+                                    format!("Stop loss: {:.10} <= {:.10}", price, stop)
+                                };
+                                // Lots close oldest first, so a lot's share of the open quantity is sold
+                                let amount = if open_quantity > 0.0 && position.quantity < open_quantity {
+                                    CloseAmount::Fraction(position.quantity / open_quantity)
+                                } else {
+                                    CloseAmount::All
+                                };
+                                pipeline.exit_position_amount(&token_mint, amount, reason, "position-monitor").await;
+                                level_hit = true;
+                                break;
+                            }
+                            if level_hit {
+                                continue;
+                            }
+                            let Some(decimals) = token_registry.decimals(&token_mint) else {
+                                debug!("No decimals for {} - volume profile skipped", token_mint);
+                                continue;
+                            };
+
+                            match volume_profiler.stop_for(&token_mint, decimals, price).await {
+                                Ok(Some(stop)) => {
+                                    let current = stops.entry(token_mint.clone()).or_insert(stop);
+                                    if stop > *current {
+                                        debug!("🛑 Stop for {} raised to {:.10} (support)", token_registry.label(&token_mint), stop);
+                                        *current = stop;
+                                    }
+                                }
+                                Ok(None) => {}
+                                Err(e) => warn!("Failed to build volume profile for {}: {}", token_mint, e),
+                            }
+
+                            // A metadata swap pulls the stop up under the price until the flag expires
+                            let tightened = metadata_monitor.tightened_stop(&token_mint, price);
+                            if let Some(tight) = tightened {
+                                let current = stops.entry(token_mint.clone()).or_insert(tight);
+                                if tight > *current {
+                                    debug!("🛑 Stop for {} tightened to {:.10} (metadata changed)", token_registry.label(&token_mint), tight);
+                                    *current = tight;
+                                }
+                            }
+
+                            forensics::trace(&token_mint, "stop_check", serde_json::json!({
+                                "price": price,
+                                "stop": stops.get(&token_mint),
+                                "metadata_tightened": tightened.is_some(),
+                            }));
+                            if let Some(&stop) = stops.get(&token_mint) {
+                                if price <= stop {
+                                    position_tracker.record_stop_triggered(&token_mint, stop, price).await;
+                                    let reason = if tightened.is_some() {
+                                        format!("Hit stop tightened after metadata change: {:.10} <= stop {:.10}", price, stop)
+                                    } else {
+                                        format!("Broke volume-profile support: {:.10} <= stop {:.10}", price, stop)
+                                    };
+                                    pipeline.exit_position(&token_mint, reason, "position-monitor").await;
+                                    stops.remove(&token_mint);
+                                }
+                            }
+                        }
+                    }
+
+                    // A held token's bar close triggers the next check right away
+                    Ok(event) = candle_events.recv() => {
+                        if Some(event.timeframe_secs()) == stop_timeframe_secs && stops.contains_key(event.token_mint()) {
+                            check_interval.reset_immediately();
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Position monitor service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(monitor_task);
+        info!("✅ Position monitor service started successfully");
+        Ok(())
+    }
+
+    /// Start token symbol and decimals resolution
+    ///
+    /// Loads the Jupiter token list into the registry and reloads it
+    /// periodically; open position mints the list does not know are resolved
+    /// from their mint and Metaplex metadata accounts. Skipped in fixtures mode
+    /// so replays stay offline.
+    async fn start_token_registry_service(&mut self) -> Result<()> {
+        if self.options.fixtures {
+            return Ok(());
+        }
+        info!("🏷️ Starting token registry service");
+
+        let token_registry = self.token_registry.clone();
+        let position_tracker = self.position_tracker.clone()
+            .ok_or_else(|| anyhow::anyhow!("Position tracker not initialized"))?;
+        let endpoint_prober = self.endpoint_prober.clone();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let registry_task = tokio::spawn(async move {
+            let mut refresh_interval = tokio::time::interval(
+                Duration::from_secs(token_registry.config().refresh_interval_secs)
+            );
+            let mut resolve_interval = tokio::time::interval(Duration::from_secs(60));
+
+            loop {
+                tokio::select! {
+                    _ = refresh_interval.tick() => {
+                        if let Err(e) = token_registry.refresh().await {
+                            warn!("Failed to refresh token list: {}", e);
+                        }
+                    }
+
+                    _ = resolve_interval.tick() => {
+                        let mints: Vec<String> = match position_tracker.get_open_positions().await {
+                            Ok(positions) => positions.into_iter().map(|p| p.token_mint).collect(),
+                            Err(e) => {
+                                warn!("Failed to load open positions for symbol resolution: {}", e);
+                                continue;
+                            }
+                        };
+
+                        let rpc_client = endpoint_prober.analytics_client(CommitmentConsumer::Analytics);
+                        match token_registry.resolve_missing(&rpc_client, &mints).await {
+                            Ok(0) => {}
+                            Ok(resolved) => debug!("🏷️ Resolved {} token mints from chain", resolved),
+                            Err(e) => warn!("Failed to resolve token metadata: {}", e),
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Token registry service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(registry_task);
+        info!("✅ Token registry service started successfully");
+        Ok(())
+    }
+
+    /// Start resolution of token ages from chain history
+    ///
+    /// Tokens whose age was asked for but is unknown, or known only from a
+    /// stream observation, are looked up every ten seconds: the oldest
+    /// transactions of the mint and its Metaplex metadata account are merged
+    /// into the token age service. Skipped in fixtures mode so replays stay offline.
+    async fn start_token_age_service(&mut self) -> Result<()> {
+        if self.options.fixtures {
+            return Ok(());
+        }
+        info!("🕰️ Starting token age service");
+
+        let token_ages = self.token_ages.clone();
+        let endpoint_prober = self.endpoint_prober.clone();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let token_age_task = tokio::spawn(async move {
+            let mut resolve_interval = tokio::time::interval(Duration::from_secs(10));
+
+            loop {
+                tokio::select! {
+                    _ = resolve_interval.tick() => {
+                        if token_ages.pending_count() == 0 {
+                            continue;
+                        }
+                        let rpc_client = endpoint_prober.analytics_client(CommitmentConsumer::Analytics);
+                        match token_ages.resolve_pending(&rpc_client).await {
+                            Ok(0) => {}
+                            Ok(resolved) => debug!("🕰️ Resolved {} token ages from chain ({} waiting)",
+                                resolved, token_ages.pending_count()),
+                            Err(e) => warn!("Failed to resolve token ages: {}", e),
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Token age service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(token_age_task);
+        info!("✅ Token age service started successfully");
+        Ok(())
+    }
+
+    /// Start triage of programs seen in live data without a registry entry
+    ///
+    /// Unknown program sightings are added to `unknown_programs` every minute;
+    /// a program reaching `BADGER_UNKNOWN_PROGRAM_ALERT_COUNT` sightings raises
+    /// an `UNKNOWN_PROGRAM` alert so it can be labelled with `SET_PROGRAM_LABEL`.
+    async fn start_program_registry_service(&mut self) -> Result<()> {
+        info!("🏷️ Starting program registry service");
+
+        let program_registry = self.program_registry.clone()
+            .ok_or_else(|| anyhow::anyhow!("Program registry not initialized"))?;
+        let control_channel = self.control_channel.clone()
+            .ok_or_else(|| anyhow::anyhow!("Control channel not initialized"))?;
+        let alert_count = std::env::var("BADGER_UNKNOWN_PROGRAM_ALERT_COUNT").ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(100);
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let registry_task = tokio::spawn(async move {
+            let mut flush_interval = tokio::time::interval(Duration::from_secs(60));
+
+            loop {
+                tokio::select! {
+                    _ = flush_interval.tick() => {
+                        let crossed = match program_registry.flush_unknown(alert_count).await {
+                            Ok(crossed) => crossed,
+                            Err(e) => {
+                                warn!("Failed to record unknown programs: {}", e);
+                                continue;
+                            }
+                        };
+                        for program in crossed {
+                            let message = format!("Unknown program {} seen {} times in {}s; label it with SET_PROGRAM_LABEL",
+                                program.program_id, program.seen_count, program.last_seen - program.first_seen);
+                            warn!("🏷️ {}", message);
+                            if let Err(e) = control_channel.record_alert("UNKNOWN_PROGRAM", "program-registry-001", &message).await {
+                                warn!("Failed to record unknown program alert: {}", e);
+                            }
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Program registry service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(registry_task);
+        info!("✅ Program registry service started successfully");
+        Ok(())
+    }
+
+    /// Start forwarding of position events and operator alerts to webhooks
+    ///
+    /// PositionOpened, PositionIncreased, StopTriggered, PositionReduced and PositionClosed
+    /// events from the transport bus, and alerts recorded in the alert log, are
+    /// POSTed as JSON to every URL in `BADGER_POSITION_WEBHOOK_URLS`. Routine
+    /// notifications are grouped into a periodic digest and repeats are rate
+    /// limited; critical alerts always go straight out. Skipped when no URL is configured.
+    async fn start_notification_service(&mut self) -> Result<()> {
+        let config = PositionWebhookConfig::from_env();
+        if config.urls.is_empty() {
+            return Ok(());
+        }
+        let digest = NotificationDigest::new(Some(NotificationDigestConfig::from_env()), Utc::now().timestamp());
+        info!("📮 Starting notification service ({} endpoints, digest every {}s)",
+              config.urls.len(), digest.config().digest_interval_secs);
+
+        let control_channel = self.control_channel.clone()
+            .ok_or_else(|| anyhow::anyhow!("Control channel not initialized"))?;
+        let sink = PositionWebhookSink::new(config);
+        let wallet_labels = self.wallet_labels.clone();
+        let mut position_events = self.transport_bus.subscribe_position_events().await;
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+        // Only alerts raised from now on are forwarded
+        let mut last_alert_id = control_channel.latest_alert_id().await
+            .map_err(|e| anyhow::anyhow!("Failed to read alert log: {}", e))?;
+
+        let webhook_task = tokio::spawn(async move {
+            let mut poll_interval = tokio::time::interval(Duration::from_secs(5));
+
+            loop {
+                tokio::select! {
+                    event = position_events.recv() => {
+                        match event {
+                            Ok(event) => {
+                                if let PositionEvent::PositionClosed { position_id, realized_pnl, .. } = &event {
+                                    debug!("📮 Forwarding close of position #{} (P&L: ${:.4})", position_id, realized_pnl);
+                                }
+                                let notification = Notification::from_position_event(&event);
+                                if digest.route(&notification, Utc::now().timestamp()) == NotificationRoute::Immediate {
+                                    sink.deliver(&event).await;
+                                }
+                            }
+                            Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                                warn!("Notification service lagged, {} position events not forwarded", skipped);
+                            }
+                            Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                        }
+                    }
+
+                    _ = poll_interval.tick() => {
+                        match control_channel.get_alerts_after(last_alert_id).await {
+                            Ok(alerts) => {
+                                for alert in alerts {
+                                    last_alert_id = alert.id;
+                                    let notification = Notification::from_alert(
+                                        &alert.alert_type, &alert.service, &wallet_labels.annotate(&alert.message), alert.created_at,
+                                    );
+                                    if digest.route(&notification, Utc::now().timestamp()) == NotificationRoute::Immediate {
+                                        sink.deliver_alert(&notification).await;
+                                    }
+                                }
+                            }
+                            Err(e) => warn!("Failed to read alert log: {}", e),
+                        }
+
+                        if let Some(report) = digest.take_due(Utc::now().timestamp()) {
+                            info!("📮 Sending digest of {} notifications ({} repeats suppressed)",
+                                  report.total(), report.suppressed.values().sum::<usize>());
+                            sink.deliver_digest(&report).await;
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Notification service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(webhook_task);
+        info!("✅ Notification service started successfully");
+        Ok(())
+    }
+
+    /// Start resolution of insider buy outcomes left in PENDING
+    ///
+    /// Closes insider buys against their later sells, or marks them to the
+    /// cached price once old enough, so insider scoring has outcomes to work with.
+    async fn start_outcome_resolver_service(&mut self) -> Result<()> {
+        info!("🎯 Starting insider outcome resolver service");
+
+        let insider_analytics = self.insider_analytics.clone()
+            .ok_or_else(|| anyhow::anyhow!("Insider analytics not initialized"))?;
+        let pnl_calculator = self.pnl_calculator.clone()
+            .ok_or_else(|| anyhow::anyhow!("PnL calculator not initialized"))?;
+        let config = OutcomeResolverConfig::default();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let resolver_task = tokio::spawn(async move {
+            let mut resolve_interval = tokio::time::interval(
+                Duration::from_secs(config.resolve_interval_secs)
+            );
+
+            loop {
+                tokio::select! {
+                    _ = resolve_interval.tick() => {
+                        let prices = pnl_calculator.cached_prices().await;
+                        match insider_analytics.resolve_pending_outcomes(&prices, &config).await {
+                            Ok(resolution) => {
+                                let resolved = resolution.resolved_by_sell + resolution.resolved_by_price;
+                                if resolved > 0 {
+                                    info!("🎯 Resolved {} insider trade outcomes ({} by sell, {} by price, {} expired)",
+                                        resolved, resolution.resolved_by_sell, resolution.resolved_by_price, resolution.expired);
+                                }
+                            }
+                            Err(e) => warn!("Failed to resolve insider trade outcomes: {}", e),
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Outcome resolver service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(resolver_task);
+        info!("✅ Insider outcome resolver service started successfully");
+        Ok(())
+    }
+
+    /// Start incremental refresh of the summary tables read by reports
+    async fn start_summary_refresh_service(&mut self) -> Result<()> {
+        info!("📚 Starting summary views refresh service");
+
+        let summary_views = self.summary_views.clone()
+            .ok_or_else(|| anyhow::anyhow!("Summary views not initialized"))?;
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let refresh_task = tokio::spawn(async move {
+            let mut refresh_interval = tokio::time::interval(
+                Duration::from_secs(summary_views.config().refresh_interval_secs)
+            );
+            let mut daily_interval = tokio::time::interval(Duration::from_secs(86400));
+            daily_interval.tick().await; // First report after a full day
+
+            loop {
+                tokio::select! {
+                    _ = refresh_interval.tick() => {
+                        if let Err(e) = summary_views.refresh().await {
+                            warn!("Failed to refresh summary views: {}", e);
+                        }
+                    }
+
+                    _ = daily_interval.tick() => {
+                        if let Err(e) = generate_strategy_pnl_report(&summary_views).await {
+                            warn!("Failed to generate strategy P&L report: {}", e);
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Summary refresh service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(refresh_task);
+        info!("✅ Summary views refresh service started successfully");
+        Ok(())
+    }
+
+    /// Start holder distribution snapshots for held tokens and buy candidates
+    ///
+    /// Flags concentrated tokens and deployer accumulation/distribution through
+    /// the operator alert log.
+    async fn start_holder_snapshot_service(&mut self) -> Result<()> {
+        info!("👥 Starting holder distribution snapshot service");
+
+        let holder_snapshots = self.holder_snapshots.clone()
+            .ok_or_else(|| anyhow::anyhow!("Holder snapshot store not initialized"))?;
+        let position_tracker = self.position_tracker.clone()
+            .ok_or_else(|| anyhow::anyhow!("Position tracker not initialized"))?;
+        let skip_rules = self.skip_rules.clone()
+            .ok_or_else(|| anyhow::anyhow!("Skip rule learner not initialized"))?;
+        let control_channel = self.control_channel.clone()
+            .ok_or_else(|| anyhow::anyhow!("Control channel not initialized"))?;
+        let endpoint_prober = self.endpoint_prober.clone();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let snapshot_task = tokio::spawn(async move {
+            let mut snapshot_interval = tokio::time::interval(
+                Duration::from_secs(holder_snapshots.config().snapshot_interval_secs)
+            );
+
+            loop {
+                tokio::select! {
+                    _ = snapshot_interval.tick() => {
+                        let mut mints = holder_snapshots.candidates();
+                        match position_tracker.get_open_positions().await {
+                            Ok(positions) => mints.extend(positions.into_iter().map(|p| p.token_mint)),
+                            Err(e) => warn!("Failed to load open positions for holder snapshots: {}", e),
+                        }
+                        mints.sort();
+                        mints.dedup();
+
+                        // Snapshots are bulk reads, so they stay on the analytics endpoint
+                        let rpc_client = endpoint_prober.analytics_client(CommitmentConsumer::Analytics);
+
+                        for mint in mints {
+                            let pool_accounts: Vec<String> = BondingCurveTracker::curve_address_for_mint(&mint)
+                                .into_iter()
+                                .collect();
+                            let distribution = match fetch_holder_distribution(&rpc_client, &mint, &pool_accounts).await {
+                                Ok(distribution) => distribution,
+                                Err(e) => {
+                                    debug!("Failed to fetch holder distribution for {}: {}", mint, e);
+                                    continue;
+                                }
+                            };
+
+                            let deployer = skip_rules.deployer(&mint);
+                            let outcome = match holder_snapshots.record_snapshot(&distribution, deployer.as_deref()).await {
+                                Ok(outcome) => outcome,
+                                Err(e) => {
+                                    warn!("Failed to record holder snapshot for {}: {}", mint, e);
+                                    continue;
+                                }
+                            };
+
+                            let mut alerts = Vec::new();
+                            if outcome.snapshot.concentrated {
+                                alerts.push(format!("{} concentrated: top holder {:.1}%, top 10 {:.1}%",
+                                    mint, outcome.snapshot.top1_share * 100.0, outcome.snapshot.top10_share * 100.0));
+                            }
+                            for change in outcome.changes.iter().filter(|c| c.is_deployer) {
+                                alerts.push(format!("Deployer {} {} on {}: {:.1}% -> {:.1}%",
+                                    change.owner, change.change_type.to_lowercase(), mint,
+                                    change.previous_share * 100.0, change.new_share * 100.0));
+                            }
+
+                            for message in alerts {
+                                warn!("👥 {}", message);
+                                if let Err(e) = control_channel.record_alert("HOLDER_DISTRIBUTION", "holder-snapshots-001", &message).await {
+                                    warn!("Failed to record holder distribution alert: {}", e);
+                                }
+                            }
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Holder snapshot service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(snapshot_task);
+        info!("✅ Holder distribution snapshot service started successfully");
+        Ok(())
+    }
+
+    /// Start periodic dust consolidation across our trading wallets
+    ///
+    /// Finds residual balances below the dust threshold and empty token accounts,
+    /// and reports the sell/close plan with the SOL it would recover.
+    async fn start_dust_consolidation_service(&mut self) -> Result<()> {
+        if self.self_trade_guard.wallet_count() == 0 {
+            debug!("No trading wallets configured - dust consolidation disabled");
+            return Ok(());
+        }
+
+        info!("🧹 Starting dust consolidation service");
+
+        let pnl_calculator = self.pnl_calculator.clone()
+            .ok_or_else(|| anyhow::anyhow!("P&L calculator not initialized"))?;
+        let control_channel = self.control_channel.clone()
+            .ok_or_else(|| anyhow::anyhow!("Control channel not initialized"))?;
+        let self_trade_guard = self.self_trade_guard.clone();
+        let bonding_curves = self.bonding_curves.clone();
+        let endpoint_prober = self.endpoint_prober.clone();
+        let token_registry = self.token_registry.clone();
+        let fee_budget = self.fee_budget.clone()
+            .ok_or_else(|| anyhow::anyhow!("Fee budget not initialized"))?;
+        let config = DustConfig::default();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let dust_task = tokio::spawn(async move {
+            let mut scan_interval = tokio::time::interval(Duration::from_secs(config.scan_interval_secs));
+
+            loop {
+                tokio::select! {
+                    _ = scan_interval.tick() => {
+                        let prices = pnl_calculator.cached_prices().await;
+                        // Tokens still on an active bonding curve can always be sold into it
+                        let price_for = |mint: &str| prices.get(mint).copied().or_else(|| {
+                            bonding_curves.state_for_mint(mint)
+                                .filter(|curve| !curve.complete)
+                                .map(|curve| curve.price_sol())
+                        });
+
+                        let rpc_client = endpoint_prober.analytics_client(CommitmentConsumer::Accounting);
+                        let plan = match scan_dust(&rpc_client, &self_trade_guard.wallets(), price_for, &config).await {
+                            Ok(plan) => plan,
+                            Err(e) => {
+                                warn!("Failed to scan wallets for dust: {}", e);
+                                continue;
+                            }
+                        };
+
+                        if plan.closable_count() == 0 {
+                            continue;
+                        }
+                        if fee_budget.throttled().await.unwrap_or(false) {
+                            debug!("⛽ Fee budget nearly spent - dust cleanup held");
+                            continue;
+                        }
+
+                        // No signer in this process - the plan is executed by the treasury operator
+                        for dust in &plan.sell_and_close {
+                            info!("🧹 Sell + close {} ({} {} in {}, ~{:.6} SOL)", dust.token_account,
+                                dust.ui_amount, token_registry.label(&dust.token_mint), dust.wallet, dust.value_sol.unwrap_or(0.0));
+                        }
+                        for dust in &plan.close_only {
+                            debug!("🧹 Close empty {} ({} in {})", dust.token_account, dust.token_mint, dust.wallet);
+                        }
+
+                        let message = format!(
+                            "{} dust sells and {} empty accounts to close; ~{:.4} SOL recoverable ({} unpriced balances left)",
+                            plan.sell_and_close.len(), plan.close_only.len(), plan.recoverable_sol(), plan.unroutable.len()
+                        );
+                        info!("🧹 {}", message);
+                        if let Err(e) = control_channel.record_alert("DUST_CONSOLIDATION", "dust-consolidation-001", &message).await {
+                            warn!("Failed to record dust consolidation alert: {}", e);
+                        }
+                    }
+
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Dust consolidation service received shutdown signal");
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        self.tasks.push(dust_task);
+        info!("✅ Dust consolidation service started successfully");
+        Ok(())
+    }
+
+    /// Receive market events from a separate ingest process (`--role analyzer`)
+    async fn start_ipc_receiver_service(&mut self) -> Result<()> {
+        let socket_path = self.options.ipc_socket_path();
+        let (accept_task, mut events) = IpcEventReceiver::bind(&socket_path, 10_000)?;
+        let pipeline = self.market_event_pipeline();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+
+        let receiver_task = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    Some(market_event) = events.recv() => {
+                        pipeline.process(market_event, "ipc-ingest").await;
+                    }
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 IPC receiver received shutdown signal");
+                        accept_task.abort();
+                        break;
+                    }
+                }
+            }
+
+            let _ = std::fs::remove_file(&socket_path);
+            Ok(())
+        });
+
+        self.tasks.push(receiver_task);
+        info!("✅ IPC receiver started successfully");
         Ok(())
     }
 
-    /// Initialize analytics components (Task 3.1: Real-time Metrics Calculation)
-    async fn initialize_analytics(&mut self) -> Result<()> {
-        info!("🔧 Initializing analytics components for real-time metrics");
-        
-        let db_manager = self.database_manager.as_ref()
-            .ok_or_else(|| anyhow::anyhow!("Database manager not initialized"))?;
-        let db = db_manager.get_database();
-
-        // Initialize position tracker
-        let position_tracker = Arc::new(PositionTracker::new(db.clone()));
-        position_tracker.initialize_schema().await
-            .map_err(|e| anyhow::anyhow!("Failed to initialize position tracker schema: {}", e))?;
+    /// Start the external signal API
+    ///
+    /// Only runs when client tokens are configured; accepted signals go
+    /// through the same entry gates and risk checks as generated ones, and
+    /// commands are queued on the operator control channel. Every call is
+    /// recorded in `api_audit_log`.
+    #[cfg(feature = "api")]
+    async fn start_signal_api_service(&mut self) -> Result<()> {
+        let config = SignalApiConfig::default();
+        if std::env::var(&config.tokens_env).is_err() {
+            info!("📡 External signal API disabled ({} not set)", config.tokens_env);
+            return Ok(());
+        }
 
-        // Initialize P&L calculator
-        let pnl_calculator = Arc::new(PnLCalculator::new(db.clone(), position_tracker.clone()));
-        pnl_calculator.initialize_schema().await
-            .map_err(|e| anyhow::anyhow!("Failed to initialize P&L calculator schema: {}", e))?;
+        let control_channel = self.control_channel.clone()
+            .ok_or_else(|| anyhow::anyhow!("Control channel not initialized"))?;
+        let db = self.database_manager.as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Database not initialized"))?
+            .get_database();
+        let audit_log = AuditLog::new(db);
 
-        // Initialize performance tracker
-        let performance_tracker = Arc::new(PerformanceTracker::new(
-            db.clone(), 
-            position_tracker.clone(),
-            pnl_calculator.clone()
-        ));
-        performance_tracker.initialize_schema().await
-            .map_err(|e| anyhow::anyhow!("Failed to initialize performance tracker schema: {}", e))?;
+        let (accept_task, mut requests) = SignalApiServer::bind(config, 1_000).await?;
+        let pipeline = self.market_event_pipeline();
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
 
-        // Initialize insider analytics
-        let insider_analytics = Arc::new(InsiderAnalytics::new(db.clone(), position_tracker.clone()));
-        insider_analytics.initialize_schema().await
-            .map_err(|e| anyhow::anyhow!("Failed to initialize insider analytics schema: {}", e))?;
+        let api_task = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    Some(request) = requests.recv() => match request {
+                        ApiRequest::Signal(external) => pipeline.process_external(external).await,
+                        ApiRequest::PositionAlerts(update) => pipeline.set_position_alerts(update).await,
+                        ApiRequest::Query(ApiQuery { kind: ApiQueryKind::CommandStatus { id }, reply }) => {
+                            let answer = match control_channel.get_command(id).await {
+                                Ok(Some(command)) => (200, serde_json::json!(command)),
+                                Ok(None) => (404, serde_json::json!({"error": format!("no command #{}", id)})),
+                                Err(e) => (500, serde_json::json!({"error": e.to_string()})),
+                            };
+                            let _ = reply.send(answer);
+                        }
+                        ApiRequest::Query(query) => pipeline.answer_query(query).await,
+                        ApiRequest::Command(command) => {
+                            let name = command.request.command.trim().to_ascii_uppercase();
+                            let answer = if !is_operator_command(&name) {
+                                (400, serde_json::json!({"error": format!("unknown command {}", name)}))
+                            } else if ADMIN_COMMANDS.contains(&name.as_str()) && command.client.role < ApiRole::Admin {
+                                warn!("📡 Refused {} to {} ({} key)", name, command.client.name, command.client.role.as_str());
+                                (403, serde_json::json!({"error": format!("{} requires the admin role", name)}))
+                            } else {
+                                match control_channel.submit_command(&name, command.request.target.as_deref()).await {
+                                    Ok(id) => {
+                                        info!("📡 {} queued command #{} {} {:?}", command.client.name, id, name, command.request.target);
+                                        (202, serde_json::json!({"command_id": id}))
+                                    }
+                                    Err(e) => (500, serde_json::json!({"error": e.to_string()})),
+                                }
+                            };
+                            let _ = command.reply.send(answer);
+                        }
+                        ApiRequest::Audit(call) => {
+                            if let Err(e) = audit_log.record_api_call(
+                                call.client.as_deref(),
+                                call.role.map(|role| role.as_str()),
+                                &call.method,
+                                &call.path,
+                                call.status,
+                                call.detail.as_deref(),
+                            ).await {
+                                warn!("Failed to audit API call {} {}: {}", call.method, call.path, e);
+                            }
+                        }
+                    },
+                    _ = shutdown_rx.recv() => {
+                        info!("🛑 Signal API received shutdown signal");
+                        accept_task.abort();
+                        break;
+                    }
+                }
+            }
 
-        // Store references
-        self.position_tracker = Some(position_tracker);
-        self.pnl_calculator = Some(pnl_calculator);
-        self.performance_tracker = Some(performance_tracker);
-        self.insider_analytics = Some(insider_analytics);
+            Ok(())
+        });
 
-        info!("✅ Analytics components initialized successfully");
-        info!("   📊 Position Tracker: Ready for real-time position tracking");
-        info!("   💰 P&L Calculator: Ready for real-time profit/loss calculation");
-        info!("   📈 Performance Tracker: Ready for bot performance metrics");
-        info!("   🕵️ Insider Analytics: Ready for wallet intelligence tracking");
-        
+        self.tasks.push(api_task);
+        info!("✅ External signal API started successfully");
         Ok(())
     }
 
-    /// Start real-time analytics reporting service (Phase 3: Task 3.1)
-    async fn start_analytics_reporting_service(&mut self) -> Result<()> {
-        info!("📊 Starting real-time analytics reporting service");
-
-        let position_tracker = self.position_tracker.clone()
-            .ok_or_else(|| anyhow::anyhow!("Position tracker not initialized"))?;
-        let pnl_calculator = self.pnl_calculator.clone()
-            .ok_or_else(|| anyhow::anyhow!("P&L calculator not initialized"))?;
-        let performance_tracker = self.performance_tracker.clone()
-            .ok_or_else(|| anyhow::anyhow!("Performance tracker not initialized"))?;
-        let insider_analytics = self.insider_analytics.clone()
-            .ok_or_else(|| anyhow::anyhow!("Insider analytics not initialized"))?;
+    /// Start the signal outbox monitor
+    ///
+    /// Expires signals that were never executed and raises an alert for each.
+    async fn start_signal_outbox_monitor(&mut self) -> Result<()> {
+        info!("📮 Starting signal outbox monitor");
 
+        let signal_outbox = self.signal_outbox.clone()
+            .ok_or_else(|| anyhow::anyhow!("Signal outbox not initialized"))?;
+        let service_registry = self.service_registry.clone();
         let mut shutdown_rx = self.shutdown_tx.subscribe();
 
-        let reporting_task = tokio::spawn(async move {
-            let mut reporting_interval = tokio::time::interval(Duration::from_secs(60)); // Report every minute
-            let mut performance_interval = tokio::time::interval(Duration::from_secs(300)); // Performance every 5 minutes
-            
-            // Start a trading session
-            let session_id = match performance_tracker.start_trading_session().await {
-                Ok(id) => {
-                    info!("🚀 Started trading session: {}", id);
-                    id
-                }
-                Err(e) => {
-                    error!("Failed to start trading session: {}", e);
-                    return Err(anyhow::anyhow!("Failed to start trading session: {}", e));
-                }
-            };
+        let monitor_task = tokio::spawn(async move {
+            let mut expiry_interval = tokio::time::interval(Duration::from_secs(30));
 
             loop {
                 tokio::select! {
-                    // Real-time reporting every minute
-                    _ = reporting_interval.tick() => {
-                        if let Err(e) = generate_real_time_report(
-                            &position_tracker,
-                            &pnl_calculator, 
-                            &insider_analytics
-                        ).await {
-                            warn!("Failed to generate real-time report: {}", e);
-                        }
-                    }
+                    _ = expiry_interval.tick() => {
+                        let expired = match signal_outbox.expire_stale().await {
+                            Ok(expired) => expired,
+                            Err(e) => {
+                                warn!("Failed to expire stale signals: {}", e);
+                                continue;
+                            }
+                        };
 
-                    // Performance metrics every 5 minutes
-                    _ = performance_interval.tick() => {
-                        if let Err(e) = generate_performance_report(
-                            &performance_tracker,
-                            &pnl_calculator
-                        ).await {
-                            warn!("Failed to generate performance report: {}", e);
+                        for entry in expired {
+                            let amount_sol = serde_json::from_str::<TradingSignal>(&entry.payload)
+                                .ok()
+                                .and_then(|signal| match signal {
+                                    TradingSignal::Buy { max_amount_sol, .. } => Some(max_amount_sol),
+                                    _ => None,
+                                })
+                                .unwrap_or(0.0);
+
+                            warn!("⌛ {} signal {} for {} expired unexecuted ({})",
+                                entry.signal_type, entry.signal_id, entry.token_mint, entry.status);
+
+                            let alert = SystemAlert::ExecutionError {
+                                order_id: entry.signal_id.clone(),
+                                token_mint: entry.token_mint.clone(),
+                                error: format!("Signal expired unexecuted (last status: {})", entry.status),
+                                amount_sol,
+                            };
+                            if let Err(e) = service_registry.route_system_alert(alert, Some("signal-outbox-001")).await {
+                                warn!("Failed to route signal expiry alert: {}", e);
+                            }
                         }
                     }
 
-                    // Handle shutdown
                     _ = shutdown_rx.recv() => {
-                        info!("🛑 Analytics reporting service received shutdown signal");
-                        
-                        // End the trading session
-                        if let Err(e) = performance_tracker.end_trading_session().await {
-                            warn!("Failed to end trading session cleanly: {}", e);
-                        }
-                        
+                        info!("🛑 Signal outbox monitor received shutdown signal");
                         break;
                     }
                 }
@@ -662,8 +6679,8 @@ impl BadgerOrchestrator {
             Ok(())
         });
 
-        self.tasks.push(reporting_task);
-        info!("✅ Analytics reporting service started successfully");
+        self.tasks.push(monitor_task);
+        info!("✅ Signal outbox monitor started successfully");
         Ok(())
     }
 
@@ -697,13 +6714,31 @@ impl BadgerOrchestrator {
         
         let mut shutdown_rx = self.shutdown_tx.subscribe();
         let config = self.websocket_config.clone();
+        let rpc_ws_url = config.primary_url.clone();
         let service_registry = self.service_registry.clone();
+        let chain_lag = self.chain_lag.clone();
+        let signature_confirmations = self.signature_confirmations.clone();
+        let log_ingest = self.log_ingest.clone();
+        let block_ingest = self.block_ingest.clone();
+        // Insiders whose swaps are decoded even when someone else signed the transaction
+        let tracked_wallets = self.insider_analytics.as_ref().map(|insider_analytics| insider_analytics.cache());
+        let metadata_monitor = self.metadata_monitor.clone();
+        let control_channel = self.control_channel.clone();
         
-        // Clone analytics components for the ingestion task
-        let position_tracker = self.position_tracker.clone();
-        let pnl_calculator = self.pnl_calculator.clone(); 
-        let performance_tracker = self.performance_tracker.clone();
-        let insider_analytics = self.insider_analytics.clone();
+        // Deliver events to the local pipeline, or to the analyzer in split-process mode
+        let pipeline = self.market_event_pipeline();
+        let sink = if self.options.role == ProcessRole::Ingest {
+            let socket_path = self.options.ipc_socket_path();
+            info!("🔌 Ingest role: shipping market events to analyzer at {}", socket_path.display());
+            let (sender, writer) = IpcEventSender::connect(&socket_path, 10_000);
+            self.tasks.push(tokio::spawn(async move {
+                writer.await?;
+                Ok(())
+            }));
+            MarketEventSink::Ipc(sender)
+        } else {
+            MarketEventSink::Pipeline(Box::new(pipeline.clone()))
+        };
         
         let ingestion_task = tokio::spawn(async move {
             info!("🚀 Badger Ingest - Real-time Solana Data Processing");
@@ -735,13 +6770,213 @@ impl BadgerOrchestrator {
             };
             
             // Real Solana data only - no mock data
+            let client = Arc::new(client);
+            signature_confirmations.attach(client.clone());
 
             // Start WebSocket client in background
+            let run_client = client.clone();
             let client_handle = tokio::spawn(async move {
                 info!("📡 Starting WebSocket client - will subscribe after connection");
                 
                 // Start the client event loop first
-                client.run().await
+                run_client.run().await
+            });
+            
+            // Allocate insider account subscription slots by priority
+            let reconnected = Arc::new(tokio::sync::Notify::new());
+            let subscription_handle = pipeline.insider_analytics.clone().map(|insider_analytics| {
+                let mut manager = InsiderSubscriptionManager::new(
+                    client.clone(),
+                    &http_url_from_ws(&rpc_ws_url),
+                    Some(SubscriptionSlotConfig {
+                        commitment: pipeline.endpoint_prober.commitments().subscriptions.commitment.to_string(),
+                        ..SubscriptionSlotConfig::default()
+                    }),
+                );
+                let reconnected = reconnected.clone();
+                let pipeline = pipeline.clone();
+                let lp_exit = LpExitConfig {
+                    emit_signals: std::env::var("BADGER_LP_EXIT").is_ok_and(|v| v == "1"),
+                    ..LpExitConfig::default()
+                };
+                
+                tokio::spawn(async move {
+                    let mut rebalance_interval = tokio::time::interval(
+                        Duration::from_secs(manager.config().rebalance_interval_secs));
+                    let mut poll_interval = tokio::time::interval(
+                        Duration::from_secs(manager.config().poll_interval_secs));
+                    
+                    loop {
+                        tokio::select! {
+                            _ = rebalance_interval.tick() => {
+                                if let Some(watch_groups) = &pipeline.watch_groups {
+                                    if let Err(e) = watch_groups.prune(Utc::now().timestamp()).await {
+                                        warn!("Failed to prune watch group buys: {}", e);
+                                    }
+                                }
+                                // Discovery candidates are polled so their own trades decide their probation
+                                let probation = match &pipeline.wallet_discovery {
+                                    Some(wallet_discovery) => wallet_discovery.probation_wallets().await.unwrap_or_else(|e| {
+                                        warn!("Failed to load probation wallets: {}", e);
+                                        Vec::new()
+                                    }),
+                                    None => Vec::new(),
+                                };
+                                match insider_analytics.get_top_insiders(1000).await {
+                                    Ok(profiles) => match manager.rebalance(&profiles, &probation).await {
+                                        Ok(outcome) if outcome.subscribed > 0 || outcome.unsubscribed > 0 => {
+                                            info!("🎯 Insider slots rebalanced: +{} -{} | {} subscribed, {} polled",
+                                                outcome.subscribed, outcome.unsubscribed,
+                                                manager.subscribed_count(), outcome.polled);
+                                        }
+                                        Ok(_) => {}
+                                        Err(e) => warn!("Failed to rebalance insider subscriptions: {}", e),
+                                    },
+                                    Err(e) => warn!("Failed to rank insiders for subscriptions: {}", e),
+                                }
+                            }
+                            _ = poll_interval.tick() => {
+                                match manager.poll_next_batch().await {
+                                    Ok(active) if !active.is_empty() => {
+                                        info!("🔎 Polling detected activity from {} low-priority insiders", active.len());
+                                        
+                                        // Attribute exact amounts and prices from the transaction's balance deltas,
+                                        // replaying each wallet's transactions in the order they happened
+                                        let polled: Vec<(String, String)> = active.into_iter()
+                                            .flat_map(|activity| {
+                                                let wallet = activity.wallet;
+                                                activity.signatures.into_iter().map(move |signature| (wallet.clone(), signature))
+                                            })
+                                            .collect();
+                                        for (wallet, signature) in polled {
+                                            let trade = match manager.fetch_activity(&wallet, &signature).await {
+                                                Ok(Some(WalletActivity::Trade(trade))) => trade,
+                                                Ok(Some(WalletActivity::Liquidity(lp))) => {
+                                                    track_insider_liquidity(&lp, &insider_analytics, &pipeline, &lp_exit).await;
+                                                    continue;
+                                                }
+                                                Ok(Some(WalletActivity::Transfer(transfer))) => {
+                                                    pipeline.observe_insider_transfer(&transfer).await;
+                                                    continue;
+                                                }
+                                                Ok(None) => continue,
+                                                Err(e) => {
+                                                    debug!("Failed to decode trade for insider {}: {}", wallet, e);
+                                                    continue;
+                                                }
+                                            };
+                                            let activity_type = match trade.side {
+                                                badger::core::SwapType::Buy => "BUY",
+                                                badger::core::SwapType::Sell => "SELL",
+                                            };
+                                            if let Err(e) = insider_analytics.track_insider_activity(
+                                                &trade.wallet,
+                                                &trade.token_mint,
+                                                activity_type,
+                                                trade.token_amount,
+                                                Some(trade.price_sol),
+                                                Some(&trade.signature),
+                                                Some(trade.slot as i64),
+                                            ).await {
+                                                warn!("Failed to track decoded insider trade: {}", e);
+                                            }
+                                            if trade.side == badger::core::SwapType::Buy {
+                                                pipeline.observe_insider_buy(&trade.wallet, &trade.token_mint, "insider-poll").await;
+                                            }
+                                        }
+                                    }
+                                    Ok(_) => {}
+                                    Err(e) => warn!("Failed to poll insider wallets: {}", e),
+                                }
+                            }
+                            _ = reconnected.notified() => {
+                                manager.reset_subscriptions();
+                            }
+                        }
+                    }
+                })
+            });
+            
+            // Subscribe held tokens' curve and metadata accounts; prune tokens that are drained or idle
+            let token_subscriptions = Arc::new(TokenSubscriptionRegistry::new(Some(TokenSubscriptionConfig {
+                commitment: pipeline.endpoint_prober.commitments().subscriptions.commitment.to_string(),
+                ..TokenSubscriptionConfig::from_env()
+            })));
+            let tokens_reconnected = Arc::new(tokio::sync::Notify::new());
+            let token_subscription_handle = pipeline.position_tracker.clone().map(|position_tracker| {
+                let client = client.clone();
+                let token_subscriptions = token_subscriptions.clone();
+                let bonding_curves = pipeline.bonding_curves.clone();
+                let pool_reserves = pipeline.pool_reserves.clone();
+                let metadata_monitor = metadata_monitor.clone();
+                let endpoint_prober = pipeline.endpoint_prober.clone();
+                let reconnected = tokens_reconnected.clone();
+                
+                tokio::spawn(async move {
+                    let mut prune_interval = tokio::time::interval(
+                        Duration::from_secs(token_subscriptions.config().prune_interval_secs));
+                    
+                    loop {
+                        tokio::select! {
+                            _ = prune_interval.tick() => {
+                                let held: std::collections::HashSet<String> = match position_tracker.get_open_positions().await {
+                                    Ok(positions) => positions.into_iter().map(|p| p.token_mint).collect(),
+                                    Err(e) => {
+                                        warn!("Failed to load open positions for token subscriptions: {}", e);
+                                        continue;
+                                    }
+                                };
+                                
+                                let (dead, unsubscribed) = token_subscriptions.prune(&client, &held).await;
+                                for (token_mint, _) in &dead {
+                                    bonding_curves.forget_mint(token_mint);
+                                    pool_reserves.forget_mint(token_mint);
+                                    metadata_monitor.forget_mint(token_mint);
+                                }
+                                if unsubscribed > 0 {
+                                    let drained = dead.iter().filter(|(_, reason)| *reason == DeadTokenReason::Drained).count();
+                                    info!("🧹 Pruned {} dead token subscriptions ({} drained, {} idle) | {} tokens subscribed, {} tracked",
+                                        unsubscribed, drained, dead.len() - drained,
+                                        token_subscriptions.subscribed_count(), token_subscriptions.tracked_count());
+                                }
+                                
+                                // Known metadata is what later notifications are compared against
+                                let unknown: Vec<String> = held.iter()
+                                    .filter(|token_mint| !metadata_monitor.is_known(token_mint))
+                                    .cloned()
+                                    .collect();
+                                if !unknown.is_empty() {
+                                    let rpc_client = endpoint_prober.analytics_client(CommitmentConsumer::Subscriptions);
+                                    if let Err(e) = metadata_monitor.load_missing(&rpc_client, &unknown).await {
+                                        debug!("Failed to load token metadata: {}", e);
+                                    }
+                                }
+                                
+                                for token_mint in held {
+                                    if token_subscriptions.is_subscribed(&token_mint) {
+                                        continue;
+                                    }
+                                    // Graduated curves no longer change; non-pump.fun tokens have no derivable curve
+                                    let mut accounts: Vec<String> = metadata_address_for_mint(&token_mint).into_iter().collect();
+                                    if !bonding_curves.state_for_mint(&token_mint).is_some_and(|curve| curve.complete) {
+                                        accounts.extend(BondingCurveTracker::curve_address_for_mint(&token_mint));
+                                    }
+                                    if accounts.is_empty() {
+                                        continue;
+                                    }
+                                    if let Err(e) = token_subscriptions.subscribe(&client, &token_mint, &accounts).await {
+                                        // Not connected yet - retry on the next pass
+                                        debug!("Failed to subscribe token {}: {}", token_mint, e);
+                                        break;
+                                    }
+                                }
+                            }
+                            _ = reconnected.notified() => {
+                                token_subscriptions.resubscribe(&client).await;
+                            }
+                        }
+                    }
+                })
             });
             
             // Real-time event processing loop (no delays, no batching)
@@ -751,10 +6986,13 @@ impl BadgerOrchestrator {
                 tokio::select! {
                     // Process WebSocket events in real-time with no delays
                     Some(event) = event_rx.recv() => {
+                        chain_lag.observe_message();
                         
                         match event {
                             WebSocketEvent::Connected { url } => {
                                 info!("🟢 Connected to Solana WebSocket: {}", url);
+                                reconnected.notify_one();
+                                tokens_reconnected.notify_one();
                                 println!("🎯 Connection established - auto-subscriptions will be sent!");
                                 
                                 // Update service status to healthy
@@ -778,6 +7016,15 @@ impl BadgerOrchestrator {
                                     995 => "Orca Program", 
                                     994 => "SPL Token Program",
                                     993 => "Pump.fun Program",
+                                    992 => "LaunchLab Program",
+                                    991 => "Moonshot Program",
+                                    990 => "Raydium Logs",
+                                    989 => "Jupiter Logs",
+                                    988 => "Orca Logs",
+                                    987 => "Pump.fun Logs",
+                                    986 => "LaunchLab Logs",
+                                    985 => "Moonshot Logs",
+                                    984 => "Blocks",
                                     _ => "Unknown"
                                 };
                                 println!("🎯 SUBSCRIPTION CONFIRMED: {} (sub: {}, req: {})", sub_type, subscription_id, request_id);
@@ -785,10 +7032,26 @@ impl BadgerOrchestrator {
                             }
                             WebSocketEvent::AccountUpdate { subscription_id, data } => {
                                 parse_and_display_account_update(subscription_id, &data);
+                                
+                                // Held tokens' metadata accounts: name, URI or authority swaps
+                                let subscribed = match client.request_id_for_subscription(subscription_id).await {
+                                    Some(request_id) => token_subscriptions.account_for_request(request_id),
+                                    None => None,
+                                };
+                                if let Some((account, token_mint)) = subscribed {
+                                    if metadata_address_for_mint(&token_mint).as_deref() == Some(account.as_str()) {
+                                        for change in metadata_monitor.observe_notification(&token_mint, &data) {
+                                            report_metadata_change(&change, control_channel.as_deref()).await;
+                                        }
+                                    }
+                                }
                             }
                             WebSocketEvent::TransactionNotification { subscription_id, data } => {
-                                println!("📈 TRANSACTION [{}]: {}", subscription_id,
-                                    serde_json::to_string_pretty(&data).unwrap_or_else(|_| format!("{:?}", data)));
+                                // Signature subscriptions belong to confirmation waits
+                                if !signature_confirmations.observe(subscription_id, &data).await {
+                                    println!("📈 TRANSACTION [{}]: {}", subscription_id,
+                                        serde_json::to_string_pretty(&data).unwrap_or_else(|_| format!("{:?}", data)));
+                                }
                             }
                             WebSocketEvent::ProgramAccountUpdate { subscription_id, data } => {
                                 println!("🔍 PROGRAM UPDATE [sub: {}] - analyzing for DEX events", subscription_id);
@@ -820,41 +7083,8 @@ impl BadgerOrchestrator {
                                         }
                                         
                                         for market_event in market_events {
-                                            // Display the event (for Phase 1 compatibility)
-                                            display_market_event(&market_event);
-                                            
-                                            // Route through transport layer (Phase 2 enhancement)
-                                            match service_registry.route_market_event(
-                                                market_event.clone(), 
-                                                Some("ingestion-service-001")
-                                            ).await {
-                                                Ok(_) => println!("   📤 MarketEvent routed to transport bus successfully"),
-                                                Err(e) => warn!("Failed to route market event: {}", e),
-                                            }
-                                            
-                                            // Process with insider analytics (Phase 3: Task 3.1)
-                                            if let Some(insider_analytics) = &insider_analytics {
-                                                process_market_event_for_insider_analytics(&market_event, insider_analytics).await;
-                                            }
-                                            
-                                            // Generate and route trading signals
-                                            if let Some(signal) = generate_basic_trading_signal(&market_event) {
-                                                display_trading_signal(&signal);
-                                                
-                                                // Route signal through transport layer
-                                                match service_registry.route_trading_signal(
-                                                    signal.clone(),
-                                                    Some("ingestion-service-001")
-                                                ).await {
-                                                    Ok(_) => println!("   📤 TradingSignal routed to transport bus successfully"),
-                                                    Err(e) => warn!("Failed to route trading signal: {}", e),
-                                                }
-                                                
-                                                // Process signal with analytics (Phase 3: Task 3.1)
-                                                if let (Some(position_tracker), Some(pnl_calc)) = (&position_tracker, &pnl_calculator) {
-                                                    process_trading_signal_for_analytics(&signal, position_tracker, pnl_calc).await;
-                                                }
-                                            }
+                                            token_subscriptions.observe(&market_event);
+                                            sink.deliver(market_event, "ingestion-service-001").await;
                                         }
                                     }
                                     Err(e) => {
@@ -863,9 +7093,9 @@ impl BadgerOrchestrator {
                                         if let Some(value) = data.get("value") {
                                             if let Some(account) = value.get("account") {
                                                 if let Some(owner) = account.get("owner").and_then(|o| o.as_str()) {
-                                                    let dex_type = badger::core::DexType::from_program_id(owner);
-                                                    if dex_type != badger::core::DexType::Unknown {
-                                                        println!("   🤔 This was a {:?} program update but parsing failed - might need parser improvement", dex_type);
+                                                    if let Some(program) = program_registry::observe(owner) {
+                                                        println!("   🤔 This was a {} ({}) program update but parsing failed - might need parser improvement",
+                                                            program.venue, program.kind.as_str());
                                                     }
                                                 }
                                             }
@@ -874,12 +7104,64 @@ impl BadgerOrchestrator {
                                 }
                             }
                             WebSocketEvent::SlotUpdate { subscription_id, data } => {
+                                if let Some(slot) = data.get("slot").and_then(|s| s.as_u64()) {
+                                    chain_lag.observe_slot(slot);
+                                }
                                 parse_and_display_slot_update(subscription_id, &data);
                             }
+                            WebSocketEvent::BlockUpdate { data, .. } if block_ingest.config().enabled => {
+                                // Decoded on the blocking pool; the decode slot is held until delivery ends
+                                if let Some(permit) = block_ingest.admit(&data) {
+                                    let block_ingest = block_ingest.clone();
+                                    let tracked_wallets = tracked_wallets.clone();
+                                    let token_subscriptions = token_subscriptions.clone();
+                                    let sink = sink.clone();
+                                    tokio::spawn(async move {
+                                        let _permit = permit;
+                                        let decoded = tokio::task::spawn_blocking(move || {
+                                            block_ingest.process_block(data, |wallet| {
+                                                tracked_wallets.as_ref().is_some_and(|cache| cache.contains_profile(wallet))
+                                            })
+                                        }).await;
+                                        match decoded {
+                                            Ok(market_events) => {
+                                                for market_event in market_events {
+                                                    token_subscriptions.observe(&market_event);
+                                                    sink.deliver(market_event, "ingestion-service-001").await;
+                                                }
+                                            }
+                                            Err(e) => warn!("Block decoding task failed: {}", e),
+                                        }
+                                    });
+                                }
+                            }
                             WebSocketEvent::BlockUpdate { subscription_id, data } => {
                                 println!("🧱 BLOCK UPDATE [{}]: {}", subscription_id,
                                     serde_json::to_string_pretty(&data).unwrap_or_else(|_| format!("{:?}", data)));
                             }
+                            WebSocketEvent::LogsNotification { data, .. } => {
+                                // Fetched off the event loop; the fetch slot is held until decoding ends
+                                if let Some((signature, permit)) = log_ingest.admit(&data) {
+                                    let log_ingest = log_ingest.clone();
+                                    let tracked_wallets = tracked_wallets.clone();
+                                    let rpc_client = pipeline.endpoint_prober.analytics_client(CommitmentConsumer::Sniper);
+                                    let token_subscriptions = token_subscriptions.clone();
+                                    let sink = sink.clone();
+                                    tokio::spawn(async move {
+                                        let _permit = permit;
+                                        let is_tracked = |wallet: &str| tracked_wallets.as_ref().is_some_and(|cache| cache.contains_profile(wallet));
+                                        match log_ingest.fetch_events(&rpc_client, &signature, is_tracked).await {
+                                            Ok(market_events) => {
+                                                for market_event in market_events {
+                                                    token_subscriptions.observe(&market_event);
+                                                    sink.deliver(market_event, "ingestion-service-001").await;
+                                                }
+                                            }
+                                            Err(e) => debug!("Failed to fetch logged transaction {}: {}", signature, e),
+                                        }
+                                    });
+                                }
+                            }
                             WebSocketEvent::Error { error } => {
                                 error!("❌ WebSocket error: {}", error);
                             }
@@ -889,12 +7171,24 @@ impl BadgerOrchestrator {
                     }
                     
                     
+                    // Stream lags the chain: reconnect to the next endpoint
+                    _ = chain_lag.failover_requested() => {
+                        warn!("⏱️ Event stream lagging the chain - failing over ingest endpoint");
+                        client.request_failover();
+                    }
+                    
                     // Handle shutdown signal
                     _ = shutdown_rx.recv() => {
                         info!("🛑 Ingestion service received shutdown signal - aborting immediately");
                         if let Some(handle) = client_handle.as_mut() {
                             handle.abort();
                         }
+                        if let Some(handle) = subscription_handle.as_ref() {
+                            handle.abort();
+                        }
+                        if let Some(handle) = token_subscription_handle.as_ref() {
+                            handle.abort();
+                        }
                         break;
                     }
                     
@@ -935,6 +7229,8 @@ impl BadgerOrchestrator {
         let mut shutdown_rx = self.shutdown_tx.subscribe();
         let transport_bus = self.transport_bus.clone();
         let service_registry = self.service_registry.clone();
+        let token_registry = self.token_registry.clone();
+        let wallet_labels = self.wallet_labels.clone();
         
         // Use a one-shot channel to synchronize subscription completion
         let (ready_tx, ready_rx) = tokio::sync::oneshot::channel();
@@ -982,9 +7278,12 @@ impl BadgerOrchestrator {
                                     token.freeze_authority.as_ref().map(|s| &s[..8]).unwrap_or("None"));
                             }
                             MarketEvent::SwapDetected { swap } => {
-                                println!("  💱 Swap: {} | {} -> {} | Wallet: {}...{} | DEX: {:?}", 
+                                let wallet = wallet_labels.get(&swap.wallet).unwrap_or_else(|| {
+                                    format!("{}...{}", &swap.wallet[..4], &swap.wallet[swap.wallet.len()-4..])
+                                });
+                                println!("  💱 Swap: {} | {} -> {} | Wallet: {} | DEX: {:?}", 
                                     &swap.signature[..8], &swap.token_in[..8], &swap.token_out[..8], 
-                                    &swap.wallet[..4], &swap.wallet[swap.wallet.len()-4..], swap.dex);
+                                    wallet, swap.dex);
                             }
                             MarketEvent::LargeTransferDetected { transfer } => {
                                 println!("  💸 Large Transfer: {} | Token: {} | Amount: {} | USD: ${:.2}", 
@@ -1000,19 +7299,23 @@ impl BadgerOrchestrator {
                         println!("🎯 TRANSPORT BUS - TradingSignal received:");
                         info!("🎯 TRANSPORT BUS - TradingSignal received:");
                         match &trading_signal {
-                            TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source } => {
+                            TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source, .. } => {
                                 println!("  🟢 BUY SIGNAL: Token: {} | Confidence: {:.1}% | Max: {:.3} SOL", 
-                                    &token_mint[..8], confidence * 100.0, max_amount_sol);
+                                    token_registry.label(token_mint), confidence * 100.0, max_amount_sol);
                                 println!("      Reason: {} | Source: {:?}", reason, source);
                             }
                             TradingSignal::Sell { token_mint, price_target, stop_loss, reason } => {
                                 println!("  🔴 SELL SIGNAL: Token: {} | Target: {:.6} | Stop: {:.6}", 
-                                    &token_mint[..8], price_target, stop_loss);
+                                    token_registry.label(token_mint), price_target, stop_loss);
                                 println!("      Reason: {}", reason);
                             }
                             TradingSignal::SwapActivity { token_mint, volume_increase, whale_activity } => {
                                 println!("  📈 SWAP ACTIVITY: Token: {} | Volume +{:.1}% | Whale: {}", 
-                                    &token_mint[..8], volume_increase * 100.0, whale_activity);
+                                    token_registry.label(token_mint), volume_increase * 100.0, whale_activity);
+                            }
+                            TradingSignal::Hedge { instrument, direction, size_sol, exposure_sol, .. } => {
+                                println!("  🛡️ HEDGE: {:?} {:.3} SOL {} | Exposure: {:.3} SOL", 
+                                    direction, size_sol, instrument, exposure_sol);
                             }
                         }
                     }
@@ -1116,15 +7419,126 @@ impl BadgerOrchestrator {
         // Start transport monitoring first to capture all events
         self.start_transport_monitoring_service().await?;
         
+        // Ingest-only process: no database, events go to the analyzer process
+        if self.options.role == ProcessRole::Ingest {
+            return self.start_ingestion_service().await;
+        }
+        
         // Initialize Phase 3 database services
         self.initialize_database_services().await?;
         
-        // Start ingestion service
-        self.start_ingestion_service().await?;
+        // Start operator control before ingestion so startup alerts are recorded
+        self.start_operator_control_service().await?;
+        
+        // Heartbeat this instance and take over from a running one with --takeover
+        self.start_handoff_service().await?;
+        
+        // Watch for signals that are emitted but never executed
+        self.start_signal_outbox_monitor().await?;
+        
+        // Release working orders (DCA tranches, ladder exits, rebalances) when due
+        self.start_working_order_service().await?;
+        
+        // Replay executions an operator requeued from the dead-letter queue
+        self.start_dead_letter_service().await?;
+        
+        // Replay insider activity missed during downtime before copy trading
+        self.start_insider_backfill_service().await?;
+        
+        // Check execution venues and the submission path before trading starts
+        self.start_venue_health_service().await?;
+        
+        // Start ingestion service, or receive events from a separate ingest process
+        if self.options.role == ProcessRole::Analyzer {
+            self.start_ipc_receiver_service().await?;
+        } else {
+            self.start_ingestion_service().await?;
+        }
+        
+        // Exercise the signal pipeline with fixture data in test mode
+        if self.options.fixtures {
+            self.start_fixture_replay_service().await?;
+        }
         
         // Start analytics reporting service (Phase 3: Task 3.1)
         self.start_analytics_reporting_service().await?;
         
+        // Recommend SOL-perp hedges when exposure gets too large
+        self.start_hedge_service().await?;
+        
+        // Shrink entries step by step as equity draws down from its peak
+        self.start_risk_manager_service().await?;
+        
+        // Keep the insider profile and launch time cache within its memory ceiling
+        self.start_insider_cache_service().await?;
+        
+        // Track fee spend of our wallets against the daily budget
+        self.start_fee_budget_service().await?;
+        
+        // Count realized P&L against the daily and weekly loss limits
+        self.start_loss_limit_service().await?;
+        
+        // Free room under the per-token exposure cap as positions are sold
+        self.start_exposure_service().await?;
+        
+        // Mirror every fill into the tracking-only tax ledger
+        self.start_tax_ledger_service().await?;
+        
+        // Parse our own fills for exact entry and exit prices
+        self.start_own_fill_service().await?;
+        
+        // Propose daily sweeps of realized profit to cold storage
+        self.start_cold_sweep_service().await?;
+        
+        // Propose converting a share of new realized profit into USDC
+        self.start_profit_lock_service().await?;
+        
+        // Integrity check, vacuum and ANALYZE in the low-activity window
+        self.start_database_maintenance_service().await?;
+        
+        // Consolidate residual token dust in our trading wallets
+        self.start_dust_consolidation_service().await?;
+        
+        // Track top-holder distribution of held and candidate tokens
+        self.start_holder_snapshot_service().await?;
+        
+        // Keep the execution path on the lowest-lag RPC endpoint
+        self.start_endpoint_probe_service().await?;
+        
+        // Maintain pre-aggregated strategy, insider and token summaries
+        self.start_summary_refresh_service().await?;
+        
+        // Watch the event stream for lag behind the chain and clock skew
+        self.start_chain_lag_service().await?;
+        
+        // Resolve insider buy outcomes so insider scoring is not starved
+        self.start_outcome_resolver_service().await?;
+        
+        // Track balances of the trading pool, cold and reserve wallets
+        self.start_wallet_portfolio_service().await?;
+        
+        // Price open positions through the shared market-data provider pool
+        self.start_market_data_service().await?;
+        
+        // Resolve token symbols and decimals for reports and alerts
+        self.start_token_registry_service().await?;
+        
+        // Look up the chain history of tokens whose age is unknown or only bounded
+        self.start_token_age_service().await?;
+        
+        // Exit positions that break below their volume-profile support
+        self.start_position_monitor_service().await?;
+        
+        // Surface programs the registry cannot classify
+        self.start_program_registry_service().await?;
+        
+        // Accept hand-curated signals from authenticated external systems
+        #[cfg(feature = "api")]
+        self.start_signal_api_service().await?;
+        
+        // Forward position events and alerts to webhooks, routine ones as digests
+        self.start_notification_service().await?;
+        
         // Display transport bus statistics and start periodic monitoring
         let stats = self.transport_bus.get_statistics().await;
         info!("📊 Initial Transport Bus Statistics:");
@@ -1132,17 +7546,21 @@ impl BadgerOrchestrator {
         info!("  - Trading Signal Subscribers: {}", stats.signal_subscribers);  
         info!("  - Wallet Event Subscribers: {}", stats.wallet_subscribers);
         info!("  - System Alert Subscribers: {}", stats.alert_subscribers);
+        info!("  - Position Event Subscribers: {}", stats.position_subscribers);
         
         // Start periodic transport statistics reporting
         let transport_stats_bus = self.transport_bus.clone();
+        let self_trade_guard = self.self_trade_guard.clone();
         let stats_task = tokio::spawn(async move {
             let mut interval = tokio::time::interval(Duration::from_secs(30));
             loop {
                 interval.tick().await;
+                self_trade_guard.prune_expired();
                 let stats = transport_stats_bus.get_statistics().await;
                 
                 if stats.market_events_sent > 0 || stats.trading_signals_sent > 0 || 
-                   stats.wallet_events_sent > 0 || stats.system_alerts_sent > 0 {
+                   stats.wallet_events_sent > 0 || stats.system_alerts_sent > 0 ||
+                   stats.position_events_sent > 0 {
                     println!("\n📈 TRANSPORT BUS ACTIVITY (Last 30s):");
                     println!("  🔥 Market Events: {} sent | {} subscribers", 
                         stats.market_events_sent, stats.market_subscribers);
@@ -1152,6 +7570,8 @@ impl BadgerOrchestrator {
                         stats.wallet_events_sent, stats.wallet_subscribers);
                     println!("  🚨 System Alerts: {} sent | {} subscribers", 
                         stats.system_alerts_sent, stats.alert_subscribers);
+                    println!("  📌 Position Events: {} sent | {} subscribers", 
+                        stats.position_events_sent, stats.position_subscribers);
                 }
             }
         });
@@ -1183,6 +7603,20 @@ impl BadgerOrchestrator {
     async fn shutdown_all(&mut self) -> Result<()> {
         info!("🛑 Initiating graceful shutdown of all services");
         
+        // Stop trading, then let a standby instance know it can take over without waiting for staleness
+        self.owns_trading.store(false, Ordering::SeqCst);
+        if let Some(handoff) = &self.handoff {
+            match handoff.current().await {
+                Ok(Some(instance)) if instance.status != INSTANCE_RETIRED => {
+                    if let Err(e) = handoff.retire(None, None).await {
+                        warn!("Failed to retire instance: {}", e);
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Failed to read instance status: {}", e),
+            }
+        }
+        
         // Send shutdown signal to all services
         let _ = self.shutdown_tx.send(());
         debug!("Shutdown signal broadcasted to all services");
@@ -1288,7 +7722,22 @@ async fn async_main() -> Result<()> {
     info!("  🔍 High-performance data queries");
     info!("Performance: Zero-delay processing + comprehensive data persistence");
 
-    let mut orchestrator = BadgerOrchestrator::new();
+    let options = LaunchOptions::from_args()?;
+    if options.is_test_mode() {
+        info!("🧪 Test mode: devnet={} fixtures={}", options.devnet, options.fixtures);
+    }
+    if options.fixtures {
+        badger::core::rng::seed_rng(options.replay_seed());
+        info!("🎲 Replay RNG seeded with {}", options.replay_seed());
+    }
+    if let Some(config) = options.chaos.as_deref().and_then(ChaosConfig::profile) {
+        chaos::enable(config);
+        warn!("🌪️ Chaos profile {} enabled: {:?}", options.chaos.as_deref().unwrap_or_default(), config);
+    }
+    let run_for = options.run_for;
+
+    let mut orchestrator = BadgerOrchestrator::new(options);
+    let retired = orchestrator.retired.clone();
     
     // Start all services
     match orchestrator.start_all_services().await {
@@ -1304,15 +7753,30 @@ async fn async_main() -> Result<()> {
         }
     }
 
-    // Wait for shutdown signal (Ctrl+C)
-    match signal::ctrl_c().await {
-        Ok(()) => {
-            info!("🛑 Shutdown signal received (Ctrl+C) - initiating immediate shutdown");
-            println!("🛑 Shutting down Badger...");
+    // Wait for shutdown signal (Ctrl+C) or the --run-for deadline
+    let run_deadline = async {
+        match run_for {
+            Some(secs) => tokio::time::sleep(Duration::from_secs(secs)).await,
+            None => std::future::pending::<()>().await,
         }
-        Err(e) => {
-            error!("❌ Failed to listen for shutdown signal: {}", e);
-            // Continue with shutdown anyway
+    };
+
+    tokio::select! {
+        result = signal::ctrl_c() => match result {
+            Ok(()) => {
+                info!("🛑 Shutdown signal received (Ctrl+C) - initiating immediate shutdown");
+                println!("🛑 Shutting down Badger...");
+            }
+            Err(e) => {
+                error!("❌ Failed to listen for shutdown signal: {}", e);
+                // Continue with shutdown anyway
+            }
+        },
+        _ = run_deadline => {
+            info!("⏱️ Run duration elapsed - initiating shutdown");
+        }
+        _ = retired.notified() => {
+            info!("🔁 Trading handed over to the new instance - initiating shutdown");
         }
     }
     
@@ -1,11 +1,29 @@
 use anyhow::Result;
+use tracing::info;
 use crate::core::types::Token;
+use crate::ingest::token_metadata::TokenMetadata;
+use super::socials::{QualityScore, SocialsConfig, SocialsScorer};
 
-pub struct HoneypotFilter;
+pub struct HoneypotFilter {
+    socials: SocialsScorer,
+}
 
 impl HoneypotFilter {
     pub fn new() -> Self {
-        Self
+        Self {
+            socials: SocialsScorer::new(Some(SocialsConfig::from_env())),
+        }
+    }
+
+    /// Whether a new launch's socials and metadata clear the sniper's minimum quality score
+    pub async fn check_launch_quality(&self, metadata: &TokenMetadata) -> Result<(bool, QualityScore)> {
+        let score = self.socials.score(metadata).await;
+        let passes = self.socials.passes(&score);
+        if !passes {
+            info!("🚫 Skipping {}: launch quality {:.2} below {:.2} {:?}",
+                  metadata.mint, score.score, self.socials.config().min_score, score.flags);
+        }
+        Ok((passes, score))
     }
 
     pub async fn quick_honeypot_check(&self, token: &Token) -> Result<bool> {
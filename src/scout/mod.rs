@@ -1,7 +1,9 @@
 pub mod scanner;
 pub mod liquidity;
 pub mod filter;
+pub mod socials;
 
 pub use scanner::*;
 pub use liquidity::LiquidityScanner;
-pub use filter::*;
\ No newline at end of file
+pub use filter::*;
+pub use socials::*;
\ No newline at end of file
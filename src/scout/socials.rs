@@ -0,0 +1,288 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
+use tracing::debug;
+
+use crate::ingest::token_metadata::TokenMetadata;
+
+/// Launches remembered after which the oldest are pruned
+const PRUNE_THRESHOLD: usize = 20_000;
+
+/// Descriptions shorter than this are too generic to fingerprint
+const MIN_FINGERPRINT_CHARS: usize = 40;
+
+/// Configuration for launch metadata scoring
+#[derive(Debug, Clone)]
+pub struct SocialsConfig {
+    /// Longest the metadata URI fetch may take (milliseconds)
+    pub fetch_timeout_ms: u64,
+    /// Gateway that `ipfs://` URIs are fetched through
+    pub ipfs_gateway: String,
+    /// How long a launch's name and description count against newer launches (seconds)
+    pub duplicate_window_secs: i64,
+    /// Score below which the sniper filter skips a launch (0.0 - 1.0)
+    pub min_score: f64,
+}
+
+impl Default for SocialsConfig {
+    fn default() -> Self {
+        Self {
+            fetch_timeout_ms: 3_000,
+            ipfs_gateway: "https://ipfs.io/ipfs/".to_string(),
+            duplicate_window_secs: 6 * 3600,
+            min_score: 0.4,
+        }
+    }
+}
+
+impl SocialsConfig {
+    /// Defaults with the minimum score overridden by `BADGER_SNIPER_MIN_QUALITY_SCORE`
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(score) = std::env::var("BADGER_SNIPER_MIN_QUALITY_SCORE").ok().and_then(|v| v.parse().ok()) {
+            config.min_score = score;
+        }
+        config
+    }
+}
+
+/// Off-chain metadata a launch's URI points to
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LaunchMetadata {
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub website: Option<String>,
+    pub twitter: Option<String>,
+    pub telegram: Option<String>,
+}
+
+impl LaunchMetadata {
+    /// Read the fields launchpads use: top level (pump.fun), `extensions` or `properties`
+    pub fn from_json(json: &Value) -> Self {
+        let field = |name: &str| -> Option<String> {
+            [Some(json), json.get("extensions"), json.get("properties")]
+                .into_iter()
+                .flatten()
+                .find_map(|object| object.get(name).and_then(Value::as_str))
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+        Self {
+            description: field("description"),
+            image: field("image"),
+            website: field("website"),
+            twitter: field("twitter").or_else(|| field("x")),
+            telegram: field("telegram"),
+        }
+    }
+}
+
+/// Heuristic quality of a new launch's metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualityScore {
+    pub token_mint: String,
+    /// 0.0 (obvious low effort) - 1.0
+    pub score: f64,
+    pub metadata_fetched: bool,
+    pub has_image: bool,
+    pub has_website: bool,
+    pub has_twitter: bool,
+    pub has_telegram: bool,
+    /// Recent launch with the same name or symbol
+    pub duplicate_of: Option<String>,
+    /// Recent launch with the same description
+    pub copied_description_of: Option<String>,
+    /// Why points were taken off
+    pub flags: Vec<String>,
+    pub scored_at: i64,
+}
+
+/// Scores new launches on their socials and metadata
+///
+/// On-chain checks (authorities, holder concentration) say nothing about
+/// effort: most low-effort scams ship without a website or socials, reuse
+/// the name of a launch that just ran, or paste a description from one. The
+/// scorer fetches the metadata URI, credits populated socials, website and
+/// image, and penalizes names and description fingerprints already seen on
+/// another mint inside the duplicate window.
+pub struct SocialsScorer {
+    config: SocialsConfig,
+    http: reqwest::Client,
+    /// Normalized name or symbol -> (first mint, seen at)
+    names: DashMap<String, (String, i64)>,
+    /// Description fingerprint -> (first mint, seen at)
+    descriptions: DashMap<u64, (String, i64)>,
+    scores: DashMap<String, QualityScore>,
+}
+
+impl SocialsScorer {
+    pub fn new(config: Option<SocialsConfig>) -> Self {
+        let config = config.unwrap_or_default();
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.fetch_timeout_ms))
+            .build()
+            .unwrap_or_default();
+        Self {
+            config,
+            http,
+            names: DashMap::new(),
+            descriptions: DashMap::new(),
+            scores: DashMap::new(),
+        }
+    }
+
+    pub fn config(&self) -> &SocialsConfig {
+        &self.config
+    }
+
+    /// Score of a launch scored before
+    pub fn cached(&self, token_mint: &str) -> Option<QualityScore> {
+        self.scores.get(token_mint).map(|score| score.clone())
+    }
+
+    /// Whether a score clears the sniper's minimum
+    pub fn passes(&self, score: &QualityScore) -> bool {
+        score.score >= self.config.min_score
+    }
+
+    /// Fetch a launch's metadata URI and score it; cached per mint
+    pub async fn score(&self, metadata: &TokenMetadata) -> QualityScore {
+        if let Some(score) = self.cached(&metadata.mint) {
+            return score;
+        }
+
+        let launch = match self.fetch(&metadata.uri).await {
+            Ok(launch) => Some(launch),
+            Err(e) => {
+                debug!("Metadata of {} not fetched from {:?}: {}", metadata.mint, metadata.uri, e);
+                None
+            }
+        };
+        let score = self.score_launch(metadata, launch.as_ref());
+        debug!("🧾 Launch quality of {}: {:.2} {:?}", metadata.mint, score.score, score.flags);
+        self.scores.insert(metadata.mint.clone(), score.clone());
+        score
+    }
+
+    /// Score already fetched metadata and remember the launch for duplicate checks
+    pub fn score_launch(&self, metadata: &TokenMetadata, launch: Option<&LaunchMetadata>) -> QualityScore {
+        let now = Utc::now().timestamp();
+        self.prune(now);
+
+        let mut score = QualityScore {
+            token_mint: metadata.mint.clone(),
+            score: 0.0,
+            metadata_fetched: launch.is_some(),
+            has_image: false,
+            has_website: false,
+            has_twitter: false,
+            has_telegram: false,
+            duplicate_of: None,
+            copied_description_of: None,
+            flags: Vec::new(),
+            scored_at: now,
+        };
+
+        match launch {
+            Some(launch) => {
+                score.has_image = launch.image.is_some();
+                score.has_website = launch.website.as_deref().map_or(false, is_link);
+                score.has_twitter = launch.twitter.as_deref().map_or(false, is_link);
+                score.has_telegram = launch.telegram.as_deref().map_or(false, is_link);
+                let credited = [
+                    (score.has_image, 0.15, "no image"),
+                    (score.has_website, 0.25, "no website"),
+                    (score.has_twitter, 0.25, "no twitter"),
+                    (score.has_telegram, 0.15, "no telegram"),
+                    (launch.description.is_some(), 0.2, "no description"),
+                ];
+                for (present, points, flag) in credited {
+                    if present {
+                        score.score += points;
+                    } else {
+                        score.flags.push(flag.to_string());
+                    }
+                }
+
+                if let Some(fingerprint) = launch.description.as_deref().and_then(description_fingerprint) {
+                    let first = self.descriptions.entry(fingerprint)
+                        .or_insert_with(|| (metadata.mint.clone(), now))
+                        .0.clone();
+                    if first != metadata.mint {
+                        score.score -= 0.4;
+                        score.flags.push(format!("description copied from {}", first));
+                        score.copied_description_of = Some(first);
+                    }
+                }
+            }
+            None => score.flags.push("metadata unreachable".to_string()),
+        }
+
+        for key in [normalize(&metadata.name), normalize(&metadata.symbol)].into_iter().filter(|key| !key.is_empty()) {
+            let first = self.names.entry(key.clone())
+                .or_insert_with(|| (metadata.mint.clone(), now))
+                .0.clone();
+            if first != metadata.mint && score.duplicate_of.is_none() {
+                score.score -= 0.4;
+                score.flags.push(format!("name {:?} reused from {}", key, first));
+                score.duplicate_of = Some(first);
+            }
+        }
+
+        score.score = score.score.clamp(0.0, 1.0);
+        score
+    }
+
+    async fn fetch(&self, uri: &str) -> Result<LaunchMetadata> {
+        let url = match uri.strip_prefix("ipfs://") {
+            Some(cid) => format!("{}{}", self.config.ipfs_gateway, cid),
+            None if uri.starts_with("http://") || uri.starts_with("https://") => uri.to_string(),
+            None => anyhow::bail!("Unsupported metadata URI"),
+        };
+        let json: Value = self.http.get(&url).send().await
+            .context("Failed to fetch metadata URI")?
+            .error_for_status()
+            .context("Metadata URI returned an error")?
+            .json().await
+            .context("Metadata URI is not JSON")?;
+        Ok(LaunchMetadata::from_json(&json))
+    }
+
+    fn prune(&self, now: i64) {
+        if self.names.len() + self.descriptions.len() + self.scores.len() < PRUNE_THRESHOLD {
+            return;
+        }
+        let horizon = now - self.config.duplicate_window_secs;
+        self.names.retain(|_, (_, seen_at)| *seen_at >= horizon);
+        self.descriptions.retain(|_, (_, seen_at)| *seen_at >= horizon);
+        self.scores.retain(|_, score| score.scored_at >= horizon);
+    }
+}
+
+/// Lowercase letters and digits only, so "PEPE 2.0" and "pepe2.0" collide
+fn normalize(text: &str) -> String {
+    text.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+/// Hash of a description with case, punctuation and spacing ignored
+fn description_fingerprint(description: &str) -> Option<u64> {
+    let normalized = normalize(description);
+    if normalized.chars().count() < MIN_FINGERPRINT_CHARS {
+        return None;
+    }
+    let mut hasher = DefaultHasher::new();
+    normalized.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+/// Whether a social field holds something more than a placeholder
+fn is_link(value: &str) -> bool {
+    let value = value.trim();
+    value.starts_with("https://") || value.starts_with("http://") || value.starts_with('@') || value.starts_with("t.me/")
+}
@@ -1,4 +1,5 @@
 use anyhow::{Result, Context, bail};
+use base64::Engine;
 use serde::{Deserialize, Serialize};
 use tokio::time::{timeout, Duration};
 use tracing::{info, debug, warn, error, instrument};
@@ -6,13 +7,23 @@ use reqwest::Client;
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::{
     pubkey::Pubkey,
-    transaction::Transaction,
+    transaction::VersionedTransaction,
     instruction::Instruction,
-    signature::{Signature, Keypair},
+    signature::Signature,
     commitment_config::CommitmentConfig,
 };
 use std::str::FromStr;
 use std::collections::HashMap;
+use tokio::sync::Mutex;
+use std::sync::Arc;
+use crate::ingest::{ConfirmationConfig, ConfirmationStats, SignatureConfirmations};
+use super::lookup_tables::{LookupTableConfig, LookupTableManager};
+use super::signer::{Signer, sign_versioned_transaction};
+use super::landing::{LandingConfig, LandingStats, LandingTelemetry};
+use super::pathway::{JitoClient, PathwayConfig, Prioritization, SwapTemplate};
+use super::slippage_guard::{SlippageGuard, SlippageGuardConfig};
+use super::token_accounts::AtaCache;
+use crate::transport::ExecutionPathway;
 
 /// Configuration for DEX operations
 #[derive(Debug, Clone)]
@@ -23,12 +34,18 @@ pub struct DexConfig {
     pub jupiter_api_url: String,
     /// Maximum slippage tolerance in basis points (100 = 1%)
     pub max_slippage_bps: u16,
-    /// Priority fee in lamports for transaction priority
-    pub priority_fee_lamports: u64,
     /// Request timeout in seconds
     pub request_timeout_secs: u64,
-    /// Maximum retries for failed transactions
-    pub max_retries: u32,
+    /// Landing telemetry window and adaptive retry/fee escalation bounds
+    pub landing: LandingConfig,
+    /// Address lookup tables for our frequently used accounts
+    pub lookup_tables: LookupTableConfig,
+    /// Jito tips, swap templates and low-urgency batching
+    pub pathway: PathwayConfig,
+    /// Largest price move since the signal a swap is still signed at
+    pub slippage_guard: SlippageGuardConfig,
+    /// Confirmation timeout and polling fallback
+    pub confirmation: ConfirmationConfig,
 }
 
 impl Default for DexConfig {
@@ -37,9 +54,12 @@ impl Default for DexConfig {
             rpc_endpoint: "https://api.mainnet-beta.solana.com".to_string(),
             jupiter_api_url: "https://quote-api.jup.ag/v6".to_string(),
             max_slippage_bps: 50, // 0.5% default slippage
-            priority_fee_lamports: 1000, // 0.000001 SOL priority fee
             request_timeout_secs: 30,
-            max_retries: 3,
+            landing: LandingConfig::default(),
+            lookup_tables: LookupTableConfig::default(),
+            pathway: PathwayConfig::default(),
+            slippage_guard: SlippageGuardConfig::from_env(),
+            confirmation: ConfirmationConfig::default(),
         }
     }
 }
@@ -59,6 +79,9 @@ pub struct SwapRequest {
     pub user_public_key: String,
     /// Whether to auto-create token accounts if needed
     pub auto_create_token_accounts: bool,
+    /// Output per input unit (raw amounts) at signal time; the swap aborts if the quote moved too far from it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference_rate: Option<f64>,
 }
 
 /// Result of a swap operation
@@ -94,7 +117,7 @@ pub struct RouteInfo {
 }
 
 /// Jupiter API quote response structure
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct JupiterQuote {
     /// Input mint
@@ -124,7 +147,7 @@ pub struct JupiterQuote {
 }
 
 /// Platform fee structure
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PlatformFee {
     /// Fee amount
@@ -134,7 +157,7 @@ pub struct PlatformFee {
 }
 
 /// Route plan step
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RoutePlan {
     /// Swap information
@@ -144,7 +167,7 @@ pub struct RoutePlan {
 }
 
 /// Swap information for route step
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SwapInfo {
     /// AMM key
@@ -177,14 +200,26 @@ pub struct JupiterSwapResponse {
 
 /// Unified DEX client for multiple DEX integrations
 pub struct DexClient {
-    /// HTTP client for API requests
-    http_client: Client,
     /// Solana RPC client
     rpc_client: RpcClient,
     /// Configuration
     config: DexConfig,
     /// Jupiter client
     jupiter_client: JupiterClient,
+    /// Address lookup tables used for transactions we build
+    lookup_tables: Mutex<LookupTableManager>,
+    /// Landing outcomes of sent transactions, driving the retry policy
+    landing: LandingTelemetry,
+    /// Block engine critical swaps are sent through
+    jito_client: JitoClient,
+    /// Pre-built quotes for critical swaps, by input and output mint
+    templates: Mutex<HashMap<String, SwapTemplate>>,
+    /// Re-validates quotes against the signal-time price before signing
+    slippage_guard: SlippageGuard,
+    /// Associated token accounts verified or created this session
+    token_accounts: AtaCache,
+    /// Signature subscriptions sent transactions are confirmed through
+    confirmations: Arc<SignatureConfirmations>,
 }
 
 impl DexClient {
@@ -217,29 +252,54 @@ impl DexClient {
             http_client.clone(),
         );
         
+        let mut lookup_tables = LookupTableManager::new(config.lookup_tables.clone())
+            .context("Failed to initialize lookup tables")?;
+        lookup_tables.refresh(&rpc_client)
+            .context("Failed to load lookup tables")?;
+        
+        let jito_client = JitoClient::new(
+            config.pathway.jito_block_engine_url.clone(),
+            http_client,
+        );
+        
         info!("DEX client initialized successfully");
         
         Ok(Self {
-            http_client,
             rpc_client,
+            landing: LandingTelemetry::new(config.landing.clone()),
+            slippage_guard: SlippageGuard::new(config.slippage_guard.clone()),
+            confirmations: Arc::new(SignatureConfirmations::new(Some(config.confirmation.clone()))),
             config,
             jupiter_client,
+            lookup_tables: Mutex::new(lookup_tables),
+            jito_client,
+            templates: Mutex::new(HashMap::new()),
+            token_accounts: AtaCache::new(),
         })
     }
     
+    /// Confirms through a registry shared with the ingestion WebSocket client
+    /// 
+    /// The registry built in `new` has no WebSocket client attached, so
+    /// without this confirmations are polled.
+    pub fn with_confirmations(mut self, confirmations: Arc<SignatureConfirmations>) -> Self {
+        self.confirmations = confirmations;
+        self
+    }
+    
     /// Executes a token swap using the best available route
     /// 
     /// # Arguments
     /// * `swap_request` - Swap parameters
-    /// * `wallet_keypair` - User's wallet keypair for signing
+    /// * `signer` - Wallet signer (in-process or remote)
     /// 
     /// # Returns
     /// * `Result<SwapResult>` - Result of the swap operation
-    #[instrument(skip(self, wallet_keypair))]
+    #[instrument(skip(self, signer))]
     pub async fn execute_swap(
         &self,
         swap_request: &SwapRequest,
-        wallet_keypair: &Keypair,
+        signer: &dyn Signer,
     ) -> Result<SwapResult> {
         info!(
             input_mint = %swap_request.input_mint,
@@ -251,6 +311,7 @@ impl DexClient {
         
         // Get quote from Jupiter (best aggregator)
         let quote = self.jupiter_client.get_quote(swap_request).await?;
+        self.check_price_move(swap_request, &quote)?;
         
         info!(
             input_amount = %quote.in_amount,
@@ -260,11 +321,9 @@ impl DexClient {
             "Received swap quote from Jupiter"
         );
         
-        // Get swap transaction from Jupiter
-        let swap_transaction = self.jupiter_client.get_swap_transaction(&quote, wallet_keypair).await?;
-        
-        // Execute the transaction
-        let signature = self.submit_transaction(&swap_transaction, wallet_keypair).await?;
+        // Submit, re-requesting the swap at an escalated priority fee on each retry
+        let (signature, priority_fee_lamports) = self.submit_swap(&quote, signer).await?;
+        self.record_output_account(swap_request, signer);
         
         // Parse amounts from quote
         let input_amount = quote.in_amount.parse::<u64>()
@@ -276,7 +335,7 @@ impl DexClient {
         let route_info = self.extract_route_info(&quote)?;
         
         // Calculate actual fee (this would normally come from transaction confirmation)
-        let fee_lamports = self.config.priority_fee_lamports + 5000; // Base fee estimate
+        let fee_lamports = priority_fee_lamports + 5000; // Base fee estimate
         
         let result = SwapResult {
             signature: signature.to_string(),
@@ -300,58 +359,353 @@ impl DexClient {
         Ok(result)
     }
     
-    /// Submits a transaction to the Solana network with retry logic
+    /// Executes a token swap on the pathway chosen by the signal's urgency
+    /// 
+    /// Critical swaps use a pre-built template when one is fresh and go out
+    /// through the Jito block engine with a high tip; everything else takes
+    /// the standard Jupiter path (batching happens before the swap is built).
     /// 
     /// # Arguments
-    /// * `transaction` - Transaction to submit
-    /// * `wallet_keypair` - Signing keypair
+    /// * `swap_request` - Swap parameters
+    /// * `signer` - Wallet signer (in-process or remote)
+    /// * `pathway` - Execution pathway for the signal's urgency
     /// 
     /// # Returns
-    /// * `Result<Signature>` - Transaction signature
-    #[instrument(skip(self, transaction, wallet_keypair))]
-    async fn submit_transaction(
+    /// * `Result<SwapResult>` - Result of the swap operation
+    #[instrument(skip(self, signer))]
+    pub async fn execute_swap_via(
+        &self,
+        swap_request: &SwapRequest,
+        signer: &dyn Signer,
+        pathway: ExecutionPathway,
+    ) -> Result<SwapResult> {
+        match pathway {
+            ExecutionPathway::JitoTemplate => self.execute_critical_swap(swap_request, signer).await,
+            ExecutionPathway::Jupiter | ExecutionPathway::Batched => self.execute_swap(swap_request, signer).await,
+        }
+    }
+    
+    /// Quotes a swap ahead of time so a later critical swap can skip quoting
+    /// 
+    /// Templates are kept per input and output mint; a newer one replaces
+    /// the old. Typically built for the full exit of a position right after
+    /// it is opened.
+    #[instrument(skip(self))]
+    pub async fn prepare_template(&self, swap_request: &SwapRequest) -> Result<()> {
+        let quote = self.jupiter_client.get_quote(swap_request).await?;
+        let key = SwapTemplate::key(&swap_request.input_mint, &swap_request.output_mint);
+        self.templates.lock().await.insert(key, SwapTemplate::new(swap_request.clone(), quote));
+        debug!(input_mint = %swap_request.input_mint, output_mint = %swap_request.output_mint, "Swap template prepared");
+        Ok(())
+    }
+    
+    /// Fresh template for a pair, if one was prepared
+    pub async fn template(&self, input_mint: &str, output_mint: &str) -> Option<SwapTemplate> {
+        let max_age = Duration::from_millis(self.config.pathway.template_max_age_ms);
+        self.templates.lock().await
+            .get(&SwapTemplate::key(input_mint, output_mint))
+            .filter(|template| template.is_fresh(max_age))
+            .cloned()
+    }
+    
+    /// Drops the template of a pair once it has been used or no longer applies
+    pub async fn discard_template(&self, input_mint: &str, output_mint: &str) {
+        self.templates.lock().await.remove(&SwapTemplate::key(input_mint, output_mint));
+    }
+    
+    /// Executes a critical swap through the Jito block engine with a high tip
+    /// 
+    /// Swaps against the pair's template when it matches the request and is
+    /// fresh, otherwise quotes first. The tip escalates on each retry like
+    /// the priority fee does on the standard path.
+    #[instrument(skip(self, signer))]
+    async fn execute_critical_swap(
         &self,
-        transaction: &Transaction,
-        wallet_keypair: &Keypair,
-    ) -> Result<Signature> {
-        let mut attempts = 0;
-        let max_attempts = self.config.max_retries + 1;
-        
-        while attempts < max_attempts {
-            attempts += 1;
+        swap_request: &SwapRequest,
+        signer: &dyn Signer,
+    ) -> Result<SwapResult> {
+        // A template the price has moved away from is re-quoted before giving up
+        let template = self.template(&swap_request.input_mint, &swap_request.output_mint).await
+            .filter(|template| template.request.amount == swap_request.amount)
+            .filter(|template| self.check_price_move(swap_request, &template.quote).is_ok());
+        let quote = match &template {
+            Some(template) => template.quote.clone(),
+            None => {
+                let quote = self.jupiter_client.get_quote(swap_request).await?;
+                self.check_price_move(swap_request, &quote)?;
+                quote
+            }
+        };
+        self.discard_template(&swap_request.input_mint, &swap_request.output_mint).await;
+        
+        info!(
+            input_amount = %quote.in_amount,
+            output_amount = %quote.out_amount,
+            from_template = template.is_some(),
+            "Executing critical swap through Jito"
+        );
+        
+        let pathway = &self.config.pathway;
+        let policy = self.landing.policy();
+        let max_attempts = policy.max_attempts();
+        let started = std::time::Instant::now();
+        let mut tip_lamports = pathway.critical_tip_lamports;
+        let mut landed = None;
+        
+        for attempt in 1..=max_attempts {
+            tip_lamports = ((pathway.critical_tip_lamports as f64 * policy.fee_escalation.powi(attempt as i32 - 1)) as u64)
+                .min(pathway.max_tip_lamports);
             
-            debug!(attempt = attempts, max_attempts, "Submitting transaction to network");
+            let result = match self.jupiter_client.get_swap_transaction(&quote, signer, Prioritization::JitoTip(tip_lamports)).await {
+                Ok(transaction) => match self.jito_client.send_transaction(&transaction).await {
+                    Ok(signature) => self.confirmations.wait(&self.rpc_client, &signature).await
+                        .map(|_| signature)
+                        .context("Jito transaction was not confirmed"),
+                    Err(e) => Err(e),
+                },
+                Err(e) => Err(e),
+            };
             
-            match self.rpc_client.send_and_confirm_transaction(transaction) {
+            match result {
                 Ok(signature) => {
+                    let latency_ms = started.elapsed().as_millis() as u64;
+                    self.landing.record_landed(signature.to_string(), attempt, tip_lamports, latency_ms);
+                    landed = Some(signature);
+                    break;
+                }
+                Err(e) => warn!(error = %e, attempt, max_attempts, tip_lamports, "Critical swap attempt failed"),
+            }
+        }
+        
+        let Some(signature) = landed else {
+            self.landing.record_dropped(max_attempts, tip_lamports);
+            bail!("Critical swap failed to land after {} attempts (last tip {} lamports)", max_attempts, tip_lamports);
+        };
+        self.record_output_account(swap_request, signer);
+        
+        let result = SwapResult {
+            signature: signature.to_string(),
+            input_mint: swap_request.input_mint.clone(),
+            output_mint: swap_request.output_mint.clone(),
+            input_amount: quote.in_amount.parse().context("Failed to parse input amount")?,
+            output_amount: quote.out_amount.parse().context("Failed to parse output amount")?,
+            fee_lamports: tip_lamports + 5000, // Base fee estimate
+            price_impact_percent: quote.price_impact_pct.parse().ok(),
+            route_info: Some(self.extract_route_info(&quote)?),
+        };
+        
+        info!(signature = %result.signature, tip_lamports, "Critical swap executed successfully");
+        Ok(result)
+    }
+    
+    /// Aborts a swap whose quote moved too far from the signal-time price
+    /// 
+    /// The error is a `PriceMoved`, so callers can count guard aborts apart
+    /// from other failures.
+    fn check_price_move(&self, swap_request: &SwapRequest, quote: &JupiterQuote) -> Result<()> {
+        let Some(reference_rate) = swap_request.reference_rate else {
+            return Ok(());
+        };
+        if let Err(moved) = self.slippage_guard.check(reference_rate, quote) {
+            warn!(
+                input_mint = %swap_request.input_mint,
+                output_mint = %swap_request.output_mint,
+                reference_rate = moved.reference_rate,
+                quoted_rate = moved.quoted_rate,
+                move_bps = moved.move_bps,
+                "Aborting swap: price moved past the guard band"
+            );
+            return Err(moved.into());
+        }
+        Ok(())
+    }
+    
+    /// Submits a swap with the adaptive retry policy and records whether it landed
+    /// 
+    /// Each retry requests a fresh swap transaction from Jupiter at the
+    /// escalated priority fee, since a signed transaction's fee is fixed.
+    /// 
+    /// # Arguments
+    /// * `quote` - Jupiter quote to swap against
+    /// * `signer` - Wallet signer
+    /// 
+    /// # Returns
+    /// * `Result<(Signature, u64)>` - Transaction signature and the priority fee it landed with
+    #[instrument(skip(self, quote, signer))]
+    async fn submit_swap(
+        &self,
+        quote: &JupiterQuote,
+        signer: &dyn Signer,
+    ) -> Result<(Signature, u64)> {
+        let policy = self.landing.policy();
+        let max_attempts = policy.max_attempts();
+        let started = std::time::Instant::now();
+        let mut priority_fee_lamports = policy.initial_fee_lamports;
+        
+        for attempt in 1..=max_attempts {
+            priority_fee_lamports = policy.fee_for_attempt(attempt);
+            
+            debug!(attempt, max_attempts, priority_fee_lamports, "Submitting transaction to network");
+            
+            let prioritization = Prioritization::PriorityFee(priority_fee_lamports);
+            let result = match self.jupiter_client.get_swap_transaction(quote, signer, prioritization).await {
+                Ok(transaction) => {
+                    self.lookup_tables.lock().await.record_usage(&transaction);
+                    self.submit_transaction(&transaction).await
+                }
+                Err(e) => Err(e),
+            };
+            
+            match result {
+                Ok(signature) => {
+                    let latency_ms = started.elapsed().as_millis() as u64;
                     info!(
                         signature = %signature,
-                        attempts = attempts,
+                        attempts = attempt,
+                        priority_fee_lamports,
+                        latency_ms,
                         "Transaction confirmed successfully"
                     );
-                    return Ok(signature);
+                    self.landing.record_landed(signature.to_string(), attempt, priority_fee_lamports, latency_ms);
+                    return Ok((signature, priority_fee_lamports));
                 }
                 Err(e) => {
                     error!(
                         error = %e,
-                        attempt = attempts,
-                        max_attempts = max_attempts,
+                        attempt,
+                        max_attempts,
+                        priority_fee_lamports,
                         "Transaction submission failed"
                     );
                     
-                    if attempts >= max_attempts {
-                        return Err(anyhow::anyhow!("Transaction failed after {} attempts: {}", max_attempts, e));
+                    if attempt < max_attempts {
+                        // Wait before retrying (exponential backoff)
+                        let delay_ms = 500 * (2_u64.pow(attempt - 1));
+                        debug!(delay_ms = delay_ms, "Waiting before retry");
+                        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
                     }
-                    
-                    // Wait before retrying (exponential backoff)
-                    let delay_ms = 1000 * (2_u64.pow(attempts - 1));
-                    debug!(delay_ms = delay_ms, "Waiting before retry");
-                    tokio::time::sleep(Duration::from_millis(delay_ms)).await;
                 }
             }
         }
         
-        unreachable!("Should have returned or failed in the loop above")
+        self.landing.record_dropped(max_attempts, priority_fee_lamports);
+        bail!("Transaction failed to land after {} attempts (last priority fee {} lamports)", max_attempts, priority_fee_lamports)
+    }
+    
+    /// Sends a signed transaction once and waits for confirmation
+    /// 
+    /// # Arguments
+    /// * `transaction` - Signed transaction to submit
+    /// 
+    /// # Returns
+    /// * `Result<Signature>` - Transaction signature
+    async fn submit_transaction(&self, transaction: &VersionedTransaction) -> Result<Signature> {
+        let signature = self.rpc_client.send_transaction(transaction)
+            .context("Failed to send transaction")?;
+        self.confirmations.wait(&self.rpc_client, &signature).await
+            .context("Transaction was not confirmed")?;
+        Ok(signature)
+    }
+    
+    /// Jito tip, template and batching settings
+    pub fn pathway_config(&self) -> &PathwayConfig {
+        &self.config.pathway
+    }
+    
+    /// How confirmations were detected, and the status requests they cost
+    pub fn confirmation_stats(&self) -> ConfirmationStats {
+        self.confirmations.stats()
+    }
+    
+    /// Landing rate and latency/fee percentiles of recently sent transactions
+    pub fn landing_stats(&self) -> LandingStats {
+        self.landing.stats()
+    }
+    
+    /// Create instruction for an associated token account our transaction needs, if it may be missing
+    /// 
+    /// Accounts already verified or created this session are answered from
+    /// the cache without an RPC call. Call `confirm_token_account` once the
+    /// transaction landed, or `release_token_account` if it failed.
+    /// 
+    /// # Arguments
+    /// * `payer` - Fee payer of the create
+    /// * `owner` - Wallet owning the token account
+    /// * `mint` - Token mint
+    /// 
+    /// # Returns
+    /// * `Result<Option<Instruction>>` - Idempotent create, or None if none is needed
+    pub fn token_account_instruction(&self, payer: &Pubkey, owner: &Pubkey, mint: &Pubkey) -> Result<Option<Instruction>> {
+        self.token_accounts.create_instruction(&self.rpc_client, payer, owner, mint)
+    }
+    
+    /// Records a token account created by a transaction that landed
+    pub fn confirm_token_account(&self, owner: &Pubkey, mint: &Pubkey) {
+        self.token_accounts.mark_exists(owner, mint);
+    }
+    
+    /// Drops a pending token account create whose transaction failed
+    pub fn release_token_account(&self, owner: &Pubkey, mint: &Pubkey) {
+        self.token_accounts.release(owner, mint);
+    }
+    
+    /// Whether the wallet's account for a mint is known to exist
+    pub fn has_token_account(&self, owner: &str, mint: &str) -> bool {
+        match (Pubkey::from_str(owner), Pubkey::from_str(mint)) {
+            (Ok(owner), Ok(mint)) => self.token_accounts.is_known(&owner, &mint),
+            _ => false,
+        }
+    }
+    
+    /// Keeps the token account cache in step with a landed swap
+    /// 
+    /// Jupiter creates the output account when it is missing, and closes the
+    /// wrapped SOL account again when SOL is the output.
+    fn record_output_account(&self, swap_request: &SwapRequest, signer: &dyn Signer) {
+        let Ok(mint) = Pubkey::from_str(&swap_request.output_mint) else { return };
+        let owner = signer.pubkey();
+        if mint == spl_token::native_mint::id() {
+            self.token_accounts.invalidate(&owner, &mint);
+        } else {
+            self.token_accounts.mark_exists(&owner, &mint);
+        }
+    }
+    
+    /// Builds a signed v0 transaction from instructions using our lookup tables
+    /// 
+    /// # Arguments
+    /// * `instructions` - Instructions to include
+    /// * `signer` - Fee payer and signer
+    /// 
+    /// # Returns
+    /// * `Result<VersionedTransaction>` - Transaction ready for submission
+    pub async fn build_transaction(
+        &self,
+        instructions: &[Instruction],
+        signer: &dyn Signer,
+    ) -> Result<VersionedTransaction> {
+        let blockhash = self.rpc_client.get_latest_blockhash()
+            .context("Failed to fetch blockhash")?;
+        
+        let mut lookup_tables = self.lookup_tables.lock().await;
+        let transaction = lookup_tables.compile(signer, instructions, blockhash).await?;
+        lookup_tables.record_usage(&transaction);
+        
+        Ok(transaction)
+    }
+    
+    /// Moves frequently used accounts into lookup tables, creating or extending tables as needed
+    /// 
+    /// # Arguments
+    /// * `signer` - Table authority and fee payer
+    /// 
+    /// # Returns
+    /// * `Result<usize>` - Number of create/extend transactions sent
+    #[instrument(skip(self, signer))]
+    pub async fn sync_lookup_tables(&self, signer: &dyn Signer) -> Result<usize> {
+        let signatures = self.lookup_tables.lock().await
+            .sync(&self.rpc_client, signer).await?;
+        Ok(signatures.len())
     }
     
     /// Extracts route information from Jupiter quote
@@ -428,6 +782,7 @@ impl DexClient {
             slippage_bps: self.config.max_slippage_bps,
             user_public_key: "11111111111111111111111111111111".to_string(), // Dummy key for quote
             auto_create_token_accounts: false,
+            reference_rate: None,
         };
         
         let quote = self.jupiter_client.get_quote(&swap_request).await?;
@@ -489,11 +844,13 @@ impl JupiterClient {
     async fn get_quote(&self, swap_request: &SwapRequest) -> Result<JupiterQuote> {
         let url = format!("{}/quote", self.api_url);
         
+        let amount = swap_request.amount.to_string();
+        let slippage_bps = swap_request.slippage_bps.to_string();
         let mut params = HashMap::new();
         params.insert("inputMint", swap_request.input_mint.as_str());
         params.insert("outputMint", swap_request.output_mint.as_str());
-        params.insert("amount", &swap_request.amount.to_string());
-        params.insert("slippageBps", &swap_request.slippage_bps.to_string());
+        params.insert("amount", amount.as_str());
+        params.insert("slippageBps", slippage_bps.as_str());
         
         debug!(url = %url, params = ?params, "Requesting quote from Jupiter");
         
@@ -527,26 +884,29 @@ impl JupiterClient {
     /// 
     /// # Arguments
     /// * `quote` - Jupiter quote
-    /// * `wallet_keypair` - User's wallet keypair
+    /// * `signer` - User's wallet signer
+    /// * `prioritization` - Priority fee or Jito tip for this attempt
     /// 
     /// # Returns
-    /// * `Result<Transaction>` - Swap transaction ready for signing
-    #[instrument(skip(self, wallet_keypair))]
+    /// * `Result<VersionedTransaction>` - Signed swap transaction
+    #[instrument(skip(self, signer))]
     async fn get_swap_transaction(
         &self,
         quote: &JupiterQuote,
-        wallet_keypair: &Keypair,
-    ) -> Result<Transaction> {
+        signer: &dyn Signer,
+        prioritization: Prioritization,
+    ) -> Result<VersionedTransaction> {
         let url = format!("{}/swap", self.api_url);
         
         let request_body = serde_json::json!({
             "quoteResponse": quote,
-            "userPublicKey": wallet_keypair.pubkey().to_string(),
+            "userPublicKey": signer.pubkey().to_string(),
             "wrapAndUnwrapSol": true,
             "useSharedAccounts": true,
             "feeAccount": null,
             "dynamicComputeUnitLimit": true,
-            "prioritizationFeeLamports": "auto"
+            "asLegacyTransaction": false,
+            "prioritizationFeeLamports": prioritization.to_json()
         });
         
         debug!(url = %url, "Requesting swap transaction from Jupiter");
@@ -568,14 +928,16 @@ impl JupiterClient {
             .context("Failed to parse Jupiter swap response")?;
         
         // Decode the base64 transaction
-        let transaction_bytes = base64::decode(&swap_response.swap_transaction)
+        let transaction_bytes = base64::engine::general_purpose::STANDARD.decode(&swap_response.swap_transaction)
             .context("Failed to decode swap transaction")?;
         
-        let mut transaction: Transaction = bincode::deserialize(&transaction_bytes)
+        // Routes are v0 messages referencing Jupiter's lookup tables
+        let mut transaction: VersionedTransaction = bincode::deserialize(&transaction_bytes)
             .context("Failed to deserialize swap transaction")?;
         
         // Sign the transaction
-        transaction.partial_sign(&[wallet_keypair], transaction.message.recent_blockhash);
+        sign_versioned_transaction(signer, &mut transaction).await
+            .context("Failed to sign swap transaction")?;
         
         debug!("Swap transaction prepared and signed");
         
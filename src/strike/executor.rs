@@ -1,42 +1,53 @@
 use anyhow::{Result, Context};
 use crate::core::types::{Signal, Token, SignalType};
 use crate::transport::signal_bus::SignalBus;
+use crate::transport::ExecutionPathway;
+use crate::ingest::SignatureConfirmations;
 use tracing::{info, debug, warn, error, instrument};
-use chrono::Utc;
 use super::dex_client::{DexClient, DexConfig, SwapRequest, SwapResult};
+use super::landing::LandingStats;
+use super::pathway::LowUrgencyBatch;
+use super::slippage_guard::PriceMoved;
 use super::wallet::{WalletManager, WalletConfig, SigningRequest};
-use solana_sdk::{
-    pubkey::Pubkey,
-    transaction::Transaction,
-};
-use std::str::FromStr;
+use crate::database::{TradeRecord, TradeRecordStore};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
 
 /// Production-ready trade executor with real DEX integration
-#[derive(Debug)]
 pub struct TradeExecutor {
     /// Signal bus for receiving trading signals
     signal_bus: SignalBus,
-    /// Database connection for trade records
-    db: BadgerDB,
+    /// Store for trade records
+    trade_records: Arc<TradeRecordStore>,
     /// DEX client for executing swaps
     dex_client: DexClient,
     /// Secure wallet manager for transaction signing
     wallet_manager: WalletManager,
+    /// Low-urgency signals waiting to be executed together
+    batch: LowUrgencyBatch,
+    /// Token amounts bought and not yet sold, kept quoted for critical exits
+    held: HashMap<String, u64>,
+    /// Swaps aborted by the slippage guard, per strategy
+    slippage_aborts: HashMap<String, u64>,
 }
 
 impl TradeExecutor {
     /// Creates a new trade executor with full DEX and wallet integration
     /// 
     /// # Arguments
-    /// * `db` - Database connection for storing trade records
+    /// * `trade_records` - Store for executed and failed trade records
     /// * `dex_config` - Optional DEX configuration (uses defaults if None)
     /// * `wallet_config` - Optional wallet configuration (uses defaults if None)
     /// 
     /// # Returns
     /// * `Result<Self>` - Trade executor instance ready for production trading
-    #[instrument]
+    #[instrument(skip(trade_records, dex_config, wallet_config))]
     pub async fn new(
-        db: BadgerDB,
+        trade_records: Arc<TradeRecordStore>,
         dex_config: Option<DexConfig>,
         wallet_config: Option<WalletConfig>,
     ) -> Result<Self> {
@@ -44,6 +55,7 @@ impl TradeExecutor {
         
         // Initialize DEX client with real Solana integration
         let dex_config = dex_config.unwrap_or_default();
+        let batch = LowUrgencyBatch::new(&dex_config.pathway);
         let dex_client = DexClient::new(dex_config)
             .context("Failed to initialize DEX client")?;
         
@@ -68,12 +80,21 @@ impl TradeExecutor {
         
         Ok(Self {
             signal_bus: SignalBus::new(),
-            db,
+            trade_records,
             dex_client,
             wallet_manager,
+            batch,
+            held: HashMap::new(),
+            slippage_aborts: HashMap::new(),
         })
     }
     
+    /// Confirms swaps through a registry shared with the ingestion WebSocket client
+    pub fn with_confirmations(mut self, confirmations: Arc<SignatureConfirmations>) -> Self {
+        self.dex_client = self.dex_client.with_confirmations(confirmations);
+        self
+    }
+    
     /// Default approval logic for high-value transactions
     /// 
     /// # Arguments
@@ -132,20 +153,35 @@ impl TradeExecutor {
             "Wallet statistics at startup"
         );
         
-        while let Ok(signal) = signal_receiver.recv().await {
-            if let Err(e) = self.execute_signal(&signal).await {
-                error!(
-                    signal_type = ?signal.signal_type,
-                    token_symbol = %signal.token.symbol,
-                    amount_sol = signal.amount_sol,
-                    error = %e,
-                    "Failed to execute trading signal"
-                );
+        let mut batch_interval = tokio::time::interval(Duration::from_millis(250));
+        let mut template_interval = tokio::time::interval(Duration::from_millis(
+            (self.dex_client.pathway_config().template_max_age_ms / 2).max(1000)
+        ));
+        
+        loop {
+            tokio::select! {
+                received = signal_receiver.recv() => {
+                    let Ok(signal) = received else { break };
+                    if signal.urgency.pathway() == ExecutionPathway::Batched && !matches!(signal.signal_type, SignalType::Alert) {
+                        debug!(token_mint = %signal.token.mint, held = self.batch.len() + 1, "Holding low-urgency signal for batch");
+                        self.batch.push(signal);
+                        continue;
+                    }
+                    self.execute_and_record(&signal).await;
+                }
                 
-                // Record failed trade attempt in database
-                if let Err(db_error) = self.record_failed_trade(&signal, &e).await {
-                    error!(error = %db_error, "Failed to record trade failure in database");
+                _ = batch_interval.tick() => {
+                    if !self.batch.is_due() {
+                        continue;
+                    }
+                    let signals = self.batch.drain();
+                    info!(swaps = signals.len(), "Executing low-urgency batch");
+                    for signal in signals {
+                        self.execute_and_record(&signal).await;
+                    }
                 }
+                
+                _ = template_interval.tick() => self.refresh_exit_templates().await,
             }
         }
         
@@ -153,6 +189,50 @@ impl TradeExecutor {
         Ok(())
     }
     
+    /// Executes a signal, recording the attempt if it fails
+    async fn execute_and_record(&mut self, signal: &Signal) {
+        if let Err(e) = self.execute_signal(signal).await {
+            if e.chain().any(|cause| cause.is::<PriceMoved>()) {
+                let strategy = signal.strategy.clone().unwrap_or_else(|| "unattributed".to_string());
+                let aborts = self.slippage_aborts.entry(strategy.clone()).or_default();
+                *aborts += 1;
+                info!(strategy = %strategy, aborts = *aborts, token_mint = %signal.token.mint, "Swap aborted by slippage guard");
+            }
+            
+            error!(
+                signal_type = ?signal.signal_type,
+                token_symbol = %signal.token.symbol,
+                amount_sol = signal.amount_sol,
+                urgency = ?signal.urgency,
+                error = %e,
+                "Failed to execute trading signal"
+            );
+            
+            // Record failed trade attempt in database
+            if let Err(db_error) = self.record_failed_trade(signal, &e).await {
+                error!(error = %db_error, "Failed to record trade failure in database");
+            }
+        }
+    }
+    
+    /// Re-quotes the full exit of every held token so critical sells can skip quoting
+    async fn refresh_exit_templates(&self) {
+        for (token_mint, amount) in &self.held {
+            let swap_request = SwapRequest {
+                input_mint: token_mint.clone(),
+                output_mint: SOL_MINT.to_string(),
+                amount: *amount,
+                slippage_bps: 100,
+                user_public_key: self.wallet_manager.pubkey().to_string(),
+                auto_create_token_accounts: false,
+                reference_rate: None,
+            };
+            if let Err(e) = self.dex_client.prepare_template(&swap_request).await {
+                debug!(token_mint = %token_mint, error = %e, "Failed to refresh exit template");
+            }
+        }
+    }
+    
     /// Executes a trading signal by performing real swaps on Solana DEXes
     /// 
     /// # Arguments
@@ -171,12 +251,13 @@ impl TradeExecutor {
             "Processing trading signal"
         );
         
+        let pathway = signal.urgency.pathway();
         match signal.signal_type {
             SignalType::Buy => {
-                self.execute_buy_order(&signal.token, signal.amount_sol).await?;
+                self.execute_buy_order(&signal.token, signal.amount_sol, signal.reference_price, pathway).await?;
             }
             SignalType::Sell => {
-                self.execute_sell_order(&signal.token, signal.amount_sol).await?;
+                self.execute_sell_order(&signal.token, signal.amount_sol, signal.reference_price, pathway).await?;
             }
             SignalType::Alert => {
                 info!(
@@ -197,11 +278,13 @@ impl TradeExecutor {
     /// # Arguments
     /// * `token` - Token to purchase
     /// * `amount_sol` - Amount of SOL to spend
+    /// * `reference_price` - SOL per token when the signal fired, for the slippage guard
+    /// * `pathway` - Execution pathway for the signal's urgency
     /// 
     /// # Returns
     /// * `Result<()>` - Ok if buy order was executed successfully
     #[instrument(skip(self))]
-    async fn execute_buy_order(&mut self, token: &Token, amount_sol: f64) -> Result<()> {
+    async fn execute_buy_order(&mut self, token: &Token, amount_sol: f64, reference_price: Option<f64>, pathway: ExecutionPathway) -> Result<()> {
         info!(
             token_symbol = %token.symbol,
             token_mint = %token.mint,
@@ -220,11 +303,16 @@ impl TradeExecutor {
             amount: amount_lamports,
             slippage_bps: 50, // 0.5% slippage tolerance
             user_public_key: self.wallet_manager.pubkey().to_string(),
-            auto_create_token_accounts: true,
+            auto_create_token_accounts: !self.dex_client.has_token_account(&self.wallet_manager.pubkey().to_string(), &token.mint),
+            // Raw token units per lamport at the signal-time price
+            reference_rate: reference_price
+                .filter(|price| *price > 0.0)
+                .map(|price| 10f64.powi(token.decimals as i32) / price / 1_000_000_000.0),
         };
         
         // Execute the swap through DEX client
-        let swap_result = self.execute_dex_swap(swap_request, "BUY").await?;
+        let swap_result = self.execute_dex_swap(swap_request, "BUY", pathway).await?;
+        *self.held.entry(token.mint.clone()).or_default() += swap_result.output_amount;
         
         // Record successful trade in database
         let mut trade_record = TradeRecord::new(
@@ -246,7 +334,7 @@ impl TradeExecutor {
         trade_record.profit_loss = Some(0.0);
         
         // Store in database
-        if let Err(e) = self.db.record_trade(trade_record).await {
+        if let Err(e) = self.trade_records.record_trade(trade_record).await {
             error!(error = %e, "Failed to record buy trade in database");
         } else {
             info!(
@@ -267,11 +355,13 @@ impl TradeExecutor {
     /// # Arguments
     /// * `token` - Token to sell
     /// * `amount_sol` - Estimated SOL value of tokens to sell
+    /// * `reference_price` - SOL per token when the signal fired, for the slippage guard
+    /// * `pathway` - Execution pathway for the signal's urgency
     /// 
     /// # Returns
     /// * `Result<()>` - Ok if sell order was executed successfully
     #[instrument(skip(self))]
-    async fn execute_sell_order(&mut self, token: &Token, amount_sol: f64) -> Result<()> {
+    async fn execute_sell_order(&mut self, token: &Token, amount_sol: f64, reference_price: Option<f64>, pathway: ExecutionPathway) -> Result<()> {
         info!(
             token_symbol = %token.symbol,
             token_mint = %token.mint,
@@ -280,24 +370,36 @@ impl TradeExecutor {
             "⚡ Executing SELL order on DEX"
         );
         
-        // For sell orders, we need to determine how many tokens to sell to get approximately amount_sol
-        // This requires getting a reverse quote or estimating based on current price
-        
-        // First, get current price to estimate token amount
-        let sol_mint = "So11111111111111111111111111111111111111112";
-        let price = self.dex_client.get_price(&token.mint, sol_mint, 1_000_000).await
-            .context("Failed to get current token price")?;
+        let sol_mint = SOL_MINT;
         
-        if price <= 0.0 {
-            return Err(anyhow::anyhow!("Invalid token price: {}", price));
-        }
+        // Critical exits sell the whole position against its pre-built template, skipping the price lookup
+        let template = match pathway {
+            ExecutionPathway::JitoTemplate => self.dex_client.template(&token.mint, sol_mint).await,
+            _ => None,
+        };
         
-        // Estimate token amount needed (with some buffer for price changes)
-        let estimated_token_amount = ((amount_sol * 1_000_000_000.0) / price * 1.1) as u64; // 10% buffer
+        let estimated_token_amount = match &template {
+            Some(template) => template.request.amount,
+            None => {
+                // For sell orders, we need to determine how many tokens to sell to get approximately amount_sol
+                // This requires getting a reverse quote or estimating based on current price
+                
+                // First, get current price to estimate token amount
+                let price = self.dex_client.get_price(&token.mint, sol_mint, 1_000_000).await
+                    .context("Failed to get current token price")?;
+                
+                if price <= 0.0 {
+                    return Err(anyhow::anyhow!("Invalid token price: {}", price));
+                }
+                
+                // Estimate token amount needed (with some buffer for price changes)
+                ((amount_sol * 1_000_000_000.0) / price * 1.1) as u64 // 10% buffer
+            }
+        };
         
         debug!(
-            price = price,
             estimated_token_amount = estimated_token_amount,
+            from_template = template.is_some(),
             "Estimated token amount for sell order"
         );
         
@@ -309,10 +411,21 @@ impl TradeExecutor {
             slippage_bps: 100, // Higher slippage tolerance for sells (1%)
             user_public_key: self.wallet_manager.pubkey().to_string(),
             auto_create_token_accounts: false, // SOL account should exist
+            // Lamports per raw token unit at the signal-time price
+            reference_rate: reference_price
+                .filter(|price| *price > 0.0)
+                .map(|price| price * 1_000_000_000.0 / 10f64.powi(token.decimals as i32)),
         };
         
         // Execute the swap through DEX client
-        let swap_result = self.execute_dex_swap(swap_request, "SELL").await?;
+        let swap_result = self.execute_dex_swap(swap_request, "SELL", pathway).await?;
+        if let Some(held) = self.held.get_mut(&token.mint) {
+            *held = held.saturating_sub(swap_result.input_amount);
+            if *held == 0 {
+                self.held.remove(&token.mint);
+                self.dex_client.discard_template(&token.mint, sol_mint).await;
+            }
+        }
         
         // Record successful trade in database
         let mut trade_record = TradeRecord::new(
@@ -336,7 +449,7 @@ impl TradeExecutor {
         trade_record.profit_loss = Some(actual_sol_received - gas_fee_sol);
         
         // Store in database
-        if let Err(e) = self.db.record_trade(trade_record).await {
+        if let Err(e) = self.trade_records.record_trade(trade_record).await {
             error!(error = %e, "Failed to record sell trade in database");
         } else {
             info!(
@@ -358,13 +471,15 @@ impl TradeExecutor {
     /// # Arguments
     /// * `swap_request` - Swap parameters
     /// * `operation_type` - Type of operation for logging ("BUY" or "SELL")
+    /// * `pathway` - Execution pathway for the signal's urgency
     /// 
     /// # Returns
     /// * `Result<SwapResult>` - Result of the swap operation
     #[instrument(skip(self))]
-    async fn execute_dex_swap(&mut self, swap_request: SwapRequest, operation_type: &str) -> Result<SwapResult> {
+    async fn execute_dex_swap(&mut self, swap_request: SwapRequest, operation_type: &str, pathway: ExecutionPathway) -> Result<SwapResult> {
         debug!(
             operation = operation_type,
+            pathway = ?pathway,
             input_mint = %swap_request.input_mint,
             output_mint = %swap_request.output_mint,
             amount = swap_request.amount,
@@ -373,11 +488,11 @@ impl TradeExecutor {
         );
         
         // Execute swap through DEX client (this handles Jupiter integration, transaction building, etc.)
-        let swap_result = self.dex_client.execute_swap(
+        let signer = self.wallet_manager.signer();
+        let swap_result = self.dex_client.execute_swap_via(
             &swap_request,
-            // Note: In a real implementation, we'd need to pass the actual Keypair
-            // For now, this is a placeholder that would need wallet integration
-            &solana_sdk::signature::Keypair::new() // TODO: Get from wallet manager
+            signer.as_ref(),
+            pathway,
         ).await
             .with_context(|| format!("Failed to execute {} swap", operation_type))?;
         
@@ -420,7 +535,7 @@ impl TradeExecutor {
         trade_record.error_message = Some(error.to_string());
         trade_record.profit_loss = Some(0.0); // No P&L for failed trades
         
-        self.db.record_trade(trade_record).await
+        self.trade_records.record_trade(trade_record).await
             .context("Failed to record trade failure")?;
         
         debug!("Failed trade recorded in database for audit");
@@ -432,25 +547,23 @@ impl TradeExecutor {
     /// # Returns
     /// * `Result<TradingStats>` - Current trading performance statistics
     pub async fn get_trading_stats(&self) -> Result<TradingStats> {
-        // Get database statistics
-        let db_stats = self.db.get_database_stats().await
-            .context("Failed to get database stats")?;
+        let summary = self.trade_records.summary().await
+            .context("Failed to summarize trade records")?;
         
         // Get wallet statistics
         let wallet_stats = self.wallet_manager.get_wallet_stats();
         
-        // TODO: Query recent trades from database to calculate performance metrics
-        // For now, return basic stats
-        
         Ok(TradingStats {
             wallet_pubkey: wallet_stats.wallet_pubkey,
             total_trades_attempted: wallet_stats.total_transactions,
             total_volume_sol: wallet_stats.total_value_lamports as f64 / 1_000_000_000.0,
-            successful_trades: 0, // TODO: Calculate from database
-            failed_trades: 0,    // TODO: Calculate from database
-            total_fees_paid_sol: 0.0, // TODO: Calculate from database
-            net_profit_loss_sol: 0.0, // TODO: Calculate from database
-            average_slippage_percent: 0.0, // TODO: Calculate from database
+            successful_trades: summary.executed as usize,
+            failed_trades: summary.failed as usize,
+            total_fees_paid_sol: summary.total_fees_sol,
+            net_profit_loss_sol: summary.net_sol_flow,
+            average_slippage_percent: summary.average_slippage_percent,
+            landing: self.dex_client.landing_stats(),
+            slippage_aborts: self.slippage_aborts.clone(),
         })
     }
 }
@@ -470,10 +583,14 @@ pub struct TradingStats {
     pub failed_trades: usize,
     /// Total fees paid in SOL
     pub total_fees_paid_sol: f64,
-    /// Net profit/loss in SOL
+    /// Net profit/loss in SOL (SOL flow of recorded trades; open holdings count at zero)
     pub net_profit_loss_sol: f64,
     /// Average slippage percentage
     pub average_slippage_percent: f64,
+    /// Landing rate and latency/fee percentiles of recent transactions
+    pub landing: LandingStats,
+    /// Swaps aborted by the slippage guard because the price moved, per strategy
+    pub slippage_aborts: HashMap<String, u64>,
 }
 
 impl TradingStats {
@@ -0,0 +1,242 @@
+use chrono::Utc;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use tracing::{debug, info};
+
+/// Configuration for landing-rate telemetry and the adaptive retry policy
+#[derive(Debug, Clone)]
+pub struct LandingConfig {
+    /// Recent transactions the landing rate is computed over
+    pub window: usize,
+    /// Samples needed before the policy adapts (static defaults until then)
+    pub min_samples: usize,
+    /// Landing rate the policy aims for
+    pub target_landing_rate: f64,
+    /// Retry bounds (attempts after the first)
+    pub min_retries: u32,
+    pub max_retries: u32,
+    /// Priority fee of the first attempt (lamports)
+    pub base_priority_fee_lamports: u64,
+    /// Priority fee never escalated beyond this (lamports)
+    pub max_priority_fee_lamports: u64,
+    /// Fee multiplier per retry bounds
+    pub min_fee_escalation: f64,
+    pub max_fee_escalation: f64,
+}
+
+impl Default for LandingConfig {
+    fn default() -> Self {
+        Self {
+            window: 200,
+            min_samples: 20,
+            target_landing_rate: 0.9,
+            min_retries: 1,
+            max_retries: 6,
+            base_priority_fee_lamports: 1000,
+            max_priority_fee_lamports: 5_000_000,
+            min_fee_escalation: 1.25,
+            max_fee_escalation: 3.0,
+        }
+    }
+}
+
+/// Outcome of one sent transaction, across all of its attempts
+#[derive(Debug, Clone)]
+pub struct LandingRecord {
+    pub signature: Option<String>,
+    pub landed: bool,
+    /// Attempts made, including the successful one
+    pub attempts: u32,
+    /// Priority fee of the last attempt (lamports)
+    pub priority_fee_lamports: u64,
+    /// Time from first send to confirmation (milliseconds)
+    pub latency_ms: Option<u64>,
+    pub sent_at: i64,
+}
+
+/// Landing statistics over the telemetry window
+#[derive(Debug, Clone, Default)]
+pub struct LandingStats {
+    pub samples: usize,
+    /// Share of transactions that landed within their retries
+    pub landing_rate: f64,
+    /// Share of transactions that landed on the first attempt
+    pub first_attempt_rate: f64,
+    pub latency_p50_ms: Option<u64>,
+    pub latency_p90_ms: Option<u64>,
+    pub latency_p99_ms: Option<u64>,
+    /// Priority fee percentiles of landed transactions (lamports)
+    pub fee_p50_lamports: Option<u64>,
+    pub fee_p90_lamports: Option<u64>,
+}
+
+/// Retry and fee escalation for the next transaction
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_fee_lamports: u64,
+    pub fee_escalation: f64,
+    pub max_fee_lamports: u64,
+}
+
+impl RetryPolicy {
+    /// Total attempts including the first
+    pub fn max_attempts(&self) -> u32 {
+        self.max_retries + 1
+    }
+
+    /// Priority fee for an attempt (1-based)
+    pub fn fee_for_attempt(&self, attempt: u32) -> u64 {
+        let fee = self.initial_fee_lamports as f64 * self.fee_escalation.powi(attempt.saturating_sub(1) as i32);
+        (fee as u64).min(self.max_fee_lamports)
+    }
+}
+
+/// Records whether and when our transactions land and adapts retries to it
+///
+/// While transactions land on the first attempt the policy backs off to
+/// fewer retries and gentle fee escalation; when the landing rate drops
+/// under the target it retries more and escalates fees faster, starting
+/// from the fee that recently landed transactions needed.
+pub struct LandingTelemetry {
+    config: LandingConfig,
+    records: Mutex<VecDeque<LandingRecord>>,
+}
+
+impl LandingTelemetry {
+    pub fn new(config: LandingConfig) -> Self {
+        Self {
+            records: Mutex::new(VecDeque::with_capacity(config.window)),
+            config,
+        }
+    }
+
+    pub fn config(&self) -> &LandingConfig {
+        &self.config
+    }
+
+    /// Record the outcome of a sent transaction
+    pub fn record(&self, record: LandingRecord) {
+        debug!(
+            signature = ?record.signature,
+            landed = record.landed,
+            attempts = record.attempts,
+            fee_lamports = record.priority_fee_lamports,
+            latency_ms = ?record.latency_ms,
+            "Transaction landing recorded"
+        );
+
+        let mut records = self.records.lock().unwrap();
+        if records.len() >= self.config.window {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+
+    /// Record a transaction that landed
+    pub fn record_landed(&self, signature: String, attempts: u32, priority_fee_lamports: u64, latency_ms: u64) {
+        self.record(LandingRecord {
+            signature: Some(signature),
+            landed: true,
+            attempts,
+            priority_fee_lamports,
+            latency_ms: Some(latency_ms),
+            sent_at: Utc::now().timestamp(),
+        });
+    }
+
+    /// Record a transaction that never landed
+    pub fn record_dropped(&self, attempts: u32, priority_fee_lamports: u64) {
+        self.record(LandingRecord {
+            signature: None,
+            landed: false,
+            attempts,
+            priority_fee_lamports,
+            latency_ms: None,
+            sent_at: Utc::now().timestamp(),
+        });
+    }
+
+    /// Landing rate and latency/fee percentiles over the window
+    pub fn stats(&self) -> LandingStats {
+        let records = self.records.lock().unwrap();
+        if records.is_empty() {
+            return LandingStats::default();
+        }
+
+        let samples = records.len();
+        let landed: Vec<&LandingRecord> = records.iter().filter(|r| r.landed).collect();
+        let first_attempt = landed.iter().filter(|r| r.attempts == 1).count();
+
+        let mut latencies: Vec<u64> = landed.iter().filter_map(|r| r.latency_ms).collect();
+        latencies.sort_unstable();
+        let mut fees: Vec<u64> = landed.iter().map(|r| r.priority_fee_lamports).collect();
+        fees.sort_unstable();
+
+        LandingStats {
+            samples,
+            landing_rate: landed.len() as f64 / samples as f64,
+            first_attempt_rate: first_attempt as f64 / samples as f64,
+            latency_p50_ms: percentile(&latencies, 0.50),
+            latency_p90_ms: percentile(&latencies, 0.90),
+            latency_p99_ms: percentile(&latencies, 0.99),
+            fee_p50_lamports: percentile(&fees, 0.50),
+            fee_p90_lamports: percentile(&fees, 0.90),
+        }
+    }
+
+    /// Retry policy for the next transaction under current network conditions
+    pub fn policy(&self) -> RetryPolicy {
+        let config = &self.config;
+        let stats = self.stats();
+
+        if stats.samples < config.min_samples {
+            return RetryPolicy {
+                max_retries: (config.min_retries + config.max_retries) / 2,
+                initial_fee_lamports: config.base_priority_fee_lamports,
+                fee_escalation: config.min_fee_escalation,
+                max_fee_lamports: config.max_priority_fee_lamports,
+            };
+        }
+
+        // 0 when first attempts land at the target rate, 1 when nothing lands first time
+        let congestion = ((config.target_landing_rate - stats.first_attempt_rate) / config.target_landing_rate)
+            .clamp(0.0, 1.0);
+        let retry_span = (config.max_retries - config.min_retries) as f64;
+        let max_retries = config.min_retries + (retry_span * congestion).round() as u32;
+        let fee_escalation = config.min_fee_escalation
+            + (config.max_fee_escalation - config.min_fee_escalation) * congestion;
+
+        // Start at the fee that typically landed, so retries are not spent rediscovering it
+        let initial_fee_lamports = stats.fee_p50_lamports
+            .unwrap_or(config.base_priority_fee_lamports)
+            .max(config.base_priority_fee_lamports)
+            .min(config.max_priority_fee_lamports);
+
+        if stats.landing_rate < config.target_landing_rate {
+            info!(
+                landing_rate = stats.landing_rate,
+                first_attempt_rate = stats.first_attempt_rate,
+                max_retries,
+                fee_escalation,
+                "Landing rate below target - escalating retry policy"
+            );
+        }
+
+        RetryPolicy {
+            max_retries,
+            initial_fee_lamports,
+            fee_escalation,
+            max_fee_lamports: config.max_priority_fee_lamports,
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted values
+fn percentile(sorted: &[u64], quantile: f64) -> Option<u64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = ((quantile * sorted.len() as f64).ceil() as usize).clamp(1, sorted.len());
+    Some(sorted[rank - 1])
+}
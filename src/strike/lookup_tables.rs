@@ -0,0 +1,230 @@
+use anyhow::{Result, Context, bail};
+use solana_address_lookup_table_program::{
+    instruction::{create_lookup_table, extend_lookup_table},
+    state::AddressLookupTable,
+};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    address_lookup_table_account::AddressLookupTableAccount,
+    hash::Hash,
+    instruction::Instruction,
+    message::{v0, VersionedMessage},
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::{Transaction, VersionedTransaction},
+};
+use tracing::{info, debug, instrument};
+use super::signer::{Signer, sign_legacy_transaction, sign_versioned_transaction};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Maximum number of addresses a lookup table can hold
+const MAX_TABLE_ADDRESSES: usize = 256;
+
+/// Configuration for address lookup table management
+#[derive(Debug, Clone)]
+pub struct LookupTableConfig {
+    /// Existing lookup tables owned by the trading wallet
+    pub table_addresses: Vec<String>,
+    /// Times an account must appear in our transactions before it is added to a table
+    pub min_uses: u32,
+    /// Addresses per extend instruction (bounded by the legacy transaction size)
+    pub max_addresses_per_extend: usize,
+}
+
+impl Default for LookupTableConfig {
+    fn default() -> Self {
+        Self {
+            table_addresses: Vec::new(),
+            min_uses: 3,
+            max_addresses_per_extend: 20,
+        }
+    }
+}
+
+/// Manages the wallet's address lookup tables
+///
+/// Counts the accounts our transactions touch and moves the frequently used
+/// ones into lookup tables, creating a new table when the current ones are
+/// full. Transactions compiled through the manager reference those accounts
+/// by a one-byte index instead of the full 32-byte key.
+pub struct LookupTableManager {
+    /// Configuration
+    config: LookupTableConfig,
+    /// Lookup tables with their on-chain contents
+    tables: Vec<AddressLookupTableAccount>,
+    /// Account -> number of our transactions that used it
+    account_uses: HashMap<Pubkey, u32>,
+}
+
+impl LookupTableManager {
+    /// Creates a lookup table manager
+    ///
+    /// # Arguments
+    /// * `config` - Lookup table configuration
+    ///
+    /// # Returns
+    /// * `Result<Self>` - Manager tracking the configured tables (contents loaded by `refresh`)
+    pub fn new(config: LookupTableConfig) -> Result<Self> {
+        let tables = config.table_addresses.iter()
+            .map(|address| {
+                Ok(AddressLookupTableAccount {
+                    key: Pubkey::from_str(address).context("Invalid lookup table address")?,
+                    addresses: Vec::new(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            config,
+            tables,
+            account_uses: HashMap::new(),
+        })
+    }
+
+    /// Lookup tables used when compiling transactions
+    pub fn tables(&self) -> &[AddressLookupTableAccount] {
+        &self.tables
+    }
+
+    /// Counts the accounts referenced by one of our transactions
+    ///
+    /// # Arguments
+    /// * `transaction` - Transaction built or received for our wallet
+    pub fn record_usage(&mut self, transaction: &VersionedTransaction) {
+        let num_signers = transaction.message.header().num_required_signatures as usize;
+        // Signers must stay in the static keys, so they never go into a table
+        for account in transaction.message.static_account_keys().iter().skip(num_signers) {
+            *self.account_uses.entry(*account).or_insert(0) += 1;
+        }
+    }
+
+    /// Frequently used accounts that are not in any table yet
+    pub fn pending_accounts(&self) -> Vec<Pubkey> {
+        let mut pending: Vec<(Pubkey, u32)> = self.account_uses.iter()
+            .filter(|(account, uses)| **uses >= self.config.min_uses && !self.contains(account))
+            .map(|(account, uses)| (*account, *uses))
+            .collect();
+        pending.sort_by_key(|(_, uses)| std::cmp::Reverse(*uses));
+        pending.into_iter().map(|(account, _)| account).collect()
+    }
+
+    fn contains(&self, account: &Pubkey) -> bool {
+        self.tables.iter().any(|table| table.addresses.contains(account))
+    }
+
+    /// Reloads table contents from chain
+    ///
+    /// # Arguments
+    /// * `rpc_client` - RPC client for account reads
+    #[instrument(skip(self, rpc_client))]
+    pub fn refresh(&mut self, rpc_client: &RpcClient) -> Result<()> {
+        for table in self.tables.iter_mut() {
+            let account = rpc_client.get_account(&table.key)
+                .with_context(|| format!("Failed to fetch lookup table {}", table.key))?;
+            let state = AddressLookupTable::deserialize(&account.data)
+                .with_context(|| format!("Failed to decode lookup table {}", table.key))?;
+            table.addresses = state.addresses.to_vec();
+        }
+
+        debug!(
+            tables = self.tables.len(),
+            addresses = self.tables.iter().map(|t| t.addresses.len()).sum::<usize>(),
+            "Lookup tables refreshed"
+        );
+        Ok(())
+    }
+
+    /// Adds pending accounts to lookup tables, creating tables as needed
+    ///
+    /// Extended addresses become usable one slot after the extension lands.
+    ///
+    /// # Arguments
+    /// * `rpc_client` - RPC client for submission
+    /// * `authority` - Wallet that owns and pays for the tables
+    ///
+    /// # Returns
+    /// * `Result<Vec<Signature>>` - Signatures of the create/extend transactions
+    #[instrument(skip(self, rpc_client, authority))]
+    pub async fn sync(&mut self, rpc_client: &RpcClient, authority: &dyn Signer) -> Result<Vec<Signature>> {
+        let mut pending = self.pending_accounts();
+        let mut signatures = Vec::new();
+
+        while !pending.is_empty() {
+            let table_index = match self.tables.iter().position(|t| t.addresses.len() < MAX_TABLE_ADDRESSES) {
+                Some(index) => index,
+                None => {
+                    let (table, signature) = self.create_table(rpc_client, authority).await?;
+                    signatures.push(signature);
+                    self.tables.push(table);
+                    self.tables.len() - 1
+                }
+            };
+
+            let room = MAX_TABLE_ADDRESSES - self.tables[table_index].addresses.len();
+            let count = pending.len().min(room).min(self.config.max_addresses_per_extend);
+            let batch: Vec<Pubkey> = pending.drain(..count).collect();
+
+            let table_key = self.tables[table_index].key;
+            let instruction = extend_lookup_table(
+                table_key,
+                authority.pubkey(),
+                Some(authority.pubkey()),
+                batch.clone(),
+            );
+            signatures.push(Self::send_legacy(rpc_client, authority, instruction).await?);
+            self.tables[table_index].addresses.extend(batch);
+
+            info!(table = %table_key, added = count, "Extended lookup table");
+        }
+
+        Ok(signatures)
+    }
+
+    async fn create_table(&self, rpc_client: &RpcClient, authority: &dyn Signer) -> Result<(AddressLookupTableAccount, Signature)> {
+        let recent_slot = rpc_client.get_slot().context("Failed to fetch slot")?;
+        let (instruction, table_key) = create_lookup_table(authority.pubkey(), authority.pubkey(), recent_slot);
+        let signature = Self::send_legacy(rpc_client, authority, instruction).await?;
+
+        info!(table = %table_key, "Created lookup table");
+        Ok((AddressLookupTableAccount { key: table_key, addresses: Vec::new() }, signature))
+    }
+
+    async fn send_legacy(rpc_client: &RpcClient, authority: &dyn Signer, instruction: Instruction) -> Result<Signature> {
+        let blockhash = rpc_client.get_latest_blockhash().context("Failed to fetch blockhash")?;
+        let mut transaction = Transaction::new_with_payer(&[instruction], Some(&authority.pubkey()));
+        transaction.message.recent_blockhash = blockhash;
+        sign_legacy_transaction(authority, &mut transaction).await?;
+        rpc_client.send_and_confirm_transaction(&transaction)
+            .context("Lookup table transaction failed")
+    }
+
+    /// Compiles instructions into a signed v0 transaction using our lookup tables
+    ///
+    /// # Arguments
+    /// * `payer` - Fee payer and sole signer
+    /// * `instructions` - Instructions to include
+    /// * `recent_blockhash` - Blockhash for the message
+    ///
+    /// # Returns
+    /// * `Result<VersionedTransaction>` - Signed transaction
+    pub async fn compile(
+        &self,
+        payer: &dyn Signer,
+        instructions: &[Instruction],
+        recent_blockhash: Hash,
+    ) -> Result<VersionedTransaction> {
+        if instructions.is_empty() {
+            bail!("Transaction has no instructions");
+        }
+
+        let message = v0::Message::try_compile(&payer.pubkey(), instructions, &self.tables, recent_blockhash)
+            .context("Failed to compile v0 message")?;
+        let mut transaction = VersionedTransaction {
+            signatures: Vec::new(),
+            message: VersionedMessage::V0(message),
+        };
+        sign_versioned_transaction(payer, &mut transaction).await?;
+        Ok(transaction)
+    }
+}
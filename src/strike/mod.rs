@@ -3,9 +3,19 @@ pub mod sniper;
 pub mod trigger;
 pub mod dex_client;
 pub mod wallet;
+pub mod lookup_tables;
+pub mod signer;
+pub mod landing;
+pub mod pathway;
+pub mod slippage_guard;
+pub mod token_accounts;
 
-pub use executor::TradingExecutor;
+pub use executor::{TradeExecutor, TradingStats};
 pub use dex_client::DexClient;
 pub use wallet::WalletManager;
+pub use lookup_tables::{LookupTableManager, LookupTableConfig};
+pub use signer::{Signer, KeypairSigner, RemoteSigner, RemoteSignerConfig};
+pub use token_accounts::AtaCache;
+pub use landing::{LandingTelemetry, LandingConfig, LandingStats, RetryPolicy};
 pub use sniper::*;
 pub use trigger::*;
\ No newline at end of file
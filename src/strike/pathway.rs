@@ -0,0 +1,205 @@
+use anyhow::{Result, Context, bail};
+use base64::Engine;
+use reqwest::Client;
+use solana_sdk::{signature::Signature, transaction::VersionedTransaction};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+use crate::core::types::{Signal, SignalType};
+use super::dex_client::{JupiterQuote, SwapRequest};
+
+/// Configuration for urgency-based execution pathways
+#[derive(Debug, Clone)]
+pub struct PathwayConfig {
+    /// Jito block engine critical swaps are sent through
+    pub jito_block_engine_url: String,
+    /// Tip of the first critical attempt (lamports)
+    pub critical_tip_lamports: u64,
+    /// Tip never escalated beyond this (lamports)
+    pub max_tip_lamports: u64,
+    /// Pre-built swap templates older than this are re-quoted (milliseconds)
+    pub template_max_age_ms: u64,
+    /// Low-urgency signals are held this long before being executed together (milliseconds)
+    pub batch_window_ms: u64,
+    /// A batch is executed early once it holds this many signals
+    pub max_batch_size: usize,
+}
+
+impl Default for PathwayConfig {
+    fn default() -> Self {
+        Self {
+            jito_block_engine_url: "https://mainnet.block-engine.jito.wtf".to_string(),
+            critical_tip_lamports: 1_000_000, // 0.001 SOL
+            max_tip_lamports: 10_000_000,
+            template_max_age_ms: 10_000,
+            batch_window_ms: 5_000,
+            max_batch_size: 10,
+        }
+    }
+}
+
+/// How a swap transaction pays for inclusion
+#[derive(Debug, Clone, Copy)]
+pub enum Prioritization {
+    /// Compute-unit priority fee (lamports)
+    PriorityFee(u64),
+    /// Tip to a Jito tip account, added to the swap by Jupiter (lamports)
+    JitoTip(u64),
+}
+
+impl Prioritization {
+    /// Value of `prioritizationFeeLamports` in a Jupiter swap request
+    pub fn to_json(self) -> serde_json::Value {
+        match self {
+            Prioritization::PriorityFee(lamports) => serde_json::json!(lamports),
+            Prioritization::JitoTip(lamports) => serde_json::json!({ "jitoTipLamports": lamports }),
+        }
+    }
+
+    pub fn lamports(self) -> u64 {
+        match self {
+            Prioritization::PriorityFee(lamports) | Prioritization::JitoTip(lamports) => lamports,
+        }
+    }
+}
+
+/// Quote fetched ahead of time so a critical swap skips the quote round-trip
+#[derive(Debug, Clone)]
+pub struct SwapTemplate {
+    pub request: SwapRequest,
+    pub quote: JupiterQuote,
+    pub built_at: Instant,
+}
+
+impl SwapTemplate {
+    pub fn new(request: SwapRequest, quote: JupiterQuote) -> Self {
+        Self { request, quote, built_at: Instant::now() }
+    }
+
+    /// Whether the template is fresh enough to swap against
+    pub fn is_fresh(&self, max_age: Duration) -> bool {
+        self.built_at.elapsed() <= max_age
+    }
+
+    /// Key templates are stored under: the input and output mint
+    pub fn key(input_mint: &str, output_mint: &str) -> String {
+        format!("{}:{}", input_mint, output_mint)
+    }
+}
+
+/// Client for sending transactions through the Jito block engine
+pub struct JitoClient {
+    url: String,
+    http_client: Client,
+}
+
+impl JitoClient {
+    pub fn new(url: String, http_client: Client) -> Self {
+        Self { url, http_client }
+    }
+
+    /// Send a signed transaction to the block engine
+    ///
+    /// The block engine forwards it to the Jito leader; the transaction must
+    /// carry its own tip. Landing is confirmed over RPC by the caller.
+    pub async fn send_transaction(&self, transaction: &VersionedTransaction) -> Result<Signature> {
+        let serialized = bincode::serialize(transaction)
+            .context("Failed to serialize transaction")?;
+        let request_body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "sendTransaction",
+            "params": [base64::engine::general_purpose::STANDARD.encode(serialized), { "encoding": "base64" }]
+        });
+
+        let response = self.http_client
+            .post(format!("{}/api/v1/transactions", self.url))
+            .json(&request_body)
+            .send()
+            .await
+            .context("Failed to send transaction to Jito block engine")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            bail!("Jito block engine rejected transaction with status {}: {}", status, error_text);
+        }
+
+        let body: serde_json::Value = response.json().await
+            .context("Failed to parse Jito block engine response")?;
+        if let Some(error) = body.get("error") {
+            bail!("Jito block engine error: {}", error);
+        }
+        let signature = body.get("result").and_then(|result| result.as_str())
+            .context("Jito block engine returned no signature")?;
+
+        debug!(signature, "Transaction sent through Jito block engine");
+        Signature::from_str(signature).context("Invalid signature from Jito block engine")
+    }
+}
+
+/// Low-urgency signals held until their batch window closes
+///
+/// Signals for the same token and side are merged into one swap of their
+/// combined size, so a run of small DCA tranches or rebalances pays one
+/// transaction's fees instead of one each.
+pub struct LowUrgencyBatch {
+    window: Duration,
+    max_size: usize,
+    pending: Vec<Signal>,
+    opened_at: Option<Instant>,
+}
+
+impl LowUrgencyBatch {
+    pub fn new(config: &PathwayConfig) -> Self {
+        Self {
+            window: Duration::from_millis(config.batch_window_ms),
+            max_size: config.max_batch_size,
+            pending: Vec::new(),
+            opened_at: None,
+        }
+    }
+
+    /// Hold a signal for the current batch
+    pub fn push(&mut self, signal: Signal) {
+        self.opened_at.get_or_insert_with(Instant::now);
+        self.pending.push(signal);
+    }
+
+    /// Whether the batch should be executed now
+    pub fn is_due(&self) -> bool {
+        match self.opened_at {
+            Some(opened_at) => self.pending.len() >= self.max_size || opened_at.elapsed() >= self.window,
+            None => false,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Take the held signals, merged per token and side
+    pub fn drain(&mut self) -> Vec<Signal> {
+        self.opened_at = None;
+        let mut merged: Vec<Signal> = Vec::new();
+        let mut index: HashMap<(String, bool), usize> = HashMap::new();
+
+        for signal in self.pending.drain(..) {
+            let key = (signal.token.mint.clone(), matches!(signal.signal_type, SignalType::Buy));
+            match index.get(&key) {
+                Some(&i) => merged[i].amount_sol += signal.amount_sol,
+                None => {
+                    index.insert(key, merged.len());
+                    merged.push(signal);
+                }
+            }
+        }
+        merged
+    }
+}
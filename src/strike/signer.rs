@@ -0,0 +1,276 @@
+use anyhow::{Result, Context, bail};
+use base64::Engine;
+use reqwest::Client;
+use serde::Deserialize;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    signer::Signer as _,
+    transaction::{Transaction, VersionedTransaction},
+};
+use tracing::debug;
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Signs transaction messages for one wallet
+///
+/// Transaction-building code only needs the public key up front and a
+/// signature over the serialized message, so keys can live in-process or in
+/// a remote signing service.
+pub trait Signer: Send + Sync {
+    /// Wallet address the signatures belong to
+    fn pubkey(&self) -> Pubkey;
+
+    /// Signer kind for logging
+    fn name(&self) -> &str;
+
+    /// Signs a serialized transaction message
+    fn sign_message<'a>(
+        &'a self,
+        message: &'a [u8],
+    ) -> Pin<Box<dyn Future<Output = Result<Signature>> + Send + 'a>>;
+}
+
+/// Configuration for a remote signing service
+#[derive(Debug, Clone)]
+pub enum RemoteSignerConfig {
+    /// HashiCorp Vault transit engine with an ed25519 key
+    VaultTransit {
+        /// Vault address, e.g. https://vault.internal:8200
+        url: String,
+        /// Name of the transit key
+        key_name: String,
+        /// Environment variable holding the Vault token
+        token_env: String,
+        /// Wallet address of the transit key
+        pubkey: String,
+    },
+    /// HTTP signing service (custom HSM service or a custody API bridge)
+    ///
+    /// Receives `{"pubkey", "message"}` with a base64 message and returns
+    /// `{"signature"}` in base58.
+    Http {
+        /// Signing endpoint URL
+        url: String,
+        /// Environment variable holding a bearer token, if the service needs one
+        token_env: Option<String>,
+        /// Wallet address the service signs for
+        pubkey: String,
+    },
+}
+
+/// In-process signer backed by a local keypair
+pub struct KeypairSigner {
+    keypair: Keypair,
+}
+
+impl KeypairSigner {
+    pub fn new(keypair: Keypair) -> Self {
+        Self { keypair }
+    }
+}
+
+impl Signer for KeypairSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.keypair.pubkey()
+    }
+
+    fn name(&self) -> &str {
+        "keypair"
+    }
+
+    fn sign_message<'a>(
+        &'a self,
+        message: &'a [u8],
+    ) -> Pin<Box<dyn Future<Output = Result<Signature>> + Send + 'a>> {
+        Box::pin(async move { Ok(self.keypair.sign_message(message)) })
+    }
+}
+
+/// Vault transit sign response
+#[derive(Debug, Deserialize)]
+struct VaultSignResponse {
+    data: VaultSignData,
+}
+
+#[derive(Debug, Deserialize)]
+struct VaultSignData {
+    /// "vault:v<version>:<base64 signature>"
+    signature: String,
+}
+
+/// HTTP signing service response
+#[derive(Debug, Deserialize)]
+struct HttpSignResponse {
+    signature: String,
+}
+
+/// Signer that delegates to a remote signing service; keys never reach this host
+pub struct RemoteSigner {
+    config: RemoteSignerConfig,
+    pubkey: Pubkey,
+    token: Option<String>,
+    client: Client,
+}
+
+impl RemoteSigner {
+    /// Creates a remote signer
+    ///
+    /// # Arguments
+    /// * `config` - Signing service configuration
+    ///
+    /// # Returns
+    /// * `Result<Self>` - Remote signer for the configured wallet
+    pub fn new(config: RemoteSignerConfig) -> Result<Self> {
+        let (pubkey, token_env) = match &config {
+            RemoteSignerConfig::VaultTransit { pubkey, token_env, .. } => (pubkey, Some(token_env)),
+            RemoteSignerConfig::Http { pubkey, token_env, .. } => (pubkey, token_env.as_ref()),
+        };
+        let pubkey = Pubkey::from_str(pubkey).context("Invalid remote signer pubkey")?;
+        let token = match token_env {
+            Some(env_var) => Some(std::env::var(env_var)
+                .with_context(|| format!("Remote signer token variable {} not set", env_var))?),
+            None => None,
+        };
+
+        let client = Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .context("Failed to create HTTP client")?;
+
+        Ok(Self {
+            config,
+            pubkey,
+            token,
+            client,
+        })
+    }
+
+    async fn sign_with_vault(&self, url: &str, key_name: &str, message: &[u8]) -> Result<Signature> {
+        let endpoint = format!("{}/v1/transit/sign/{}", url.trim_end_matches('/'), key_name);
+        let response: VaultSignResponse = self.client
+            .post(&endpoint)
+            .header("X-Vault-Token", self.token.as_deref().unwrap_or_default())
+            .json(&serde_json::json!({
+                "input": base64::engine::general_purpose::STANDARD.encode(message),
+            }))
+            .send()
+            .await
+            .context("Vault transit sign request failed")?
+            .error_for_status()
+            .context("Vault transit sign rejected")?
+            .json()
+            .await
+            .context("Failed to parse Vault sign response")?;
+
+        let encoded = response.data.signature.rsplit(':').next().unwrap_or_default();
+        let bytes = base64::engine::general_purpose::STANDARD.decode(encoded)
+            .context("Invalid Vault signature encoding")?;
+        Signature::try_from(bytes.as_slice()).map_err(|_| anyhow::anyhow!("Vault signature is not 64 bytes"))
+    }
+
+    async fn sign_with_http(&self, url: &str, message: &[u8]) -> Result<Signature> {
+        let mut request = self.client
+            .post(url)
+            .json(&serde_json::json!({
+                "pubkey": self.pubkey.to_string(),
+                "message": base64::engine::general_purpose::STANDARD.encode(message),
+            }));
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+
+        let response: HttpSignResponse = request
+            .send()
+            .await
+            .context("Signing service request failed")?
+            .error_for_status()
+            .context("Signing service rejected request")?
+            .json()
+            .await
+            .context("Failed to parse signing service response")?;
+
+        Signature::from_str(&response.signature).context("Invalid signature from signing service")
+    }
+}
+
+impl Signer for RemoteSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.pubkey
+    }
+
+    fn name(&self) -> &str {
+        match self.config {
+            RemoteSignerConfig::VaultTransit { .. } => "vault-transit",
+            RemoteSignerConfig::Http { .. } => "remote-http",
+        }
+    }
+
+    fn sign_message<'a>(
+        &'a self,
+        message: &'a [u8],
+    ) -> Pin<Box<dyn Future<Output = Result<Signature>> + Send + 'a>> {
+        Box::pin(async move {
+            let signature = match &self.config {
+                RemoteSignerConfig::VaultTransit { url, key_name, .. } => self.sign_with_vault(url, key_name, message).await?,
+                RemoteSignerConfig::Http { url, .. } => self.sign_with_http(url, message).await?,
+            };
+
+            // A misconfigured service could sign with a different key
+            if !signature.verify(self.pubkey.as_ref(), message) {
+                bail!("Remote signature does not verify against {}", self.pubkey);
+            }
+
+            debug!(signer = self.name(), pubkey = %self.pubkey, "Message signed remotely");
+            Ok(signature)
+        })
+    }
+}
+
+/// Adds the signer's signature to a versioned transaction
+///
+/// # Arguments
+/// * `signer` - Signer for one of the transaction's required signers
+/// * `transaction` - Transaction to sign; other signatures are kept
+///
+/// # Returns
+/// * `Result<Signature>` - The added signature
+pub async fn sign_versioned_transaction(signer: &dyn Signer, transaction: &mut VersionedTransaction) -> Result<Signature> {
+    let pubkey = signer.pubkey();
+    let num_signers = transaction.message.header().num_required_signatures as usize;
+    let index = transaction.message.static_account_keys()
+        .iter()
+        .take(num_signers)
+        .position(|key| *key == pubkey)
+        .with_context(|| format!("{} is not a required signer of this transaction", pubkey))?;
+
+    transaction.signatures.resize(num_signers, Signature::default());
+    let signature = signer.sign_message(&transaction.message.serialize()).await?;
+    transaction.signatures[index] = signature;
+    Ok(signature)
+}
+
+/// Adds the signer's signature to a legacy transaction
+///
+/// # Arguments
+/// * `signer` - Signer for one of the transaction's required signers
+/// * `transaction` - Transaction with its recent blockhash already set
+///
+/// # Returns
+/// * `Result<Signature>` - The added signature
+pub async fn sign_legacy_transaction(signer: &dyn Signer, transaction: &mut Transaction) -> Result<Signature> {
+    let pubkey = signer.pubkey();
+    let num_signers = transaction.message.header.num_required_signatures as usize;
+    let index = transaction.message.account_keys
+        .iter()
+        .take(num_signers)
+        .position(|key| *key == pubkey)
+        .with_context(|| format!("{} is not a required signer of this transaction", pubkey))?;
+
+    transaction.signatures.resize(num_signers, Signature::default());
+    let signature = signer.sign_message(&transaction.message_data()).await?;
+    transaction.signatures[index] = signature;
+    Ok(signature)
+}
@@ -0,0 +1,96 @@
+use std::fmt;
+
+use super::dex_client::JupiterQuote;
+
+/// Configuration for the pre-signing price move guard
+#[derive(Debug, Clone)]
+pub struct SlippageGuardConfig {
+    /// Largest adverse move from the signal-time price a swap may be signed at (basis points)
+    pub max_move_bps: u32,
+}
+
+impl Default for SlippageGuardConfig {
+    fn default() -> Self {
+        Self { max_move_bps: 300 }
+    }
+}
+
+impl SlippageGuardConfig {
+    /// Defaults with the guard band overridden by `BADGER_SLIPPAGE_GUARD_BPS`
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(bps) = std::env::var("BADGER_SLIPPAGE_GUARD_BPS").ok().and_then(|v| v.parse().ok()) {
+            config.max_move_bps = bps;
+        }
+        config
+    }
+}
+
+/// A swap aborted because its quote moved too far from the signal-time price
+#[derive(Debug, Clone)]
+pub struct PriceMoved {
+    /// Output per input unit (raw amounts) expected at signal time
+    pub reference_rate: f64,
+    /// Output per input unit (raw amounts) of the quote about to be signed
+    pub quoted_rate: f64,
+    pub move_bps: f64,
+    pub max_move_bps: u32,
+}
+
+impl fmt::Display for PriceMoved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "price moved {:.0} bps against us since the signal (guard {} bps)",
+            self.move_bps, self.max_move_bps
+        )
+    }
+}
+
+impl std::error::Error for PriceMoved {}
+
+/// Checks the quote about to be signed against the price the signal saw
+///
+/// Only adverse moves count: a quote paying out less than expected at signal
+/// time by more than the band aborts the swap, a better one goes through.
+#[derive(Debug, Clone)]
+pub struct SlippageGuard {
+    config: SlippageGuardConfig,
+}
+
+impl SlippageGuard {
+    pub fn new(config: SlippageGuardConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn config(&self) -> &SlippageGuardConfig {
+        &self.config
+    }
+
+    /// Output per input unit of a quote, in raw amounts
+    pub fn quoted_rate(quote: &JupiterQuote) -> Option<f64> {
+        let in_amount = quote.in_amount.parse::<f64>().ok()?;
+        let out_amount = quote.out_amount.parse::<f64>().ok()?;
+        (in_amount > 0.0).then(|| out_amount / in_amount)
+    }
+
+    /// Pass a quote whose rate is within the band of the reference rate
+    pub fn check(&self, reference_rate: f64, quote: &JupiterQuote) -> Result<(), PriceMoved> {
+        let Some(quoted_rate) = Self::quoted_rate(quote) else {
+            return Ok(());
+        };
+        if reference_rate.is_nan() || reference_rate <= 0.0 {
+            return Ok(());
+        }
+        let move_bps = (reference_rate - quoted_rate) / reference_rate * 10_000.0;
+        if move_bps > self.config.max_move_bps as f64 {
+            return Err(PriceMoved {
+                reference_rate,
+                quoted_rate,
+                move_bps,
+                max_move_bps: self.config.max_move_bps,
+            });
+        }
+        Ok(())
+    }
+}
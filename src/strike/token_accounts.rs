@@ -0,0 +1,104 @@
+use anyhow::{Result, Context};
+use dashmap::DashMap;
+use dashmap::mapref::entry::Entry;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+};
+use spl_associated_token_account::{
+    get_associated_token_address,
+    instruction::create_associated_token_account_idempotent,
+};
+use tracing::debug;
+
+/// What we know about an associated token account this session
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AtaState {
+    /// Seen on chain or created by one of our landed transactions
+    Exists,
+    /// A create instruction is in a transaction that has not landed yet
+    Creating,
+}
+
+/// Associated token accounts verified or created this session, by owner and mint
+///
+/// Builders ask the cache instead of fetching the account before every
+/// transfer or swap. Only positive answers are kept: a missing account is
+/// looked up again next time, since someone else may create it. The first
+/// builder to find an account missing reserves its creation, so concurrent
+/// builders for the same owner and mint do not each add a create
+/// instruction. Creates are idempotent, which keeps a retried transaction
+/// safe when the account appeared in the meantime.
+#[derive(Debug, Default)]
+pub struct AtaCache {
+    accounts: DashMap<(Pubkey, Pubkey), AtaState>,
+}
+
+impl AtaCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the account is known to exist, without touching the RPC
+    pub fn is_known(&self, owner: &Pubkey, mint: &Pubkey) -> bool {
+        self.accounts.get(&(*owner, *mint)).is_some_and(|state| *state == AtaState::Exists)
+    }
+
+    /// Create instruction for the owner's account of a mint, if one is needed
+    ///
+    /// # Arguments
+    /// * `rpc_client` - Used only when the account is not cached yet
+    /// * `payer` - Fee payer of the create
+    /// * `owner` - Wallet owning the token account
+    /// * `mint` - Token mint
+    ///
+    /// # Returns
+    /// * `Result<Option<Instruction>>` - None if the account exists or another builder is creating it
+    pub fn create_instruction(
+        &self,
+        rpc_client: &RpcClient,
+        payer: &Pubkey,
+        owner: &Pubkey,
+        mint: &Pubkey,
+    ) -> Result<Option<Instruction>> {
+        if self.accounts.contains_key(&(*owner, *mint)) {
+            return Ok(None);
+        }
+
+        let address = get_associated_token_address(owner, mint);
+        let exists = rpc_client.get_account_with_commitment(&address, rpc_client.commitment())
+            .context("Failed to fetch associated token account")?
+            .value
+            .is_some();
+
+        match self.accounts.entry((*owner, *mint)) {
+            // Another builder got there while we were fetching
+            Entry::Occupied(_) => Ok(None),
+            Entry::Vacant(entry) if exists => {
+                entry.insert(AtaState::Exists);
+                Ok(None)
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(AtaState::Creating);
+                debug!(owner = %owner, mint = %mint, ata = %address, "Adding create for associated token account");
+                Ok(Some(create_associated_token_account_idempotent(payer, owner, mint, &spl_token::id())))
+            }
+        }
+    }
+
+    /// Records an account as existing, e.g. once a transaction creating it landed
+    pub fn mark_exists(&self, owner: &Pubkey, mint: &Pubkey) {
+        self.accounts.insert((*owner, *mint), AtaState::Exists);
+    }
+
+    /// Gives up a reservation whose transaction failed, so the next builder adds the create again
+    pub fn release(&self, owner: &Pubkey, mint: &Pubkey) {
+        self.accounts.remove_if(&(*owner, *mint), |_, state| *state == AtaState::Creating);
+    }
+
+    /// Forgets an account, e.g. after we closed it
+    pub fn invalidate(&self, owner: &Pubkey, mint: &Pubkey) {
+        self.accounts.remove(&(*owner, *mint));
+    }
+}
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use crate::core::types::{Signal, SignalType, Token};
 use crate::transport::signal_bus::SignalBus;
+use crate::transport::SignalUrgency;
 
 pub struct TriggerEngine {
     signal_bus: SignalBus,
@@ -59,26 +60,33 @@ impl TriggerEngine {
         Ok(false)
     }
 
-    pub async fn generate_buy_signal(&self, token: Token, amount_sol: f64) -> Result<()> {
+    pub async fn generate_buy_signal(&self, token: Token, amount_sol: f64, price_sol: Option<f64>) -> Result<()> {
         let signal = Signal {
             signal_type: SignalType::Buy,
             token,
             wallet: None,
             amount_sol,
             timestamp: self.current_timestamp(),
+            urgency: SignalUrgency::High,
+            reference_price: price_sol,
+            strategy: None,
         };
         
         self.signal_bus.publish(signal)?;
         Ok(())
     }
 
-    pub async fn generate_sell_signal(&self, token: Token, amount_sol: f64) -> Result<()> {
+    pub async fn generate_sell_signal(&self, token: Token, amount_sol: f64, price_sol: Option<f64>) -> Result<()> {
         let signal = Signal {
             signal_type: SignalType::Sell,
             token,
             wallet: None,
             amount_sol,
             timestamp: self.current_timestamp(),
+            // Exit conditions (profit target, loss threshold, max hold) have already been hit
+            urgency: SignalUrgency::Critical,
+            reference_price: price_sol,
+            strategy: None,
         };
         
         self.signal_bus.publish(signal)?;
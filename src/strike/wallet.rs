@@ -2,19 +2,24 @@ use anyhow::{Result, Context, bail};
 use serde::{Deserialize, Serialize};
 use solana_sdk::{
     signature::{Keypair, Signature, Signer},
+    signer::keypair::keypair_from_seed,
     pubkey::Pubkey,
-    transaction::Transaction,
+    transaction::VersionedTransaction,
 };
 use tracing::{info, debug, warn, error, instrument};
-use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use super::signer::{Signer as WalletSigner, KeypairSigner, RemoteSigner, RemoteSignerConfig, sign_versioned_transaction};
 
 /// Wallet configuration for secure key management
 #[derive(Debug, Clone)]
 pub struct WalletConfig {
-    /// Path to wallet keypair file (JSON format)
+    /// Path to wallet keypair file (any supported format)
     pub keypair_path: Option<String>,
+    /// Folder of keypair files; the first valid file (by name) becomes the primary wallet
+    pub wallet_folder: Option<String>,
     /// Environment variable name containing private key
     pub private_key_env: Option<String>,
     /// Maximum transaction value in lamports (safety limit)
@@ -23,16 +28,20 @@ pub struct WalletConfig {
     pub require_approval_for_large_transactions: bool,
     /// Approval threshold in lamports
     pub approval_threshold_lamports: u64,
+    /// Remote signing service; when set, no local keypair is loaded
+    pub remote_signer: Option<RemoteSignerConfig>,
 }
 
 impl Default for WalletConfig {
     fn default() -> Self {
         Self {
             keypair_path: None,
+            wallet_folder: None,
             private_key_env: Some("SOLANA_PRIVATE_KEY".to_string()),
             max_transaction_value_lamports: 1_000_000_000, // 1 SOL
             require_approval_for_large_transactions: true,
             approval_threshold_lamports: 100_000_000, // 0.1 SOL
+            remote_signer: None,
         }
     }
 }
@@ -41,7 +50,7 @@ impl Default for WalletConfig {
 #[derive(Debug, Clone)]
 pub struct SigningRequest {
     /// Transaction to sign
-    pub transaction: Transaction,
+    pub transaction: VersionedTransaction,
     /// Estimated value being transferred (in lamports)
     pub estimated_value_lamports: u64,
     /// Description of the transaction for approval
@@ -54,7 +63,7 @@ pub struct SigningRequest {
 #[derive(Debug, Clone)]
 pub struct SigningResult {
     /// Signed transaction
-    pub signed_transaction: Transaction,
+    pub signed_transaction: VersionedTransaction,
     /// Transaction signature
     pub signature: Signature,
     /// Wallet public key used for signing
@@ -63,16 +72,188 @@ pub struct SigningResult {
     pub approval_granted: bool,
 }
 
+/// Keypair encodings accepted when loading wallets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeypairFormat {
+    /// Solana CLI `id.json`: JSON array of 64 bytes
+    SolanaCliJson,
+    /// Base58-encoded 64-byte secret key (Phantom "Export Private Key")
+    Base58,
+    /// Base58-encoded 32-byte seed
+    Base58Seed,
+    /// JSON object export with a `privateKey`/`secretKey` field (Phantom/Backpack)
+    WalletExportJson,
+    /// Raw 64 bytes
+    Raw,
+}
+
+impl fmt::Display for KeypairFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            KeypairFormat::SolanaCliJson => "Solana CLI JSON",
+            KeypairFormat::Base58 => "base58 secret key",
+            KeypairFormat::Base58Seed => "base58 seed",
+            KeypairFormat::WalletExportJson => "wallet export JSON",
+            KeypairFormat::Raw => "raw bytes",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Keypair loaded from a wallet folder
+#[derive(Debug)]
+pub struct LoadedKeypair {
+    /// File the keypair was read from
+    pub path: PathBuf,
+    /// Detected encoding
+    pub format: KeypairFormat,
+    /// Decoded keypair
+    pub keypair: Keypair,
+}
+
+/// Detects the keypair encoding and decodes it
+///
+/// # Arguments
+/// * `contents` - File or environment variable contents
+///
+/// # Returns
+/// * `Result<(Keypair, KeypairFormat)>` - Decoded keypair and detected format
+pub fn parse_keypair(contents: &[u8]) -> Result<(Keypair, KeypairFormat)> {
+    let text = std::str::from_utf8(contents).map(str::trim).unwrap_or("");
+
+    if text.starts_with('[') {
+        let bytes: Vec<u8> = serde_json::from_str(text)
+            .context("Looks like a Solana CLI JSON array but could not be parsed as a list of bytes")?;
+        return Ok((keypair_from_secret_bytes(&bytes)?, KeypairFormat::SolanaCliJson));
+    }
+
+    if text.starts_with('{') {
+        let export: serde_json::Value = serde_json::from_str(text)
+            .context("Looks like a wallet export JSON object but is not valid JSON")?;
+        let secret = ["privateKey", "secretKey", "private_key", "secret_key"]
+            .iter()
+            .find_map(|key| export.get(*key))
+            .context("Wallet export JSON has no privateKey/secretKey field")?;
+
+        let keypair = match secret {
+            serde_json::Value::String(encoded) => keypair_from_secret_bytes(&decode_base58(encoded)?)?,
+            serde_json::Value::Array(_) => {
+                let bytes: Vec<u8> = serde_json::from_value(secret.clone())
+                    .context("Secret key array in wallet export must contain bytes (0-255)")?;
+                keypair_from_secret_bytes(&bytes)?
+            }
+            _ => bail!("Secret key in wallet export must be a base58 string or byte array"),
+        };
+        return Ok((keypair, KeypairFormat::WalletExportJson));
+    }
+
+    if !text.is_empty() && !text.contains(char::is_whitespace) {
+        let decoded = decode_base58(text).and_then(|bytes| {
+            let format = if bytes.len() == 32 { KeypairFormat::Base58Seed } else { KeypairFormat::Base58 };
+            Ok((keypair_from_secret_bytes(&bytes)?, format))
+        });
+        match decoded {
+            Ok(decoded) => return Ok(decoded),
+            // Raw key bytes can happen to be valid UTF-8 without whitespace
+            Err(_) if contents.len() == 64 => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    if contents.len() == 64 {
+        return Ok((keypair_from_secret_bytes(contents)?, KeypairFormat::Raw));
+    }
+
+    bail!(
+        "Unrecognized keypair format ({} bytes). Expected a Solana CLI JSON array, \
+         base58 secret key, Phantom/Backpack export JSON, or 64 raw bytes",
+        contents.len()
+    )
+}
+
+/// Decodes a base58 secret, reporting the offending character on failure
+fn decode_base58(encoded: &str) -> Result<Vec<u8>> {
+    bs58::decode(encoded.trim())
+        .into_vec()
+        .map_err(|e| anyhow::anyhow!("Invalid base58 secret key: {}", e))
+}
+
+/// Builds a keypair from a 64-byte secret key or 32-byte seed
+///
+/// 64-byte keys are checked so that the embedded public key matches the secret.
+fn keypair_from_secret_bytes(bytes: &[u8]) -> Result<Keypair> {
+    match bytes.len() {
+        32 => keypair_from_seed(bytes)
+            .map_err(|e| anyhow::anyhow!("Invalid 32-byte seed: {}", e)),
+        64 => {
+            let keypair = Keypair::from_bytes(bytes)
+                .map_err(|e| anyhow::anyhow!("Invalid 64-byte secret key: {}", e))?;
+            let derived = keypair_from_seed(&bytes[..32])
+                .map_err(|e| anyhow::anyhow!("Invalid secret key seed: {}", e))?;
+            if derived.pubkey() != keypair.pubkey() {
+                bail!("Public key half of the secret does not match the private key (corrupted or truncated export?)");
+            }
+            Ok(keypair)
+        }
+        len => bail!("Secret key has {} bytes; expected 64 (secret key) or 32 (seed)", len),
+    }
+}
+
+/// Loads every keypair file in a wallet folder
+///
+/// Files that fail to parse are reported individually and skipped.
+///
+/// # Arguments
+/// * `folder` - Directory containing keypair files
+///
+/// # Returns
+/// * `Result<Vec<LoadedKeypair>>` - Valid keypairs sorted by file name
+pub fn load_wallet_folder(folder: &Path) -> Result<Vec<LoadedKeypair>> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(folder)
+        .with_context(|| format!("Failed to read wallet folder: {}", folder.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file())
+        .filter(|path| !path.file_name().and_then(|n| n.to_str()).is_some_and(|n| n.starts_with('.')))
+        .collect();
+    paths.sort();
+
+    let mut loaded = Vec::new();
+    for path in paths {
+        let contents = match fs::read(&path) {
+            Ok(contents) => contents,
+            Err(e) => {
+                error!(path = %path.display(), "Failed to read keypair file: {}", e);
+                continue;
+            }
+        };
+
+        match parse_keypair(&contents) {
+            Ok((keypair, format)) => {
+                info!(path = %path.display(), pubkey = %keypair.pubkey(), %format, "Loaded keypair");
+                loaded.push(LoadedKeypair { path, format, keypair });
+            }
+            Err(e) => {
+                error!(path = %path.display(), "Skipping keypair file: {:#}", e);
+            }
+        }
+    }
+
+    Ok(loaded)
+}
+
+/// Decides whether a high-value signing request may go ahead
+type ApprovalCallback = Box<dyn Fn(&SigningRequest) -> bool + Send + Sync>;
+
 /// Secure wallet manager with safety controls
 pub struct WalletManager {
-    /// Primary wallet keypair
-    keypair: Keypair,
+    /// Primary wallet signer (local keypair or remote signing service)
+    signer: Arc<dyn WalletSigner>,
     /// Wallet configuration
     config: WalletConfig,
     /// Transaction history for audit
     transaction_history: Vec<TransactionRecord>,
     /// Approval callback for high-value transactions
-    approval_callback: Option<Box<dyn Fn(&SigningRequest) -> bool + Send + Sync>>,
+    approval_callback: Option<ApprovalCallback>,
 }
 
 /// Transaction record for audit trail
@@ -104,18 +285,23 @@ impl WalletManager {
     pub fn new(config: WalletConfig) -> Result<Self> {
         info!("Initializing secure wallet manager");
         
-        // Load keypair from configuration
-        let keypair = Self::load_keypair(&config)?;
+        // Keys stay off this host when a remote signer is configured
+        let signer: Arc<dyn WalletSigner> = match &config.remote_signer {
+            Some(remote) => Arc::new(RemoteSigner::new(remote.clone())
+                .context("Failed to initialize remote signer")?),
+            None => Arc::new(KeypairSigner::new(Self::load_keypair(&config)?)),
+        };
         
         info!(
-            pubkey = %keypair.pubkey(),
+            pubkey = %signer.pubkey(),
+            signer = signer.name(),
             max_transaction_value = config.max_transaction_value_lamports,
             approval_threshold = config.approval_threshold_lamports,
             "Wallet manager initialized successfully"
         );
         
         Ok(Self {
-            keypair,
+            signer,
             config,
             transaction_history: Vec::new(),
             approval_callback: None,
@@ -139,30 +325,33 @@ impl WalletManager {
                 let keypair_bytes = fs::read(keypair_path)
                     .with_context(|| format!("Failed to read keypair file: {}", keypair_path))?;
                 
-                // Try parsing as JSON array format
-                if let Ok(json_bytes) = serde_json::from_slice::<Vec<u8>>(&keypair_bytes) {
-                    if json_bytes.len() == 64 {
-                        let keypair = Keypair::from_bytes(&json_bytes)
-                            .context("Failed to create keypair from JSON bytes")?;
-                        
-                        info!("Keypair loaded successfully from file");
-                        return Ok(keypair);
-                    }
-                }
+                let (keypair, format) = parse_keypair(&keypair_bytes)
+                    .with_context(|| format!("Invalid keypair file: {}", keypair_path))?;
                 
-                // Try parsing as raw bytes
-                if keypair_bytes.len() == 64 {
-                    let keypair = Keypair::from_bytes(&keypair_bytes)
-                        .context("Failed to create keypair from raw bytes")?;
-                    
-                    info!("Keypair loaded successfully from file (raw format)");
-                    return Ok(keypair);
-                }
-                
-                bail!("Invalid keypair file format. Expected 64 bytes or JSON array format.");
+                info!(%format, "Keypair loaded successfully from file");
+                return Ok(keypair);
             } else {
-                warn!(path = %keypair_path, "Keypair file not found, trying environment variable");
+                warn!(path = %keypair_path, "Keypair file not found, trying wallet folder");
+            }
+        }
+        
+        // Try loading from wallet folder
+        if let Some(wallet_folder) = &config.wallet_folder {
+            debug!(folder = %wallet_folder, "Loading keypairs from wallet folder");
+            
+            let mut loaded = load_wallet_folder(Path::new(wallet_folder))?;
+            if !loaded.is_empty() {
+                let primary = loaded.remove(0);
+                info!(
+                    path = %primary.path.display(),
+                    format = %primary.format,
+                    additional_keypairs = loaded.len(),
+                    "Primary keypair loaded from wallet folder"
+                );
+                return Ok(primary.keypair);
             }
+            
+            warn!(folder = %wallet_folder, "No valid keypairs in wallet folder, trying environment variable");
         }
         
         // Try loading from environment variable
@@ -170,29 +359,11 @@ impl WalletManager {
             debug!(env_var = %env_var, "Loading keypair from environment variable");
             
             if let Ok(private_key_str) = std::env::var(env_var) {
-                // Try parsing as base58 (Solana CLI format)
-                if let Ok(bytes) = bs58::decode(&private_key_str).into_vec() {
-                    if bytes.len() == 64 {
-                        let keypair = Keypair::from_bytes(&bytes)
-                            .context("Failed to create keypair from base58 string")?;
-                        
-                        info!("Keypair loaded successfully from environment variable");
-                        return Ok(keypair);
-                    }
-                }
-                
-                // Try parsing as JSON array
-                if let Ok(json_bytes) = serde_json::from_str::<Vec<u8>>(&private_key_str) {
-                    if json_bytes.len() == 64 {
-                        let keypair = Keypair::from_bytes(&json_bytes)
-                            .context("Failed to create keypair from JSON string")?;
-                        
-                        info!("Keypair loaded successfully from environment variable (JSON format)");
-                        return Ok(keypair);
-                    }
-                }
+                let (keypair, format) = parse_keypair(private_key_str.as_bytes())
+                    .with_context(|| format!("Invalid private key in environment variable {}", env_var))?;
                 
-                bail!("Invalid private key format in environment variable. Expected base58 or JSON array.");
+                info!(%format, "Keypair loaded successfully from environment variable");
+                return Ok(keypair);
             } else {
                 warn!(env_var = %env_var, "Environment variable not found");
             }
@@ -247,10 +418,7 @@ impl WalletManager {
         
         // Sign the transaction
         let mut transaction = signing_request.transaction;
-        let signature = transaction.signatures[0]; // Will be updated after signing
-        
-        transaction.partial_sign(&[&self.keypair], transaction.message.recent_blockhash);
-        let actual_signature = transaction.signatures[0];
+        let actual_signature = sign_versioned_transaction(self.signer.as_ref(), &mut transaction).await?;
         
         // Record transaction for audit
         let transaction_record = TransactionRecord {
@@ -258,7 +426,7 @@ impl WalletManager {
             timestamp: chrono::Utc::now().timestamp(),
             value_lamports: signing_request.estimated_value_lamports,
             description: signing_request.description.clone(),
-            signer_pubkey: self.keypair.pubkey().to_string(),
+            signer_pubkey: self.signer.pubkey().to_string(),
             required_approval: requires_approval,
         };
         
@@ -272,7 +440,7 @@ impl WalletManager {
         let result = SigningResult {
             signed_transaction: transaction,
             signature: actual_signature,
-            signer_pubkey: self.keypair.pubkey(),
+            signer_pubkey: self.signer.pubkey(),
             approval_granted,
         };
         
@@ -304,18 +472,19 @@ impl WalletManager {
         }
         
         // Validate transaction structure
-        if signing_request.transaction.message.instructions.is_empty() {
+        if signing_request.transaction.message.instructions().is_empty() {
             bail!("Transaction has no instructions");
         }
         
         // Check that our wallet is a signer
-        let our_pubkey = self.keypair.pubkey();
-        let is_signer = signing_request.transaction.message.account_keys
+        let our_pubkey = self.signer.pubkey();
+        let message = &signing_request.transaction.message;
+        let is_signer = message.static_account_keys()
             .iter()
             .enumerate()
             .any(|(i, pubkey)| {
                 *pubkey == our_pubkey && 
-                signing_request.transaction.message.header.num_required_signatures as usize > i
+                message.header().num_required_signatures as usize > i
             });
         
         if !is_signer {
@@ -384,7 +553,15 @@ impl WalletManager {
     /// # Returns
     /// * `Pubkey` - Wallet public key
     pub fn pubkey(&self) -> Pubkey {
-        self.keypair.pubkey()
+        self.signer.pubkey()
+    }
+    
+    /// Gets the wallet signer for transaction-building paths
+    /// 
+    /// # Returns
+    /// * `Arc<dyn Signer>` - Signer for the primary wallet
+    pub fn signer(&self) -> Arc<dyn WalletSigner> {
+        self.signer.clone()
     }
     
     /// Gets transaction history for audit
@@ -417,7 +594,7 @@ impl WalletManager {
             .count();
         
         WalletStats {
-            wallet_pubkey: self.keypair.pubkey(),
+            wallet_pubkey: self.signer.pubkey(),
             total_transactions,
             total_value_lamports: total_value,
             high_value_transactions,
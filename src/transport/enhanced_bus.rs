@@ -1,9 +1,11 @@
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use tokio::sync::broadcast;
 use tracing::{debug, warn, error, instrument};
 use std::sync::Arc;
 
 use crate::core::{MarketEvent, TradingSignal};
+use super::events::CoordinatedActivityType;
 
 /// Multi-channel event bus for different event types in the Badger trading system
 /// 
@@ -12,12 +14,16 @@ use crate::core::{MarketEvent, TradingSignal};
 /// - TradingSignal: Buy/sell signals generated from market analysis
 /// - WalletEvent: Insider wallet activity and tracking
 /// - SystemAlert: System status, errors, and performance alerts
+/// - PositionEvent: Lifecycle transitions of our own positions
+/// - CandleEvent: Closed price candles per token and timeframe
 #[derive(Debug, Clone)]
 pub struct EnhancedTransportBus {
     market_events: broadcast::Sender<MarketEvent>,
     trading_signals: broadcast::Sender<TradingSignal>,
     wallet_events: broadcast::Sender<WalletEvent>,
     system_alerts: broadcast::Sender<SystemAlert>,
+    position_events: broadcast::Sender<PositionEvent>,
+    candle_events: broadcast::Sender<CandleEvent>,
     stats: Arc<tokio::sync::RwLock<BusStatistics>>,
 }
 
@@ -28,10 +34,14 @@ pub struct BusStatistics {
     pub trading_signals_sent: u64,
     pub wallet_events_sent: u64,
     pub system_alerts_sent: u64,
+    pub position_events_sent: u64,
+    pub candle_events_sent: u64,
     pub market_subscribers: usize,
     pub signal_subscribers: usize,
     pub wallet_subscribers: usize,
     pub alert_subscribers: usize,
+    pub position_subscribers: usize,
+    pub candle_subscribers: usize,
 }
 
 impl Default for BusStatistics {
@@ -41,10 +51,14 @@ impl Default for BusStatistics {
             trading_signals_sent: 0,
             wallet_events_sent: 0,
             system_alerts_sent: 0,
+            position_events_sent: 0,
+            candle_events_sent: 0,
             market_subscribers: 0,
             signal_subscribers: 0,
             wallet_subscribers: 0,
             alert_subscribers: 0,
+            position_subscribers: 0,
+            candle_subscribers: 0,
         }
     }
 }
@@ -139,6 +153,148 @@ pub enum SystemAlert {
         reason: String,
         uptime_seconds: u64,
     },
+    CoordinatedActivity {
+        activity_type: CoordinatedActivityType,
+        wallets: Vec<String>,
+        total_value_sol: f64,
+        evidence: Vec<String>, // Transaction signatures
+    },
+}
+
+/// Lifecycle transitions of our own positions, for accounting and notification tools
+///
+/// Serialized with an `event` tag, e.g. `{"event": "PositionClosed", "position_id": 7, ...}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event")]
+pub enum PositionEvent {
+    PositionOpened {
+        position_id: i64,
+        token_mint: String,
+        entry_price: f64,
+        quantity: f64,
+        /// SOL committed to the entry
+        cost_sol: f64,
+        fees: f64,
+        signal_id: Option<String>,
+        insider_wallet: Option<String>,
+        timestamp: i64,
+    },
+    /// Another entry into a token that already had an open position
+    PositionIncreased {
+        position_id: i64,
+        token_mint: String,
+        added_quantity: f64,
+        /// Open quantity across all positions in the token after the entry
+        total_quantity: f64,
+        entry_price: f64,
+        timestamp: i64,
+    },
+    /// An exit was taken because price fell through the stop
+    StopTriggered {
+        position_id: i64,
+        token_mint: String,
+        stop_price: f64,
+        trigger_price: f64,
+        timestamp: i64,
+    },
+    /// Part of a position was sold; the rest stays open
+    PositionReduced {
+        position_id: i64,
+        token_mint: String,
+        closed_quantity: f64,
+        remaining_quantity: f64,
+        exit_price: f64,
+        /// Net of the closed share of entry fees and the exit fees
+        realized_pnl: f64,
+        fees: f64,
+        timestamp: i64,
+    },
+    PositionClosed {
+        position_id: i64,
+        token_mint: String,
+        entry_price: f64,
+        exit_price: f64,
+        quantity: f64,
+        /// Net of entry and exit fees
+        realized_pnl: f64,
+        fees: f64,
+        hold_secs: i64,
+        timestamp: i64,
+    },
+}
+
+impl PositionEvent {
+    pub fn position_id(&self) -> i64 {
+        match self {
+            PositionEvent::PositionOpened { position_id, .. }
+            | PositionEvent::PositionIncreased { position_id, .. }
+            | PositionEvent::StopTriggered { position_id, .. }
+            | PositionEvent::PositionReduced { position_id, .. }
+            | PositionEvent::PositionClosed { position_id, .. } => *position_id,
+        }
+    }
+
+    pub fn token_mint(&self) -> &str {
+        match self {
+            PositionEvent::PositionOpened { token_mint, .. }
+            | PositionEvent::PositionIncreased { token_mint, .. }
+            | PositionEvent::StopTriggered { token_mint, .. }
+            | PositionEvent::PositionReduced { token_mint, .. }
+            | PositionEvent::PositionClosed { token_mint, .. } => token_mint,
+        }
+    }
+
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            PositionEvent::PositionOpened { .. } => "PositionOpened",
+            PositionEvent::PositionIncreased { .. } => "PositionIncreased",
+            PositionEvent::StopTriggered { .. } => "StopTriggered",
+            PositionEvent::PositionReduced { .. } => "PositionReduced",
+            PositionEvent::PositionClosed { .. } => "PositionClosed",
+        }
+    }
+}
+
+/// Price candle events, so consumers react on bar close instead of keeping their own windows
+///
+/// Token candles are in SOL per token; the SOL candle (under the SOL mint)
+/// is in USD per SOL.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event")]
+pub enum CandleEvent {
+    /// A candle's window ended; its values are final
+    CandleClosed {
+        token_mint: String,
+        /// Width of the candle (seconds)
+        timeframe_secs: i64,
+        /// Unix time the candle opened at
+        bucket_start: i64,
+        open: f64,
+        high: f64,
+        low: f64,
+        close: f64,
+        samples: i64,
+    },
+}
+
+impl CandleEvent {
+    pub fn token_mint(&self) -> &str {
+        match self {
+            CandleEvent::CandleClosed { token_mint, .. } => token_mint,
+        }
+    }
+
+    pub fn timeframe_secs(&self) -> i64 {
+        match self {
+            CandleEvent::CandleClosed { timeframe_secs, .. } => *timeframe_secs,
+        }
+    }
+
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            CandleEvent::CandleClosed { .. } => "CandleClosed",
+        }
+    }
 }
 
 impl EnhancedTransportBus {
@@ -149,6 +305,8 @@ impl EnhancedTransportBus {
     /// - TradingSignal: 10,000 capacity (medium frequency signals)  
     /// - WalletEvent: 5,000 capacity (lower frequency insider events)
     /// - SystemAlert: 1,000 capacity (low frequency system events)
+    /// - PositionEvent: 1,000 capacity (low frequency position transitions)
+    /// - CandleEvent: 5,000 capacity (one per held token and timeframe per bar)
     #[instrument]
     pub fn new() -> Self {
         debug!("Initializing EnhancedTransportBus with production channel sizes");
@@ -157,12 +315,16 @@ impl EnhancedTransportBus {
         let (signal_tx, _) = broadcast::channel(10_000);
         let (wallet_tx, _) = broadcast::channel(5_000);
         let (alert_tx, _) = broadcast::channel(1_000);
+        let (position_tx, _) = broadcast::channel(1_000);
+        let (candle_tx, _) = broadcast::channel(5_000);
         
         let bus = Self {
             market_events: market_tx,
             trading_signals: signal_tx,
             wallet_events: wallet_tx,
             system_alerts: alert_tx,
+            position_events: position_tx,
+            candle_events: candle_tx,
             stats: Arc::new(tokio::sync::RwLock::new(BusStatistics::default())),
         };
         
@@ -190,12 +352,16 @@ impl EnhancedTransportBus {
         let (signal_tx, _) = broadcast::channel(signal_capacity);
         let (wallet_tx, _) = broadcast::channel(wallet_capacity);
         let (alert_tx, _) = broadcast::channel(alert_capacity);
+        let (position_tx, _) = broadcast::channel(alert_capacity);
+        let (candle_tx, _) = broadcast::channel(wallet_capacity);
         
         Self {
             market_events: market_tx,
             trading_signals: signal_tx,
             wallet_events: wallet_tx,
             system_alerts: alert_tx,
+            position_events: position_tx,
+            candle_events: candle_tx,
             stats: Arc::new(tokio::sync::RwLock::new(BusStatistics::default())),
         }
     }
@@ -286,6 +452,48 @@ impl EnhancedTransportBus {
         }
     }
     
+    /// Publish a position lifecycle event (opened, increased, stop triggered, closed)
+    #[instrument(skip(self, event), fields(event_type = event.event_type()))]
+    pub async fn publish_position_event(&self, event: PositionEvent) -> Result<usize> {
+        match self.position_events.send(event) {
+            Ok(subscriber_count) => {
+                let mut stats = self.stats.write().await;
+                stats.position_events_sent += 1;
+                debug!(
+                    subscriber_count = subscriber_count,
+                    total_sent = stats.position_events_sent,
+                    "Published position event"
+                );
+                Ok(subscriber_count)
+            }
+            Err(e) => {
+                debug!(error = %e, "Position event not published - no subscribers");
+                Err(anyhow::anyhow!("No position event subscribers: {}", e))
+            }
+        }
+    }
+    
+    /// Publish a candle event (bar close per token and timeframe)
+    #[instrument(skip(self, event), fields(event_type = event.event_type()))]
+    pub async fn publish_candle_event(&self, event: CandleEvent) -> Result<usize> {
+        match self.candle_events.send(event) {
+            Ok(subscriber_count) => {
+                let mut stats = self.stats.write().await;
+                stats.candle_events_sent += 1;
+                debug!(
+                    subscriber_count = subscriber_count,
+                    total_sent = stats.candle_events_sent,
+                    "Published candle event"
+                );
+                Ok(subscriber_count)
+            }
+            Err(e) => {
+                debug!(error = %e, "Candle event not published - no subscribers");
+                Err(anyhow::anyhow!("No candle event subscribers: {}", e))
+            }
+        }
+    }
+    
     // Event Subscribers
     
     /// Subscribe to market events (pools, tokens, swaps, transfers)
@@ -340,6 +548,32 @@ impl EnhancedTransportBus {
         receiver
     }
     
+    /// Subscribe to position lifecycle events
+    #[instrument(skip(self))]
+    pub async fn subscribe_position_events(&self) -> broadcast::Receiver<PositionEvent> {
+        let receiver = self.position_events.subscribe();
+        let mut stats = self.stats.write().await;
+        stats.position_subscribers = self.position_events.receiver_count();
+        debug!(
+            total_subscribers = stats.position_subscribers,
+            "New position event subscriber added"
+        );
+        receiver
+    }
+    
+    /// Subscribe to candle close events
+    #[instrument(skip(self))]
+    pub async fn subscribe_candle_events(&self) -> broadcast::Receiver<CandleEvent> {
+        let receiver = self.candle_events.subscribe();
+        let mut stats = self.stats.write().await;
+        stats.candle_subscribers = self.candle_events.receiver_count();
+        debug!(
+            total_subscribers = stats.candle_subscribers,
+            "New candle event subscriber added"
+        );
+        receiver
+    }
+    
     // Statistics and Monitoring
     
     /// Get current bus statistics for monitoring
@@ -351,6 +585,8 @@ impl EnhancedTransportBus {
         stats.signal_subscribers = self.trading_signals.receiver_count();
         stats.wallet_subscribers = self.wallet_events.receiver_count();
         stats.alert_subscribers = self.system_alerts.receiver_count();
+        stats.position_subscribers = self.position_events.receiver_count();
+        stats.candle_subscribers = self.candle_events.receiver_count();
         
         stats.clone()
     }
@@ -364,7 +600,7 @@ impl EnhancedTransportBus {
             trading_signals_healthy: stats.signal_subscribers > 0,
             wallet_events_healthy: stats.wallet_subscribers > 0,
             system_alerts_healthy: stats.alert_subscribers > 0,
-            total_events_processed: stats.market_events_sent + stats.trading_signals_sent + stats.wallet_events_sent + stats.system_alerts_sent,
+            total_events_processed: stats.market_events_sent + stats.trading_signals_sent + stats.wallet_events_sent + stats.system_alerts_sent + stats.position_events_sent + stats.candle_events_sent,
         }
     }
 }
@@ -296,6 +296,8 @@ pub enum CoordinatedActivityType {
     WashTrading,
     Sniping,
     LiquidityMigration,
+    /// A tracked insider moved SOL to another tracked insider
+    InsiderFunding,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
@@ -0,0 +1,184 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn, error};
+
+use crate::core::MarketEvent;
+
+/// Default socket used between `--role ingest` and `--role analyzer` processes
+pub const DEFAULT_IPC_SOCKET: &str = "/tmp/badger-ingest.sock";
+
+/// Upper bound on a single encoded event; larger frames indicate a corrupt stream
+const MAX_FRAME_BYTES: usize = 1024 * 1024;
+
+/// Encode a market event as a length-prefixed bincode frame
+///
+/// Frame layout: `u32` little-endian payload length followed by the payload.
+pub fn encode_event(event: &MarketEvent) -> Result<Vec<u8>> {
+    let payload = bincode::serialize(event).context("Failed to encode market event")?;
+    let mut frame = Vec::with_capacity(4 + payload.len());
+    frame.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    frame.extend_from_slice(&payload);
+    Ok(frame)
+}
+
+/// Decode a frame payload (without the length prefix)
+pub fn decode_event(payload: &[u8]) -> Result<MarketEvent> {
+    bincode::deserialize(payload).context("Failed to decode market event")
+}
+
+/// Sends decoded market events from the ingest process to the analyzer
+///
+/// `send` never blocks the ingest hot path: frames are queued for a background
+/// writer, and dropped (and counted) if the analyzer falls behind.
+#[derive(Clone)]
+pub struct IpcEventSender {
+    tx: mpsc::Sender<Vec<u8>>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl IpcEventSender {
+    /// Start a writer that connects (and reconnects) to the analyzer socket
+    ///
+    /// # Arguments
+    /// * `socket_path` - Unix socket the analyzer listens on
+    /// * `capacity` - Frames buffered while the analyzer is slow or reconnecting
+    pub fn connect(socket_path: &Path, capacity: usize) -> (Self, JoinHandle<()>) {
+        let (tx, mut rx) = mpsc::channel::<Vec<u8>>(capacity);
+        let socket_path: PathBuf = socket_path.to_path_buf();
+
+        let writer = tokio::spawn(async move {
+            let mut stream: Option<UnixStream> = None;
+
+            while let Some(frame) = rx.recv().await {
+                loop {
+                    if stream.is_none() {
+                        match UnixStream::connect(&socket_path).await {
+                            Ok(connected) => {
+                                info!("🔌 Connected to analyzer at {}", socket_path.display());
+                                stream = Some(connected);
+                            }
+                            Err(e) => {
+                                debug!("Analyzer not reachable at {}: {}", socket_path.display(), e);
+                                tokio::time::sleep(Duration::from_millis(500)).await;
+                                continue;
+                            }
+                        }
+                    }
+
+                    let connected = stream.as_mut().expect("stream connected above");
+                    match connected.write_all(&frame).await {
+                        Ok(()) => break,
+                        Err(e) => {
+                            warn!("Lost connection to analyzer: {}", e);
+                            stream = None;
+                        }
+                    }
+                }
+            }
+        });
+
+        (Self { tx, dropped: Arc::new(AtomicU64::new(0)) }, writer)
+    }
+
+    /// Queue an event for the analyzer
+    pub fn send(&self, event: &MarketEvent) -> Result<()> {
+        let frame = encode_event(event)?;
+        if self.tx.try_send(frame).is_err() {
+            let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+            if dropped % 1000 == 1 {
+                warn!("IPC queue full - {} events dropped so far", dropped);
+            }
+        }
+        Ok(())
+    }
+
+    /// Events dropped because the queue was full
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+/// Receives market events from ingest processes over a Unix socket
+pub struct IpcEventReceiver;
+
+impl IpcEventReceiver {
+    /// Bind the analyzer socket and forward decoded events to a channel
+    ///
+    /// # Arguments
+    /// * `socket_path` - Unix socket path; a stale file from a previous run is removed
+    /// * `capacity` - Channel capacity for decoded events
+    ///
+    /// # Returns
+    /// * `Result<(JoinHandle<()>, mpsc::Receiver<MarketEvent>)>` - Accept task and event stream
+    pub fn bind(socket_path: &Path, capacity: usize) -> Result<(JoinHandle<()>, mpsc::Receiver<MarketEvent>)> {
+        if socket_path.exists() {
+            std::fs::remove_file(socket_path)
+                .with_context(|| format!("Failed to remove stale socket {}", socket_path.display()))?;
+        }
+
+        let listener = UnixListener::bind(socket_path)
+            .with_context(|| format!("Failed to bind IPC socket {}", socket_path.display()))?;
+        let (tx, rx) = mpsc::channel(capacity);
+
+        info!("🔌 Analyzer listening for ingest processes on {}", socket_path.display());
+
+        let accept_task = tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, _)) => {
+                        info!("🔌 Ingest process connected");
+                        tokio::spawn(Self::read_frames(stream, tx.clone()));
+                    }
+                    Err(e) => {
+                        error!("Failed to accept ingest connection: {}", e);
+                        tokio::time::sleep(Duration::from_millis(100)).await;
+                    }
+                }
+            }
+        });
+
+        Ok((accept_task, rx))
+    }
+
+    /// Read frames from one ingest connection until it closes
+    async fn read_frames(mut stream: UnixStream, tx: mpsc::Sender<MarketEvent>) {
+        let mut payload = Vec::new();
+
+        loop {
+            let len = match stream.read_u32_le().await {
+                Ok(len) => len as usize,
+                Err(_) => {
+                    info!("🔌 Ingest process disconnected");
+                    return;
+                }
+            };
+
+            if len > MAX_FRAME_BYTES {
+                error!("IPC frame of {} bytes exceeds limit - dropping connection", len);
+                return;
+            }
+
+            payload.resize(len, 0);
+            if let Err(e) = stream.read_exact(&mut payload).await {
+                warn!("Truncated IPC frame: {}", e);
+                return;
+            }
+
+            match decode_event(&payload) {
+                Ok(event) => {
+                    if tx.send(event).await.is_err() {
+                        return;
+                    }
+                }
+                Err(e) => warn!("Skipping undecodable IPC frame: {}", e),
+            }
+        }
+    }
+}
@@ -2,6 +2,11 @@ pub mod enhanced_bus;
 pub mod events;
 pub mod signals;
 pub mod routing;
+pub mod ipc;
+#[cfg(feature = "api")]
+pub mod signal_api;
+pub mod webhooks;
+pub mod notifications;
 
 // Legacy modules (will be deprecated)
 pub mod market_bus;
@@ -12,7 +17,7 @@ pub mod alert_bus;
 pub use enhanced_bus::{
     EnhancedTransportBus, BusStatistics, BusHealthStatus, 
     WalletEvent, SystemAlert, InsiderAction as EnhancedInsiderAction, 
-    MovementDirection
+    MovementDirection, PositionEvent, CandleEvent
 };
 pub use events::{
     EnhancedMarketEvent, EnhancedPoolInfo, EnhancedTokenMetadata, 
@@ -23,7 +28,7 @@ pub use events::{
     WalletSwapHistory, WalletTransferHistory, MarketImpact
 };
 pub use signals::{
-    EnhancedTradingSignal, SignalUrgency, ExecutionStrategy, SellStrategy,
+    EnhancedTradingSignal, SignalUrgency, ExecutionPathway, ExecutionStrategy, SellStrategy,
     AlertType as EnhancedAlertType, AlertSeverity, RiskType, RecommendedAction,
     PriceLevel, PriceLevelType, PriceLevelAction, RiskMonitoring,
     AlertEvidence, EvidenceType, RiskEvidence
@@ -34,6 +39,18 @@ pub use routing::{
     FilterOperator, RoutingRule, RoutingCondition, RegistryStatistics,
     ServiceStatistics, RegistryHealthStatus
 };
+pub use ipc::{IpcEventSender, IpcEventReceiver, DEFAULT_IPC_SOCKET};
+#[cfg(feature = "api")]
+pub use signal_api::{
+    SignalApiServer, SignalApiConfig, ExternalSignal, ExternalSignalRequest, ExternalSide, SIGNAL_API_PATH,
+    ApiRequest, PositionAlertRulesRequest, PositionAlertUpdate, POSITION_ALERTS_API_PREFIX,
+    ApiRole, ApiClient, ApiQuery, ApiQueryKind, ApiCommand, ApiCommandRequest, ApiCall,
+    EXPOSURE_API_PATH, POSITIONS_API_PATH, COMMANDS_API_PATH,
+};
+pub use webhooks::{PositionWebhookSink, PositionWebhookConfig};
+pub use notifications::{
+    Notification, NotificationDigest, NotificationDigestConfig, NotificationDigestReport, NotificationRoute,
+};
 
 // Legacy exports (for backward compatibility)
 pub use market_bus::MarketBus;
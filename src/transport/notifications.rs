@@ -0,0 +1,249 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use tracing::debug;
+
+use super::enhanced_bus::PositionEvent;
+use super::signals::AlertSeverity;
+
+/// Alert types that always go out immediately, however often they repeat
+const CRITICAL_ALERT_TYPES: &[&str] = &["DELEVERAGE", "DATABASE_INTEGRITY", "EXECUTION_ERROR", "FEE_BUDGET"];
+
+/// Alert types that are routine and wait for the digest
+const INFO_ALERT_TYPES: &[&str] = &[
+    "SERVICE_STARTUP", "SERVICE_SHUTDOWN", "CONFIGURATION_CHANGE", "HIGH_TRAFFIC", "DUST_CONSOLIDATION",
+];
+
+/// Rate-limit keys remembered after which expired ones are pruned
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Configuration for notification digests and rate limiting
+#[derive(Debug, Clone)]
+pub struct NotificationDigestConfig {
+    /// Notifications below this severity are held for the next digest
+    pub immediate_severity: AlertSeverity,
+    /// Seconds between digests; 0 sends every notification as it happens
+    pub digest_interval_secs: i64,
+    /// Identical notifications within this window are sent once (seconds)
+    pub rate_limit_secs: i64,
+    /// Notifications listed in a digest; the rest only appear in its counts
+    pub max_digest_items: usize,
+}
+
+impl Default for NotificationDigestConfig {
+    fn default() -> Self {
+        Self {
+            immediate_severity: AlertSeverity::Warning,
+            digest_interval_secs: 900,
+            rate_limit_secs: 600,
+            max_digest_items: 50,
+        }
+    }
+}
+
+impl NotificationDigestConfig {
+    /// Defaults with overrides from `BADGER_NOTIFY_DIGEST_SECS` and `BADGER_NOTIFY_RATE_LIMIT_SECS` when set
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(secs) = std::env::var("BADGER_NOTIFY_DIGEST_SECS").ok().and_then(|v| v.parse().ok()) {
+            config.digest_interval_secs = secs;
+        }
+        if let Some(secs) = std::env::var("BADGER_NOTIFY_RATE_LIMIT_SECS").ok().and_then(|v| v.parse().ok()) {
+            config.rate_limit_secs = secs;
+        }
+        config
+    }
+}
+
+/// A position event or operator alert on its way to the notification channel
+#[derive(Debug, Clone, Serialize)]
+pub struct Notification {
+    pub severity: AlertSeverity,
+    /// Event or alert type, e.g. "PositionOpened" or "CHAIN_LAG"
+    pub kind: String,
+    /// Notifications with the same key count as identical for rate limiting
+    #[serde(skip)]
+    pub key: String,
+    pub message: String,
+    pub timestamp: i64,
+}
+
+impl Notification {
+    /// Stops need a look; fills and closes are routine
+    pub fn from_position_event(event: &PositionEvent) -> Self {
+        let (severity, message, timestamp) = match event {
+            PositionEvent::PositionOpened { position_id, token_mint, cost_sol, timestamp, .. } => (
+                AlertSeverity::Info,
+                format!("Opened #{} {} for {:.4} SOL", position_id, token_mint, cost_sol),
+                *timestamp,
+            ),
+            PositionEvent::PositionIncreased { position_id, token_mint, added_quantity, timestamp, .. } => (
+                AlertSeverity::Info,
+                format!("Added {:.2} to #{} {}", added_quantity, position_id, token_mint),
+                *timestamp,
+            ),
+            PositionEvent::StopTriggered { position_id, token_mint, stop_price, trigger_price, timestamp } => (
+                AlertSeverity::Warning,
+                format!("Stop on #{} {} at {:.10} (stop {:.10})", position_id, token_mint, trigger_price, stop_price),
+                *timestamp,
+            ),
+            PositionEvent::PositionReduced { position_id, token_mint, realized_pnl, timestamp, .. } => (
+                AlertSeverity::Info,
+                format!("Reduced #{} {} (P&L {:.4} SOL)", position_id, token_mint, realized_pnl),
+                *timestamp,
+            ),
+            PositionEvent::PositionClosed { position_id, token_mint, realized_pnl, timestamp, .. } => (
+                AlertSeverity::Info,
+                format!("Closed #{} {} (P&L {:.4} SOL)", position_id, token_mint, realized_pnl),
+                *timestamp,
+            ),
+        };
+        Self {
+            severity,
+            kind: event.event_type().to_string(),
+            key: format!("{}:{}", event.event_type(), event.position_id()),
+            message,
+            timestamp,
+        }
+    }
+
+    /// Alert recorded in the operator alert log
+    pub fn from_alert(alert_type: &str, service: &str, message: &str, timestamp: i64) -> Self {
+        let severity = if CRITICAL_ALERT_TYPES.contains(&alert_type) {
+            AlertSeverity::Critical
+        } else if INFO_ALERT_TYPES.contains(&alert_type) {
+            AlertSeverity::Info
+        } else {
+            AlertSeverity::Warning
+        };
+        Self {
+            severity,
+            kind: alert_type.to_string(),
+            key: format!("{}:{}:{}", alert_type, service, message),
+            message: format!("[{}] {}", service, message),
+            timestamp,
+        }
+    }
+}
+
+/// What to do with a notification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationRoute {
+    /// Send it now
+    Immediate,
+    /// Held for the next digest
+    Digested,
+    /// Identical to one sent within the rate-limit window; dropped
+    Suppressed,
+}
+
+/// Low-priority notifications grouped over one digest period
+#[derive(Debug, Clone, Serialize)]
+pub struct NotificationDigestReport {
+    pub period_start: i64,
+    pub period_end: i64,
+    /// Notifications per kind, including those not listed
+    pub counts: BTreeMap<String, usize>,
+    pub notifications: Vec<Notification>,
+    /// Repeats dropped by the rate limit, per kind
+    pub suppressed: BTreeMap<String, usize>,
+}
+
+impl NotificationDigestReport {
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+}
+
+struct DigestState {
+    period_start: i64,
+    counts: BTreeMap<String, usize>,
+    pending: Vec<Notification>,
+    suppressed: BTreeMap<String, usize>,
+    /// Key -> when it was last let through
+    last_seen: HashMap<String, i64>,
+}
+
+/// Decides which notifications go out now, which wait for a digest and
+/// which are repeats
+///
+/// Pinging on every fill trains people to mute the channel, and then a
+/// de-leveraging trip goes unseen. Routine notifications are grouped into a
+/// periodic digest, identical ones are sent once per rate-limit window, and
+/// critical ones always go straight out.
+pub struct NotificationDigest {
+    config: NotificationDigestConfig,
+    state: Mutex<DigestState>,
+}
+
+impl NotificationDigest {
+    pub fn new(config: Option<NotificationDigestConfig>, now: i64) -> Self {
+        Self {
+            config: config.unwrap_or_default(),
+            state: Mutex::new(DigestState {
+                period_start: now,
+                counts: BTreeMap::new(),
+                pending: Vec::new(),
+                suppressed: BTreeMap::new(),
+                last_seen: HashMap::new(),
+            }),
+        }
+    }
+
+    pub fn config(&self) -> &NotificationDigestConfig {
+        &self.config
+    }
+
+    /// Route a notification, recording it for rate limiting and the digest
+    pub fn route(&self, notification: &Notification, now: i64) -> NotificationRoute {
+        if notification.severity == AlertSeverity::Critical {
+            return NotificationRoute::Immediate;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        if state.last_seen.len() > PRUNE_THRESHOLD {
+            let window = self.config.rate_limit_secs;
+            state.last_seen.retain(|_, seen_at| now - *seen_at < window);
+        }
+        if let Some(seen_at) = state.last_seen.get(&notification.key) {
+            if now - *seen_at < self.config.rate_limit_secs {
+                *state.suppressed.entry(notification.kind.clone()).or_insert(0) += 1;
+                debug!("🔕 Repeated {} notification suppressed", notification.kind);
+                return NotificationRoute::Suppressed;
+            }
+        }
+        state.last_seen.insert(notification.key.clone(), now);
+
+        if self.config.digest_interval_secs <= 0 || notification.severity >= self.config.immediate_severity {
+            return NotificationRoute::Immediate;
+        }
+        *state.counts.entry(notification.kind.clone()).or_insert(0) += 1;
+        if state.pending.len() < self.config.max_digest_items {
+            state.pending.push(notification.clone());
+        }
+        NotificationRoute::Digested
+    }
+
+    /// Close the digest period once it has run its interval
+    ///
+    /// # Returns
+    /// * `Option<NotificationDigestReport>` - The period's digest; None while the period is open or when nothing was held or suppressed
+    pub fn take_due(&self, now: i64) -> Option<NotificationDigestReport> {
+        let mut state = self.state.lock().unwrap();
+        if now - state.period_start < self.config.digest_interval_secs.max(0) {
+            return None;
+        }
+        let report = NotificationDigestReport {
+            period_start: state.period_start,
+            period_end: now,
+            counts: std::mem::take(&mut state.counts),
+            notifications: std::mem::take(&mut state.pending),
+            suppressed: std::mem::take(&mut state.suppressed),
+        };
+        state.period_start = now;
+        if report.counts.is_empty() && report.suppressed.is_empty() {
+            return None;
+        }
+        Some(report)
+    }
+}
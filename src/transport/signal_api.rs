@@ -0,0 +1,637 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn, error};
+
+/// Longest accepted request line (bytes)
+const MAX_REQUEST_LINE_BYTES: u64 = 8 * 1024;
+/// Largest accepted header section, all lines together (bytes)
+const MAX_HEADER_BYTES: u64 = 16 * 1024;
+
+/// Path external systems POST signals to
+pub const SIGNAL_API_PATH: &str = "/v1/signals";
+
+/// Per-position alert rules are PUT to `/v1/positions/{id}/alerts`
+pub const POSITION_ALERTS_API_PREFIX: &str = "/v1/positions/";
+
+/// Combined per-mint exposure is read with GET, for all tokens or `/v1/exposure/{mint}`
+pub const EXPOSURE_API_PATH: &str = "/v1/exposure";
+
+/// Open positions are read with GET
+pub const POSITIONS_API_PATH: &str = "/v1/positions";
+
+/// Operator commands are POSTed here and their outcome read from `/v1/commands/{id}`
+pub const COMMANDS_API_PATH: &str = "/v1/commands";
+
+/// What an API client may do; each role includes the ones before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiRole {
+    /// Reads only: exposure, open positions and command outcomes (dashboards)
+    ReadOnly,
+    /// Signals, alert rules, and operator commands that trade or change configuration
+    Operator,
+    /// Commands that move funds, swap the live strategy or hand off trading
+    Admin,
+}
+
+impl ApiRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiRole::ReadOnly => "read_only",
+            ApiRole::Operator => "operator",
+            ApiRole::Admin => "admin",
+        }
+    }
+}
+
+impl FromStr for ApiRole {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "read_only" | "readonly" => Ok(ApiRole::ReadOnly),
+            "operator" => Ok(ApiRole::Operator),
+            "admin" => Ok(ApiRole::Admin),
+            other => anyhow::bail!("unknown API role {}", other),
+        }
+    }
+}
+
+/// Authenticated API client
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiClient {
+    pub name: String,
+    pub role: ApiRole,
+}
+
+/// Configuration for the external signal endpoint
+#[derive(Debug, Clone)]
+pub struct SignalApiConfig {
+    /// Address the endpoint listens on; keep it on localhost or behind a proxy
+    pub bind_addr: String,
+    /// Environment variable with `client:token` pairs, comma separated
+    pub tokens_env: String,
+    /// Largest accepted request body (bytes)
+    pub max_body_bytes: usize,
+    /// Buy size cap applied before the strategy's own risk checks (SOL)
+    pub max_amount_sol: f64,
+}
+
+impl Default for SignalApiConfig {
+    fn default() -> Self {
+        Self {
+            bind_addr: "127.0.0.1:8787".to_string(),
+            tokens_env: "BADGER_SIGNAL_API_TOKENS".to_string(),
+            max_body_bytes: 16 * 1024,
+            max_amount_sol: 1.0,
+        }
+    }
+}
+
+/// Side of an external signal
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExternalSide {
+    Buy,
+    Sell,
+}
+
+/// Signal body accepted from external systems
+///
+/// ```json
+/// {"side": "buy", "token_mint": "...", "amount_sol": 0.2, "confidence": 0.8, "reason": "notebook call"}
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalSignalRequest {
+    pub side: ExternalSide,
+    pub token_mint: String,
+    /// Buy size (SOL); required for buys
+    pub amount_sol: Option<f64>,
+    /// Buy confidence in [0, 1]; defaults to 0.5
+    pub confidence: Option<f64>,
+    /// Sell price target; defaults to the current mark, and must be positive when set
+    pub price_target: Option<f64>,
+    /// Sell stop loss; defaults to none
+    pub stop_loss: Option<f64>,
+    pub reason: Option<String>,
+}
+
+/// Validated signal from an authenticated external client
+#[derive(Debug, Clone)]
+pub struct ExternalSignal {
+    /// `external:<client>`, recorded with the signal so its trades can be told apart
+    pub signal_source: String,
+    pub request: ExternalSignalRequest,
+}
+
+/// Alert rule override for one position; omitted rules keep their defaults
+///
+/// ```json
+/// {"gain_pct": 2.0, "loss_pct": 0.2, "pnl_move_sol": 1.0}
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PositionAlertRulesRequest {
+    /// Return that fires a gain alert (1.0 = +100%); 0 disables it
+    pub gain_pct: Option<f64>,
+    /// Loss that fires a loss alert (0.3 = -30%); 0 disables it
+    pub loss_pct: Option<f64>,
+    /// Unrealized P&L change within the move window that fires a move alert (SOL); 0 disables it
+    pub pnl_move_sol: Option<f64>,
+}
+
+impl PositionAlertRulesRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        for value in [self.gain_pct, self.loss_pct, self.pnl_move_sol].into_iter().flatten() {
+            if !value.is_finite() || value < 0.0 {
+                return Err("alert thresholds must be non-negative".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Validated alert rule override from an authenticated external client
+#[derive(Debug, Clone)]
+pub struct PositionAlertUpdate {
+    /// `external:<client>`
+    pub signal_source: String,
+    pub position_id: i64,
+    pub rules: PositionAlertRulesRequest,
+}
+
+/// Read answered by the caller
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiQueryKind {
+    /// Combined exposure across strategies of one token, or of every token held when None
+    Exposure { token_mint: Option<String> },
+    OpenPositions,
+    /// Status and result of an operator command
+    CommandStatus { id: i64 },
+}
+
+/// Read waiting for the caller's answer: HTTP status and JSON body
+#[derive(Debug)]
+pub struct ApiQuery {
+    pub kind: ApiQueryKind,
+    pub reply: oneshot::Sender<(u16, serde_json::Value)>,
+}
+
+/// Operator command body
+///
+/// ```json
+/// {"command": "CLOSE_POSITION", "target": "<mint>"}
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiCommandRequest {
+    pub command: String,
+    pub target: Option<String>,
+}
+
+/// Operator command from an authenticated client; the caller checks the role
+/// the command needs, queues it and answers with its id
+#[derive(Debug)]
+pub struct ApiCommand {
+    pub client: ApiClient,
+    pub request: ApiCommandRequest,
+    pub reply: oneshot::Sender<(u16, serde_json::Value)>,
+}
+
+/// One API call for the audit log, refused ones included
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiCall {
+    /// None when the caller did not authenticate
+    pub client: Option<String>,
+    pub role: Option<ApiRole>,
+    pub method: String,
+    pub path: String,
+    pub status: u16,
+    /// Command and target, or the error returned
+    pub detail: Option<String>,
+}
+
+/// Request accepted by the API, queued for the caller
+#[derive(Debug)]
+pub enum ApiRequest {
+    Signal(ExternalSignal),
+    PositionAlerts(PositionAlertUpdate),
+    Query(ApiQuery),
+    Command(ApiCommand),
+    Audit(ApiCall),
+}
+
+/// Endpoint a request is for
+#[derive(Debug, Clone, PartialEq)]
+enum Route {
+    Signal,
+    PositionAlerts(i64),
+    Query(ApiQueryKind),
+    Command,
+}
+
+impl Route {
+    fn parse(method: &str, path: &str) -> Option<Self> {
+        let id_after = |prefix: &str, suffix: &str| path.strip_prefix(prefix)
+            .and_then(|rest| rest.strip_suffix(suffix))
+            .and_then(|id| id.parse::<i64>().ok());
+        match method {
+            "POST" if path == SIGNAL_API_PATH => Some(Route::Signal),
+            "POST" if path == COMMANDS_API_PATH => Some(Route::Command),
+            "PUT" => id_after(POSITION_ALERTS_API_PREFIX, "/alerts").map(Route::PositionAlerts),
+            "GET" if path == POSITIONS_API_PATH => Some(Route::Query(ApiQueryKind::OpenPositions)),
+            "GET" => match path.strip_prefix(EXPOSURE_API_PATH) {
+                Some("") | Some("/") => Some(Route::Query(ApiQueryKind::Exposure { token_mint: None })),
+                Some(rest) => rest.strip_prefix('/')
+                    .map(|mint| Route::Query(ApiQueryKind::Exposure { token_mint: Some(mint.to_string()) })),
+                None => id_after(&format!("{}/", COMMANDS_API_PATH), "")
+                    .map(|id| Route::Query(ApiQueryKind::CommandStatus { id })),
+            },
+            _ => None,
+        }
+    }
+
+    /// Least role that may call the route; commands can need more, decided by the caller
+    fn required_role(&self) -> ApiRole {
+        match self {
+            Route::Query(_) => ApiRole::ReadOnly,
+            Route::Signal | Route::PositionAlerts(_) | Route::Command => ApiRole::Operator,
+        }
+    }
+}
+
+impl ExternalSignalRequest {
+    /// Check the request against the endpoint limits
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - Reason the request was rejected
+    pub fn validate(&self, config: &SignalApiConfig) -> Result<(), String> {
+        Pubkey::from_str(&self.token_mint).map_err(|_| format!("invalid token_mint {}", self.token_mint))?;
+
+        if let Some(confidence) = self.confidence {
+            if !(0.0..=1.0).contains(&confidence) {
+                return Err("confidence must be between 0 and 1".to_string());
+            }
+        }
+
+        match self.side {
+            ExternalSide::Buy => {
+                let amount_sol = self.amount_sol.ok_or("amount_sol is required for buys")?;
+                if !amount_sol.is_finite() || amount_sol <= 0.0 {
+                    return Err("amount_sol must be positive".to_string());
+                }
+                if amount_sol > config.max_amount_sol {
+                    return Err(format!("amount_sol exceeds the {} SOL limit", config.max_amount_sol));
+                }
+            }
+            ExternalSide::Sell => {
+                // The exit is booked at the target, so a zero target would book a total loss
+                if self.price_target.is_some_and(|target| !target.is_finite() || target <= 0.0) {
+                    return Err("price_target must be positive".to_string());
+                }
+                if self.stop_loss.is_some_and(|stop_loss| !stop_loss.is_finite() || stop_loss < 0.0) {
+                    return Err("stop_loss must be non-negative".to_string());
+                }
+            }
+        }
+
+        if self.reason.as_ref().is_some_and(|reason| reason.len() > 256) {
+            return Err("reason is longer than 256 characters".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// HTTP endpoint that lets authenticated external systems submit signals,
+/// set per-position alert rules, queue operator commands and read state
+///
+/// Every client key carries a role: read-only keys (dashboards) can only
+/// read, operator keys can also trade and change configuration, and admin
+/// keys can run every command. Accepted requests are only queued; the caller
+/// feeds signals through the same pipeline and risk checks as internally
+/// generated signals. Every call, refused ones included, is handed to the
+/// caller for the audit log.
+pub struct SignalApiServer;
+
+impl SignalApiServer {
+    /// Bind the endpoint and forward validated requests to a channel
+    ///
+    /// # Arguments
+    /// * `config` - Endpoint configuration
+    /// * `capacity` - Channel capacity for accepted requests
+    ///
+    /// # Returns
+    /// * `Result<(JoinHandle<()>, mpsc::Receiver<ApiRequest>)>` - Accept task and request stream
+    pub async fn bind(config: SignalApiConfig, capacity: usize) -> Result<(JoinHandle<()>, mpsc::Receiver<ApiRequest>)> {
+        let tokens = Arc::new(Self::load_tokens(&config.tokens_env)?);
+        let listener = TcpListener::bind(&config.bind_addr).await
+            .with_context(|| format!("Failed to bind signal API on {}", config.bind_addr))?;
+        let (tx, rx) = mpsc::channel(capacity);
+
+        info!("📡 Accepting external signals on http://{}{} ({} clients)", config.bind_addr, SIGNAL_API_PATH, tokens.len());
+
+        let config = Arc::new(config);
+        let accept_task = tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, peer)) => {
+                        let (config, tokens, tx) = (config.clone(), tokens.clone(), tx.clone());
+                        tokio::spawn(async move {
+                            let response = tokio::time::timeout(
+                                Duration::from_secs(10),
+                                Self::handle_connection(stream, &config, &tokens, &tx),
+                            ).await;
+                            if let Ok(Err(e)) = response {
+                                debug!("Signal API connection from {} failed: {}", peer, e);
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        error!("Failed to accept signal API connection: {}", e);
+                        tokio::time::sleep(Duration::from_millis(100)).await;
+                    }
+                }
+            }
+        });
+
+        Ok((accept_task, rx))
+    }
+
+    /// Parse `client:token[:role]` entries; token -> client
+    ///
+    /// Entries without a role are operator keys, as every key was before roles existed.
+    fn load_tokens(tokens_env: &str) -> Result<HashMap<String, ApiClient>> {
+        let raw = std::env::var(tokens_env)
+            .with_context(|| format!("Signal API token variable {} not set", tokens_env))?;
+
+        let tokens = raw.split(',')
+            .filter(|entry| !entry.trim().is_empty())
+            .map(|entry| {
+                let mut parts = entry.trim().splitn(3, ':');
+                let (Some(client), Some(token)) = (parts.next(), parts.next()) else {
+                    anyhow::bail!("Invalid entry in {}; expected client:token[:role]", tokens_env);
+                };
+                let role = match parts.next() {
+                    Some(role) => role.parse().with_context(|| format!("Invalid role for client {} in {}", client, tokens_env))?,
+                    // Keys without a role get the least privilege
+                    None => ApiRole::ReadOnly,
+                };
+                Ok((token.to_string(), ApiClient { name: client.to_string(), role }))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+
+        if tokens.is_empty() {
+            anyhow::bail!("{} has no client tokens", tokens_env);
+        }
+        Ok(tokens)
+    }
+
+    /// Client of a bearer token, comparing against every known token in constant time
+    fn client_for_token<'a>(tokens: &'a HashMap<String, ApiClient>, presented: &str) -> Option<&'a ApiClient> {
+        let mut found = None;
+        for (token, client) in tokens {
+            if constant_time_eq(token.as_bytes(), presented.as_bytes()) {
+                found = Some(client);
+            }
+        }
+        found
+    }
+
+    /// Serve one request per connection
+    async fn handle_connection(
+        stream: TcpStream,
+        config: &SignalApiConfig,
+        tokens: &HashMap<String, ApiClient>,
+        tx: &mpsc::Sender<ApiRequest>,
+    ) -> Result<()> {
+        let mut reader = BufReader::new(stream);
+
+        let mut request_line = String::new();
+        (&mut reader).take(MAX_REQUEST_LINE_BYTES).read_line(&mut request_line).await.context("Failed to read request line")?;
+        if request_line.len() as u64 >= MAX_REQUEST_LINE_BYTES && !request_line.ends_with('\n') {
+            warn!("📡 Rejected request with a request line over {} bytes", MAX_REQUEST_LINE_BYTES);
+            return Self::reject(reader.into_inner(), 414, "URI Too Long").await;
+        }
+        let mut parts = request_line.split_whitespace();
+        let (method, path) = (parts.next().unwrap_or_default(), parts.next().unwrap_or_default());
+
+        let mut content_length = 0usize;
+        let mut bearer = None;
+        let mut header_budget = MAX_HEADER_BYTES;
+        loop {
+            let mut line = String::new();
+            let read = (&mut reader).take(header_budget).read_line(&mut line).await.context("Failed to read header")?;
+            if read == 0 {
+                break;
+            }
+            header_budget -= read as u64;
+            // Out of budget before the blank line that ends the headers
+            if header_budget == 0 && !line.trim_end().is_empty() {
+                warn!("📡 Rejected {} {}: headers over {} bytes", method, path, MAX_HEADER_BYTES);
+                return Self::reject(reader.into_inner(), 431, "Request Header Fields Too Large").await;
+            }
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                match name.trim().to_ascii_lowercase().as_str() {
+                    "content-length" => content_length = value.trim().parse().unwrap_or(0),
+                    "authorization" => bearer = value.trim().strip_prefix("Bearer ").map(str::to_string),
+                    _ => {}
+                }
+            }
+        }
+
+        let route = Route::parse(method, path);
+        let client = bearer.as_deref().and_then(|token| Self::client_for_token(tokens, token));
+
+        let (status, body, detail) = match (&route, client) {
+            (None, _) => (404, serde_json::json!({"error": "not found"}), None),
+            (Some(_), None) => {
+                warn!("📡 Rejected unauthenticated {} {}", method, path);
+                (401, serde_json::json!({"error": "unauthorized"}), None)
+            }
+            (Some(route), Some(client)) if client.role < route.required_role() => {
+                warn!("📡 Refused {} {} to {} ({} key)", method, path, client.name, client.role.as_str());
+                (403, serde_json::json!({"error": format!("{} role required", route.required_role().as_str())}), None)
+            }
+            (Some(_), Some(_)) if content_length > config.max_body_bytes => {
+                (413, serde_json::json!({"error": "body too large"}), None)
+            }
+            (Some(route), Some(client)) => {
+                let mut payload = vec![0u8; content_length];
+                reader.read_exact(&mut payload).await.context("Truncated request body")?;
+                match route {
+                    Route::Signal => Self::accept(&client.name, &payload, config, tx).await,
+                    Route::PositionAlerts(position_id) => Self::accept_alert_rules(&client.name, *position_id, &payload, tx),
+                    Route::Query(kind) => Self::query(kind.clone(), tx).await,
+                    Route::Command => Self::accept_command(client, &payload, tx).await,
+                }
+            }
+        };
+
+        // Unknown paths are noise; every call that reached an endpoint is audited
+        if route.is_some() {
+            let call = ApiCall {
+                client: client.map(|client| client.name.clone()),
+                role: client.map(|client| client.role),
+                method: method.to_string(),
+                path: path.to_string(),
+                status,
+                detail: detail.or_else(|| body.get("error").and_then(|e| e.as_str()).map(str::to_string)),
+            };
+            if tx.send(ApiRequest::Audit(call)).await.is_err() {
+                warn!("📡 Audit of {} {} lost: request queue closed", method, path);
+            }
+        }
+
+        let body = body.to_string();
+        let reason = match status {
+            200 => "OK",
+            202 => "Accepted",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            403 => "Forbidden",
+            404 => "Not Found",
+            413 => "Payload Too Large",
+            500 => "Internal Server Error",
+            503 => "Service Unavailable",
+            _ => "Unknown",
+        };
+        let response = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status, reason, body.len(), body
+        );
+        reader.into_inner().write_all(response.as_bytes()).await.context("Failed to write response")?;
+        Ok(())
+    }
+
+    /// Answer a request refused before it was parsed, without reading the rest of it
+    async fn reject(mut stream: TcpStream, status: u16, reason: &str) -> Result<()> {
+        let body = serde_json::json!({"error": reason.to_lowercase()}).to_string();
+        let response = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status, reason, body.len(), body
+        );
+        stream.write_all(response.as_bytes()).await.context("Failed to write response")?;
+        // Closing with unread request bytes resets the connection before the client reads the answer
+        stream.shutdown().await.context("Failed to close response")?;
+        let _ = tokio::time::timeout(
+            Duration::from_secs(1),
+            tokio::io::copy(&mut (&mut stream).take(MAX_HEADER_BYTES), &mut tokio::io::sink()),
+        ).await;
+        Ok(())
+    }
+
+    /// Validate a request body and queue the signal
+    async fn accept(
+        client: &str,
+        payload: &[u8],
+        config: &SignalApiConfig,
+        tx: &mpsc::Sender<ApiRequest>,
+    ) -> (u16, serde_json::Value, Option<String>) {
+        let request: ExternalSignalRequest = match serde_json::from_slice(payload) {
+            Ok(request) => request,
+            Err(e) => return (400, serde_json::json!({"error": format!("invalid signal: {}", e)}), None),
+        };
+        if let Err(reason) = request.validate(config) {
+            return (400, serde_json::json!({"error": reason}), None);
+        }
+        let detail = Some(format!("{:?} {}", request.side, request.token_mint));
+
+        let signal = ExternalSignal {
+            signal_source: format!("external:{}", client),
+            request,
+        };
+        info!("📡 {} submitted {:?} {}", signal.signal_source, signal.request.side, signal.request.token_mint);
+
+        match tx.try_send(ApiRequest::Signal(signal)) {
+            Ok(()) => (202, serde_json::json!({"status": "accepted"}), detail),
+            Err(_) => (503, serde_json::json!({"error": "signal queue full"}), detail),
+        }
+    }
+
+    /// Validate an alert rule override and queue it
+    fn accept_alert_rules(
+        client: &str,
+        position_id: i64,
+        payload: &[u8],
+        tx: &mpsc::Sender<ApiRequest>,
+    ) -> (u16, serde_json::Value, Option<String>) {
+        let rules: PositionAlertRulesRequest = match serde_json::from_slice(payload) {
+            Ok(rules) => rules,
+            Err(e) => return (400, serde_json::json!({"error": format!("invalid alert rules: {}", e)}), None),
+        };
+        if let Err(reason) = rules.validate() {
+            return (400, serde_json::json!({"error": reason}), None);
+        }
+        let detail = serde_json::to_string(&rules).ok();
+
+        let update = PositionAlertUpdate {
+            signal_source: format!("external:{}", client),
+            position_id,
+            rules,
+        };
+        info!("📡 {} set alert rules of position #{}", update.signal_source, position_id);
+
+        match tx.try_send(ApiRequest::PositionAlerts(update)) {
+            Ok(()) => (202, serde_json::json!({"status": "accepted"}), detail),
+            Err(_) => (503, serde_json::json!({"error": "request queue full"}), detail),
+        }
+    }
+
+    /// Ask the caller for a read and wait for its answer
+    async fn query(kind: ApiQueryKind, tx: &mpsc::Sender<ApiRequest>) -> (u16, serde_json::Value, Option<String>) {
+        if let ApiQueryKind::Exposure { token_mint: Some(token_mint) } = &kind {
+            if Pubkey::from_str(token_mint).is_err() {
+                return (400, serde_json::json!({"error": format!("invalid token_mint {}", token_mint)}), None);
+            }
+        }
+        let (reply, response) = oneshot::channel();
+        if tx.try_send(ApiRequest::Query(ApiQuery { kind, reply })).is_err() {
+            return (503, serde_json::json!({"error": "request queue full"}), None);
+        }
+        match response.await {
+            Ok((status, body)) => (status, body, None),
+            Err(_) => (503, serde_json::json!({"error": "query unavailable"}), None),
+        }
+    }
+
+    /// Parse an operator command and wait for the caller to queue it
+    async fn accept_command(client: &ApiClient, payload: &[u8], tx: &mpsc::Sender<ApiRequest>) -> (u16, serde_json::Value, Option<String>) {
+        let request: ApiCommandRequest = match serde_json::from_slice(payload) {
+            Ok(request) => request,
+            Err(e) => return (400, serde_json::json!({"error": format!("invalid command: {}", e)}), None),
+        };
+        let detail = Some(match &request.target {
+            Some(target) => format!("{} {}", request.command, target),
+            None => request.command.clone(),
+        });
+        info!("📡 {} submitted command {}", client.name, detail.as_deref().unwrap_or_default());
+
+        let (reply, response) = oneshot::channel();
+        if tx.try_send(ApiRequest::Command(ApiCommand { client: client.clone(), request, reply })).is_err() {
+            return (503, serde_json::json!({"error": "request queue full"}), detail);
+        }
+        match response.await {
+            Ok((status, body)) => (status, body, detail),
+            Err(_) => (503, serde_json::json!({"error": "command queue unavailable"}), detail),
+        }
+    }
+}
+
+/// Byte equality whose timing does not depend on where the inputs differ
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
@@ -104,11 +104,12 @@ pub enum EnhancedTradingSignal {
 }
 
 /// Signal urgency levels for execution prioritization
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
 pub enum SignalUrgency {
     /// Low priority - execute within 5-10 minutes
     Low,
     /// Medium priority - execute within 1-2 minutes
+    #[default]
     Medium,
     /// High priority - execute within 10-30 seconds
     High,
@@ -116,6 +117,47 @@ pub enum SignalUrgency {
     Critical,
 }
 
+impl SignalUrgency {
+    /// Urgency of a basic trading signal, as carried by its enhanced form
+    ///
+    /// Sniper buys and sells at or through their stop are critical; working
+    /// orders (DCA tranches, rebalances) were scheduled ahead and can wait.
+    pub fn for_signal(signal: &crate::core::TradingSignal) -> Option<Self> {
+        match signal {
+            crate::core::TradingSignal::Buy { source: SignalSource::Sniper, .. } => Some(SignalUrgency::Critical),
+            crate::core::TradingSignal::Buy { source: SignalSource::WorkingOrder, .. } => Some(SignalUrgency::Low),
+            crate::core::TradingSignal::Buy { confidence, .. } => {
+                Some(if *confidence > 0.8 { SignalUrgency::High } else { SignalUrgency::Medium })
+            }
+            crate::core::TradingSignal::Sell { price_target, stop_loss, .. } if *stop_loss > 0.0 && price_target <= stop_loss => {
+                Some(SignalUrgency::Critical)
+            }
+            crate::core::TradingSignal::Sell { .. } => Some(SignalUrgency::Medium),
+            _ => None,
+        }
+    }
+
+    /// Execution pathway for signals of this urgency
+    pub fn pathway(self) -> ExecutionPathway {
+        match self {
+            SignalUrgency::Critical => ExecutionPathway::JitoTemplate,
+            SignalUrgency::High | SignalUrgency::Medium => ExecutionPathway::Jupiter,
+            SignalUrgency::Low => ExecutionPathway::Batched,
+        }
+    }
+}
+
+/// How a signal's swap is built and submitted
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ExecutionPathway {
+    /// Pre-built swap template sent through the Jito block engine with a high tip
+    JitoTemplate,
+    /// Fresh Jupiter quote submitted over RPC with adaptive priority fees
+    Jupiter,
+    /// Held briefly and merged with other low-urgency swaps of the same token
+    Batched,
+}
+
 /// Risk levels for position sizing and execution decisions
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub enum RiskLevel {
@@ -181,7 +223,7 @@ pub enum AlertType {
 }
 
 /// Alert severity levels
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub enum AlertSeverity {
     /// Informational only
     Info,
@@ -379,7 +421,7 @@ impl EnhancedTradingSignal {
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_millis();
-        let random: u32 = rand::random();
+        let random = crate::core::rng::random_u32();
         format!("signal_{}_{}", timestamp, random)
     }
 }
@@ -388,16 +430,17 @@ impl From<crate::core::TradingSignal> for EnhancedTradingSignal {
     fn from(signal: crate::core::TradingSignal) -> Self {
         let now = Utc::now();
         let signal_id = EnhancedTradingSignal::generate_signal_id();
+        let urgency = SignalUrgency::for_signal(&signal).unwrap_or_default();
         
         match signal {
-            crate::core::TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source } => {
+            crate::core::TradingSignal::Buy { token_mint, confidence, max_amount_sol, reason, source, .. } => {
                 EnhancedTradingSignal::Buy {
                     token_mint,
                     confidence,
                     max_amount_sol,
                     reason,
                     source,
-                    urgency: if confidence > 0.8 { SignalUrgency::High } else { SignalUrgency::Medium },
+                    urgency,
                     risk_level: if confidence > 0.7 { RiskLevel::Medium } else { RiskLevel::High },
                     expected_roi: None,
                     time_horizon_minutes: 60, // Default 1 hour
@@ -418,7 +461,7 @@ impl From<crate::core::TradingSignal> for EnhancedTradingSignal {
                     target_price: Some(price_target),
                     stop_loss_price: Some(stop_loss),
                     reason,
-                    urgency: SignalUrgency::Medium,
+                    urgency,
                     sell_strategy: SellStrategy::Market,
                     max_slippage_percentage: 5.0,
                     partial_sell_percentage: None,
@@ -443,6 +486,21 @@ impl From<crate::core::TradingSignal> for EnhancedTradingSignal {
                     signal_id,
                 }
             }
+            crate::core::TradingSignal::Hedge { instrument, direction, size_sol, exposure_sol, reason } => {
+                EnhancedTradingSignal::Alert {
+                    message: format!("Hedge {:?} {:.3} SOL of {} against {:.3} SOL exposure: {}",
+                                   direction, size_sol, instrument, exposure_sol, reason),
+                    alert_type: AlertType::Risk,
+                    severity: AlertSeverity::Warning,
+                    requires_action: true,
+                    action_deadline: Some(now + chrono::Duration::minutes(15)),
+                    related_tokens: vec![instrument],
+                    related_wallets: Vec::new(),
+                    evidence: Vec::new(),
+                    created_at: now,
+                    signal_id,
+                }
+            }
         }
     }
 }
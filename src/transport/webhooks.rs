@@ -0,0 +1,132 @@
+use anyhow::Result;
+use reqwest::Client;
+use serde::Serialize;
+use std::time::Duration;
+use tracing::{debug, warn};
+
+use super::enhanced_bus::PositionEvent;
+use super::notifications::{Notification, NotificationDigestReport};
+
+/// Configuration for forwarding position events to webhooks
+#[derive(Debug, Clone)]
+pub struct PositionWebhookConfig {
+    /// Endpoints every position event is POSTed to
+    pub urls: Vec<String>,
+    /// Per-request timeout (milliseconds)
+    pub request_timeout_ms: u64,
+    /// Attempts per endpoint before the event is dropped for it
+    pub max_attempts: u32,
+}
+
+impl Default for PositionWebhookConfig {
+    fn default() -> Self {
+        Self {
+            urls: Vec::new(),
+            request_timeout_ms: 5000,
+            max_attempts: 3,
+        }
+    }
+}
+
+impl PositionWebhookConfig {
+    /// Endpoints from `BADGER_POSITION_WEBHOOK_URLS` (comma separated)
+    pub fn from_env() -> Self {
+        let urls = std::env::var("BADGER_POSITION_WEBHOOK_URLS")
+            .map(|value| value.split(',')
+                .map(|url| url.trim().to_string())
+                .filter(|url| !url.is_empty())
+                .collect())
+            .unwrap_or_default();
+        Self { urls, ..Self::default() }
+    }
+}
+
+/// Posts position lifecycle events as JSON to the configured webhooks
+///
+/// Each event goes out as its tagged JSON form, e.g.
+/// `{"event": "PositionClosed", "position_id": 7, "realized_pnl": 0.12, ...}`.
+/// Operator alerts and notification digests go to the same endpoints tagged
+/// `"Alert"` and `"Digest"`.
+pub struct PositionWebhookSink {
+    client: Client,
+    config: PositionWebhookConfig,
+}
+
+impl PositionWebhookSink {
+    pub fn new(config: PositionWebhookConfig) -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_millis(config.request_timeout_ms))
+            .build()
+            .unwrap_or_default();
+        Self { client, config }
+    }
+
+    pub fn config(&self) -> &PositionWebhookConfig {
+        &self.config
+    }
+
+    /// Deliver an event to every webhook; returns how many accepted it
+    pub async fn deliver(&self, event: &PositionEvent) -> usize {
+        let label = format!("{} #{}", event.event_type(), event.position_id());
+        self.deliver_payload(event, &label).await
+    }
+
+    /// Deliver an operator alert as `{"event": "Alert", "severity": ..., "kind": ..., ...}`
+    pub async fn deliver_alert(&self, notification: &Notification) -> usize {
+        #[derive(Serialize)]
+        struct AlertPayload<'a> {
+            event: &'static str,
+            #[serde(flatten)]
+            notification: &'a Notification,
+        }
+        let label = format!("{} alert", notification.kind);
+        self.deliver_payload(&AlertPayload { event: "Alert", notification }, &label).await
+    }
+
+    /// Deliver a digest as `{"event": "Digest", "counts": {...}, "notifications": [...], ...}`
+    pub async fn deliver_digest(&self, report: &NotificationDigestReport) -> usize {
+        #[derive(Serialize)]
+        struct DigestPayload<'a> {
+            event: &'static str,
+            #[serde(flatten)]
+            report: &'a NotificationDigestReport,
+        }
+        self.deliver_payload(&DigestPayload { event: "Digest", report }, "digest").await
+    }
+
+    async fn deliver_payload<T: Serialize>(&self, payload: &T, label: &str) -> usize {
+        let mut delivered = 0;
+        for url in &self.config.urls {
+            match self.post_with_retry(url, payload, label).await {
+                Ok(()) => delivered += 1,
+                Err(e) => warn!("Position webhook {} dropped {}: {}", url, label, e),
+            }
+        }
+        delivered
+    }
+
+    async fn post_with_retry<T: Serialize>(&self, url: &str, payload: &T, label: &str) -> Result<()> {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let result = self.client
+                .post(url)
+                .json(payload)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+
+            match result {
+                Ok(_) => {
+                    debug!("📮 {} delivered to {}", label, url);
+                    return Ok(());
+                }
+                Err(e) if attempt >= self.config.max_attempts => return Err(e.into()),
+                Err(e) => {
+                    debug!("Position webhook {} attempt {} failed: {}", url, attempt, e);
+                    tokio::time::sleep(Duration::from_millis(250 * attempt as u64)).await;
+                }
+            }
+        }
+    }
+}
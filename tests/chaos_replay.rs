@@ -0,0 +1,42 @@
+mod common;
+
+use badger::core::chaos::ChaosConfig;
+
+/// Replay the fixtures under one fault profile and check it degraded safely
+///
+/// The manifest's invariants cover double buys, duplicate executions, exposure
+/// over the per-mint cap, orphaned positions and signals left unsettled.
+fn assert_degrades_safely(profile: &str) {
+    assert!(ChaosConfig::PROFILES.contains(&profile), "unknown chaos profile {}", profile);
+
+    let run = common::run_fixtures(&format!("chaos-{}", profile), &["--chaos", profile]);
+
+    assert_eq!(run.manifest.chaos_profile.as_deref(), Some(profile));
+    assert!(run.manifest.invariant_violations.is_empty(),
+        "{} broke invariants: {:?}", profile, run.manifest.invariant_violations);
+}
+
+#[test]
+fn ws_drop_degrades_safely() {
+    assert_degrades_safely("ws-drop");
+}
+
+#[test]
+fn db_delay_degrades_safely() {
+    assert_degrades_safely("db-delay");
+}
+
+#[test]
+fn rpc_fail_degrades_safely() {
+    assert_degrades_safely("rpc-fail");
+}
+
+#[test]
+fn reconnect_degrades_safely() {
+    assert_degrades_safely("reconnect");
+}
+
+#[test]
+fn all_faults_degrade_safely() {
+    assert_degrades_safely("all");
+}
@@ -0,0 +1,23 @@
+use badger::database::analytics::position_tracker::CloseAmount;
+
+#[test]
+fn parses_all_percentages_and_sol_amounts() {
+    assert_eq!("all".parse::<CloseAmount>(), Ok(CloseAmount::All));
+    assert_eq!("50%".parse::<CloseAmount>(), Ok(CloseAmount::Fraction(0.5)));
+    assert_eq!("100%".parse::<CloseAmount>(), Ok(CloseAmount::All));
+    assert_eq!("0.5 SOL".parse::<CloseAmount>(), Ok(CloseAmount::Sol(0.5)));
+}
+
+#[test]
+fn rejects_non_finite_and_non_positive_amounts() {
+    for input in ["nan%", "NaN%", "inf%", "0%", "-5%", "101%", "NaN SOL", "nan sol", "inf SOL", "-inf SOL", "0 SOL", "-1 SOL"] {
+        assert!(input.parse::<CloseAmount>().is_err(), "{} should be rejected", input);
+    }
+}
+
+#[test]
+fn nan_amounts_close_nothing() {
+    assert_eq!(CloseAmount::Fraction(f64::NAN).quantity(10.0, 1.0), 0.0);
+    assert_eq!(CloseAmount::Sol(f64::NAN).quantity(10.0, 1.0), 0.0);
+    assert_eq!(CloseAmount::Sol(f64::INFINITY).quantity(10.0, 1.0), 10.0);
+}
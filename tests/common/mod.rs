@@ -0,0 +1,49 @@
+// Shared by every integration test crate; not every crate uses every helper
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use badger::database::ReplayManifest;
+
+/// A finished `--fixtures` run and the directory it wrote its data to
+pub struct FixtureRun {
+    pub dir: PathBuf,
+    pub manifest: ReplayManifest,
+}
+
+impl FixtureRun {
+    /// Fixtures database the run wrote to
+    pub fn database_url(&self) -> String {
+        format!("sqlite:{}", self.dir.join("data/badger-fixtures.db").display())
+    }
+}
+
+/// Boot the badger binary in fixtures mode in a scratch directory and wait for it to exit
+///
+/// # Arguments
+/// * `name` - Scratch directory name, unique per test so runs can go in parallel
+/// * `extra_args` - Arguments appended after `--fixtures --run-for`
+pub fn run_fixtures(name: &str, extra_args: &[&str]) -> FixtureRun {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("create scratch directory");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_badger"))
+        .current_dir(&dir)
+        .args(["--fixtures", "--run-for", "10"])
+        .args(extra_args)
+        .output()
+        .expect("run badger");
+    assert!(output.status.success(), "badger exited with {}:\n{}",
+        output.status, String::from_utf8_lossy(&output.stdout));
+
+    let manifests: Vec<PathBuf> = std::fs::read_dir(dir.join("data/replays"))
+        .expect("replay manifest directory")
+        .map(|entry| entry.expect("replay manifest entry").path())
+        .collect();
+    assert_eq!(manifests.len(), 1, "expected one replay manifest, found {:?}", manifests);
+
+    let manifest = ReplayManifest::load(&manifests[0]).expect("load replay manifest");
+    FixtureRun { dir, manifest }
+}
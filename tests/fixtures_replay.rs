@@ -0,0 +1,20 @@
+mod common;
+
+use sqlx::sqlite::SqlitePool;
+
+#[tokio::test]
+async fn fixture_signals_reach_execution() {
+    let run = common::run_fixtures("fixtures-replay", &[]);
+
+    assert!(run.manifest.input_events > 0, "no fixture events were replayed");
+    assert!(run.manifest.positions > 0, "fixture replay opened no positions");
+    assert!(run.manifest.invariant_violations.is_empty(),
+        "fixture replay broke invariants: {:?}", run.manifest.invariant_violations);
+
+    let pool = SqlitePool::connect(&run.database_url()).await.expect("open fixtures database");
+    let executed: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM signal_outbox WHERE status = 'EXECUTED'")
+        .fetch_one(&pool)
+        .await
+        .expect("count executed signals");
+    assert!(executed > 0, "no fixture signal reached execution");
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use badger::database::{BadgerDatabase, TradeRecord, TradeRecordStore};
+use badger::strike::dex_client::JupiterQuote;
+use badger::strike::slippage_guard::{SlippageGuard, SlippageGuardConfig};
+use badger::strike::{AtaCache, LandingConfig, LandingTelemetry};
+use solana_sdk::pubkey::Pubkey;
+
+fn quote(in_amount: u64, out_amount: u64) -> JupiterQuote {
+    JupiterQuote {
+        input_mint: "So11111111111111111111111111111111111111112".to_string(),
+        in_amount: in_amount.to_string(),
+        output_mint: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
+        out_amount: out_amount.to_string(),
+        other_amount_threshold: out_amount.to_string(),
+        swap_mode: "ExactIn".to_string(),
+        slippage_bps: 50,
+        platform_fee: None,
+        price_impact_pct: "0".to_string(),
+        route_plan: Vec::new(),
+        context_slot: 0,
+        time_taken: 0.0,
+    }
+}
+
+#[test]
+fn slippage_guard_blocks_only_adverse_moves_past_the_band() {
+    let guard = SlippageGuard::new(SlippageGuardConfig { max_move_bps: 300 });
+
+    assert!(guard.check(2.0, &quote(1_000, 2_000)).is_ok());
+    assert!(guard.check(2.0, &quote(1_000, 1_960)).is_ok());
+    assert!(guard.check(2.0, &quote(1_000, 2_400)).is_ok());
+
+    let moved = guard.check(2.0, &quote(1_000, 1_900)).unwrap_err();
+    assert_eq!(moved.move_bps.round(), 500.0);
+
+    // No usable reference price: nothing to compare against
+    assert!(guard.check(f64::NAN, &quote(1_000, 1)).is_ok());
+    assert!(guard.check(0.0, &quote(1_000, 1)).is_ok());
+}
+
+#[test]
+fn landing_policy_escalates_when_first_attempts_stop_landing() {
+    let config = LandingConfig { min_samples: 4, ..LandingConfig::default() };
+    let telemetry = LandingTelemetry::new(config.clone());
+
+    for i in 0..4 {
+        telemetry.record_landed(format!("sig{}", i), 1, config.base_priority_fee_lamports, 400);
+    }
+    let calm = telemetry.policy();
+    assert_eq!(telemetry.stats().landing_rate, 1.0);
+    assert_eq!(calm.max_retries, config.min_retries);
+
+    for _ in 0..8 {
+        telemetry.record_dropped(3, 50_000);
+    }
+    let congested = telemetry.policy();
+    assert!(congested.max_retries > calm.max_retries);
+    assert!(congested.fee_escalation > calm.fee_escalation);
+    assert!(congested.fee_for_attempt(congested.max_attempts()) <= config.max_priority_fee_lamports);
+}
+
+#[test]
+fn ata_cache_only_remembers_accounts_marked_as_existing() {
+    let cache = AtaCache::new();
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    assert!(!cache.is_known(&owner, &mint));
+    cache.mark_exists(&owner, &mint);
+    assert!(cache.is_known(&owner, &mint));
+    cache.invalidate(&owner, &mint);
+    assert!(!cache.is_known(&owner, &mint));
+}
+
+#[tokio::test]
+async fn trade_records_round_trip_and_summarize() {
+    let path = std::env::temp_dir().join(format!("badger-trade-records-{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let db = Arc::new(BadgerDatabase::new(&format!("sqlite:{}", path.display())).await.unwrap());
+    let store = TradeRecordStore::new(db);
+    store.initialize_schema().await.unwrap();
+
+    let mut buy = TradeRecord::new("mint".to_string(), None, "buy".to_string(), 1.0, "executed".to_string());
+    buy.actual_input_amount = Some(1.0);
+    buy.gas_fee = Some(0.01);
+    buy.slippage = Some(1.0);
+    store.record_trade(buy).await.unwrap();
+
+    let mut sell = TradeRecord::new("mint".to_string(), None, "sell".to_string(), 1.5, "executed".to_string());
+    sell.actual_output_amount = Some(1.5);
+    sell.gas_fee = Some(0.01);
+    sell.slippage = Some(3.0);
+    store.record_trade(sell).await.unwrap();
+
+    let mut failed = TradeRecord::new("mint".to_string(), None, "buy".to_string(), 2.0, "failed".to_string());
+    failed.error_message = Some("price moved".to_string());
+    store.record_trade(failed).await.unwrap();
+
+    let recent = store.recent(10).await.unwrap();
+    assert_eq!(recent.len(), 3);
+    assert_eq!(recent[0].status, "failed");
+
+    let summary = store.summary().await.unwrap();
+    assert_eq!(summary.executed, 2);
+    assert_eq!(summary.failed, 1);
+    assert!((summary.total_fees_sol - 0.02).abs() < 1e-9);
+    assert!((summary.net_sol_flow - 0.48).abs() < 1e-9);
+    assert!((summary.average_slippage_percent - 2.0).abs() < 1e-9);
+
+    for suffix in ["", "-wal", "-shm"] {
+        let _ = std::fs::remove_file(format!("{}{}", path.display(), suffix));
+    }
+}